@@ -32,18 +32,29 @@ pub enum Action {
     Right,
     Tab,
     Paste,
+    /// Table navigation actions (configurable, see keymap profiles)
+    PageLeft,
+    PageRight,
+    GoToFirstColumn,
+    GoToLastColumn,
     /// DataManagementDialog actions (configurable)
     DeleteSelectedSource,
     LoadAllPendingDatasets,
     EditSelectedAlias,
+    /// Edit the notes of the selected dataset
+    EditSelectedNotes,
+    /// Toggle storing repetitive string columns of the selected source as Categorical
+    ToggleCategoricalStrings,
     /// Open the Project Settings dialog
     OpenProjectSettingsDialog,
     /// Close the Project Settings dialog
     CloseProjectSettingsDialog,
     /// Open the Sort dialog in the current context
     OpenSortDialog,
-    /// Quick sort: add/select current column in Sort dialog
+    /// Quick sort by the selected column: ascending, descending, then unsorted
     QuickSortCurrentColumn,
+    /// Add the selected column to the current sort, or toggle it there (asc, desc, removed)
+    QuickSortAppendCurrentColumn,
     /// Open the Filter dialog
     OpenFilterDialog,
     /// Quick filter: equals on current cell value
@@ -54,14 +65,145 @@ pub enum Action {
     MoveSelectedColumnRight,
     /// Open SQL dialog
     OpenSqlDialog,
+    /// Open the "Ask your data" dialog (natural language to SQL via the configured LLM)
+    OpenAskDataDialog,
     /// Open JMESPath dialog
     OpenJmesDialog,
+    /// Open the transform history of the current dataset
+    OpenTransformHistoryDialog,
+    /// Export the transform pipeline to a JSON file
+    ExportTransformHistory,
+    /// Import a transform pipeline from a JSON file and replay it
+    ImportTransformHistory,
+    /// Export the transform pipeline as a Python/Polars script
+    ExportTransformScript,
+    /// Open the checkpoints (named snapshots) of the current dataset
+    OpenCheckpointsDialog,
+    /// Name and save a checkpoint of the current view
+    AddCheckpoint,
+    DeleteCheckpoint,
+    /// Toggle saving a checkpoint as Parquet or only as its transform history
+    ToggleCheckpointParquet,
+    /// Save the current view as a checkpoint with this name
+    CreateCheckpoint(String),
+    /// Restore the checkpoint at this index
+    RestoreCheckpoint(usize),
+    /// Open the sample dialog (head/tail/random rows into a new dataset)
+    OpenSampleDialog,
+    /// Create a sampled copy of the current view as a new dataset
+    SampleDialogApplied {
+        options: crate::dataframe::sampling::SampleOptions,
+        dataset_name: String,
+    },
+    /// Open the crosstab dialog (one column's values against another's)
+    OpenCrosstabDialog,
+    /// Open the missing-data report (null/empty share per column)
+    OpenMissingDataDialog,
+    /// Open the Sigma rule evaluation dialog
+    OpenSigmaDialog,
+    /// Evaluate Sigma rules against the current data
+    SigmaDialogApplied(crate::dataframe::sigma::SigmaOptions),
+    /// Open the pattern set dialog (a file of named regexes matched against columns)
+    OpenPatternSetDialog,
+    /// Apply a pattern set to the current data
+    PatternSetDialogApplied(crate::dataframe::pattern_sets::PatternSetOptions),
+    /// Tag the selected row with labels and a note
+    TagSelectedRow,
+    /// Open the row tag manager (labels in use, filter, rename, delete)
+    OpenRowTagsDialog,
+    /// Set the selected row's labels and note; both empty removes its tag
+    RowTagApplied { labels: Vec<String>, note: String },
+    /// Keep only the rows tagged with this label
+    FilterByTag(String),
+    /// Open the findings report dialog (tagged rows, filters, queries and notes as Markdown/HTML)
+    OpenFindingsReportDialog,
+    /// Open the quick actions menu for the selected column
+    OpenColumnMenu,
+    /// Open the go to row dialog (row number, percentage or offset)
+    OpenGoToRowDialog,
+    /// Select this 0-based row of the current view and scroll to it
+    GoToRow(usize),
+    /// Open the go to column dialog (fuzzy search over column names)
+    OpenGoToColumnDialog,
+    /// Select the visible column at this index and scroll to it
+    GoToColumn(usize),
+    /// Start typing a column name to jump to it in a column list
+    SearchColumns,
+    /// Sort by the selected column alone, in the given direction
+    SortSelectedColumn { ascending: bool },
+    HideSelectedColumn,
+    /// Keep the selected column on screen while scrolling sideways, or stop doing so
+    TogglePinSelectedColumn,
+    /// Wrap the selected column's text onto several lines per row, or stop doing so
+    ToggleWrapSelectedColumn,
+    /// Alternate the row background by groups of equal values in the selected column, or go
+    /// back to alternating every other row
+    ToggleGroupRowsBySelectedColumn,
+    /// Open the validation rules dialog (per-column checks, violations and highlighting)
+    OpenValidationDialog,
+    /// Open the resample dialog (events per time bucket, optionally per group)
+    OpenResampleDialog,
+    /// Resample the current view into a new dataset or chart
+    ResampleDialogApplied {
+        options: crate::dataframe::resample::ResampleOptions,
+        output: crate::dialog::resample_dialog::ResampleOutput,
+        dataset_name: String,
+    },
+    /// Open the script editor for Rhai transforms
+    OpenScriptDialog,
+    /// Run the script and add its output columns to the current data
+    RunScript,
+    /// Name and save the script to the project
+    SaveScript,
+    /// List the project's saved scripts
+    OpenSavedScripts,
+    DeleteSavedScript,
+    /// Run a script and add its output columns; `name` is the saved script's name, if any
+    ScriptDialogApplied { name: String, source: String },
+    /// Run a script and open its output columns as a new dataset
+    ScriptDialogAppliedNewDataset { dataset_name: String, source: String },
+    /// Add or replace a saved script in the project
+    ScriptSaved(crate::dataframe::scripting::SavedScript),
+    /// Remove the saved script with this name from the project
+    ScriptDeleted(String),
+    /// The project's recent and saved JMESPath expressions changed
+    JmesHistoryChanged(crate::jmes::history::JmesHistory),
+    /// The project's recent Find searches changed
+    FindHistoryChanged(Vec<crate::dialog::find_dialog::FindHistoryEntry>),
+    /// Search a folder for the missing data files of a workspace
+    SearchFolderForMissingFiles,
+    /// Open the workspace using the located data files
+    ApplyPathRemap,
+    /// Reset the dataset to its original data and apply these steps in order
+    ReplayTransformHistory(Vec<crate::dataframe::transform_history::TransformStep>),
     /// Open Column Operations dialog
     OpenColumnOperationsDialog,
     /// Open Find dialog
     OpenFindDialog,
     /// Open DataFrame Details dialog
     OpenDataframeDetailsDialog,
+    /// Open value counts (frequency) for the selected column
+    OpenValueCounts,
+    /// Open Record View dialog for the selected row
+    OpenRecordViewDialog,
+    /// Open Cell Viewer dialog for the selected cell
+    OpenCellViewerDialog,
+    /// Open the URL or file path in the selected cell with the system handler (after confirmation)
+    OpenCellTarget,
+    /// Open Hex Viewer dialog for a binary or hex-encoded cell
+    OpenHexViewerDialog,
+    /// Open Group By tree dialog
+    OpenGroupByDialog,
+    /// Open Heatmap dialog for the selected column
+    OpenHeatmapDialog,
+    /// Open the histogram / value-count chart for the selected column
+    OpenChartDialog,
+    /// Open the Benford's law first-digit analysis for the selected column
+    OpenBenfordDialog,
+    /// Show, focus or hide the timeline panel above the table
+    ToggleTimeline,
+    /// Open Display Settings dialog (datetime, null and number formatting)
+    OpenDisplaySettingsDialog,
     /// Open Column Width dialog
     OpenColumnWidthDialog,
     /// Open Data Export dialog
@@ -82,6 +224,26 @@ pub enum Action {
     MoveTabLeft,
     /// Move current tab one position right
     MoveTabRight,
+    /// Rename the active tab (sets the dataset alias)
+    RenameTab,
+    /// Copy the active tab's current view into a new dataset
+    DuplicateTab,
+    /// Close the active tab, removing its dataset
+    CloseTab,
+    /// Close every tab except the active one
+    CloseOtherTabs,
+    /// Close the tabs to the right of the active one
+    CloseTabsToRight,
+    /// Open the recent files quick-open dialog
+    OpenQuickOpenDialog,
+    /// Open the recent files entry at this index
+    QuickOpenRecent(usize),
+    /// Open the fuzzy tab switcher
+    OpenTabSwitcher,
+    /// Switch to the tab at this index
+    GoToTab(usize),
+    /// Remove the selected entry from the recent files list
+    ForgetRecentEntry,
     /// Switch to previous tab
     PrevTab,
     /// Switch to next tab
@@ -90,10 +252,14 @@ pub enum Action {
     SyncTabs,
     /// Close any active dialog
     DialogClose,
+    /// User agreed to the operation a confirm dialog asked about
+    ConfirmDialogAccepted,
     /// User applied a sort dialog with columns and directions
     SortDialogApplied(Vec<SortColumn>),
     /// User applied a filter dialog with a root expression
     FilterDialogApplied(FilterExpr),
+    /// User chose the columns to group the table by in the group-by dialog
+    GroupByDialogApplied { group_columns: Vec<String>, aggregate_column: Option<String> },
     /// Add a single filter condition programmatically (e.g., from Unique Values)
     AddFilterCondition(ColumnFilter),
     /// User applied a SQL dialog with a query string
@@ -107,6 +273,13 @@ pub enum Action {
     SqlDialogRestore,
     /// User applied a column width dialog with configuration
     ColumnWidthDialogApplied(ColumnWidthConfig),
+    /// User applied heatmap settings for a column (None disables the heatmap)
+    HeatmapDialogApplied {
+        column: String,
+        config: Option<crate::dialog::heatmap_dialog::HeatmapColumnConfig>,
+    },
+    /// User applied display settings for the current table
+    DisplaySettingsApplied(crate::dialog::display_settings_dialog::DisplaySettings),
     /// User reordered columns in the column width dialog
     ColumnWidthDialogReordered(Vec<String>),
     /// User requested to find next match in the DataTable
@@ -202,10 +375,23 @@ pub enum Action {
         dataset_id: String,
         alias: Option<String>,
     },
+    /// User saved the notes of a dataset; None clears them
+    EditDatasetNotes {
+        source_id: usize,
+        dataset_id: String,
+        notes: Option<String>,
+    },
     /// User applied project settings dialog with configuration
     ProjectSettingsApplied(crate::dialog::ProjectSettingsConfig),
     /// User requested to cast a column to a new dtype
     ColumnCastRequested { column: String, dtype: String },
+    /// Rename a column of the view
+    ColumnRenameRequested { column: String, name: String },
+    /// Schema editor: cast, rename and reorder columns as one transform
+    SchemaEditApplied(crate::dataframe::schema_edit::SchemaEdit),
+    /// SchemaEditorDialog specific actions
+    RenameSchemaColumn,
+    EditDatetimeFormat,
     /// Apply a JMESPath transformation to the dataset
     JmesTransformDataset((String, TransformScope)),
     /// Add columns to the dataset using JMESPath expressions per column name
@@ -216,6 +402,12 @@ pub enum Action {
     ColumnOperationRequested(String),
     /// User applied column operation options
     ColumnOperationOptionsApplied(crate::dialog::column_operation_options_dialog::ColumnOperationConfig),
+    /// Run a saved column operation preset from ColumnOperationsDialog
+    ColumnOperationPresetSelected(crate::dialog::column_operation_options_dialog::ColumnOperationPreset),
+    /// Add or replace a column operation preset in the project
+    ColumnOperationPresetSaved(crate::dialog::column_operation_options_dialog::ColumnOperationPreset),
+    /// Remove the column operation preset with this name from the project
+    ColumnOperationPresetDeleted(String),
     /// User applied the embeddings prompt dialog with computed embedding
     EmbeddingsPromptDialogApplied {
         source_column: String,
@@ -235,6 +427,9 @@ pub enum Action {
     ToggleSortDirection,
     RemoveSortColumn,
     AddSortColumn,
+    ToggleSortNatural,
+    ToggleSortCaseInsensitive,
+    CycleSortNulls,
     /// Filter dialog specific actions
     AddFilter,
     EditFilter,
@@ -252,15 +447,63 @@ pub enum Action {
     EditColumn,
     DeleteColumn,
     ApplyTransform,
+    AcceptCompletion,
+    SaveJmesExpression,
+    OpenJmesHistory,
+    DeleteJmesHistoryEntry,
+    TogglePreviewSample,
     /// FindAllResults dialog specific actions
     GoToFirst,
     GoToLast,
     PageUp,
     PageDown,
+    /// GroupByDialog specific actions
+    ToggleGroupColumn,
+    SetAggregateColumn,
+    GoToGroupRow,
+    ExpandAllGroups,
+    CollapseAllGroups,
+    /// CellViewerDialog specific actions
+    ToggleFold,
+    ExpandAllFolds,
+    CollapseAllFolds,
+    ToggleDecodedView,
+    /// OpenTargetDialog specific actions
+    ConfirmOpenTarget,
+    DenyOpenTarget,
+    /// HeatmapDialog specific actions
+    DisableHeatmap,
+    /// ChartDialog specific actions
+    ToggleChartKind,
+    ToggleChartLogScale,
+    /// MissingDataDialog specific actions
+    DropMissingColumns,
+    FillMissingColumns,
+    /// RowTagsDialog specific actions
+    TagRow,
+    DeleteTag,
+    RenameTag,
+    /// Show or hide the synthetic tags column
+    ToggleTagsColumn,
+    /// ValidationDialog specific actions
+    AddValidationRule,
+    EditValidationRule,
+    DeleteValidationRule,
+    RunValidation,
+    /// Open the violations of the last validation run as a new dataset
+    OpenViolationsDataset,
+    /// Remove the highlighting of failing cells
+    ClearValidation,
+    /// FindingsReportDialog specific actions
+    ExportFindingsReport,
+    /// DisplaySettingsDialog specific actions
+    ClearDisplayFormat,
     /// SqlDialog specific actions
     SelectAllText,
     CopyText,
     RunQuery,
+    /// Run the query without the `sql_row_limit` safeguard
+    RunQueryFetchAll,
     CreateNewDataset,
     RestoreDataFrame,
     OpenSqlFileBrowser,
@@ -288,8 +531,12 @@ pub enum Action {
     StartColumnEditing,
     ToggleEditMode,
     ToggleColumnHidden,
+    /// Wrap the text of the highlighted column onto several lines, or stop doing so
+    ToggleColumnWrap,
     MoveColumnUp,
     MoveColumnDown,
+    /// Size every column from all rows instead of a sample
+    ComputeExactColumnWidths,
     /// JsonOptionsDialog specific actions
     OpenJsonFileBrowser,
     PasteJsonFilePath,
@@ -299,6 +546,9 @@ pub enum Action {
     /// ColumnOperationOptionsDialog specific actions
     ToggleField,
     ToggleButtons,
+    SaveColumnOperationPreset,
+    /// ColumnOperationsDialog specific actions
+    DeleteColumnOperationPreset,
     /// DataFrameDetailsDialog specific actions
     SwitchToNextTab,
     SwitchToPrevTab,
@@ -308,6 +558,9 @@ pub enum Action {
     OpenCastOverlay,
     AddFilterFromValue,
     ExportCurrentTab,
+    /// Re-hash the source files and compare with the hashes recorded at import
+    VerifyEvidence,
+    OpenSchemaEditor,
     NavigateHeatmapLeft,
     NavigateHeatmapRight,
     NavigateHeatmapUp,
@@ -352,6 +605,8 @@ pub enum Action {
     LlmClientDialogApplied(crate::dialog::llm_client_dialog::LlmConfig),
     /// User cancelled LLM client dialog
     LlmClientDialogCancel,
+    /// User saved threat-intel provider keys and rate limits
+    ThreatIntelSettingsApplied(crate::threat_intel::ThreatIntelConfig),
     /// Open the LLM Client Create dialog for embeddings
     OpenLlmClientCreateDialogEmbeddings,
     /// Open the LLM Client Create dialog for completion
@@ -362,6 +617,8 @@ pub enum Action {
     LlmClientCreateDialogApplied(crate::dialog::llm_client_create_dialog::LlmClientSelection),
     /// Open the Style Set Manager dialog
     OpenStyleSetManagerDialog,
+    /// Enable or disable the built-in IOC Highlighting style set
+    ToggleIocHighlighting,
     /// Close the Style Set Manager dialog
     CloseStyleSetManagerDialog,
     /// Open the Style Rule Editor dialog
@@ -388,6 +645,10 @@ pub enum Action {
     FocusCategoryTree,
     FocusStyleSetTable,
     EditStyleSet,
+    /// Browse built-in style set templates
+    OpenTemplateGallery,
+    /// Switch the current tab between its own enabled style sets and the global ones
+    ToggleStyleSetTabScope,
     /// StyleSetEditorDialog specific actions
     OpenStyleSetEditorDialog,
     CloseStyleSetEditorDialog,
@@ -424,7 +685,7 @@ mod tests {
     #[test]
     fn test_action_display() {
         let a1 = Action::DialogClose;
-        let a2 = Action::SortDialogApplied(vec![SortColumn { name: "test".to_string(), ascending: true }]);
+        let a2 = Action::SortDialogApplied(vec![SortColumn::new("test", true)]);
         let a1_str = format!("{a1}");
         let a2_str = format!("{a2}");
         info!("Action::DialogClose Display: {}", a1_str);
@@ -1,8 +1,6 @@
-use clap::{Parser, ValueEnum};
+use clap::{Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
 use std::io;
-use std::io::Read;
-use std::fs;
 use crossterm::event::{self, Event as CEvent, EnableMouseCapture, DisableMouseCapture};
 use crossterm::terminal::{enable_raw_mode, disable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
 use crossterm::execute;
@@ -10,25 +8,18 @@ use ratatui::backend::CrosstermBackend;
 use ratatui::Terminal;
 use ratatui::prelude::{Rect, Widget};
 use std::time::Duration;
-use datatui::dialog::{DataTabManagerDialog, KeybindingsDialog, MessageDialog};
-use datatui::style::StyleConfig;
+use datatui::dialog::{DataTabManagerDialog, HelpDialog, KeybindingsDialog, MessageDialog};
+use datatui::style::{StyleConfig, downgrade_buffer};
 use datatui::config::Config;
 use datatui::components::Component;
 use datatui::tui::Event as TuiEvent;
 use datatui::action::Action;
 use datatui::update_check;
-use datatui::data_import_types::DataImportConfig;
+use datatui::recent_files::RecentFiles;
+use datatui::services::{ControlServer, DataService};
 use std::thread;
-use datatui::dialog::csv_options_dialog::CsvImportOptions;
-use datatui::dialog::xlsx_options_dialog::XlsxImportOptions;
-use datatui::dialog::sqlite_options_dialog::SqliteImportOptions;
-use datatui::dialog::parquet_options_dialog::ParquetImportOptions;
-use datatui::dialog::json_options_dialog::JsonImportOptions;
-use datatui::excel_operations::ExcelOperations;
 use color_eyre::Result;
 use tracing::error;
-use uuid::Uuid;
-use glob::glob;
 
 /// Simple CLI for DataTabManagerDialog demo
 #[derive(Parser, Debug)]
@@ -45,8 +36,49 @@ struct Args {
     ///           --load 'xlsx:C:\\data\\book.xlsx;all_sheets=true'
     ///           --load 'sqlite:C:\\db\\app.sqlite;table=users'
     ///           --load 'json:STDIN' (reads from stdin into a temp file)
-    #[arg(long = "load", value_name = "SPEC")]
+    #[arg(long = "load", value_name = "SPEC", global = true)]
     load: Vec<String>,
+    /// Run without the TUI, answering JSON-RPC requests on a socket path or loopback host:port address.
+    /// --load datasets are loaded first.
+    #[arg(long = "control-socket", value_name = "ADDR")]
+    control_socket: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+/// One-shot commands that run without the TUI. Inputs are --load specs or file paths.
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run SQL over the --load datasets and print the result (CSV by default)
+    Query {
+        /// The query; datasets are tables named as in the TUI, e.g. "logs.csv"
+        sql: String,
+        /// Write the result to a file instead of stdout
+        #[arg(short, long, value_name = "PATH")]
+        output: Option<PathBuf>,
+        /// csv, jsonl or parquet (default: from the output extension, or csv)
+        #[arg(short, long)]
+        format: Option<String>,
+    },
+    /// Print the row count, columns and types of each dataset in the inputs
+    Schema {
+        #[arg(required = true, value_name = "INPUT")]
+        inputs: Vec<String>,
+        /// Print JSON instead of text
+        #[arg(long)]
+        json: bool,
+    },
+    /// Convert a dataset to CSV, JSON Lines or Parquet
+    Convert {
+        input: String,
+        output: PathBuf,
+        /// csv, jsonl or parquet (default: from the output extension)
+        #[arg(short, long)]
+        format: Option<String>,
+        /// Dataset to convert when the input holds several (worksheets, tables)
+        #[arg(long)]
+        dataset: Option<String>,
+    },
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
@@ -68,10 +100,18 @@ fn main() -> Result<()> {
     };
     datatui::logging::init_with(Some(log_path), level)?;
     
+    if let Some(address) = &args.control_socket {
+        return run_control_server(address, &args);
+    }
+    if let Some(command) = &args.command {
+        return run_command(command, &args);
+    }
+
     // Load Config and create DataTabManagerDialog
     let style = StyleConfig::default();
     let mut tab_manager = DataTabManagerDialog::new(style);
     if let Ok(cfg) = Config::from_path(args.config.as_ref()) {
+        datatui::plugins::load_dir(&cfg.plugin_dir());
         let _ = tab_manager.register_config_handler(cfg);
     }
 
@@ -102,6 +142,8 @@ fn run_app<B: ratatui::backend::Backend>(
     let mut pending_load_specs: Option<Vec<String>> = if initial_load_specs.is_empty() { None } else { Some(initial_load_specs) };
     // Optional global Keybindings dialog overlay, opened via a global shortcut
     let mut keybindings_dialog: Option<KeybindingsDialog> = None;
+    // Optional searchable help overlay listing every bound action
+    let mut help_dialog: Option<HelpDialog> = None;
     // Optional update check message dialog
     let mut update_message_dialog: Option<MessageDialog> = None;
     
@@ -127,6 +169,11 @@ fn run_app<B: ratatui::backend::Backend>(
             if let Some(dialog) = &mut keybindings_dialog {
                 let _ = dialog.draw(f, size);
             }
+            // When open, render the help dialog on top
+            if let Some(dialog) = &mut help_dialog {
+                let area = Rect::new(size.x + size.width / 10, size.y + size.height / 10, size.width - size.width / 5, size.height - size.height / 5);
+                dialog.render(area, f.buffer_mut());
+            }
             // When open, render the update message dialog on top (centered modal)
             if let Some(dialog) = &mut update_message_dialog {
                 use ratatui::widgets::Clear;
@@ -139,6 +186,9 @@ fn run_app<B: ratatui::backend::Backend>(
                 let dialog_area = Rect::new(dialog_x, dialog_y, dialog_width, dialog_height);
                 let _ = dialog.draw(f, dialog_area);
             }
+            if !tab_manager.config.use_truecolor() {
+                downgrade_buffer(f.buffer_mut());
+            }
         })?;
         // After drawing, process queued Render work (overlay is now visible)
         let _ = tab_manager.update(Action::Render);
@@ -247,8 +297,18 @@ fn run_app<B: ratatui::backend::Backend>(
         }
         
         // Poll for events
-        if event::poll(Duration::from_millis(100))?
-            && let CEvent::Key(key_event) = event::read()? {
+        if event::poll(Duration::from_millis(100))? {
+            let event = event::read()?;
+            // Mouse clicks and drags go to the tab manager while no app-level dialog is open
+            if let CEvent::Mouse(mouse_event) = event
+                && keybindings_dialog.is_none()
+                && help_dialog.is_none()
+                && update_message_dialog.is_none()
+                && let Err(e) = tab_manager.handle_events(Some(TuiEvent::Mouse(mouse_event)))
+            {
+                error!("Error handling mouse event: {e}");
+            }
+            if let CEvent::Key(key_event) = event {
                 if let Some(global_action) = tab_manager.config.action_for_key(datatui::config::Mode::Global, key_event){
                     match global_action {
                         Action::Quit => {
@@ -266,59 +326,80 @@ fn run_app<B: ratatui::backend::Backend>(
                             }
                             continue;
                         }
+                        Action::Help => {
+                            if help_dialog.is_some() {
+                                help_dialog = None;
+                            } else {
+                                let mut dlg = HelpDialog::new();
+                                if let Err(err) = dlg.register_config_handler(tab_manager.config.clone()) {
+                                    error!("Error registering config handler for HelpDialog: {err}");
+                                }
+                                help_dialog = Some(dlg);
+                            }
+                            continue;
+                        }
                         _ => {}
                     }
                 }
 
-            // If update message dialog is open, it consumes events first
-            if let Some(dialog) = &mut update_message_dialog {
-                match dialog.handle_events(Some(TuiEvent::Key(key_event))) {
-                    Ok(Some(Action::DialogClose)) => {
-                        update_message_dialog = None;
+                // If update message dialog is open, it consumes events first
+                if let Some(dialog) = &mut update_message_dialog {
+                    match dialog.handle_events(Some(TuiEvent::Key(key_event))) {
+                        Ok(Some(Action::DialogClose)) => {
+                            update_message_dialog = None;
+                        }
+                        Ok(Some(_)) => {}
+                        Ok(None) => {}
+                        Err(e) => error!("Error handling UpdateMessageDialog event: {e}"),
                     }
-                    Ok(Some(_)) => {}
-                    Ok(None) => {}
-                    Err(e) => error!("Error handling UpdateMessageDialog event: {e}"),
+                    continue;
                 }
-                continue;
-            }
             
-            // If keybindings dialog is open, it consumes events first
-            if let Some(dialog) = &mut keybindings_dialog {
-                match dialog.handle_events(Some(TuiEvent::Key(key_event))) {
-                    Ok(Some(Action::DialogClose)) => {
-                        keybindings_dialog = None;
-                    }
-                    Ok(Some(Action::SaveKeybindings)) => {
-                        let _ = tab_manager.register_config_handler(dialog.get_config());
-                        keybindings_dialog = None;
+                // If help dialog is open, it consumes events first
+                if let Some(dialog) = &mut help_dialog {
+                    if let Some(Action::DialogClose) = dialog.handle_key_event(key_event) {
+                        help_dialog = None;
                     }
-                    Ok(Some(Action::SaveWorkspaceState)) => {
-                        let _ = tab_manager.save_workspace_state();
+                    continue;
+                }
+
+                // If keybindings dialog is open, it consumes events first
+                if let Some(dialog) = &mut keybindings_dialog {
+                    match dialog.handle_events(Some(TuiEvent::Key(key_event))) {
+                        Ok(Some(Action::DialogClose)) => {
+                            keybindings_dialog = None;
+                        }
+                        Ok(Some(Action::SaveKeybindings)) => {
+                            let _ = tab_manager.register_config_handler(dialog.get_config());
+                            keybindings_dialog = None;
+                        }
+                        Ok(Some(Action::SaveWorkspaceState)) => {
+                            let _ = tab_manager.save_workspace_state();
+                        }
+                        Ok(Some(_)) => {}
+                        Ok(None) => {}
+                        Err(e) => error!("Error handling KeybindingsDialog event: {e}"),
                     }
-                    Ok(Some(_)) => {}
-                    Ok(None) => {}
-                    Err(e) => error!("Error handling KeybindingsDialog event: {e}"),
+                    continue;
                 }
-                continue;
-            }
-            // Otherwise pass to tab manager
-            // Convert to TuiEvent and pass to handle_events
-            let tui_event = TuiEvent::Key(key_event);
-            match tab_manager.handle_events(Some(tui_event)) {
-                Ok(Some(action)) => {
-                    // Handle global quit/suspend
-                    match action {
-                        Action::Quit | Action::Suspend => break,
-                        other => {
-                            if let Err(e) = tab_manager.update(other) {
-                                error!("Error updating after action: {e}");
+                // Otherwise pass to tab manager
+                // Convert to TuiEvent and pass to handle_events
+                let tui_event = TuiEvent::Key(key_event);
+                match tab_manager.handle_events(Some(tui_event)) {
+                    Ok(Some(action)) => {
+                        // Handle global quit/suspend
+                        match action {
+                            Action::Quit | Action::Suspend => break,
+                            other => {
+                                if let Err(e) = tab_manager.update(other) {
+                                    error!("Error updating after action: {e}");
+                                }
                             }
                         }
                     }
+                    Ok(None) => {}
+                    Err(e) => error!("Error handling TuiEvent: {e}"),
                 }
-                Ok(None) => {}
-                Err(e) => error!("Error handling TuiEvent: {e}"),
             }
         }
         // Tick update (animate progress, etc.)
@@ -333,20 +414,91 @@ fn run_app<B: ratatui::backend::Backend>(
 }
 
 
+// Serve automation requests until a client sends shutdown
+fn run_control_server(address: &std::path::Path, args: &Args) -> color_eyre::Result<()> {
+    if let Ok(cfg) = Config::from_path(args.config.as_ref()) {
+        datatui::plugins::load_dir(&cfg.plugin_dir());
+    }
+    let mut service = DataService::new();
+    for spec in &args.load {
+        for cfg in datatui::load_spec::parse_load_spec(spec)? {
+            service.import(cfg)?;
+        }
+    }
+    ControlServer::new(service).listen(&address.to_string_lossy())
+}
 
-// Ensure only known option keys are present for a given kind; otherwise return an error
-fn ensure_only_allowed_keys(kind: &str, kv: &std::collections::HashMap<String, String>, allowed: &[&str]) -> color_eyre::Result<()> {
-    let mut unknown: Vec<String> = Vec::new();
-    for k in kv.keys() {
-        if !allowed.contains(&k.as_str()) {
-            unknown.push(k.clone());
+// Import every input into a fresh DataService
+fn load_inputs<'a>(inputs: impl IntoIterator<Item = &'a String>) -> color_eyre::Result<DataService> {
+    let mut service = DataService::new();
+    for input in inputs {
+        for cfg in datatui::load_spec::parse_input(input)? {
+            service.import(cfg)?;
         }
     }
-    if !unknown.is_empty() {
-        return Err(color_eyre::eyre::eyre!(format!(
-            "Unknown option(s) for {kind}: {}",
-            unknown.join(", ")
-        )));
+    Ok(service)
+}
+
+// Run a one-shot subcommand, writing results to stdout or a file
+fn run_command(command: &Command, args: &Args) -> color_eyre::Result<()> {
+    use color_eyre::eyre::eyre;
+    use datatui::services::ExportFormat;
+    use std::io::Write;
+    let format_for = |format: &Option<String>, output: Option<&PathBuf>| -> color_eyre::Result<ExportFormat> {
+        match (format, output) {
+            (Some(name), _) => name.parse(),
+            (None, Some(path)) => ExportFormat::from_path(path),
+            (None, None) => Ok(ExportFormat::Csv),
+        }
+    };
+    match command {
+        Command::Query { sql, output, format } => {
+            if let Ok(cfg) = Config::from_path(args.config.as_ref()) {
+                datatui::plugins::load_dir(&cfg.plugin_dir());
+            }
+            let service = load_inputs(&args.load)?;
+            let mut df = service.sql(sql)?;
+            let format = format_for(format, output.as_ref())?;
+            match output {
+                Some(path) => DataService::export(&mut df, path, format)?,
+                None => DataService::write(&mut df, io::stdout().lock(), format)?,
+            }
+        }
+        Command::Schema { inputs, json } => {
+            let service = load_inputs(inputs)?;
+            let mut out = io::stdout().lock();
+            let mut described = Vec::new();
+            for name in service.names() {
+                let Some(df) = service.dataset(&name) else { continue };
+                if *json {
+                    let columns: Vec<serde_json::Value> = df
+                        .get_columns()
+                        .iter()
+                        .map(|c| serde_json::json!({ "name": c.name().as_str(), "dtype": c.dtype().to_string() }))
+                        .collect();
+                    described.push(serde_json::json!({ "name": name, "rows": df.height(), "columns": columns }));
+                } else {
+                    writeln!(out, "{name} ({} rows)", df.height())?;
+                    for column in df.get_columns() {
+                        writeln!(out, "  {}\t{}", column.name(), column.dtype())?;
+                    }
+                }
+            }
+            if *json {
+                writeln!(out, "{}", serde_json::to_string_pretty(&described)?)?;
+            }
+        }
+        Command::Convert { input, output, format, dataset } => {
+            let service = load_inputs([input])?;
+            let names = service.names();
+            let name = match (dataset, names.as_slice()) {
+                (Some(name), _) => name.clone(),
+                (None, [only]) => only.clone(),
+                (None, _) => return Err(eyre!("'{input}' holds {} datasets; pick one with --dataset: {}", names.len(), names.join(", "))),
+            };
+            let df = service.dataset(&name).ok_or_else(|| eyre!("No dataset named '{name}'"))?;
+            DataService::export(&mut df.as_ref().clone(), output, format_for(format, Some(output))?)?;
+        }
     }
     Ok(())
 }
@@ -355,10 +507,11 @@ fn ensure_only_allowed_keys(kind: &str, kv: &std::collections::HashMap<String, S
 fn materialize_and_add_loads(specs: &[String], tab_manager: &mut DataTabManagerDialog) -> color_eyre::Result<usize> {
     let mut added = 0usize;
     for s in specs {
-        match parse_load_spec(s) {
+        match datatui::load_spec::parse_load_spec(s) {
             Ok(cfgs) => {
                 for cfg in cfgs {
-                    tab_manager.data_management_dialog.add_data_source(cfg);
+                    RecentFiles::remember_import(&cfg);
+                    tab_manager.data_management_dialog.import_data_source(cfg)?;
                     added = added.saturating_add(1);
                 }
             }
@@ -370,259 +523,3 @@ fn materialize_and_add_loads(specs: &[String], tab_manager: &mut DataTabManagerD
     }
     Ok(added)
 }
-
-// Returns one or multiple DataImportConfig values for a single spec (e.g., xlsx sheets can expand).
-fn parse_load_spec(spec: &str) -> color_eyre::Result<Vec<DataImportConfig>> {
-    // Split on the first ':' into kind and the rest
-    let (kind_raw, rest) = spec
-        .split_once(':')
-        .ok_or_else(|| color_eyre::eyre::eyre!("Expected 'kind:path[;key=value...]'"))?;
-    let kind = kind_raw.trim().to_ascii_lowercase();
-
-    // Path is first segment before ';', options follow as key=value pairs separated by ';'
-    let mut parts = rest.split(';');
-    let mut path = parts.next().unwrap_or("").trim().to_string();
-    let mut kv: std::collections::HashMap<String, String> = std::collections::HashMap::new();
-    for seg in parts {
-        if seg.trim().is_empty() { continue; }
-        if let Some((k, v)) = seg.split_once('=') {
-            kv.insert(k.trim().to_ascii_lowercase(), v.trim().to_string());
-        } else {
-            // bare flags like 'all_sheets' => true
-            kv.insert(seg.trim().to_ascii_lowercase(), "true".to_string());
-        }
-    }
-
-    // Handle STDIN magic path for supported kinds
-    if path.eq_ignore_ascii_case("stdin") || path == "-" {
-        path = write_stdin_to_temp_file(&kind, &kv)?;
-    }
-
-    // Expand wildcards (glob). If none, returns the original path.
-    let paths = expand_glob_paths(&path)?;
-
-    match kind.as_str() {
-        // Text/CSV-like
-        "text" | "csv" | "tsv" | "psv" => {
-            ensure_only_allowed_keys(
-                &kind,
-                &kv,
-                &[
-                    "delim",
-                    "delimiter",
-                    "header",
-                    "has_header",
-                    "quote",
-                    "quote_char",
-                    "escape",
-                    "escape_char",
-                    "merge",
-                ],
-            )?;
-            let mut out = Vec::new();
-            let merge = kv.get("merge").map(|v| parse_bool(v)).unwrap_or(false);
-            if !paths.is_empty() {
-                // First path becomes primary; rest go into additional_paths and merge flag.
-                let mut primary: Option<DataImportConfig> = None;
-                let mut extras: Vec<PathBuf> = Vec::new();
-                for (idx, pb) in paths.iter().enumerate() {
-                    let mut opts = CsvImportOptions::default();
-                    // Kind shortcuts for delimiter
-                    if kind == "tsv" { opts.delimiter = '\t'; }
-                    if kind == "psv" { opts.delimiter = '|'; }
-                    if kind == "csv" { opts.delimiter = ','; }
-                    // Guess from extension if not overridden
-                    if let Some(ext) = pb.extension()
-                            .and_then(|e| e.to_str())
-                            .map(|s| s.to_ascii_lowercase()
-                    )
-                        && opts.delimiter == ',' {
-                            if ext == "tsv" { 
-                                opts.delimiter = '\t';
-                            } else if ext == "psv" { 
-                                opts.delimiter = '|'; 
-                            }
-                        }
-                    if let Some(v) = kv.get("delim").or_else(|| kv.get("delimiter")) {
-                        opts.delimiter = parse_delimiter(v)?;
-                    }
-                    if let Some(v) = kv.get("header").or_else(|| kv.get("has_header")) {
-                        opts.has_header = parse_bool(v);
-                    }
-                    if let Some(v) = kv.get("quote").or_else(|| kv.get("quote_char")) {
-                        opts.quote_char = parse_char_opt(v);
-                    }
-                    if let Some(v) = kv.get("escape").or_else(|| kv.get("escape_char")) {
-                        opts.escape_char = parse_char_opt(v);
-                    }
-                    if idx == 0 {
-                        let mut cfg = DataImportConfig::text(pb.clone(), opts);
-                        if let DataImportConfig::Text(ref mut t) = cfg {
-                            t.merge = merge;
-                        }
-                        primary = Some(cfg);
-                    } else {
-                        extras.push(pb.clone());
-                    }
-                }
-                if let Some(mut cfg) = primary {
-                    if let DataImportConfig::Text(ref mut t) = cfg {
-                        t.additional_paths = extras;
-                    }
-                    out.push(cfg);
-                }
-            }
-            Ok(out)
-        }
-        // Excel
-        "xlsx" | "xls" => {
-            ensure_only_allowed_keys(&kind, &kv, &["all_sheets", "sheets", "sheet"]) ?;
-            let mut out = Vec::new();
-            for pb in paths {
-                // Default: load worksheet info and mark all load=true
-                let mut worksheets = ExcelOperations::read_worksheet_info(&pb).unwrap_or_default();
-                // Filters
-                let all = kv.get("all_sheets").map(|v| parse_bool(v)).unwrap_or(true);
-                if !all
-                    && let Some(names) = kv.get("sheets").or_else(|| kv.get("sheet")) {
-                        let set: std::collections::HashSet<String> = names.split(',').map(|s| s.trim().to_string()).collect();
-                        for ws in &mut worksheets { ws.load = set.contains(&ws.name); }
-                        worksheets.retain(|w| w.load);
-                    }
-                let opts = XlsxImportOptions { worksheets };
-                out.push(DataImportConfig::excel(pb, opts));
-            }
-            Ok(out)
-        }
-        // SQLite
-        "sqlite" | "db" => {
-            ensure_only_allowed_keys(&kind, &kv, &["import_all_tables", "table", "tables"]) ?;
-            let mut out = Vec::new();
-            for pb in paths {
-                let mut opts = SqliteImportOptions::default();
-                if let Some(v) = kv.get("import_all_tables") { opts.import_all_tables = parse_bool(v); }
-                if let Some(t) = kv.get("table") { opts.import_all_tables = false; opts.selected_tables = vec![t.to_string()]; }
-                if let Some(ts) = kv.get("tables") { opts.import_all_tables = false; opts.selected_tables = ts.split(',').map(|s| s.trim().to_string()).collect(); }
-                out.push(DataImportConfig::sqlite(pb, opts));
-            }
-            Ok(out)
-        }
-        // Parquet
-        "parquet" => {
-            ensure_only_allowed_keys(&kind, &kv, &[])?;
-            let mut out = Vec::new();
-            for pb in paths {
-                let opts = ParquetImportOptions::default();
-                out.push(DataImportConfig::parquet(pb, opts));
-            }
-            Ok(out)
-        }
-        // JSON / NDJSON
-        "json" | "jsonl" | "ndjson" => {
-            ensure_only_allowed_keys(&kind, &kv, &["ndjson", "records", "merge"]) ?;
-            let mut out = Vec::new();
-            let merge = kv.get("merge").map(|v| parse_bool(v)).unwrap_or(false);
-            if !paths.is_empty() {
-                let mut primary: Option<DataImportConfig> = None;
-                let mut extras: Vec<PathBuf> = Vec::new();
-                for (idx, pb) in paths.iter().enumerate() {
-                    let mut opts = JsonImportOptions::default();
-                    if kind == "jsonl" || kind == "ndjson" { opts.ndjson = true; }
-                    if let Some(v) = kv.get("ndjson") { opts.ndjson = parse_bool(v); }
-                    if let Some(expr) = kv.get("records") { opts.records_expr = expr.to_string(); }
-                    if idx == 0 {
-                        let mut cfg = DataImportConfig::json(pb.clone(), opts);
-                        if let DataImportConfig::Json(ref mut j) = cfg {
-                            j.merge = merge;
-                        }
-                        primary = Some(cfg);
-                    } else {
-                        extras.push(pb.clone());
-                    }
-                }
-                if let Some(mut cfg) = primary {
-                    if let DataImportConfig::Json(ref mut j) = cfg {
-                        j.additional_paths = extras;
-                    }
-                    out.push(cfg);
-                }
-            }
-            Ok(out)
-        }
-        other => Err(color_eyre::eyre::eyre!(format!("Unknown load kind '{other}'")))
-    }
-}
-
-fn parse_bool(v: &str) -> bool {
-    matches!(v.to_ascii_lowercase().as_str(), "1" | "true" | "yes" | "on")
-}
-
-fn parse_char_opt(v: &str) -> Option<char> {
-    if v.eq_ignore_ascii_case("none") || v.is_empty() { return None; }
-    if v.starts_with("char:") {
-        return v.chars().nth(5);
-    }
-    let unquoted = v.trim_matches('\'').trim_matches('"');
-    unquoted.chars().next()
-}
-
-fn parse_delimiter(v: &str) -> color_eyre::Result<char> {
-    let s = v.to_ascii_lowercase();
-    Ok(match s.as_str() {
-        "," | "comma" => ',',
-        "\t" | "tab" => '\t',
-        "|" | "pipe" | "psv" => '|',
-        "space" => ' ',
-        _ if s.starts_with("char:") => s.chars().nth(5).ok_or_else(|| color_eyre::eyre::eyre!("Missing char after 'char:'"))?,
-        _ => s.chars().next().ok_or_else(|| color_eyre::eyre::eyre!("Invalid delimiter"))?,
-    })
-}
-
-// If path is STDIN/-: read stdin bytes and write to a temp file with an extension based on kind.
-fn write_stdin_to_temp_file(kind: &str, kv: &std::collections::HashMap<String, String>) -> color_eyre::Result<String> {
-    let mut stdin = std::io::stdin();
-    // If attached to terminal, we will still block waiting for input; it's the user's responsibility.
-    let mut buf: Vec<u8> = Vec::with_capacity(1024 * 64);
-    stdin.read_to_end(&mut buf)?;
-    let ext = match kind {
-        "text" | "csv" | "tsv" | "psv" => {
-            match kv.get("delim").map(|s| s.as_str()) {
-                Some("tab") => "tsv",
-                Some("psv") | Some("pipe") => "psv",
-                _ => "csv",
-            }
-        }
-        "json" | "jsonl" | "ndjson" => {
-            if kv.get("ndjson").map(|v| parse_bool(v)).unwrap_or(kind == "jsonl" || kind == "ndjson") { "jsonl" } else { "json" }
-        }
-        "parquet" => "parquet",
-        "xlsx" | "xls" => "xlsx",
-        "sqlite" | "db" => "sqlite",
-        _ => "dat",
-    };
-    let tmp = std::env::temp_dir().join(format!("datatui_stdin_{}.{}", Uuid::new_v4(), ext));
-    fs::write(&tmp, buf)?;
-    Ok(tmp.to_string_lossy().to_string())
-}
-
-// Expand a potential glob into concrete paths. If no wildcard is present or expansion
-// yields no matches, fall back to the original path as a single entry.
-fn expand_glob_paths(input: &str) -> color_eyre::Result<Vec<PathBuf>> {
-    let has_wildcards = input.contains('*') || input.contains('?') || input.contains('[');
-    if !has_wildcards {
-        return Ok(vec![PathBuf::from(input)]);
-    }
-    let mut out = Vec::new();
-    for entry in glob(input).map_err(|e| color_eyre::eyre::eyre!(format!("Invalid glob pattern '{input}': {e}")))? {
-        match entry {
-            Ok(p) => out.push(p),
-            Err(e) => error!("Glob error on '{input}': {e}"),
-        }
-    }
-    if out.is_empty() {
-        // If nothing matched, treat as literal to avoid surprising drops
-        Ok(vec![PathBuf::from(input)])
-    } else {
-        Ok(out)
-    }
-}
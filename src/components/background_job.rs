@@ -0,0 +1,123 @@
+//! BackgroundJob: run a long column operation on a worker thread with progress and cancellation
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+
+/// Handle given to the worker closure for reporting progress and observing cancellation
+#[derive(Debug, Clone)]
+pub struct JobContext {
+    cancelled: Arc<AtomicBool>,
+    /// f64 progress in [0, 1], stored as bits
+    progress: Arc<AtomicU64>,
+}
+
+impl JobContext {
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    pub fn set_progress(&self, progress: f64) {
+        self.progress.store(progress.clamp(0.0, 1.0).to_bits(), Ordering::Relaxed);
+    }
+
+    /// Error to return from the worker when `is_cancelled` is observed
+    pub fn check_cancelled(&self) -> Result<()> {
+        if self.is_cancelled() { Err(eyre!("Cancelled")) } else { Ok(()) }
+    }
+}
+
+/// A worker thread producing a single `T`.
+///
+/// The UI polls `try_finish` on each tick. Cancelling only signals the worker; the UI stops
+/// waiting immediately and any late result is dropped with the job.
+#[derive(Debug)]
+pub struct BackgroundJob<T> {
+    context: JobContext,
+    receiver: Receiver<Result<T>>,
+    /// Whether the worker reports real progress (otherwise the overlay animates)
+    reports_progress: bool,
+}
+
+impl<T: Send + 'static> BackgroundJob<T> {
+    pub fn spawn<F>(reports_progress: bool, work: F) -> Self
+    where
+        F: FnOnce(&JobContext) -> Result<T> + Send + 'static,
+    {
+        let context = JobContext {
+            cancelled: Arc::new(AtomicBool::new(false)),
+            progress: Arc::new(AtomicU64::new(0f64.to_bits())),
+        };
+        let (sender, receiver) = mpsc::channel();
+        let worker_context = context.clone();
+        std::thread::spawn(move || {
+            let result = work(&worker_context);
+            // The receiver is gone if the job was cancelled and dropped
+            let _ = sender.send(result);
+        });
+        Self { context, receiver, reports_progress }
+    }
+
+    pub fn cancel(&self) {
+        self.context.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Latest reported progress, if the worker reports any
+    pub fn progress(&self) -> Option<f64> {
+        self.reports_progress
+            .then(|| f64::from_bits(self.context.progress.load(Ordering::Relaxed)))
+    }
+
+    /// The worker's result once it has finished; None while still running
+    pub fn try_finish(&self) -> Option<Result<T>> {
+        match self.receiver.try_recv() {
+            Ok(result) => Some(result),
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => Some(Err(eyre!("Background job stopped without a result"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    fn wait<T: Send + 'static>(job: &BackgroundJob<T>) -> Result<T> {
+        let start = Instant::now();
+        loop {
+            if let Some(result) = job.try_finish() {
+                return result;
+            }
+            assert!(start.elapsed() < Duration::from_secs(5), "job did not finish");
+            std::thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn test_job_reports_result_and_progress() {
+        let job = BackgroundJob::spawn(true, |ctx| {
+            ctx.set_progress(0.5);
+            Ok(21 * 2)
+        });
+        assert_eq!(wait(&job).unwrap(), 42);
+        assert_eq!(job.progress(), Some(0.5));
+    }
+
+    #[test]
+    fn test_cancel_is_observed_by_worker() {
+        let job = BackgroundJob::spawn(false, |ctx| {
+            while !ctx.is_cancelled() {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+            ctx.check_cancelled().map(|_| ())
+        });
+        assert!(job.try_finish().is_none());
+        assert_eq!(job.progress(), None);
+        job.cancel();
+        assert!(wait(&job).is_err());
+    }
+}
@@ -0,0 +1,175 @@
+//! Terminal width of grid cell text.
+//!
+//! Widths are summed per grapheme cluster, the same way ratatui lays out spans, so CJK and
+//! emoji (two columns), combining marks (zero) and control characters measure as drawn.
+
+use std::borrow::Cow;
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Marker drawn at the end of text that was cut to fit its column
+pub const ELLIPSIS: &str = "…";
+
+/// Single-line form of a cell: line breaks become a visible symbol, tabs a space, and other
+/// control characters are dropped so they cannot move the cursor
+pub fn sanitize(text: &str) -> Cow<'_, str> {
+    if !text.chars().any(char::is_control) {
+        return Cow::Borrowed(text);
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' | '\r' => out.push('⏎'),
+            '\t' => out.push(' '),
+            c if c.is_control() => {}
+            c => out.push(c),
+        }
+    }
+    Cow::Owned(out)
+}
+
+/// Columns `text` takes once sanitized
+pub fn display_width(text: &str) -> usize {
+    sanitize(text).graphemes(true).map(UnicodeWidthStr::width).sum()
+}
+
+/// `text` cut to at most `max_width` columns, ending in an ellipsis when shortened. Grapheme
+/// clusters are never split, so a wide character that would straddle the edge is left out.
+pub fn truncate_to_width(text: &str, max_width: usize) -> Cow<'_, str> {
+    let widths: Vec<(&str, usize)> = text.graphemes(true).map(|g| (g, g.width())).collect();
+    if widths.iter().map(|(_, w)| w).sum::<usize>() <= max_width {
+        return Cow::Borrowed(text);
+    }
+    let budget = max_width.saturating_sub(ELLIPSIS.width());
+    let mut out = String::new();
+    let mut used = 0;
+    for (grapheme, width) in widths {
+        if used + width > budget {
+            break;
+        }
+        out.push_str(grapheme);
+        used += width;
+    }
+    if max_width > 0 {
+        out.push_str(ELLIPSIS);
+    }
+    Cow::Owned(out)
+}
+
+/// Cell text ready to draw in a column `max_width` wide
+pub fn fit_cell(text: &str, max_width: usize) -> String {
+    truncate_to_width(&sanitize(text), max_width).into_owned()
+}
+
+/// Cell text wrapped onto at most `max_lines` lines of `max_width` columns. Line breaks in the
+/// text start a new line, long lines break after the last space that fits (mid-word when there is
+/// none), and text past the last line is cut with an ellipsis.
+pub fn wrap_cell(text: &str, max_width: usize, max_lines: usize) -> Vec<String> {
+    if max_width == 0 || max_lines <= 1 {
+        return vec![fit_cell(text, max_width)];
+    }
+    let mut lines = Vec::new();
+    for paragraph in text.split('\n') {
+        let paragraph = sanitize(paragraph.strip_suffix('\r').unwrap_or(paragraph));
+        let mut line = String::new();
+        let mut width = 0;
+        // Byte offset just past the last space in `line`, with the width up to it
+        let mut last_space: Option<(usize, usize)> = None;
+        for grapheme in paragraph.graphemes(true) {
+            let grapheme_width = grapheme.width();
+            let is_space = grapheme.chars().all(char::is_whitespace);
+            if width + grapheme_width > max_width && !line.is_empty() {
+                match last_space {
+                    Some((at, at_width)) if at < line.len() && !is_space => {
+                        let rest = line.split_off(at);
+                        lines.push(line.trim_end().to_string());
+                        line = rest;
+                        width -= at_width;
+                    }
+                    _ => {
+                        lines.push(line.trim_end().to_string());
+                        line.clear();
+                        width = 0;
+                    }
+                }
+                last_space = None;
+                if is_space && line.is_empty() {
+                    continue;
+                }
+            }
+            line.push_str(grapheme);
+            width += grapheme_width;
+            if is_space {
+                last_space = Some((line.len(), width));
+            }
+        }
+        lines.push(line);
+    }
+    if lines.len() > max_lines {
+        lines.truncate(max_lines);
+        if let Some(last) = lines.last_mut() {
+            let budget = max_width.saturating_sub(ELLIPSIS.width());
+            let mut kept = String::new();
+            let mut used = 0;
+            for grapheme in last.graphemes(true) {
+                if used + grapheme.width() > budget {
+                    break;
+                }
+                kept.push_str(grapheme);
+                used += grapheme.width();
+            }
+            *last = format!("{}{ELLIPSIS}", kept.trim_end());
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mixed_width_content() {
+        assert_eq!(display_width("abc"), 3);
+        assert_eq!(display_width("日本語"), 6);
+        assert_eq!(display_width("🦀 ok"), 5);
+        // e + combining acute accent is one column
+        assert_eq!(display_width("cafe\u{301}"), 4);
+        assert_eq!(display_width("a\tb\r\nc\u{7}"), 5);
+        assert_eq!(sanitize("a\tb\r\nc\u{7}"), "a b⏎c");
+
+        assert_eq!(truncate_to_width("short", 5), "short");
+        assert_eq!(truncate_to_width("overflow", 5), "over…");
+        // A wide character that does not fit whole is dropped, not split
+        assert_eq!(truncate_to_width("日本語", 5), "日本…");
+        assert_eq!(truncate_to_width("日本語", 4), "日…");
+        assert_eq!(truncate_to_width("cafe\u{301}s", 5), "cafe\u{301}s");
+        assert_eq!(truncate_to_width("cafe\u{301}s!", 5), "cafe\u{301}…");
+        assert_eq!(truncate_to_width("abc", 0), "");
+        for text in ["日本語テキスト", "🦀🦀🦀🦀", "mixed 日本 text", "e\u{301}e\u{301}e\u{301}e\u{301}"] {
+            for width in 0..12 {
+                assert!(display_width(&fit_cell(text, width)) <= width, "{text:?} at {width}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_wrap_cell() {
+        assert_eq!(wrap_cell("short", 10, 3), vec!["short"]);
+        assert_eq!(wrap_cell("the quick brown fox", 10, 3), vec!["the quick", "brown fox"]);
+        assert_eq!(wrap_cell("abcdefghijkl", 5, 3), vec!["abcde", "fghij", "kl"]);
+        assert_eq!(wrap_cell("line one\r\nline two", 10, 3), vec!["line one", "line two"]);
+        assert_eq!(wrap_cell("a b c d e f g h", 3, 2), vec!["a b", "c…"]);
+        assert_eq!(wrap_cell("日本語テキスト", 5, 3), vec!["日本", "語テ", "キス…"]);
+        assert_eq!(wrap_cell("anything", 4, 1), vec!["any…"]);
+        for text in ["日本語テキスト", "mixed 日本 text that wraps", "e\u{301}e\u{301} e\u{301}e\u{301}"] {
+            // Two columns fit any grapheme
+            for width in 2..12 {
+                assert!(wrap_cell(text, width, 3).iter().all(|l| display_width(l) <= width), "{text:?} at {width}");
+            }
+        }
+    }
+}
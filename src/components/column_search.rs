@@ -0,0 +1,90 @@
+//! Column search: type part of a column name to jump to the best match
+//!
+//! Used by the go to column dialog and, through `ColumnSearch`, by the column pickers of the
+//! sort, filter and column width dialogs.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+use crate::recent_files::fuzzy_score;
+
+/// Indices of the columns matching `query`, best match first; ties keep column order
+pub fn rank_columns(query: &str, columns: &[String]) -> Vec<usize> {
+    let mut scored: Vec<(i64, usize)> = columns
+        .iter()
+        .enumerate()
+        .filter_map(|(i, column)| fuzzy_score(query, column).map(|s| (s, i)))
+        .collect();
+    scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    scored.into_iter().map(|(_, i)| i).collect()
+}
+
+/// Type-to-jump state for a column list
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ColumnSearch {
+    /// The query while searching
+    query: Option<String>,
+}
+
+impl ColumnSearch {
+    pub fn is_active(&self) -> bool {
+        self.query.is_some()
+    }
+
+    pub fn start(&mut self) {
+        self.query = Some(String::new());
+    }
+
+    /// Shown in place of the list title while searching
+    pub fn title(&self) -> Option<String> {
+        self.query.as_ref().map(|q| format!("Go to column: {q}_"))
+    }
+
+    /// Handle a key while searching; every key is consumed. Typing and Backspace edit the query
+    /// and return the best matching column to jump to; Enter and Esc end the search.
+    pub fn handle_key(&mut self, key: KeyEvent, columns: &[String]) -> Option<usize> {
+        let query = self.query.as_mut()?;
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        match key.code {
+            KeyCode::Enter | KeyCode::Esc => {
+                self.query = None;
+                return None;
+            }
+            KeyCode::Backspace => {
+                query.pop();
+            }
+            KeyCode::Char(c) if !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => query.push(c),
+            _ => return None,
+        }
+        if query.is_empty() {
+            return None;
+        }
+        rank_columns(query, columns).first().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rank_and_search_columns() {
+        let columns: Vec<String> = ["event_id", "source_ip", "dest_ip", "timestamp"].iter().map(|s| s.to_string()).collect();
+        // Shorter names win ties, loose matches come last
+        assert_eq!(rank_columns("ip", &columns), vec![2, 1, 3]);
+        assert_eq!(rank_columns("dip", &columns), vec![2]);
+        assert!(rank_columns("zz", &columns).is_empty());
+
+        let mut search = ColumnSearch::default();
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+        assert_eq!(search.handle_key(key(KeyCode::Char('t')), &columns), None);
+        search.start();
+        assert_eq!(search.handle_key(key(KeyCode::Char('t')), &columns), Some(3));
+        assert_eq!(search.handle_key(key(KeyCode::Char('s')), &columns), Some(3));
+        assert_eq!(search.handle_key(key(KeyCode::Backspace), &columns), Some(3));
+        assert_eq!(search.title().as_deref(), Some("Go to column: t_"));
+        search.handle_key(key(KeyCode::Enter), &columns);
+        assert!(!search.is_active());
+    }
+}
@@ -6,14 +6,16 @@
 //! Extension points: custom cell rendering, sorting/filtering hooks, advanced navigation, etc.
 use crate::style::StyleConfig;
 use crate::components::Component;
-use crate::dataframe::manager::ManagedDataFrame;
+use crate::dataframe::find_index::CellMatcher;
+use crate::dataframe::manager::{ManagedDataFrame, ViewWindow};
 use crate::action::Action;
-use crate::config::Config;
+use crate::config::{Config, Mode};
 use crate::tui::Event;
 use std::sync::Arc;
 use color_eyre::Result;
 use crossterm::event::{KeyEvent, KeyEventKind, MouseEvent};
-use ratatui::widgets::{Table, Row, Cell, Block, Borders};
+use ratatui::widgets::{Table, Row, Cell, Block, Borders, BorderType, Clear, Paragraph, Widget};
+use ratatui::buffer::Buffer;
 use ratatui::prelude::{Frame, Rect, Size};
 use ratatui::layout::Constraint;
 use tokio::sync::mpsc::UnboundedSender;
@@ -21,8 +23,13 @@ use ratatui::style::{Modifier, Style, Color};
 use polars::prelude::DataFrame;
 use crate::dialog::find_dialog::{FindOptions, SearchMode};
 use crate::dialog::column_width_dialog::ColumnWidthConfig;
-use crate::dialog::styling::{StyleSet, matches_column, MergeMode, StyleLogic, Condition, ApplicationScope, GrepCapture};
-use ratatui::text::{Line, Span};
+use crate::dialog::sort_dialog::sort_arrow;
+use crate::components::cell_text::{display_width, fit_cell, wrap_cell};
+use crate::dialog::styling::{StyleSet, matches_column, MergeMode, StyleLogic, Condition, ApplicationScope, GrepCapture, GradientStyle};
+use crate::dialog::heatmap_dialog::HeatmapConfig;
+use crate::dialog::display_settings_dialog::DisplaySettings;
+use crate::dialog::hex_viewer_dialog::parse_hex_string;
+use ratatui::text::{Line, Span, Text};
 use polars::prelude::{AnyValue};
 use regex::Regex;
 use serde_json::{Value, Number};
@@ -35,10 +42,46 @@ fn anyvalue_to_display_string(value: &AnyValue) -> String {
     match value {
         AnyValue::Null => "".to_string(),
         AnyValue::String(s) => s.to_string(),
+        AnyValue::StringOwned(s) => s.to_string(),
         other => format!("{other}"),
     }
 }
 
+/// Compute the (min, max) of the numeric values in a column, parsing strings as numbers.
+///
+/// Returns None when the column has no numeric values.
+fn column_numeric_bounds(col: &polars::prelude::Column) -> Option<(f64, f64)> {
+    let mut min = f64::MAX;
+    let mut max = f64::MIN;
+    for i in 0..col.len() {
+        if let Ok(val) = col.get(i) {
+            let num_val = match val {
+                AnyValue::Int8(v) => Some(v as f64),
+                AnyValue::Int16(v) => Some(v as f64),
+                AnyValue::Int32(v) => Some(v as f64),
+                AnyValue::Int64(v) => Some(v as f64),
+                AnyValue::UInt8(v) => Some(v as f64),
+                AnyValue::UInt16(v) => Some(v as f64),
+                AnyValue::UInt32(v) => Some(v as f64),
+                AnyValue::UInt64(v) => Some(v as f64),
+                AnyValue::Float32(v) => Some(v as f64),
+                AnyValue::Float64(v) => Some(v),
+                AnyValue::String(s) => s.parse::<f64>().ok(),
+                _ => None,
+            };
+            if let Some(v) = num_val {
+                if v < min { min = v; }
+                if v > max { max = v; }
+            }
+        }
+    }
+    if min != f64::MAX && max != f64::MIN {
+        Some((min, max))
+    } else {
+        None
+    }
+}
+
 /// Merge two styles according to the merge mode
 fn merge_styles(existing: Option<Style>, new_style: Style, mode: MergeMode) -> Option<Style> {
     match mode {
@@ -115,8 +158,8 @@ fn invert_color(color: Color) -> Color {
 /// Information about a RegexGroup style to apply to a cell
 #[derive(Debug, Clone)]
 struct RegexGroupStyle {
-    /// The regex pattern to match
-    pattern: String,
+    /// The compiled rule pattern
+    regex: Regex,
     /// Which capture group to style
     capture: GrepCapture,
     /// The style to apply to the matched group
@@ -143,12 +186,7 @@ fn apply_regex_group_styles(
     let mut styled_ranges: Vec<(usize, usize, Style)> = Vec::new();
     
     for regex_style in regex_styles {
-        let re = match Regex::new(&regex_style.pattern) {
-            Ok(r) => r,
-            Err(_) => continue,
-        };
-        
-        for caps in re.captures_iter(cell_str) {
+        for caps in regex_style.regex.captures_iter(cell_str) {
             // Get the capture group based on the GrepCapture specification
             let capture_match = match &regex_style.capture {
                 GrepCapture::Group(n) => caps.get(*n),
@@ -300,6 +338,12 @@ pub struct DataTable {
     pub last_area_width: u16,
     /// Enabled style sets for conditional styling
     pub style_sets: Vec<StyleSet>,
+    /// Configured keybindings (for the TableNavigation keymap)
+    pub config: Config,
+    /// Float the full content of the selected cell next to it when it is truncated
+    pub cell_peek: bool,
+    /// Keys typed so far towards a multi-key TableNavigation binding (vim's `gg`)
+    pending_keys: Vec<KeyEvent>,
 }
 
 impl DataTable {
@@ -307,6 +351,16 @@ impl DataTable {
     const MIN_COL_WIDTH: u16 = 4;
     /// Maximum column width in characters for display
     const MAX_COL_WIDTH: u16 = 255;
+    /// Widest a wrapped column grows to from its content; a manual width still takes precedence
+    const WRAPPED_COL_WIDTH: u16 = 40;
+    /// Display lines a wrapped cell takes at most
+    const MAX_WRAP_LINES: usize = 6;
+    /// Widest the quick-peek box grows to, borders excluded
+    const PEEK_WIDTH: usize = 60;
+    /// Lines of content the quick-peek box shows at most
+    const PEEK_LINES: usize = 10;
+    /// Rows sampled when sizing all columns at once (see `exact_column_widths` for a full scan)
+    pub const WIDTH_SAMPLE_ROWS: usize = 1000;
 
     /// Create a new DataTable for the given DataFrame and style
     pub fn new(dataframe: ManagedDataFrame, style: StyleConfig) -> Self {
@@ -318,6 +372,9 @@ impl DataTable {
             last_area_height: 0,
             last_area_width: 0,
             style_sets: Vec::new(),
+            config: Config::default(),
+            cell_peek: false,
+            pending_keys: Vec::new(),
         }
     }
 
@@ -346,14 +403,59 @@ impl DataTable {
         self.dataframe.set_current_df(df);
     }
 
+    /// Set the heatmap shading configuration
+    pub fn set_heatmap_config(&mut self, config: HeatmapConfig) {
+        self.dataframe.heatmap_config = config;
+    }
+
+    /// Set the display formatting settings (applied at render time only)
+    pub fn set_display_settings(&mut self, settings: DisplaySettings) {
+        self.dataframe.display_settings = settings;
+    }
+
+    /// Width of a cell's content, covering both the raw and the formatted rendering
+    fn cell_display_len(&self, column: &str, value: &AnyValue) -> usize {
+        let raw = display_width(&value.to_string());
+        let formatted = display_width(&self.dataframe.display_settings.format_value(column, value));
+        raw.max(formatted)
+    }
+
+    /// Min/max of the numeric values in a column, used as default heatmap bounds
+    pub fn column_numeric_bounds(&mut self, column: &str) -> Result<Option<(f64, f64)>> {
+        Ok(self.dataframe.column_bounds(column, column_numeric_bounds))
+    }
+
+    /// Get the selected row as (column, value) pairs for all visible columns.
+    ///
+    /// Returns an empty list if the selected row is out of bounds.
+    pub fn selected_row_record(&self) -> Result<Vec<(String, String)>> {
+        let visible_columns = self.get_visible_columns()?;
+        // Only the selected row is collected
+        let df = self.dataframe.collect_rows(self.selection.row, 1)?;
+        let row = 0;
+        if row >= df.height() {
+            return Ok(Vec::new());
+        }
+        let mut record = Vec::with_capacity(visible_columns.len());
+        for col_name in visible_columns {
+            let value = df
+                .column(&col_name)
+                .ok()
+                .and_then(|series| series.get(row).ok())
+                .map(|val| anyvalue_to_display_string(&val))
+                .unwrap_or_default();
+            record.push((col_name, value));
+        }
+        Ok(record)
+    }
+
     /// Get the value of the currently selected cell as a string.
     ///
     /// Returns an empty string if the selection is out of bounds or the value cannot be retrieved.
     pub fn selected_cell_value(&self) -> Result<String> {
         let visible_columns = self.get_visible_columns()?;
-        let df = self.get_dataframe()?;
-        let df = df.as_ref();
-        let row = self.selection.row;
+        let df = self.dataframe.collect_rows(self.selection.row, 1)?;
+        let row = 0;
         let col = self.selection.col;
 
         if row < df.height() && col < visible_columns.len() {
@@ -371,9 +473,8 @@ impl DataTable {
     /// Returns an empty string if the selection is out of bounds or the value cannot be retrieved.
     pub fn selected_cell_json_value(&self) -> Result<Value> {
         let visible_columns = self.get_visible_columns()?;
-        let df = self.get_dataframe()?;
-        let df = df.as_ref();
-        let row = self.selection.row;
+        let df = self.dataframe.collect_rows(self.selection.row, 1)?;
+        let row = 0;
         let col = self.selection.col;
         
         if row < df.height() && col < visible_columns.len() {
@@ -387,6 +488,29 @@ impl DataTable {
         Ok(Value::Null)
     }
 
+    /// Get the raw bytes of the selected cell for hex viewing.
+    ///
+    /// Binary values are returned as-is and hex-looking strings are decoded; anything else yields None.
+    pub fn selected_cell_bytes(&self) -> Result<Option<Vec<u8>>> {
+        let visible_columns = self.get_visible_columns()?;
+        let df = self.dataframe.collect_rows(self.selection.row, 1)?;
+        let row = 0;
+        let Some(col_name) = visible_columns.get(self.selection.col) else {
+            return Ok(None);
+        };
+        if row >= df.height() {
+            return Ok(None);
+        }
+        let bytes = match df.column(col_name).ok().and_then(|s| s.get(row).ok()) {
+            Some(AnyValue::Binary(b)) => Some(b.to_vec()),
+            Some(AnyValue::BinaryOwned(b)) => Some(b),
+            Some(AnyValue::String(s)) => parse_hex_string(s),
+            Some(AnyValue::StringOwned(s)) => parse_hex_string(s.as_str()),
+            _ => None,
+        };
+        Ok(bytes)
+    }
+
     /// Table border width (left + right borders for Borders::ALL)
     const TABLE_BORDER_WIDTH: u16 = 2;
     /// Table border height (top + bottom borders for Borders::ALL)
@@ -399,7 +523,7 @@ impl DataTable {
     /// Helper to determine visible columns and their widths given a col_start and area width
     fn visible_col_range(
         &self,
-        df: &polars::prelude::DataFrame,
+        window: &ViewWindow,
         columns: &[String],
         area_width: u16,
         row_start: usize,
@@ -426,32 +550,22 @@ impl DataTable {
                     // Auto-expand mode: calculate based on content
                     let mut max_len = Self::MIN_COL_WIDTH as usize;
                     for i in row_start..row_end {
-                        let val = df.column(col_name)
-                            .ok()
-                            .and_then(|s| s.get(i).ok())
-                            .map(|v| v.to_string())
-                            .unwrap_or_default();
-                        let cell_len = val.chars().count();
+                        let cell_len = self.cell_display_len(col_name, &window.get(col_name, i));
                         if cell_len > max_len {
                             max_len = cell_len;
                         }
                     }
-                    max_len.clamp(Self::MIN_COL_WIDTH as usize, Self::MAX_COL_WIDTH as usize) as u16
+                    self.cap_wrapped_width(col_name, max_len.clamp(Self::MIN_COL_WIDTH as usize, Self::MAX_COL_WIDTH as usize) as u16)
                 } else {
                     // Manual mode but no width set: fallback to auto-calculation
                     let mut max_len = Self::MIN_COL_WIDTH as usize;
                     for i in row_start..row_end {
-                        let val = df.column(col_name)
-                            .ok()
-                            .and_then(|s| s.get(i).ok())
-                            .map(|v| v.to_string())
-                            .unwrap_or_default();
-                        let cell_len = val.chars().count();
+                        let cell_len = self.cell_display_len(col_name, &window.get(col_name, i));
                         if cell_len > max_len {
                             max_len = cell_len;
                         }
                     }
-                    max_len.clamp(Self::MIN_COL_WIDTH as usize, Self::MAX_COL_WIDTH as usize) as u16
+                    self.cap_wrapped_width(col_name, max_len.clamp(Self::MIN_COL_WIDTH as usize, Self::MAX_COL_WIDTH as usize) as u16)
                 }
             };
             
@@ -486,6 +600,41 @@ impl DataTable {
         (col_start, col_end, col_widths)
     }
 
+    /// Number of leading visible columns that are pinned
+    fn pinned_count(&self, visible_columns: &[String]) -> usize {
+        let pinned = &self.dataframe.column_width_config.pinned_columns;
+        visible_columns.iter().take_while(|c| pinned.contains(c)).count()
+    }
+
+    /// Columns drawn from horizontal scroll position `scroll_x`, as indices into `columns` with
+    /// their widths: the pinned columns, then as many of the scrolled ones as fit
+    fn drawn_columns(
+        &self,
+        window: &ViewWindow,
+        columns: &[String],
+        area_width: u16,
+        row_start: usize,
+        row_end: usize,
+        scroll_x: usize,
+    ) -> (Vec<usize>, Vec<u16>) {
+        let pinned = self.pinned_count(columns);
+        let indices: Vec<usize> = (0..pinned).chain(scroll_x.max(pinned).min(columns.len())..columns.len()).collect();
+        let names: Vec<String> = indices.iter().map(|&i| columns[i].clone()).collect();
+        let (_, shown, col_widths) = self.visible_col_range(window, &names, area_width, row_start, row_end, 0);
+        (indices[..shown].to_vec(), col_widths)
+    }
+
+    /// Widths the columns are drawn at: the last column fills what the others leave
+    fn drawn_widths(col_widths: &[u16], table_width: u16) -> Vec<usize> {
+        let mut widths: Vec<usize> = col_widths.iter().map(|w| *w as usize).collect();
+        if let Some((last, others)) = widths.split_last_mut() {
+            let available = table_width.saturating_sub(Self::TABLE_BORDER_WIDTH) as usize;
+            let taken: usize = others.iter().map(|w| w + Self::COLUMN_SPACING as usize).sum();
+            *last = available.saturating_sub(taken).max(*last);
+        }
+        widths
+    }
+
     /// Ensure the current selection is visible within the viewport.
     /// 
     /// This method adjusts scroll.x and scroll.y so that the selected cell is
@@ -493,10 +642,8 @@ impl DataTable {
     /// 
     /// Returns true if any scroll adjustment was made.
     fn ensure_selection_visible(&mut self) -> Result<bool> {
-        let df = self.get_dataframe()?;
-        let df = df.as_ref();
         let visible_columns = self.get_visible_columns()?;
-        let nrows = df.height();
+        let nrows = self.dataframe.view_height()?;
         let ncols = visible_columns.len();
         
         if nrows == 0 || ncols == 0 {
@@ -529,6 +676,7 @@ impl DataTable {
         // Calculate which columns are currently visible (use same row range as draw())
         let row_start = self.scroll.y.min(nrows);
         let row_end = (row_start + max_visible_rows).min(nrows);
+        let window = self.display_window(row_start, row_end - row_start, &visible_columns)?;
         
         let sel_col = self.selection.col;
         
@@ -540,22 +688,24 @@ impl DataTable {
         // 2. Otherwise, check if selection would be fully visible from current scroll
         // 3. If not fully visible (or at edge with truncation), scroll right
         
-        if sel_col < self.scroll.x {
-            // Selection is to the left of the first visible column - scroll left
+        let pinned = self.pinned_count(&visible_columns);
+        if sel_col < pinned {
+            // Pinned columns are always on screen
+        } else if sel_col < self.scroll.x.max(pinned) {
+            // Selection is to the left of the first scrolled column - scroll left
             self.scroll.x = sel_col;
         } else {
             // Check if the selection is fully visible from current scroll position
-            let (col_start, col_end, col_widths) = self.visible_col_range(
-                df, &visible_columns, area_width, row_start, row_end, self.scroll.x
+            let (drawn, col_widths) = self.drawn_columns(
+                &window, &visible_columns, area_width, row_start, row_end, self.scroll.x
             );
             
             // Calculate if the selected column is fully visible
-            let is_fully_visible = if sel_col >= col_start && sel_col < col_end {
+            let is_fully_visible = if let Some(idx_in_widths) = drawn.iter().position(|&i| i == sel_col) {
                 // Column is in the visible range, but check if it has full width
-                let idx_in_widths = sel_col - col_start;
                 if let Some(&allocated_width) = col_widths.get(idx_in_widths) {
                     let desired_width = self.desired_column_width(
-                        df, &visible_columns, sel_col, row_start, row_end
+                        &window, &visible_columns, sel_col, row_start, row_end
                     );
                     // Consider fully visible if allocated width >= desired width,
                     // OR if the column is the only one and takes full width
@@ -575,15 +725,60 @@ impl DataTable {
                 self.scroll.x = sel_col;
             }
         }
+
+        // --- Wrapped rows ---
+        // Rows taller than one line fit fewer to a page; scroll down until the selected row's
+        // last line is on screen
+        if self.has_wrapped_columns(&visible_columns) && self.selection.row > self.scroll.y {
+            let row_start = self.scroll.y;
+            let row_end = self.selection.row + 1;
+            let window = self.display_window(row_start, row_end - row_start, &visible_columns)?;
+            let (drawn, col_widths) = self.drawn_columns(
+                &window, &visible_columns, area_width, row_start, row_end, self.scroll.x
+            );
+            let names: Vec<String> = drawn.iter().map(|&i| visible_columns[i].clone()).collect();
+            let heights = self.row_heights(&window, &names, &Self::drawn_widths(&col_widths, area_width), row_start, row_end);
+            let mut total: usize = heights.iter().map(|h| *h as usize).sum();
+            for height in heights.iter().take(heights.len() - 1) {
+                if total <= max_visible_rows {
+                    break;
+                }
+                total -= *height as usize;
+                self.scroll.y += 1;
+            }
+        }
         
         Ok(self.scroll != old_scroll)
     }
 
+    /// Rows `offset..offset + len` of the view for display. Hidden columns are left out of the
+    /// collected window unless a row-scoped style rule may need them.
+    fn display_window(&mut self, offset: usize, len: usize, visible_columns: &[String]) -> Result<ViewWindow> {
+        if self.has_row_scope_rules() {
+            self.dataframe.window(offset, len)
+        } else {
+            self.dataframe.projected_window(offset, len, Some(visible_columns))
+        }
+    }
+
+    /// Whether any style rule applies to whole rows, which is evaluated against every column
+    fn has_row_scope_rules(&self) -> bool {
+        self.style_sets.iter().any(|ss| {
+            ss.rules.iter().any(|r| match &r.logic {
+                StyleLogic::Conditional(cond) => cond
+                    .applications
+                    .iter()
+                    .any(|a| matches!(a.scope, ApplicationScope::Row)),
+                _ => false,
+            })
+        })
+    }
+
     /// Compute the desired width for a specific column index within `columns`,
     /// using the same logic as `visible_col_range` but for a single column.
     fn desired_column_width(
         &self,
-        df: &polars::prelude::DataFrame,
+        window: &ViewWindow,
         columns: &[String],
         col_index: usize,
         row_start: usize,
@@ -598,49 +793,127 @@ impl DataTable {
         }
         let mut max_len = Self::MIN_COL_WIDTH as usize;
         for i in row_start..row_end {
-            let val = df.column(col_name)
-                .ok()
-                .and_then(|s| s.get(i).ok())
-                .map(|v| v.to_string())
-                .unwrap_or_default();
-            let cell_len = val.chars().count();
+            let cell_len = self.cell_display_len(col_name, &window.get(col_name, i));
             if cell_len > max_len {
                 max_len = cell_len;
             }
         }
-        max_len
-            .clamp(Self::MIN_COL_WIDTH as usize, Self::MAX_COL_WIDTH as usize) as u16
+        self.cap_wrapped_width(col_name, max_len.clamp(Self::MIN_COL_WIDTH as usize, Self::MAX_COL_WIDTH as usize) as u16)
+    }
+
+    /// Content-based `width` of `column`, limited when the column wraps so long text flows onto
+    /// more lines instead of widening it
+    fn cap_wrapped_width(&self, column: &str, width: u16) -> u16 {
+        if self.dataframe.column_width_config.wrapped_columns.iter().any(|c| c == column) {
+            width.min(Self::WRAPPED_COL_WIDTH)
+        } else {
+            width
+        }
+    }
+
+    /// Display lines each row of `row_start..row_end` takes: the most lines any wrapped column
+    /// among `columns` (drawn at `widths`) needs for its cell, 1 when none wrap
+    fn row_heights(
+        &self,
+        window: &ViewWindow,
+        columns: &[String],
+        widths: &[usize],
+        row_start: usize,
+        row_end: usize,
+    ) -> Vec<u16> {
+        let wrapped = &self.dataframe.column_width_config.wrapped_columns;
+        let wrapped: Vec<(&String, usize)> = columns
+            .iter()
+            .zip(widths.iter().copied())
+            .filter(|(c, _)| wrapped.contains(c))
+            .collect();
+        (row_start..row_end)
+            .map(|i| {
+                wrapped
+                    .iter()
+                    .map(|(column, width)| {
+                        let text = self.dataframe.display_settings.format_value(column, &window.get(column, i));
+                        wrap_cell(&text, *width, Self::MAX_WRAP_LINES).len()
+                    })
+                    .max()
+                    .unwrap_or(1) as u16
+            })
+            .collect()
+    }
+
+    /// Whether any visible column wraps its text
+    fn has_wrapped_columns(&self, visible_columns: &[String]) -> bool {
+        let wrapped = &self.dataframe.column_width_config.wrapped_columns;
+        !wrapped.is_empty() && visible_columns.iter().any(|c| wrapped.contains(c))
+    }
+
+    /// Wrap the text of `column` onto several lines, or go back to one truncated line.
+    /// Returns whether the column now wraps.
+    pub fn toggle_wrapped_column(&mut self, column: &str) -> bool {
+        let wrapped = &mut self.dataframe.column_width_config.wrapped_columns;
+        if let Some(index) = wrapped.iter().position(|c| c == column) {
+            wrapped.remove(index);
+            false
+        } else {
+            wrapped.push(column.to_string());
+            true
+        }
     }
 
-    /// Get the calculated widths for all columns (for use when locking column widths)
+    /// Alternate the row background whenever the value of `column` changes, or go back to
+    /// alternating every other row when it already does. Returns whether rows are now grouped.
+    pub fn toggle_group_column(&mut self, column: &str) -> bool {
+        let group_column = &mut self.dataframe.column_width_config.group_column;
+        if group_column.as_deref() == Some(column) {
+            *group_column = None;
+            false
+        } else {
+            *group_column = Some(column.to_string());
+            true
+        }
+    }
+
+    /// Get the calculated widths for all columns (for use when locking column widths).
+    /// Manual widths are kept; other columns are sized from the first `WIDTH_SAMPLE_ROWS` rows.
     pub fn get_all_column_widths(&self) -> Result<std::collections::HashMap<String, u16>> {
-        let df = self.get_dataframe()?;
-        let columns: Vec<String> = df.get_column_names_owned()
+        let window = ViewWindow { offset: 0, df: Arc::new(self.dataframe.collect_rows(0, Self::WIDTH_SAMPLE_ROWS)?) };
+        let columns: Vec<String> = window.df.get_column_names_owned()
             .into_iter()
             .map(|s| s.to_string())
             .collect();
-        
-        let row_count = df.height();
-        // Sample rows for width calculation (similar to visible_col_range)
         let row_start = 0;
-        let row_end = row_count.min(100); // Sample first 100 rows for performance
+        let row_end = window.df.height();
         
         let mut widths = std::collections::HashMap::new();
         for (col_idx, col_name) in columns.iter().enumerate() {
-            let width = self.desired_column_width(&df, &columns, col_idx, row_start, row_end);
+            let width = self.desired_column_width(&window, &columns, col_idx, row_start, row_end);
             widths.insert(col_name.clone(), width);
         }
         
         Ok(widths)
     }
 
+    /// Widths that fit the header and every row of the view, ignoring manual widths. This
+    /// collects the whole view, so it is only run on request.
+    pub fn exact_column_widths(&self) -> Result<std::collections::HashMap<String, u16>> {
+        let window = ViewWindow { offset: 0, df: self.dataframe.get_dataframe()? };
+        let mut widths = std::collections::HashMap::new();
+        for name in window.df.get_column_names_owned() {
+            let mut max_len = display_width(&name);
+            for i in 0..window.df.height() {
+                max_len = max_len.max(self.cell_display_len(&name, &window.get(&name, i)));
+            }
+            let width = max_len.clamp(Self::MIN_COL_WIDTH as usize, Self::MAX_COL_WIDTH as usize) as u16;
+            widths.insert(name.to_string(), width);
+        }
+        Ok(widths)
+    }
+
     /// Get visible columns (excluding hidden ones)
     pub fn get_visible_columns(&self) -> Result<Vec<String>> {
-        let df = self.get_dataframe()?;
-
-        let all_columns: Vec<String> = df.get_column_names_owned()
+        let all_columns: Vec<String> = self.dataframe.column_types()
             .into_iter()
-            .map(|s| s.to_string())
+            .map(|(name, _)| name)
             .collect();
 
         Ok(all_columns.into_iter()
@@ -652,9 +925,38 @@ impl DataTable {
             .collect())
     }
     
+    /// Pin or unpin `column`. Pinned columns are moved to the front, in the order they were
+    /// pinned; an unpinned column follows them. Returns whether the column is now pinned.
+    pub fn toggle_pinned_column(&mut self, column: &str) -> Result<bool> {
+        let pinned = &mut self.dataframe.column_width_config.pinned_columns;
+        let now_pinned = match pinned.iter().position(|c| c == column) {
+            Some(i) => {
+                pinned.remove(i);
+                false
+            }
+            None => {
+                pinned.push(column.to_string());
+                true
+            }
+        };
+        let names: Vec<String> = self.dataframe.column_types().into_iter().map(|(name, _)| name).collect();
+        let pinned = &self.dataframe.column_width_config.pinned_columns;
+        let order: Vec<String> = pinned
+            .iter()
+            .filter(|c| names.contains(c))
+            .chain(names.iter().filter(|c| !pinned.contains(c)))
+            .cloned()
+            .collect();
+        self.dataframe.reorder_columns(&order)?;
+        Ok(now_pinned)
+    }
+
     /// Compute min/max bounds for gradient columns in active style rules
-    fn compute_gradient_bounds(&self, df: &DataFrame, visible_columns: &[String]) -> BTreeMap<String, (f64, f64)> {
+    fn compute_gradient_bounds(&mut self, visible_columns: &[String]) -> BTreeMap<String, (f64, f64)> {
         let mut bounds: BTreeMap<String, (f64, f64)> = BTreeMap::new();
+        let column_types: BTreeMap<String, polars::datatypes::DataType> =
+            self.dataframe.column_types().into_iter().collect();
+        let mut sources: Vec<String> = Vec::new();
         
         // Collect all gradient source columns that don't have explicit bounds
         for style_set in &self.style_sets {
@@ -669,48 +971,27 @@ impl DataTable {
                     if bounds.contains_key(&gradient.source_column) {
                         continue;
                     }
-                    // Compute bounds from data
-                    if let Ok(col) = df.column(&gradient.source_column) {
-                        let mut min = f64::MAX;
-                        let mut max = f64::MIN;
-                        for i in 0..col.len() {
-                            if let Ok(val) = col.get(i) {
-                                let num_val = match val {
-                                    AnyValue::Int8(v) => Some(v as f64),
-                                    AnyValue::Int16(v) => Some(v as f64),
-                                    AnyValue::Int32(v) => Some(v as f64),
-                                    AnyValue::Int64(v) => Some(v as f64),
-                                    AnyValue::UInt8(v) => Some(v as f64),
-                                    AnyValue::UInt16(v) => Some(v as f64),
-                                    AnyValue::UInt32(v) => Some(v as f64),
-                                    AnyValue::UInt64(v) => Some(v as f64),
-                                    AnyValue::Float32(v) => Some(v as f64),
-                                    AnyValue::Float64(v) => Some(v),
-                                    AnyValue::String(s) => s.parse::<f64>().ok(),
-                                    _ => None,
-                                };
-                                if let Some(v) = num_val {
-                                    if v < min { min = v; }
-                                    if v > max { max = v; }
-                                }
-                            }
-                        }
-                        if min != f64::MAX && max != f64::MIN {
-                            bounds.insert(gradient.source_column.clone(), (min, max));
-                        }
+                    if !sources.contains(&gradient.source_column) {
+                        sources.push(gradient.source_column.clone());
                     }
                 }
             }
         }
+        // Compute bounds from data
+        for source in sources {
+            if column_types.contains_key(&source)
+                && let Some(b) = self.dataframe.column_bounds(&source, column_numeric_bounds) {
+                    bounds.insert(source, b);
+                }
+        }
         
         // Also compute bounds for visible numeric columns that might be used
         for col_name in visible_columns {
             if bounds.contains_key(col_name) {
                 continue;
             }
-            if let Ok(col) = df.column(col_name) {
+            if let Some(dtype) = column_types.get(col_name) {
                 // Only compute if it's a numeric type
-                let dtype = col.dtype();
                 let is_numeric = matches!(dtype,
                     polars::datatypes::DataType::Int8 |
                     polars::datatypes::DataType::Int16 |
@@ -723,34 +1004,11 @@ impl DataTable {
                     polars::datatypes::DataType::Float32 |
                     polars::datatypes::DataType::Float64
                 );
-                if is_numeric {
-                    let mut min = f64::MAX;
-                    let mut max = f64::MIN;
-                    for i in 0..col.len() {
-                        if let Ok(val) = col.get(i) {
-                            let num_val = match val {
-                                AnyValue::Int8(v) => Some(v as f64),
-                                AnyValue::Int16(v) => Some(v as f64),
-                                AnyValue::Int32(v) => Some(v as f64),
-                                AnyValue::Int64(v) => Some(v as f64),
-                                AnyValue::UInt8(v) => Some(v as f64),
-                                AnyValue::UInt16(v) => Some(v as f64),
-                                AnyValue::UInt32(v) => Some(v as f64),
-                                AnyValue::UInt64(v) => Some(v as f64),
-                                AnyValue::Float32(v) => Some(v as f64),
-                                AnyValue::Float64(v) => Some(v),
-                                _ => None,
-                            };
-                            if let Some(v) = num_val {
-                                if v < min { min = v; }
-                                if v > max { max = v; }
-                            }
-                        }
-                    }
-                    if min != f64::MAX && max != f64::MIN {
-                        bounds.insert(col_name.clone(), (min, max));
+                // Heatmap columns may hold numbers as strings, so include them regardless of dtype
+                if (is_numeric || self.dataframe.heatmap_config.is_enabled(col_name))
+                    && let Some(b) = self.dataframe.column_bounds(col_name, column_numeric_bounds) {
+                        bounds.insert(col_name.clone(), b);
                     }
-                }
             }
         }
         
@@ -780,13 +1038,16 @@ impl DataTable {
 
     /// Search for the next cell matching the pattern, using options and search_mode.
     /// Returns Ok(Some((row, col))) if found, Ok(None) if not, or Err if error.
-    pub fn find_next(&self, pattern: &str, options: &FindOptions, search_mode: &SearchMode) -> color_eyre::Result<Option<(usize, usize)>> {
+    pub fn find_next(&mut self, pattern: &str, options: &FindOptions, search_mode: &SearchMode) -> color_eyre::Result<Option<(usize, usize)>> {
         let visible_columns = self.get_visible_columns()?;
-        let df = self.get_dataframe()?;
-        let df = df.as_ref();
-        let nrows = df.height();
         let ncols = visible_columns.len();
-        if nrows == 0 || ncols == 0 || pattern.is_empty() {
+        if ncols == 0 || pattern.is_empty() {
+            return Ok(None);
+        }
+        let matcher = CellMatcher::new(pattern, options, search_mode)?;
+        let columns = self.dataframe.find_index(&visible_columns)?;
+        let nrows = columns[0].len();
+        if nrows == 0 {
             return Ok(None);
         }
         let start_row = self.selection.row;
@@ -854,103 +1115,18 @@ impl DataTable {
         }
         // Remove the current cell from search (start after/before selection)
         if !indices.is_empty() { indices.remove(0); }
-        // Prepare matcher
-        let matcher: Box<dyn Fn(&str) -> bool> = match search_mode {
-            SearchMode::Normal => {
-                let pat = if options.match_case { pattern.to_string() } else { pattern.to_lowercase() };
-                let whole_word = options.whole_word;
-                Box::new(move |cell: &str| {
-                    let cell_cmp = if options.match_case { cell.to_string() } else { cell.to_lowercase() };
-                    if whole_word {
-                        cell_cmp == pat
-                    } else {
-                        cell_cmp.contains(&pat)
-                    }
-                })
-            }
-            SearchMode::Regex => {
-                let re = if options.match_case {
-                    Regex::new(pattern)
-                } else {
-                    Regex::new(&format!("(?i){pattern}"))
-                }?;
-                let whole_word = options.whole_word;
-                Box::new(move |cell: &str| {
-                    if whole_word {
-                        re.find(cell).is_some_and(|m| m.as_str() == cell)
-                    } else {
-                        re.is_match(cell)
-                    }
-                })
-            }
-        };
-        // Search
-        for (row, col) in indices {
-            let col_name = &visible_columns[col];
-            if let Ok(series) = df.column(col_name)
-                && let Ok(val) = series.get(row) {
-                    let cell_str = val.str_value();
-                    if matcher(&cell_str) {
-                        return Ok(Some((row, col)));
-                    }
-                }
-        }
-        Ok(None)
+        Ok(indices.into_iter().find(|&(row, col)| matcher.matches(&columns[col], row)))
     }
 
     /// Count the number of matches for the given pattern, options, and search mode in the visible DataFrame.
-    pub fn count_matches(&self, pattern: &str, options: &FindOptions, search_mode: &SearchMode) -> color_eyre::Result<usize> {
+    pub fn count_matches(&mut self, pattern: &str, options: &FindOptions, search_mode: &SearchMode) -> color_eyre::Result<usize> {
         let visible_columns = self.get_visible_columns()?;
-        let df = self.get_dataframe()?;
-        let df = df.as_ref();
-        let nrows = df.height();
-        let ncols = visible_columns.len();
-        if nrows == 0 || ncols == 0 || pattern.is_empty() {
+        if visible_columns.is_empty() || pattern.is_empty() {
             return Ok(0);
         }
-        // Prepare matcher
-        let matcher: Box<dyn Fn(&str) -> bool> = match search_mode {
-            SearchMode::Normal => {
-                let pat = if options.match_case { pattern.to_string() } else { pattern.to_lowercase() };
-                let whole_word = options.whole_word;
-                Box::new(move |cell: &str| {
-                    let cell_cmp = if options.match_case { cell.to_string() } else { cell.to_lowercase() };
-                    if whole_word {
-                        cell_cmp == pat
-                    } else {
-                        cell_cmp.contains(&pat)
-                    }
-                })
-            }
-            SearchMode::Regex => {
-                let re = if options.match_case {
-                    Regex::new(pattern)
-                } else {
-                    Regex::new(&format!("(?i){pattern}"))
-                }?;
-                let whole_word = options.whole_word;
-                Box::new(move |cell: &str| {
-                    if whole_word {
-                        re.find(cell).is_some_and(|m| m.as_str() == cell)
-                    } else {
-                        re.is_match(cell)
-                    }
-                })
-            }
-        };
-        let mut count = 0;
-        for row in 0..nrows {
-            for col_name in visible_columns.iter() {
-                if let Ok(series) = df.column(col_name)
-                    && let Ok(val) = series.get(row) {
-                        let cell_str = val.str_value();
-                        if matcher(&cell_str) {
-                            count += 1;
-                        }
-                    }
-            }
-        }
-        Ok(count)
+        let matcher = CellMatcher::new(pattern, options, search_mode)?;
+        let columns = self.dataframe.find_index(&visible_columns)?;
+        Ok(columns.iter().map(|column| matcher.matching_rows(column).len()).sum())
     }
 
     /// Scrolls the table so that the selected cell is visible.
@@ -959,121 +1135,75 @@ impl DataTable {
         Ok(())
     }
 
+    /// Selects `row` of the view (clamped) and scrolls it to the top of the table, or as near
+    /// as the remaining rows allow.
+    pub fn go_to_row(&mut self, row: usize) -> Result<()> {
+        let nrows = self.dataframe.view_height()?;
+        let page_height = if self.last_area_height > 0 {
+            self.last_area_height
+                .saturating_sub(Self::HEADER_HEIGHT + Self::TABLE_BORDER_HEIGHT) as usize
+        } else {
+            10 // fallback default
+        };
+        self.selection.row = row.min(nrows.saturating_sub(1));
+        self.scroll.y = self.selection.row.min(nrows.saturating_sub(page_height));
+        self.ensure_selection_visible()?;
+        Ok(())
+    }
+
     /// Find all matches for the given pattern, options, and search mode in the visible DataFrame.
     /// Returns a vector of FindAllResult with row, column, and context around each match.
     pub fn find_all_matches(
-        &self, pattern: &str, options: &FindOptions, search_mode: &SearchMode,
+        &mut self, pattern: &str, options: &FindOptions, search_mode: &SearchMode,
         context_chars: usize
     ) -> color_eyre::Result<Vec<crate::dialog::find_all_results_dialog::FindAllResult>> {
-        let df = self.get_dataframe()?;
-        let df = df.as_ref();
         let visible_columns = self.get_visible_columns()?;
-        let nrows = df.height();
-        let ncols = visible_columns.len();
-        if nrows == 0 || ncols == 0 || pattern.is_empty() {
+        if visible_columns.is_empty() || pattern.is_empty() {
             return Ok(Vec::new());
         }
-        // Prepare matcher (same logic as find_next/count_matches)
-        let matcher: Box<dyn Fn(&str) -> bool> = match search_mode {
-            SearchMode::Normal => {
-                let pat = if options.match_case { pattern.to_string() } else { pattern.to_lowercase() };
-                let whole_word = options.whole_word;
-                Box::new(move |cell: &str| {
-                    let cell_cmp = if options.match_case { cell.to_string() } else { cell.to_lowercase() };
-                    if whole_word {
-                        cell_cmp == pat
-                    } else {
-                        cell_cmp.contains(&pat)
-                    }
-                })
-            }
-            SearchMode::Regex => {
-                let re = if options.match_case {
-                    Regex::new(pattern)
-                } else {
-                    Regex::new(&format!("(?i){pattern}"))
-                }?;
-                let whole_word = options.whole_word;
-                Box::new(move |cell: &str| {
-                    if whole_word {
-                        re.find(cell).is_some_and(|m| m.as_str() == cell)
-                    } else {
-                        re.is_match(cell)
-                    }
-                })
-            }
-        };
-        let mut results = Vec::new();
-        // Search through all cells in the DataFrame
-        for row in 0..nrows {
-            for col_name in visible_columns.iter() {
-                if let Ok(series) = df.column(col_name)
-                    && let Ok(val) = series.get(row) {
-                        let cell_str = val.str_value();
-                        if matcher(&cell_str) {
-                            // Generate context around the match
-                            let context = self.generate_context(&cell_str, pattern, context_chars, search_mode, options);
-                            results.push(crate::dialog::find_all_results_dialog::FindAllResult {
-                                row,
-                                column: col_name.clone(),
-                                context,
-                            });
-                        }
-                    }
-            }
-        }
-        Ok(results)
+        let matcher = CellMatcher::new(pattern, options, search_mode)?;
+        let columns = self.dataframe.find_index(&visible_columns)?;
+        // Matches in row order, then column order within a row
+        let mut matches: Vec<(usize, usize)> = columns
+            .iter()
+            .enumerate()
+            .flat_map(|(col, column)| matcher.matching_rows(column).into_iter().map(move |row| (row, col)))
+            .collect();
+        matches.sort_unstable();
+        Ok(matches
+            .into_iter()
+            .map(|(row, col)| crate::dialog::find_all_results_dialog::FindAllResult {
+                row,
+                column: visible_columns[col].clone(),
+                context: self.generate_context(&columns[col].text[row], &matcher, context_chars),
+            })
+            .collect())
     }
 
     /// Generate context around a match in a cell string.
     /// Returns a string with context_chars characters before and after the match.
-    fn generate_context(&self, cell_str: &str, pattern: &str, context_chars: usize, search_mode: &SearchMode, options: &FindOptions) -> String {
-        // Find the position of the match in the cell string
-        let match_pos = match search_mode {
-            SearchMode::Normal => {
-                let search_text = if options.match_case { pattern } else { &pattern.to_lowercase() };
-                let cell_text = if options.match_case { cell_str } else { &cell_str.to_lowercase() };
-                if options.whole_word {
-                    // For whole word, find the exact word boundary
-                    cell_text.find(search_text)
-                } else {
-                    // For substring, find any occurrence
-                    cell_text.find(search_text)
-                }
-            }
-            SearchMode::Regex => {
-                let re = if options.match_case {
-                    Regex::new(pattern)
-                } else {
-                    Regex::new(&format!("(?i){pattern}"))
-                };
-                if let Ok(re) = re {
-                    re.find(cell_str).map(|m| m.start())
-                } else {
-                    None
-                }
-            }
-        };
-        
-        if let Some(pos) = match_pos {
-            // Calculate start and end positions for context
-            let start = pos.saturating_sub(context_chars);
-            let end = (pos + pattern.len() + context_chars).min(cell_str.len());
-            
-            // Extract context with ellipsis if needed
-            let mut context = String::new();
-            if start > 0 {
-                context.push_str("...");
-            }
-            context.push_str(&cell_str[start..end]);
-            if end < cell_str.len() {
-                context.push_str("...");
-            }
-            context
-        } else {
+    fn generate_context(&self, cell_str: &str, matcher: &CellMatcher, context_chars: usize) -> String {
+        let Some(found) = matcher.find(cell_str) else {
             // Fallback: return the full cell string if match position cannot be determined
-            cell_str.to_string()
+            return cell_str.to_string();
+        };
+        let before: Vec<(usize, char)> = cell_str[..found.start].char_indices().collect();
+        let start = before.len().checked_sub(context_chars).map_or(0, |i| before[i].0);
+        let end = cell_str[found.end..]
+            .char_indices()
+            .nth(context_chars)
+            .map_or(cell_str.len(), |(i, _)| found.end + i);
+
+        // Extract context with ellipsis if needed
+        let mut context = String::new();
+        if start > 0 {
+            context.push_str("...");
         }
+        context.push_str(&cell_str[start..end]);
+        if end < cell_str.len() {
+            context.push_str("...");
+        }
+        context
     }
 }
 
@@ -1086,7 +1216,8 @@ impl Component for DataTable {
 
     /// Register a configuration handler.
     fn register_config_handler(&mut self, config: Config) -> Result<()> {
-        let _ = config;
+        self.style = config.style_config.clone();
+        self.config = config;
         Ok(())
     }
 
@@ -1110,9 +1241,7 @@ impl Component for DataTable {
     fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
         use crossterm::event::{KeyCode, KeyModifiers};
         
-        let df = self.get_dataframe()?;
-        let df = df.as_ref();
-        let nrows = df.height();
+        let nrows = self.dataframe.view_height()?;
         let visible_columns = self.get_visible_columns()?;
         let ncols = visible_columns.len();
         
@@ -1134,12 +1263,32 @@ impl Component for DataTable {
         let area_width = self.last_area_width;
         let row_start = self.scroll.y.min(nrows);
         let row_end = (row_start + page_height).min(nrows).max(row_start + 1);
-        let (col_start, col_end, _) = self.visible_col_range(
-            df, &visible_columns, area_width, row_start, row_end, self.scroll.x
+        let window = self.display_window(row_start, row_end - row_start, &visible_columns)?;
+        let (drawn, _) = self.drawn_columns(
+            &window, &visible_columns, area_width, row_start, row_end, self.scroll.x
         );
-        let visible_col_count = col_end.saturating_sub(col_start).max(1);
+        let visible_col_count = drawn.len().saturating_sub(self.pinned_count(&visible_columns)).max(1);
         
-        if key.kind == KeyEventKind::Press {
+        if let Some(action) = self.config.action_for_key_sequence(Mode::TableNavigation, &mut self.pending_keys, key) {
+            let last_row = nrows.saturating_sub(1);
+            let last_col = ncols.saturating_sub(1);
+            match action {
+                Action::Up => self.selection.row = self.selection.row.saturating_sub(1),
+                Action::Down => self.selection.row = (self.selection.row + 1).min(last_row),
+                Action::Left => self.selection.col = self.selection.col.saturating_sub(1),
+                Action::Right => self.selection.col = (self.selection.col + 1).min(last_col),
+                Action::PageUp => self.selection.row = self.selection.row.saturating_sub(page_height),
+                Action::PageDown => self.selection.row = (self.selection.row + page_height).min(last_row),
+                Action::PageLeft => self.selection.col = self.selection.col.saturating_sub(visible_col_count),
+                Action::PageRight => self.selection.col = (self.selection.col + visible_col_count).min(last_col),
+                Action::GoToFirst => self.selection.row = 0,
+                Action::GoToLast => self.selection.row = last_row,
+                Action::GoToFirstColumn => self.selection.col = 0,
+                Action::GoToLastColumn => self.selection.col = last_col,
+                _ => {}
+            }
+        } else if key.kind == KeyEventKind::Press && self.pending_keys.is_empty() {
+            // Built-in arrow/Home/End handling, unless the key started a multi-key binding
             match key.code {
                 // --- Vertical navigation ---
                 KeyCode::Up => {
@@ -1216,10 +1365,16 @@ impl Component for DataTable {
 
     /// Render the component on the screen.
     fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
-        let df = self.get_dataframe()?;
-        let df = df.as_ref();
+        self.render(area, frame.buffer_mut())
+    }
+}
+
+impl DataTable {
+    /// Render the table into `buf`. Apps embedding the table can call this directly, or render
+    /// `&mut DataTable` as a ratatui widget.
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) -> Result<()> {
         let visible_columns = self.get_visible_columns()?;
-        let total_rows = df.height();
+        let total_rows = self.dataframe.view_height()?;
         let total_cols = visible_columns.len();
         
         // Calculate visible rows: area height minus header and borders
@@ -1255,12 +1410,25 @@ impl Component for DataTable {
         let row_start = self.scroll.y.min(total_rows);
         let row_end = (row_start + max_visible_rows).min(total_rows);
         let col_start = self.scroll.x.min(total_cols);
+        // Only the rows on screen (plus a margin for lazy views) are collected
+        let window = self.display_window(row_start, row_end - row_start, &visible_columns)?;
         
         // Use table_area.width (actual available width after scroll bar)
-        let (col_start, col_end, col_widths) = self.visible_col_range(
-            df, &visible_columns, table_area.width, row_start, row_end, col_start
+        let (drawn, col_widths) = self.drawn_columns(
+            &window, &visible_columns, table_area.width, row_start, row_end, col_start
         );
-        let visible_columns_slice = &visible_columns[col_start..col_end];
+        let drawn_columns: Vec<String> = drawn.iter().map(|&i| visible_columns[i].clone()).collect();
+        let visible_columns_slice = drawn_columns.as_slice();
+        let drawn_widths = Self::drawn_widths(&col_widths, table_area.width);
+
+        // Wrapped rows take several lines, so fewer of them fit
+        let row_heights = self.row_heights(&window, visible_columns_slice, &drawn_widths, row_start, row_end);
+        let mut used_lines = 0usize;
+        let fitting = row_heights.iter().take_while(|h| {
+            used_lines += **h as usize;
+            used_lines <= max_visible_rows
+        }).count().max(1);
+        let row_end = (row_start + fitting).min(row_end);
         
         // Draw vertical scroll bar if needed
         if needs_vertical_scroll {
@@ -1298,7 +1466,7 @@ impl Component for DataTable {
             
             // Draw scroll bar track
             for y in scroll_bar_area.y..scroll_bar_area.bottom() {
-                frame.buffer_mut().set_string(
+                buf.set_string(
                     scroll_bar_area.x,
                     y,
                     "│",
@@ -1342,7 +1510,7 @@ impl Component for DataTable {
                     Style::default().fg(ratatui::style::Color::DarkGray)
                 };
                 
-                frame.buffer_mut().set_string(
+                buf.set_string(
                     scroll_bar_area.x,
                     y,
                     char_to_use,
@@ -1354,29 +1522,17 @@ impl Component for DataTable {
         // Only build visible rows (avoid full materialization)
         let mut visible_rows: Vec<Vec<AnyValue>> = Vec::with_capacity(row_end - row_start);
         for i in row_start..row_end {
-            let mut row: Vec<AnyValue> = Vec::with_capacity(col_end - col_start);
+            let mut row: Vec<AnyValue> = Vec::with_capacity(visible_columns_slice.len());
             for col in visible_columns_slice {
-                let any_val = df.column(col)
-                    .ok()
-                    .and_then(|s| s.get(i).ok())
-                    .unwrap_or_else(|| AnyValue::Null);
-                row.push(any_val);
+                row.push(window.get(col, i));
             }
             visible_rows.push(row);
         }
 
         // Determine if any rules need full row (all columns) data for evaluation
-        let has_row_scope_rules = self.style_sets.iter().any(|ss| {
-            ss.rules.iter().any(|r| match &r.logic {
-                StyleLogic::Conditional(cond) => cond
-                    .applications
-                    .iter()
-                    .any(|a| matches!(a.scope, ApplicationScope::Row)),
-                _ => false,
-            })
-        });
+        let has_row_scope_rules = self.has_row_scope_rules();
         let all_columns: Vec<String> = if has_row_scope_rules {
-            df.get_column_names().iter().map(|s| s.to_string()).collect()
+            window.df.get_column_names().iter().map(|s| s.to_string()).collect()
         } else {
             Vec::new()
         };
@@ -1385,28 +1541,48 @@ impl Component for DataTable {
         let header = Row::new(
             visible_columns_slice
                 .iter()
-                .map(|c| {
+                .enumerate()
+                .map(|(j, c)| {
                     let col_name = c;
                     let mut label = col_name.clone();
                     if let Some(ref sort_cols) = self.dataframe.last_sort
                         && let Some((sort_idx, sort_col)) = sort_cols.iter()
                                 .enumerate()
                                 .find(|(_, sc)| sc.name == col_name.clone()) {
-                            let arrow = if sort_col.ascending { "↑" } else { "↓" };
+                            let arrow = sort_arrow(sort_col.ascending);
+                            // Priority numbers only matter for multi-column sorts
                             let prefix = if sort_cols.len() > 1 {
-                                format!("{}[{}] ", arrow, sort_idx+1)
+                                format!("{}{} ", arrow, sort_idx+1)
                             } else {
                                 format!("{arrow} ")
                             };
                             label = format!("{prefix}{label}");
                         }
-                    Cell::from(label).style(self.style.table_header)
+                    Cell::from(fit_cell(&label, drawn_widths[j])).style(self.style.table_header)
                 })
         );
 
         // Pre-compute gradient bounds for columns that need it
-        let gradient_bounds: BTreeMap<String, (f64, f64)> = self.compute_gradient_bounds(df, &visible_columns_slice);
+        let gradient_bounds: BTreeMap<String, (f64, f64)> = self.compute_gradient_bounds(visible_columns_slice);
+        let heatmap_gradient = GradientStyle::default();
+
+        // Compile regex rule patterns once per frame rather than per row and cell
+        let regex_cache: BTreeMap<&str, Regex> = self.style_sets.iter()
+            .flat_map(|ss| ss.rules.iter())
+            .filter_map(|rule| match &rule.logic {
+                StyleLogic::Conditional(cond) => match &cond.condition {
+                    Condition::Regex { pattern, .. } => Some(pattern.as_str()),
+                    Condition::Filter { .. } => None,
+                },
+                _ => None,
+            })
+            .filter_map(|pattern| Regex::new(pattern).ok().map(|re| (pattern, re)))
+            .collect();
         
+        // Rows of a group share a background when rows are grouped by a column
+        let group_starts = self.dataframe.column_width_config.group_column.clone()
+            .and_then(|column| self.dataframe.group_starts(&column));
+
         // Build row data for style rule evaluation
         let row_widgets: Vec<Row> = visible_rows.iter().enumerate().map(|(i, row)| {
             let global_row = row_start + i;
@@ -1426,11 +1602,7 @@ impl Component for DataTable {
                     if let Some(val) = row_data.get(col_name) {
                         data.insert(col_name.clone(), val.clone());
                     } else {
-                        let any_val = df
-                            .column(col_name)
-                            .ok()
-                            .and_then(|s| s.get(global_row).ok())
-                            .unwrap_or(AnyValue::Null);
+                        let any_val = window.get(col_name, global_row);
                         data.insert(col_name.clone(), anyvalue_to_display_string(&any_val));
                     }
                 }
@@ -1447,6 +1619,18 @@ impl Component for DataTable {
             let mut cell_styles: Vec<Option<ratatui::style::Style>> = vec![None; visible_columns_slice.len()];
             // Track RegexGroup styles per column (pattern, capture, style)
             let mut cell_regex_styles: Vec<Vec<RegexGroupStyle>> = vec![Vec::new(); visible_columns_slice.len()];
+
+            // Heatmap shading is the base layer; style rules merge on top of it
+            for (j, col_name) in visible_columns_slice.iter().enumerate() {
+                if let Some(heatmap) = self.dataframe.heatmap_config.get(col_name)
+                    && let Some(value) = row_data.get(col_name).and_then(|v| v.parse::<f64>().ok()) {
+                        let (data_min, data_max) = gradient_bounds.get(col_name).copied().unwrap_or((0.0, 1.0));
+                        let min = heatmap.min.unwrap_or(data_min);
+                        let max = heatmap.max.unwrap_or(data_max);
+                        let normalized = heatmap_gradient.normalize(value, min, max);
+                        cell_styles[j] = Some(heatmap_gradient.interpolate(normalized).to_ratatui_style());
+                    }
+            }
             
             // Collect and sort rules by priority
             let mut all_rules: Vec<_> = self.style_sets.iter()
@@ -1462,30 +1646,13 @@ impl Component for DataTable {
                             Condition::Filter { expr, columns } => (columns.clone(), Some(expr)),
                             Condition::Regex { pattern, columns } => {
                                 // For regex conditions, we evaluate separately
-                                let re = match Regex::new(pattern) {
-                                    Ok(r) => r,
-                                    Err(_) => continue,
+                                let Some(re) = regex_cache.get(pattern.as_str()) else {
+                                    continue;
                                 };
-                                // Check if any cell matches the regex
-                                let cols_to_check = columns.as_ref()
-                                    .map(|c| c.iter().filter_map(|cn| {
-                                        if matches_column(cn, &[cn.clone()]) {
-                                            Some(cn.clone())
-                                        } else {
-                                            None
-                                        }
-                                    }).collect::<Vec<_>>())
-                                    .unwrap_or_else(|| row_data_for_eval.keys().cloned().collect());
-                                
-                                let mut regex_matched = false;
-                                for col in &cols_to_check {
-                                    if let Some(val) = row_data_for_eval.get(col) {
-                                        if re.is_match(val) {
-                                            regex_matched = true;
-                                            break;
-                                        }
-                                    }
-                                }
+                                // Check if any cell in the condition columns (glob patterns) matches the regex
+                                let regex_matched = row_data_for_eval.iter()
+                                    .filter(|(col_name, _)| columns.as_ref().is_none_or(|c| c.is_empty() || matches_column(col_name, c)))
+                                    .any(|(_, val)| re.is_match(val));
                                 if !regex_matched {
                                     continue;
                                 }
@@ -1542,7 +1709,8 @@ impl Component for DataTable {
                                 ApplicationScope::RegexGroup(capture) => {
                                     // RegexGroup styling - collect info for per-character styling
                                     // Only applies to Regex conditions
-                                    if let Condition::Regex { pattern, columns } = &cond.condition {
+                                    if let Condition::Regex { pattern, columns } = &cond.condition
+                                        && let Some(re) = regex_cache.get(pattern.as_str()) {
                                         let target_cols = target_cols.or(columns.as_ref());
                                         for (j, col_name) in visible_columns_slice.iter().enumerate() {
                                             let should_apply = match target_cols {
@@ -1551,7 +1719,7 @@ impl Component for DataTable {
                                             };
                                             if should_apply {
                                                 cell_regex_styles[j].push(RegexGroupStyle {
-                                                    pattern: pattern.clone(),
+                                                    regex: re.clone(),
                                                     capture: capture.clone(),
                                                     style: matched_style,
                                                 });
@@ -1613,12 +1781,31 @@ impl Component for DataTable {
                     }
                 }
             }
-            
+
+            // Cells failing a validation rule stay marked over any style rule
+            if let Some(validator) = &self.dataframe.validator {
+                for (j, col_name) in visible_columns_slice.iter().enumerate() {
+                    if validator.fails(col_name, row_data.get(col_name).map(String::as_str).unwrap_or_default()) {
+                        let failed = Style::default().fg(Color::White).bg(Color::Red);
+                        cell_styles[j] = Some(cell_styles[j].unwrap_or_default().patch(failed));
+                    }
+                }
+            }
+
             // Create cells with applied styles
             let cells: Vec<Cell> = (0..visible_columns_slice.len()).map(|j| {
-                let col_idx = col_start + j;
+                let col_idx = drawn[j];
                 let value = &row[j];
-                let cell_str = anyvalue_to_display_string(value);
+                let text = self.dataframe.display_settings.format_value(&visible_columns_slice[j], value);
+                let cell_lines = if row_heights[i] > 1 && self.dataframe.column_width_config.wrapped_columns.contains(&visible_columns_slice[j]) {
+                    wrap_cell(&text, drawn_widths[j], Self::MAX_WRAP_LINES)
+                } else {
+                    vec![fit_cell(&text, drawn_widths[j])]
+                };
+                let plain_text = || Text::from(cell_lines.iter().map(|l| Line::from(l.clone())).collect::<Vec<_>>());
+                let styled_text = |base_style: Option<Style>| -> Text<'static> {
+                    cell_lines.iter().map(|line| apply_regex_group_styles(line, &cell_regex_styles[j], base_style)).collect::<Vec<_>>().into()
+                };
                 
                 // Check if this cell has RegexGroup styles to apply
                 let cell = if !cell_regex_styles[j].is_empty() {
@@ -1630,15 +1817,10 @@ impl Component for DataTable {
                             None
                         }
                     });
-                    let styled_line = apply_regex_group_styles(
-                        &cell_str,
-                        &cell_regex_styles[j],
-                        base_style
-                    );
-                    Cell::from(styled_line)
+                    Cell::from(styled_text(base_style))
                 } else {
                     // Normal cell styling
-                    let mut cell = Cell::from(cell_str);
+                    let mut cell = Cell::from(plain_text());
                     
                     // Apply cell-specific style if set
                     if let Some(ref cell_style) = cell_styles[j] {
@@ -1655,8 +1837,6 @@ impl Component for DataTable {
                 // Selected cell style overrides - for RegexGroup cells we need special handling
                 if global_row == self.selection.row && col_idx == self.selection.col {
                     // For the selected cell, override with selected style
-                    let value = &row[j];
-                    let cell_str = anyvalue_to_display_string(value);
                     let mut selected_cell_style = default_row_selection_style.clone();
 
                     if !selected_cell_style.add_modifier.contains(Modifier::UNDERLINED) {
@@ -1672,23 +1852,36 @@ impl Component for DataTable {
                             selected_cell_style.bg = Some(invert_color(bg));
                         }
                     }
-                    Cell::from(cell_str).style(selected_cell_style)
+                    // Keep regex group highlights visible on the selected cell
+                    if cell_regex_styles[j].is_empty() {
+                        Cell::from(plain_text()).style(selected_cell_style)
+                    } else {
+                        Cell::from(styled_text(None)).style(selected_cell_style)
+                    }
                 } else {
                     cell
                 }
             }).collect();
             
             // Create row with applied styles
-            let mut r = Row::new(cells);
+            let mut r = Row::new(cells).height(row_heights[i]);
             
-            // Apply row-level style if set
-            if let Some(ref rs) = row_style {
-                r = r.style(*rs);
+            // Apply row-level style if set; keep the selected row marked when a rule styles it
+            if let Some(rs) = row_style {
+                if global_row == self.selection.row {
+                    r = r.style(rs.add_modifier(Modifier::REVERSED));
+                } else {
+                    r = r.style(rs);
+                }
             } else {
                 // Default row styling
+                let band = match &group_starts {
+                    Some(starts) => starts.partition_point(|&start| start <= global_row).saturating_sub(1),
+                    None => global_row,
+                };
                 if global_row == self.selection.row {
                     r = r.style(Style::default().add_modifier(Modifier::REVERSED));
-                } else if global_row % 2 == 0 {
+                } else if band % 2 == 0 {
                     r = r.style(self.style.table_row_even);
                 } else {
                     r = r.style(self.style.table_row_odd);
@@ -1714,10 +1907,114 @@ impl Component for DataTable {
             .block(Block::default()
             .borders(Borders::ALL)
             .style(self.style.table_border));
-        frame.render_widget(table, table_area);
+        Widget::render(table, table_area, buf);
+
+        // Column minimap on the bottom border when not every column fits
+        if drawn.len() < total_cols && table_area.height > 2 {
+            let label = format!(" col {}/{} ", self.selection.col + 1, total_cols);
+            let bar_width = (table_area.width as usize).saturating_sub(label.chars().count() + 4);
+            if bar_width >= 4 {
+                let y = table_area.bottom() - 1;
+                let x = table_area.x + 2;
+                for (i, c) in column_minimap(total_cols, &drawn, self.selection.col, bar_width).chars().enumerate() {
+                    let style = match c {
+                        '─' => self.style.table_border,
+                        _ => Style::default().fg(ratatui::style::Color::Cyan),
+                    };
+                    buf.set_string(x + i as u16, y, c.to_string(), style);
+                }
+                buf.set_string(x + bar_width as u16, y, label, self.style.table_border);
+            }
+        }
+
+        // Quick peek at the selected cell when its content is cut off
+        if self.cell_peek
+            && let Some(i) = self.selection.row.checked_sub(row_start).filter(|i| *i < visible_rows.len())
+            && let Some(j) = drawn.iter().position(|&c| c == self.selection.col)
+        {
+            let column = &visible_columns_slice[j];
+            let text = self.dataframe.display_settings.format_value(column, &visible_rows[i][j]);
+            let shown_lines = if self.dataframe.column_width_config.wrapped_columns.contains(column) { row_heights[i] as usize } else { 1 };
+            if cell_truncated(&text, drawn_widths[j], shown_lines) {
+                let cell = Rect::new(
+                    table_area.x + 1 + (drawn_widths[..j].iter().sum::<usize>() + j * Self::COLUMN_SPACING as usize) as u16,
+                    table_area.y + 1 + Self::HEADER_HEIGHT + row_heights[..i].iter().sum::<u16>(),
+                    drawn_widths[j] as u16,
+                    row_heights[i],
+                );
+                self.render_cell_peek(column, &text, cell, table_area, buf);
+            }
+        }
         Ok(())
     }
-} 
+
+    /// Draw `text` in a bordered box below `cell`, or above it when there is no room below,
+    /// kept inside `bounds`
+    fn render_cell_peek(&self, column: &str, text: &str, cell: Rect, bounds: Rect, buf: &mut Buffer) {
+        let longest = text.lines().map(display_width).max().unwrap_or(0).max(display_width(column));
+        let content_width = longest.min(Self::PEEK_WIDTH).min((bounds.width as usize).saturating_sub(2));
+        if content_width == 0 {
+            return;
+        }
+        let lines = wrap_cell(text, content_width, Self::PEEK_LINES);
+        let width = content_width as u16 + 2;
+        let height = (lines.len() as u16 + 2).min(bounds.height);
+        let x = cell.x.min(bounds.right().saturating_sub(width)).max(bounds.x);
+        let y = if cell.bottom() + height <= bounds.bottom() {
+            cell.bottom()
+        } else if cell.y >= bounds.y + height {
+            cell.y - height
+        } else {
+            bounds.bottom().saturating_sub(height).max(bounds.y)
+        };
+        let popup = Rect::new(x, y, width, height);
+        Clear.render(popup, buf);
+        Paragraph::new(lines.into_iter().map(Line::from).collect::<Vec<_>>())
+            .style(self.style.table_cell)
+            .block(Block::default()
+                .title(fit_cell(column, content_width))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(self.style.table_border))
+            .render(popup, buf);
+    }
+}
+
+/// Whether a cell drawn on `shown_lines` lines of `width` columns hides part of `text`
+fn cell_truncated(text: &str, width: usize, shown_lines: usize) -> bool {
+    if shown_lines <= 1 {
+        text.contains('\n') || display_width(text) > width
+    } else {
+        wrap_cell(text, width, shown_lines + 1).len() > shown_lines
+    }
+}
+
+/// A `width`-cell strip standing for all `total` columns: `◆` where the selected column is,
+/// `━` over columns on screen and `─` elsewhere
+fn column_minimap(total: usize, drawn: &[usize], selected: usize, width: usize) -> String {
+    (0..width)
+        .map(|i| {
+            let start = i * total / width;
+            let end = ((i + 1) * total / width).max(start + 1);
+            if (start..end).contains(&selected) {
+                '◆'
+            } else if drawn.iter().any(|c| (start..end).contains(c)) {
+                '━'
+            } else {
+                '─'
+            }
+        })
+        .collect()
+}
+
+/// Errors are logged; call `DataTable::render` to handle them instead.
+impl Widget for &mut DataTable {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if let Err(e) = DataTable::render(self, area, buf) {
+            tracing::error!("Failed to render table: {e}");
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -1749,4 +2046,229 @@ mod tests {
         datatable.selection = TableSelection { row: 10, col: 0 };
         assert_eq!(datatable.selected_cell_value().unwrap(), "");
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_sampled_and_exact_column_widths() {
+        let mut values: Vec<String> = vec!["ab".to_string(); DataTable::WIDTH_SAMPLE_ROWS + 10];
+        values.push("x".repeat(40));
+        let df = df!("a_long_header" => values).unwrap();
+        let datatable = DataTable::new(ManagedDataFrame::new(df, "test".to_string(), None, None), StyleConfig::default());
+
+        // The long value is past the sample
+        assert!(datatable.get_all_column_widths().unwrap()["a_long_header"] < 40);
+        assert!(datatable.exact_column_widths().unwrap()["a_long_header"] >= 40);
+    }
+
+    #[test]
+    fn test_vim_gg_goes_to_first_row() {
+        use crate::config::KeymapProfile;
+        use crossterm::event::{KeyCode, KeyModifiers};
+        let df = df!("n" => (0..10).collect::<Vec<i32>>()).unwrap();
+        let mut datatable = DataTable::new(ManagedDataFrame::new(df, "test".to_string(), None, None), StyleConfig::default());
+        let mut config = Config { keymap: KeymapProfile::Vim, ..Config::default() };
+        config.reset_keybindings_to_default();
+        datatable.register_config_handler(config).unwrap();
+        let press = |datatable: &mut DataTable, code| datatable.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE)).unwrap();
+
+        datatable.selection = TableSelection { row: 5, col: 0 };
+        press(&mut datatable, KeyCode::Char('g'));
+        assert_eq!(datatable.selection.row, 5);
+        press(&mut datatable, KeyCode::Char('g'));
+        assert_eq!(datatable.selection.row, 0);
+
+        // g followed by another key runs that key
+        press(&mut datatable, KeyCode::Char('g'));
+        press(&mut datatable, KeyCode::Char('j'));
+        assert_eq!(datatable.selection.row, 1);
+    }
+
+    #[test]
+    fn test_selected_row_record() {
+        let s1 = Series::new("col1".into(), &["a", "b", "c"]);
+        let s2 = Series::new("col2".into(), &[1, 2, 3]);
+        let df = DataFrame::new(vec![s1.into(), s2.into()]).unwrap();
+        let managed_df = ManagedDataFrame::new(df, "test".to_string(), None, None);
+        let mut datatable = DataTable::new(managed_df, StyleConfig::default());
+
+        datatable.selection = TableSelection { row: 2, col: 0 };
+        let record = datatable.selected_row_record().unwrap();
+        assert_eq!(record, vec![
+            ("col1".to_string(), "c".to_string()),
+            ("col2".to_string(), "3".to_string()),
+        ]);
+
+        datatable.selection = TableSelection { row: 10, col: 0 };
+        assert!(datatable.selected_row_record().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_pinned_columns_stay_on_screen() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let df = df!("a" => ["1"], "b" => ["2"], "c" => ["3"], "d" => ["4"], "e" => ["5"], "f" => ["6"]).unwrap();
+        let mut datatable = DataTable::new(ManagedDataFrame::new(df, "test".to_string(), None, None), StyleConfig::default());
+        assert!(datatable.toggle_pinned_column("d").unwrap());
+        assert_eq!(datatable.get_visible_columns().unwrap(), vec!["d", "a", "b", "c", "e", "f"]);
+
+        // Room for three columns: the pinned one, then the scrolled ones from the selection on
+        datatable.selection = TableSelection { row: 0, col: 4 };
+        let mut terminal = Terminal::new(TestBackend::new(16, 5)).unwrap();
+        terminal.draw(|frame| { datatable.draw(frame, frame.area()).unwrap(); }).unwrap();
+        let header: String = (1..15).map(|x| terminal.backend().buffer()[(x, 1)].symbol().to_string()).collect();
+        assert_eq!(header.split_whitespace().collect::<Vec<_>>(), vec!["d", "e", "f"]);
+
+        assert!(!datatable.toggle_pinned_column("d").unwrap());
+        assert_eq!(datatable.get_visible_columns().unwrap(), vec!["d", "a", "b", "c", "e", "f"]);
+        assert!(datatable.get_column_width_config().pinned_columns.is_empty());
+    }
+
+    #[test]
+    fn test_wrapped_column_rows() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let df = df!("id" => ["1", "2", "3"], "msg" => ["short", "a much longer message that wraps", "x"]).unwrap();
+        let mut datatable = DataTable::new(ManagedDataFrame::new(df, "test".to_string(), None, None), StyleConfig::default());
+        assert!(datatable.toggle_wrapped_column("msg"));
+
+        let line = |terminal: &Terminal<TestBackend>, y: u16| -> String {
+            (1..29).map(|x| terminal.backend().buffer()[(x, y)].symbol().to_string()).collect()
+        };
+        let mut terminal = Terminal::new(TestBackend::new(30, 10)).unwrap();
+        terminal.draw(|frame| { datatable.draw(frame, frame.area()).unwrap(); }).unwrap();
+        assert!(line(&terminal, 3).contains("a much longer message"));
+        assert!(line(&terminal, 4).trim().starts_with("that wraps"));
+        assert!(line(&terminal, 5).trim().starts_with('3'));
+
+        // Three lines of rows: the last row only fits once the first scrolls off
+        datatable.selection = TableSelection { row: 2, col: 0 };
+        let mut terminal = Terminal::new(TestBackend::new(30, 6)).unwrap();
+        terminal.draw(|frame| { datatable.draw(frame, frame.area()).unwrap(); }).unwrap();
+        assert_eq!(datatable.scroll.y, 1);
+        assert!(line(&terminal, 4).trim().starts_with('3'));
+
+        assert!(!datatable.toggle_wrapped_column("msg"));
+    }
+
+    #[test]
+    fn test_cell_peek_shows_truncated_cell() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let df = df!("id" => ["1", "2"], "msg" => ["a message far too long for its column to show whole", "ok"]).unwrap();
+        let mut datatable = DataTable::new(ManagedDataFrame::new(df, "test".to_string(), None, None), StyleConfig::default());
+        datatable.selection = TableSelection { row: 0, col: 1 };
+        let screen = |datatable: &mut DataTable| -> String {
+            let mut terminal = Terminal::new(TestBackend::new(30, 12)).unwrap();
+            terminal.draw(|frame| { datatable.draw(frame, frame.area()).unwrap(); }).unwrap();
+            let buffer = terminal.backend().buffer();
+            (0..12).map(|y| (0..30).map(|x| buffer[(x, y)].symbol()).collect::<String>() + "\n").collect()
+        };
+        assert!(!screen(&mut datatable).contains("whole"));
+
+        datatable.cell_peek = true;
+        assert!(screen(&mut datatable).contains("whole"));
+        // Cells that fit get no box
+        datatable.selection = TableSelection { row: 1, col: 1 };
+        assert!(!screen(&mut datatable).contains("whole"));
+    }
+
+    #[test]
+    fn test_column_minimap() {
+        assert_eq!(column_minimap(300, &(100..110).collect::<Vec<_>>(), 104, 30), "──────────◆───────────────────");
+        assert_eq!(column_minimap(8, &[0, 4, 5], 4, 8), "━───◆━──");
+        // Fewer columns than cells: each column spans several cells
+        assert_eq!(column_minimap(2, &[1], 1, 4), "──◆◆");
+    }
+
+    #[test]
+    fn test_mixed_width_cells_keep_columns_aligned() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let name = Series::new("name".into(), &["plain", "日本語の長いテキスト", "🦀🦀🦀🦀🦀🦀🦀🦀", "cafe\u{301}\tbar\nbaz"]);
+        let tag = Series::new("tag".into(), &["x", "x", "x", "x"]);
+        let df = DataFrame::new(vec![name.into(), tag.into()]).unwrap();
+        let mut datatable = DataTable::new(ManagedDataFrame::new(df, "test".to_string(), None, None), StyleConfig::default());
+        let mut widths = datatable.get_column_width_config();
+        widths.manual_widths.insert("name".to_string(), 10);
+        datatable.set_column_width_config(widths);
+
+        let mut terminal = Terminal::new(TestBackend::new(40, 8)).unwrap();
+        terminal.draw(|frame| { datatable.draw(frame, frame.area()).unwrap(); }).unwrap();
+        let buf = terminal.backend().buffer();
+        // The second column starts at the same x on the header and every row
+        let tag_x = (1..40).find(|x| buf[(*x, 1)].symbol() == "t").unwrap();
+        for y in 2..6 {
+            assert_eq!(buf[(tag_x, y)].symbol(), "x", "row {y}");
+        }
+        let row: String = (1..tag_x).map(|x| buf[(x, 3)].symbol()).collect();
+        assert!(row.contains('…'), "{row:?}");
+    }
+
+    #[test]
+    fn test_row_scope_styles_whole_row() {
+        use crate::dialog::filter_dialog::{ColumnFilter, FilterCondition, FilterExpr};
+        use crate::dialog::styling::style_set::{MatchedStyle, StyleApplication, StyleRule};
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let severity = Series::new("severity".into(), &["info", "critical", "info"]);
+        let message = Series::new("message".into(), &["ok", "disk failed", "ok"]);
+        let df = DataFrame::new(vec![severity.into(), message.into()]).unwrap();
+        let mut datatable = DataTable::new(ManagedDataFrame::new(df, "test".to_string(), None, None), StyleConfig::default());
+
+        let condition = Condition::Filter {
+            expr: FilterExpr::Condition(ColumnFilter {
+                column: "severity".to_string(),
+                condition: FilterCondition::Equals { value: "critical".to_string(), case_sensitive: false },
+            }),
+            columns: Some(vec!["severity".to_string()]),
+        };
+        let application = StyleApplication {
+            scope: ApplicationScope::Row,
+            style: MatchedStyle { fg: None, bg: Some(Color::Red), modifiers: None },
+            target_columns: None,
+        };
+        datatable.set_style_sets(vec![StyleSet { rules: vec![StyleRule::conditional(condition, vec![application])], ..StyleSet::default() }]);
+        // Select the critical row so the selection marker has to survive the row style
+        datatable.selection = TableSelection { row: 1, col: 0 };
+
+        let mut terminal = Terminal::new(TestBackend::new(40, 8)).unwrap();
+        terminal.draw(|frame| { datatable.draw(frame, frame.area()).unwrap(); }).unwrap();
+        let buf = terminal.backend().buffer();
+        // Rows start below the top border and header; "message" starts after "severity" plus spacing
+        let message_x = (1..40).find(|x| buf[(*x, 1)].symbol() == "m").unwrap();
+        assert_eq!(buf[(message_x, 3)].bg, Color::Red);
+        assert!(buf[(message_x, 3)].modifier.contains(Modifier::REVERSED));
+        assert_ne!(buf[(message_x, 2)].bg, Color::Red);
+    }
+
+    #[test]
+    fn test_regex_group_styles_render_in_grid() {
+        use crate::dialog::styling::style_set::{MatchedStyle, StyleApplication, StyleRule};
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let host = Series::new("host".into(), &["web01", "web02"]);
+        let message = Series::new("message".into(), &["from 10.1.2.3 ok", "no address"]);
+        let df = DataFrame::new(vec![host.into(), message.into()]).unwrap();
+        let mut datatable = DataTable::new(ManagedDataFrame::new(df, "test".to_string(), None, None), StyleConfig::default());
+
+        // Column globs on the regex condition must resolve against real column names
+        let condition = Condition::Regex { pattern: r"(\d+\.){3}\d+".to_string(), columns: Some(vec!["mess*".to_string()]) };
+        let application = StyleApplication {
+            scope: ApplicationScope::RegexGroup(GrepCapture::Group(0)),
+            style: MatchedStyle { fg: Some(Color::Magenta), bg: None, modifiers: None },
+            target_columns: None,
+        };
+        datatable.set_style_sets(vec![StyleSet { rules: vec![StyleRule::conditional(condition, vec![application])], ..StyleSet::default() }]);
+        datatable.selection = TableSelection { row: 1, col: 0 };
+
+        let mut terminal = Terminal::new(TestBackend::new(40, 8)).unwrap();
+        terminal.draw(|frame| { datatable.draw(frame, frame.area()).unwrap(); }).unwrap();
+        let buf = terminal.backend().buffer();
+        let message_x = (1..40).find(|x| buf[(*x, 1)].symbol() == "m").unwrap();
+        // "from " precedes the address in the first data row
+        assert_ne!(buf[(message_x, 2)].fg, Color::Magenta);
+        assert_eq!(buf[(message_x + 5, 2)].symbol(), "1");
+        assert_eq!(buf[(message_x + 5, 2)].fg, Color::Magenta);
+        assert_eq!(buf[(message_x + 12, 2)].fg, Color::Magenta);
+        assert_ne!(buf[(message_x + 14, 2)].fg, Color::Magenta);
+    }
+}
@@ -45,12 +45,27 @@
 //! See method-level documentation for details on customization and event handling.
 
 use crate::components::{Component, datatable::DataTable};
+use crate::components::background_job::{BackgroundJob, JobContext};
 use crate::style::StyleConfig;
-use crate::dataframe::manager::SortableDataFrame;
+use crate::dataframe::manager::{filter_with_progress, filtered_plan, next_quick_sort, null_outside_filter, FilterableDataFrame, SortableDataFrame};
+use crate::dataframe::spill::{sink_to_parquet, SpillFile};
+use crate::dataframe::cluster_metrics::{cluster_quality, ClusterQuality};
+use crate::dataframe::hashing::HashAlgorithm;
+use crate::dataframe::lookup::{lookup_columns, LookupOptions};
+use crate::dataframe::pattern_sets::{run_pattern_set, PatternSetOptions, PatternSetSummary};
+use crate::dataframe::sigma::{run_sigma, SigmaEvaluation, SigmaOptions, SigmaOutput};
+use crate::dataframe::row_tags::TAGS_COLUMN;
+use crate::dataframe::validation::CellValidator;
+use crate::dataframe::findings_report::Findings;
+use crate::dataframe::timestamp_normalize::{normalize_timestamps, NormalizeReport};
+use crate::threat_intel::{enrich_values, EnrichmentCache, ThreatIntelConfig, ThreatIntelProvider};
+use crate::dataframe::outliers::{outlier_scores, OutlierOptions, OutlierOutput};
+use crate::dataframe::redaction::{RedactionOptions, Redactor};
 use crate::action::Action;
 use crate::config::{Config, Mode};
 use crate::tui::Event;
-use crate::sql::register_all;
+use crate::sql::diagnostics::diagnose;
+use crate::sql::session::{SharedSqlSession, SqlOutput, SqlSession};
 use color_eyre::Result;
 use crossterm::event::{KeyEvent, MouseEvent};
 use ratatui::prelude::{Frame, Rect, Size};
@@ -58,29 +73,67 @@ use ratatui::widgets::{Block, Borders, Paragraph, Wrap, Widget, Clear};
 use ratatui::layout::{Layout, Direction, Constraint};
 use tokio::sync::mpsc::UnboundedSender;
 use std::collections::{BTreeSet, HashSet};
-use crate::dialog::sort_dialog::{SortDialog, SortDialogMode};
+use crate::dialog::sort_dialog::{SortColumn, SortDialog, SortDialogMode};
 use crate::dialog::filter_dialog::FilterDialog;
 use crate::dialog::sql_dialog::SqlDialog;
 use crate::dialog::column_width_dialog::ColumnWidthDialog;
 use crate::dialog::find_dialog::FindDialog;
-use crate::dialog::find_dialog::{FindOptions, SearchMode};
+use crate::dialog::find_dialog::{FindHistoryEntry, FindOptions, SearchMode};
 use crate::dialog::find_all_results_dialog::FindAllResultsDialog;
+use crate::dialog::record_view_dialog::RecordViewDialog;
+use crate::dialog::group_by_dialog::GroupByDialog;
+use crate::dialog::heatmap_dialog::HeatmapDialog;
+use crate::dialog::chart_dialog::ChartDialog;
+use crate::dialog::benford_dialog::BenfordDialog;
+use crate::components::timeline_panel::TimelinePanel;
+use crate::components::group_view::GroupView;
+use crate::dialog::display_settings_dialog::DisplaySettingsDialog;
+use crate::dialog::hex_viewer_dialog::HexViewerDialog;
+use crate::dialog::ask_data_dialog::AskDataDialog;
+use crate::dialog::transform_history_dialog::TransformHistoryDialog;
+use crate::dialog::checkpoints_dialog::CheckpointsDialog;
+use crate::dialog::sample_dialog::SampleDialog;
+use crate::dialog::resample_dialog::{ResampleDialog, ResampleOutput};
+use crate::dialog::crosstab_dialog::CrosstabDialog;
+use crate::dialog::sigma_dialog::SigmaDialog;
+use crate::dialog::pattern_set_dialog::PatternSetDialog;
+use crate::dialog::row_tags_dialog::RowTagsDialog;
+use crate::dialog::validation_dialog::ValidationDialog;
+use crate::dialog::column_menu_dialog::ColumnMenuDialog;
+use crate::dialog::go_to_column_dialog::GoToColumnDialog;
+use crate::dialog::go_to_row_dialog::GoToRowDialog;
+use crate::dialog::findings_report_dialog::FindingsReportDialog;
+use crate::dialog::missing_data_dialog::MissingDataDialog;
+use crate::dataframe::sampling::sample;
+use crate::dataframe::schema_edit::{parse_dtype, ColumnSchema, SchemaEdit};
+use crate::dataframe::resample::{resample, resample_chart_bars};
+use crate::dataframe::checkpoints::Checkpoint;
+use crate::dataframe::scripting::{run_script, run_script_cancellable, SavedScript, ScriptTarget};
+use crate::dialog::script_dialog::ScriptDialog;
+use crate::dataframe::transform_history::TransformStep;
+use crate::dialog::ClusterReportDialog;
+use crate::dialog::MessageDialog;
+use crate::dialog::ErrorDialog;
+use crate::dialog::{ConfirmDialog, ConfirmKind};
+use crate::dialog::cell_viewer_dialog::CellViewerDialog;
+use crate::dialog::open_target_dialog::{detect_open_target, open_with_system_handler, OpenTargetDialog};
+use crate::components::syntax_highlight::spans_from_styled_ranges;
 use crate::dialog::dataframe_details_dialog::DataFrameDetailsDialog;
 use crate::dialog::data_management_dialog::LoadedDataset;
 use crate::dialog::JmesPathDialog;
 use crate::dialog::jmes_dialog::JmesPathKeyValuePair;
+use crate::jmes::batch as jmes_batch;
+use crate::jmes::hints::PREVIEW_ROWS;
+use crate::jmes::history::{JmesExpression, JmesHistory};
 use crate::dialog::TransformScope;
 use crate::dialog::ColumnOperationsDialog;
 use crate::dialog::ColumnOperationOptionsDialog;
 use crate::dialog::ColumnOperationOptionsMode;
 use crate::dialog::ColumnOperationKind;
-use crate::dialog::filter_dialog::{FilterExpr, FilterCondition, FilterDialogMode};
+use crate::dialog::filter_dialog::{ColumnFilter, FilterExpr, FilterCondition, FilterDialogMode};
 use crate::dialog::LlmClientCreateDialog;
-// use polars_sql::SQLContext; // replaced by custom new_sql_context
-use crate::sql::new_sql_context;
 use std::sync::Arc;
 use std::collections::HashMap;
-use polars_lazy::frame::IntoLazy;
 use arboard::Clipboard;
 use tracing::{debug, info, error};
 use textwrap::wrap;
@@ -91,14 +144,19 @@ use serde_json::{Value as JsonValue, Map as JsonMap};
 use jmespath;
 use serde_json::Value;
 use polars::prelude::{NamedFrom, IntoColumn};
-use crate::dialog::OperationOptions;
+use crate::dialog::{ColumnOperationConfig, ColumnOperationPreset, OperationOptions};
 use crate::dialog::{ClusterAlgorithm, KmeansOptions, DbscanOptions};
 use crate::dialog::styling::{StyleLogic, Condition, ApplicationScope, GrepCapture, matches_column};
 // use crate::dialog::DataExportDialog; // moved to DataTabManagerDialog
+#[cfg(feature = "clustering")]
 use linfa::prelude::{Fit, Predict};
+#[cfg(feature = "clustering")]
 use linfa_reduction::Pca as LinfaPca;
+#[cfg(feature = "clustering")]
 use ndarray::{Array2, ArrayBase, Ix2, OwnedRepr};
+#[cfg(feature = "clustering")]
 use linfa_clustering::KMeans;
+#[cfg(feature = "clustering")]
 use linfa::DatasetBase;
 
 
@@ -148,6 +206,72 @@ pub struct DataTableContainer {
     pub find_dialog_active: bool,
     pub find_all_results_dialog: Option<FindAllResultsDialog>,
     pub find_all_results_dialog_active: bool,
+    pub record_view_dialog: Option<RecordViewDialog>,
+    pub record_view_dialog_active: bool,
+    pub group_by_dialog: GroupByDialog,
+    pub group_by_dialog_active: bool,
+    /// Grouped rows shown in place of the table; keys go to it until Escape
+    pub group_view: Option<GroupView>,
+    pub heatmap_dialog: Option<HeatmapDialog>,
+    pub heatmap_dialog_active: bool,
+    pub chart_dialog: Option<ChartDialog>,
+    pub chart_dialog_active: bool,
+    pub benford_dialog: Option<BenfordDialog>,
+    pub benford_dialog_active: bool,
+    /// Event-density strip above the table; None while hidden
+    pub timeline: Option<TimelinePanel>,
+    pub display_settings_dialog: Option<DisplaySettingsDialog>,
+    pub display_settings_dialog_active: bool,
+    pub hex_viewer_dialog: Option<HexViewerDialog>,
+    pub hex_viewer_dialog_active: bool,
+    /// Kept after closing so the last question is still there when reopened
+    pub ask_data_dialog: Option<AskDataDialog>,
+    pub ask_data_dialog_active: bool,
+    pub transform_history_dialog: Option<TransformHistoryDialog>,
+    pub transform_history_dialog_active: bool,
+    pub checkpoints_dialog: Option<CheckpointsDialog>,
+    pub checkpoints_dialog_active: bool,
+    pub sample_dialog: Option<SampleDialog>,
+    pub sample_dialog_active: bool,
+    pub resample_dialog: Option<ResampleDialog>,
+    pub resample_dialog_active: bool,
+    pub crosstab_dialog: Option<CrosstabDialog>,
+    pub crosstab_dialog_active: bool,
+    pub sigma_dialog: Option<SigmaDialog>,
+    pub sigma_dialog_active: bool,
+    pub pattern_set_dialog: Option<PatternSetDialog>,
+    pub pattern_set_dialog_active: bool,
+    pub row_tags_dialog: Option<RowTagsDialog>,
+    pub row_tags_dialog_active: bool,
+    pub validation_dialog: Option<ValidationDialog>,
+    pub validation_dialog_active: bool,
+    pub column_menu_dialog: Option<ColumnMenuDialog>,
+    pub column_menu_dialog_active: bool,
+    pub go_to_row_dialog: Option<GoToRowDialog>,
+    pub go_to_row_dialog_active: bool,
+    pub go_to_column_dialog: Option<GoToColumnDialog>,
+    pub go_to_column_dialog_active: bool,
+    pub findings_report_dialog: Option<FindingsReportDialog>,
+    pub findings_report_dialog_active: bool,
+    pub missing_data_dialog: Option<MissingDataDialog>,
+    pub missing_data_dialog_active: bool,
+    /// Kept after closing so the script is still there when reopened
+    pub script_dialog: Option<ScriptDialog>,
+    pub script_dialog_active: bool,
+    /// The project's saved scripts, kept in step by the tab manager
+    pub saved_scripts: Vec<SavedScript>,
+    pub cluster_report_dialog: Option<ClusterReportDialog>,
+    pub cluster_report_dialog_active: bool,
+    /// Transient notice shown over the table (e.g. partial failures of a finished job)
+    pub message_dialog: Option<MessageDialog>,
+    /// Failure shown over the table with its cause chain and details
+    pub error_dialog: Option<ErrorDialog>,
+    /// Asks before an irreversible operation; its pending action runs once accepted
+    pub confirm_dialog: Option<ConfirmDialog>,
+    pub cell_viewer_dialog: Option<CellViewerDialog>,
+    pub cell_viewer_dialog_active: bool,
+    pub open_target_dialog: Option<OpenTargetDialog>,
+    pub open_target_dialog_active: bool,
     pub dataframe_details_dialog: DataFrameDetailsDialog,
     pub dataframe_details_dialog_active: bool,
     pub jmes_dialog: JmesPathDialog,
@@ -166,6 +290,8 @@ pub struct DataTableContainer {
     pub last_column_width_dialog_max_rows: Option<usize>,
     pub last_find_dialog_area: Option<ratatui::layout::Rect>,
     pub last_find_all_results_dialog_area: Option<ratatui::layout::Rect>,
+    pub last_record_view_dialog_area: Option<ratatui::layout::Rect>,
+    pub last_group_by_dialog_area: Option<ratatui::layout::Rect>,
     pub last_dataframe_details_dialog_area: Option<ratatui::layout::Rect>,
     pub last_dataframe_details_dialog_max_rows: Option<usize>,
     pub last_jmes_dialog_area: Option<ratatui::layout::Rect>,
@@ -174,7 +300,11 @@ pub struct DataTableContainer {
     pub current_search_pattern: Option<String>,
     pub current_search_mode: Option<SearchMode>,
     pub current_search_options: Option<FindOptions>,
+    /// Cell an incremental search starts from: the selection when Find opened or last moved
+    find_origin: Option<(usize, usize)>,
     pub available_datasets: HashMap<String, LoadedDataset>,
+    /// The workspace's SQL session, shared with the other tabs
+    pub sql_session: SharedSqlSession,
     // Progress overlay and pending long-running operation
     pub busy_active: bool,
     pub busy_message: String,
@@ -182,7 +312,14 @@ pub struct DataTableContainer {
     pub queued_embeddings: Option<QueuedEmbeddings>,
     pub in_progress_embeddings: Option<EmbeddingsJob>,
     pub queued_pca: Option<QueuedPca>,
+    pub queued_tsne: Option<QueuedTsne>,
     pub queued_cluster: Option<QueuedCluster>,
+    /// Worker thread for the current PCA/t-SNE/clustering/embeddings run
+    pub running_job: Option<BackgroundJob<ColumnJobOutput>>,
+    /// History step for the queued/running job, recorded once its result is applied
+    pub pending_history_step: Option<TransformStep>,
+    /// Source column hidden outside the filtered rows while a filtered-rows operation runs
+    pub masked_operation: Option<MaskedOperation>,
     // LLM client creation dialog for ad-hoc operations (e.g., embeddings)
     pub llm_client_create_dialog: Option<LlmClientCreateDialog>,
     pub llm_client_create_dialog_active: bool,
@@ -217,12 +354,41 @@ impl core::fmt::Debug for DataTableContainer {
             .field("column_width_dialog_active", &self.column_width_dialog_active)
             .field("find_dialog_active", &self.find_dialog_active)
             .field("find_all_results_dialog_active", &self.find_all_results_dialog_active)
+            .field("record_view_dialog_active", &self.record_view_dialog_active)
+            .field("group_by_dialog_active", &self.group_by_dialog_active)
+            .field("group_view", &self.group_view.as_ref().map(|g| &g.group_columns))
+            .field("heatmap_dialog_active", &self.heatmap_dialog_active)
+            .field("chart_dialog_active", &self.chart_dialog_active)
+            .field("benford_dialog_active", &self.benford_dialog_active)
+            .field("timeline", &self.timeline.as_ref().map(|t| t.column.as_str()))
+            .field("display_settings_dialog_active", &self.display_settings_dialog_active)
+            .field("hex_viewer_dialog_active", &self.hex_viewer_dialog_active)
+            .field("ask_data_dialog_active", &self.ask_data_dialog_active)
+            .field("transform_history_dialog_active", &self.transform_history_dialog_active)
+            .field("checkpoints_dialog_active", &self.checkpoints_dialog_active)
+            .field("sample_dialog_active", &self.sample_dialog_active)
+            .field("resample_dialog_active", &self.resample_dialog_active)
+            .field("crosstab_dialog_active", &self.crosstab_dialog_active)
+            .field("sigma_dialog_active", &self.sigma_dialog_active)
+            .field("pattern_set_dialog_active", &self.pattern_set_dialog_active)
+            .field("row_tags_dialog_active", &self.row_tags_dialog_active)
+            .field("validation_dialog_active", &self.validation_dialog_active)
+            .field("column_menu_dialog_active", &self.column_menu_dialog_active)
+            .field("go_to_row_dialog_active", &self.go_to_row_dialog_active)
+            .field("go_to_column_dialog_active", &self.go_to_column_dialog_active)
+            .field("findings_report_dialog_active", &self.findings_report_dialog_active)
+            .field("missing_data_dialog_active", &self.missing_data_dialog_active)
+            .field("script_dialog_active", &self.script_dialog_active)
+            .field("cluster_report_dialog_active", &self.cluster_report_dialog_active)
+            .field("cell_viewer_dialog_active", &self.cell_viewer_dialog_active)
+            .field("open_target_dialog_active", &self.open_target_dialog_active)
             .field("dataframe_details_dialog_active", &self.dataframe_details_dialog_active)
             .field("jmes_dialog_active", &self.jmes_dialog_active)
             .field("current_search_pattern", &self.current_search_pattern)
             .field("current_search_mode", &self.current_search_mode)
             .field("current_search_options", &self.current_search_options)
             .field("available_datasets", &"HashMap{..}")
+            .field("busy_active", &self.busy_active)
             .finish()
     }
 }
@@ -299,42 +465,55 @@ impl DataTableContainer {
             .map_err(|e| color_eyre::eyre::eyre!("Failed to build DataFrame: {}", e))?;
         self.datatable.dataframe.set_current_df(new_df);
         // Auto sort by the similarity column (descending)
-        let sort_cols = vec![crate::dialog::sort_dialog::SortColumn { name: new_name.clone(), ascending: false }];
+        let sort_cols = vec![crate::dialog::sort_dialog::SortColumn::new(new_name.clone(), false)];
         let _ = self.datatable.dataframe.sort_by_columns(&sort_cols);
         Ok(())
     }
-    fn process_next_embeddings_batch(&mut self) -> color_eyre::Result<Option<bool>> {
-        let job = if let Some(j) = self.in_progress_embeddings.take() { j } else { return Ok(None) };
-        if job.next_start >= job.total_uniques { self.in_progress_embeddings = Some(job); return Ok(Some(true)); }
-        let start = job.next_start;
-        let end = (start + job.batch_size).min(job.total_uniques);
-        let slice: Vec<String> = job.uniques[start..end].to_vec();
-        let dims_opt = if job.num_dimensions > 0 { Some(job.num_dimensions) } else { None };
+    /// Fetch embeddings for every unique text on a worker thread, one provider batch at a time
+    fn start_embeddings_job(&mut self, job: EmbeddingsJob) {
+        // LlmConfig holds non-Send client builders; only the provider settings cross to the worker
+        let settings = self.config.llm_config.provider_settings();
+        let policy = self.config.llm_config.request_policy.clone();
         let provider = job.provider.clone();
         let model_name = job.model_name.clone();
-        // Temporarily release `job` mutable borrow before calling into &self method
-        self.in_progress_embeddings = Some(job);
-        let batch = self.config.llm_config.fetch_embeddings_via_provider(provider, &model_name, &slice, dims_opt)?;
-        let mut job = self.in_progress_embeddings.take().expect("embeddings job should exist");
-        if batch.len() != slice.len() { return Err(color_eyre::eyre::eyre!("Embeddings provider returned wrong length for batch")); }
-        if job.unique_embeddings.is_empty() {
-            job.unique_embeddings = vec![Vec::new(); job.total_uniques];
-        }
-        for (i, emb) in batch.into_iter().enumerate() {
-            job.unique_embeddings[start + i] = emb;
-        }
-        job.next_start = end;
-        let progress = end as f64 / job.total_uniques.max(1) as f64;
-        self.busy_progress = progress;
-        self.busy_message = format!(
-            "Generating embeddings with {}... {}/{}",
-            job.provider.display_name(),
-            end,
-            job.total_uniques
-        );
-        let finished = job.next_start >= job.total_uniques;
+        let dims_opt = if job.num_dimensions > 0 { Some(job.num_dimensions) } else { None };
+        let uniques = job.uniques.clone();
+        let batch_size = job.batch_size.max(1);
         self.in_progress_embeddings = Some(job);
-        Ok(Some(finished))
+        self.running_job = Some(BackgroundJob::spawn(true, move |ctx| {
+            let llm_config = settings.into_config();
+            let is_cancelled = || ctx.is_cancelled();
+            let mut runner = crate::dialog::llm::RequestRunner::new(policy, &is_cancelled);
+            let mut unique_embeddings: Vec<Option<Vec<f32>>> = Vec::with_capacity(uniques.len());
+            let mut warnings: Vec<String> = Vec::new();
+            let mut first_error: Option<color_eyre::Report> = None;
+            for (batch_idx, batch) in uniques.chunks(batch_size).enumerate() {
+                ctx.check_cancelled()?;
+                let result = runner.run(|| {
+                    let embeddings = llm_config.fetch_embeddings_via_provider(provider.clone(), &model_name, &batch.to_vec(), dims_opt)?;
+                    if embeddings.len() != batch.len() { return Err(color_eyre::eyre::eyre!("Embeddings provider returned wrong length for batch")); }
+                    Ok(embeddings)
+                });
+                match result {
+                    Ok(embeddings) => unique_embeddings.extend(embeddings.into_iter().map(Some)),
+                    Err(e) => {
+                        ctx.check_cancelled()?;
+                        // Keep going: the failed batch's rows get null embeddings
+                        let start = batch_idx * batch_size;
+                        warnings.push(format!("Values {}-{}: {}", start + 1, start + batch.len(), e));
+                        unique_embeddings.extend(std::iter::repeat_n(None, batch.len()));
+                        first_error.get_or_insert(e);
+                    }
+                }
+                ctx.set_progress(unique_embeddings.len() as f64 / uniques.len().max(1) as f64);
+            }
+            // Nothing succeeded: report the failure itself rather than an all-null column
+            if !uniques.is_empty() && unique_embeddings.iter().all(Option::is_none)
+                && let Some(e) = first_error {
+                    return Err(e);
+                }
+            Ok(ColumnJobOutput::Embeddings { unique_embeddings, warnings })
+        }));
     }
 
     fn finalize_embeddings_job(&mut self) -> color_eyre::Result<()> {
@@ -342,10 +521,9 @@ impl DataTableContainer {
         let Some(job) = self.in_progress_embeddings.take() else { return Ok(()); };
         // Build ListChunked per row using unique_index and computed embeddings
         let row_embeddings_iter = job.row_texts.into_iter().map(|opt_text| {
-            opt_text.map(|t| {
+            opt_text.and_then(|t| {
                 let idx = job.unique_index.get(&t).copied().unwrap();
-                let v: &Vec<f32> = &job.unique_embeddings[idx];
-                Series::new(PlSmallStr::EMPTY, v.clone())
+                job.unique_embeddings[idx].as_ref().map(|v| Series::new(PlSmallStr::EMPTY, v.clone()))
             })
         });
         let mut lc: ListChunked = row_embeddings_iter.collect();
@@ -383,65 +561,118 @@ impl DataTableContainer {
     }
 
 
-    fn execute_pca(&mut self, source_column: &str, new_column_name: &str, target_k: usize) -> color_eyre::Result<()> {
+    /// Numeric vectors of a List(numeric) column, one per row, all of the same non-zero length
+    fn list_column_vectors(&self, source_column: &str) -> color_eyre::Result<Vec<Vec<f64>>> {
         use polars::prelude::*;
         let df_arc = self.datatable.get_dataframe()?;
-        let df_ref = df_arc.as_ref();
-        let s = df_ref.column(source_column).map_err(|e| color_eyre::eyre::eyre!("{}", e))?;
-        // Validate
-        match s.dtype() {
-            DataType::List(inner) => {
-                match inner.as_ref() {
-                    DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 |
-                    DataType::Int128 | DataType::UInt8 | DataType::UInt16 | DataType::UInt32 |
-                    DataType::UInt64 | DataType::Float32 | DataType::Float64 => {}
-                    _ => return Err(color_eyre::eyre::eyre!("Source column '{}' must be vector of numbers", source_column)),
-                }
-            }
-            _ => return Err(color_eyre::eyre::eyre!("Source column '{}' must be vector of numbers", source_column)),
+        let s = df_arc.column(source_column).map_err(|e| color_eyre::eyre::eyre!("{}", e))?;
+        if !matches!(s.dtype(), DataType::List(inner) if inner.is_primitive_numeric()) {
+            return Err(color_eyre::eyre::eyre!("Source column '{}' must be vector of numbers", source_column));
         }
-        let nrows = s.len();
-        if nrows == 0 { return Ok(()); }
         let list = s.list().map_err(|e| color_eyre::eyre::eyre!("{}", e))?;
-        let mut data: Vec<Vec<f64>> = Vec::with_capacity(nrows);
-        let mut d_opt: Option<usize> = None;
-        for i in 0..nrows {
+        let mut data: Vec<Vec<f64>> = Vec::with_capacity(s.len());
+        for i in 0..s.len() {
             let sub = list
                 .get_as_series(i)
                 .ok_or_else(|| color_eyre::eyre::eyre!("Row {} is null or missing in '{}'", i, source_column))?;
-            let sub_f64 = match sub.dtype() {
-                DataType::Float64 => sub.f64().unwrap().into_no_null_iter().collect::<Vec<f64>>(),
-                DataType::Float32 => sub.f32().unwrap().into_no_null_iter().map(|v| v as f64).collect::<Vec<f64>>(),
-                DataType::Int64 => sub.i64().unwrap().into_no_null_iter().map(|v| v as f64).collect::<Vec<f64>>(),
-                DataType::Int32 => sub.i32().unwrap().into_no_null_iter().map(|v| v as f64).collect::<Vec<f64>>(),
-                DataType::Int16 => sub.i16().unwrap().into_no_null_iter().map(|v| v as f64).collect::<Vec<f64>>(),
-                DataType::Int8  => sub.i8().unwrap().into_no_null_iter().map(|v| v as f64).collect::<Vec<f64>>(),
-                DataType::UInt64 => sub.u64().unwrap().into_no_null_iter().map(|v| v as f64).collect::<Vec<f64>>(),
-                DataType::UInt32 => sub.u32().unwrap().into_no_null_iter().map(|v| v as f64).collect::<Vec<f64>>(),
-                DataType::UInt16 => sub.u16().unwrap().into_no_null_iter().map(|v| v as f64).collect::<Vec<f64>>(),
-                DataType::UInt8  => sub.u8().unwrap().into_no_null_iter().map(|v| v as f64).collect::<Vec<f64>>(),
-                other => return Err(color_eyre::eyre::eyre!("Unsupported inner dtype in list: {:?}", other)),
-            };
-            if let Some(d) = d_opt { if sub_f64.len() != d { return Err(color_eyre::eyre::eyre!("Inconsistent vector length at row {}: expected {}, got {}", i, d, sub_f64.len())); } } else { d_opt = Some(sub_f64.len()); }
-            data.push(sub_f64);
-        }
-        let d = d_opt.unwrap_or(0);
-        if d == 0 { return Err(color_eyre::eyre::eyre!("Vectors have zero length")); }
-        let k = target_k.clamp(1, d);
-        // Build ndarray matrix (nrows x d)
-        let mut x = Array2::<f64>::zeros((nrows, d));
-        for i in 0..nrows { for j in 0..d { x[[i, j]] = data[i][j]; } }
-        // Fit PCA with linfa on a dataset wrapper
-        let ds = linfa::DatasetBase::from(x);
-        let pca = LinfaPca::params(k).fit(&ds).map_err(|e| color_eyre::eyre::eyre!("PCA fit failed: {:?}", e))?;
-        let y_ds = pca.predict(ds);
-        let y: ArrayBase<OwnedRepr<f64>, Ix2> = y_ds.records;
-        // Build new column
-        let row_iter = (0..nrows).map(|i| {
-            let row_vals: Vec<f32> = (0..k).map(|j| y[(i, j)] as f32).collect();
-            Some(Series::new(PlSmallStr::EMPTY, row_vals))
-        });
-        let mut lc: ListChunked = row_iter.collect();
+            let sub_f64 = sub.cast(&DataType::Float64).map_err(|e| color_eyre::eyre::eyre!("{}", e))?;
+            let row: Vec<f64> = sub_f64.f64().map_err(|e| color_eyre::eyre::eyre!("{}", e))?.into_no_null_iter().collect();
+            if let Some(first) = data.first()
+                && row.len() != first.len() {
+                    return Err(color_eyre::eyre::eyre!("Inconsistent vector length at row {}: expected {}, got {}", i, first.len(), row.len()));
+                }
+            data.push(row);
+        }
+        if data.first().is_some_and(|row| row.is_empty()) {
+            return Err(color_eyre::eyre::eyre!("Vectors have zero length"));
+        }
+        Ok(data)
+    }
+
+    /// Extract the source vectors and run PCA on a worker thread
+    fn start_pca_job(&mut self, queued: QueuedPca) -> color_eyre::Result<()> {
+        let data = self.list_column_vectors(&queued.source_column)?;
+        self.running_job = Some(BackgroundJob::spawn(false, move |_ctx| {
+            let rows = compute_pca(&data, queued.k)?;
+            Ok(ColumnJobOutput::Pca { source_column: queued.source_column, new_column_name: queued.new_column_name, rows })
+        }));
+        Ok(())
+    }
+
+    /// Extract the source vectors and run t-SNE on a worker thread, reporting per-iteration progress
+    fn start_tsne_job(&mut self, queued: QueuedTsne) -> color_eyre::Result<()> {
+        use crate::dataframe::tsne::{tsne_2d_with_progress, TsneParams};
+        let data = self.list_column_vectors(&queued.source_column)?;
+        let params = TsneParams { perplexity: queued.perplexity.max(1) as f64, iterations: queued.iterations, ..TsneParams::default() };
+        self.running_job = Some(BackgroundJob::spawn(true, move |ctx| {
+            let total = params.iterations.max(1) as f64;
+            let coords = tsne_2d_with_progress(&data, params, |iter| {
+                ctx.set_progress(iter as f64 / total);
+                !ctx.is_cancelled()
+            })?;
+            Ok(ColumnJobOutput::Tsne { source_column: queued.source_column, new_column_name: queued.new_column_name, coords })
+        }));
+        Ok(())
+    }
+
+    /// Extract the source vectors and cluster them on a worker thread
+    fn start_cluster_job(&mut self, queued: QueuedCluster) -> color_eyre::Result<()> {
+        let data = self.list_column_vectors(&queued.source_column)?;
+        let sweep = queued.kmeans.as_ref().is_some_and(|o| o.elbow_sweep);
+        self.running_job = Some(BackgroundJob::spawn(sweep, move |ctx| {
+            let (labels, quality) = compute_clusters(&data, queued.algorithm, queued.kmeans, ctx)?;
+            Ok(ColumnJobOutput::Cluster { source_column: queued.source_column, new_column_name: queued.new_column_name, labels, quality })
+        }));
+        Ok(())
+    }
+
+    /// Look up the source column's distinct indicators with a threat-intel provider on a worker thread
+    fn start_threat_intel_job(&mut self, source_column: &str, new_column_name: &str, provider: ThreatIntelProvider) -> color_eyre::Result<()> {
+        let df_arc = self.datatable.get_dataframe()?;
+        let values: Vec<Option<String>> = df_arc
+            .column(source_column)
+            .map_err(|e| color_eyre::eyre::eyre!("{}", e))?
+            .str()
+            .map_err(|e| color_eyre::eyre::eyre!("{}", e))?
+            .into_iter()
+            .map(|v| v.map(str::to_string))
+            .collect();
+        let settings = self.config.threat_intel.clone();
+        let cache = EnrichmentCache::new(ThreatIntelConfig::cache_dir());
+        let source_column = source_column.to_string();
+        let new_column_name = new_column_name.to_string();
+        self.running_job = Some(BackgroundJob::spawn(true, move |ctx| {
+            let is_cancelled = || ctx.is_cancelled();
+            let (values, warnings) = enrich_values(provider, &settings, &cache, &values, &is_cancelled, |done| ctx.set_progress(done))?;
+            Ok(ColumnJobOutput::ThreatIntel { source_column, new_column_name, provider, values, warnings })
+        }));
+        Ok(())
+    }
+
+    fn append_threat_intel_column(&mut self, source_column: &str, new_column_name: &str, provider: ThreatIntelProvider, values: Vec<Option<String>>) -> color_eyre::Result<()> {
+        use polars::prelude::*;
+        let df_arc = self.datatable.get_dataframe()?;
+        let df_ref = df_arc.as_ref();
+        let suffix = provider.label().to_lowercase();
+        let mut new_name = if new_column_name.trim().is_empty() { format!("{source_column}_{suffix}") } else { new_column_name.to_string() };
+        if df_ref.get_column_names_owned().into_iter().any(|n| n.as_str() == new_name) { new_name = format!("{new_name}__{suffix}"); }
+        let mut cols: Vec<polars::prelude::Column> = df_ref.get_columns().to_vec();
+        cols.push(Series::new(new_name.as_str().into(), values).into_column());
+        let new_df = polars::prelude::DataFrame::new(cols)
+            .map_err(|e| color_eyre::eyre::eyre!("Failed to build DataFrame: {}", e))?;
+        self.datatable.dataframe.set_current_df(new_df);
+        Ok(())
+    }
+
+    fn append_pca_column(&mut self, source_column: &str, new_column_name: &str, rows: Vec<Vec<f32>>) -> color_eyre::Result<()> {
+        use polars::prelude::*;
+        if rows.is_empty() { return Ok(()); }
+        let df_arc = self.datatable.get_dataframe()?;
+        let df_ref = df_arc.as_ref();
+        let mut lc: ListChunked = rows
+            .into_iter()
+            .map(|row| Some(Series::new(PlSmallStr::EMPTY, row)))
+            .collect();
         let mut new_name = if new_column_name.trim().is_empty() { format!("{source_column}_pca") } else { new_column_name.to_string() };
         if df_ref.get_column_names_owned().into_iter().any(|n| n.as_str() == new_name) { new_name = format!("{new_name}__pca"); }
         lc.rename(PlSmallStr::from_str(&new_name));
@@ -455,74 +686,275 @@ impl DataTableContainer {
         Ok(())
     }
 
-    fn execute_cluster(
-        &mut self,
-        source_column: &str,
-        new_column_name: &str,
-        algorithm: ClusterAlgorithm,
-        kmeans: Option<KmeansOptions>,
-        _dbscan: Option<DbscanOptions>,
-    ) -> color_eyre::Result<()> {
+    fn append_tsne_columns(&mut self, source_column: &str, new_column_name: &str, coords: Vec<[f64; 2]>) -> color_eyre::Result<()> {
         use polars::prelude::*;
+        if coords.is_empty() { return Ok(()); }
         let df_arc = self.datatable.get_dataframe()?;
         let df_ref = df_arc.as_ref();
-        let s = df_ref.column(source_column).map_err(|e| color_eyre::eyre::eyre!("{}", e))?;
-        match s.dtype() {
-            DataType::List(inner) => {
-                match inner.as_ref() {
-                    DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 |
-                    DataType::Int128 | DataType::UInt8 | DataType::UInt16 | DataType::UInt32 |
-                    DataType::UInt64 | DataType::Float32 | DataType::Float64 => {}
-                    _ => return Err(color_eyre::eyre::eyre!("Source column '{}' must be vector of numbers", source_column)),
-                }
-            }
-            _ => return Err(color_eyre::eyre::eyre!("Source column '{}' must be vector of numbers", source_column)),
+        let base = if new_column_name.trim().is_empty() { format!("{source_column}_tsne") } else { new_column_name.to_string() };
+        let existing = df_ref.get_column_names_owned();
+        let unique_name = |name: String| if existing.iter().any(|n| n.as_str() == name) { format!("{name}__tsne") } else { name };
+        let x_name = unique_name(format!("{base}_x"));
+        let y_name = unique_name(format!("{base}_y"));
+        let xs: Vec<f64> = coords.iter().map(|c| c[0]).collect();
+        let ys: Vec<f64> = coords.iter().map(|c| c[1]).collect();
+        let mut cols: Vec<polars::prelude::Column> = df_ref.get_columns().to_vec();
+        cols.push(Series::new(PlSmallStr::from_str(&x_name), xs).into_column());
+        cols.push(Series::new(PlSmallStr::from_str(&y_name), ys).into_column());
+        let new_df = polars::prelude::DataFrame::new(cols)
+            .map_err(|e| color_eyre::eyre::eyre!("Failed to build DataFrame: {}", e))?;
+        self.datatable.dataframe.set_current_df(new_df);
+        Ok(())
+    }
+
+    /// Add one String column per capture group of `pattern`, extracted from `source_column`.
+    ///
+    /// New columns are named `{prefix}_{group}` when a prefix is given, otherwise by group name
+    /// (unnamed groups fall back to `{source_column}_{index}`).
+    fn apply_regex_extract(&mut self, source_column: &str, prefix: &str, pattern: &str) -> color_eyre::Result<()> {
+        use polars::prelude::*;
+        let re = regex::Regex::new(pattern).map_err(|e| color_eyre::eyre::eyre!("Invalid regex: {}", e))?;
+        if re.captures_len() < 2 {
+            return Err(color_eyre::eyre::eyre!("Pattern has no capture groups"));
         }
-        let nrows = s.len();
-        if nrows == 0 { return Ok(()); }
-        let list = s.list().map_err(|e| color_eyre::eyre::eyre!("{}", e))?;
-        let mut data: Vec<Vec<f64>> = Vec::with_capacity(nrows);
-        let mut d_opt: Option<usize> = None;
-        for i in 0..nrows {
-            let sub = list
-                .get_as_series(i)
-                .ok_or_else(|| color_eyre::eyre::eyre!("Row {} is null or missing in '{}'", i, source_column))?;
-            let sub_f64 = match sub.dtype() {
-                DataType::Float64 => sub.f64().unwrap().into_no_null_iter().collect::<Vec<f64>>(),
-                DataType::Float32 => sub.f32().unwrap().into_no_null_iter().map(|v| v as f64).collect::<Vec<f64>>(),
-                DataType::Int64 => sub.i64().unwrap().into_no_null_iter().map(|v| v as f64).collect::<Vec<f64>>(),
-                DataType::Int32 => sub.i32().unwrap().into_no_null_iter().map(|v| v as f64).collect::<Vec<f64>>(),
-                DataType::Int16 => sub.i16().unwrap().into_no_null_iter().map(|v| v as f64).collect::<Vec<f64>>(),
-                DataType::Int8  => sub.i8().unwrap().into_no_null_iter().map(|v| v as f64).collect::<Vec<f64>>(),
-                DataType::UInt64 => sub.u64().unwrap().into_no_null_iter().map(|v| v as f64).collect::<Vec<f64>>(),
-                DataType::UInt32 => sub.u32().unwrap().into_no_null_iter().map(|v| v as f64).collect::<Vec<f64>>(),
-                DataType::UInt16 => sub.u16().unwrap().into_no_null_iter().map(|v| v as f64).collect::<Vec<f64>>(),
-                DataType::UInt8  => sub.u8().unwrap().into_no_null_iter().map(|v| v as f64).collect::<Vec<f64>>(),
-                other => return Err(color_eyre::eyre::eyre!("Unsupported inner dtype in list: {:?}", other)),
+        let df_arc = self.datatable.get_dataframe()?;
+        let df_ref = df_arc.as_ref();
+        let extracted = df_ref
+            .clone()
+            .lazy()
+            .select([col(source_column).str().extract_groups(pattern)?.alias("__regex_groups")])
+            .collect()
+            .map_err(|e| color_eyre::eyre::eyre!("Regex extract failed: {}", e))?;
+        let groups = extracted
+            .column("__regex_groups")
+            .and_then(|c| c.struct_().cloned())
+            .map_err(|e| color_eyre::eyre::eyre!("{}", e))?;
+        let existing = df_ref.get_column_names_owned();
+        let mut cols: Vec<polars::prelude::Column> = df_ref.get_columns().to_vec();
+        for (idx, mut field) in groups.fields_as_series().into_iter().enumerate() {
+            let group = field.name().to_string();
+            let mut name = if !prefix.trim().is_empty() {
+                format!("{}_{group}", prefix.trim())
+            } else if re.capture_names().nth(idx + 1).flatten().is_some() {
+                group
+            } else {
+                format!("{source_column}_{group}")
             };
-            if let Some(d) = d_opt { if sub_f64.len() != d { return Err(color_eyre::eyre::eyre!("Inconsistent vector length at row {}: expected {}, got {}", i, d, sub_f64.len())); } } else { d_opt = Some(sub_f64.len()); }
-            data.push(sub_f64);
-        }
-        let d = d_opt.unwrap_or(0);
-        if d == 0 { return Err(color_eyre::eyre::eyre!("Vectors have zero length")); }
-        // ndarray features
-        let mut x = Array2::<f64>::zeros((nrows, d));
-        for i in 0..nrows { for j in 0..d { x[[i, j]] = data[i][j]; } }
-        // Build dataset for algorithms that expect DatasetBase
-        let ds = DatasetBase::from(x.clone());
-        // Run clustering
-        let labels: Vec<usize> = match algorithm {
-            ClusterAlgorithm::Kmeans => {
-                let k = kmeans.map(|o| o.number_of_clusters).unwrap_or(8);
-                let model = KMeans::params(k).fit(&ds).map_err(|e| color_eyre::eyre::eyre!("KMeans fit failed: {:?}", e))?;
-                let pred = model.predict(ds);
-                pred.targets.to_vec()
-            }
-            ClusterAlgorithm::Dbscan => {
-                // DBSCAN not available with current linfa_clustering API in this build; return error
-                return Err(color_eyre::eyre::eyre!("DBSCAN clustering is currently unsupported in this build"));
+            if existing.iter().any(|n| n.as_str() == name) { name = format!("{name}__regex"); }
+            field.rename(PlSmallStr::from_str(&name));
+            cols.push(field.into_column());
+        }
+        let new_df = polars::prelude::DataFrame::new(cols)
+            .map_err(|e| color_eyre::eyre::eyre!("Failed to build DataFrame: {}", e))?;
+        self.datatable.dataframe.set_current_df(new_df);
+        Ok(())
+    }
+
+    /// Add a Datetime (UTC, microseconds) column parsed from the text column `source_column`.
+    ///
+    /// Values that do not match the format (or the auto-detected one) become null.
+    fn apply_parse_datetime(&mut self, source_column: &str, new_column_name: &str, format: Option<&str>) -> color_eyre::Result<()> {
+        use polars::prelude::*;
+        use crate::dataframe::datetime_parse::parse_datetime_values;
+        let df_arc = self.datatable.get_dataframe()?;
+        let df_ref = df_arc.as_ref();
+        let s = df_ref.column(source_column).map_err(|e| color_eyre::eyre::eyre!("{}", e))?;
+        let values: Vec<Option<&str>> = s
+            .str()
+            .map_err(|e| color_eyre::eyre::eyre!("{}", e))?
+            .into_iter()
+            .collect();
+        let (parsed, used_format) = parse_datetime_values(&values, format)?;
+        debug!("Parsed '{}' as datetime with format '{}'", source_column, used_format);
+        let mut new_name = if new_column_name.trim().is_empty() { format!("{source_column}_datetime") } else { new_column_name.to_string() };
+        if df_ref.get_column_names_owned().into_iter().any(|n| n.as_str() == new_name) { new_name = format!("{new_name}__datetime"); }
+        let datetimes = Int64Chunked::from_iter_options(PlSmallStr::from_str(&new_name), parsed.into_iter())
+            .into_datetime(TimeUnit::Microseconds, Some(TimeZone::UTC));
+        let mut cols: Vec<polars::prelude::Column> = df_ref.get_columns().to_vec();
+        cols.push(datetimes.into_series().into_column());
+        let new_df = polars::prelude::DataFrame::new(cols)
+            .map_err(|e| color_eyre::eyre::eyre!("Failed to build DataFrame: {}", e))?;
+        self.datatable.dataframe.set_current_df(new_df);
+        Ok(())
+    }
+
+    /// Add a UTC Datetime column read from mixed timestamp representations; returns what was
+    /// recognised and which rows were not
+    fn apply_normalize_timestamps(&mut self, source_column: &str, new_column_name: &str) -> color_eyre::Result<NormalizeReport> {
+        use polars::prelude::*;
+        let df_arc = self.datatable.get_dataframe()?;
+        let df_ref = df_arc.as_ref();
+        let s = df_ref.column(source_column).map_err(|e| color_eyre::eyre::eyre!("{}", e))?;
+        let as_str = s.cast(&DataType::String).map_err(|e| color_eyre::eyre::eyre!("{}", e))?;
+        let values: Vec<Option<String>> = as_str
+            .str()
+            .map_err(|e| color_eyre::eyre::eyre!("{}", e))?
+            .into_iter()
+            .map(|v| v.map(str::to_string))
+            .collect();
+        let (parsed, report) = normalize_timestamps(&values);
+        if report.counts.is_empty() {
+            return Err(color_eyre::eyre::eyre!("No timestamps recognised in '{}'", source_column));
+        }
+        let mut new_name = if new_column_name.trim().is_empty() { format!("{source_column}_utc") } else { new_column_name.to_string() };
+        if df_ref.get_column_names_owned().into_iter().any(|n| n.as_str() == new_name) { new_name = format!("{new_name}__utc"); }
+        let datetimes = Int64Chunked::from_iter_options(PlSmallStr::from_str(&new_name), parsed.into_iter())
+            .into_datetime(TimeUnit::Microseconds, Some(TimeZone::UTC));
+        let mut cols: Vec<polars::prelude::Column> = df_ref.get_columns().to_vec();
+        cols.push(datetimes.into_series().into_column());
+        let new_df = polars::prelude::DataFrame::new(cols)
+            .map_err(|e| color_eyre::eyre::eyre!("Failed to build DataFrame: {}", e))?;
+        self.datatable.dataframe.set_current_df(new_df);
+        Ok(report)
+    }
+
+    /// Add a String column holding the hex digest of each value of a text or binary column
+    fn apply_hash_column(&mut self, source_column: &str, new_column_name: &str, algorithm: HashAlgorithm) -> color_eyre::Result<()> {
+        use polars::prelude::*;
+        let df_arc = self.datatable.get_dataframe()?;
+        let df_ref = df_arc.as_ref();
+        let s = df_ref.column(source_column).map_err(|e| color_eyre::eyre::eyre!("{}", e))?;
+        let digests: Vec<Option<String>> = match s.dtype() {
+            DataType::String => s
+                .str()
+                .map_err(|e| color_eyre::eyre::eyre!("{}", e))?
+                .into_iter()
+                .map(|v| v.map(|text| algorithm.hex_digest(text.as_bytes())))
+                .collect(),
+            DataType::Binary => s
+                .binary()
+                .map_err(|e| color_eyre::eyre::eyre!("{}", e))?
+                .into_iter()
+                .map(|v| v.map(|bytes| algorithm.hex_digest(bytes)))
+                .collect(),
+            other => return Err(color_eyre::eyre::eyre!("Cannot hash column '{}' of type {:?}", source_column, other)),
+        };
+        let suffix = algorithm.label().to_lowercase();
+        let mut new_name = if new_column_name.trim().is_empty() { format!("{source_column}_{suffix}") } else { new_column_name.to_string() };
+        if df_ref.get_column_names_owned().into_iter().any(|n| n.as_str() == new_name) { new_name = format!("{new_name}__{suffix}"); }
+        let mut cols: Vec<polars::prelude::Column> = df_ref.get_columns().to_vec();
+        cols.push(Series::new(PlSmallStr::from_str(&new_name), digests).into_column());
+        let new_df = polars::prelude::DataFrame::new(cols)
+            .map_err(|e| color_eyre::eyre::eyre!("Failed to build DataFrame: {}", e))?;
+        self.datatable.dataframe.set_current_df(new_df);
+        Ok(())
+    }
+
+    /// Add a String column computed by a plugin operation from each value of the source column
+    fn apply_plugin_operation(&mut self, source_column: &str, new_column_name: &str, plugin: &str, operation: &str) -> color_eyre::Result<()> {
+        let df_arc = self.datatable.get_dataframe()?;
+        let df_ref = df_arc.as_ref();
+        let s = df_ref.column(source_column).map_err(|e| color_eyre::eyre::eyre!("{}", e))?;
+        let mut new_name = if new_column_name.trim().is_empty() { format!("{source_column}_{operation}") } else { new_column_name.to_string() };
+        if df_ref.get_column_names_owned().into_iter().any(|n| n.as_str() == new_name) { new_name = format!("{new_name}__{operation}"); }
+        let column = crate::plugins::apply_operation(plugin, operation, s, &new_name)?;
+        let mut cols: Vec<polars::prelude::Column> = df_ref.get_columns().to_vec();
+        cols.push(column);
+        let new_df = polars::prelude::DataFrame::new(cols)
+            .map_err(|e| color_eyre::eyre::eyre!("Failed to build DataFrame: {}", e))?;
+        self.datatable.dataframe.set_current_df(new_df);
+        Ok(())
+    }
+
+    /// Append an outlier flag (or score) column for a numeric source column
+    /// Other loaded datasets as (table name, columns), sorted by name
+    fn lookup_datasets(&self) -> Vec<(String, Vec<String>)> {
+        let mut datasets: Vec<(String, Vec<String>)> = self
+            .available_datasets
+            .values()
+            .map(|d| {
+                let name = d.dataset.alias.clone().unwrap_or(d.dataset.name.clone());
+                let columns = d.dataframe.get_column_names_owned().into_iter().map(|c| c.to_string()).collect();
+                (name, columns)
+            })
+            .filter(|(name, _)| name != &self.sql_current_df_name)
+            .collect();
+        datasets.sort_by(|a, b| a.0.cmp(&b.0));
+        datasets
+    }
+
+    fn apply_lookup(&mut self, source_column: &str, prefix: &str, options: &LookupOptions) -> color_eyre::Result<()> {
+        let lookup_df = self
+            .available_datasets
+            .values()
+            .find(|d| d.dataset.alias.as_ref().unwrap_or(&d.dataset.name) == &options.dataset)
+            .map(|d| d.dataframe.clone())
+            .ok_or_else(|| color_eyre::eyre::eyre!("Dataset '{}' is not loaded", options.dataset))?;
+        let df_arc = self.datatable.get_dataframe()?;
+        let new_df = lookup_columns(df_arc.as_ref(), source_column, &lookup_df, options, prefix)?;
+        self.datatable.dataframe.set_current_df(new_df);
+        Ok(())
+    }
+
+    fn apply_outliers(&mut self, source_column: &str, new_column_name: &str, options: &OutlierOptions) -> color_eyre::Result<()> {
+        use polars::prelude::*;
+        let threshold = options.threshold_value()?;
+        let df_arc = self.datatable.get_dataframe()?;
+        let df_ref = df_arc.as_ref();
+        let s = df_ref.column(source_column).map_err(|e| color_eyre::eyre::eyre!("{}", e))?;
+        let as_f64 = s.cast(&DataType::Float64).map_err(|e| color_eyre::eyre::eyre!("{}", e))?;
+        let values: Vec<Option<f64>> = as_f64
+            .f64()
+            .map_err(|e| color_eyre::eyre::eyre!("{}", e))?
+            .into_iter()
+            .collect();
+        let scores = outlier_scores(&values, options.method);
+        if scores.iter().all(Option::is_none) {
+            return Err(color_eyre::eyre::eyre!("Column '{}' has no numeric values", source_column));
+        }
+        let suffix = match options.output { OutlierOutput::Flag => "outlier", OutlierOutput::Score => "outlier_score" };
+        let mut new_name = if new_column_name.trim().is_empty() { format!("{source_column}_{suffix}") } else { new_column_name.to_string() };
+        if df_ref.get_column_names_owned().into_iter().any(|n| n.as_str() == new_name) { new_name = format!("{new_name}__{suffix}"); }
+        let series = match options.output {
+            OutlierOutput::Flag => {
+                let flags: Vec<Option<bool>> = scores.iter().map(|v| v.map(|score| score > threshold)).collect();
+                Series::new(PlSmallStr::from_str(&new_name), flags)
             }
+            OutlierOutput::Score => Series::new(PlSmallStr::from_str(&new_name), scores),
         };
+        let mut cols: Vec<polars::prelude::Column> = df_ref.get_columns().to_vec();
+        cols.push(series.into_column());
+        let new_df = polars::prelude::DataFrame::new(cols)
+            .map_err(|e| color_eyre::eyre::eyre!("Failed to build DataFrame: {}", e))?;
+        self.datatable.dataframe.set_current_df(new_df);
+        if options.sort_by_score {
+            let sort_cols = vec![crate::dialog::sort_dialog::SortColumn::new(new_name, false)];
+            self.datatable.dataframe.sort_by_columns(&sort_cols)?;
+        }
+        Ok(())
+    }
+
+    /// Build a sanitized copy of the current DataFrame with PII redacted from the source column
+    /// (or every String column when `all_text_columns` is set)
+    fn build_redacted_df(&self, source_column: &str, options: &RedactionOptions) -> color_eyre::Result<polars::prelude::DataFrame> {
+        use polars::prelude::*;
+        let redactor = Redactor::new(options)?;
+        let df_arc = self.datatable.get_dataframe()?;
+        let df_ref = df_arc.as_ref();
+        let mut cols: Vec<polars::prelude::Column> = Vec::with_capacity(df_ref.width());
+        for c in df_ref.get_columns() {
+            let is_target = c.dtype() == &DataType::String
+                && (options.all_text_columns || c.name().as_str() == source_column);
+            if !is_target {
+                cols.push(c.clone());
+                continue;
+            }
+            let redacted: StringChunked = c
+                .str()
+                .map_err(|e| color_eyre::eyre::eyre!("{}", e))?
+                .into_iter()
+                .map(|v| v.map(|text| redactor.redact(text).into_owned()))
+                .collect();
+            cols.push(redacted.with_name(c.name().clone()).into_series().into_column());
+        }
+        polars::prelude::DataFrame::new(cols)
+            .map_err(|e| color_eyre::eyre::eyre!("Failed to build DataFrame: {}", e))
+    }
+
+    fn append_cluster_column(&mut self, source_column: &str, new_column_name: &str, labels: Vec<usize>) -> color_eyre::Result<()> {
+        use polars::prelude::*;
+        let df_arc = self.datatable.get_dataframe()?;
+        let df_ref = df_arc.as_ref();
         // Append labels as a new Int32 column
         let mut cols: Vec<polars::prelude::Column> = Vec::with_capacity(df_ref.width() + 1);
         for c in df_ref.get_columns() { cols.push(c.clone()); }
@@ -540,7 +972,355 @@ impl DataTableContainer {
         self.datatable.dataframe.set_current_df(new_df);
         Ok(())
     }
-    
+
+    /// Apply a finished worker result to the table on the UI thread
+    fn apply_job_output(&mut self, output: ColumnJobOutput) -> color_eyre::Result<Option<Action>> {
+        match output {
+            ColumnJobOutput::Pca { source_column, new_column_name, rows } => {
+                self.append_pca_column(&source_column, &new_column_name, rows)?;
+                self.record_pending_history_step();
+                Ok(Some(Action::SaveWorkspaceState))
+            }
+            ColumnJobOutput::Tsne { source_column, new_column_name, coords } => {
+                self.append_tsne_columns(&source_column, &new_column_name, coords)?;
+                self.record_pending_history_step();
+                Ok(Some(Action::SaveWorkspaceState))
+            }
+            ColumnJobOutput::Cluster { source_column, new_column_name, labels, quality } => {
+                let added_column = labels.is_some();
+                if let Some(labels) = labels {
+                    self.append_cluster_column(&source_column, &new_column_name, labels)?;
+                    self.record_pending_history_step();
+                }
+                self.pending_history_step = None;
+                if !quality.is_empty() {
+                    let mut report = ClusterReportDialog::new(source_column, quality);
+                    report.register_config_handler(self.config.clone())?;
+                    self.cluster_report_dialog = Some(report);
+                    self.cluster_report_dialog_active = true;
+                }
+                // A sweep leaves the table unchanged
+                Ok(added_column.then_some(Action::SaveWorkspaceState))
+            }
+            ColumnJobOutput::Embeddings { unique_embeddings, warnings } => {
+                if let Some(job) = &mut self.in_progress_embeddings {
+                    job.unique_embeddings = unique_embeddings;
+                }
+                self.finalize_embeddings_job()?;
+                self.record_pending_history_step();
+                if !warnings.is_empty() {
+                    let message = format!(
+                        "{} embedding batch(es) failed after retries; their rows were left null.\n\n{}",
+                        warnings.len(),
+                        warnings.join("\n")
+                    );
+                    let mut dialog = MessageDialog::with_title(message, "Embeddings Warnings");
+                    dialog.register_config_handler(self.config.clone())?;
+                    self.message_dialog = Some(dialog);
+                }
+                // If we initiated from prompt flow, reopen the prompt dialog now
+                if let Some(pending) = self.pending_prompt_flow.take() {
+                    let mapping = self.embedding_column_config_mapping.clone();
+                    let initial = pending.embeddings_column_name.clone();
+                    let mut dialog = crate::dialog::EmbeddingsPromptDialog::new_with_mapping(mapping, initial);
+                    dialog.register_config_handler(self.config.clone())?;
+                    // Restore prompt text and similarity new column name
+                    dialog.new_column_input.insert_str(&pending.similarity_new_column);
+                    dialog.new_column_name = dialog.new_column_input.lines().join("\n");
+                    dialog.prompt_input.insert_str(&pending.prompt_text);
+                    self.embeddings_prompt_dialog = Some(dialog);
+                    self.embeddings_prompt_dialog_active = true;
+                    return Ok(None);
+                }
+                Ok(Some(Action::SaveWorkspaceState))
+            }
+            ColumnJobOutput::ThreatIntel { source_column, new_column_name, provider, values, warnings } => {
+                self.append_threat_intel_column(&source_column, &new_column_name, provider, values)?;
+                self.record_pending_history_step();
+                if !warnings.is_empty() {
+                    let message = format!(
+                        "{} {} lookup(s) failed after retries; their rows were left null.\n\n{}",
+                        warnings.len(),
+                        provider.label(),
+                        warnings.join("\n")
+                    );
+                    let mut dialog = MessageDialog::with_title(message, "Threat Intel Warnings");
+                    dialog.register_config_handler(self.config.clone())?;
+                    self.message_dialog = Some(dialog);
+                }
+                Ok(Some(Action::SaveWorkspaceState))
+            }
+            output @ (ColumnJobOutput::View(_) | ColumnJobOutput::SpilledView(_)) => {
+                match output {
+                    ColumnJobOutput::SpilledView(spill) => self.datatable.dataframe.set_spilled_view(spill.scan()?, spill),
+                    ColumnJobOutput::View(df) => self.datatable.dataframe.set_result(df, self.config.spill_bytes())?,
+                    _ => unreachable!(),
+                }
+                let jmes_expression = match &self.pending_history_step {
+                    Some(TransformStep::Sort(columns)) => {
+                        self.datatable.dataframe.last_sort = Some(columns.clone());
+                        None
+                    }
+                    Some(TransformStep::Filter(filter)) => {
+                        self.datatable.dataframe.filter = Some(filter.clone());
+                        None
+                    }
+                    Some(TransformStep::Script { .. }) => {
+                        self.script_dialog_active = false;
+                        None
+                    }
+                    Some(TransformStep::JmesTransform { query, .. }) => Some(JmesExpression::Transform(query.clone())),
+                    Some(TransformStep::JmesAddColumns { pairs, .. }) => Some(JmesExpression::AddColumns(pairs.clone())),
+                    _ => None,
+                };
+                self.record_pending_history_step();
+                if let Some(expression) = jmes_expression {
+                    self.jmes_dialog.history.record(expression);
+                    self.jmes_dialog_active = false;
+                    // The tab manager stores the history in the project and saves the workspace
+                    return Ok(Some(Action::JmesHistoryChanged(self.jmes_dialog.history.clone())));
+                }
+                Ok(Some(Action::SaveWorkspaceState))
+            }
+            ColumnJobOutput::NewDataset { dataset_name, df } => {
+                self.script_dialog_active = false;
+                Ok(Some(Action::SqlDialogAppliedNewDataset { dataset_name, dataframe: Arc::new(df) }))
+            }
+        }
+    }
+
+    /// Run a Rhai script on a worker thread behind the busy overlay. `name` is the script's name
+    /// in the history when adding columns, or the dataset's name for a new dataset. The script
+    /// dialog stays open for errors; Esc stops the script between operations.
+    fn start_script_job(&mut self, source: String, target: ScriptTarget, name: String) -> color_eyre::Result<()> {
+        let df = self.datatable.get_dataframe()?;
+        let job = match target {
+            ScriptTarget::NewDataset => BackgroundJob::spawn(false, move |ctx| {
+                let ctx = ctx.clone();
+                let df = run_script_cancellable(&df, &source, target, move || ctx.is_cancelled())?;
+                Ok(ColumnJobOutput::NewDataset { dataset_name: name, df })
+            }),
+            ScriptTarget::AddColumns => {
+                self.pending_history_step = Some(TransformStep::Script { name, source: source.clone() });
+                BackgroundJob::spawn(false, move |ctx| {
+                    let ctx = ctx.clone();
+                    let df = run_script_cancellable(&df, &source, target, move || ctx.is_cancelled())?;
+                    Ok(ColumnJobOutput::View(df))
+                })
+            }
+        };
+        self.running_job = Some(job);
+        self.busy_message = "Running script...".to_string();
+        self.busy_active = true;
+        self.busy_progress = 0.0;
+        Ok(())
+    }
+
+    /// Sort, filter or evaluate JMESPath on a worker thread behind the busy overlay. The table
+    /// keeps its current view until the rows arrive, so cancelling leaves it unchanged. A running
+    /// sort cannot be interrupted; cancelling only stops waiting for it.
+    fn start_view_job(&mut self, step: TransformStep) -> color_eyre::Result<()> {
+        let job = match &step {
+            TransformStep::Sort(columns) => {
+                let plan = self.datatable.dataframe.sorted_plan(columns)?;
+                let spill = self.datatable.dataframe.spills_result(false, self.config.spill_bytes());
+                self.busy_message = "Sorting...".to_string();
+                BackgroundJob::spawn(false, move |_ctx| {
+                    if spill {
+                        let (_, spill) = sink_to_parquet(plan)?;
+                        return Ok(ColumnJobOutput::SpilledView(spill));
+                    }
+                    Ok(ColumnJobOutput::View(plan.collect()?))
+                })
+            }
+            TransformStep::Filter(filter) => {
+                self.datatable.dataframe.check_filter(filter)?;
+                let base = self.datatable.dataframe.df.clone();
+                let filter = filter.clone();
+                self.busy_message = "Filtering...".to_string();
+                if self.datatable.dataframe.spills_result(true, self.config.spill_bytes()) {
+                    // Streamed to disk batch by batch, so there is no chunked progress to report
+                    BackgroundJob::spawn(false, move |_ctx| {
+                        let (_, spill) = sink_to_parquet(filtered_plan(base, filter))?;
+                        Ok(ColumnJobOutput::SpilledView(spill))
+                    })
+                } else {
+                    BackgroundJob::spawn(true, move |ctx| {
+                        let df = filter_with_progress(base, &filter, |done| {
+                            ctx.set_progress(done);
+                            !ctx.is_cancelled()
+                        })?;
+                        Ok(ColumnJobOutput::View(df))
+                    })
+                }
+            }
+            TransformStep::JmesTransform { query, scope } => {
+                // Syntax errors are shown in the dialog before any rows are evaluated
+                self.jmes_runtime
+                    .compile(query)
+                    .map_err(|e| color_eyre::eyre::eyre!("JMESPath compile error: {}", e))?;
+                let source_df = self.get_source_df_for_scope(scope.clone())?;
+                let query = query.clone();
+                self.busy_message = "Evaluating JMESPath...".to_string();
+                BackgroundJob::spawn(true, move |ctx| {
+                    let df = Self::jmes_transform_df(&source_df, &query, |done| {
+                        ctx.set_progress(done);
+                        !ctx.is_cancelled()
+                    })?;
+                    Ok(ColumnJobOutput::View(df))
+                })
+            }
+            TransformStep::JmesAddColumns { pairs, scope } => {
+                for p in pairs {
+                    self.jmes_runtime
+                        .compile(&p.value)
+                        .map_err(|e| color_eyre::eyre::eyre!("JMESPath compile error for '{}': {}", p.name, e))?;
+                }
+                let source_df = self.get_source_df_for_scope(scope.clone())?;
+                let pairs = pairs.clone();
+                self.busy_message = "Evaluating JMESPath...".to_string();
+                BackgroundJob::spawn(true, move |ctx| {
+                    let df = Self::jmes_add_columns_df(&source_df, &pairs, |done| {
+                        ctx.set_progress(done);
+                        !ctx.is_cancelled()
+                    })?;
+                    Ok(ColumnJobOutput::View(df))
+                })
+            }
+            _ => return Err(color_eyre::eyre::eyre!("Only sort, filter and JMESPath steps run in the background")),
+        };
+        self.running_job = Some(job);
+        self.pending_history_step = Some(step);
+        self.busy_active = true;
+        self.busy_progress = 0.0;
+        Ok(())
+    }
+
+    /// The current view without the tab filter. A filter starts from the original rows, so the
+    /// steps recorded since it was applied are run again over all of them.
+    fn unfiltered_view(&mut self) -> color_eyre::Result<polars::prelude::DataFrame> {
+        if self.datatable.dataframe.filter.is_none() {
+            return Ok(self.datatable.get_dataframe()?.as_ref().clone());
+        }
+        let steps = self.datatable.dataframe.history.steps();
+        let since_filter = steps.iter().rposition(|s| matches!(s, TransformStep::Filter(_))).map_or(0, |i| i + 1);
+        let saved = self.datatable.dataframe.clone();
+        self.datatable.reset_current_df();
+        let rebuilt = steps[since_filter..].iter().try_for_each(|step| {
+            if self.apply_transform_step(step)? {
+                Ok(())
+            } else {
+                Err(color_eyre::eyre::eyre!("'{}' was applied after the filter and cannot be run again over the other rows", step.summary()))
+            }
+        });
+        let unfiltered = rebuilt.and_then(|()| self.datatable.get_dataframe());
+        self.datatable.dataframe = saved;
+        Ok(unfiltered?.as_ref().clone())
+    }
+
+    /// Show the unfiltered view with `source_column` nulled outside the rows matching `filter`, so
+    /// a per-row operation only computes (and calls out for) those rows. The tab filter is lifted
+    /// because the new column covers every row.
+    fn mask_rows_for_operation(&mut self, source_column: &str, filter: &FilterExpr) -> color_eyre::Result<()> {
+        let (masked, source) = null_outside_filter(self.unfiltered_view()?, source_column, filter)?;
+        let previous = self.datatable.get_dataframe()?;
+        let previous_filter = self.datatable.dataframe.filter.take();
+        self.masked_operation = Some(MaskedOperation { source, previous, previous_filter });
+        self.datatable.dataframe.set_current_df(masked);
+        Ok(())
+    }
+
+    /// Put the source column back once a filtered-rows operation has added its column
+    fn restore_masked_source(&mut self) -> color_eyre::Result<()> {
+        let Some(masked) = self.masked_operation.take() else { return Ok(()) };
+        let mut df = self.datatable.get_dataframe()?.as_ref().clone();
+        let name = masked.source.name().to_string();
+        df.replace(&name, masked.source.take_materialized_series())?;
+        self.datatable.dataframe.set_current_df(df);
+        Ok(())
+    }
+
+    /// Go back to the view and filter from before a failed or cancelled filtered-rows operation
+    fn rollback_masked_operation(&mut self) {
+        if let Some(masked) = self.masked_operation.take() {
+            self.datatable.dataframe.set_current_arc(masked.previous);
+            self.datatable.dataframe.filter = masked.previous_filter;
+        }
+    }
+
+    fn record_pending_history_step(&mut self) {
+        if let Some(step) = self.pending_history_step.take() {
+            self.record_transform(step);
+        }
+    }
+
+    /// Apply the running job's result once its worker has finished
+    fn poll_running_job(&mut self) -> color_eyre::Result<Option<Action>> {
+        let Some(job) = &self.running_job else { return Ok(None) };
+        let Some(result) = job.try_finish() else { return Ok(None) };
+        self.running_job = None;
+        self.end_busy();
+        match result.and_then(|output| self.apply_job_output(output)) {
+            Ok(action) => {
+                self.restore_masked_source()?;
+                Ok(action)
+            }
+            Err(e) => {
+                self.rollback_masked_operation();
+                self.in_progress_embeddings = None;
+                self.pending_prompt_flow = None;
+                self.pending_history_step = None;
+                Ok(self.report_job_error(e))
+            }
+        }
+    }
+
+    /// Stop waiting for the running job (Esc on the busy overlay) and drop any queued work
+    fn cancel_running_job(&mut self) {
+        if let Some(job) = self.running_job.take() {
+            job.cancel();
+        }
+        if let Some(job) = self.in_progress_embeddings.take() {
+            self.embedding_column_config_mapping.remove(&job.new_column_name);
+        }
+        self.queued_embeddings = None;
+        self.queued_pca = None;
+        self.queued_tsne = None;
+        self.queued_cluster = None;
+        self.pending_prompt_flow = None;
+        self.pending_history_step = None;
+        self.rollback_masked_operation();
+        self.end_busy();
+    }
+
+    fn end_busy(&mut self) {
+        self.busy_active = false;
+        self.busy_message.clear();
+        self.busy_progress = 0.0;
+    }
+
+    /// Show a failed column operation in its options dialog, or as an app error if it has none
+    fn report_job_error(&mut self, e: color_eyre::Report) -> Option<Action> {
+        error!("Column operation failed: {}", e);
+        // The JMESPath dialog stays open while its expressions are evaluated
+        if self.jmes_dialog_active {
+            self.jmes_dialog.set_error(format!("{e}"));
+            return None;
+        }
+        if self.script_dialog_active
+            && let Some(dialog) = &mut self.script_dialog {
+                dialog.set_error(format!("{e}"));
+                return None;
+            }
+        if let Some(dialog) = &mut self.column_operation_options_dialog {
+            dialog.mode = ColumnOperationOptionsMode::Error(format!("{e}"));
+            self.column_operation_options_dialog_active = true;
+            return None;
+        }
+        self.error_dialog = Some(ErrorDialog::from_report(&e));
+        None
+    }
+
     /// Set the SQL statement for the SQL dialog
     pub fn set_sql_statement(&mut self, sql_statement: String) {
         self.sql_dialog.set_textarea_content(sql_statement);
@@ -552,6 +1332,22 @@ impl DataTableContainer {
         self.filter_dialog.set_root_expr(filter_expression);
     }
 
+    /// Append `filter` under the root of the current filter expression and return the result
+    fn append_filter_condition(&mut self, filter: ColumnFilter) -> FilterExpr {
+        let mut expr = self.datatable.dataframe.filter.clone().unwrap_or_else(|| FilterExpr::And(vec![]));
+        match &mut expr {
+            FilterExpr::And(children) | FilterExpr::Or(children) => {
+                children.push(FilterExpr::Condition(filter));
+            }
+            FilterExpr::Condition(_) => {
+                expr = FilterExpr::And(vec![expr, FilterExpr::Condition(filter)]);
+            }
+        }
+        // Update container and dialog state
+        self.set_filter_expression(expr.clone());
+        expr
+    }
+
     /// Create a new DataTableContainer with available DataFrames for SQL context.
     ///
     /// # Arguments
@@ -629,6 +1425,64 @@ impl DataTableContainer {
             find_dialog_active: false,
             find_all_results_dialog: None,
             find_all_results_dialog_active: false,
+            record_view_dialog: None,
+            record_view_dialog_active: false,
+            group_by_dialog: GroupByDialog::new(),
+            group_by_dialog_active: false,
+            group_view: None,
+            heatmap_dialog: None,
+            heatmap_dialog_active: false,
+            chart_dialog: None,
+            chart_dialog_active: false,
+            benford_dialog: None,
+            benford_dialog_active: false,
+            timeline: None,
+            display_settings_dialog: None,
+            display_settings_dialog_active: false,
+            hex_viewer_dialog: None,
+            hex_viewer_dialog_active: false,
+            ask_data_dialog: None,
+            ask_data_dialog_active: false,
+            transform_history_dialog: None,
+            transform_history_dialog_active: false,
+            checkpoints_dialog: None,
+            checkpoints_dialog_active: false,
+            sample_dialog: None,
+            sample_dialog_active: false,
+            resample_dialog: None,
+            resample_dialog_active: false,
+            crosstab_dialog: None,
+            crosstab_dialog_active: false,
+            sigma_dialog: None,
+            sigma_dialog_active: false,
+            pattern_set_dialog: None,
+            pattern_set_dialog_active: false,
+            row_tags_dialog: None,
+            row_tags_dialog_active: false,
+            validation_dialog: None,
+            validation_dialog_active: false,
+            column_menu_dialog: None,
+            column_menu_dialog_active: false,
+            go_to_row_dialog: None,
+            go_to_row_dialog_active: false,
+            go_to_column_dialog: None,
+            go_to_column_dialog_active: false,
+            findings_report_dialog: None,
+            findings_report_dialog_active: false,
+            missing_data_dialog: None,
+            missing_data_dialog_active: false,
+            script_dialog: None,
+            script_dialog_active: false,
+            saved_scripts: Vec::new(),
+            cluster_report_dialog: None,
+            cluster_report_dialog_active: false,
+            message_dialog: None,
+            error_dialog: None,
+            confirm_dialog: None,
+            cell_viewer_dialog: None,
+            cell_viewer_dialog_active: false,
+            open_target_dialog: None,
+            open_target_dialog_active: false,
             dataframe_details_dialog,
             dataframe_details_dialog_active: false,
             jmes_dialog,
@@ -646,6 +1500,8 @@ impl DataTableContainer {
             last_column_width_dialog_max_rows: None,
             last_find_dialog_area: None,
             last_find_all_results_dialog_area: None,
+            last_record_view_dialog_area: None,
+            last_group_by_dialog_area: None,
             last_dataframe_details_dialog_area: None,
             last_dataframe_details_dialog_max_rows: None,
             last_jmes_dialog_area: None,
@@ -654,6 +1510,7 @@ impl DataTableContainer {
             current_search_pattern: None,
             current_search_mode: None,
             current_search_options: None,
+            find_origin: None,
             available_datasets,
             busy_active: false,
             busy_message: String::new(),
@@ -661,7 +1518,11 @@ impl DataTableContainer {
             queued_embeddings: None,
             in_progress_embeddings: None,
             queued_pca: None,
+            queued_tsne: None,
             queued_cluster: None,
+            running_job: None,
+            pending_history_step: None,
+            masked_operation: None,
             llm_client_create_dialog: None,
             llm_client_create_dialog_active: false,
             last_llm_client_create_dialog_area: None,
@@ -670,16 +1531,802 @@ impl DataTableContainer {
             embeddings_prompt_dialog: None,
             embeddings_prompt_dialog_active: false,
             pending_prompt_flow: None,
+            sql_session: SqlSession::shared(),
+        }
+    }
+
+    /// Helper: return source DataFrame based on `TransformScope`.
+    fn get_source_df_for_scope(&self, scope: TransformScope) -> color_eyre::Result<polars::prelude::DataFrame> {
+        let source_df = match scope {
+            TransformScope::Original => self.datatable.dataframe.collect_base_df()?,
+            TransformScope::Current => self.datatable.get_dataframe()?.as_ref().clone(),
+        };
+        Ok(source_df)
+    }
+
+    /// Run a SQL statement in the workspace's session, with all loaded datasets registered as tables
+    fn execute_sql(&self, query: &str, row_limit: Option<usize>) -> color_eyre::Result<SqlOutput> {
+        let mut session = self.sql_session.lock().map_err(|_| color_eyre::eyre::eyre!("SQL session is unavailable"))?;
+        session.execute(query, &self.available_datasets, row_limit)
+    }
+
+    /// Stream the result of a query over tables larger than the spill threshold to disk; None
+    /// when it should run with `execute_sql`
+    fn spill_sql(&self, query: &str) -> color_eyre::Result<Option<(polars::prelude::LazyFrame, SpillFile)>> {
+        let mut session = self.sql_session.lock().map_err(|_| color_eyre::eyre::eyre!("SQL session is unavailable"))?;
+        session.spill_query(query, &self.available_datasets, self.config.spill_bytes())
+    }
+
+    /// Select the first match of `pattern` after the cell Find started from, or go back to
+    /// that cell when nothing matches; the outcome is shown in the Find dialog
+    fn incremental_find(&mut self, pattern: &str, options: &FindOptions, search_mode: &SearchMode) -> color_eyre::Result<()> {
+        let (origin_row, origin_col) = self.find_origin.unwrap_or((self.datatable.selection.row, self.datatable.selection.col));
+        self.datatable.selection.row = origin_row;
+        self.datatable.selection.col = origin_col;
+        let status = if pattern.is_empty() {
+            None
+        } else {
+            match self.datatable.find_next(pattern, options, search_mode) {
+                Ok(Some((row, col))) => {
+                    self.datatable.selection.row = row;
+                    self.datatable.selection.col = col;
+                    Some(format!("Match at row {}", row + 1))
+                }
+                Ok(None) => Some("No match".to_string()),
+                // Patterns are often incomplete regexes while being typed
+                Err(_) => Some("Incomplete pattern".to_string()),
+            }
+        };
+        self.datatable.scroll_to_selection()?;
+        self.current_search_pattern = status.as_ref().map(|_| pattern.to_string());
+        self.current_search_mode = Some(search_mode.clone());
+        self.current_search_options = Some(options.clone());
+        self.find_dialog.incremental_status = status;
+        Ok(())
+    }
+
+    /// Row limit for a query run from the SQL dialog; none when it was run with "fetch all"
+    fn interactive_sql_row_limit(&self) -> Option<usize> {
+        if self.sql_dialog.fetch_all { None } else { self.config.sql_row_limit }
+    }
+
+    /// Tell the user a SQL result was cut to the row limit and how to get every row
+    fn show_sql_row_limit_message(&mut self, rows: usize) -> color_eyre::Result<()> {
+        let key = self
+            .config
+            .key_for_action(Mode::SqlDialog, &Action::RunQueryFetchAll)
+            .unwrap_or_else(|| "Run (All Rows)".to_string());
+        let mut dialog = MessageDialog::with_title(
+            format!("The result was cut to its first {rows} rows (\"sql_row_limit\" in the config). Run the query with {key} in the SQL dialog to fetch every row."),
+            "Row Limit Reached",
+        );
+        dialog.register_config_handler(self.config.clone())?;
+        self.message_dialog = Some(dialog);
+        Ok(())
+    }
+
+    /// Show a failed query's error in the SQL dialog, pointing at the offending token and
+    /// suggesting known table and column names when one was not found
+    fn show_sql_error(&mut self, query: &str, error: &color_eyre::Report) {
+        let message = format!("{error}");
+        let mut tables = Vec::new();
+        let mut columns = self.datatable.dataframe.view_columns().unwrap_or_default();
+        for data_context in self.available_datasets.values() {
+            tables.push(data_context.dataset.alias.clone().unwrap_or(data_context.dataset.name.clone()));
+            columns.extend(data_context.dataframe.get_column_names().iter().map(|name| name.to_string()));
+        }
+        let diagnostic = diagnose(query, &message, &tables, &columns);
+        self.sql_dialog.set_query_error(message, diagnostic);
+    }
+
+    /// Filter the original data; the filter is kept on the dataframe for workspace capture
+    fn apply_filter(&mut self, filter: &FilterExpr) -> color_eyre::Result<()> {
+        self.datatable.dataframe.apply_filter(filter.clone())
+    }
+
+    /// Apply one history step to the table. Returns false for steps that cannot be replayed.
+    fn apply_transform_step(&mut self, step: &TransformStep) -> color_eyre::Result<bool> {
+        match step {
+            TransformStep::Filter(filter) => self.apply_filter(filter)?,
+            TransformStep::Sort(columns) => self.datatable.dataframe.sort_by_columns(columns)?,
+            TransformStep::Sql(query) => {
+                if let Some((scan, spill)) = self.spill_sql(query)? {
+                    self.datatable.dataframe.last_sql_query = Some(query.clone());
+                    self.datatable.dataframe.set_spilled_view(scan, spill);
+                // `sql_row_limit` only guards interactive previews; a replayed step gets every row
+                } else if let SqlOutput::Rows(new_df) | SqlOutput::Limited(new_df) = self.execute_sql(query, None)? {
+                    self.datatable.dataframe.last_sql_query = Some(query.clone());
+                    self.datatable.dataframe.set_result(new_df, self.config.spill_bytes())?;
+                }
+            }
+            TransformStep::JmesTransform { query, scope } => self.apply_jmes_transform(query, scope.clone())?,
+            TransformStep::JmesAddColumns { pairs, scope } => self.apply_jmes_add_columns(pairs.clone(), scope.clone())?,
+            TransformStep::ColumnOperation(cfg) => {
+                if let Some(filter) = &cfg.only_rows {
+                    self.mask_rows_for_operation(&cfg.source_column, filter)?;
+                }
+                let applied = self.apply_column_operation(cfg);
+                match applied {
+                    Ok(true) => self.restore_masked_source()?,
+                    _ => self.rollback_masked_operation(),
+                }
+                return applied;
+            }
+            TransformStep::Schema(edit) => self.apply_schema_edit(edit)?,
+            TransformStep::Script { source, .. } => self.apply_script(source)?,
+            TransformStep::Sigma(options) => {
+                self.apply_sigma(options)?;
+            }
+            TransformStep::PatternSet(options) => {
+                self.apply_pattern_set(options)?;
+            }
+            TransformStep::Reset => self.datatable.reset_current_df(),
+        }
+        Ok(true)
+    }
+
+    /// Apply a recorded column operation. Returns false for operations that cannot be replayed.
+    fn apply_column_operation(&mut self, cfg: &ColumnOperationConfig) -> color_eyre::Result<bool> {
+        match &cfg.options {
+            OperationOptions::RegexExtract { pattern } => {
+                self.apply_regex_extract(&cfg.source_column, &cfg.new_column_name, pattern)?
+            }
+            OperationOptions::ParseDatetime { format } => {
+                self.apply_parse_datetime(&cfg.source_column, &cfg.new_column_name, format.as_deref())?
+            }
+            OperationOptions::NormalizeTimestamps => {
+                self.apply_normalize_timestamps(&cfg.source_column, &cfg.new_column_name)?;
+            }
+            OperationOptions::Hash { algorithm } => {
+                self.apply_hash_column(&cfg.source_column, &cfg.new_column_name, *algorithm)?
+            }
+            OperationOptions::Outliers { outliers } => {
+                self.apply_outliers(&cfg.source_column, &cfg.new_column_name, outliers)?
+            }
+            OperationOptions::Plugin { plugin, operation } => {
+                self.apply_plugin_operation(&cfg.source_column, &cfg.new_column_name, plugin, operation)?
+            }
+            OperationOptions::Lookup { lookup } => {
+                self.apply_lookup(&cfg.source_column, &cfg.new_column_name, lookup)?
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Record a step the user just applied
+    fn record_transform(&mut self, step: TransformStep) {
+        self.datatable.dataframe.record_transform(step);
+    }
+
+    /// Ask before running `pending` unless the user turned off confirmations for `kind`.
+    /// Returns true when the confirm dialog was opened and `pending` should wait for it.
+    fn confirm_first(&mut self, kind: ConfirmKind, message: impl Into<String>, pending: Action) -> Result<bool> {
+        if !self.config.needs_confirmation(kind) {
+            return Ok(false);
+        }
+        let mut dialog = ConfirmDialog::new(kind, message, pending);
+        dialog.register_config_handler(self.config.clone())?;
+        self.confirm_dialog = Some(dialog);
+        Ok(true)
+    }
+
+    /// Run the operation a confirm dialog was opened for
+    fn run_confirmed(&mut self, pending: Action) -> Result<Option<Action>> {
+        match pending {
+            Action::SqlDialogApplied(query) => self.apply_sql_to_view(query),
+            Action::JmesTransformDataset((query, scope)) => self.apply_jmes_to_view(query, scope),
+            Action::DropMissingColumns => {
+                match self.missing_data_dialog.as_mut().and_then(|d| d.drop_flagged()) {
+                    Some(action @ Action::SqlDialogAppliedNewDataset { .. }) => {
+                        self.missing_data_dialog_active = false;
+                        Ok(Some(action))
+                    }
+                    _ => Ok(None),
+                }
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Replace the current view with the result of a SQL query
+    fn apply_sql_to_view(&mut self, query: String) -> Result<Option<Action>> {
+        // A result streamed to disk needs no row limit
+        match self.spill_sql(&query) {
+            Ok(Some((scan, spill))) => {
+                self.datatable.dataframe.last_sql_query = Some(query.clone());
+                self.datatable.dataframe.set_spilled_view(scan, spill);
+                self.record_transform(TransformStep::Sql(query));
+                self.sql_dialog_active = false;
+                return Ok(Some(Action::SaveWorkspaceState));
+            }
+            Ok(None) => {}
+            Err(e) => {
+                error!("{e}");
+                self.show_sql_error(&query, &e);
+                return Ok(None);
+            }
+        }
+        match self.execute_sql(&query, self.interactive_sql_row_limit()) {
+            Ok(SqlOutput::Message(message)) => {
+                // Views are kept by the session, not the tab; the view is left as it was
+                self.sql_dialog.set_notice(message);
+                Ok(Some(Action::SaveWorkspaceState))
+            }
+            Ok(output @ (SqlOutput::Rows(_) | SqlOutput::Limited(_))) => {
+                let (SqlOutput::Rows(new_df) | SqlOutput::Limited(new_df)) = output.clone() else { unreachable!() };
+                if matches!(output, SqlOutput::Limited(_)) {
+                    self.show_sql_row_limit_message(new_df.height())?;
+                }
+                // record last sql
+                self.datatable.dataframe.last_sql_query = Some(query.clone());
+                if let Err(e) = self.datatable.dataframe.set_result(new_df, self.config.spill_bytes()) {
+                    self.sql_dialog.set_error(format!("{e}"));
+                    return Ok(None);
+                }
+                self.record_transform(TransformStep::Sql(query));
+                self.sql_dialog_active = false;
+                // Signal to persist workspace state
+                Ok(Some(Action::SaveWorkspaceState))
+            }
+            Err(e) => {
+                error!("{e}");
+                self.show_sql_error(&query, &e);
+                Ok(None)
+            }
+        }
+    }
+
+    /// Replace the current view with the result of a JMESPath transform, evaluated in the background
+    fn apply_jmes_to_view(&mut self, query: String, scope: TransformScope) -> Result<Option<Action>> {
+        if let Err(e) = self.start_view_job(TransformStep::JmesTransform { query, scope }) {
+            self.jmes_dialog.set_error(format!("{e}"));
+        }
+        Ok(None)
+    }
+
+    /// Rebuild the view from the original data by applying `steps` in order.
+    ///
+    /// The history is replaced by the replayed steps. Returns the summaries of steps that were
+    /// skipped because they cannot be replayed; stops at the first step that fails.
+    pub fn replay_history(&mut self, steps: Vec<TransformStep>) -> color_eyre::Result<Vec<String>> {
+        self.datatable.reset_current_df();
+        self.datatable.dataframe.filter = None;
+        self.datatable.dataframe.last_sort = None;
+        self.datatable.dataframe.last_sql_query = None;
+        self.datatable.dataframe.history.clear();
+        let mut skipped = Vec::new();
+        for (i, step) in steps.into_iter().enumerate() {
+            let applied = self
+                .apply_transform_step(&step)
+                .map_err(|e| color_eyre::eyre::eyre!("Step {} ({}) failed: {e}", i + 1, step.kind_name()))?;
+            if applied {
+                self.record_transform(step);
+            } else {
+                skipped.push(format!("{}: {}", step.kind_name(), step.summary()));
+            }
+        }
+        Ok(skipped)
+    }
+
+    /// Sort by `columns` from a quick sort, keeping the sort dialog in step. An empty list
+    /// removes the sort: when the view only went through sorts and filters it is rebuilt without
+    /// the sorts, otherwise the rows keep their current order.
+    fn quick_sort(&mut self, columns: Vec<SortColumn>) -> Result<Option<Action>> {
+        self.sort_dialog.sort_columns = columns.clone();
+        self.sort_dialog.active_index = columns.len().saturating_sub(1);
+        self.sort_dialog.mode = SortDialogMode::List;
+        if !columns.is_empty() {
+            self.start_view_job(TransformStep::Sort(columns))?;
+            return Ok(None);
+        }
+        let steps = self.datatable.dataframe.history.steps();
+        if steps.iter().all(|s| matches!(s, TransformStep::Sort(_) | TransformStep::Filter(_))) {
+            let unsorted = steps.into_iter().filter(|s| !matches!(s, TransformStep::Sort(_))).collect();
+            self.replay_history(unsorted)?;
+        } else {
+            self.datatable.dataframe.last_sort = None;
+            let mut dialog = MessageDialog::with_title(
+                "The view was changed by other transforms, so the rows keep their current order. Replay the history without the sort (Transform History) to restore it.",
+                "Sort Removed",
+            );
+            dialog.register_config_handler(self.config.clone())?;
+            self.message_dialog = Some(dialog);
+        }
+        Ok(Some(Action::SaveWorkspaceState))
+    }
+
+    /// Replay requested from the history dialog; failures and skipped steps are shown in the dialog
+    fn handle_replay_transform_history(&mut self, steps: Vec<TransformStep>) -> color_eyre::Result<Option<Action>> {
+        let result = self.replay_history(steps);
+        let history = self.datatable.dataframe.history.clone();
+        let Some(dialog) = &mut self.transform_history_dialog else { return Ok(None) };
+        dialog.set_history(history);
+        match result {
+            Ok(skipped) if skipped.is_empty() => {
+                self.transform_history_dialog_active = false;
+            }
+            Ok(skipped) => {
+                dialog.set_notice(format!(
+                    "Replayed; {} step(s) need to be run again by hand:\n{}",
+                    skipped.len(),
+                    skipped.join("\n")
+                ));
+            }
+            Err(e) => dialog.set_notice(format!("{e}")),
+        }
+        Ok(Some(Action::SaveWorkspaceState))
+    }
+
+    /// Snapshot the current view (and the history that produced it) under `name`
+    pub fn create_checkpoint(&mut self, name: String) -> color_eyre::Result<()> {
+        let df = self.datatable.get_dataframe()?;
+        let history = self.datatable.dataframe.history.clone();
+        self.datatable.dataframe.checkpoints.push(Checkpoint::new(name, df, history));
+        Ok(())
+    }
+
+    /// Make a checkpoint the current view. Checkpoints loaded without data are rebuilt by replaying
+    /// their history, and the result is kept for later restores.
+    pub fn restore_checkpoint(&mut self, index: usize) -> color_eyre::Result<()> {
+        let checkpoint = self
+            .datatable
+            .dataframe
+            .checkpoints
+            .get(index)
+            .cloned()
+            .ok_or_else(|| color_eyre::eyre::eyre!("No checkpoint at index {}", index))?;
+        match checkpoint.dataframe {
+            Some(df) => {
+                self.datatable.dataframe.set_current_arc(df);
+                self.datatable.dataframe.history = checkpoint.history;
+            }
+            None => {
+                self.replay_history(checkpoint.history.steps())?;
+                let df = self.datatable.get_dataframe()?;
+                self.datatable.dataframe.checkpoints[index].dataframe = Some(df);
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_checkpoints_action(&mut self, action: Action) -> color_eyre::Result<Option<Action>> {
+        let Some(dialog) = &mut self.checkpoints_dialog else { return Ok(None) };
+        match action {
+            Action::DialogClose => {
+                self.checkpoints_dialog_active = false;
+                Ok(None)
+            }
+            Action::CreateCheckpoint(name) => {
+                self.create_checkpoint(name)?;
+                let checkpoints = self.datatable.dataframe.checkpoints.clone();
+                if let Some(dialog) = &mut self.checkpoints_dialog {
+                    dialog.set_checkpoints(checkpoints);
+                }
+                Ok(Some(Action::SaveWorkspaceState))
+            }
+            Action::RestoreCheckpoint(index) => {
+                self.checkpoints_dialog_active = false;
+                match self.restore_checkpoint(index) {
+                    Ok(()) => Ok(Some(Action::SaveWorkspaceState)),
+                    Err(e) => {
+                        self.error_dialog = Some(ErrorDialog::from_report(&e.wrap_err("Failed to restore checkpoint")));
+                        Ok(None)
+                    }
+                }
+            }
+            // Deleted or toggled in the dialog
+            Action::SaveWorkspaceState => {
+                self.datatable.dataframe.checkpoints = dialog.checkpoints.clone();
+                Ok(Some(Action::SaveWorkspaceState))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Open the row tag manager, or with `edit_row` go straight to tagging the selected row
+    fn open_row_tags_dialog(&mut self, edit_row: bool) -> color_eyre::Result<()> {
+        let mut dialog = RowTagsDialog::new(self.datatable.dataframe.row_tags.clone());
+        dialog.register_config_handler(self.config.clone())?;
+        if edit_row {
+            let df = self.datatable.get_dataframe()?;
+            dialog.edit_row(dialog.tags.get(&df, self.datatable.selection.row).cloned().as_ref());
+            dialog.edit_only = true;
+        }
+        self.row_tags_dialog = Some(dialog);
+        self.row_tags_dialog_active = true;
+        Ok(())
+    }
+
+    /// Recompute the tags column if it is shown
+    fn refresh_tags_column(&mut self) -> color_eyre::Result<()> {
+        let df = self.datatable.get_dataframe()?;
+        if df.column(TAGS_COLUMN).is_ok() {
+            let new_df = self.datatable.dataframe.row_tags.with_tags_column(&df)?;
+            self.datatable.dataframe.set_current_df(new_df);
+        }
+        Ok(())
+    }
+
+    fn handle_row_tags_action(&mut self, action: Action) -> color_eyre::Result<Option<Action>> {
+        let Some(dialog) = &mut self.row_tags_dialog else { return Ok(None) };
+        match action {
+            Action::DialogClose => {
+                self.row_tags_dialog_active = false;
+                Ok(None)
+            }
+            Action::TagSelectedRow => {
+                let df = self.datatable.get_dataframe()?;
+                dialog.edit_row(dialog.tags.get(&df, self.datatable.selection.row).cloned().as_ref());
+                Ok(None)
+            }
+            Action::RowTagApplied { labels, note } => {
+                let df = self.datatable.get_dataframe()?;
+                // Key on the dataset's own columns so derived columns do not change a row's identity
+                let key_columns: Vec<String> = self
+                    .datatable
+                    .dataframe
+                    .df
+                    .clone()
+                    .collect_schema()
+                    .map_err(|e| color_eyre::eyre::eyre!("Failed to read schema: {}", e))?
+                    .iter_names()
+                    .map(|name| name.to_string())
+                    .collect();
+                if let Err(e) = dialog.tags.set(&df, self.datatable.selection.row, &key_columns, labels, note) {
+                    dialog.error = Some(e.to_string());
+                    return Ok(None);
+                }
+                self.row_tags_dialog_active = false;
+                self.datatable.dataframe.row_tags = dialog.tags.clone();
+                self.refresh_tags_column()?;
+                Ok(Some(Action::SaveWorkspaceState))
+            }
+            Action::FilterByTag(label) => {
+                let df = self.datatable.get_dataframe()?;
+                let new_df = dialog.tags.filter_by_label(&df, &label)?;
+                self.row_tags_dialog_active = false;
+                self.datatable.dataframe.set_current_df(new_df);
+                Ok(None)
+            }
+            Action::ToggleTagsColumn => {
+                let df = self.datatable.get_dataframe()?;
+                let new_df = if df.column(TAGS_COLUMN).is_ok() {
+                    df.as_ref().drop(TAGS_COLUMN).map_err(|e| color_eyre::eyre::eyre!("Failed to drop tags column: {}", e))?
+                } else {
+                    dialog.tags.with_tags_column(&df)?
+                };
+                self.row_tags_dialog_active = false;
+                self.datatable.dataframe.set_current_df(new_df);
+                Ok(None)
+            }
+            // Renamed or deleted in the dialog
+            Action::SaveWorkspaceState => {
+                self.datatable.dataframe.row_tags = dialog.tags.clone();
+                self.refresh_tags_column()?;
+                Ok(Some(Action::SaveWorkspaceState))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Open the filter dialog on adding "equals the selected cell" for the selected column
+    fn open_quick_filter(&mut self) -> color_eyre::Result<()> {
+        let col_index = self.datatable.selection.col;
+        let df = self.datatable.get_dataframe()?;
+        let df = df.as_ref();
+        let columns: Vec<String> = df
+            .get_column_names_owned()
+            .into_iter()
+            .map(|s| s.to_string())
+            .collect();
+        self.filter_dialog.set_columns(columns, col_index);
+        let selected_value = self.datatable.selected_cell_value()?;
+        self.filter_dialog.add_value = selected_value.clone();
+        self.filter_dialog.add_condition = Some(FilterCondition::Equals { value: selected_value, case_sensitive: false });
+        let root_children = self.filter_dialog.get_root_expr().child_count();
+        self.filter_dialog.add_insertion_path = Some(vec![root_children]);
+        self.filter_dialog.mode = FilterDialogMode::Add;
+        self.filter_dialog_active = true;
+        Ok(())
+    }
+
+    /// Open DataFrame Details on the value counts of the selected column
+    fn open_value_counts(&mut self) -> color_eyre::Result<()> {
+        let column = self.selected_column_name()?;
+        let df_arc = self.datatable.get_dataframe()?;
+        let columns: Vec<String> = df_arc
+            .get_column_names_owned()
+            .into_iter()
+            .map(|s| s.to_string())
+            .collect();
+        let col_index = columns.iter().position(|c| c == &column).unwrap_or(0);
+        self.dataframe_details_dialog.set_columns(columns, col_index);
+        self.dataframe_details_dialog.set_dataframe(df_arc.clone());
+        self.dataframe_details_dialog.embedding_column_config_mapping = self.embedding_column_config_mapping.clone();
+        self.dataframe_details_dialog.show_value_counts();
+        self.dataframe_details_dialog_active = true;
+        Ok(())
+    }
+
+    fn open_column_menu(&mut self) -> color_eyre::Result<()> {
+        let column = self.selected_column_name()?;
+        if column.is_empty() {
+            return Ok(());
         }
+        let df = self.datatable.get_dataframe()?;
+        let dtype = df.column(&column).map(|c| format!("{:?}", c.dtype())).unwrap_or_default();
+        let pinned = self.datatable.dataframe.column_width_config.pinned_columns.contains(&column);
+        let wrapped = self.datatable.dataframe.column_width_config.wrapped_columns.contains(&column);
+        let grouped = self.datatable.dataframe.column_width_config.group_column.as_ref() == Some(&column);
+        let mut dialog = ColumnMenuDialog::new(column, dtype, self.datatable.selected_cell_value()?, pinned, wrapped, grouped);
+        dialog.register_config_handler(self.config.clone())?;
+        self.column_menu_dialog = Some(dialog);
+        self.column_menu_dialog_active = true;
+        Ok(())
     }
 
-    /// Helper: return source DataFrame based on `TransformScope`.
-    fn get_source_df_for_scope(&self, scope: TransformScope) -> color_eyre::Result<polars::prelude::DataFrame> {
-        let source_df = match scope {
-            TransformScope::Original => self.datatable.dataframe.collect_base_df()?,
-            TransformScope::Current => self.datatable.get_dataframe()?.as_ref().clone(),
+    /// Apply a schema edit changing one column of the view, recorded like one from the schema editor
+    fn edit_column_schema(&mut self, column: &str, change: impl FnOnce(&mut ColumnSchema)) -> color_eyre::Result<()> {
+        let df = self.datatable.get_dataframe()?;
+        let mut edit = SchemaEdit::from_frame(&df);
+        if let Some(spec) = edit.columns.iter_mut().find(|c| c.source == column) {
+            change(spec);
+        }
+        self.apply_schema_edit(&edit)?;
+        self.record_transform(TransformStep::Schema(edit));
+        Ok(())
+    }
+
+    fn handle_column_menu_action(&mut self, action: Action) -> color_eyre::Result<Option<Action>> {
+        self.column_menu_dialog_active = false;
+        let column = self.selected_column_name()?;
+        match action {
+            Action::SortSelectedColumn { ascending } => self.quick_sort(vec![SortColumn::new(column, ascending)]),
+            Action::QuickFilterEqualsCurrentValue => {
+                self.open_quick_filter()?;
+                Ok(None)
+            }
+            Action::OpenValueCounts => {
+                self.open_value_counts()?;
+                Ok(None)
+            }
+            Action::HideSelectedColumn => {
+                self.datatable.dataframe.column_width_config.hidden_columns.insert(column, true);
+                Ok(Some(Action::SaveWorkspaceState))
+            }
+            Action::TogglePinSelectedColumn => {
+                self.datatable.toggle_pinned_column(&column)?;
+                // The column moved; keep it selected
+                let visible_columns = self.datatable.get_visible_columns()?;
+                self.datatable.selection.col = visible_columns.iter().position(|c| c == &column).unwrap_or(0);
+                Ok(Some(Action::SaveWorkspaceState))
+            }
+            Action::ToggleGroupRowsBySelectedColumn => {
+                self.datatable.toggle_group_column(&column);
+                Ok(Some(Action::SaveWorkspaceState))
+            }
+            Action::ToggleWrapSelectedColumn => {
+                self.datatable.toggle_wrapped_column(&column);
+                self.datatable.scroll_to_selection()?;
+                Ok(Some(Action::SaveWorkspaceState))
+            }
+            Action::ColumnCastRequested { column, dtype } => {
+                let result = self.edit_column_schema(&column, |spec| spec.dtype = Some(dtype));
+                self.finish_column_menu_edit(result)
+            }
+            Action::ColumnRenameRequested { column, name } => {
+                let result = self.edit_column_schema(&column, |spec| spec.name = name.clone());
+                if result.is_ok() {
+                    let config = &mut self.datatable.dataframe.column_width_config;
+                    for renamed in config.pinned_columns.iter_mut().chain(config.wrapped_columns.iter_mut()).filter(|c| **c == column) {
+                        *renamed = name.clone();
+                    }
+                    if config.group_column.as_ref() == Some(&column) {
+                        config.group_column = Some(name.clone());
+                    }
+                }
+                self.finish_column_menu_edit(result)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Keep the menu open with the error when a cast or rename failed
+    fn finish_column_menu_edit(&mut self, result: color_eyre::Result<()>) -> color_eyre::Result<Option<Action>> {
+        match result {
+            Ok(()) => Ok(Some(Action::SaveWorkspaceState)),
+            Err(e) => {
+                if let Some(dialog) = &mut self.column_menu_dialog {
+                    dialog.error = Some(e.to_string());
+                }
+                self.column_menu_dialog_active = true;
+                Ok(None)
+            }
+        }
+    }
+
+    fn open_validation_dialog(&mut self) -> color_eyre::Result<()> {
+        let df = self.datatable.get_dataframe()?;
+        let columns = df.get_column_names_owned().into_iter().map(|c| c.to_string()).collect();
+        let datasets = self.lookup_datasets().into_iter().map(|(name, _)| name).collect();
+        let mut dialog = ValidationDialog::new(self.datatable.dataframe.validation_rules.clone(), columns, datasets);
+        dialog.register_config_handler(self.config.clone())?;
+        self.validation_dialog = Some(dialog);
+        self.validation_dialog_active = true;
+        Ok(())
+    }
+
+    /// Run the dataset's validation rules over the current view and highlight the failing cells
+    fn run_validation(&mut self) -> color_eyre::Result<(Vec<usize>, polars::prelude::DataFrame)> {
+        let df = self.datatable.get_dataframe()?;
+        let rules = &self.datatable.dataframe.validation_rules;
+        let validator = CellValidator::compile(&df, rules, |name| {
+            self.available_datasets
+                .values()
+                .find(|d| d.dataset.alias.as_ref().unwrap_or(&d.dataset.name) == name)
+                .map(|d| d.dataframe.clone())
+        })?;
+        let (violations, counts) = validator.violations(&df, rules)?;
+        self.datatable.dataframe.validator = Some(Arc::new(validator));
+        Ok((counts, violations))
+    }
+
+    fn handle_validation_action(&mut self, action: Action) -> color_eyre::Result<Option<Action>> {
+        match action {
+            Action::DialogClose => {
+                self.validation_dialog_active = false;
+                Ok(None)
+            }
+            Action::RunValidation => {
+                let result = self.run_validation();
+                let Some(dialog) = &mut self.validation_dialog else { return Ok(None) };
+                match result {
+                    Ok((counts, violations)) => dialog.set_results(counts, Arc::new(violations)),
+                    Err(e) => dialog.error = Some(e.to_string()),
+                }
+                Ok(None)
+            }
+            Action::OpenViolationsDataset => {
+                let Some(violations) = self.validation_dialog.as_ref().and_then(|d| d.violations.clone()) else { return Ok(None) };
+                self.validation_dialog_active = false;
+                Ok(Some(Action::SqlDialogAppliedNewDataset {
+                    dataset_name: format!("{}_violations", self.datatable.dataframe.metadata.name),
+                    dataframe: violations,
+                }))
+            }
+            Action::ClearValidation => {
+                self.datatable.dataframe.validator = None;
+                Ok(None)
+            }
+            // Rules added, edited or deleted in the dialog; highlighting from older rules is dropped
+            Action::SaveWorkspaceState => {
+                let Some(dialog) = &self.validation_dialog else { return Ok(None) };
+                self.datatable.dataframe.validation_rules = dialog.rules.clone();
+                self.datatable.dataframe.validator = None;
+                Ok(Some(Action::SaveWorkspaceState))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Add the output columns of a Rhai script to the current data
+    fn apply_script(&mut self, source: &str) -> color_eyre::Result<()> {
+        let df = self.datatable.get_dataframe()?;
+        let new_df = run_script(&df, source, ScriptTarget::AddColumns)?;
+        self.datatable.dataframe.set_current_df(new_df);
+        Ok(())
+    }
+
+    /// Cast, rename and reorder columns of the current data
+    fn apply_schema_edit(&mut self, edit: &SchemaEdit) -> color_eyre::Result<()> {
+        let df = self.datatable.get_dataframe()?;
+        let new_df = edit.apply(&df)?;
+        self.datatable.dataframe.set_current_df(new_df);
+        Ok(())
+    }
+
+    /// Add the Sigma matches column to the current data
+    fn apply_sigma(&mut self, options: &SigmaOptions) -> color_eyre::Result<SigmaEvaluation> {
+        let df = self.datatable.get_dataframe()?;
+        let (new_df, evaluation) = run_sigma(&df, options)?;
+        self.datatable.dataframe.set_current_df(new_df);
+        Ok(evaluation)
+    }
+
+    /// Evaluate the rules from the Sigma dialog; failures stay in the dialog, results are summarised
+    /// in a message
+    fn run_sigma_rules(&mut self, options: SigmaOptions) -> color_eyre::Result<Option<Action>> {
+        let result = match options.output {
+            SigmaOutput::Column => self.apply_sigma(&options).map(|evaluation| (evaluation, None)),
+            SigmaOutput::Dataset => {
+                let df = self.datatable.get_dataframe()?;
+                run_sigma(&df, &options).map(|(new_df, evaluation)| (evaluation, Some(new_df)))
+            }
         };
-        Ok(source_df)
+        let (evaluation, new_dataset) = match result {
+            Ok(result) => result,
+            Err(e) => {
+                if let Some(dialog) = &mut self.sigma_dialog {
+                    dialog.error = Some(e.to_string());
+                }
+                return Ok(None);
+            }
+        };
+        self.sigma_dialog_active = false;
+        let mut dialog = MessageDialog::with_title(evaluation.to_string(), "Sigma Results");
+        dialog.register_config_handler(self.config.clone())?;
+        self.message_dialog = Some(dialog);
+        match new_dataset {
+            Some(new_df) => Ok(Some(Action::SqlDialogAppliedNewDataset {
+                dataset_name: format!("{}_sigma", self.datatable.dataframe.metadata.name),
+                dataframe: Arc::new(new_df),
+            })),
+            None => {
+                self.record_transform(TransformStep::Sigma(options));
+                Ok(Some(Action::SaveWorkspaceState))
+            }
+        }
+    }
+
+    /// Apply a pattern set to the current data, adding its matches column and/or filtering
+    fn apply_pattern_set(&mut self, options: &PatternSetOptions) -> color_eyre::Result<PatternSetSummary> {
+        let df = self.datatable.get_dataframe()?;
+        let (new_df, summary) = run_pattern_set(&df, options)?;
+        self.datatable.dataframe.set_current_df(new_df);
+        Ok(summary)
+    }
+
+    /// Run the pattern set from the dialog; failures stay in the dialog, hits are summarised in a
+    /// message
+    fn run_pattern_set_dialog(&mut self, options: PatternSetOptions) -> color_eyre::Result<Option<Action>> {
+        let summary = match self.apply_pattern_set(&options) {
+            Ok(summary) => summary,
+            Err(e) => {
+                if let Some(dialog) = &mut self.pattern_set_dialog {
+                    dialog.error = Some(e.to_string());
+                }
+                return Ok(None);
+            }
+        };
+        self.pattern_set_dialog_active = false;
+        let mut dialog = MessageDialog::with_title(summary.to_string(), "Pattern Set Results");
+        dialog.register_config_handler(self.config.clone())?;
+        self.message_dialog = Some(dialog);
+        self.record_transform(TransformStep::PatternSet(options));
+        Ok(Some(Action::SaveWorkspaceState))
+    }
+
+    fn handle_script_action(&mut self, action: Action) -> color_eyre::Result<Option<Action>> {
+        match action {
+            Action::DialogClose => {
+                self.script_dialog_active = false;
+                Ok(None)
+            }
+            Action::ScriptDialogApplied { name, source } => {
+                self.start_script_job(source, ScriptTarget::AddColumns, name)?;
+                Ok(None)
+            }
+            Action::ScriptDialogAppliedNewDataset { dataset_name, source } => {
+                self.start_script_job(source, ScriptTarget::NewDataset, dataset_name)?;
+                Ok(None)
+            }
+            // Stored in the project by the tab manager
+            Action::ScriptSaved(script) => {
+                let mut scripts = self.saved_scripts.clone();
+                match scripts.iter_mut().find(|s| s.name == script.name) {
+                    Some(existing) => *existing = script.clone(),
+                    None => scripts.push(script.clone()),
+                }
+                self.set_saved_scripts(scripts);
+                Ok(Some(Action::ScriptSaved(script)))
+            }
+            Action::ScriptDeleted(name) => {
+                self.saved_scripts.retain(|s| s.name != name);
+                Ok(Some(Action::ScriptDeleted(name)))
+            }
+            _ => Ok(None),
+        }
     }
 
     /// Helper: get column names as owned `String`s.
@@ -691,30 +2338,6 @@ impl DataTableContainer {
             .collect()
     }
 
-    /// Helper: convert an optional Polars AnyValue into serde_json::Value for JMES input.
-    fn anyvalue_opt_to_json(v: Option<polars::prelude::AnyValue>) -> serde_json::Value {
-        match v {
-            Some(polars::prelude::AnyValue::Null) | None => serde_json::Value::Null,
-            Some(polars::prelude::AnyValue::Boolean(b)) => serde_json::Value::Bool(b),
-            Some(polars::prelude::AnyValue::Int8(x)) => serde_json::Value::Number((x as i64).into()),
-            Some(polars::prelude::AnyValue::Int16(x)) => serde_json::Value::Number((x as i64).into()),
-            Some(polars::prelude::AnyValue::Int32(x)) => serde_json::Value::Number((x as i64).into()),
-            Some(polars::prelude::AnyValue::Int64(x)) => serde_json::Value::Number(x.into()),
-            Some(polars::prelude::AnyValue::UInt8(x)) => serde_json::Value::Number((x as u64).into()),
-            Some(polars::prelude::AnyValue::UInt16(x)) => serde_json::Value::Number((x as u64).into()),
-            Some(polars::prelude::AnyValue::UInt32(x)) => serde_json::Value::Number((x as u64).into()),
-            Some(polars::prelude::AnyValue::UInt64(x)) => serde_json::Value::Number(serde_json::Number::from(x)),
-            Some(polars::prelude::AnyValue::Float32(f)) => {
-                if f.is_finite() { serde_json::Number::from_f64(f as f64).map(serde_json::Value::Number).unwrap_or(serde_json::Value::Null) } else { serde_json::Value::Null }
-            }
-            Some(polars::prelude::AnyValue::Float64(f)) => {
-                if f.is_finite() { serde_json::Number::from_f64(f).map(serde_json::Value::Number).unwrap_or(serde_json::Value::Null) } else { serde_json::Value::Null }
-            }
-            Some(polars::prelude::AnyValue::String(s)) => serde_json::Value::String(s.into()),
-            Some(other) => serde_json::Value::String(other.to_string()),
-        }
-    }
-
     /// Helper: convert optional AnyValue directly to display string.
     fn anyvalue_opt_to_string(v: Option<polars::prelude::AnyValue>) -> String {
         match v {
@@ -723,44 +2346,6 @@ impl DataTableContainer {
         }
     }
 
-    /// Helper: build a JSON object for a single row from a DataFrame.
-    fn build_row_object_json(
-        df: &polars::prelude::DataFrame,
-        col_names: &[String],
-        row_idx: usize,
-    ) -> serde_json::Map<String, serde_json::Value> {
-        let mut row_obj: serde_json::Map<String, serde_json::Value> = serde_json::Map::new();
-        for col in col_names {
-            let v = df.column(col)
-                .ok()
-                .and_then(|s| s.get(row_idx).ok());
-            let json_v = Self::anyvalue_opt_to_json(v);
-            row_obj.insert(col.clone(), json_v);
-        }
-        row_obj
-    }
-
-    /// Helper: ensure a JMES result is an object and convert it to a serde_json::Map.
-    fn jmes_result_to_object_map(
-        result: &jmespath::Variable,
-        row_idx: usize,
-    ) -> color_eyre::Result<serde_json::Map<String, serde_json::Value>> {
-        if !result.is_object() {
-            return Err(color_eyre::eyre::eyre!(
-                "JMESPath result must be an object; got non-object at row {}",
-                row_idx
-            ));
-        }
-        let json_str = result.to_string();
-        let json_val: serde_json::Value = serde_json::from_str(&json_str)
-            .map_err(|e| color_eyre::eyre::eyre!("Failed to parse JMES result as JSON at row {}: {}", row_idx, e))?;
-        let obj = json_val
-            .as_object()
-            .cloned()
-            .ok_or_else(|| color_eyre::eyre::eyre!("JMESPath result is not an object at row {}", row_idx))?;
-        Ok(obj)
-    }
-
     /// Helper: stringify a serde_json::Value with special-casing for Null.
     fn json_to_string(v: &serde_json::Value) -> String {
         match v {
@@ -846,50 +2431,31 @@ impl DataTableContainer {
     /// - When scope is `TransformScope::Original`, iterate the base DataFrame (not `current_df`).
     /// - The JMESPath query must return an object for each record; otherwise an error is returned.
     fn apply_jmes_transform(&mut self, query: &str, scope: TransformScope) -> color_eyre::Result<()> {
-        // Compile the JMESPath expression using stored custom runtime
-        let expr = self.jmes_runtime
-            .compile(query)
-            .map_err(|e| color_eyre::eyre::eyre!("JMESPath compile error: {}", e))?;
-
-        // Choose the source DataFrame per scope
         let source_df = self.get_source_df_for_scope(scope)?;
-
-        let nrows = source_df.height();
-        if nrows == 0 {
-            // Empty source -> empty result
-            self.datatable.set_current_df(polars::prelude::DataFrame::empty());
-            return Ok(());
-        }
-
-        // Prepare column names once
-        let col_names: Vec<String> = Self::get_column_names_vec(&source_df);
-
-        // Convert each row to a JSON object, run JMES, ensure object, collect results
-        let mut transformed_rows: Vec<JsonMap<String, JsonValue>> = Vec::with_capacity(nrows);
-        for row_idx in 0..nrows {
-            // Build input object for this row
-            let row_obj: JsonMap<String, JsonValue> = Self::build_row_object_json(&source_df, &col_names, row_idx);
-
-            // Evaluate JMESPath
-            let var = jmespath::Variable::try_from(JsonValue::Object(row_obj))
-                .map_err(|e| color_eyre::eyre::eyre!("Failed to convert row to JMES variable at row {}: {}", row_idx, e))?;
-            let result = expr
-                .search(&var)
-                .map_err(|e| color_eyre::eyre::eyre!("JMESPath search error at row {}: {}", row_idx, e))?;
-
-            // Ensure object result and convert
-            let obj = Self::jmes_result_to_object_map(&result, row_idx)?;
-            transformed_rows.push(obj);
-        }
-
-        // Build DataFrame from object rows
-        let new_df = Self::build_df_from_json_maps(&transformed_rows)?;
-
-        // Set as current view
+        let new_df = Self::jmes_transform_df(&source_df, query, |_| true)?;
         self.datatable.set_current_df(new_df);
         Ok(())
     }
 
+    /// Evaluate a JMESPath transform on every row of `source_df` in parallel; `on_progress` is
+    /// given the fraction of rows done and returns false to stop.
+    fn jmes_transform_df(
+        source_df: &polars::prelude::DataFrame,
+        query: &str,
+        on_progress: impl Fn(f64) -> bool + Sync,
+    ) -> color_eyre::Result<polars::prelude::DataFrame> {
+        let results = jmes_batch::evaluate(source_df, &[query.to_string()], on_progress)?;
+        let transformed_rows = results
+            .into_iter()
+            .enumerate()
+            .map(|(row_idx, mut values)| match values.pop() {
+                Some(JsonValue::Object(obj)) => Ok(obj),
+                _ => Err(color_eyre::eyre::eyre!("JMESPath result must be an object; got non-object at row {}", row_idx)),
+            })
+            .collect::<color_eyre::Result<Vec<JsonMap<String, JsonValue>>>>()?;
+        Self::build_df_from_json_maps(&transformed_rows)
+    }
+
     /// Add/merge columns using JMESPath expressions per provided key/value pairs.
     ///
     /// Behavior per pair:
@@ -901,78 +2467,59 @@ impl DataTableContainer {
     /// - Input scope determines whether Original (`df`) or Current (`current_df`) is used as source.
     /// - Output is a new DataFrame where all values are string-typed for consistency.
     fn apply_jmes_add_columns(&mut self, pairs: Vec<JmesPathKeyValuePair>, scope: TransformScope) -> color_eyre::Result<()> {
-        if pairs.is_empty() { return Ok(()); }
-
-        // Pre-compile expressions (with stored custom runtime)
-        let mut compiled = Vec::with_capacity(pairs.len());
-        for p in &pairs {
-            let expr = self.jmes_runtime
-                .compile(&p.value)
-                .map_err(|e| color_eyre::eyre::eyre!("JMESPath compile error for '{}': {}", p.name, e))?;
-            compiled.push((p.name.clone(), expr));
-        }
-
-        // Select source DataFrame
         let source_df = self.get_source_df_for_scope(scope)?;
+        let new_df = Self::jmes_add_columns_df(&source_df, &pairs, |_| true)?;
+        self.datatable.set_current_df(new_df);
+        Ok(())
+    }
 
-        let nrows = source_df.height();
-        if nrows == 0 {
-            return Ok(());
+    /// Evaluate add-column pairs on every row of `source_df` in parallel; `on_progress` is
+    /// given the fraction of rows done and returns false to stop.
+    fn jmes_add_columns_df(
+        source_df: &polars::prelude::DataFrame,
+        pairs: &[JmesPathKeyValuePair],
+        on_progress: impl Fn(f64) -> bool + Sync,
+    ) -> color_eyre::Result<polars::prelude::DataFrame> {
+        if pairs.is_empty() || source_df.height() == 0 {
+            return Ok(source_df.clone());
         }
+        let expressions: Vec<String> = pairs.iter().map(|p| p.value.clone()).collect();
+        let results = jmes_batch::evaluate(source_df, &expressions, on_progress)?;
 
-        let col_names: Vec<String> = Self::get_column_names_vec(&source_df);
+        let col_names: Vec<String> = Self::get_column_names_vec(source_df);
 
         // Per-row final field map (string values), initialized from original row string values
-        let mut row_maps: Vec<std::collections::BTreeMap<String, String>> = Vec::with_capacity(nrows);
-        row_maps.resize_with(nrows, std::collections::BTreeMap::new);
-
-        // Initialize with existing columns as strings
-        for (row_idx, map) in row_maps.iter_mut().enumerate().take(nrows) {
-            for col in &col_names {
-                let v = source_df.column(col).ok().and_then(|s| s.get(row_idx).ok());
-                map.insert(col.clone(), Self::anyvalue_opt_to_string(v));
-            }
-        }
-
-        // Apply expressions row-wise
-        for (row_idx, row_map) in row_maps.iter_mut().enumerate().take(nrows) {
-            // Build JSON input for this row
-            let row_obj: JsonMap<String, JsonValue> = Self::build_row_object_json(&source_df, &col_names, row_idx);
-            let var = jmespath::Variable::try_from(JsonValue::Object(row_obj))
-                .map_err(|e| color_eyre::eyre::eyre!("Failed to convert row to JMES variable at row {}: {}", row_idx, e))?;
-
-            // For each pair, evaluate and assign/merge
-            for (name, expr) in &compiled {
-                let result = expr
-                    .search(&var)
-                    .map_err(|e| color_eyre::eyre::eyre!("JMESPath search error at row {}: {}", row_idx, e))?;
-                if !name.is_empty() {
-                    // Assign to named column
-                    let s = if result.is_null() {
-                        String::new()
-                    } else {
-                        result.to_string()
-                    };
-                    row_map.insert(name.clone(), s);
-                } else {
-                    // Merge object or accept null
-                    if result.is_null() {
-                        continue;
-                    }
-                    // Parse object and merge keys
-                    let obj = Self::jmes_result_to_object_map(&result, row_idx)?;
-                    for (k, v) in obj {
-                        let s = Self::json_to_string(&v);
-                        row_map.insert(k.clone(), s);
+        let mut row_maps: Vec<std::collections::BTreeMap<String, String>> = vec![std::collections::BTreeMap::new(); source_df.height()];
+        for column in source_df.get_columns() {
+            let series = column.as_materialized_series().rechunk();
+            for (map, v) in row_maps.iter_mut().zip(series.iter()) {
+                map.insert(column.name().to_string(), Self::anyvalue_opt_to_string(Some(v)));
+            }
+        }
+
+        // Assign named results and merge unnamed object results
+        for (row_idx, (row_map, values)) in row_maps.iter_mut().zip(results).enumerate() {
+            for (pair, result) in pairs.iter().zip(values) {
+                if !pair.name.is_empty() {
+                    let s = if result.is_null() { String::new() } else { result.to_string() };
+                    row_map.insert(pair.name.clone(), s);
+                    continue;
+                }
+                match result {
+                    JsonValue::Null => {}
+                    JsonValue::Object(obj) => {
+                        for (k, v) in obj {
+                            let s = Self::json_to_string(&v);
+                            row_map.insert(k, s);
+                        }
                     }
+                    _ => return Err(color_eyre::eyre::eyre!("JMESPath result must be an object; got non-object at row {}", row_idx)),
                 }
             }
         }
 
         // Build DataFrame from per-row string maps, preserving original column order and appending new ones
-        let new_df = Self::build_df_from_string_maps_with_order(&row_maps, &col_names)?;
-        self.datatable.set_current_df(new_df);
-        Ok(())
+        Self::build_df_from_string_maps_with_order(&row_maps, &col_names)
     }
 
     /// Get the value of the currently selected cell as a string.
@@ -1121,98 +2668,537 @@ impl DataTableContainer {
                             }
                         }
                     }
-                    SearchMode::Regex => {
-                        // Regex search
-                        let re = if options.match_case {
-                            Regex::new(pattern)
+                    SearchMode::Regex => {
+                        // Regex search
+                        let re = if options.match_case {
+                            Regex::new(pattern)
+                        } else {
+                            Regex::new(&format!("(?i){pattern}"))
+                        };
+                        if let Ok(re) = re {
+                            let mut spans = Vec::new();
+                            let mut last_end = 0;
+                            let cell_value_safe = cell_value.replace("\n", "").replace("\r", "");
+                            for mat in re.find_iter(&cell_value_safe) {
+                                // Add text before match
+                                if mat.start() > last_end {
+                                    spans.push(Span::raw(cell_value_safe[last_end..mat.start()].to_string()));
+                                }
+                                // Add highlighted match
+                                spans.push(Span::styled(
+                                    cell_value_safe[mat.start()..mat.end()].to_string(),
+                                    ratatui::style::Style::default()
+                                        .fg(ratatui::style::Color::Black)
+                                        .bg(ratatui::style::Color::Yellow)
+                                        .add_modifier(ratatui::style::Modifier::BOLD)
+                                ));
+                                last_end = mat.end();
+                            }
+                            // Add remaining text
+                            if last_end < cell_value.len() {
+                                spans.push(Span::raw(cell_value_safe[last_end..].to_string()));
+                            }
+                            return Ok(Line::from(spans));
+                        }
+                    }
+                }
+            }
+        
+        // Apply RegexGroup styles if present
+        if !styled_ranges.is_empty() {
+            return Ok(Line::from(spans_from_styled_ranges(&cell_value, styled_ranges)));
+        }
+        
+        // No highlighting needed
+        Ok(Line::from(cell_value))
+    }
+
+    /// Get the name of the currently selected column as a string.
+    ///
+    /// Returns an empty string if the selection is out of bounds.
+    pub fn selected_column_name(&self) -> Result<String> {
+        let visible_columns = self.datatable.get_visible_columns()?;
+        let col = self.datatable.selection.col;
+        if col < visible_columns.len() {
+            Ok(visible_columns[col].clone())
+        } else {
+            Ok("".to_string())
+        }
+    }
+
+    /// Toggle the visibility of the instructions area.
+    pub fn toggle_instructions(&mut self) {
+        self.show_instructions = !self.show_instructions;
+    }
+
+    /// Options dialog for `op`, seeded with the compatible columns and the current selection
+    fn column_operation_options_dialog_for(&self, op: ColumnOperationKind) -> Result<ColumnOperationOptionsDialog> {
+        // Seed with filtered DF columns (only compatible types) and current selection
+        let df = self.datatable.get_dataframe()?;
+        let df_ref = df.as_ref();
+        let all_names: Vec<String> = df_ref
+            .get_column_names_owned()
+            .into_iter()
+            .map(|s| s.to_string())
+            .collect();
+        use polars::prelude::DataType;
+        let is_numeric = |dt: &DataType| matches!(
+            dt,
+            DataType::Int8
+                | DataType::Int16
+                | DataType::Int32
+                | DataType::Int64
+                | DataType::Int128
+                | DataType::UInt8
+                | DataType::UInt16
+                | DataType::UInt32
+                | DataType::UInt64
+                | DataType::Float32
+                | DataType::Float64
+        );
+        let filtered: Vec<String> = match op {
+            ColumnOperationKind::GenerateEmbeddings | ColumnOperationKind::RegexExtract | ColumnOperationKind::ParseDatetime | ColumnOperationKind::Redact | ColumnOperationKind::ThreatIntel => all_names
+                .into_iter()
+                .filter(|name| df_ref.column(name).ok().map(|s| s.dtype() == &DataType::String).unwrap_or(false))
+                .collect(),
+            ColumnOperationKind::Pca | ColumnOperationKind::Tsne | ColumnOperationKind::Cluster => all_names
+                .into_iter()
+                .filter(|name| {
+                    if let Ok(s) = df_ref.column(name) {
+                        match s.dtype() {
+                            DataType::List(inner) => is_numeric(inner.as_ref()),
+                            _ => false,
+                        }
+                    } else { false }
+                })
+                .collect(),
+            ColumnOperationKind::Hash => all_names
+                .into_iter()
+                .filter(|name| df_ref.column(name).ok().map(|s| matches!(s.dtype(), DataType::String | DataType::Binary)).unwrap_or(false))
+                .collect(),
+            ColumnOperationKind::Outliers => all_names
+                .into_iter()
+                .filter(|name| df_ref.column(name).ok().map(|s| is_numeric(s.dtype())).unwrap_or(false))
+                .collect(),
+            ColumnOperationKind::NormalizeTimestamps => all_names
+                .into_iter()
+                .filter(|name| df_ref.column(name).ok().map(|s| s.dtype() == &DataType::String || s.dtype().is_integer()).unwrap_or(false))
+                .collect(),
+            ColumnOperationKind::SortByPromptSimilarity => Vec::new(),
+            // Plugins take any column as text; lookup keys are compared as text
+            ColumnOperationKind::Plugin | ColumnOperationKind::Lookup => all_names,
+        };
+        // Compute initial selected index based on current table selection
+        let current_col_name = {
+            let visible_columns = self.datatable.get_visible_columns().unwrap_or_default();
+            let idx = self.datatable.selection.col.min(visible_columns.len().saturating_sub(1));
+            visible_columns.get(idx).cloned().unwrap_or_default()
+        };
+        let selected_idx = filtered.iter().position(|n| n == &current_col_name).unwrap_or(0);
+        let mut dialog = ColumnOperationOptionsDialog::new_with_columns(op, filtered, selected_idx);
+        dialog.register_config_handler(self.config.clone())?;
+        if dialog.columns.is_empty() {
+            dialog.mode = ColumnOperationOptionsMode::Error("No compatible columns found for this operation".to_string());
+        }
+        dialog.set_row_filter(self.datatable.dataframe.filter.clone());
+        if dialog.operation == ColumnOperationKind::Lookup {
+            dialog.set_lookup_datasets(self.lookup_datasets());
+            if dialog.lookup_datasets.is_empty() {
+                dialog.mode = ColumnOperationOptionsMode::Error("Load a second dataset to look up values from".to_string());
+            }
+        }
+        Ok(dialog)
+    }
+
+    /// Validate and run a configured column operation from the options dialog or a preset
+    fn run_column_operation(&mut self, cfg: ColumnOperationConfig) -> Result<Option<Action>> {
+        debug!("ColumnOperationOptionsApplied: {:?}", cfg);
+        // Validate source column dtype per operation requirements
+        let df_arc = self.datatable.get_dataframe()?;
+        let df_ref = df_arc.as_ref();
+        let dtype_opt = df_ref.column(&cfg.source_column).ok().map(|s| s.dtype().clone());
+        use polars::prelude::DataType;
+        let mut is_ok = false;
+        let mut err_msg = String::new();
+        if let Some(dtype) = dtype_opt {
+            match cfg.operation {
+                ColumnOperationKind::GenerateEmbeddings | ColumnOperationKind::RegexExtract | ColumnOperationKind::ParseDatetime | ColumnOperationKind::Redact | ColumnOperationKind::ThreatIntel => {
+                    is_ok = matches!(dtype, DataType::String);
+                    if !is_ok {
+                        err_msg = format!("Source column '{}' must be String", cfg.source_column);
+                        error!("{:?} error: {}", cfg.operation, err_msg);
+                    }
+                }
+                ColumnOperationKind::Hash => {
+                    is_ok = matches!(dtype, DataType::String | DataType::Binary);
+                    if !is_ok { err_msg = format!("Source column '{}' must be String or Binary", cfg.source_column); }
+                }
+                ColumnOperationKind::NormalizeTimestamps => {
+                    is_ok = matches!(dtype, DataType::String) || dtype.is_integer();
+                    if !is_ok { err_msg = format!("Source column '{}' must be String or integer", cfg.source_column); }
+                }
+                ColumnOperationKind::Outliers => {
+                    is_ok = dtype.is_primitive_numeric();
+                    if !is_ok { err_msg = format!("Source column '{}' must be numeric", cfg.source_column); }
+                }
+                ColumnOperationKind::Plugin => {
+                    is_ok = matches!(cfg.options, OperationOptions::Plugin { ref plugin, .. } if !plugin.is_empty());
+                    if !is_ok { err_msg = "No plugin operation selected".to_string(); }
+                }
+                ColumnOperationKind::Lookup => {
+                    is_ok = matches!(cfg.options, OperationOptions::Lookup { ref lookup } if !lookup.dataset.is_empty() && !lookup.key_column.is_empty());
+                    if !is_ok { err_msg = "Select a lookup dataset and key column".to_string(); }
+                }
+                ColumnOperationKind::Pca | ColumnOperationKind::Tsne | ColumnOperationKind::Cluster | ColumnOperationKind::SortByPromptSimilarity => {
+                    // Must be a vector of numbers: List(Numeric)
+                    let is_vec_num = matches!(
+                        dtype,
+                        DataType::List(inner)
+                            if matches!(*inner,
+                                DataType::Int8
+                                    | DataType::Int16
+                                    | DataType::Int32
+                                    | DataType::Int64
+                                    | DataType::Int128
+                                    | DataType::UInt8
+                                    | DataType::UInt16
+                                    | DataType::UInt32
+                                    | DataType::UInt64
+                                    | DataType::Float32
+                                    | DataType::Float64
+                            )
+                    );
+                    is_ok = is_vec_num;
+                    if !is_ok { err_msg = format!("Source column '{}' must be a vector of numbers", cfg.source_column); }
+                }
+            }
+        } else {
+            err_msg = format!("Source column '{}' not found", cfg.source_column);
+        }
+
+        if !is_ok {
+            if let Some(dialog) = &mut self.column_operation_options_dialog {
+                dialog.mode = ColumnOperationOptionsMode::Error(err_msg.clone());
+            }
+            error!("ColumnOperationOptionsApplied error: {}", &err_msg);
+            return Ok(None);
+        }
+
+        // Limited to the filtered rows: background operations run on the masked
+        // view; the others go through replay, which masks and restores around them
+        if let Some(filter) = &cfg.only_rows {
+            let background = matches!(cfg.operation, ColumnOperationKind::GenerateEmbeddings | ColumnOperationKind::ThreatIntel);
+            let result = if background {
+                self.mask_rows_for_operation(&cfg.source_column, filter)
+            } else {
+                self.apply_transform_step(&TransformStep::ColumnOperation(cfg.clone())).map(|_| ())
+            };
+            if let Err(e) = result {
+                if let Some(dialog) = &mut self.column_operation_options_dialog {
+                    dialog.mode = ColumnOperationOptionsMode::Error(format!("{e}"));
+                }
+                return Ok(None);
+            }
+            if !background {
+                self.record_transform(TransformStep::ColumnOperation(cfg.clone()));
+                self.column_operation_options_dialog_active = false;
+                return Ok(Some(Action::SaveWorkspaceState));
+            }
+        }
+
+        // Apply operation
+        match cfg.operation {
+            ColumnOperationKind::GenerateEmbeddings => {
+                debug!("ColumnOperationOptionsApplied: GenerateEmbeddings");
+                // Extract options
+                let (model_name, num_dims) = match &cfg.options {
+                    OperationOptions::GenerateEmbeddings {
+                        model_name,
+                        num_dimensions 
+                    } => (model_name.clone(), *num_dimensions),
+                    _ => ("text-embedding-3-small".to_string(), 0),
+                };
+                // Queue embeddings to show overlay first, then execute on Render
+                self.busy_active = true;
+                let provider = if let Some(dialog_ref) = &self.column_operation_options_dialog { dialog_ref.selected_provider.clone() } else { crate::dialog::LlmProvider::OpenAI };
+                self.busy_message = format!("Generating embeddings with {}...", provider.display_name());
+                self.busy_progress = 0.0;
+                // Snapshot provider config (non-secret fields) for reproducibility
+                let snapshot = EmbeddingColumnConfig {
+                    provider: provider.clone(),
+                    model_name: model_name.clone(),
+                    num_dimensions: num_dims
+                };
+                self.embedding_column_config_mapping.insert(cfg.new_column_name.clone(), snapshot);
+                self.pending_history_step = Some(TransformStep::ColumnOperation(cfg.clone()));
+                self.queued_embeddings = Some(QueuedEmbeddings {
+                    source_column: cfg.source_column.clone(),
+                    new_column_name: cfg.new_column_name.clone(),
+                    model_name,
+                    num_dimensions: num_dims,
+                    selected_provider: Some(provider),
+                    hide_new_column: cfg.hide_new_column,
+                });
+                // If prompt flow is pending, remember new embeddings column name
+                if let Some(ref mut pending) = self.pending_prompt_flow { pending.embeddings_column_name = Some(cfg.new_column_name.clone()); }
+                self.column_operation_options_dialog_active = false;
+                // Do not trigger Render immediately; allow one frame to draw the overlay first
+                Ok(None)
+            }
+            ColumnOperationKind::Pca => {
+                // Extract k
+                let k = match &cfg.options {
+                    OperationOptions::Pca { target_embedding_size } => *target_embedding_size,
+                    _ => 2,
+                };
+                self.busy_active = true;
+                self.busy_message = "Running PCA...".to_string();
+                self.busy_progress = 0.0;
+                self.pending_history_step = Some(TransformStep::ColumnOperation(cfg.clone()));
+                self.queued_pca = Some(QueuedPca {
+                    source_column: cfg.source_column.clone(),
+                    new_column_name: cfg.new_column_name.clone(),
+                    k,
+                });
+                self.column_operation_options_dialog_active = false;
+                Ok(None)
+            }
+            ColumnOperationKind::Tsne => {
+                let (perplexity, iterations) = match &cfg.options {
+                    OperationOptions::Tsne { perplexity, iterations } => (*perplexity, *iterations),
+                    _ => (30, 500),
+                };
+                self.busy_active = true;
+                self.busy_message = "Running t-SNE...".to_string();
+                self.busy_progress = 0.0;
+                self.pending_history_step = Some(TransformStep::ColumnOperation(cfg.clone()));
+                self.queued_tsne = Some(QueuedTsne {
+                    source_column: cfg.source_column.clone(),
+                    new_column_name: cfg.new_column_name.clone(),
+                    perplexity,
+                    iterations,
+                });
+                self.column_operation_options_dialog_active = false;
+                Ok(None)
+            }
+            ColumnOperationKind::Cluster => {
+                // Prepare dataset (nrows x dim) from List(Numeric) source
+                let (algo, kmeans_opts, dbscan_opts) = match &cfg.options {
+                    OperationOptions::Cluster { algorithm, kmeans, dbscan } => (algorithm.clone(), kmeans.clone(), dbscan.clone()),
+                    _ => (crate::dialog::ClusterAlgorithm::Kmeans, None, None),
+                };
+                self.busy_active = true;
+                self.busy_message = "Clustering...".to_string();
+                self.busy_progress = 0.0;
+                self.pending_history_step = Some(TransformStep::ColumnOperation(cfg.clone()));
+                self.queued_cluster = Some(QueuedCluster {
+                    source_column: cfg.source_column.clone(),
+                    new_column_name: cfg.new_column_name.clone(),
+                    algorithm: algo,
+                    kmeans: kmeans_opts,
+                    dbscan: dbscan_opts,
+                });
+                self.column_operation_options_dialog_active = false;
+                Ok(None)
+            }
+            ColumnOperationKind::RegexExtract => {
+                let pattern = match &cfg.options {
+                    OperationOptions::RegexExtract { pattern } => pattern.clone(),
+                    _ => String::new(),
+                };
+                match self.apply_regex_extract(&cfg.source_column, &cfg.new_column_name, &pattern) {
+                    Ok(()) => {
+                        self.record_transform(TransformStep::ColumnOperation(cfg.clone()));
+                        self.column_operation_options_dialog_active = false;
+                        Ok(Some(Action::SaveWorkspaceState))
+                    }
+                    Err(e) => {
+                        if let Some(dialog) = &mut self.column_operation_options_dialog {
+                            dialog.mode = ColumnOperationOptionsMode::Error(format!("{e}"));
+                        }
+                        Ok(None)
+                    }
+                }
+            }
+            ColumnOperationKind::ParseDatetime => {
+                let format = match &cfg.options {
+                    OperationOptions::ParseDatetime { format } => format.clone(),
+                    _ => None,
+                };
+                match self.apply_parse_datetime(&cfg.source_column, &cfg.new_column_name, format.as_deref()) {
+                    Ok(()) => {
+                        self.record_transform(TransformStep::ColumnOperation(cfg.clone()));
+                        self.column_operation_options_dialog_active = false;
+                        Ok(Some(Action::SaveWorkspaceState))
+                    }
+                    Err(e) => {
+                        if let Some(dialog) = &mut self.column_operation_options_dialog {
+                            dialog.mode = ColumnOperationOptionsMode::Error(format!("{e}"));
+                        }
+                        Ok(None)
+                    }
+                }
+            }
+            ColumnOperationKind::NormalizeTimestamps => {
+                match self.apply_normalize_timestamps(&cfg.source_column, &cfg.new_column_name) {
+                    Ok(report) => {
+                        self.record_transform(TransformStep::ColumnOperation(cfg.clone()));
+                        self.column_operation_options_dialog_active = false;
+                        let mut dialog = MessageDialog::with_title(report.to_string(), "Timestamp Normalization");
+                        dialog.register_config_handler(self.config.clone())?;
+                        self.message_dialog = Some(dialog);
+                        Ok(Some(Action::SaveWorkspaceState))
+                    }
+                    Err(e) => {
+                        if let Some(dialog) = &mut self.column_operation_options_dialog {
+                            dialog.mode = ColumnOperationOptionsMode::Error(format!("{e}"));
+                        }
+                        Ok(None)
+                    }
+                }
+            }
+            ColumnOperationKind::Hash => {
+                let algorithm = match &cfg.options {
+                    OperationOptions::Hash { algorithm } => *algorithm,
+                    _ => HashAlgorithm::default(),
+                };
+                match self.apply_hash_column(&cfg.source_column, &cfg.new_column_name, algorithm) {
+                    Ok(()) => {
+                        self.record_transform(TransformStep::ColumnOperation(cfg.clone()));
+                        self.column_operation_options_dialog_active = false;
+                        Ok(Some(Action::SaveWorkspaceState))
+                    }
+                    Err(e) => {
+                        if let Some(dialog) = &mut self.column_operation_options_dialog {
+                            dialog.mode = ColumnOperationOptionsMode::Error(format!("{e}"));
+                        }
+                        Ok(None)
+                    }
+                }
+            }
+            ColumnOperationKind::Outliers => {
+                let outliers = match &cfg.options {
+                    OperationOptions::Outliers { outliers } => outliers.clone(),
+                    _ => OutlierOptions::default(),
+                };
+                match self.apply_outliers(&cfg.source_column, &cfg.new_column_name, &outliers) {
+                    Ok(()) => {
+                        self.record_transform(TransformStep::ColumnOperation(cfg.clone()));
+                        self.column_operation_options_dialog_active = false;
+                        Ok(Some(Action::SaveWorkspaceState))
+                    }
+                    Err(e) => {
+                        if let Some(dialog) = &mut self.column_operation_options_dialog {
+                            dialog.mode = ColumnOperationOptionsMode::Error(format!("{e}"));
+                        }
+                        Ok(None)
+                    }
+                }
+            }
+            ColumnOperationKind::Redact => {
+                let redaction = match &cfg.options {
+                    OperationOptions::Redact { redaction } => redaction.clone(),
+                    _ => RedactionOptions::default(),
+                };
+                match self.build_redacted_df(&cfg.source_column, &redaction) {
+                    Ok(new_df) => {
+                        self.column_operation_options_dialog_active = false;
+                        let dataset_name = if cfg.new_column_name.trim().is_empty() {
+                            format!("{}_redacted", self.datatable.dataframe.metadata.name)
                         } else {
-                            Regex::new(&format!("(?i){pattern}"))
+                            cfg.new_column_name.trim().to_string()
                         };
-                        if let Ok(re) = re {
-                            let mut spans = Vec::new();
-                            let mut last_end = 0;
-                            let cell_value_safe = cell_value.replace("\n", "").replace("\r", "");
-                            for mat in re.find_iter(&cell_value_safe) {
-                                // Add text before match
-                                if mat.start() > last_end {
-                                    spans.push(Span::raw(cell_value_safe[last_end..mat.start()].to_string()));
-                                }
-                                // Add highlighted match
-                                spans.push(Span::styled(
-                                    cell_value_safe[mat.start()..mat.end()].to_string(),
-                                    ratatui::style::Style::default()
-                                        .fg(ratatui::style::Color::Black)
-                                        .bg(ratatui::style::Color::Yellow)
-                                        .add_modifier(ratatui::style::Modifier::BOLD)
-                                ));
-                                last_end = mat.end();
-                            }
-                            // Add remaining text
-                            if last_end < cell_value.len() {
-                                spans.push(Span::raw(cell_value_safe[last_end..].to_string()));
-                            }
-                            return Ok(Line::from(spans));
+                        // Sanitized copy opens as a new dataset tab; the original is untouched
+                        Ok(Some(Action::SqlDialogAppliedNewDataset {
+                            dataset_name,
+                            dataframe: Arc::new(new_df),
+                        }))
+                    }
+                    Err(e) => {
+                        if let Some(dialog) = &mut self.column_operation_options_dialog {
+                            dialog.mode = ColumnOperationOptionsMode::Error(format!("{e}"));
                         }
+                        Ok(None)
                     }
                 }
             }
-        
-        // Apply RegexGroup styles if present
-        if !styled_ranges.is_empty() {
-            // Sort ranges by start position
-            styled_ranges.sort_by_key(|(start, _, _)| *start);
-            
-            // Remove overlapping ranges (keep first one for overlaps)
-            let mut non_overlapping: Vec<(usize, usize, ratatui::style::Style)> = Vec::new();
-            for (start, end, style) in styled_ranges {
-                if non_overlapping.is_empty() || start >= non_overlapping.last().unwrap().1 {
-                    non_overlapping.push((start, end, style));
-                }
-            }
-            
-            // Build spans
-            let mut spans = Vec::new();
-            let mut last_end = 0;
-            
-            for (start, end, style) in non_overlapping {
-                // Add unstyled text before this match
-                if start > last_end {
-                    spans.push(Span::raw(cell_value[last_end..start].to_string()));
-                }
-                
-                // Add styled match
-                spans.push(Span::styled(cell_value[start..end].to_string(), style));
-                last_end = end;
-            }
-            
-            // Add remaining text after last match
-            if last_end < cell_value.len() {
-                spans.push(Span::raw(cell_value[last_end..].to_string()));
-            }
-            
-            return Ok(Line::from(spans));
+            ColumnOperationKind::ThreatIntel => {
+                let OperationOptions::ThreatIntel { provider } = cfg.options else { return Ok(None) };
+                if let Err(e) = self.start_threat_intel_job(&cfg.source_column, &cfg.new_column_name, provider) {
+                    self.rollback_masked_operation();
+                    if let Some(dialog) = &mut self.column_operation_options_dialog {
+                        dialog.mode = ColumnOperationOptionsMode::Error(format!("{e}"));
+                    }
+                    return Ok(None);
+                }
+                self.busy_active = true;
+                self.busy_message = format!("Enriching with {}...", provider.label());
+                self.busy_progress = 0.0;
+                self.pending_history_step = Some(TransformStep::ColumnOperation(cfg.clone()));
+                self.column_operation_options_dialog_active = false;
+                Ok(None)
+            }
+            ColumnOperationKind::Lookup => {
+                let OperationOptions::Lookup { lookup } = &cfg.options else { return Ok(None) };
+                match self.apply_lookup(&cfg.source_column, &cfg.new_column_name, lookup) {
+                    Ok(()) => {
+                        self.record_transform(TransformStep::ColumnOperation(cfg.clone()));
+                        self.column_operation_options_dialog_active = false;
+                        Ok(Some(Action::SaveWorkspaceState))
+                    }
+                    Err(e) => {
+                        if let Some(dialog) = &mut self.column_operation_options_dialog {
+                            dialog.mode = ColumnOperationOptionsMode::Error(format!("{e}"));
+                        }
+                        Ok(None)
+                    }
+                }
+            }
+            ColumnOperationKind::Plugin => {
+                let OperationOptions::Plugin { plugin, operation } = &cfg.options else { return Ok(None) };
+                match self.apply_plugin_operation(&cfg.source_column, &cfg.new_column_name, plugin, operation) {
+                    Ok(()) => {
+                        self.record_transform(TransformStep::ColumnOperation(cfg.clone()));
+                        self.column_operation_options_dialog_active = false;
+                        Ok(Some(Action::SaveWorkspaceState))
+                    }
+                    Err(e) => {
+                        if let Some(dialog) = &mut self.column_operation_options_dialog {
+                            dialog.mode = ColumnOperationOptionsMode::Error(format!("{e}"));
+                        }
+                        Ok(None)
+                    }
+                }
+            }
+            ColumnOperationKind::SortByPromptSimilarity => {
+                // Not applied via options dialog; handled by dedicated prompt dialog
+                self.column_operation_options_dialog_active = false;
+                Ok(None)
+            }
         }
-        
-        // No highlighting needed
-        Ok(Line::from(cell_value))
     }
 
-    /// Get the name of the currently selected column as a string.
-    ///
-    /// Returns an empty string if the selection is out of bounds.
-    pub fn selected_column_name(&self) -> Result<String> {
-        let visible_columns = self.datatable.get_visible_columns()?;
-        let col = self.datatable.selection.col;
-        if col < visible_columns.len() {
-            Ok(visible_columns[col].clone())
-        } else {
-            Ok("".to_string())
-        }
+    /// Recent and saved JMESPath expressions of the project
+    pub fn set_jmes_history(&mut self, history: JmesHistory) {
+        self.jmes_dialog.history = history;
     }
 
-    /// Toggle the visibility of the instructions area.
-    pub fn toggle_instructions(&mut self) {
-        self.show_instructions = !self.show_instructions;
+    /// Recent Find searches of the project
+    pub fn set_find_history(&mut self, history: Vec<FindHistoryEntry>) {
+        self.find_dialog.history = history;
+    }
+
+    /// Saved column operation presets of the project
+    pub fn set_column_operation_presets(&mut self, presets: Vec<ColumnOperationPreset>) {
+        self.column_operations_dialog.set_presets(presets);
+    }
+
+    /// Update the project's saved scripts
+    pub fn set_saved_scripts(&mut self, saved_scripts: Vec<SavedScript>) {
+        if let Some(dialog) = &mut self.script_dialog {
+            dialog.set_saved_scripts(saved_scripts.clone());
+        }
+        self.saved_scripts = saved_scripts;
     }
 
     /// Update the available DataFrames for SQL context.
@@ -1229,16 +3215,41 @@ impl DataTableContainer {
         self.config.actions_to_instructions(&[
             (Mode::DataTableContainer, Action::OpenSortDialog),
             (Mode::DataTableContainer, Action::QuickSortCurrentColumn),
+            (Mode::DataTableContainer, Action::QuickSortAppendCurrentColumn),
             (Mode::DataTableContainer, Action::OpenFilterDialog),
             (Mode::DataTableContainer, Action::QuickFilterEqualsCurrentValue),
             (Mode::DataTableContainer, Action::MoveSelectedColumnLeft),
             (Mode::DataTableContainer, Action::MoveSelectedColumnRight),
             // (Mode::DataTableContainer, Action::OpenDataExportDialog),
             (Mode::DataTableContainer, Action::OpenSqlDialog),
+            (Mode::DataTableContainer, Action::OpenAskDataDialog),
+            (Mode::DataTableContainer, Action::OpenTransformHistoryDialog),
+            (Mode::DataTableContainer, Action::OpenCheckpointsDialog),
+            (Mode::DataTableContainer, Action::OpenSampleDialog),
+            (Mode::DataTableContainer, Action::OpenResampleDialog),
+            (Mode::DataTableContainer, Action::OpenCrosstabDialog),
+            (Mode::DataTableContainer, Action::OpenSigmaDialog),
+            (Mode::DataTableContainer, Action::OpenPatternSetDialog),
+            (Mode::DataTableContainer, Action::TagSelectedRow),
+            (Mode::DataTableContainer, Action::OpenRowTagsDialog),
+            (Mode::DataTableContainer, Action::OpenFindingsReportDialog),
+            (Mode::DataTableContainer, Action::OpenMissingDataDialog),
+            (Mode::DataTableContainer, Action::OpenScriptDialog),
             (Mode::DataTableContainer, Action::OpenJmesDialog),
             (Mode::DataTableContainer, Action::OpenColumnOperationsDialog),
             (Mode::DataTableContainer, Action::OpenFindDialog),
             (Mode::DataTableContainer, Action::OpenDataframeDetailsDialog),
+            (Mode::DataTableContainer, Action::OpenValueCounts),
+            (Mode::DataTableContainer, Action::OpenRecordViewDialog),
+            (Mode::DataTableContainer, Action::OpenCellViewerDialog),
+            (Mode::DataTableContainer, Action::OpenHexViewerDialog),
+            (Mode::DataTableContainer, Action::OpenCellTarget),
+            (Mode::DataTableContainer, Action::OpenGroupByDialog),
+            (Mode::DataTableContainer, Action::OpenHeatmapDialog),
+            (Mode::DataTableContainer, Action::OpenChartDialog),
+            (Mode::DataTableContainer, Action::OpenBenfordDialog),
+            (Mode::DataTableContainer, Action::ToggleTimeline),
+            (Mode::DataTableContainer, Action::OpenDisplaySettingsDialog),
             (Mode::DataTableContainer, Action::OpenColumnWidthDialog),
             (Mode::DataTableContainer, Action::CopySelectedCell),
             (Mode::Global, Action::ToggleInstructions),
@@ -1246,6 +3257,9 @@ impl DataTableContainer {
     }
 
     fn get_instructions(&self) -> String {
+        if let Some(view) = &self.group_view {
+            return view.instructions();
+        }
         let base_instructions = self.build_instructions_from_config();
         
         if let Some(additional_instructions) = &self.additional_instructions {
@@ -1272,6 +3286,7 @@ impl Component for DataTableContainer {
     ///
     fn register_config_handler(&mut self, config: Config) -> Result<()> {
         self.config = config.clone();
+        self.style = config.style_config.clone();
         // Propagate to subcomponents that implement config registration
         let _ = self.datatable.register_config_handler(config.clone());
         let _ = self.sort_dialog.register_config_handler(config.clone());
@@ -1280,6 +3295,7 @@ impl Component for DataTableContainer {
         let _ = self.column_width_dialog.register_config_handler(config.clone());
         let _ = self.find_dialog.register_config_handler(config.clone());
         let _ = self.dataframe_details_dialog.register_config_handler(config.clone());
+        let _ = self.group_by_dialog.register_config_handler(config.clone());
         let _ = self.jmes_dialog.register_config_handler(config.clone());
         let _ = self.column_operations_dialog.register_config_handler(config.clone());
         // DataExportDialog moved to DataTabManagerDialog
@@ -1309,43 +3325,449 @@ impl Component for DataTableContainer {
     /// This method manages dialog activation, dialog event handling, and forwards navigation events to the DataTable.
     fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
         debug!("DataTableContainer handle_key_event: {:?}", key);
-        // If busy overlay is active, consume input except maybe Esc; block cell navigation
+        // While busy, only Esc (cancel) is honored; block navigation/interaction
         if self.busy_active {
-            // Ignore all input while busy to prevent navigation/interaction
+            if let Some(Action::Escape) = self.config.action_for_key(Mode::Global, key) {
+                self.cancel_running_job();
+            }
+            return Ok(None);
+        }
+        // Route key events to the confirm dialog, which sits above every other dialog
+        if let Some(dialog) = &mut self.confirm_dialog {
+            match dialog.handle_key_event(key) {
+                Some(Action::ConfirmDialogAccepted) => {
+                    if let Err(e) = dialog.remember(&mut self.config) {
+                        error!("Failed to save confirmation setting: {}", e);
+                    }
+                    let pending = dialog.pending.clone();
+                    self.confirm_dialog = None;
+                    return self.run_confirmed(pending);
+                }
+                Some(Action::DialogClose) => self.confirm_dialog = None,
+                _ => {}
+            }
+            return Ok(None);
+        }
+
+        // Route key events to FindAllResultsDialog if active (check this first)
+        if self.find_all_results_dialog_active {
+            if let Some(dialog) = &mut self.find_all_results_dialog
+                && let Some(action) = dialog.handle_key_event(key) {
+                    match action {
+                        Action::DialogClose => {
+                            self.find_all_results_dialog_active = false;
+                            // Keep the dialog instance for persistence
+                        }
+                        Action::OpenFindDialog => {
+                            self.find_all_results_dialog_active = false;
+                            self.find_dialog_active = true;
+                            self.find_origin = Some((self.datatable.selection.row, self.datatable.selection.col));
+                        }
+                        Action::GoToResult { row, column } => {
+                            // Find the column index in the visible columns
+                            let visible_columns = self.datatable.get_visible_columns()?;
+                            if let Some(col_idx) = visible_columns.iter().position(|col| col == &column) {
+                                self.datatable.selection.row = row;
+                                self.datatable.selection.col = col_idx;
+                                self.datatable.scroll_to_selection()?;
+                                // Optionally close the dialog after going to result
+                                // self.find_all_results_dialog_active = false;
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            return Ok(None);
+        }
+        // Route key events to RecordViewDialog if active
+        if self.record_view_dialog_active {
+            if let Some(dialog) = &mut self.record_view_dialog
+                && let Some(action) = dialog.handle_key_event(key) {
+                    match action {
+                        Action::DialogClose => {
+                            self.record_view_dialog_active = false;
+                        }
+                        Action::GoToResult { row, column } => {
+                            let visible_columns = self.datatable.get_visible_columns()?;
+                            if let Some(col_idx) = visible_columns.iter().position(|col| col == &column) {
+                                self.datatable.selection.row = row;
+                                self.datatable.selection.col = col_idx;
+                                self.datatable.scroll_to_selection()?;
+                            }
+                            self.record_view_dialog_active = false;
+                        }
+                        _ => {}
+                    }
+                }
+            return Ok(None);
+        }
+        // Route key events to OpenTargetDialog if active
+        if self.open_target_dialog_active {
+            if let Some(dialog) = &mut self.open_target_dialog
+                && let Some(action) = dialog.handle_key_event(key) {
+                    match action {
+                        Action::DialogClose => {
+                            self.open_target_dialog_active = false;
+                        }
+                        Action::ConfirmOpenTarget => match open_with_system_handler(&dialog.target) {
+                            Ok(()) => self.open_target_dialog_active = false,
+                            Err(e) => dialog.error = Some(format!("Failed to open: {e}")),
+                        },
+                        _ => {}
+                    }
+                }
+            return Ok(None);
+        }
+        // Route key events to CellViewerDialog if active
+        if self.cell_viewer_dialog_active {
+            if let Some(dialog) = &mut self.cell_viewer_dialog
+                && let Some(Action::DialogClose) = dialog.handle_key_event(key) {
+                    self.cell_viewer_dialog_active = false;
+                }
+            return Ok(None);
+        }
+        // Route key events to AskDataDialog if active
+        if self.ask_data_dialog_active {
+            if let Some(dialog) = &mut self.ask_data_dialog
+                && let Some(Action::DialogClose) = dialog.handle_key_event(key) {
+                    self.ask_data_dialog_active = false;
+                }
+            return Ok(None);
+        }
+        // Route key events to TransformHistoryDialog if active
+        if self.transform_history_dialog_active {
+            if let Some(dialog) = &mut self.transform_history_dialog {
+                match dialog.handle_key_event(key) {
+                    Some(Action::DialogClose) => self.transform_history_dialog_active = false,
+                    Some(Action::ReplayTransformHistory(steps)) => {
+                        return self.handle_replay_transform_history(steps);
+                    }
+                    _ => {}
+                }
+            }
+            return Ok(None);
+        }
+        // Route key events to CheckpointsDialog if active
+        if self.checkpoints_dialog_active {
+            if let Some(action) = self.checkpoints_dialog.as_mut().and_then(|d| d.handle_key_event(key)) {
+                return self.handle_checkpoints_action(action);
+            }
+            return Ok(None);
+        }
+        // Route key events to SampleDialog if active
+        if self.sample_dialog_active {
+            match self.sample_dialog.as_mut().and_then(|d| d.handle_key_event(key)) {
+                Some(Action::DialogClose) => self.sample_dialog_active = false,
+                Some(Action::SampleDialogApplied { options, dataset_name }) => {
+                    let df = self.datatable.get_dataframe()?;
+                    match sample(df.as_ref(), &options) {
+                        Ok(sampled) => {
+                            self.sample_dialog_active = false;
+                            let dataset_name = if dataset_name.is_empty() {
+                                format!("{}_{}", self.datatable.dataframe.metadata.name, options.method.suffix())
+                            } else {
+                                dataset_name
+                            };
+                            // The sample opens as a new dataset tab; the original is untouched
+                            return Ok(Some(Action::SqlDialogAppliedNewDataset {
+                                dataset_name,
+                                dataframe: Arc::new(sampled),
+                            }));
+                        }
+                        Err(e) => {
+                            if let Some(dialog) = &mut self.sample_dialog {
+                                dialog.set_error(format!("{e}"));
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+            return Ok(None);
+        }
+        // Route key events to ResampleDialog if active
+        if self.resample_dialog_active {
+            match self.resample_dialog.as_mut().and_then(|d| d.handle_key_event(key)) {
+                Some(Action::DialogClose) => self.resample_dialog_active = false,
+                Some(Action::ResampleDialogApplied { options, output, dataset_name }) => {
+                    let df = self.datatable.get_dataframe()?;
+                    match resample(df.as_ref(), &options) {
+                        Ok(buckets) => {
+                            self.resample_dialog_active = false;
+                            if output == ResampleOutput::Chart {
+                                let title = format!("{} per {}", options.output_column(), options.every);
+                                let mut dialog = ChartDialog::with_bars(title, resample_chart_bars(&buckets, &options)?);
+                                dialog.register_config_handler(self.config.clone())?;
+                                self.chart_dialog = Some(dialog);
+                                self.chart_dialog_active = true;
+                                return Ok(None);
+                            }
+                            let dataset_name = if dataset_name.is_empty() {
+                                format!("{}_resampled", self.datatable.dataframe.metadata.name)
+                            } else {
+                                dataset_name
+                            };
+                            return Ok(Some(Action::SqlDialogAppliedNewDataset {
+                                dataset_name,
+                                dataframe: Arc::new(buckets),
+                            }));
+                        }
+                        Err(e) => {
+                            if let Some(dialog) = &mut self.resample_dialog {
+                                dialog.set_error(format!("{e}"));
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+            return Ok(None);
+        }
+        // Route key events to CrosstabDialog if active
+        if self.crosstab_dialog_active {
+            match self.crosstab_dialog.as_mut().and_then(|d| d.handle_key_event(key)) {
+                Some(Action::DialogClose) => self.crosstab_dialog_active = false,
+                Some(action @ Action::SqlDialogAppliedNewDataset { .. }) => {
+                    self.crosstab_dialog_active = false;
+                    return Ok(Some(action));
+                }
+                _ => {}
+            }
+            return Ok(None);
+        }
+        // Route key events to MissingDataDialog if active
+        if self.missing_data_dialog_active {
+            if self.config.action_for_key(Mode::MissingDataDialog, key) == Some(Action::DropMissingColumns)
+                && let Some(columns) = self.missing_data_dialog.as_ref().map(|d| d.flagged_columns()).filter(|c| !c.is_empty())
+            {
+                let message = format!("Drop {} column(s) into a new dataset: {}?", columns.len(), columns.join(", "));
+                if self.confirm_first(ConfirmKind::DropColumns, message, Action::DropMissingColumns)? {
+                    return Ok(None);
+                }
+            }
+            match self.missing_data_dialog.as_mut().and_then(|d| d.handle_key_event(key)) {
+                Some(Action::DialogClose) => self.missing_data_dialog_active = false,
+                Some(action @ Action::SqlDialogAppliedNewDataset { .. }) => {
+                    self.missing_data_dialog_active = false;
+                    return Ok(Some(action));
+                }
+                _ => {}
+            }
+            return Ok(None);
+        }
+        // Route key events to SigmaDialog if active
+        if self.sigma_dialog_active {
+            match self.sigma_dialog.as_mut().and_then(|d| d.handle_key_event(key)) {
+                Some(Action::DialogClose) => self.sigma_dialog_active = false,
+                Some(Action::SigmaDialogApplied(options)) => return self.run_sigma_rules(options),
+                _ => {}
+            }
+            return Ok(None);
+        }
+        // Route key events to PatternSetDialog if active
+        if self.pattern_set_dialog_active {
+            match self.pattern_set_dialog.as_mut().and_then(|d| d.handle_key_event(key)) {
+                Some(Action::DialogClose) => self.pattern_set_dialog_active = false,
+                Some(Action::PatternSetDialogApplied(options)) => return self.run_pattern_set_dialog(options),
+                _ => {}
+            }
+            return Ok(None);
+        }
+        // Route key events to RowTagsDialog if active
+        if self.row_tags_dialog_active {
+            if let Some(action) = self.row_tags_dialog.as_mut().and_then(|d| d.handle_key_event(key)) {
+                return self.handle_row_tags_action(action);
+            }
+            return Ok(None);
+        }
+        // Route key events to ColumnMenuDialog if active
+        if self.column_menu_dialog_active {
+            match self.column_menu_dialog.as_mut().and_then(|d| d.handle_key_event(key)) {
+                Some(Action::DialogClose) => self.column_menu_dialog_active = false,
+                Some(action) => return self.handle_column_menu_action(action),
+                None => {}
+            }
+            return Ok(None);
+        }
+        // Route key events to GoToRowDialog if active
+        if self.go_to_row_dialog_active {
+            match self.go_to_row_dialog.as_mut().and_then(|d| d.handle_key_event(key)) {
+                Some(Action::DialogClose) => self.go_to_row_dialog_active = false,
+                Some(Action::GoToRow(row)) => {
+                    self.go_to_row_dialog_active = false;
+                    self.datatable.go_to_row(row)?;
+                }
+                _ => {}
+            }
+            return Ok(None);
+        }
+        // Route key events to GoToColumnDialog if active
+        if self.go_to_column_dialog_active {
+            match self.go_to_column_dialog.as_mut().and_then(|d| d.handle_key_event(key)) {
+                Some(Action::DialogClose) => self.go_to_column_dialog_active = false,
+                Some(Action::GoToColumn(col)) => {
+                    self.go_to_column_dialog_active = false;
+                    self.datatable.selection.col = col;
+                    self.datatable.scroll_to_selection()?;
+                }
+                _ => {}
+            }
+            return Ok(None);
+        }
+        // Route key events to ValidationDialog if active
+        if self.validation_dialog_active {
+            if let Some(action) = self.validation_dialog.as_mut().and_then(|d| d.handle_key_event(key)) {
+                return self.handle_validation_action(action);
+            }
+            return Ok(None);
+        }
+        // Route key events to FindingsReportDialog if active
+        if self.findings_report_dialog_active {
+            if let Some(Action::DialogClose) = self.findings_report_dialog.as_mut().and_then(|d| d.handle_key_event(key)) {
+                self.findings_report_dialog_active = false;
+            }
+            return Ok(None);
+        }
+        // Route key events to ScriptDialog if active
+        if self.script_dialog_active {
+            if let Some(action) = self.script_dialog.as_mut().and_then(|d| d.handle_key_event(key)) {
+                return self.handle_script_action(action);
+            }
+            return Ok(None);
+        }
+        // Route key events to HexViewerDialog if active
+        if self.hex_viewer_dialog_active {
+            if let Some(dialog) = &mut self.hex_viewer_dialog
+                && let Some(Action::DialogClose) = dialog.handle_key_event(key) {
+                    self.hex_viewer_dialog_active = false;
+                }
+            return Ok(None);
+        }
+        // Route key events to the error dialog if shown
+        if let Some(dialog) = &mut self.error_dialog {
+            if let Some(Action::DialogClose) = dialog.handle_key_event(key)? {
+                self.error_dialog = None;
+            }
+            return Ok(None);
+        }
+        // Route key events to the message dialog if shown
+        if let Some(dialog) = &mut self.message_dialog {
+            if let Some(Action::DialogClose) = dialog.handle_key_event(key)? {
+                self.message_dialog = None;
+            }
             return Ok(None);
         }
-
-        // Route key events to FindAllResultsDialog if active (check this first)
-        if self.find_all_results_dialog_active {
-            if let Some(dialog) = &mut self.find_all_results_dialog
+        // Route key events to ClusterReportDialog if active
+        if self.cluster_report_dialog_active {
+            if let Some(dialog) = &mut self.cluster_report_dialog
+                && let Some(Action::DialogClose) = dialog.handle_key_event(key) {
+                    self.cluster_report_dialog_active = false;
+                }
+            return Ok(None);
+        }
+        // Route key events to DisplaySettingsDialog if active
+        if self.display_settings_dialog_active {
+            if let Some(dialog) = &mut self.display_settings_dialog
                 && let Some(action) = dialog.handle_key_event(key) {
                     match action {
                         Action::DialogClose => {
-                            self.find_all_results_dialog_active = false;
-                            // Keep the dialog instance for persistence
+                            self.display_settings_dialog_active = false;
                         }
-                        Action::GoToResult { row, column } => {
-                            // Find the column index in the visible columns
-                            let visible_columns = self.datatable.get_visible_columns()?;
-                            if let Some(col_idx) = visible_columns.iter().position(|col| col == &column) {
-                                self.datatable.selection.row = row;
-                                self.datatable.selection.col = col_idx;
-                                self.datatable.scroll_to_selection()?;
-                                // Optionally close the dialog after going to result
-                                // self.find_all_results_dialog_active = false;
-                            }
+                        Action::DisplaySettingsApplied(settings) => {
+                            self.datatable.set_display_settings(settings);
+                            self.display_settings_dialog_active = false;
+                            return Ok(Some(Action::SaveWorkspaceState));
+                        }
+                        _ => {}
+                    }
+                }
+            return Ok(None);
+        }
+        // Route key events to HeatmapDialog if active
+        if self.heatmap_dialog_active {
+            if let Some(dialog) = &mut self.heatmap_dialog
+                && let Some(action) = dialog.handle_key_event(key) {
+                    match action {
+                        Action::DialogClose => {
+                            self.heatmap_dialog_active = false;
+                        }
+                        Action::HeatmapDialogApplied { column, config } => {
+                            self.datatable.dataframe.heatmap_config.set(column, config);
+                            self.heatmap_dialog_active = false;
+                            return Ok(Some(Action::SaveWorkspaceState));
                         }
                         _ => {}
                     }
                 }
             return Ok(None);
         }
+        // Route key events to ChartDialog if active
+        if self.chart_dialog_active {
+            if let Some(Action::DialogClose) = self.chart_dialog.as_mut().and_then(|d| d.handle_key_event(key)) {
+                self.chart_dialog_active = false;
+            }
+            return Ok(None);
+        }
+        // Route key events to BenfordDialog if active
+        if self.benford_dialog_active {
+            if let Some(Action::DialogClose) = self.benford_dialog.as_mut().and_then(|d| d.handle_key_event(key)) {
+                self.benford_dialog_active = false;
+            }
+            return Ok(None);
+        }
+        // Route key events to GroupByDialog if active
+        if self.group_by_dialog_active {
+            if let Some(action) = self.group_by_dialog.handle_key_event(key) {
+                match action {
+                    Action::DialogClose => {
+                        self.group_by_dialog_active = false;
+                    }
+                    Action::GroupByDialogApplied { group_columns, aggregate_column } => {
+                        match GroupView::new(self.datatable.dataframe.view_plan(), group_columns, aggregate_column) {
+                            Ok(mut view) => {
+                                view.register_config_handler(self.config.clone())?;
+                                self.group_view = Some(view);
+                                self.group_by_dialog_active = false;
+                            }
+                            Err(e) => self.group_by_dialog.error = Some(e.to_string()),
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            return Ok(None);
+        }
+        // Route key events to the group view while it replaces the table
+        if let Some(view) = &mut self.group_view {
+            match view.handle_key_event(key) {
+                Some(Action::DialogClose) => self.group_view = None,
+                Some(Action::OpenGroupByDialog) => {
+                    let columns = self.datatable.get_visible_columns()?;
+                    let col_index = self.datatable.selection.col.min(columns.len().saturating_sub(1));
+                    self.group_by_dialog.set_columns(columns, col_index);
+                    self.group_by_dialog_active = true;
+                }
+                Some(Action::GoToResult { row, column }) => {
+                    let visible_columns = self.datatable.get_visible_columns()?;
+                    self.datatable.selection.row = row;
+                    if let Some(col_idx) = visible_columns.iter().position(|col| col == &column) {
+                        self.datatable.selection.col = col_idx;
+                    }
+                    self.datatable.scroll_to_selection()?;
+                    self.group_view = None;
+                }
+                _ => {}
+            }
+            return Ok(None);
+        }
         // Route key events to FindDialog if active
         if self.find_dialog_active {
             if let Some(action) = self.find_dialog.handle_key_event(key) {
+                let searched = matches!(action, Action::FindNext { .. } | Action::FindCount { .. } | Action::FindAll { .. });
                 match action {
                     Action::DialogClose => {
                         self.find_dialog_active = false;
+                        self.find_origin = None;
                         self.current_search_pattern = None;
                         self.current_search_mode = None;
                         self.current_search_options = None;
@@ -1363,6 +3785,7 @@ impl Component for DataTableContainer {
                                 self.datatable.selection.row = row;
                                 self.datatable.selection.col = col;
                                 self.datatable.scroll_to_selection()?;
+                                self.find_origin = Some((row, col));
                                 // Optionally close dialog or keep open for repeated search
                             }
                             Ok(None) => {
@@ -1413,6 +3836,10 @@ impl Component for DataTableContainer {
                     }
                     _ => {}
                 }
+                if searched {
+                    // The tab manager stores the history in the project and saves the workspace
+                    return Ok(Some(Action::FindHistoryChanged(self.find_dialog.history.clone())));
+                }
             }
             return Ok(None);
         }
@@ -1440,28 +3867,8 @@ impl Component for DataTableContainer {
                         self.dataframe_details_dialog_active = false;
                     }
                     Action::ColumnCastRequested { column, dtype } => {
-                        use polars::prelude::DataType;
-                        // Parse dtype string back to DataType using simple matching on Debug strings we produced
-                        let target_dt: Option<DataType> = match dtype.as_str() {
-                            "Int8" => Some(DataType::Int8),
-                            "Int16" => Some(DataType::Int16),
-                            "Int32" => Some(DataType::Int32),
-                            "Int64" => Some(DataType::Int64),
-                            "Int128" => Some(DataType::Int128),
-                            "UInt8" => Some(DataType::UInt8),
-                            "UInt16" => Some(DataType::UInt16),
-                            "UInt32" => Some(DataType::UInt32),
-                            "UInt64" => Some(DataType::UInt64),
-                            "Float32" => Some(DataType::Float32),
-                            "Float64" => Some(DataType::Float64),
-                            "Boolean" => Some(DataType::Boolean),
-                            "String" | "Utf8" => Some(DataType::String),
-                            "Date" => Some(DataType::Date),
-                            s if s.starts_with("Datetime(") => Some(DataType::Datetime(polars::prelude::TimeUnit::Milliseconds, None)),
-                            "Time" => Some(DataType::Time),
-                            s if s.starts_with("Duration(") => Some(DataType::Duration(polars::prelude::TimeUnit::Milliseconds)),
-                            _ => None,
-                        };
+                        // Parse dtype string back to DataType from the Debug strings we produced
+                        let target_dt = parse_dtype(&dtype);
                         match target_dt {
                             Some(dt) => {
                                 // Try casting the selected column into a new Series first
@@ -1516,20 +3923,21 @@ impl Component for DataTableContainer {
                             }
                         }
                     }
+                    Action::SchemaEditApplied(edit) => match self.apply_schema_edit(&edit) {
+                        Ok(()) => {
+                            self.record_transform(TransformStep::Schema(edit));
+                            self.dataframe_details_dialog.close_schema_editor();
+                            let df_arc = self.datatable.get_dataframe()?;
+                            let columns = Self::get_column_names_vec(&df_arc);
+                            self.dataframe_details_dialog.set_columns(columns, 0);
+                            self.dataframe_details_dialog.set_dataframe(df_arc);
+                            return Ok(Some(Action::SaveWorkspaceState));
+                        }
+                        Err(e) => self.dataframe_details_dialog.set_schema_error(format!("{e}")),
+                    },
                     Action::AddFilterCondition(filter) => {
                         // Open filter dialog pre-populated with the selected column and value
-                        // First, merge with existing expression: append under root AND
-                        let mut expr = self.datatable.dataframe.filter.clone().unwrap_or_else(|| FilterExpr::And(vec![]));
-                        match &mut expr {
-                            FilterExpr::And(children) | FilterExpr::Or(children) => {
-                                children.push(FilterExpr::Condition(filter.clone()));
-                            }
-                            FilterExpr::Condition(_) => {
-                                expr = FilterExpr::And(vec![expr, FilterExpr::Condition(filter.clone())]);
-                            }
-                        }
-                        // Update container and dialog state
-                        self.set_filter_expression(expr);
+                        self.append_filter_condition(filter.clone());
                         // Initialize dialog columns and index to the filter's column
                         let df = self.datatable.get_dataframe()?;
                         let df_ref = df.as_ref();
@@ -1559,29 +3967,21 @@ impl Component for DataTableContainer {
                         self.jmes_dialog_active = false;
                     }
                     Action::JmesTransformDataset((query, scope)) => {
-                        match self.apply_jmes_transform(&query, scope) {
-                            Ok(()) => {
-                                self.jmes_dialog_active = false;
-                                return Ok(Some(Action::SaveWorkspaceState));
-                            }
-                            Err(e) => {
-                                self.jmes_dialog.set_error(format!("{e}"));
-                            }
+                        let pending = Action::JmesTransformDataset((query.clone(), scope.clone()));
+                        if self.confirm_first(ConfirmKind::OverwriteView, "Replace the current view with the JMESPath results?", pending)? {
+                            return Ok(None);
                         }
+                        return self.apply_jmes_to_view(query, scope);
                     }
                     Action::JmesTransformAddColumns(key_value_pairs, scope) => {
                         // Persist the latest add_columns on the dialog so state capture can save them
                         self.jmes_dialog.add_columns = key_value_pairs.clone();
-                        match self.apply_jmes_add_columns(key_value_pairs, scope) {
-                            Ok(()) => {
-                                self.jmes_dialog_active = false;
-                                return Ok(Some(Action::SaveWorkspaceState));
-                            }
-                            Err(e) => {
-                                self.jmes_dialog.set_error(format!("{e}"));
-                            }
+                        if let Err(e) = self.start_view_job(TransformStep::JmesAddColumns { pairs: key_value_pairs, scope }) {
+                            self.jmes_dialog.set_error(format!("{e}"));
                         }
                     }
+                    // Stored in the project by the tab manager
+                    Action::JmesHistoryChanged(_) => return Ok(Some(action)),
                     _ => {}
                 }
             }
@@ -1599,8 +3999,18 @@ impl Component for DataTableContainer {
                         let op = match which.as_str() {
                             "GenerateEmbeddings" => ColumnOperationKind::GenerateEmbeddings,
                             "Pca" => ColumnOperationKind::Pca,
+                            "Tsne" => ColumnOperationKind::Tsne,
+                            "RegexExtract" => ColumnOperationKind::RegexExtract,
+                            "ParseDatetime" => ColumnOperationKind::ParseDatetime,
+                            "NormalizeTimestamps" => ColumnOperationKind::NormalizeTimestamps,
+                            "Hash" => ColumnOperationKind::Hash,
+                            "Redact" => ColumnOperationKind::Redact,
+                            "Outliers" => ColumnOperationKind::Outliers,
+                            "Lookup" => ColumnOperationKind::Lookup,
+                            "ThreatIntel" => ColumnOperationKind::ThreatIntel,
                             "Cluster" => ColumnOperationKind::Cluster,
                             "SortByPromptSimilarity" => ColumnOperationKind::SortByPromptSimilarity,
+                            "Plugin" => ColumnOperationKind::Plugin,
                             _ => ColumnOperationKind::GenerateEmbeddings,
                         };
                         // Special-case: open Prompt Similarity dialog directly (uses embedding column mapping)
@@ -1619,64 +4029,30 @@ impl Component for DataTableContainer {
                             self.column_operations_dialog_active = false;
                             return Ok(None);
                         }
-                        // Seed with filtered DF columns (only compatible types) and current selection
-                        let df = self.datatable.get_dataframe()?;
-                        let df_ref = df.as_ref();
-                        let all_names: Vec<String> = df_ref
-                            .get_column_names_owned()
-                            .into_iter()
-                            .map(|s| s.to_string())
-                            .collect();
-                        use polars::prelude::DataType;
-                        let is_numeric = |dt: &DataType| matches!(
-                            dt,
-                            DataType::Int8
-                                | DataType::Int16
-                                | DataType::Int32
-                                | DataType::Int64
-                                | DataType::Int128
-                                | DataType::UInt8
-                                | DataType::UInt16
-                                | DataType::UInt32
-                                | DataType::UInt64
-                                | DataType::Float32
-                                | DataType::Float64
-                        );
-                        let filtered: Vec<String> = match op {
-                            ColumnOperationKind::GenerateEmbeddings => all_names
-                                .into_iter()
-                                .filter(|name| df_ref.column(name).ok().map(|s| s.dtype() == &DataType::String).unwrap_or(false))
-                                .collect(),
-                            ColumnOperationKind::Pca | ColumnOperationKind::Cluster => all_names
-                                .into_iter()
-                                .filter(|name| {
-                                    if let Ok(s) = df_ref.column(name) {
-                                        match s.dtype() {
-                                            DataType::List(inner) => is_numeric(inner.as_ref()),
-                                            _ => false,
-                                        }
-                                    } else { false }
-                                })
-                                .collect(),
-                            ColumnOperationKind::SortByPromptSimilarity => Vec::new(),
-                        };
-                        // Compute initial selected index based on current table selection
-                        let current_col_name = {
-                            let visible_columns = self.datatable.get_visible_columns().unwrap_or_default();
-                            let idx = self.datatable.selection.col.min(visible_columns.len().saturating_sub(1));
-                            visible_columns.get(idx).cloned().unwrap_or_default()
-                        };
-                        let selected_idx = filtered.iter().position(|n| n == &current_col_name).unwrap_or(0);
-                        let mut dialog = ColumnOperationOptionsDialog::new_with_columns(op, filtered, selected_idx);
-                        dialog.register_config_handler(self.config.clone())?;
-                        if dialog.columns.is_empty() {
-                            dialog.mode = ColumnOperationOptionsMode::Error("No compatible columns found for this operation".to_string());
-                        }
+                        let dialog = self.column_operation_options_dialog_for(op)?;
+                        self.column_operation_options_dialog = Some(dialog);
+                        self.column_operation_options_dialog_active = true;
+                        self.column_operations_dialog_active = false;
+                        return Ok(None);
+                    }
+                    Action::ColumnOperationPresetSelected(preset) => {
+                        let mut dialog = self.column_operation_options_dialog_for(preset.config.operation.clone())?;
+                        dialog.load_preset(&preset);
+                        // Run straight away when the preset's column is here; otherwise leave the
+                        // filled-in options open so another column can be picked
+                        let runnable = dialog.columns.contains(&preset.config.source_column)
+                            && dialog.mode == ColumnOperationOptionsMode::Input;
+                        let cfg = dialog.current_config();
                         self.column_operation_options_dialog = Some(dialog);
                         self.column_operation_options_dialog_active = true;
                         self.column_operations_dialog_active = false;
+                        if runnable {
+                            return self.run_column_operation(cfg);
+                        }
                         return Ok(None);
                     }
+                    // Stored in the project by the tab manager
+                    Action::ColumnOperationPresetDeleted(_) => return Ok(Some(action)),
                     _ => {}
                 }
             }
@@ -1784,137 +4160,15 @@ impl Component for DataTableContainer {
                             self.column_operation_options_dialog_active = false;
                             self.column_operations_dialog_active = true;
                         }
-                        Action::ColumnOperationOptionsApplied(cfg) => {
-                            debug!("ColumnOperationOptionsApplied: {:?}", cfg);
-                            // Validate source column dtype per operation requirements
-                            let df_arc = self.datatable.get_dataframe()?;
-                            let df_ref = df_arc.as_ref();
-                            let dtype_opt = df_ref.column(&cfg.source_column).ok().map(|s| s.dtype().clone());
-                            use polars::prelude::DataType;
-                            let mut is_ok = false;
-                            let mut err_msg = String::new();
-                            if let Some(dtype) = dtype_opt {
-                                match cfg.operation {
-                                    ColumnOperationKind::GenerateEmbeddings => {
-                                        is_ok = matches!(dtype, DataType::String);
-                                        if !is_ok {
-                                            err_msg = format!("Source column '{}' must be String", cfg.source_column);
-                                            error!("GenerateEmbeddings error: {}", err_msg);
-                                        }
-                                    }
-                                    ColumnOperationKind::Pca | ColumnOperationKind::Cluster | ColumnOperationKind::SortByPromptSimilarity => {
-                                        // Must be a vector of numbers: List(Numeric)
-                                        let is_vec_num = matches!(
-                                            dtype,
-                                            DataType::List(inner)
-                                                if matches!(*inner,
-                                                    DataType::Int8
-                                                        | DataType::Int16
-                                                        | DataType::Int32
-                                                        | DataType::Int64
-                                                        | DataType::Int128
-                                                        | DataType::UInt8
-                                                        | DataType::UInt16
-                                                        | DataType::UInt32
-                                                        | DataType::UInt64
-                                                        | DataType::Float32
-                                                        | DataType::Float64
-                                                )
-                                        );
-                                        is_ok = is_vec_num;
-                                        if !is_ok { err_msg = format!("Source column '{}' must be a vector of numbers", cfg.source_column); }
-                                    }
-                                }
-                            } else {
-                                err_msg = format!("Source column '{}' not found", cfg.source_column);
-                            }
-
-                            if !is_ok {
-                                dialog.mode = ColumnOperationOptionsMode::Error(err_msg.clone());
-                                error!("ColumnOperationOptionsApplied error: {}", &err_msg);
-                                return Ok(None);
-                            }
-
-                            // Apply operation
-                            match cfg.operation {
-                                ColumnOperationKind::GenerateEmbeddings => {
-                                    debug!("ColumnOperationOptionsApplied: GenerateEmbeddings");
-                                    // Extract options
-                                    let (model_name, num_dims) = match &cfg.options {
-                                        OperationOptions::GenerateEmbeddings {
-                                            model_name,
-                                            num_dimensions 
-                                        } => (model_name.clone(), *num_dimensions),
-                                        _ => ("text-embedding-3-small".to_string(), 0),
-                                    };
-                                    // Queue embeddings to show overlay first, then execute on Render
-                                    self.busy_active = true;
-                                    let provider = if let Some(dialog_ref) = &self.column_operation_options_dialog { dialog_ref.selected_provider.clone() } else { crate::dialog::LlmProvider::OpenAI };
-                                    self.busy_message = format!("Generating embeddings with {}...", provider.display_name());
-                                    self.busy_progress = 0.0;
-                                    // Snapshot provider config (non-secret fields) for reproducibility
-                                    let snapshot = EmbeddingColumnConfig {
-                                        provider: provider.clone(),
-                                        model_name: model_name.clone(),
-                                        num_dimensions: num_dims
-                                    };
-                                    self.embedding_column_config_mapping.insert(cfg.new_column_name.clone(), snapshot);
-                                    self.queued_embeddings = Some(QueuedEmbeddings {
-                                        source_column: cfg.source_column.clone(),
-                                        new_column_name: cfg.new_column_name.clone(),
-                                        model_name,
-                                        num_dimensions: num_dims,
-                                        selected_provider: Some(provider),
-                                        hide_new_column: cfg.hide_new_column,
-                                    });
-                                    // If prompt flow is pending, remember new embeddings column name
-                                    if let Some(ref mut pending) = self.pending_prompt_flow { pending.embeddings_column_name = Some(cfg.new_column_name.clone()); }
-                                    self.column_operation_options_dialog_active = false;
-                                    // Do not trigger Render immediately; allow one frame to draw the overlay first
-                                    return Ok(None);
-                                }
-                                ColumnOperationKind::Pca => {
-                                    // Extract k
-                                    let k = match &cfg.options {
-                                        OperationOptions::Pca { target_embedding_size } => *target_embedding_size,
-                                        _ => 2,
-                                    };
-                                    self.busy_active = true;
-                                    self.busy_message = "Running PCA...".to_string();
-                                    self.busy_progress = 0.0;
-                                    self.queued_pca = Some(QueuedPca {
-                                        source_column: cfg.source_column.clone(),
-                                        new_column_name: cfg.new_column_name.clone(),
-                                        k,
-                                    });
-                                    self.column_operation_options_dialog_active = false;
-                                    return Ok(None);
-                                }
-                                ColumnOperationKind::Cluster => {
-                                    // Prepare dataset (nrows x dim) from List(Numeric) source
-                                    let (algo, kmeans_opts, dbscan_opts) = match &cfg.options {
-                                        OperationOptions::Cluster { algorithm, kmeans, dbscan } => (algorithm.clone(), kmeans.clone(), dbscan.clone()),
-                                        _ => (crate::dialog::ClusterAlgorithm::Kmeans, None, None),
-                                    };
-                                    self.busy_active = true;
-                                    self.busy_message = "Clustering...".to_string();
-                                    self.busy_progress = 0.0;
-                                    self.queued_cluster = Some(QueuedCluster {
-                                        source_column: cfg.source_column.clone(),
-                                        new_column_name: cfg.new_column_name.clone(),
-                                        algorithm: algo,
-                                        kmeans: kmeans_opts,
-                                        dbscan: dbscan_opts,
-                                    });
-                                    self.column_operation_options_dialog_active = false;
-                                    return Ok(None);
-                                }
-                                ColumnOperationKind::SortByPromptSimilarity => {
-                                    // Not applied via options dialog; handled by dedicated prompt dialog
-                                    self.column_operation_options_dialog_active = false;
-                                    return Ok(None);
-                                }
+                        Action::ColumnOperationOptionsApplied(cfg) => return self.run_column_operation(cfg),
+                        Action::ColumnOperationPresetSaved(preset) => {
+                            let mut presets = self.column_operations_dialog.presets.clone();
+                            match presets.iter_mut().find(|p| p.name == preset.name) {
+                                Some(existing) => *existing = preset.clone(),
+                                None => presets.push(preset.clone()),
                             }
+                            self.column_operations_dialog.set_presets(presets);
+                            return Ok(Some(Action::ColumnOperationPresetSaved(preset)));
                         }
                         _ => {}
                     }
@@ -1941,12 +4195,14 @@ impl Component for DataTableContainer {
             if let Some(action) = self.sort_dialog.handle_key_event(key, max_rows) {
                 match action {
                     crate::action::Action::SortDialogApplied(sort_columns) => {
-                        // Apply sort to DataTable/DataFrame here
-                        if let Err(e) = self.datatable.dataframe.sort_by_columns(&sort_columns) {
+                        self.sort_dialog_active = false;
+                        if sort_columns.is_empty() {
+                            return Ok(None);
+                        }
+                        if let Err(e) = self.start_view_job(TransformStep::Sort(sort_columns)) {
                             error!("Sort error: {e}");
                         }
-                        self.sort_dialog_active = false;
-                        return Ok(Some(Action::SaveWorkspaceState));
+                        return Ok(None);
                     }
                     _ => {
                         self.sort_dialog_active = false;
@@ -1976,14 +4232,9 @@ impl Component for DataTableContainer {
             if let Some(action) = self.filter_dialog.handle_key_event(key, max_rows) {
                 if let Action::FilterDialogApplied(filter) = action {
                     info!("FilterDialogApplied: {:?}", filter);
-                    // Persist the filter expression on the dataframe for workspace capture
-                    self.datatable.dataframe.filter = Some(filter.clone());
-                    let base_df = self.datatable.dataframe.collect_base_df()?;
-                    let mask = filter.create_mask(&base_df)?;
-                    let filtered_df = base_df.filter(&mask)?;
-                    self.datatable.dataframe.current_df = Some(Arc::new(filtered_df));
-                    // Signal to persist workspace state
-                    return Ok(Some(Action::SaveWorkspaceState));
+                    // The workspace is saved once the filtered rows arrive
+                    self.start_view_job(TransformStep::Filter(filter))?;
+                    return Ok(None);
                 }
                 self.filter_dialog_active = false;
             }
@@ -2008,71 +4259,39 @@ impl Component for DataTableContainer {
                                 let dataset_name = parts[1].to_string();
                                 let query = parts[2].to_string();
                                 
-                                // Execute the SQL query to create a new dataset
-                                let mut ctx = new_sql_context();
-                                register_all(&mut ctx)?;
-                                // Register all available DataFrames
-                                for data_context in self.available_datasets.values() {
-                                    let name = &data_context.dataset.alias.clone().unwrap_or(data_context.dataset.name.clone());
-                                    ctx.register(name, (*data_context.dataframe).clone().lazy());
-                                }
-                                
-                                match ctx.execute(&query) {
-                                    Ok(lf) => match lf.collect() {
-                                        Ok(new_df) => {
-                                            self.sql_dialog_active = false;
-                                            // Return the action to be handled by parent component
-                                            return Ok(Some(Action::SqlDialogAppliedNewDataset { 
-                                                dataset_name, 
-                                                dataframe: Arc::new(new_df) 
-                                            }));
-                                        }
-                                        Err(e) => {
-                                            self.sql_dialog.set_error(format!("Collect error: {e}"));
-                                        }
-                                    },
+                                // Execute the SQL query to create a new dataset; a saved dataset is
+                                // never cut to `sql_row_limit`
+                                match self.execute_sql(&query, None) {
+                                    Ok(SqlOutput::Message(message)) => {
+                                        self.sql_dialog.set_notice(message);
+                                        return Ok(Some(Action::SaveWorkspaceState));
+                                    }
+                                    Ok(SqlOutput::Rows(new_df) | SqlOutput::Limited(new_df)) => {
+                                        self.sql_dialog_active = false;
+                                        // Return the action to be handled by parent component
+                                        return Ok(Some(Action::SqlDialogAppliedNewDataset { 
+                                            dataset_name, 
+                                            dataframe: Arc::new(new_df) 
+                                        }));
+                                    }
                                     Err(e) => {
-                                        self.sql_dialog.set_error(format!("SQL error: {e}"));
+                                        self.show_sql_error(&query, &e);
                                     }
                                 }
                             }
                         } else {
                             // Regular SQL query - update current DataFrame
-                            let mut ctx = new_sql_context();
-                            if let Err(error) = register_all(&mut ctx){
-                                error!("SQL error: {error}");
-                                self.sql_dialog.set_error(format!("SQL error: {error}"));
+                            let pending = Action::SqlDialogApplied(query_or_command.clone());
+                            if self.confirm_first(ConfirmKind::OverwriteView, "Replace the current view with the SQL results?", pending)? {
                                 return Ok(None);
-                            };
-                            // Register all available DataFrames
-                            for data_context in self.available_datasets.values() {
-                                let name = &data_context.dataset.alias.clone().unwrap_or(data_context.dataset.name.clone());
-                                ctx.register(name, (*data_context.dataframe).clone().lazy());
-                            }
-                            
-                            match ctx.execute(&query_or_command) {
-                                Ok(lf) => match lf.collect() {
-                                    Ok(new_df) => {
-                                        // record last sql
-                                        self.datatable.dataframe.last_sql_query = Some(query_or_command.clone());
-                                        self.datatable.set_current_df(new_df);
-                                        self.sql_dialog_active = false;
-                                        // Signal to persist workspace state
-                                        return Ok(Some(Action::SaveWorkspaceState));
-                                    }
-                                    Err(e) => {
-                                        self.sql_dialog.set_error(format!("Collect error: {e}"));
-                                    }
-                                },
-                                Err(e) => {
-                                    self.sql_dialog.set_error(format!("SQL error: {e}"));
-                                }
                             }
+                            return self.apply_sql_to_view(query_or_command);
                         }
                     }
                     Action::SqlDialogRestore => {
                         // Restore the original DataFrame
                         self.datatable.reset_current_df();
+                        self.record_transform(TransformStep::Reset);
                         self.sql_dialog_active = false;
                     }
                     Action::DialogClose => {
@@ -2116,6 +4335,10 @@ impl Component for DataTableContainer {
                             self.column_width_dialog.set_columns(column_order);
                         }
                     }
+                    Action::ComputeExactColumnWidths => match self.datatable.exact_column_widths() {
+                        Ok(widths) => self.column_width_dialog.apply_exact_widths(widths),
+                        Err(e) => error!("Failed to compute column widths: {}", e),
+                    },
                     _ => {
                         self.column_width_dialog_active = false;
                     }
@@ -2123,7 +4346,17 @@ impl Component for DataTableContainer {
             }
             return Ok(None);
         }
-        
+
+        // Route key events to the timeline panel while it has focus
+        if let Some(timeline) = self.timeline.as_mut().filter(|t| t.focused)
+            && self.config.action_for_key(Mode::DataTableContainer, key) != Some(Action::ToggleTimeline) {
+                if let Some(Action::AddFilterCondition(filter)) = timeline.handle_key_event(key) {
+                    let expr = self.append_filter_condition(filter);
+                    self.start_view_job(TransformStep::Filter(expr))?;
+                }
+                return Ok(None);
+            }
+
         if let Some(action) = self.config.action_for_key(crate::config::Mode::DataTableContainer, key) {
             match action {
                 Action::OpenSortDialog => {
@@ -2142,26 +4375,13 @@ impl Component for DataTableContainer {
                     self.embeddings_prompt_dialog_active = true;
                     return Ok(None);
                 }
-                Action::QuickSortCurrentColumn => {
+                Action::QuickSortCurrentColumn | Action::QuickSortAppendCurrentColumn => {
                     let visible_columns = self.datatable.get_visible_columns()?;
                     let col_idx = self.datatable.selection.col.min(visible_columns.len().saturating_sub(1));
                     if let Some(col_name) = visible_columns.get(col_idx) {
-                        if let Some(existing_idx) = self
-                            .sort_dialog
-                            .sort_columns
-                            .iter()
-                            .position(|sc| sc.name == *col_name)
-                        {
-                            self.sort_dialog.active_index = existing_idx;
-                        } else {
-                            self.sort_dialog.sort_columns.push(crate::dialog::sort_dialog::SortColumn {
-                                name: col_name.clone(),
-                                ascending: true,
-                            });
-                            self.sort_dialog.active_index = self.sort_dialog.sort_columns.len().saturating_sub(1);
-                        }
-                        self.sort_dialog.mode = SortDialogMode::List;
-                        self.sort_dialog_active = true;
+                        let current = self.datatable.dataframe.last_sort.clone().unwrap_or_default();
+                        let columns = next_quick_sort(&current, col_name, action == Action::QuickSortAppendCurrentColumn);
+                        return self.quick_sort(columns);
                     }
                     return Ok(None);
                 }
@@ -2179,22 +4399,7 @@ impl Component for DataTableContainer {
                     return Ok(None);
                 }
                 Action::QuickFilterEqualsCurrentValue => {
-                    let col_index = self.datatable.selection.col;
-                    let df = self.datatable.get_dataframe()?;
-                    let df = df.as_ref();
-                    let columns: Vec<String> = df
-                        .get_column_names_owned()
-                        .into_iter()
-                        .map(|s| s.to_string())
-                        .collect();
-                    self.filter_dialog.set_columns(columns, col_index);
-                    let selected_value = self.datatable.selected_cell_value()?;
-                    self.filter_dialog.add_value = selected_value.clone();
-                    self.filter_dialog.add_condition = Some(FilterCondition::Equals { value: selected_value, case_sensitive: false });
-                    let root_children = self.filter_dialog.get_root_expr().child_count();
-                    self.filter_dialog.add_insertion_path = Some(vec![root_children]);
-                    self.filter_dialog.mode = FilterDialogMode::Add;
-                    self.filter_dialog_active = true;
+                    self.open_quick_filter()?;
                     return Ok(None);
                 }
                 Action::MoveSelectedColumnLeft | Action::MoveSelectedColumnRight => {
@@ -2230,9 +4435,161 @@ impl Component for DataTableContainer {
                     return Ok(Some(Action::OpenDataExportDialog));
                 }
                 Action::OpenSqlDialog => { self.sql_dialog_active = true; return Ok(None); }
-                Action::OpenJmesDialog => { self.jmes_dialog_active = true; return Ok(None); }
+                Action::OpenAskDataDialog => {
+                    let df = self.datatable.get_dataframe()?;
+                    let schema: Vec<(String, String)> = df
+                        .get_columns()
+                        .iter()
+                        .map(|c| (c.name().to_string(), c.dtype().to_string()))
+                        .collect();
+                    let mut other_tables: Vec<String> = self
+                        .available_datasets
+                        .values()
+                        .map(|d| d.dataset.alias.clone().unwrap_or(d.dataset.name.clone()))
+                        .filter(|name| name != &self.sql_current_df_name)
+                        .collect();
+                    other_tables.sort();
+                    let dialog = self.ask_data_dialog.get_or_insert_with(|| {
+                        AskDataDialog::new(String::new(), Vec::new(), Vec::new())
+                    });
+                    dialog.table_name = self.sql_current_df_name.clone();
+                    dialog.schema = schema;
+                    dialog.other_tables = other_tables;
+                    dialog.register_config_handler(self.config.clone())?;
+                    self.ask_data_dialog_active = true;
+                    return Ok(None);
+                }
+                Action::OpenTransformHistoryDialog => {
+                    let mut dialog = TransformHistoryDialog::new(
+                        self.sql_current_df_name.clone(),
+                        self.datatable.dataframe.history.clone(),
+                    );
+                    dialog.source_path = self.datatable.dataframe.metadata.source_path.clone();
+                    dialog.register_config_handler(self.config.clone())?;
+                    self.transform_history_dialog = Some(dialog);
+                    self.transform_history_dialog_active = true;
+                    return Ok(None);
+                }
+                Action::OpenCheckpointsDialog => {
+                    let mut dialog = CheckpointsDialog::new(self.datatable.dataframe.checkpoints.clone());
+                    dialog.register_config_handler(self.config.clone())?;
+                    self.checkpoints_dialog = Some(dialog);
+                    self.checkpoints_dialog_active = true;
+                    return Ok(None);
+                }
+                Action::OpenSampleDialog => {
+                    let total_rows = self.datatable.get_dataframe()?.height();
+                    let mut dialog = SampleDialog::new(&self.datatable.dataframe.metadata.name, total_rows);
+                    dialog.register_config_handler(self.config.clone())?;
+                    self.sample_dialog = Some(dialog);
+                    self.sample_dialog_active = true;
+                    return Ok(None);
+                }
+                Action::OpenResampleDialog => {
+                    let df = self.datatable.get_dataframe()?;
+                    let columns: Vec<String> = df.get_column_names_owned().into_iter().map(|c| c.to_string()).collect();
+                    // Prefer the first datetime column, then the selected one
+                    let selected = self.selected_column_name()?;
+                    let time_idx = df
+                        .get_columns()
+                        .iter()
+                        .position(|c| matches!(c.dtype(), polars::prelude::DataType::Datetime(_, _) | polars::prelude::DataType::Date))
+                        .or_else(|| columns.iter().position(|c| c == &selected))
+                        .unwrap_or(0);
+                    let mut dialog = ResampleDialog::new(&self.datatable.dataframe.metadata.name, columns, time_idx);
+                    dialog.register_config_handler(self.config.clone())?;
+                    self.resample_dialog = Some(dialog);
+                    self.resample_dialog_active = true;
+                    return Ok(None);
+                }
+                Action::OpenCrosstabDialog => {
+                    let df = self.datatable.get_dataframe()?;
+                    let selected = self.selected_column_name()?;
+                    let row_idx = df.get_column_names().iter().position(|c| c.as_str() == selected).unwrap_or(0);
+                    let mut dialog = CrosstabDialog::new(&self.datatable.dataframe.metadata.name, df, row_idx);
+                    dialog.register_config_handler(self.config.clone())?;
+                    self.crosstab_dialog = Some(dialog);
+                    self.crosstab_dialog_active = true;
+                    return Ok(None);
+                }
+                Action::OpenMissingDataDialog => {
+                    let df = self.datatable.get_dataframe()?;
+                    let mut dialog = MissingDataDialog::new(&self.datatable.dataframe.metadata.name, df);
+                    dialog.register_config_handler(self.config.clone())?;
+                    self.missing_data_dialog = Some(dialog);
+                    self.missing_data_dialog_active = true;
+                    return Ok(None);
+                }
+                Action::OpenSigmaDialog => {
+                    let dialog = self.sigma_dialog.get_or_insert_with(SigmaDialog::new);
+                    dialog.error = None;
+                    dialog.register_config_handler(self.config.clone())?;
+                    self.sigma_dialog_active = true;
+                    return Ok(None);
+                }
+                Action::OpenPatternSetDialog => {
+                    let df = self.datatable.get_dataframe()?;
+                    let columns = df.get_column_names().iter().map(|c| c.to_string()).collect();
+                    let selected = self.selected_column_name().unwrap_or_default();
+                    let dialog = self.pattern_set_dialog.get_or_insert_with(PatternSetDialog::new);
+                    dialog.set_columns(columns, &selected);
+                    dialog.error = None;
+                    dialog.register_config_handler(self.config.clone())?;
+                    self.pattern_set_dialog_active = true;
+                    return Ok(None);
+                }
+                Action::TagSelectedRow => {
+                    self.open_row_tags_dialog(true)?;
+                    return Ok(None);
+                }
+                Action::OpenRowTagsDialog => {
+                    self.open_row_tags_dialog(false)?;
+                    return Ok(None);
+                }
+                Action::OpenValidationDialog => {
+                    self.open_validation_dialog()?;
+                    return Ok(None);
+                }
+                Action::OpenFindingsReportDialog => {
+                    let view = self.datatable.get_dataframe()?;
+                    let managed = &self.datatable.dataframe;
+                    let findings = Findings::collect(
+                        &managed.metadata.name,
+                        managed.metadata.source_path.clone(),
+                        &managed.collect_base_df()?,
+                        (view.height(), view.width()),
+                        managed.filter.as_ref(),
+                        &managed.history,
+                        &managed.row_tags,
+                    )?;
+                    let mut dialog = FindingsReportDialog::new(findings);
+                    dialog.register_config_handler(self.config.clone())?;
+                    self.findings_report_dialog = Some(dialog);
+                    self.findings_report_dialog_active = true;
+                    return Ok(None);
+                }
+                Action::OpenScriptDialog => {
+                    let dialog = self.script_dialog.get_or_insert_with(|| ScriptDialog::new(Vec::new()));
+                    dialog.set_saved_scripts(self.saved_scripts.clone());
+                    dialog.register_config_handler(self.config.clone())?;
+                    self.script_dialog_active = true;
+                    return Ok(None);
+                }
+                Action::OpenJmesDialog => {
+                    // Complete against the view's columns; preview on the selected row and the ones after it
+                    let row_df = self.datatable.dataframe.collect_rows(self.datatable.selection.row, PREVIEW_ROWS)?;
+                    let columns = Self::get_column_names_vec(&row_df);
+                    let rows = jmes_batch::row_objects(&row_df).into_iter().map(Value::Object).collect();
+                    self.jmes_dialog.set_context(columns, rows);
+                    self.jmes_dialog_active = true;
+                    return Ok(None);
+                }
                 Action::OpenColumnOperationsDialog => { self.column_operations_dialog_active = true; return Ok(None); }
-                Action::OpenFindDialog => { self.find_dialog_active = true; return Ok(None); }
+                Action::OpenFindDialog => {
+                    self.find_dialog_active = true;
+                    self.find_origin = Some((self.datatable.selection.row, self.datatable.selection.col));
+                    return Ok(None);
+                }
                 Action::OpenDataframeDetailsDialog => {
                     let df_arc = self.datatable.get_dataframe()?;
                     let df_ref = df_arc.as_ref();
@@ -2246,16 +4603,160 @@ impl Component for DataTableContainer {
                     self.dataframe_details_dialog.set_dataframe(df_arc.clone());
                     // Provide embeddings mapping for Embeddings tab
                     self.dataframe_details_dialog.embedding_column_config_mapping = self.embedding_column_config_mapping.clone();
+                    self.dataframe_details_dialog.set_file_hashes(self.datatable.dataframe.metadata.file_hashes.clone());
+                    self.dataframe_details_dialog.notes = self.datatable.dataframe.metadata.notes.clone();
                     self.dataframe_details_dialog_active = true;
                     return Ok(None);
                 }
-                Action::OpenColumnWidthDialog => {
-                    let df = self.datatable.get_dataframe()?;
-                    let df = df.as_ref();
-                    let columns: Vec<String> = df
-                        .get_column_names_owned()
-                        .into_iter()
-                        .map(|s| s.to_string()).collect();
+                Action::OpenValueCounts => {
+                    self.open_value_counts()?;
+                    return Ok(None);
+                }
+                Action::OpenColumnMenu => {
+                    self.open_column_menu()?;
+                    return Ok(None);
+                }
+                Action::OpenGoToColumnDialog => {
+                    let columns = self.datatable.get_visible_columns()?;
+                    let mut dialog = GoToColumnDialog::new(columns, self.datatable.selection.col);
+                    dialog.register_config_handler(self.config.clone())?;
+                    self.go_to_column_dialog = Some(dialog);
+                    self.go_to_column_dialog_active = true;
+                    return Ok(None);
+                }
+                Action::OpenGoToRowDialog => {
+                    let total = self.datatable.dataframe.view_height()?;
+                    let mut dialog = GoToRowDialog::new(self.datatable.selection.row, total);
+                    dialog.register_config_handler(self.config.clone())?;
+                    self.go_to_row_dialog = Some(dialog);
+                    self.go_to_row_dialog_active = true;
+                    return Ok(None);
+                }
+                Action::OpenHeatmapDialog => {
+                    let column = self.selected_column_name()?;
+                    if !column.is_empty() {
+                        let existing = self.datatable.dataframe.heatmap_config.get(&column).copied();
+                        let data_bounds = self.datatable.column_numeric_bounds(&column)?;
+                        let mut dialog = HeatmapDialog::new(column, existing, data_bounds);
+                        dialog.register_config_handler(self.config.clone())?;
+                        self.heatmap_dialog = Some(dialog);
+                        self.heatmap_dialog_active = true;
+                    }
+                    return Ok(None);
+                }
+                Action::OpenChartDialog => {
+                    let column = self.selected_column_name()?;
+                    if !column.is_empty() {
+                        let df = self.datatable.get_dataframe()?;
+                        let values = df.column(&column).map_err(|e| color_eyre::eyre::eyre!("{}", e))?.clone();
+                        let mut dialog = ChartDialog::new(values);
+                        dialog.register_config_handler(self.config.clone())?;
+                        self.chart_dialog = Some(dialog);
+                        self.chart_dialog_active = true;
+                    }
+                    return Ok(None);
+                }
+                Action::OpenBenfordDialog => {
+                    let column = self.selected_column_name()?;
+                    if !column.is_empty() {
+                        let df = self.datatable.get_dataframe()?;
+                        let values = df.column(&column).map_err(|e| color_eyre::eyre::eyre!("{}", e))?;
+                        let mut dialog = BenfordDialog::new(values);
+                        dialog.register_config_handler(self.config.clone())?;
+                        self.benford_dialog = Some(dialog);
+                        self.benford_dialog_active = true;
+                    }
+                    return Ok(None);
+                }
+                Action::ToggleTimeline => {
+                    match &mut self.timeline {
+                        Some(timeline) if !timeline.focused => timeline.focused = true,
+                        Some(_) => self.timeline = None,
+                        None => {
+                            // The selected column if it holds timestamps, else the first one that does
+                            let df = self.datatable.get_dataframe()?;
+                            let selected = self.selected_column_name()?;
+                            let is_time = |c: &polars::prelude::Column| matches!(c.dtype(), polars::prelude::DataType::Datetime(_, _) | polars::prelude::DataType::Date);
+                            let column = df
+                                .column(&selected)
+                                .ok()
+                                .filter(|c| is_time(c))
+                                .or_else(|| df.get_columns().iter().find(|c| is_time(c)))
+                                .map(|c| c.name().to_string())
+                                .unwrap_or(selected);
+                            let mut timeline = TimelinePanel::new(column);
+                            timeline.register_config_handler(self.config.clone())?;
+                            self.timeline = Some(timeline);
+                        }
+                    }
+                    return Ok(None);
+                }
+                Action::OpenDisplaySettingsDialog => {
+                    let columns = self.datatable.get_visible_columns()?;
+                    let mut dialog = DisplaySettingsDialog::new(
+                        self.datatable.dataframe.display_settings.clone(),
+                        columns,
+                        Some(self.datatable.selection.col),
+                    );
+                    dialog.register_config_handler(self.config.clone())?;
+                    self.display_settings_dialog = Some(dialog);
+                    self.display_settings_dialog_active = true;
+                    return Ok(None);
+                }
+                Action::OpenGroupByDialog => {
+                    let columns = self.datatable.get_visible_columns()?;
+                    let col_index = self.datatable.selection.col.min(columns.len().saturating_sub(1));
+                    self.group_by_dialog.set_columns(columns, col_index);
+                    self.group_by_dialog_active = true;
+                    return Ok(None);
+                }
+                Action::OpenRecordViewDialog => {
+                    let row = self.datatable.selection.row;
+                    let fields = self.datatable.selected_row_record()?;
+                    let mut dialog = RecordViewDialog::new(row, fields);
+                    dialog.register_config_handler(self.config.clone())?;
+                    self.record_view_dialog = Some(dialog);
+                    self.record_view_dialog_active = true;
+                    return Ok(None);
+                }
+                Action::OpenCellViewerDialog => {
+                    let column = self.selected_column_name()?;
+                    let value = self.selected_cell_json_value()?;
+                    let mut dialog = CellViewerDialog::new(column, self.datatable.selection.row, value);
+                    dialog.register_config_handler(self.config.clone())?;
+                    self.cell_viewer_dialog = Some(dialog);
+                    self.cell_viewer_dialog_active = true;
+                    return Ok(None);
+                }
+                Action::OpenCellTarget => {
+                    let cell_text = match self.selected_cell_json_value()? {
+                        Value::String(s) => s,
+                        v => v.to_string(),
+                    };
+                    if let Some(target) = detect_open_target(&cell_text) {
+                        let mut dialog = OpenTargetDialog::new(target);
+                        dialog.register_config_handler(self.config.clone())?;
+                        self.open_target_dialog = Some(dialog);
+                        self.open_target_dialog_active = true;
+                    } else {
+                        debug!("Selected cell has no URL or existing path to open");
+                    }
+                    return Ok(None);
+                }
+                Action::OpenHexViewerDialog => {
+                    if let Some(bytes) = self.datatable.selected_cell_bytes()? {
+                        let column = self.selected_column_name()?;
+                        let mut dialog = HexViewerDialog::new(column, self.datatable.selection.row, bytes);
+                        dialog.register_config_handler(self.config.clone())?;
+                        self.hex_viewer_dialog = Some(dialog);
+                        self.hex_viewer_dialog_active = true;
+                    } else {
+                        debug!("Selected cell is not binary or hex-encoded; hex viewer not opened");
+                    }
+                    return Ok(None);
+                }
+                Action::OpenColumnWidthDialog => {
+                    let columns = self.datatable.dataframe.view_columns()?;
                     self.column_width_dialog.set_columns(columns);
                     let config = self.datatable.get_column_width_config();
                     self.column_width_dialog.set_config(config);
@@ -2296,18 +4797,34 @@ impl Component for DataTableContainer {
     fn update(&mut self, action: Action) -> Result<Option<Action>> {
         debug!("update: {:?}", action);
         match action {
+            Action::Error(message) => self.error_dialog = Some(ErrorDialog::new(message)),
             Action::Tick => {
                 self.find_dialog.tick_search_progress();
-                // Advance busy progress animation if active
+                // Show worker progress when reported, otherwise animate the busy bar
                 if self.busy_active {
-                    self.busy_progress += 0.02;
-                    if self.busy_progress >= 1.0 { self.busy_progress = 0.0; }
+                    match self.running_job.as_ref().and_then(|job| job.progress()) {
+                        Some(progress) => self.busy_progress = progress,
+                        None => {
+                            self.busy_progress += 0.02;
+                            if self.busy_progress >= 1.0 { self.busy_progress = 0.0; }
+                        }
+                    }
                 }
-                // No background job polling
             }
             Action::Render => {
+                if self.find_dialog_active
+                    && let Some((pattern, options, search_mode)) = self.find_dialog.take_incremental_search(std::time::Instant::now()) {
+                        self.incremental_find(&pattern, &options, &search_mode)?;
+                    }
+                // Generated SQL goes to the SQL dialog for review; it runs from there as usual
+                if let Some(dialog) = &mut self.ask_data_dialog
+                    && let Some(sql) = dialog.poll_generation() {
+                        self.ask_data_dialog_active = false;
+                        self.sql_dialog.set_textarea_content(sql);
+                        self.sql_dialog_active = true;
+                    }
                 if let Some(q) = self.queued_embeddings.take() {
-                    // Initialize progressive embeddings job
+                    // Prepare the embeddings job; the provider calls run on a worker thread
                     let provider = q.selected_provider.clone().unwrap_or(crate::dialog::LlmProvider::OpenAI);
                     // Prepare source series as strings and build unique lists
                     let df_arc = self.datatable.get_dataframe()?;
@@ -2334,7 +4851,6 @@ impl Component for DataTableContainer {
                             }
                         } else { row_texts.push(None); }
                     }
-                    let total = uniques.len();
                     let job = EmbeddingsJob {
                         source_column: q.source_column,
                         new_column_name: q.new_column_name,
@@ -2346,78 +4862,27 @@ impl Component for DataTableContainer {
                         uniques,
                         unique_index,
                         unique_embeddings: Vec::new(),
-                        next_start: 0,
                         batch_size: 256,
-                        total_uniques: total,
                     };
-                    self.in_progress_embeddings = Some(job);
-                    // Reset progress
+                    self.start_embeddings_job(job);
                     self.busy_progress = 0.0;
                 }
-                // Process next batch if an embeddings job is active
-                if let Some(done) = self.process_next_embeddings_batch()? {
-                    if done {
-                        // Finalize embeddings column
-                        self.finalize_embeddings_job()?;
-                        self.busy_active = false;
-                        self.busy_message.clear();
-                        self.busy_progress = 0.0;
-                        // If we initiated from prompt flow, reopen the prompt dialog now
-                        if let Some(pending) = self.pending_prompt_flow.take() {
-                            let mapping = self.embedding_column_config_mapping.clone();
-                            let initial = pending.embeddings_column_name.clone();
-                            let mut dialog = crate::dialog::EmbeddingsPromptDialog::new_with_mapping(mapping, initial);
-                            dialog.register_config_handler(self.config.clone())?;
-                            // Restore prompt text and similarity new column name
-                            dialog.new_column_input.insert_str(&pending.similarity_new_column);
-                            dialog.new_column_name = dialog.new_column_input.lines().join("\n");
-                            dialog.prompt_input.insert_str(&pending.prompt_text);
-                            self.embeddings_prompt_dialog = Some(dialog);
-                            self.embeddings_prompt_dialog_active = true;
-                            return Ok(None);
-                        }
-                        return Ok(Some(Action::SaveWorkspaceState));
-                    } else {
-                        // Continue on next render
-                        return Ok(None);
+                if let Some(p) = self.queued_pca.take()
+                    && let Err(e) = self.start_pca_job(p) {
+                        self.end_busy();
+                        return Ok(self.report_job_error(e));
                     }
-                }
-                if let Some(p) = self.queued_pca.take() {
-                    let res = self.execute_pca(&p.source_column, &p.new_column_name, p.k);
-                    self.busy_active = false;
-                    self.busy_message.clear();
-                    self.busy_progress = 0.0;
-                    match res {
-                        Ok(_) => return Ok(Some(Action::SaveWorkspaceState)),
-                        Err(e) => {
-                            if let Some(dialog) = &mut self.column_operation_options_dialog {
-                                dialog.mode = ColumnOperationOptionsMode::Error(format!("{e}"));
-                            }
-                            return Ok(None);
-                        }
+                if let Some(t) = self.queued_tsne.take()
+                    && let Err(e) = self.start_tsne_job(t) {
+                        self.end_busy();
+                        return Ok(self.report_job_error(e));
                     }
-                }
-                if let Some(cq) = self.queued_cluster.take() {
-                    let res = self.execute_cluster(
-                        &cq.source_column,
-                        &cq.new_column_name,
-                        cq.algorithm,
-                        cq.kmeans,
-                        cq.dbscan,
-                    );
-                    self.busy_active = false;
-                    self.busy_message.clear();
-                    self.busy_progress = 0.0;
-                    match res {
-                        Ok(_) => return Ok(Some(Action::SaveWorkspaceState)),
-                        Err(e) => {
-                            if let Some(dialog) = &mut self.column_operation_options_dialog {
-                                dialog.mode = ColumnOperationOptionsMode::Error(format!("{e}"));
-                            }
-                            return Ok(None);
-                        }
+                if let Some(cq) = self.queued_cluster.take()
+                    && let Err(e) = self.start_cluster_job(cq) {
+                        self.end_busy();
+                        return Ok(self.report_job_error(e));
                     }
-                }
+                return self.poll_running_job();
             }
             _ => {}
         }
@@ -2463,19 +4928,16 @@ impl Component for DataTableContainer {
             }
         }
 
-        // Layout with conditional instruction area
-        let constraints = if self.show_instructions {
-            vec![
-                Constraint::Length(viewing_box_height),
-                Constraint::Min(min_table_height),
-                Constraint::Length(instructions_height),
-            ]
-        } else {
-            vec![
-                Constraint::Length(viewing_box_height),
-                Constraint::Min(min_table_height),
-            ]
-        };
+        // Layout with conditional timeline and instruction areas
+        let timeline_height = if self.timeline.is_some() { TimelinePanel::HEIGHT } else { 0 };
+        let mut constraints = vec![
+            Constraint::Length(viewing_box_height),
+            Constraint::Length(timeline_height),
+            Constraint::Min(min_table_height),
+        ];
+        if self.show_instructions {
+            constraints.push(Constraint::Length(instructions_height));
+        }
 
         let chunks = Layout::default()
             .direction(Direction::Vertical)
@@ -2500,8 +4962,18 @@ impl Component for DataTableContainer {
         }
         frame.render_widget(viewing_box, chunks[0]);
 
-        // DataTable (middle)
-        self.datatable.draw(frame, chunks[1])?;
+        // Timeline strip (optional, above the table)
+        if let Some(timeline) = &mut self.timeline {
+            let df = self.datatable.get_dataframe()?;
+            timeline.update_bins(&df, chunks[1].width);
+            timeline.render(chunks[1], frame.buffer_mut());
+        }
+
+        // DataTable (middle), or the groups in its place
+        match &mut self.group_view {
+            Some(view) => view.render(chunks[2], frame.buffer_mut()),
+            None => self.datatable.draw(frame, chunks[2])?,
+        }
 
         // Instruction area (bottom, wrapped) - only if show_instructions is true
         if self.show_instructions {
@@ -2510,17 +4982,11 @@ impl Component for DataTableContainer {
                 .block(Block::default().title("Instructions").borders(Borders::ALL))
                 .wrap(Wrap { trim: true })
                 .style(ratatui::style::Style::default().fg(Color::Yellow));
-            Clear.render(chunks[2], frame.buffer_mut()   );
-            frame.render_widget(instructions, chunks[2]);
+            Clear.render(chunks[3], frame.buffer_mut()   );
+            frame.render_widget(instructions, chunks[3]);
         }
         let col_index = self.datatable.selection.col;
-        let df = self.datatable.get_dataframe()?;
-        let df = df.as_ref();
-        let columns: Vec<String> = df
-            .get_column_names_owned()
-            .into_iter()
-            .map(|s| s.to_string())
-            .collect();
+        let columns = self.datatable.dataframe.view_columns()?;
 
         self.sort_dialog.set_columns(columns.clone(), col_index);
 
@@ -2656,6 +5122,299 @@ impl Component for DataTableContainer {
             self.find_all_results_dialog.as_mut().unwrap().render(popup_area, frame.buffer_mut());
             self.last_find_all_results_dialog_area = Some(popup_area);
         }
+        // Render RecordViewDialog as a popup overlay only if active
+        if self.record_view_dialog_active
+            && let Some(dialog) = &mut self.record_view_dialog {
+                let popup_area = ratatui::layout::Rect {
+                    x: area.x + area.width / 8,
+                    y: area.y + area.height / 8,
+                    width: area.width - area.width / 4,
+                    height: area.height - area.height / 4,
+                };
+                dialog.render(popup_area, frame.buffer_mut());
+                self.last_record_view_dialog_area = Some(popup_area);
+            }
+        // Render OpenTargetDialog as a popup overlay only if active
+        if self.open_target_dialog_active
+            && let Some(dialog) = &self.open_target_dialog {
+                let popup_area = ratatui::layout::Rect {
+                    x: area.x + area.width / 4,
+                    y: area.y + area.height / 4,
+                    width: area.width / 2,
+                    height: area.height / 2,
+                };
+                dialog.render(popup_area, frame.buffer_mut());
+            }
+        // Render CellViewerDialog as a popup overlay only if active
+        if self.cell_viewer_dialog_active
+            && let Some(dialog) = &mut self.cell_viewer_dialog {
+                let popup_area = ratatui::layout::Rect {
+                    x: area.x + area.width / 8,
+                    y: area.y + area.height / 8,
+                    width: area.width - area.width / 4,
+                    height: area.height - area.height / 4,
+                };
+                dialog.render(popup_area, frame.buffer_mut());
+            }
+        // Render AskDataDialog as a popup overlay only if active
+        if self.ask_data_dialog_active
+            && let Some(dialog) = &self.ask_data_dialog {
+                let popup_area = ratatui::layout::Rect {
+                    x: area.x + area.width / 8,
+                    y: area.y + area.height / 8,
+                    width: area.width - area.width / 4,
+                    height: area.height - area.height / 4,
+                };
+                dialog.render(popup_area, frame.buffer_mut());
+            }
+        // Render TransformHistoryDialog as a popup overlay only if active
+        if self.transform_history_dialog_active
+            && let Some(dialog) = &self.transform_history_dialog {
+                let popup_area = ratatui::layout::Rect {
+                    x: area.x + area.width / 8,
+                    y: area.y + area.height / 8,
+                    width: area.width - area.width / 4,
+                    height: area.height - area.height / 4,
+                };
+                dialog.render(popup_area, frame.buffer_mut());
+            }
+        // Render CheckpointsDialog as a popup overlay only if active
+        if self.checkpoints_dialog_active
+            && let Some(dialog) = &self.checkpoints_dialog {
+                let popup_area = ratatui::layout::Rect {
+                    x: area.x + area.width / 8,
+                    y: area.y + area.height / 8,
+                    width: area.width - area.width / 4,
+                    height: area.height - area.height / 4,
+                };
+                dialog.render(popup_area, frame.buffer_mut());
+            }
+        // Render SampleDialog as a popup overlay only if active
+        if self.sample_dialog_active
+            && let Some(dialog) = &self.sample_dialog {
+                let popup_area = ratatui::layout::Rect {
+                    x: area.x + area.width / 4,
+                    y: area.y + area.height / 4,
+                    width: area.width / 2,
+                    height: area.height / 2,
+                };
+                dialog.render(popup_area, frame.buffer_mut());
+            }
+        // Render ResampleDialog as a popup overlay only if active
+        if self.resample_dialog_active
+            && let Some(dialog) = &self.resample_dialog {
+                let popup_area = ratatui::layout::Rect {
+                    x: area.x + area.width / 4,
+                    y: area.y + area.height / 4,
+                    width: area.width / 2,
+                    height: area.height / 2,
+                };
+                dialog.render(popup_area, frame.buffer_mut());
+            }
+        // Render CrosstabDialog as a popup overlay only if active
+        if self.crosstab_dialog_active
+            && let Some(dialog) = &self.crosstab_dialog {
+                let popup_area = ratatui::layout::Rect {
+                    x: area.x + area.width / 8,
+                    y: area.y + area.height / 8,
+                    width: area.width * 3 / 4,
+                    height: area.height * 3 / 4,
+                };
+                dialog.render(popup_area, frame.buffer_mut());
+            }
+        // Render MissingDataDialog as a popup overlay only if active
+        if self.missing_data_dialog_active
+            && let Some(dialog) = &mut self.missing_data_dialog {
+                let popup_area = ratatui::layout::Rect {
+                    x: area.x + area.width / 8,
+                    y: area.y + area.height / 8,
+                    width: area.width * 3 / 4,
+                    height: area.height * 3 / 4,
+                };
+                dialog.render(popup_area, frame.buffer_mut());
+            }
+        // Render SigmaDialog as a popup overlay only if active
+        if self.sigma_dialog_active
+            && let Some(dialog) = &self.sigma_dialog {
+                let popup_area = ratatui::layout::Rect {
+                    x: area.x + area.width / 8,
+                    y: area.y + area.height / 4,
+                    width: area.width * 3 / 4,
+                    height: area.height / 2,
+                };
+                dialog.render(popup_area, frame.buffer_mut());
+            }
+        // Render PatternSetDialog as a popup overlay only if active
+        if self.pattern_set_dialog_active
+            && let Some(dialog) = &self.pattern_set_dialog {
+                let popup_area = ratatui::layout::Rect {
+                    x: area.x + area.width / 8,
+                    y: area.y + area.height / 4,
+                    width: area.width * 3 / 4,
+                    height: area.height / 2,
+                };
+                dialog.render(popup_area, frame.buffer_mut());
+            }
+        // Render RowTagsDialog as a popup overlay only if active
+        if self.row_tags_dialog_active
+            && let Some(dialog) = &self.row_tags_dialog {
+                let popup_area = ratatui::layout::Rect {
+                    x: area.x + area.width / 4,
+                    y: area.y + area.height / 4,
+                    width: area.width / 2,
+                    height: area.height / 2,
+                };
+                dialog.render(popup_area, frame.buffer_mut());
+            }
+        // Render ColumnMenuDialog as a popup overlay only if active
+        if self.column_menu_dialog_active
+            && let Some(dialog) = &self.column_menu_dialog {
+                let width = area.width.min(50);
+                let height = area.height.min(18);
+                let popup_area = ratatui::layout::Rect {
+                    x: area.x + (area.width - width) / 2,
+                    y: area.y + (area.height - height) / 2,
+                    width,
+                    height,
+                };
+                dialog.render(popup_area, frame.buffer_mut());
+            }
+        // Render GoToRowDialog as a popup overlay only if active
+        if self.go_to_row_dialog_active
+            && let Some(dialog) = &self.go_to_row_dialog {
+                let width = area.width.min(60);
+                let height = area.height.min(14);
+                let popup_area = ratatui::layout::Rect {
+                    x: area.x + (area.width - width) / 2,
+                    y: area.y + (area.height - height) / 2,
+                    width,
+                    height,
+                };
+                dialog.render(popup_area, frame.buffer_mut());
+            }
+        // Render GoToColumnDialog as a popup overlay only if active
+        if self.go_to_column_dialog_active
+            && let Some(dialog) = &self.go_to_column_dialog {
+                let width = area.width.min(60);
+                let height = area.height.min(24);
+                let popup_area = ratatui::layout::Rect {
+                    x: area.x + (area.width - width) / 2,
+                    y: area.y + (area.height - height) / 2,
+                    width,
+                    height,
+                };
+                dialog.render(popup_area, frame.buffer_mut());
+            }
+        // Render ValidationDialog as a popup overlay only if active
+        if self.validation_dialog_active
+            && let Some(dialog) = &self.validation_dialog {
+                let popup_area = ratatui::layout::Rect {
+                    x: area.x + area.width / 6,
+                    y: area.y + area.height / 6,
+                    width: area.width * 2 / 3,
+                    height: area.height * 2 / 3,
+                };
+                dialog.render(popup_area, frame.buffer_mut());
+            }
+        // Render FindingsReportDialog as a popup overlay only if active
+        if self.findings_report_dialog_active
+            && let Some(dialog) = &self.findings_report_dialog {
+                let popup_area = ratatui::layout::Rect {
+                    x: area.x + area.width / 8,
+                    y: area.y + area.height / 8,
+                    width: area.width * 3 / 4,
+                    height: area.height * 3 / 4,
+                };
+                dialog.render(popup_area, frame.buffer_mut());
+            }
+        // Render ScriptDialog as a popup overlay only if active
+        if self.script_dialog_active
+            && let Some(dialog) = &mut self.script_dialog {
+                let popup_area = ratatui::layout::Rect {
+                    x: area.x + area.width / 8,
+                    y: area.y + area.height / 8,
+                    width: area.width - area.width / 4,
+                    height: area.height - area.height / 4,
+                };
+                dialog.render(popup_area, frame.buffer_mut());
+            }
+        // Render HexViewerDialog as a popup overlay only if active
+        if self.hex_viewer_dialog_active
+            && let Some(dialog) = &mut self.hex_viewer_dialog {
+                let popup_area = ratatui::layout::Rect {
+                    x: area.x + area.width / 8,
+                    y: area.y + area.height / 8,
+                    width: area.width - area.width / 4,
+                    height: area.height - area.height / 4,
+                };
+                dialog.render(popup_area, frame.buffer_mut());
+            }
+        // Render ClusterReportDialog as a popup overlay only if active
+        if self.cluster_report_dialog_active
+            && let Some(dialog) = &self.cluster_report_dialog {
+                let popup_area = ratatui::layout::Rect {
+                    x: area.x + area.width / 8,
+                    y: area.y + area.height / 8,
+                    width: area.width - area.width / 4,
+                    height: area.height - area.height / 4,
+                };
+                dialog.render(popup_area, frame.buffer_mut());
+            }
+        // Render DisplaySettingsDialog as a popup overlay only if active
+        if self.display_settings_dialog_active
+            && let Some(dialog) = &self.display_settings_dialog {
+                let popup_area = ratatui::layout::Rect {
+                    x: area.x + area.width / 8,
+                    y: area.y + area.height / 8,
+                    width: area.width - area.width / 4,
+                    height: area.height - area.height / 4,
+                };
+                dialog.render(popup_area, frame.buffer_mut());
+            }
+        // Render HeatmapDialog as a popup overlay only if active
+        if self.heatmap_dialog_active
+            && let Some(dialog) = &self.heatmap_dialog {
+                let popup_area = ratatui::layout::Rect {
+                    x: area.x + area.width / 4,
+                    y: area.y + area.height / 4,
+                    width: area.width / 2,
+                    height: area.height / 2,
+                };
+                dialog.render(popup_area, frame.buffer_mut());
+            }
+        // Render ChartDialog as a popup overlay only if active
+        if self.chart_dialog_active
+            && let Some(dialog) = &self.chart_dialog {
+                let popup_area = ratatui::layout::Rect {
+                    x: area.x + area.width / 8,
+                    y: area.y + area.height / 8,
+                    width: area.width - area.width / 4,
+                    height: area.height - area.height / 4,
+                };
+                dialog.render(popup_area, frame.buffer_mut());
+            }
+        // Render BenfordDialog as a popup overlay only if active
+        if self.benford_dialog_active
+            && let Some(dialog) = &self.benford_dialog {
+                let popup_area = ratatui::layout::Rect {
+                    x: area.x + area.width / 8,
+                    y: area.y + area.height / 8,
+                    width: area.width - area.width / 4,
+                    height: area.height - area.height / 4,
+                };
+                dialog.render(popup_area, frame.buffer_mut());
+            }
+        // Render GroupByDialog as a popup overlay only if active
+        if self.group_by_dialog_active {
+            let popup_area = ratatui::layout::Rect {
+                x: area.x + area.width / 8,
+                y: area.y + area.height / 8,
+                width: area.width - area.width / 4,
+                height: area.height - area.height / 4,
+            };
+            self.group_by_dialog.render(popup_area, frame.buffer_mut());
+            self.last_group_by_dialog_area = Some(popup_area);
+        }
         // DataExportDialog rendering moved to DataTabManagerDialog
         // Render DataFrameDetailsDialog as a popup overlay only if active
         if self.dataframe_details_dialog_active {
@@ -2669,6 +5428,31 @@ impl Component for DataTableContainer {
             self.last_dataframe_details_dialog_area = Some(popup_area);
             self.last_dataframe_details_dialog_max_rows = Some(max_rows);
         }
+        // Render the message dialog above other popups
+        if let Some(dialog) = &self.message_dialog {
+            let popup_area = ratatui::layout::Rect {
+                x: area.x + area.width / 4,
+                y: area.y + area.height / 4,
+                width: area.width / 2,
+                height: area.height / 2,
+            };
+            ratatui::widgets::Clear.render(popup_area, frame.buffer_mut());
+            dialog.render(popup_area, frame.buffer_mut());
+        }
+        // Render the error dialog above other popups
+        if let Some(dialog) = &self.error_dialog {
+            let popup_area = ratatui::layout::Rect {
+                x: area.x + area.width / 8,
+                y: area.y + area.height / 8,
+                width: area.width - area.width / 4,
+                height: area.height - area.height / 4,
+            };
+            dialog.render(popup_area, frame.buffer_mut());
+        }
+        // Render the confirm dialog above other popups
+        if let Some(dialog) = &self.confirm_dialog {
+            dialog.render(area, frame.buffer_mut());
+        }
         // Render busy/progress overlay if active (always on top)
         if self.busy_active {
             use ratatui::widgets::Gauge;
@@ -2685,7 +5469,7 @@ impl Component for DataTableContainer {
                 .block(Block::default().title(self.busy_message.clone()).borders(Borders::ALL))
                 .ratio(self.busy_progress.clamp(0.0, 1.0))
                 .style(RtStyle::default().fg(Color::Yellow))
-                .label("Working...");
+                .label("Working... (Esc to cancel)");
             gauge.render(popup_area, frame.buffer_mut());
         }
         Ok(())
@@ -2713,10 +5497,8 @@ pub struct EmbeddingsJob {
     pub row_texts: Vec<Option<String>>,
     pub uniques: Vec<String>,
     pub unique_index: std::collections::HashMap<String, usize>,
-    pub unique_embeddings: Vec<Vec<f32>>, // filled progressively, aligned with uniques
-    pub next_start: usize,
+    pub unique_embeddings: Vec<Option<Vec<f32>>>, // filled when the worker finishes, aligned with uniques; None for failed batches
     pub batch_size: usize,
-    pub total_uniques: usize,
 }
 
 #[derive(Debug, Clone)]
@@ -2726,6 +5508,117 @@ pub struct QueuedPca {
     pub k: usize,
 }
 
+#[derive(Debug, Clone)]
+pub struct QueuedTsne {
+    pub source_column: String,
+    pub new_column_name: String,
+    pub perplexity: usize,
+    pub iterations: usize,
+}
+
+/// A column operation limited to the rows of a filter. Until it has added its column, the view is
+/// the original data with the source column nulled outside those rows.
+#[derive(Debug, Clone)]
+pub struct MaskedOperation {
+    /// The source column as it is in the original data
+    pub source: polars::prelude::Column,
+    /// View and filter to go back to when the operation fails or is cancelled
+    pub previous: Arc<polars::prelude::DataFrame>,
+    pub previous_filter: Option<FilterExpr>,
+}
+
+/// Result of a column operation computed on a worker thread; applied to the table by the UI thread
+#[derive(Debug)]
+pub enum ColumnJobOutput {
+    Pca { source_column: String, new_column_name: String, rows: Vec<Vec<f32>> },
+    Tsne { source_column: String, new_column_name: String, coords: Vec<[f64; 2]> },
+    /// `labels` is None for an elbow sweep, which only reports quality
+    Cluster { source_column: String, new_column_name: String, labels: Option<Vec<usize>>, quality: Vec<ClusterQuality> },
+    Embeddings { unique_embeddings: Vec<Option<Vec<f32>>>, warnings: Vec<String> },
+    /// One summary per row; `warnings` lists indicators whose lookup failed
+    ThreatIntel { source_column: String, new_column_name: String, provider: ThreatIntelProvider, values: Vec<Option<String>>, warnings: Vec<String> },
+    /// Rows of a sorted, filtered, JMESPath or script view; the step that produced it is the pending history step
+    View(polars::prelude::DataFrame),
+    /// Rows of a sorted or filtered view too large to hold, streamed to a spill file
+    SpilledView(SpillFile),
+    /// Output of a script run into a new dataset
+    NewDataset { dataset_name: String, df: polars::prelude::DataFrame },
+}
+
+#[cfg(feature = "clustering")]
+fn vectors_to_array(data: &[Vec<f64>]) -> Array2<f64> {
+    let d = data.first().map(|row| row.len()).unwrap_or(0);
+    let mut x = Array2::<f64>::zeros((data.len(), d));
+    for (i, row) in data.iter().enumerate() { for (j, v) in row.iter().enumerate() { x[[i, j]] = *v; } }
+    x
+}
+
+/// Project vectors onto their first `target_k` principal components
+#[cfg(feature = "clustering")]
+fn compute_pca(data: &[Vec<f64>], target_k: usize) -> color_eyre::Result<Vec<Vec<f32>>> {
+    let Some(first) = data.first() else { return Ok(Vec::new()) };
+    let k = target_k.clamp(1, first.len());
+    let ds = linfa::DatasetBase::from(vectors_to_array(data));
+    let pca = LinfaPca::params(k).fit(&ds).map_err(|e| color_eyre::eyre::eyre!("PCA fit failed: {:?}", e))?;
+    let y: ArrayBase<OwnedRepr<f64>, Ix2> = pca.predict(ds).records;
+    Ok((0..data.len()).map(|i| (0..k).map(|j| y[(i, j)] as f32).collect()).collect())
+}
+
+#[cfg(not(feature = "clustering"))]
+fn compute_pca(_data: &[Vec<f64>], _target_k: usize) -> color_eyre::Result<Vec<Vec<f32>>> {
+    Err(color_eyre::eyre::eyre!("PCA requires datatui built with the \"clustering\" feature"))
+}
+
+/// K-means labels for `data` with `k` clusters
+#[cfg(feature = "clustering")]
+fn kmeans_labels(data: &[Vec<f64>], k: usize) -> color_eyre::Result<Vec<usize>> {
+    let ds = DatasetBase::from(vectors_to_array(data));
+    let model = KMeans::params(k).fit(&ds).map_err(|e| color_eyre::eyre::eyre!("KMeans fit failed: {:?}", e))?;
+    Ok(model.predict(ds).targets.to_vec())
+}
+
+#[cfg(not(feature = "clustering"))]
+fn kmeans_labels(_data: &[Vec<f64>], _k: usize) -> color_eyre::Result<Vec<usize>> {
+    Err(color_eyre::eyre::eyre!("K-means requires datatui built with the \"clustering\" feature"))
+}
+
+/// Cluster vectors, returning labels (None for an elbow sweep) and the quality of each run
+fn compute_clusters(
+    data: &[Vec<f64>],
+    algorithm: ClusterAlgorithm,
+    kmeans: Option<KmeansOptions>,
+    ctx: &JobContext,
+) -> color_eyre::Result<(Option<Vec<usize>>, Vec<ClusterQuality>)> {
+    let nrows = data.len();
+    if nrows == 0 { return Ok((None, Vec::new())); }
+    match algorithm {
+        ClusterAlgorithm::Kmeans => {
+            // Elbow sweep: score k = 2..=N without touching the table
+            if let Some(opts) = kmeans.as_ref().filter(|o| o.elbow_sweep) {
+                let max_k = opts.number_of_clusters.min(nrows);
+                if max_k < 2 { return Err(color_eyre::eyre::eyre!("Elbow sweep needs a maximum of at least 2 clusters")); }
+                let mut results = Vec::with_capacity(max_k - 1);
+                for k in 2..=max_k {
+                    ctx.check_cancelled()?;
+                    let labels = kmeans_labels(data, k)?;
+                    results.push(cluster_quality(data, &labels, k));
+                    ctx.set_progress((k - 1) as f64 / (max_k - 1) as f64);
+                }
+                return Ok((None, results));
+            }
+            let k = kmeans.map(|o| o.number_of_clusters).unwrap_or(8);
+            let labels = kmeans_labels(data, k)?;
+            ctx.check_cancelled()?;
+            let quality = cluster_quality(data, &labels, k);
+            Ok((Some(labels), vec![quality]))
+        }
+        ClusterAlgorithm::Dbscan => {
+            // DBSCAN not available with current linfa_clustering API in this build; return error
+            Err(color_eyre::eyre::eyre!("DBSCAN clustering is currently unsupported in this build"))
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct QueuedCluster {
     pub source_column: String,
@@ -2734,3 +5627,67 @@ pub struct QueuedCluster {
     pub kmeans: Option<KmeansOptions>,
     pub dbscan: Option<DbscanOptions>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dataframe::manager::ManagedDataFrame;
+    use crate::dialog::filter_dialog::{ColumnFilter, FilterCondition};
+    use polars::prelude::*;
+
+    fn hash_step(source: &str, new_column: &str, only_rows: Option<FilterExpr>) -> TransformStep {
+        TransformStep::ColumnOperation(ColumnOperationConfig {
+            operation: ColumnOperationKind::Hash,
+            new_column_name: new_column.to_string(),
+            source_column: source.to_string(),
+            hide_new_column: false,
+            options: OperationOptions::Hash { algorithm: HashAlgorithm::Sha256 },
+            only_rows,
+        })
+    }
+
+    #[test]
+    fn test_filtered_rows_operation_keeps_earlier_columns() {
+        let df = df!("status" => ["500", "200", "500"], "ip" => ["1.1.1.1", "2.2.2.2", "3.3.3.3"], "user" => ["a", "b", "c"]).unwrap();
+        let managed = ManagedDataFrame::new(df, "events".to_string(), None, None);
+        let mut container = DataTableContainer::new(DataTable::new(managed, StyleConfig::default()), StyleConfig::default());
+        let filter = FilterExpr::Condition(ColumnFilter {
+            column: "status".to_string(),
+            condition: FilterCondition::Equals { value: "500".to_string(), case_sensitive: false },
+        });
+        for step in [TransformStep::Filter(filter.clone()), hash_step("user", "user_hash", None), hash_step("ip", "ip_hash", Some(filter))] {
+            assert!(container.apply_transform_step(&step).unwrap());
+            container.record_transform(step);
+        }
+
+        let view = container.datatable.get_dataframe().unwrap();
+        assert_eq!(view.height(), 3);
+        assert_eq!(view.column("user_hash").unwrap().null_count(), 0);
+        assert_eq!(view.column("ip_hash").unwrap().null_count(), 1);
+        assert_eq!(view.column("ip").unwrap().null_count(), 0);
+    }
+
+    #[test]
+    fn test_regex_extract_names_groups() {
+        let df = df!("email" => ["alice@corp", "bob@home"], "user" => ["a", "b"]).unwrap();
+        let managed = ManagedDataFrame::new(df, "accounts".to_string(), None, None);
+        let mut container = DataTableContainer::new(DataTable::new(managed, StyleConfig::default()), StyleConfig::default());
+        let pattern = r"(?P<user>\w+)@(\w+)";
+
+        // Named groups keep their name (renamed on a clash), unnamed ones take the source column's
+        container.apply_regex_extract("email", "", pattern).unwrap();
+        let view = container.datatable.get_dataframe().unwrap();
+        assert_eq!(view.get_column_names_owned().iter().map(|n| n.as_str()).collect::<Vec<_>>(), ["email", "user", "user__regex", "email_2"]);
+        assert_eq!(view.column("user__regex").unwrap().str().unwrap().get(0), Some("alice"));
+        assert_eq!(view.column("email_2").unwrap().str().unwrap().get(1), Some("home"));
+
+        // A prefix names every group
+        container.apply_regex_extract("email", "m", pattern).unwrap();
+        let view = container.datatable.get_dataframe().unwrap();
+        assert_eq!(view.column("m_user").unwrap().str().unwrap().get(1), Some("bob"));
+        assert_eq!(view.column("m_2").unwrap().str().unwrap().get(0), Some("corp"));
+
+        let error = container.apply_regex_extract("email", "", r"\w+@\w+").unwrap_err();
+        assert_eq!(error.to_string(), "Pattern has no capture groups");
+    }
+}
@@ -0,0 +1,333 @@
+//! GroupView: the current view grouped by one or more columns into collapsible nodes, shown in
+//! place of the data table
+//!
+//! Each node lists its row count, share of all rows and, with an aggregate column, the sum and
+//! mean of that column. The view starts with the first level only; a node's subgroups are grouped
+//! by Polars when it is expanded, so a large view is never walked row by row.
+
+use std::collections::HashSet;
+
+use crossterm::event::{KeyEvent, KeyEventKind};
+use polars::prelude::LazyFrame;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Cell, Row, Table};
+
+use crate::action::Action;
+use crate::config::{Config, Mode};
+use crate::dataframe::grouping::{group_children, group_level, GroupRow};
+
+pub struct GroupView {
+    pub group_columns: Vec<String>,
+    pub aggregate_column: Option<String>,
+    /// Groups on screen in display order; an expanded group's subgroups follow it
+    pub rows: Vec<GroupRow>,
+    expanded: HashSet<Vec<Option<String>>>,
+    pub selected: usize,
+    scroll_offset: usize,
+    visible_rows: usize,
+    total_rows: usize,
+    /// The view the groups were computed from
+    plan: LazyFrame,
+    error: Option<String>,
+    config: Config,
+}
+
+impl std::fmt::Debug for GroupView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GroupView")
+            .field("group_columns", &self.group_columns)
+            .field("aggregate_column", &self.aggregate_column)
+            .field("rows", &self.rows.len())
+            .field("selected", &self.selected)
+            .finish()
+    }
+}
+
+impl GroupView {
+    /// Group `plan` by `group_columns`, computing the first level
+    pub fn new(plan: LazyFrame, group_columns: Vec<String>, aggregate_column: Option<String>) -> color_eyre::Result<Self> {
+        let rows = group_children(plan.clone(), &group_columns, &[], aggregate_column.as_deref())?;
+        Ok(Self {
+            total_rows: rows.iter().map(|g| g.count).sum(),
+            group_columns,
+            aggregate_column,
+            rows,
+            expanded: HashSet::new(),
+            selected: 0,
+            scroll_offset: 0,
+            visible_rows: 1,
+            plan,
+            error: None,
+            config: Config::default(),
+        })
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn is_leaf(&self, group: &GroupRow) -> bool {
+        group.path.len() >= self.group_columns.len()
+    }
+
+    /// Group a node's subgroups and show them below it
+    fn expand(&mut self, index: usize) {
+        let Some(group) = self.rows.get(index) else { return };
+        if self.is_leaf(group) || self.expanded.contains(&group.path) {
+            return;
+        }
+        let path = group.path.clone();
+        match group_children(self.plan.clone(), &self.group_columns, &path, self.aggregate_column.as_deref()) {
+            Ok(children) => {
+                self.rows.splice(index + 1..index + 1, children);
+                self.expanded.insert(path);
+                self.error = None;
+            }
+            Err(e) => self.error = Some(e.to_string()),
+        }
+    }
+
+    /// Hide a node's subgroups. Returns false when it was not expanded.
+    fn collapse(&mut self, index: usize) -> bool {
+        let Some(path) = self.rows.get(index).map(|g| g.path.clone()) else { return false };
+        if !self.expanded.remove(&path) {
+            return false;
+        }
+        let end = self.rows[index + 1..]
+            .iter()
+            .position(|g| !g.path.starts_with(&path))
+            .map_or(self.rows.len(), |n| index + 1 + n);
+        self.rows.drain(index + 1..end);
+        self.expanded.retain(|p| !p.starts_with(&path));
+        true
+    }
+
+    /// Collapse the selected node, or move to its parent if it is already collapsed
+    fn collapse_selected(&mut self) {
+        if self.collapse(self.selected) {
+            return;
+        }
+        let Some(path) = self.rows.get(self.selected).map(|g| g.path.clone()) else { return };
+        if path.len() > 1
+            && let Some(parent) = self.rows[..self.selected].iter().rposition(|g| g.path[..] == path[..path.len() - 1])
+        {
+            self.selected = parent;
+        }
+    }
+
+    /// Group every level across the whole view, one Polars query per level
+    fn expand_all(&mut self) {
+        let mut levels = Vec::with_capacity(self.group_columns.len());
+        for depth in 0..self.group_columns.len() {
+            match group_level(self.plan.clone(), &self.group_columns, depth, self.aggregate_column.as_deref()) {
+                Ok(level) => levels.push(level),
+                Err(e) => {
+                    self.error = Some(e.to_string());
+                    return;
+                }
+            }
+        }
+        // Each level is in key order, so a parent's subgroups are the next run of the level below
+        fn place(levels: &mut [std::iter::Peekable<std::vec::IntoIter<GroupRow>>], depth: usize, parent: &[Option<String>], out: &mut Vec<GroupRow>) {
+            while let Some(group) = levels[depth].next_if(|g| g.path.starts_with(parent)) {
+                let path = group.path.clone();
+                out.push(group);
+                if depth + 1 < levels.len() {
+                    place(levels, depth + 1, &path, out);
+                }
+            }
+        }
+        let mut levels: Vec<_> = levels.into_iter().map(|l| l.into_iter().peekable()).collect();
+        let mut rows = Vec::new();
+        place(&mut levels, 0, &[], &mut rows);
+        self.expanded = rows.iter().filter(|g| !self.is_leaf(g)).map(|g| g.path.clone()).collect();
+        self.rows = rows;
+        self.error = None;
+    }
+
+    fn collapse_all(&mut self) {
+        self.rows.retain(|g| g.path.len() == 1);
+        self.expanded.clear();
+        self.selected = 0;
+    }
+
+    fn move_selection(&mut self, action: Action) {
+        if action == Action::Up {
+            self.selected = self.selected.saturating_sub(1);
+        } else if self.selected + 1 < self.rows.len() {
+            self.selected += 1;
+        }
+    }
+
+    fn update_scroll_offset(&mut self) {
+        let visible_rows = self.visible_rows.max(1);
+        if self.selected < self.scroll_offset {
+            self.scroll_offset = self.selected;
+        } else if self.selected >= self.scroll_offset + visible_rows {
+            self.scroll_offset = self.selected + 1 - visible_rows;
+        }
+    }
+
+    /// Instructions from the configured keybindings
+    pub fn instructions(&self) -> String {
+        self.config.actions_to_instructions(&[
+            (Mode::Global, Action::Enter),
+            (Mode::Global, Action::Escape),
+            (Mode::Global, Action::Tab),
+            (Mode::GroupBy, Action::GoToGroupRow),
+            (Mode::GroupBy, Action::ExpandAllGroups),
+            (Mode::GroupBy, Action::CollapseAllGroups),
+        ])
+    }
+
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let mut title = format!("Groups: {}", self.group_columns.join(" > "));
+        if let Some(error) = &self.error {
+            title.push_str(&format!("  Error: {error}"));
+        }
+        let block = Block::default().title(title).borders(Borders::ALL);
+        // Borders and the header row
+        self.visible_rows = area.height.saturating_sub(3).max(1) as usize;
+        self.selected = self.selected.min(self.rows.len().saturating_sub(1));
+        self.update_scroll_offset();
+
+        let total = self.total_rows.max(1) as f64;
+        let rows: Vec<Row> = self
+            .rows
+            .iter()
+            .enumerate()
+            .skip(self.scroll_offset)
+            .take(self.visible_rows)
+            .map(|(i, group)| {
+                let glyph = if self.is_leaf(group) {
+                    " "
+                } else if self.expanded.contains(&group.path) {
+                    "▼"
+                } else {
+                    "▶"
+                };
+                let mut cells = vec![
+                    Cell::from(format!("{}{} {}", "  ".repeat(group.path.len() - 1), glyph, group.key())),
+                    Cell::from(group.count.to_string()),
+                    Cell::from(format!("{:.1}%", group.count as f64 * 100.0 / total)),
+                ];
+                if self.aggregate_column.is_some() {
+                    cells.push(Cell::from(group.sum.map(|v| format!("{v:.3}")).unwrap_or_default()));
+                    cells.push(Cell::from(group.mean.map(|v| format!("{v:.3}")).unwrap_or_default()));
+                }
+                let style = if i == self.selected {
+                    Style::default().fg(Color::Black).bg(Color::Cyan).add_modifier(Modifier::BOLD)
+                } else if self.is_leaf(group) {
+                    Style::default()
+                } else {
+                    Style::default().fg(Color::Yellow)
+                };
+                Row::new(cells).style(style)
+            })
+            .collect();
+
+        let mut header = vec!["Group".to_string(), "Rows".to_string(), "Share".to_string()];
+        let mut widths = vec![Constraint::Min(20), Constraint::Length(12), Constraint::Length(8)];
+        if let Some(column) = &self.aggregate_column {
+            header.push(format!("sum({column})"));
+            header.push(format!("mean({column})"));
+            widths.extend([Constraint::Length(16), Constraint::Length(16)]);
+        }
+        let table = Table::new(rows, widths)
+            .header(Row::new(header).style(Style::default().add_modifier(Modifier::BOLD)))
+            .block(block);
+        Widget::render(table, area, buf);
+    }
+
+    /// Handle keys while the view is shown. Escape returns `DialogClose` to go back to the table
+    /// and Tab returns `OpenGroupByDialog` to choose other columns.
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        if let Some(global_action) = self.config.action_for_key(Mode::Global, key) {
+            match global_action {
+                Action::Escape => return Some(Action::DialogClose),
+                Action::Tab => return Some(Action::OpenGroupByDialog),
+                Action::Enter => {
+                    if !self.collapse(self.selected) {
+                        self.expand(self.selected);
+                    }
+                    return None;
+                }
+                Action::Right => {
+                    self.expand(self.selected);
+                    return None;
+                }
+                Action::Left => {
+                    self.collapse_selected();
+                    return None;
+                }
+                Action::Up | Action::Down => {
+                    self.move_selection(global_action);
+                    return None;
+                }
+                _ => {}
+            }
+        }
+        match self.config.action_for_key(Mode::GroupBy, key)? {
+            // Letter keys from a keymap profile (vim's j/k)
+            action @ (Action::Up | Action::Down) => self.move_selection(action),
+            Action::GoToGroupRow => {
+                let group = self.rows.get(self.selected)?;
+                return Some(Action::GoToResult {
+                    row: group.first_row,
+                    column: self.group_columns[group.path.len() - 1].clone(),
+                });
+            }
+            Action::ExpandAllGroups => self.expand_all(),
+            Action::CollapseAllGroups => self.collapse_all(),
+            _ => {}
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use polars::prelude::*;
+
+    fn press(view: &mut GroupView, code: KeyCode, modifiers: KeyModifiers) -> Option<Action> {
+        view.handle_key_event(KeyEvent::new(code, modifiers))
+    }
+
+    #[test]
+    fn test_expand_collapse_and_go_to_group() {
+        let df = df! {
+            "host" => ["a", "b", "a", "a", "b"],
+            "user" => ["x", "y", "y", "x", "y"],
+        }
+        .unwrap();
+        let mut view = GroupView::new(df.lazy(), vec!["host".into(), "user".into()], None).unwrap();
+        view.config.reset_keybindings_to_default();
+        assert_eq!(view.rows.len(), 2);
+
+        // Expand "a" and go to the first row of its second subgroup
+        press(&mut view, KeyCode::Enter, KeyModifiers::NONE);
+        assert_eq!(view.rows.iter().map(GroupRow::key).collect::<Vec<_>>(), ["a", "x", "y", "b"]);
+        press(&mut view, KeyCode::Down, KeyModifiers::NONE);
+        press(&mut view, KeyCode::Down, KeyModifiers::NONE);
+        let action = press(&mut view, KeyCode::Char('g'), KeyModifiers::CONTROL);
+        assert_eq!(action, Some(Action::GoToResult { row: 2, column: "user".to_string() }));
+
+        // Left on a subgroup moves to its parent, then collapses it
+        press(&mut view, KeyCode::Left, KeyModifiers::NONE);
+        assert_eq!(view.selected, 0);
+        press(&mut view, KeyCode::Left, KeyModifiers::NONE);
+        assert_eq!(view.rows.len(), 2);
+
+        press(&mut view, KeyCode::Right, KeyModifiers::CONTROL);
+        assert_eq!(view.rows.iter().map(GroupRow::key).collect::<Vec<_>>(), ["a", "x", "y", "b", "y"]);
+        press(&mut view, KeyCode::Left, KeyModifiers::CONTROL);
+        assert_eq!(view.rows.len(), 2);
+    }
+}
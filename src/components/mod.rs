@@ -1,8 +1,15 @@
+pub mod background_job;
+pub mod cell_text;
+pub mod column_search;
 pub mod datatable;
 pub mod fps;
+pub mod group_view;
 pub mod home;
 pub mod datatable_container;
 pub mod dialog_layout;
+pub mod syntax_highlight;
+pub mod table_view;
+pub mod timeline_panel;
 
 use color_eyre::Result;
 use crossterm::event::{KeyEvent, MouseEvent};
@@ -34,7 +41,7 @@ use crate::{action::Action, config::Config, tui::Event};
 /// # fn example(key: KeyEvent) -> Result<Option<Action>> {
 /// if key.code == KeyCode::Char('s') {
 ///     return Ok(Some(Action::SortDialogApplied(vec![
-///         SortColumn { name: "foo".to_string(), ascending: true }
+///         SortColumn::new("foo", true)
 ///     ])));
 /// }
 /// Ok(None)
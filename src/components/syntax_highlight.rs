@@ -0,0 +1,141 @@
+use lazy_static::lazy_static;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use regex::Regex;
+
+/// Kind of structured content detected in a cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentKind {
+    Json,
+    Xml,
+    Sql,
+    Url,
+    Plain,
+}
+
+lazy_static! {
+    static ref SQL_START: Regex = Regex::new(
+        r"(?i)^\s*(select|with|insert|update|delete|create|alter|drop|pragma)\b"
+    ).unwrap();
+    static ref URL_START: Regex = Regex::new(r"(?i)^[a-z][a-z0-9+.-]*://\S+$").unwrap();
+
+    static ref JSON_TOKENS: Regex = Regex::new(
+        r#"(?P<key>"(?:[^"\\]|\\.)*")\s*:|(?P<str>"(?:[^"\\]|\\.)*")|(?P<num>-?\b\d+(?:\.\d+)?(?:[eE][+-]?\d+)?\b)|(?P<lit>\b(?:true|false|null)\b)"#
+    ).unwrap();
+    static ref XML_TOKENS: Regex = Regex::new(
+        r#"(?P<comment><!--.*?-->)|(?P<tag></?[A-Za-z_][\w:.-]*|/?>)|(?P<attr>[A-Za-z_][\w:.-]*)=(?P<val>"[^"]*"|'[^']*')"#
+    ).unwrap();
+    static ref SQL_TOKENS: Regex = Regex::new(
+        r#"(?i)(?P<str>'(?:[^']|'')*')|(?P<comment>--.*$)|(?P<num>\b\d+(?:\.\d+)?\b)|(?P<kw>\b(?:select|from|where|and|or|not|in|is|null|as|join|left|right|inner|outer|full|cross|on|group|by|order|having|limit|offset|union|all|distinct|insert|into|values|update|set|delete|create|table|view|index|alter|drop|with|case|when|then|else|end|asc|desc|like|between|exists|pragma)\b)"#
+    ).unwrap();
+    static ref URL_TOKENS: Regex = Regex::new(
+        r"(?P<scheme>^[A-Za-z][A-Za-z0-9+.-]*://)(?P<host>[^/?#\s]*)|(?P<key>[?&][^=&#\s]+)=|(?P<frag>#\S*$)"
+    ).unwrap();
+}
+
+/// Guess the kind of content in a cell from its first non-whitespace characters
+pub fn detect_content_kind(text: &str) -> ContentKind {
+    let trimmed = text.trim();
+    if (trimmed.starts_with('{') && trimmed.ends_with('}'))
+        || (trimmed.starts_with('[') && trimmed.ends_with(']'))
+    {
+        ContentKind::Json
+    } else if trimmed.starts_with('<') && trimmed.ends_with('>') {
+        ContentKind::Xml
+    } else if URL_START.is_match(trimmed) {
+        ContentKind::Url
+    } else if SQL_START.is_match(trimmed) {
+        ContentKind::Sql
+    } else {
+        ContentKind::Plain
+    }
+}
+
+/// Build spans from styled byte ranges. Ranges are sorted and overlapping ranges dropped,
+/// keeping the earliest one.
+pub fn spans_from_styled_ranges(text: &str, mut ranges: Vec<(usize, usize, Style)>) -> Vec<Span<'static>> {
+    ranges.sort_by_key(|(start, _, _)| *start);
+    let mut spans = Vec::new();
+    let mut last_end = 0;
+    for (start, end, style) in ranges {
+        if start < last_end || end > text.len() || start >= end {
+            continue;
+        }
+        if start > last_end {
+            spans.push(Span::raw(text[last_end..start].to_string()));
+        }
+        spans.push(Span::styled(text[start..end].to_string(), style));
+        last_end = end;
+    }
+    if last_end < text.len() {
+        spans.push(Span::raw(text[last_end..].to_string()));
+    }
+    spans
+}
+
+fn group_ranges(re: &Regex, text: &str, groups: &[(&str, Style)]) -> Vec<(usize, usize, Style)> {
+    let mut ranges = Vec::new();
+    for caps in re.captures_iter(text) {
+        for (name, style) in groups {
+            if let Some(m) = caps.name(name) {
+                ranges.push((m.start(), m.end(), *style));
+            }
+        }
+    }
+    ranges
+}
+
+/// Styled byte ranges for one line of `kind` content
+pub fn highlight_ranges(text: &str, kind: ContentKind) -> Vec<(usize, usize, Style)> {
+    let key = Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+    let string = Style::default().fg(Color::Green);
+    let number = Style::default().fg(Color::Magenta);
+    let keyword = Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+    let comment = Style::default().fg(Color::DarkGray);
+    match kind {
+        ContentKind::Json => group_ranges(&JSON_TOKENS, text, &[
+            ("key", key), ("str", string), ("num", number), ("lit", keyword),
+        ]),
+        ContentKind::Xml => group_ranges(&XML_TOKENS, text, &[
+            ("comment", comment), ("tag", key), ("attr", keyword), ("val", string),
+        ]),
+        ContentKind::Sql => group_ranges(&SQL_TOKENS, text, &[
+            ("str", string), ("comment", comment), ("num", number), ("kw", keyword),
+        ]),
+        ContentKind::Url => group_ranges(&URL_TOKENS, text, &[
+            ("scheme", comment), ("host", key), ("key", keyword), ("frag", string),
+        ]),
+        ContentKind::Plain => Vec::new(),
+    }
+}
+
+/// Highlight one line of `kind` content
+pub fn highlight_line(text: &str, kind: ContentKind) -> Line<'static> {
+    Line::from(spans_from_styled_ranges(text, highlight_ranges(text, kind)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_content_kind() {
+        assert_eq!(detect_content_kind(r#"{"a": 1}"#), ContentKind::Json);
+        assert_eq!(detect_content_kind("<a href='x'>y</a>"), ContentKind::Xml);
+        assert_eq!(detect_content_kind("SELECT * FROM t"), ContentKind::Sql);
+        assert_eq!(detect_content_kind("https://example.com/a?b=c"), ContentKind::Url);
+        assert_eq!(detect_content_kind("hello world"), ContentKind::Plain);
+    }
+
+    #[test]
+    fn test_highlight_sql_keywords_and_strings() {
+        let line = highlight_line("select name from t where x = 'a'", ContentKind::Sql);
+        let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(text, "select name from t where x = 'a'");
+        let styled: Vec<&str> = line.spans.iter()
+            .filter(|s| s.style != Style::default())
+            .map(|s| s.content.as_ref())
+            .collect();
+        assert_eq!(styled, vec!["select", "from", "where", "'a'"]);
+    }
+}
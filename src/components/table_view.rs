@@ -0,0 +1,147 @@
+//! TableView: the DataTable with its cell viewer, for embedding in other ratatui apps
+//!
+//! The view owns a `DataTable` (selection, scrolling, styling) and opens the `CellViewerDialog`
+//! over it on the configured key. None of the container's other dialogs are involved, so an app
+//! only needs to forward key events and call `render`:
+//!
+//! ```no_run
+//! use datatui::components::table_view::TableView;
+//! use polars::prelude::*;
+//!
+//! let df = df!("host" => ["web-1", "web-2"], "status" => [200, 500])?;
+//! let mut view = TableView::new(df, "requests");
+//! // In the app's draw closure: view.render(area, frame.buffer_mut())?;
+//! // In its event loop:        view.handle_key_event(key)?;
+//! # Ok::<(), color_eyre::Report>(())
+//! ```
+
+use color_eyre::Result;
+use crossterm::event::KeyEvent;
+use polars::prelude::DataFrame;
+use ratatui::buffer::Buffer;
+use ratatui::prelude::{Frame, Rect};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::action::Action;
+use crate::components::Component;
+use crate::components::datatable::DataTable;
+use crate::config::{Config, Mode};
+use crate::dataframe::manager::ManagedDataFrame;
+use crate::dialog::cell_viewer_dialog::CellViewerDialog;
+
+/// A DataTable plus an optional cell viewer popup
+#[derive(Debug)]
+pub struct TableView {
+    pub table: DataTable,
+    /// Open cell viewer, if any; key events go to it until it closes
+    pub cell_viewer: Option<CellViewerDialog>,
+    /// Whether the cell viewer key opens the viewer
+    pub cell_viewer_enabled: bool,
+    config: Config,
+}
+
+impl TableView {
+    /// Show `df` with the built-in keybindings and theme
+    pub fn new(df: DataFrame, name: impl Into<String>) -> Self {
+        Self::from_managed(ManagedDataFrame::new(df, name.into(), None, None), Config::builtin())
+    }
+
+    /// Show a managed DataFrame (with its sort, filter and display settings) using `config`
+    pub fn from_managed(dataframe: ManagedDataFrame, config: Config) -> Self {
+        let mut table = DataTable::new(dataframe, config.style_config.clone());
+        table.config = config.clone();
+        Self { table, cell_viewer: None, cell_viewer_enabled: true, config }
+    }
+
+    /// Open the cell viewer on the selected cell
+    pub fn open_cell_viewer(&mut self) -> Result<()> {
+        let column = self.table.get_visible_columns()?.get(self.table.selection.col).cloned().unwrap_or_default();
+        let value = self.table.selected_cell_json_value()?;
+        let mut dialog = CellViewerDialog::new(column, self.table.selection.row, value);
+        dialog.register_config_handler(self.config.clone())?;
+        self.cell_viewer = Some(dialog);
+        Ok(())
+    }
+
+    /// Route a key to the cell viewer when open, otherwise to the table
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        if let Some(dialog) = &mut self.cell_viewer {
+            if let Some(Action::DialogClose) = dialog.handle_key_event(key) {
+                self.cell_viewer = None;
+            }
+            return Ok(None);
+        }
+        if self.cell_viewer_enabled
+            && self.config.action_for_key(Mode::DataTableContainer, key) == Some(Action::OpenCellViewerDialog)
+        {
+            self.open_cell_viewer()?;
+            return Ok(None);
+        }
+        self.table.handle_key_event(key)
+    }
+
+    /// Render the table, with the cell viewer over its middle when open
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) -> Result<()> {
+        self.table.render(area, buf)?;
+        if let Some(dialog) = &mut self.cell_viewer {
+            let popup_area = Rect {
+                x: area.x + area.width / 8,
+                y: area.y + area.height / 8,
+                width: area.width - area.width / 4,
+                height: area.height - area.height / 4,
+            };
+            dialog.render(popup_area, buf);
+        }
+        Ok(())
+    }
+}
+
+impl Component for TableView {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) -> Result<()> {
+        let _ = tx;
+        Ok(())
+    }
+
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.table.register_config_handler(config.clone())?;
+        self.config = config;
+        Ok(())
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        TableView::handle_key_event(self, key)
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
+        self.render(area, frame.buffer_mut())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use polars::prelude::*;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    #[test]
+    fn test_navigate_and_open_cell_viewer() {
+        let df = df!("host" => ["web-1", "web-2"], "payload" => ["{}", "{\"status\": 500}"]).unwrap();
+        let mut view = TableView::new(df, "requests");
+        view.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE)).unwrap();
+        view.handle_key_event(KeyEvent::new(KeyCode::Right, KeyModifiers::NONE)).unwrap();
+        assert_eq!((view.table.selection.row, view.table.selection.col), (1, 1));
+
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('V'), KeyModifiers::SHIFT)).unwrap();
+        assert_eq!(view.cell_viewer.as_ref().map(|d| d.raw.as_str()), Some("{\"status\": 500}"));
+
+        let mut terminal = Terminal::new(TestBackend::new(60, 16)).unwrap();
+        terminal.draw(|frame| view.render(frame.area(), frame.buffer_mut()).unwrap()).unwrap();
+        let text: String = terminal.backend().buffer().content().iter().map(|c| c.symbol()).collect();
+        assert!(text.contains("payload (row 2)"));
+        assert!(text.contains("status"));
+
+        view.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)).unwrap();
+        assert!(view.cell_viewer.is_none());
+    }
+}
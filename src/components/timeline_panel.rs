@@ -0,0 +1,220 @@
+//! TimelinePanel: event-density strip over a datetime column, drawn above the data table
+//!
+//! While focused, Left/Right move a cursor over the strip. Enter anchors a brush at the cursor
+//! and a second Enter turns the brushed span into a `Between` filter on the column.
+
+use std::sync::Arc;
+
+use crossterm::event::{KeyEvent, KeyEventKind};
+use polars::prelude::DataFrame;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders};
+
+use crate::action::Action;
+use crate::config::{Config, Mode};
+use crate::dataframe::timeline::{format_micros, timeline_bins, TimelineBins};
+use crate::dialog::filter_dialog::{ColumnFilter, FilterCondition};
+
+const LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+#[derive(Debug)]
+pub struct TimelinePanel {
+    pub column: String,
+    pub focused: bool,
+    pub cursor: usize,
+    /// Bin where the brush started; the brush spans from here to the cursor
+    pub anchor: Option<usize>,
+    bins: Option<TimelineBins>,
+    /// (DataFrame pointer, bin count) the bins were computed for
+    source: Option<(usize, usize)>,
+    error: Option<String>,
+    config: Config,
+}
+
+impl TimelinePanel {
+    /// Rows taken above the table: border, strip, axis labels, border
+    pub const HEIGHT: u16 = 4;
+
+    pub fn new(column: impl Into<String>) -> Self {
+        Self {
+            column: column.into(),
+            focused: true,
+            cursor: 0,
+            anchor: None,
+            bins: None,
+            source: None,
+            error: None,
+            config: Config::default(),
+        }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    /// Recount the bins when the view or the panel width changed since the last call
+    pub fn update_bins(&mut self, df: &Arc<DataFrame>, width: u16) {
+        let bin_count = width.saturating_sub(2).max(1) as usize;
+        let key = (Arc::as_ptr(df) as usize, bin_count);
+        if self.source == Some(key) {
+            return;
+        }
+        self.source = Some(key);
+        let result = df
+            .column(&self.column)
+            .map_err(|e| color_eyre::eyre::eyre!("{}", e))
+            .and_then(|c| timeline_bins(c, bin_count));
+        match result {
+            Ok(bins) => {
+                self.cursor = self.cursor.min(bins.counts.len().saturating_sub(1));
+                self.anchor = self.anchor.filter(|a| *a < bins.counts.len());
+                self.bins = Some(bins);
+                self.error = None;
+            }
+            Err(e) => {
+                self.bins = None;
+                self.error = Some(e.to_string());
+            }
+        }
+    }
+
+    /// Selected bins, inclusive: the brush if one is anchored, else the cursor bin
+    fn selection(&self) -> (usize, usize) {
+        let anchor = self.anchor.unwrap_or(self.cursor);
+        (anchor.min(self.cursor), anchor.max(self.cursor))
+    }
+
+    fn title(&self) -> String {
+        let Some(bins) = &self.bins else {
+            return format!("Timeline: {}", self.column);
+        };
+        let (first, last) = self.selection();
+        let (lo, hi) = bins.range(first, last);
+        let events: u64 = bins.counts[first..=last].iter().sum();
+        let label = if self.anchor.is_some() { "Brush" } else { "Cursor" };
+        format!("Timeline: {}  {label}: {} → {} ({events} events)", self.column, format_micros(lo), format_micros(hi))
+    }
+
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        let border_style = if self.focused { Style::default().fg(Color::Cyan) } else { Style::default() };
+        let block = Block::default().title(self.title()).borders(Borders::ALL).border_style(border_style);
+        let inner = block.inner(area);
+        block.render(area, buf);
+        if inner.height == 0 {
+            return;
+        }
+        let Some(bins) = &self.bins else {
+            let message = self.error.clone().unwrap_or_default();
+            buf.set_string(inner.x, inner.y, message, Style::default().fg(Color::Red));
+            return;
+        };
+
+        let max = bins.max_count().max(1);
+        let (first, last) = self.selection();
+        for (i, count) in bins.counts.iter().enumerate().take(inner.width as usize) {
+            let ch = if *count == 0 { ' ' } else { LEVELS[((*count * (LEVELS.len() as u64 - 1)) / max) as usize] };
+            let style = if self.focused && i == self.cursor {
+                Style::default().fg(Color::Black).bg(Color::Cyan)
+            } else if self.anchor.is_some() && (first..=last).contains(&i) {
+                Style::default().fg(Color::Yellow).bg(Color::DarkGray)
+            } else {
+                Style::default().fg(Color::Green)
+            };
+            buf.set_string(inner.x + i as u16, inner.y, ch.to_string(), style);
+        }
+
+        if inner.height > 1 {
+            let y = inner.y + 1;
+            let start = format_micros(bins.start);
+            let end = format_micros(bins.end);
+            buf.set_string(inner.x, y, &start, Style::default().fg(Color::DarkGray));
+            if inner.width as usize > start.len() + end.len() + 1 {
+                buf.set_string(inner.x + inner.width - end.len() as u16, y, &end, Style::default().fg(Color::DarkGray));
+            }
+        }
+    }
+
+    /// Handle keys while focused. Escape drops the brush, then gives focus back to the table.
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        let n = self.bins.as_ref().map(|b| b.counts.len()).unwrap_or(0);
+        match self.config.action_for_key(Mode::Global, key) {
+            Some(Action::Left) => self.cursor = self.cursor.saturating_sub(1),
+            Some(Action::Right) => self.cursor = (self.cursor + 1).min(n.saturating_sub(1)),
+            Some(Action::Escape) => {
+                if self.anchor.take().is_none() {
+                    self.focused = false;
+                }
+            }
+            Some(Action::Enter) if n > 0 => {
+                if self.anchor.is_none() {
+                    self.anchor = Some(self.cursor);
+                } else {
+                    return self.brush_filter();
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+
+    /// Filter for the brushed span; clears the brush
+    fn brush_filter(&mut self) -> Option<Action> {
+        let bins = self.bins.as_ref()?;
+        let (first, last) = self.selection();
+        let (lo, hi) = bins.range(first, last);
+        self.anchor = None;
+        Some(Action::AddFilterCondition(ColumnFilter {
+            column: self.column.clone(),
+            condition: FilterCondition::Between { min: format_micros(lo), max: format_micros(hi), inclusive: true },
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use polars::prelude::*;
+
+    fn press(panel: &mut TimelinePanel, code: KeyCode) -> Option<Action> {
+        panel.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn test_brush_selects_time_range() {
+        let hour = 3_600_000_000i64;
+        let ts = Int64Chunked::from_vec("ts".into(), vec![0, hour, 2 * hour, 3 * hour])
+            .into_datetime(TimeUnit::Microseconds, None)
+            .into_series()
+            .into_column();
+        let df = Arc::new(DataFrame::new(vec![ts]).unwrap());
+        let mut panel = TimelinePanel::new("ts");
+        panel.config.reset_keybindings_to_default();
+        panel.update_bins(&df, 5);
+        assert_eq!(panel.bins.as_ref().unwrap().counts, [1, 1, 2]);
+
+        press(&mut panel, KeyCode::Right);
+        press(&mut panel, KeyCode::Enter);
+        press(&mut panel, KeyCode::Right);
+        match press(&mut panel, KeyCode::Enter) {
+            Some(Action::AddFilterCondition(filter)) => {
+                assert_eq!(filter.column, "ts");
+                assert_eq!(
+                    filter.condition,
+                    FilterCondition::Between { min: format_micros(hour), max: format_micros(3 * hour), inclusive: true }
+                );
+                let mask = filter.create_mask(&df).unwrap();
+                assert_eq!(mask.into_iter().map(|m| m.unwrap()).collect::<Vec<_>>(), [false, true, true, true]);
+            }
+            other => panic!("expected AddFilterCondition, got {other:?}"),
+        }
+        assert!(panel.anchor.is_none());
+        press(&mut panel, KeyCode::Esc);
+        assert!(!panel.focused);
+    }
+}
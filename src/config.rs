@@ -10,8 +10,10 @@ use serde::{Deserialize, Serialize, de::Deserializer};
 use directories::BaseDirs;
 
 use crate::action::Action;
+use crate::dialog::confirm_dialog::ConfirmKind;
 use crate::dialog::llm_client_dialog::LlmConfig;
-use crate::style::StyleConfig;
+use crate::threat_intel::ThreatIntelConfig;
+use crate::style::{StyleConfig, ThemeConfig, ThemePalette};
 use chrono::{DateTime, Utc};
 
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -27,6 +29,18 @@ pub enum Mode {
     Filter,
     Find,
     FindAllResults,
+    RecordView,
+    GroupBy,
+    HexViewer,
+    CellViewer,
+    OpenTargetDialog,
+    HeatmapDialog,
+    ChartDialog,
+    MissingDataDialog,
+    RowTagsDialog,
+    ValidationDialog,
+    FindingsReportDialog,
+    DisplaySettingsDialog,
     JmesPath,
     SqlDialog,
     XlsxOptionsDialog,
@@ -50,9 +64,129 @@ pub enum Mode {
     StyleSetEditorDialog,
     ApplicationScopeEditorDialog,
     ColorPickerDialog,
+    TransformHistoryDialog,
+    CheckpointsDialog,
+    ScriptDialog,
+    PathRemapDialog,
+    QuickOpenDialog,
+    SchemaEditorDialog,
+    /// Cursor movement in the data table, on top of the arrow/Home/End keys
+    TableNavigation,
+}
+
+/// Grouping of bound actions in the help dialog
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ActionCategory {
+    General,
+    Navigation,
+    Tabs,
+    Table,
+    Analysis,
+    Investigation,
+    Data,
+    Dialogs,
+    Styling,
+}
+
+impl ActionCategory {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ActionCategory::General => "General",
+            ActionCategory::Navigation => "Navigation",
+            ActionCategory::Tabs => "Tabs & Workspace",
+            ActionCategory::Table => "Table & View",
+            ActionCategory::Analysis => "Transform & Analyse",
+            ActionCategory::Investigation => "Investigation",
+            ActionCategory::Data => "Import & Export",
+            ActionCategory::Dialogs => "Dialogs",
+            ActionCategory::Styling => "Styling",
+        }
+    }
+
+    /// Category of `action` as bound in `mode`
+    pub fn of(mode: Mode, action: &Action) -> Self {
+        match mode {
+            Mode::Global if matches!(action, Action::Up | Action::Down | Action::Left | Action::Right | Action::Tab) => {
+                ActionCategory::Navigation
+            }
+            Mode::Global => ActionCategory::General,
+            Mode::TableNavigation => ActionCategory::Navigation,
+            Mode::DataTabManager => ActionCategory::Tabs,
+            Mode::DataTableContainer => match action {
+                Action::OpenGoToRowDialog | Action::OpenGoToColumnDialog => ActionCategory::Navigation,
+                Action::OpenSigmaDialog
+                | Action::OpenPatternSetDialog
+                | Action::TagSelectedRow
+                | Action::OpenRowTagsDialog
+                | Action::OpenValidationDialog
+                | Action::OpenFindingsReportDialog => ActionCategory::Investigation,
+                Action::OpenSqlDialog
+                | Action::OpenAskDataDialog
+                | Action::OpenJmesDialog
+                | Action::OpenScriptDialog
+                | Action::OpenColumnOperationsDialog
+                | Action::OpenEmbeddingsPromptDialog
+                | Action::OpenGroupByDialog
+                | Action::OpenHeatmapDialog
+                | Action::OpenChartDialog
+                | Action::OpenBenfordDialog
+                | Action::OpenCrosstabDialog
+                | Action::OpenMissingDataDialog
+                | Action::OpenSampleDialog
+                | Action::OpenResampleDialog
+                | Action::OpenTransformHistoryDialog
+                | Action::OpenCheckpointsDialog => ActionCategory::Analysis,
+                _ => ActionCategory::Table,
+            },
+            Mode::DataManagement
+            | Mode::DataImport
+            | Mode::CsvOptions
+            | Mode::XlsxOptionsDialog
+            | Mode::ParquetOptionsDialog
+            | Mode::SqliteOptionsDialog
+            | Mode::JsonOptionsDialog
+            | Mode::FileBrowser
+            | Mode::AliasEdit
+            | Mode::TableExport
+            | Mode::ProjectSettings
+            | Mode::PathRemapDialog
+            | Mode::QuickOpenDialog => ActionCategory::Data,
+            Mode::StyleSetManagerDialog
+            | Mode::StyleRuleEditorDialog
+            | Mode::StyleSetBrowserDialog
+            | Mode::StyleSetEditorDialog
+            | Mode::ApplicationScopeEditorDialog
+            | Mode::ColorPickerDialog => ActionCategory::Styling,
+            _ => ActionCategory::Dialogs,
+        }
+    }
 }
 
 const CONFIG: &str = include_str!("../.config/config.json5");
+const VIM_KEYMAP: &str = include_str!("../.config/keymaps/vim.json5");
+const EMACS_KEYMAP: &str = include_str!("../.config/keymaps/emacs.json5");
+
+/// Navigation keymap profile, applied on top of the configured keybindings
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeymapProfile {
+    #[default]
+    Default,
+    Vim,
+    Emacs,
+}
+
+impl KeymapProfile {
+    /// Keybindings the profile adds; None for the default profile
+    fn overlay(self) -> Option<KeyBindings> {
+        let raw = match self {
+            KeymapProfile::Default => return None,
+            KeymapProfile::Vim => VIM_KEYMAP,
+            KeymapProfile::Emacs => EMACS_KEYMAP,
+        };
+        json5::from_str::<Config>(raw).ok().map(|cfg| cfg.keybindings)
+    }
+}
 
 #[derive(Clone, Debug, Serialize, Deserialize, Default)]
 pub struct AppConfig {
@@ -68,16 +202,50 @@ pub struct Config {
     pub config: AppConfig,
     #[serde(default)]
     pub keybindings: KeyBindings,
+    /// Navigation keymap profile: "default", "vim" or "emacs"
+    #[serde(default)]
+    pub keymap: KeymapProfile,
+    /// Store repetitive string columns as Categorical when importing new data sources
+    #[serde(default)]
+    pub categorical_strings: bool,
+    /// Soft memory limit in MB; imports that would go over it ask for confirmation first
+    #[serde(default)]
+    pub memory_soft_limit_mb: Option<u64>,
+    /// Filter, sort and SQL results larger than this many MB are written to temporary Parquet
+    /// files and read back lazily instead of kept in memory
+    #[serde(default)]
+    pub spill_threshold_mb: Option<u64>,
+    /// Interactive SQL results are cut to this many rows unless run with "fetch all"; `null` turns
+    /// the limit off
+    #[serde(default = "default_sql_row_limit")]
+    pub sql_row_limit: Option<usize>,
+    /// Directory of WASM plugins loaded at startup; defaults to `.datatui-plugins` in the
+    /// config directory
+    #[serde(default)]
+    pub plugin_dir: Option<PathBuf>,
+    /// Irreversible operations the user chose "don't ask again" for
+    #[serde(default)]
+    pub skip_confirmations: Vec<ConfirmKind>,
     #[serde(default)]
     pub styles: Styles,
     #[serde(default)]
     pub style_config: StyleConfig,
+    /// Color theme; when set it replaces `style_config`
+    #[serde(default)]
+    pub theme: Option<ThemeConfig>,
     #[serde(default)]
     pub llm_config: LlmConfig,
+    /// Threat-intel API keys and rate limits, kept in `~/.datatui-threat-intel.toml`
+    #[serde(default)]
+    pub threat_intel: ThreatIntelConfig,
     #[serde(default, serialize_with = "serialize_optional_datetime", deserialize_with = "deserialize_optional_datetime")]
     pub next_update_check: Option<DateTime<Utc>>,
 }
 
+fn default_sql_row_limit() -> Option<usize> {
+    Some(100_000)
+}
+
 fn serialize_optional_datetime<S>(date: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: serde::Serializer,
@@ -156,6 +324,7 @@ impl Config {
                     .or_insert_with(|| cmd.clone());
             }
         }
+        cfg.apply_keymap_profile();
         for (mode, default_styles) in default_config.styles.0.iter() {
             let user_styles = cfg.styles.0.entry(*mode).or_default();
             for (style_key, style) in default_styles.iter() {
@@ -163,9 +332,17 @@ impl Config {
             }
         }
 
+        cfg.apply_theme();
+
         // Load LLM config from ~/.datatui-llm-settings.toml (ensure exists)
         cfg.load_llm_config()?;
 
+        // Load threat-intel settings from ~/.datatui-threat-intel.toml (defaults if missing)
+        match ThreatIntelConfig::load_from(&ThreatIntelConfig::config_path()) {
+            Ok(threat_intel) => cfg.threat_intel = threat_intel,
+            Err(e) => eprintln!("Warning: Failed to load threat-intel config: {e}"),
+        }
+
         // Enable update checks by default if not set (set to now so it checks immediately on first run)
         let was_none = cfg.next_update_check.is_none();
         if was_none {
@@ -181,6 +358,32 @@ impl Config {
         Ok(cfg)
     }
 
+    /// The built-in defaults without reading or writing any files, for embedding components in
+    /// other apps
+    pub fn builtin() -> Self {
+        let mut cfg: Config = json5::from_str(CONFIG).unwrap();
+        cfg.apply_keymap_profile();
+        cfg.apply_theme();
+        cfg
+    }
+
+    /// Rebuild `style_config` from the selected theme, if any
+    pub fn apply_theme(&mut self) {
+        if let Some(theme) = &self.theme {
+            self.style_config = theme.palette().style_config();
+        }
+    }
+
+    /// Colors of the selected theme, for the parts of the UI `style_config` does not cover
+    pub fn palette(&self) -> ThemePalette {
+        self.theme.as_ref().map(ThemeConfig::palette).unwrap_or_default()
+    }
+
+    /// Whether rendered frames must be mapped onto the 256-color palette
+    pub fn use_truecolor(&self) -> bool {
+        self.theme.as_ref().is_none_or(|t| t.color_mode.use_truecolor())
+    }
+
     /// Load LLM configuration from ~/.datatui-llm-settings.toml file (ensure exists)
     fn load_llm_config(&mut self) -> Result<(), config::ConfigError> {
         let config_dir = get_config_dir();
@@ -232,12 +435,17 @@ impl Config {
         fs::write(&llm_config_path, toml_content)
     }
 
+    /// Save threat-intel settings to ~/.datatui-threat-intel.toml
+    pub fn save_threat_intel_config(&self) -> Result<()> {
+        self.threat_intel.save_to(&ThreatIntelConfig::config_path())
+    }
+
     /// Get LLM config (always available now)
     pub fn get_llm_config(&mut self) -> &mut LlmConfig {
         &mut self.llm_config
     }
 
-    /// Save config to the default config file (only updates next_update_check field)
+    /// Save config to the default config file (only updates the next_update_check and theme fields)
     pub fn save(&self) -> Result<(), std::io::Error> {
         let home_cfg = default_home_config_path();
         if let Some(parent) = home_cfg.parent() {
@@ -260,6 +468,10 @@ impl Config {
         } else {
             existing_config["next_update_check"] = serde_json::Value::Null;
         }
+        if let Some(theme) = &self.theme {
+            existing_config["theme"] = serde_json::to_value(theme)
+                .map_err(|e| std::io::Error::other(format!("Failed to format theme: {}", e)))?;
+        }
 
         // Format as JSON5 (which is a superset of JSON)
         let json5_content = serde_json::to_string_pretty(&existing_config)
@@ -268,6 +480,69 @@ impl Config {
         fs::write(&home_cfg, json5_content)
     }
 
+    /// Whether `kind` should ask before running
+    pub fn needs_confirmation(&self, kind: ConfirmKind) -> bool {
+        !self.skip_confirmations.contains(&kind)
+    }
+
+    /// Stop asking before `kind` and add it to the default config file, keeping its other settings
+    pub fn skip_confirmation(&mut self, kind: ConfirmKind) -> Result<(), std::io::Error> {
+        if !self.skip_confirmations.contains(&kind) {
+            self.skip_confirmations.push(kind);
+        }
+        let home_cfg = default_home_config_path();
+        if let Some(parent) = home_cfg.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        let mut existing_config: serde_json::Value = fs::read_to_string(&home_cfg)
+            .ok()
+            .and_then(|content| json5::from_str(&content).ok())
+            .unwrap_or_else(|| serde_json::json!({}));
+        let mut skipped: Vec<ConfirmKind> = serde_json::from_value(existing_config["skip_confirmations"].clone()).unwrap_or_default();
+        if !skipped.contains(&kind) {
+            skipped.push(kind);
+        }
+        existing_config["skip_confirmations"] = serde_json::to_value(skipped)
+            .map_err(|e| std::io::Error::other(format!("Failed to format confirmations: {}", e)))?;
+        let content = serde_json::to_string_pretty(&existing_config)
+            .map_err(|e| std::io::Error::other(format!("Failed to format config: {}", e)))?;
+        fs::write(&home_cfg, content)
+    }
+
+    /// Write the keybindings into the default config file, keeping its other settings
+    pub fn save_keybindings(&self) -> Result<PathBuf, std::io::Error> {
+        let home_cfg = default_home_config_path();
+        if let Some(parent) = home_cfg.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        let mut existing_config: serde_json::Value = fs::read_to_string(&home_cfg)
+            .ok()
+            .and_then(|content| json5::from_str(&content).ok())
+            .unwrap_or_else(|| serde_json::json!({}));
+        existing_config["keybindings"] = serde_json::to_value(self.keybindings_export())
+            .map_err(|e| std::io::Error::other(format!("Failed to format keybindings: {}", e)))?;
+        let content = serde_json::to_string_pretty(&existing_config)
+            .map_err(|e| std::io::Error::other(format!("Failed to format config: {}", e)))?;
+        fs::write(&home_cfg, content)?;
+        Ok(home_cfg)
+    }
+
+    /// Bindings that `keys` would collide with if bound to `action` in `mode`: another action
+    /// on the same keys in `mode`, or in Global, which dialogs check first. For Global itself
+    /// every mode is checked, since a Global key hides the same key everywhere else.
+    pub fn keybinding_conflicts(&self, mode: Mode, keys: &[KeyEvent], action: &Action) -> Vec<(Mode, Action)> {
+        let mut conflicts: Vec<(Mode, Action)> = self
+            .keybindings
+            .0
+            .iter()
+            .filter(|(m, _)| **m == mode || **m == Mode::Global || mode == Mode::Global)
+            .filter_map(|(m, bindings)| bindings.get(keys).map(|a| (*m, a.clone())))
+            .filter(|(m, a)| !(*m == mode && a == action))
+            .collect();
+        conflicts.sort_by_key(|(m, a)| format!("{m:?} {a}"));
+        conflicts
+    }
+
     /// Build instructions string from list of (mode, action) tuples
     pub fn actions_to_instructions(&self, actions: &[(Mode, Action)]) -> String {
         actions.iter()
@@ -294,6 +569,10 @@ impl Config {
             Action::Down => "Down",
             Action::Left => "Left",
             Action::Right => "Right",
+            Action::PageLeft => "Page Left",
+            Action::PageRight => "Page Right",
+            Action::GoToFirstColumn => "First Column",
+            Action::GoToLastColumn => "Last Column",
             Action::Tab => "Tab",
             Action::Paste => "Paste",
             Action::SelectAllText => "Select All",
@@ -304,16 +583,63 @@ impl Config {
             // DataTableContainer actions
             Action::OpenSortDialog => "Sort",
             Action::QuickSortCurrentColumn => "Quick Sort",
+            Action::QuickSortAppendCurrentColumn => "Quick Sort (Add)",
             Action::OpenFilterDialog => "Filter",
             Action::QuickFilterEqualsCurrentValue => "Quick Filter",
             Action::MoveSelectedColumnLeft => "Move Column Left",
             Action::MoveSelectedColumnRight => "Move Column Right",
             Action::OpenSqlDialog => "SQL",
+            Action::OpenAskDataDialog => "Ask Data",
+            Action::OpenTransformHistoryDialog => "History",
+            Action::ExportTransformHistory => "Export Pipeline",
+            Action::ImportTransformHistory => "Import Pipeline",
+            Action::ExportTransformScript => "Export Script",
+            Action::OpenCheckpointsDialog => "Checkpoints",
+            Action::AddCheckpoint => "Add Checkpoint",
+            Action::DeleteCheckpoint => "Delete",
+            Action::ToggleCheckpointParquet => "Parquet/History",
+            Action::OpenSampleDialog => "Sample",
+            Action::OpenResampleDialog => "Resample",
+            Action::OpenCrosstabDialog => "Crosstab",
+            Action::OpenMissingDataDialog => "Missing Data",
+            Action::OpenSigmaDialog => "Sigma Rules",
+            Action::OpenPatternSetDialog => "Pattern Set",
+            Action::TagSelectedRow => "Tag Row",
+            Action::OpenRowTagsDialog => "Row Tags",
+            Action::OpenValidationDialog => "Validation Rules",
+            Action::OpenColumnMenu => "Column Menu",
+            Action::OpenGoToRowDialog => "Go to Row",
+            Action::OpenGoToColumnDialog => "Go to Column",
+            Action::SearchColumns => "Search Columns",
+            Action::SortSelectedColumn { .. } => "Sort Column",
+            Action::HideSelectedColumn => "Hide Column",
+            Action::TogglePinSelectedColumn => "Pin Column",
+            Action::ToggleWrapSelectedColumn => "Wrap Column",
+            Action::ToggleGroupRowsBySelectedColumn => "Color Rows by Group",
+            Action::OpenFindingsReportDialog => "Findings Report",
+            Action::OpenScriptDialog => "Script",
+            Action::RunScript => "Run",
+            Action::SaveScript => "Save",
+            Action::OpenSavedScripts => "Saved Scripts",
+            Action::DeleteSavedScript => "Delete",
+            Action::SearchFolderForMissingFiles => "Search Folder",
+            Action::ApplyPathRemap => "Apply",
             Action::OpenJmesDialog => "JMESPath",
             Action::OpenColumnOperationsDialog => "Column Ops",
             Action::OpenEmbeddingsPromptDialog => "Prompt Similarity",
             Action::OpenFindDialog => "Find",
             Action::OpenDataframeDetailsDialog => "Details",
+            Action::OpenValueCounts => "Value Counts",
+            Action::OpenRecordViewDialog => "Record View",
+            Action::OpenGroupByDialog => "Group By",
+            Action::OpenCellViewerDialog => "Cell Viewer",
+            Action::OpenHexViewerDialog => "Hex View",
+            Action::OpenCellTarget => "Open Link",
+            Action::OpenHeatmapDialog => "Heatmap",
+            Action::OpenChartDialog => "Chart",
+            Action::OpenBenfordDialog => "Benford",
+            Action::ToggleTimeline => "Timeline",
+            Action::OpenDisplaySettingsDialog => "Display Settings",
             Action::OpenColumnWidthDialog => "Column Width",
             Action::OpenDataExportDialog => "Export",
             Action::CopySelectedCell => "Copy",
@@ -325,15 +651,26 @@ impl Config {
             Action::MoveTabToBack => "Move Tab Back",
             Action::MoveTabLeft => "Move Tab Left",
             Action::MoveTabRight => "Move Tab Right",
+            Action::RenameTab => "Rename Tab",
+            Action::DuplicateTab => "Duplicate Tab",
+            Action::CloseTab => "Close Tab",
+            Action::CloseOtherTabs => "Close Others",
+            Action::CloseTabsToRight => "Close Right",
+            Action::OpenQuickOpenDialog => "Quick Open",
+            Action::OpenTabSwitcher => "Switch Tab",
+            Action::ForgetRecentEntry => "Forget",
             Action::PrevTab => "Prev Tab",
             Action::NextTab => "Next Tab",
             Action::SyncTabs => "Sync Tabs",
             Action::OpenStyleSetManagerDialog => "Manage Style Sets",
+            Action::ToggleIocHighlighting => "IOC Highlighting",
             
             // Dialog actions
             Action::DeleteSelectedSource => "Delete Source",
             Action::LoadAllPendingDatasets => "Load All",
             Action::EditSelectedAlias => "Edit Alias",
+            Action::EditSelectedNotes => "Edit Notes",
+            Action::ToggleCategoricalStrings => "Categorical Strings",
             Action::OpenDataImportDialog => "Import",
             Action::ConfirmDataImport => "Confirm Import",
             Action::DataImportSelect => "Select",
@@ -344,6 +681,9 @@ impl Config {
             Action::ToggleSortDirection => "Toggle Sort",
             Action::RemoveSortColumn => "Remove Sort",
             Action::AddSortColumn => "Add Sort",
+            Action::ToggleSortNatural => "Natural Order",
+            Action::ToggleSortCaseInsensitive => "Ignore Case",
+            Action::CycleSortNulls => "Nulls First/Last",
             
             // Filter dialog actions
             Action::AddFilter => "Add Filter",
@@ -362,10 +702,57 @@ impl Config {
             Action::GoToLast => "Last",
             Action::PageUp => "Page Up",
             Action::PageDown => "Page Down",
+
+            // GroupBy dialog actions
+            Action::ToggleGroupColumn => "Toggle Group Column",
+            Action::SetAggregateColumn => "Aggregate Column",
+            Action::GoToGroupRow => "Go To Group",
+            Action::ExpandAllGroups => "Expand All",
+            Action::CollapseAllGroups => "Collapse All",
+
+            // Cell viewer dialog actions
+            Action::ToggleFold => "Toggle Fold",
+            Action::ExpandAllFolds => "Expand All",
+            Action::CollapseAllFolds => "Collapse All",
+            Action::ToggleDecodedView => "Decoded View",
+
+            // Open target dialog actions
+            Action::ConfirmOpenTarget => "Open",
+            Action::DenyOpenTarget => "Cancel",
+
+            // Heatmap dialog actions
+            Action::DisableHeatmap => "Disable Heatmap",
+
+            // Chart dialog actions
+            Action::ToggleChartKind => "Histogram/Counts",
+            Action::ToggleChartLogScale => "Log Scale",
+
+            // Missing data dialog actions
+            Action::DropMissingColumns => "Drop Columns",
+            Action::FillMissingColumns => "Fill Columns",
+
+            // Row tags dialog actions
+            Action::TagRow => "Tag Row",
+            Action::DeleteTag => "Delete Tag",
+            Action::RenameTag => "Rename Tag",
+            Action::ToggleTagsColumn => "Tags Column",
+            Action::AddValidationRule => "Add Rule",
+            Action::EditValidationRule => "Edit Rule",
+            Action::DeleteValidationRule => "Delete Rule",
+            Action::RunValidation => "Run",
+            Action::OpenViolationsDataset => "Violations Dataset",
+            Action::ClearValidation => "Clear Highlights",
+
+            // Findings report dialog actions
+            Action::ExportFindingsReport => "Save Report",
+
+            // Display settings dialog actions
+            Action::ClearDisplayFormat => "Clear Format",
             
             // SQL dialog actions
             Action::CopyText => "Copy Text",
             Action::RunQuery => "Run Query",
+            Action::RunQueryFetchAll => "Run (All Rows)",
             Action::CreateNewDataset => "New Dataset",
             Action::RestoreDataFrame => "Restore",
             Action::OpenSqlFileBrowser => "Browse SQL",
@@ -382,18 +769,27 @@ impl Config {
             // Column width dialog actions
             Action::ToggleAutoExpand => "Auto Expand",
             Action::ToggleColumnHidden => "Hide Column",
+            Action::ToggleColumnWrap => "Wrap Text",
             Action::MoveColumnUp => "Move Up",
             Action::MoveColumnDown => "Move Down",
+            Action::ComputeExactColumnWidths => "Exact Widths",
             
             // JMESPath dialog actions
             Action::AddColumn => "Add Column",
             Action::EditColumn => "Edit Column",
             Action::DeleteColumn => "Delete Column",
             Action::ApplyTransform => "Apply",
+            Action::AcceptCompletion => "Complete",
+            Action::SaveJmesExpression => "Save Expression",
+            Action::OpenJmesHistory => "History",
+            Action::DeleteJmesHistoryEntry => "Delete",
+            Action::TogglePreviewSample => "Sample Rows",
             
             // ColumnOperationOptions dialog actions
             Action::ToggleField => "Toggle Field",
             Action::ToggleButtons => "Toggle Buttons",
+            Action::SaveColumnOperationPreset => "Save as Preset",
+            Action::DeleteColumnOperationPreset => "Delete Preset",
             
             // DataFrameDetails dialog actions
             Action::SwitchToNextTab => "Next Tab",
@@ -404,6 +800,10 @@ impl Config {
             Action::OpenCastOverlay => "Open Cast Overlay",
             Action::AddFilterFromValue => "Add Filter From Value",
             Action::ExportCurrentTab => "Export Current Tab",
+            Action::VerifyEvidence => "Verify Hashes",
+            Action::OpenSchemaEditor => "Schema Editor",
+            Action::RenameSchemaColumn => "Rename",
+            Action::EditDatetimeFormat => "Datetime Format",
             Action::NavigateHeatmapLeft => "Heatmap Left",
             Action::NavigateHeatmapRight => "Heatmap Right",
             Action::NavigateHeatmapUp => "Heatmap Up",
@@ -473,6 +873,8 @@ impl Config {
             Action::FocusCategoryTree => "Focus Categories",
             Action::FocusStyleSetTable => "Focus Table",
             Action::EditStyleSet => "Edit Style Set",
+            Action::OpenTemplateGallery => "Templates",
+            Action::ToggleStyleSetTabScope => "This Tab/All Tabs",
             
             // StyleSetEditorDialog actions
             Action::OpenStyleSetEditorDialog => "Open Style Set Editor",
@@ -505,6 +907,107 @@ impl Config {
         }
     }
     
+    /// One-line description of the actions reachable from the table, tabs and global keys;
+    /// dialog-local actions are described by their dialog instead
+    pub fn action_description(&self, action: &Action) -> Option<&'static str> {
+        Some(match action {
+            // Global actions
+            Action::Quit => "Quit datatui (the workspace is saved first)",
+            Action::Escape => "Close the current dialog or cancel a running job",
+            Action::Enter => "Confirm or apply in the current dialog",
+            Action::Tab => "Move to the next field",
+            Action::Up | Action::Down | Action::Left | Action::Right => "Move the selection",
+            Action::Backspace => "Delete the character before the cursor",
+            Action::DeleteWord => "Delete the word before the cursor",
+            Action::SelectAllText => "Select all text in the input",
+            Action::CopyText => "Copy the selected text",
+            Action::Paste => "Paste from the clipboard into the input",
+            Action::ToggleInstructions => "Show or hide the instructions strip",
+            Action::OpenKeybindings => "View and rebind keys per mode",
+            Action::Help => "Search all actions and their keys",
+
+            // Table navigation
+            Action::PageUp | Action::PageDown => "Scroll the table a page up or down",
+            Action::PageLeft | Action::PageRight => "Scroll the table a page of columns left or right",
+            Action::GoToFirst => "Jump to the first row",
+            Action::GoToLast => "Jump to the last row",
+            Action::GoToFirstColumn => "Jump to the first column",
+            Action::GoToLastColumn => "Jump to the last column",
+
+            // DataTableContainer actions
+            Action::OpenSortDialog => "Sort by one or more columns",
+            Action::QuickSortCurrentColumn => "Sort by the selected column: ascending, descending, then unsorted",
+            Action::QuickSortAppendCurrentColumn => "Add the selected column to a multi-column sort, or toggle it there",
+            Action::AcceptCompletion => "Complete the column or function name being typed",
+            Action::SaveJmesExpression => "Save the JMESPath expression to the project under a name",
+            Action::OpenJmesHistory => "Pick a saved or recently used JMESPath expression",
+            Action::TogglePreviewSample => "Preview the JMESPath expression on several rows instead of only the selected one",
+            Action::OpenSchemaEditor => "Cast, rename and reorder several columns at once",
+            Action::RenameSchemaColumn => "Rename the selected column in the schema editor",
+            Action::EditDatetimeFormat => "Set the format used to read text as Datetime or Date",
+            Action::OpenFilterDialog => "Build a filter from conditions",
+            Action::QuickFilterEqualsCurrentValue => "Keep rows equal to the selected cell",
+            Action::MoveSelectedColumnLeft | Action::MoveSelectedColumnRight => "Move the selected column",
+            Action::OpenSqlDialog => "Query the data with SQL",
+            Action::OpenAskDataDialog => "Ask a question in plain language and get SQL",
+            Action::OpenTransformHistoryDialog => "Review, undo or export the applied transforms",
+            Action::OpenCheckpointsDialog => "Save and restore named views",
+            Action::OpenSampleDialog => "Take a random or stratified sample",
+            Action::OpenResampleDialog => "Bucket rows by time and aggregate",
+            Action::OpenCrosstabDialog => "Cross-tabulate two columns",
+            Action::OpenMissingDataDialog => "Find and fix columns with missing values",
+            Action::OpenSigmaDialog => "Evaluate Sigma detection rules",
+            Action::OpenPatternSetDialog => "Match a file of named regexes against columns",
+            Action::TagSelectedRow => "Tag the selected row with labels and a note",
+            Action::OpenRowTagsDialog => "Manage row tags and filter by tag",
+            Action::OpenValidationDialog => "Check columns against not null, unique, regex, range and reference rules",
+            Action::OpenFindingsReportDialog => "Export a findings report as Markdown or HTML",
+            Action::OpenScriptDialog => "Run a Rhai script over the rows",
+            Action::OpenJmesDialog => "Transform JSON columns with JMESPath",
+            Action::OpenColumnOperationsDialog => "Derive columns: embeddings, clustering, parsing, enrichment",
+            Action::OpenEmbeddingsPromptDialog => "Sort rows by similarity to a prompt",
+            Action::OpenFindDialog => "Find text or patterns in the table",
+            Action::OpenDataframeDetailsDialog => "Schema, statistics and evidence hashes",
+            Action::OpenValueCounts => "Count the values of the selected column",
+            Action::OpenColumnMenu => "Sort, filter, count, hide, pin, cast or rename the selected column from a menu",
+            Action::OpenGoToRowDialog => "Jump to a row number, a percentage of the view or an offset from the selected row",
+            Action::OpenGoToColumnDialog => "Jump to a column by typing part of its name",
+            Action::SearchColumns => "Type part of a column name to jump to it in the list",
+            Action::OpenRecordViewDialog => "View the selected row as a record",
+            Action::OpenCellViewerDialog => "View the full selected cell",
+            Action::OpenHexViewerDialog => "View the selected cell as hex",
+            Action::OpenCellTarget => "Open the link or path in the selected cell",
+            Action::OpenGroupByDialog => "Group and aggregate",
+            Action::OpenHeatmapDialog => "Heatmap of the selected column",
+            Action::OpenChartDialog => "Histogram or value-count chart of the selected column",
+            Action::OpenBenfordDialog => "Compare leading digits with Benford's law",
+            Action::ToggleTimeline => "Show, focus or hide the timeline panel",
+            Action::OpenDisplaySettingsDialog => "Change how the table is displayed",
+            Action::OpenColumnWidthDialog => "Set column widths",
+            Action::CopySelectedCell => "Copy the selected cell",
+
+            // DataTabManager actions
+            Action::OpenStyleSetManagerDialog => "Manage style sets and highlighting rules",
+            Action::ToggleIocHighlighting => "Highlight known indicators in the table",
+            Action::OpenProjectSettingsDialog => "Workspace and project settings",
+            Action::OpenDataManagementDialog => "Import data sources and manage datasets",
+            Action::MoveTabToFront | Action::MoveTabToBack | Action::MoveTabLeft | Action::MoveTabRight => "Reorder tabs",
+            Action::RenameTab => "Rename the current tab",
+            Action::DuplicateTab => "Duplicate the current tab",
+            Action::CloseTab => "Close the current tab",
+            Action::CloseOtherTabs => "Close all other tabs",
+            Action::CloseTabsToRight => "Close the tabs to the right",
+            Action::OpenQuickOpenDialog => "Re-open a recent file or project",
+            Action::OpenTabSwitcher => "Jump to an open tab by typing part of its name",
+            Action::PrevTab | Action::NextTab => "Switch tabs",
+            Action::SyncTabs => "Reload the tabs from the data sources",
+            Action::OpenDataExportDialog => "Export the current data",
+            Action::SaveColumnOperationPreset => "Save the configured column operation to the project as a named preset",
+            Action::DeleteColumnOperationPreset => "Delete the selected column operation preset",
+            _ => return None,
+        })
+    }
+
     /// Resolve an action for a full key sequence for a given mode.
     pub fn action_for_keys(&self, mode: Mode, keys: &[KeyEvent]) -> Option<Action> {
         let map = self.keybindings.0.get(&mode)?;
@@ -519,6 +1022,27 @@ impl Config {
         self.action_for_keys(mode, &[key])
     }
 
+    /// Resolve an action for `key` typed after the keys in `pending`, for multi-key bindings
+    /// such as vim's `gg`. A key that starts a longer binding is kept in `pending` and gives
+    /// None; when the keys typed so far match nothing, the last key is tried on its own.
+    pub fn action_for_key_sequence(&self, mode: Mode, pending: &mut Vec<KeyEvent>, key: KeyEvent) -> Option<Action> {
+        if key.kind != crossterm::event::KeyEventKind::Press {
+            return None;
+        }
+        let map = self.keybindings.0.get(&mode)?;
+        pending.push(key);
+        if let Some(action) = map.get(pending.as_slice()) {
+            pending.clear();
+            return Some(action.clone());
+        }
+        if map.keys().any(|keys| keys.len() > pending.len() && keys.starts_with(pending)) {
+            return None;
+        }
+        let retry = pending.len() > 1;
+        pending.clear();
+        if retry { self.action_for_key_sequence(mode, pending, key) } else { None }
+    }
+
     /// Find the key for a given action in a specific mode
     pub fn key_for_action(&self, mode: Mode, action: &Action) -> Option<String> {
         let mode_bindings = self.keybindings.0.get(&mode)?;
@@ -538,10 +1062,51 @@ impl Config {
         if let Ok(default_cfg) = json5::from_str::<Config>(CONFIG) {
             self.keybindings = default_cfg.keybindings;
         }
+        self.apply_keymap_profile();
     }
 
-    /// Serialize only the keybindings portion to JSON5 compatible with the app config format
-    pub fn keybindings_to_json5(&self) -> String {
+    /// Spill threshold in bytes, when out-of-core mode is enabled
+    pub fn spill_bytes(&self) -> Option<usize> {
+        self.spill_threshold_mb.map(|mb| mb as usize * 1024 * 1024)
+    }
+
+    /// Directory WASM plugins are loaded from
+    pub fn plugin_dir(&self) -> PathBuf {
+        match &self.plugin_dir {
+            Some(dir) => expand_tilde(dir),
+            None => get_config_dir().join(".datatui-plugins"),
+        }
+    }
+
+    /// Apply the selected keymap profile. A profile binding replaces the other keys bound to
+    /// the same action in that mode, so e.g. the vim profile moving a dialog off Ctrl-d frees
+    /// Ctrl-d for paging. A profile binding whose key is taken by an action the profile does
+    /// not rebind is skipped with a warning, leaving both actions where they were.
+    pub fn apply_keymap_profile(&mut self) {
+        let Some(overlay) = self.keymap.overlay() else { return };
+        for (mode, bindings) in overlay.0 {
+            let mode_bindings = self.keybindings.0.entry(mode).or_default();
+            let rebound: Vec<Action> = bindings.values().cloned().collect();
+            let (clashes, bindings): (HashMap<_, _>, HashMap<_, _>) = bindings.into_iter().partition(|(keys, action)| {
+                mode_bindings.get(keys).is_some_and(|bound| bound != action && !rebound.contains(bound))
+            });
+            for (keys, action) in clashes {
+                tracing::warn!(
+                    "{:?} keymap: not binding {:?} to {action} in {mode:?}, it is already bound to {}",
+                    self.keymap,
+                    keys.iter().map(key_event_to_string).collect::<Vec<_>>(),
+                    mode_bindings[&keys]
+                );
+            }
+            for action in bindings.values() {
+                mode_bindings.retain(|_, bound| bound != action);
+            }
+            mode_bindings.extend(bindings);
+        }
+    }
+
+    /// Keybindings keyed by their config file notation, e.g. "<Ctrl-s>"
+    fn keybindings_export(&self) -> HashMap<Mode, HashMap<String, Action>> {
         let mut out: HashMap<Mode, HashMap<String, Action>> = HashMap::new();
         for (mode, inner) in self.keybindings.0.iter() {
             let mut m: HashMap<String, Action> = HashMap::new();
@@ -552,6 +1117,12 @@ impl Config {
             }
             out.insert(*mode, m);
         }
+        out
+    }
+
+    /// Serialize only the keybindings portion to JSON5 compatible with the app config format
+    pub fn keybindings_to_json5(&self) -> String {
+        let out = self.keybindings_export();
         #[derive(Serialize)]
         struct KeybindingsExport<'a> {
             keybindings: &'a HashMap<Mode, HashMap<String, Action>>,
@@ -992,6 +1563,112 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_keybinding_conflicts() {
+        let mut config = Config::default();
+        config.reset_keybindings_to_default();
+        let ctrl_s = vec![KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL)];
+        // Ctrl-s already saves in the keybindings dialog; Esc is taken globally
+        assert_eq!(
+            config.keybinding_conflicts(Mode::KeybindingsDialog, &ctrl_s, &Action::ResetKeybindings),
+            vec![(Mode::KeybindingsDialog, Action::SaveKeybindings)]
+        );
+        assert!(config.keybinding_conflicts(Mode::KeybindingsDialog, &ctrl_s, &Action::SaveKeybindings).is_empty());
+        let esc = vec![KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)];
+        assert!(config
+            .keybinding_conflicts(Mode::Sort, &esc, &Action::Enter)
+            .contains(&(Mode::Global, Action::Escape)));
+    }
+
+    #[test]
+    fn test_keymap_profiles() {
+        let key = |code, modifiers| KeyEvent::new(code, modifiers);
+        let ctrl_d = key(KeyCode::Char('d'), KeyModifiers::CONTROL);
+        let mut config = Config { keymap: KeymapProfile::Vim, ..Config::default() };
+        config.reset_keybindings_to_default();
+        assert_eq!(config.action_for_key(Mode::TableNavigation, key(KeyCode::Char('j'), KeyModifiers::NONE)), Some(Action::Down));
+        assert_eq!(config.action_for_key(Mode::TableNavigation, key(KeyCode::Char('G'), KeyModifiers::SHIFT)), Some(Action::GoToLast));
+        assert_eq!(config.action_for_key(Mode::TableNavigation, ctrl_d), Some(Action::PageDown));
+        // The details dialog moved off Ctrl-d so the table can page with it
+        assert_eq!(config.action_for_key(Mode::DataTableContainer, ctrl_d), None);
+        assert_eq!(
+            config.action_for_key(Mode::DataTableContainer, key(KeyCode::Char('I'), KeyModifiers::SHIFT)),
+            Some(Action::OpenDataframeDetailsDialog)
+        );
+        assert_eq!(config.action_for_key(Mode::DataTableContainer, key(KeyCode::Char('/'), KeyModifiers::NONE)), Some(Action::OpenFindDialog));
+
+        config.keymap = KeymapProfile::Emacs;
+        config.reset_keybindings_to_default();
+        let ctrl_f = key(KeyCode::Char('f'), KeyModifiers::CONTROL);
+        assert_eq!(config.action_for_key(Mode::TableNavigation, ctrl_f), Some(Action::Right));
+        assert_eq!(config.action_for_key(Mode::DataTableContainer, ctrl_f), None);
+        assert_eq!(
+            config.action_for_key(Mode::DataTableContainer, key(KeyCode::Char('s'), KeyModifiers::CONTROL)),
+            Some(Action::OpenFindDialog)
+        );
+        assert_eq!(config.action_for_key(Mode::TableNavigation, key(KeyCode::Char('j'), KeyModifiers::NONE)), None);
+
+        // Container bindings are looked up before the table's, so they must not hide navigation keys
+        for profile in [KeymapProfile::Default, KeymapProfile::Vim, KeymapProfile::Emacs] {
+            config.keymap = profile;
+            config.reset_keybindings_to_default();
+            let container = &config.keybindings.0[&Mode::DataTableContainer];
+            for (keys, action) in &config.keybindings.0[&Mode::TableNavigation] {
+                assert!(
+                    !container.contains_key(keys),
+                    "{profile:?}: {:?} for {action:?} is shadowed by {:?}",
+                    keys,
+                    container[keys]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_multi_key_bindings() {
+        let key = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+        let mut config = Config { keymap: KeymapProfile::Vim, ..Config::default() };
+        config.reset_keybindings_to_default();
+        let mut pending = Vec::new();
+
+        // The first g waits for the second
+        assert_eq!(config.action_for_key_sequence(Mode::TableNavigation, &mut pending, key('g')), None);
+        assert_eq!(pending, [key('g')]);
+        assert_eq!(config.action_for_key_sequence(Mode::TableNavigation, &mut pending, key('g')), Some(Action::GoToFirst));
+        assert!(pending.is_empty());
+
+        // A key that does not continue the sequence is taken on its own
+        config.action_for_key_sequence(Mode::TableNavigation, &mut pending, key('g'));
+        assert_eq!(config.action_for_key_sequence(Mode::TableNavigation, &mut pending, key('j')), Some(Action::Down));
+        assert!(pending.is_empty());
+        assert_eq!(config.action_for_key_sequence(Mode::TableNavigation, &mut pending, key('x')), None);
+        assert!(pending.is_empty());
+
+        assert_eq!(config.action_for_key(Mode::FindAllResults, key('j')), Some(Action::Down));
+        assert_eq!(config.action_for_key(Mode::FindAllResults, key('/')), Some(Action::OpenFindDialog));
+        assert_eq!(config.action_for_key(Mode::GroupBy, key('k')), Some(Action::Up));
+    }
+
+    #[test]
+    fn test_keymap_profile_keeps_clashing_bindings() {
+        let key = |code, modifiers| KeyEvent::new(code, modifiers);
+        let slash = key(KeyCode::Char('/'), KeyModifiers::NONE);
+        let mut config = Config::default();
+        config.reset_keybindings_to_default();
+        config.keybindings.0.get_mut(&Mode::DataTableContainer).unwrap().insert(vec![slash], Action::OpenSigmaDialog);
+
+        // The vim profile binds / to the find dialog, but it is taken by an action the profile leaves alone
+        config.keymap = KeymapProfile::Vim;
+        config.apply_keymap_profile();
+        assert_eq!(config.action_for_key(Mode::DataTableContainer, slash), Some(Action::OpenSigmaDialog));
+        assert_eq!(
+            config.action_for_key(Mode::DataTableContainer, key(KeyCode::Char('f'), KeyModifiers::CONTROL)),
+            Some(Action::OpenFindDialog)
+        );
+        // Bindings that do not clash still apply
+        assert_eq!(config.action_for_key(Mode::DataTableContainer, key(KeyCode::Char(':'), KeyModifiers::NONE)), Some(Action::OpenGoToRowDialog));
+    }
+
     #[test]
     fn test_parse_style_default() {
         let style = parse_style("");
@@ -74,6 +74,32 @@ impl DataImportConfig {
         }
     }
 
+    /// Every file this import reads: the main file plus any merged files
+    pub fn paths(&self) -> Vec<&PathBuf> {
+        match self {
+            DataImportConfig::Text(config) => std::iter::once(&config.file_path).chain(&config.additional_paths).collect(),
+            DataImportConfig::Json(config) => std::iter::once(&config.file_path).chain(&config.additional_paths).collect(),
+            DataImportConfig::Excel(config) => vec![&config.file_path],
+            DataImportConfig::Sqlite(config) => vec![&config.file_path],
+            DataImportConfig::Parquet(config) => vec![&config.file_path],
+        }
+    }
+
+    /// Mutable access to every file this import reads
+    pub fn paths_mut(&mut self) -> Vec<&mut PathBuf> {
+        match self {
+            DataImportConfig::Text(config) => {
+                std::iter::once(&mut config.file_path).chain(config.additional_paths.iter_mut()).collect()
+            }
+            DataImportConfig::Json(config) => {
+                std::iter::once(&mut config.file_path).chain(config.additional_paths.iter_mut()).collect()
+            }
+            DataImportConfig::Excel(config) => vec![&mut config.file_path],
+            DataImportConfig::Sqlite(config) => vec![&mut config.file_path],
+            DataImportConfig::Parquet(config) => vec![&mut config.file_path],
+        }
+    }
+
     /// Get a display name for the import type
     pub fn import_type_name(&self) -> &'static str {
         match self {
@@ -0,0 +1,138 @@
+//! Benford's law: first significant digit distribution of a numeric column compared with the
+//! logarithmic expectation, scored with a chi-square goodness-of-fit test.
+
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use polars::prelude::*;
+
+/// Chi-square critical value for 8 degrees of freedom at the 5% level
+pub const CHI_SQUARE_CRITICAL_5: f64 = 15.507;
+
+/// Share of values expected to start with `digit` (1..=9)
+pub fn expected_share(digit: usize) -> f64 {
+    (1.0 + 1.0 / digit as f64).log10()
+}
+
+/// First significant digit of `v`, or None for zero and non-finite values
+pub fn first_digit(v: f64) -> Option<usize> {
+    if !v.is_finite() || v == 0.0 {
+        return None;
+    }
+    format!("{:e}", v.abs()).chars().next().and_then(|c| c.to_digit(10)).map(|d| d as usize)
+}
+
+/// Upper tail probability of a chi-square statistic with 8 degrees of freedom
+/// (closed form for an even number of degrees of freedom)
+fn chi_square_p_value(x: f64) -> f64 {
+    let half = x / 2.0;
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    for k in 1..4 {
+        term *= half / k as f64;
+        sum += term;
+    }
+    ((-half).exp() * sum).clamp(0.0, 1.0)
+}
+
+/// First-digit counts of a column and how far they are from Benford
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenfordAnalysis {
+    pub column: String,
+    /// Values starting with 1..=9
+    pub counts: [u64; 9],
+    /// Nulls, zeros and non-finite values
+    pub skipped: u64,
+    pub chi_square: f64,
+    pub p_value: f64,
+}
+
+impl BenfordAnalysis {
+    pub fn total(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// Observed share of values starting with `digit` (1..=9)
+    pub fn observed_share(&self, digit: usize) -> f64 {
+        let total = self.total();
+        if total == 0 { 0.0 } else { self.counts[digit - 1] as f64 / total as f64 }
+    }
+
+    pub fn conforms(&self) -> bool {
+        self.chi_square <= CHI_SQUARE_CRITICAL_5
+    }
+
+    pub fn verdict(&self) -> &'static str {
+        if self.total() < 100 {
+            "too few values for a reliable test"
+        } else if self.conforms() {
+            "consistent with Benford"
+        } else {
+            "deviates from Benford"
+        }
+    }
+}
+
+/// Count first digits of a numeric column and score them against Benford's distribution
+pub fn benford(column: &Column) -> Result<BenfordAnalysis> {
+    if !column.dtype().is_primitive_numeric() {
+        return Err(eyre!("Benford analysis needs a numeric column, '{}' is {}", column.name(), column.dtype()));
+    }
+    let floats = column.cast(&DataType::Float64).map_err(|e| eyre!("{}", e))?;
+    let mut counts = [0u64; 9];
+    let mut skipped = 0u64;
+    for v in floats.f64().map_err(|e| eyre!("{}", e))?.into_iter() {
+        match v.and_then(first_digit) {
+            Some(d) => counts[d - 1] += 1,
+            None => skipped += 1,
+        }
+    }
+    let total: u64 = counts.iter().sum();
+    if total == 0 {
+        return Err(eyre!("'{}' has no non-zero values", column.name()));
+    }
+    let chi_square = counts
+        .iter()
+        .enumerate()
+        .map(|(i, &observed)| {
+            let expected = total as f64 * expected_share(i + 1);
+            (observed as f64 - expected).powi(2) / expected
+        })
+        .sum();
+    Ok(BenfordAnalysis {
+        column: column.name().to_string(),
+        counts,
+        skipped,
+        chi_square,
+        p_value: chi_square_p_value(chi_square),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_benford_fit() {
+        assert_eq!(first_digit(0.0042), Some(4));
+        assert_eq!(first_digit(-987.0), Some(9));
+        assert_eq!(first_digit(0.0), None);
+        assert!((expected_share(1) - std::f64::consts::LOG10_2).abs() < 1e-12);
+        assert!((chi_square_p_value(CHI_SQUARE_CRITICAL_5) - 0.05).abs() < 1e-3);
+
+        // Powers of 2 follow Benford closely
+        let powers: Vec<f64> = (0..500).map(|i| 2f64.powi(i)).chain([0.0]).collect();
+        let analysis = benford(&Column::new("p".into(), powers)).unwrap();
+        assert_eq!(analysis.total(), 500);
+        assert_eq!(analysis.skipped, 1);
+        assert!(analysis.conforms(), "chi-square {}", analysis.chi_square);
+
+        // Uniform leading digits do not
+        let uniform: Vec<i64> = (0..900).map(|i| (i % 9 + 1) * 100 + i / 9).collect();
+        let analysis = benford(&Column::new("u".into(), uniform)).unwrap();
+        assert_eq!(analysis.counts, [100; 9]);
+        assert!(!analysis.conforms());
+        assert!(analysis.p_value < 0.001);
+
+        assert!(benford(&Column::new("s".into(), ["1"])).is_err());
+    }
+}
@@ -0,0 +1,91 @@
+//! Store repetitive string columns as Categorical to cut memory
+//!
+//! Log data is full of string columns that repeat a small set of values (hosts, users, event
+//! names). A Categorical column keeps each distinct value once plus a small integer per row.
+
+use color_eyre::Result;
+use polars::prelude::*;
+
+/// Convert a string column when its distinct values are at most this share of its rows
+pub const MAX_UNIQUE_RATIO: f64 = 0.5;
+
+/// Columns shorter than this are left alone; the saving would not be worth the dictionary
+pub const MIN_ROWS: usize = 100;
+
+/// Whether `column` is a string column that repeats its values enough to convert
+fn is_repetitive(column: &Column) -> Result<bool> {
+    if column.dtype() != &DataType::String || column.len() < MIN_ROWS {
+        return Ok(false);
+    }
+    let unique = column.n_unique()?;
+    Ok((unique as f64) <= column.len() as f64 * MAX_UNIQUE_RATIO)
+}
+
+/// Cast the repetitive string columns of `df` to Categorical, leaving other columns unchanged
+pub fn categorize_strings(mut df: DataFrame) -> Result<DataFrame> {
+    let names: Vec<PlSmallStr> = df
+        .get_columns()
+        .iter()
+        .filter_map(|c| match is_repetitive(c) {
+            Ok(true) => Some(Ok(c.name().clone())),
+            Ok(false) => None,
+            Err(e) => Some(Err(e)),
+        })
+        .collect::<Result<_>>()?;
+    for name in names {
+        let column = df.column(&name)?.cast(&DataType::Categorical(None, CategoricalOrdering::Physical))?;
+        df.with_column(column)?;
+    }
+    Ok(df)
+}
+
+/// Estimated size of `df` in bytes, and what it would be with Categorical columns stored as
+/// plain strings. The two are equal when nothing was converted.
+pub fn memory_usage(df: &DataFrame) -> Result<(usize, usize)> {
+    let current = df.estimated_size();
+    let mut plain = current;
+    for column in df.get_columns() {
+        if matches!(column.dtype(), DataType::Categorical(_, _)) {
+            plain -= column.as_materialized_series().estimated_size();
+            plain += column.cast(&DataType::String)?.as_materialized_series().estimated_size();
+        }
+    }
+    Ok((current, plain))
+}
+
+/// Byte count for display, e.g. "12.3 MB"
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 { format!("{bytes} B") } else { format!("{value:.1} {}", UNITS[unit]) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_categorize_repetitive_strings_only() {
+        let hosts: Vec<String> = (0..1000).map(|i| format!("workstation-{:02}.corp.example.com", i % 10)).collect();
+        let ids: Vec<String> = (0..1000).map(|i| format!("id-{i}")).collect();
+        let df = df!("host" => hosts, "id" => ids, "n" => (0..1000i64).collect::<Vec<_>>()).unwrap();
+        let (before, _) = memory_usage(&df).unwrap();
+
+        let df = categorize_strings(df).unwrap();
+        assert!(matches!(df.column("host").unwrap().dtype(), DataType::Categorical(_, _)));
+        assert_eq!(df.column("id").unwrap().dtype(), &DataType::String);
+        assert_eq!(df.column("n").unwrap().dtype(), &DataType::Int64);
+        assert_eq!(df.column("host").unwrap().get(3).unwrap().str_value(), "workstation-03.corp.example.com");
+
+        let (after, plain) = memory_usage(&df).unwrap();
+        assert!(after < before);
+        assert_eq!(plain, before);
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+    }
+}
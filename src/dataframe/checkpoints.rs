@@ -0,0 +1,76 @@
+//! Named checkpoints of intermediate views of a dataset
+//!
+//! A checkpoint keeps the view in memory for the session. In the workspace it is stored either
+//! as a Parquet snapshot or, to save space, only as the transform history that produced it,
+//! which is replayed on restore.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use polars::prelude::DataFrame;
+use serde::{Deserialize, Serialize};
+
+use crate::dataframe::transform_history::TransformHistory;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub rows: usize,
+    pub columns: usize,
+    /// Transforms that produced this view
+    pub history: TransformHistory,
+    /// Save the view as Parquet with the workspace (otherwise only the history is saved)
+    #[serde(default)]
+    pub store_parquet: bool,
+    /// Parquet file name under the workspace tabs folder, set when saved
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parquet: Option<String>,
+    #[serde(skip)]
+    pub dataframe: Option<Arc<DataFrame>>,
+}
+
+impl Checkpoint {
+    pub fn new(name: String, dataframe: Arc<DataFrame>, history: TransformHistory) -> Self {
+        Self {
+            name,
+            created_at: Utc::now(),
+            rows: dataframe.height(),
+            columns: dataframe.width(),
+            history,
+            store_parquet: true,
+            parquet: None,
+            dataframe: Some(dataframe),
+        }
+    }
+
+    /// How a restore will rebuild the view
+    pub fn restore_kind(&self) -> &'static str {
+        if self.dataframe.is_some() { "snapshot" } else { "replay" }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dataframe::transform_history::TransformStep;
+    use polars::prelude::*;
+
+    #[test]
+    fn test_checkpoint_serializes_without_data() {
+        let df = df!("host" => ["a", "b", "c"], "bytes" => [1i64, 2, 3]).unwrap();
+        let mut history = TransformHistory::default();
+        history.push(TransformStep::Sql("SELECT * FROM df WHERE bytes > 1".to_string()), 2, 2);
+        let checkpoint = Checkpoint::new("big only".to_string(), Arc::new(df), history.clone());
+        assert_eq!((checkpoint.rows, checkpoint.columns), (3, 2));
+        assert_eq!(checkpoint.restore_kind(), "snapshot");
+
+        let json = serde_json::to_string(&checkpoint).unwrap();
+        assert!(!json.contains("\"parquet\""));
+        let restored: Checkpoint = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.name, "big only");
+        assert_eq!(restored.history, history);
+        assert!(restored.store_parquet);
+        assert_eq!(restored.restore_kind(), "replay");
+    }
+}
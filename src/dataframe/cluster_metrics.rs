@@ -0,0 +1,107 @@
+//! Cluster quality metrics (inertia, silhouette) used to compare KMeans runs and pick k.
+
+/// Silhouette is O(n²); larger inputs are scored on an evenly strided sample of this many rows
+pub const MAX_SILHOUETTE_ROWS: usize = 2000;
+
+/// Quality of one clustering run
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClusterQuality {
+    pub k: usize,
+    /// Sum of squared distances from each point to its cluster centroid (lower is tighter)
+    pub inertia: f64,
+    /// Mean silhouette in [-1, 1] (higher is better separated); None when undefined
+    pub silhouette: Option<f64>,
+}
+
+fn squared_distance(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| (x - y) * (x - y)).sum()
+}
+
+/// Sum of squared distances from each row to the mean of its cluster
+pub fn inertia(data: &[Vec<f64>], labels: &[usize]) -> f64 {
+    let dim = data.first().map(|v| v.len()).unwrap_or(0);
+    let k = labels.iter().copied().max().map(|m| m + 1).unwrap_or(0);
+    let mut sums = vec![vec![0.0; dim]; k];
+    let mut counts = vec![0usize; k];
+    for (row, &label) in data.iter().zip(labels) {
+        counts[label] += 1;
+        for (s, v) in sums[label].iter_mut().zip(row) {
+            *s += v;
+        }
+    }
+    for (centroid, &count) in sums.iter_mut().zip(&counts) {
+        if count > 0 {
+            centroid.iter_mut().for_each(|s| *s /= count as f64);
+        }
+    }
+    data.iter()
+        .zip(labels)
+        .map(|(row, &label)| squared_distance(row, &sums[label]))
+        .sum()
+}
+
+/// Mean silhouette coefficient, or None when there are fewer than two non-empty clusters
+pub fn silhouette_score(data: &[Vec<f64>], labels: &[usize]) -> Option<f64> {
+    let stride = data.len().div_ceil(MAX_SILHOUETTE_ROWS).max(1);
+    let idx: Vec<usize> = (0..data.len()).step_by(stride).collect();
+    let k = labels.iter().copied().max()? + 1;
+    let mut sizes = vec![0usize; k];
+    for &i in &idx {
+        sizes[labels[i]] += 1;
+    }
+    if sizes.iter().filter(|&&c| c > 0).count() < 2 {
+        return None;
+    }
+    let mut total = 0.0;
+    for &i in &idx {
+        let own = labels[i];
+        // Singleton clusters score 0 by convention
+        if sizes[own] <= 1 {
+            continue;
+        }
+        let mut dist_sums = vec![0.0; k];
+        for &j in &idx {
+            if i != j {
+                dist_sums[labels[j]] += squared_distance(&data[i], &data[j]).sqrt();
+            }
+        }
+        let a = dist_sums[own] / (sizes[own] - 1) as f64;
+        let b = (0..k)
+            .filter(|&c| c != own && sizes[c] > 0)
+            .map(|c| dist_sums[c] / sizes[c] as f64)
+            .fold(f64::INFINITY, f64::min);
+        let denom = a.max(b);
+        if denom > 0.0 {
+            total += (b - a) / denom;
+        }
+    }
+    Some(total / idx.len() as f64)
+}
+
+/// Score a labelling with both metrics
+pub fn cluster_quality(data: &[Vec<f64>], labels: &[usize], k: usize) -> ClusterQuality {
+    ClusterQuality { k, inertia: inertia(data, labels), silhouette: silhouette_score(data, labels) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inertia() {
+        let data = vec![vec![0.0, 0.0], vec![2.0, 0.0], vec![10.0, 10.0]];
+        // Centroid of the first cluster is (1, 0): each point contributes 1
+        assert_eq!(inertia(&data, &[0, 0, 1]), 2.0);
+        assert_eq!(inertia(&data, &[0, 1, 2]), 0.0);
+    }
+
+    #[test]
+    fn test_silhouette_prefers_natural_split() {
+        let data = vec![vec![0.0], vec![0.1], vec![0.2], vec![9.0], vec![9.1], vec![9.2]];
+        let good = silhouette_score(&data, &[0, 0, 0, 1, 1, 1]).unwrap();
+        let bad = silhouette_score(&data, &[0, 1, 0, 1, 0, 1]).unwrap();
+        assert!(good > 0.9, "good={good}");
+        assert!(bad < 0.0, "bad={bad}");
+        assert_eq!(silhouette_score(&data, &[0; 6]), None);
+    }
+}
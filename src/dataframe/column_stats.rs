@@ -0,0 +1,157 @@
+//! Per-column summary statistics for the details dialog's Statistics tab.
+
+use std::collections::HashMap;
+
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use polars::prelude::*;
+
+use crate::dataframe::outliers::quantile;
+
+/// Mean, spread and quartiles of a numeric column (nulls and NaNs ignored)
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumericStats {
+    pub mean: f64,
+    /// Sample standard deviation; None with fewer than two values
+    pub std: Option<f64>,
+    pub q25: f64,
+    pub median: f64,
+    pub q75: f64,
+}
+
+/// Summary of a single column
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnStats {
+    pub dtype: String,
+    /// Non-null values
+    pub count: usize,
+    pub nulls: usize,
+    pub distinct: usize,
+    pub min: Option<String>,
+    pub max: Option<String>,
+    /// Only for numeric columns
+    pub numeric: Option<NumericStats>,
+    /// Most frequent values with their counts; only for non-numeric columns
+    pub top_values: Vec<(String, usize)>,
+}
+
+impl ColumnStats {
+    /// (statistic, value) pairs in display order
+    pub fn rows(&self) -> Vec<(String, String)> {
+        let mut rows = vec![
+            ("type".to_string(), self.dtype.clone()),
+            ("count".to_string(), self.count.to_string()),
+            ("nulls".to_string(), self.nulls.to_string()),
+            ("distinct".to_string(), self.distinct.to_string()),
+            ("min".to_string(), self.min.clone().unwrap_or_default()),
+            ("max".to_string(), self.max.clone().unwrap_or_default()),
+        ];
+        if let Some(n) = &self.numeric {
+            rows.push(("mean".to_string(), format!("{:.6}", n.mean)));
+            rows.push(("std".to_string(), n.std.map(|v| format!("{v:.6}")).unwrap_or_default()));
+            rows.push(("25%".to_string(), format!("{:.6}", n.q25)));
+            rows.push(("50%".to_string(), format!("{:.6}", n.median)));
+            rows.push(("75%".to_string(), format!("{:.6}", n.q75)));
+        }
+        for (i, (value, count)) in self.top_values.iter().enumerate() {
+            rows.push((format!("top {}", i + 1), format!("{value} ({count})")));
+        }
+        rows
+    }
+}
+
+fn scalar_text(scalar: PolarsResult<Scalar>) -> Option<String> {
+    match scalar.ok()?.value() {
+        AnyValue::Null => None,
+        v => Some(v.str_value().to_string()),
+    }
+}
+
+fn numeric_stats(column: &Column) -> Result<Option<NumericStats>> {
+    let floats = column.cast(&DataType::Float64).map_err(|e| eyre!("{}", e))?;
+    let mut values: Vec<f64> = floats
+        .f64()
+        .map_err(|e| eyre!("{}", e))?
+        .into_iter()
+        .flatten()
+        .filter(|v| v.is_finite())
+        .collect();
+    if values.is_empty() {
+        return Ok(None);
+    }
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let std = (values.len() > 1).then(|| (values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt());
+    values.sort_by(|a, b| a.total_cmp(b));
+    Ok(Some(NumericStats {
+        mean,
+        std,
+        q25: quantile(&values, 0.25),
+        median: quantile(&values, 0.5),
+        q75: quantile(&values, 0.75),
+    }))
+}
+
+fn top_values(column: &Column, top_k: usize) -> Result<Vec<(String, usize)>> {
+    let text = column.cast(&DataType::String).map_err(|e| eyre!("{}", e))?;
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for v in text.str().map_err(|e| eyre!("{}", e))?.into_iter().flatten() {
+        *counts.entry(v).or_insert(0) += 1;
+    }
+    let mut pairs: Vec<(String, usize)> = counts.into_iter().map(|(v, c)| (v.to_string(), c)).collect();
+    // Count descending, then value ascending for a stable order
+    pairs.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    pairs.truncate(top_k);
+    Ok(pairs)
+}
+
+/// Summarise `df[column]`, listing up to `top_k` most frequent values for non-numeric columns
+pub fn column_stats(df: &DataFrame, column: &str, top_k: usize) -> Result<ColumnStats> {
+    let col = df.column(column).map_err(|e| eyre!("{}", e))?;
+    let nulls = col.null_count();
+    let distinct = col.drop_nulls().n_unique().map_err(|e| eyre!("{}", e))?;
+    let (numeric, top_values) = if col.dtype().is_primitive_numeric() {
+        (numeric_stats(col)?, Vec::new())
+    } else {
+        (None, top_values(col, top_k)?)
+    };
+    Ok(ColumnStats {
+        dtype: col.dtype().to_string(),
+        count: col.len() - nulls,
+        nulls,
+        distinct,
+        min: scalar_text(col.min_reduce()),
+        max: scalar_text(col.max_reduce()),
+        numeric,
+        top_values,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numeric_and_string_stats() {
+        let df = df!(
+            "n" => [Some(1i64), Some(2), None, Some(3), Some(4)],
+            "s" => [Some("b"), Some("a"), Some("b"), None, Some("c")]
+        )
+        .unwrap();
+
+        let n = column_stats(&df, "n", 3).unwrap();
+        assert_eq!((n.count, n.nulls, n.distinct), (4, 1, 4));
+        assert_eq!((n.min.as_deref(), n.max.as_deref()), (Some("1"), Some("4")));
+        let numeric = n.numeric.unwrap();
+        assert_eq!(numeric.mean, 2.5);
+        assert_eq!((numeric.q25, numeric.median, numeric.q75), (1.75, 2.5, 3.25));
+        assert!(n.top_values.is_empty());
+
+        let s = column_stats(&df, "s", 2).unwrap();
+        assert_eq!((s.count, s.nulls, s.distinct), (4, 1, 3));
+        assert_eq!(s.min.as_deref(), Some("a"));
+        assert!(s.numeric.is_none());
+        assert_eq!(s.top_values, [("b".to_string(), 2), ("a".to_string(), 1)]);
+        assert!(s.rows().iter().any(|(k, v)| k == "top 1" && v == "b (2)"));
+    }
+}
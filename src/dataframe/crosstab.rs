@@ -0,0 +1,159 @@
+//! Two-column contingency tables ("status code by URL path").
+
+use std::collections::HashMap;
+
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use polars::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::dataframe::resample::ResampleAgg;
+
+/// Column keys beyond this many (least frequent first) are left out of the table
+pub const MAX_CROSSTAB_COLUMNS: usize = 50;
+
+const NULL_KEY: &str = "(null)";
+
+/// User-facing options for the crosstab dialog
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct CrosstabOptions {
+    /// Its values become the table rows
+    pub row_column: String,
+    /// Its values become the table columns
+    pub col_column: String,
+    pub aggregation: ResampleAgg,
+    /// Column read by every aggregation except Count
+    pub value_column: String,
+}
+
+/// A pivoted result: `cells[row][col]` is None where no rows share both keys
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Crosstab {
+    pub row_keys: Vec<String>,
+    pub col_keys: Vec<String>,
+    pub cells: Vec<Vec<Option<f64>>>,
+    /// Column keys dropped by `MAX_CROSSTAB_COLUMNS`
+    pub omitted_columns: usize,
+}
+
+impl Crosstab {
+    /// The table as a DataFrame: the row key column followed by one Float64 column per column key
+    pub fn to_dataframe(&self, row_column: &str) -> Result<DataFrame> {
+        let mut cols = vec![Column::new(PlSmallStr::from_str(row_column), &self.row_keys)];
+        for (c, key) in self.col_keys.iter().enumerate() {
+            let mut name = key.clone();
+            if name == row_column {
+                name = format!("{name}__col");
+            }
+            let values: Vec<Option<f64>> = self.cells.iter().map(|row| row[c]).collect();
+            cols.push(Column::new(PlSmallStr::from_str(&name), values));
+        }
+        DataFrame::new(cols).map_err(|e| eyre!("Failed to build DataFrame: {}", e))
+    }
+}
+
+fn key_text(v: AnyValue) -> String {
+    match v {
+        AnyValue::Null => NULL_KEY.to_string(),
+        v => v.str_value().to_string(),
+    }
+}
+
+/// Cross-tabulate `df` by `options.row_column` × `options.col_column`.
+/// Row keys are sorted; column keys are ordered by how many rows they cover, most first.
+pub fn crosstab(df: &DataFrame, options: &CrosstabOptions) -> Result<Crosstab> {
+    let row = options.row_column.as_str();
+    let col_name = options.col_column.as_str();
+    let value = options.value_column.as_str();
+    for name in [row, col_name] {
+        if df.column(name).is_err() {
+            return Err(eyre!("Column '{}' not found", name));
+        }
+    }
+    if row == col_name {
+        return Err(eyre!("Pick two different columns"));
+    }
+    if options.aggregation.needs_value_column() && df.column(value).is_err() {
+        return Err(eyre!("{} needs a value column", options.aggregation.label()));
+    }
+    let agg = match options.aggregation {
+        ResampleAgg::Count => len(),
+        ResampleAgg::Sum => col(value).sum(),
+        ResampleAgg::Mean => col(value).mean(),
+        ResampleAgg::Min => col(value).min(),
+        ResampleAgg::Max => col(value).max(),
+        ResampleAgg::DistinctCount => col(value).n_unique(),
+    }
+    .cast(DataType::Float64)
+    .alias("__value");
+    let grouped = df
+        .clone()
+        .lazy()
+        .group_by([col(row), col(col_name)])
+        .agg([agg, len().alias("__rows")])
+        .collect()
+        .map_err(|e| eyre!("Crosstab failed: {}", e))?;
+
+    let row_vals = grouped.column(row).map_err(|e| eyre!("{}", e))?;
+    let col_vals = grouped.column(col_name).map_err(|e| eyre!("{}", e))?;
+    let values = grouped.column("__value").map_err(|e| eyre!("{}", e))?.f64().map_err(|e| eyre!("{}", e))?.clone();
+    let rows_per = grouped.column("__rows").and_then(|c| c.cast(&DataType::UInt64)).map_err(|e| eyre!("{}", e))?;
+    let rows_per = rows_per.u64().map_err(|e| eyre!("{}", e))?;
+
+    let mut entries = Vec::with_capacity(grouped.height());
+    let mut col_weight: HashMap<String, u64> = HashMap::new();
+    for i in 0..grouped.height() {
+        let r = key_text(row_vals.get(i).map_err(|e| eyre!("{}", e))?);
+        let c = key_text(col_vals.get(i).map_err(|e| eyre!("{}", e))?);
+        *col_weight.entry(c.clone()).or_insert(0) += rows_per.get(i).unwrap_or(0);
+        entries.push((r, c, values.get(i)));
+    }
+
+    let mut col_keys: Vec<(String, u64)> = col_weight.into_iter().collect();
+    col_keys.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    let omitted_columns = col_keys.len().saturating_sub(MAX_CROSSTAB_COLUMNS);
+    col_keys.truncate(MAX_CROSSTAB_COLUMNS);
+    let col_keys: Vec<String> = col_keys.into_iter().map(|(k, _)| k).collect();
+    let col_index: HashMap<&str, usize> = col_keys.iter().enumerate().map(|(i, k)| (k.as_str(), i)).collect();
+
+    let mut row_keys: Vec<String> = entries.iter().map(|(r, _, _)| r.clone()).collect();
+    row_keys.sort();
+    row_keys.dedup();
+    let row_index: HashMap<&str, usize> = row_keys.iter().enumerate().map(|(i, k)| (k.as_str(), i)).collect();
+
+    let mut cells = vec![vec![None; col_keys.len()]; row_keys.len()];
+    for (r, c, v) in &entries {
+        if let Some(&ci) = col_index.get(c.as_str()) {
+            cells[row_index[r.as_str()]][ci] = *v;
+        }
+    }
+    Ok(Crosstab { row_keys, col_keys, cells, omitted_columns })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_by_path() {
+        let df = df!(
+            "path" => ["/a", "/a", "/b", "/a", "/b"],
+            "status" => [Some(200i64), Some(404), Some(200), Some(200), None],
+            "bytes" => [10i64, 1, 5, 30, 2]
+        )
+        .unwrap();
+        let options = CrosstabOptions { row_column: "path".to_string(), col_column: "status".to_string(), ..Default::default() };
+        let table = crosstab(&df, &options).unwrap();
+        assert_eq!(table.row_keys, ["/a", "/b"]);
+        assert_eq!(table.col_keys, ["200", "(null)", "404"]);
+        assert_eq!(table.cells[0], [Some(2.0), None, Some(1.0)]);
+        assert_eq!(table.cells[1], [Some(1.0), Some(1.0), None]);
+
+        let sums = CrosstabOptions { aggregation: ResampleAgg::Sum, value_column: "bytes".to_string(), ..options };
+        let table = crosstab(&df, &sums).unwrap();
+        assert_eq!(table.cells[0][0], Some(40.0));
+        let out = table.to_dataframe("path").unwrap();
+        assert_eq!(out.width(), 4);
+        assert_eq!(out.column("200").unwrap().f64().unwrap().get(1), Some(5.0));
+    }
+}
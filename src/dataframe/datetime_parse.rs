@@ -0,0 +1,116 @@
+//! Parse text timestamps into epoch microseconds for building Datetime columns.
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+
+/// Formats tried, in order, when no explicit format is given
+pub const AUTO_DATETIME_FORMATS: &[&str] = &[
+    "%Y-%m-%dT%H:%M:%S%.f%:z",
+    "%Y-%m-%dT%H:%M:%S%.fZ",
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M:%S%.f%:z",
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y/%m/%d %H:%M:%S%.f",
+    "%m/%d/%Y %H:%M:%S%.f",
+    "%m/%d/%Y %I:%M:%S %p",
+    "%d/%m/%Y %H:%M:%S%.f",
+    "%d-%b-%Y %H:%M:%S%.f",
+    "%a, %d %b %Y %H:%M:%S %z",
+    "%b %d %Y %H:%M:%S",
+    "%Y-%m-%d",
+    "%Y/%m/%d",
+    "%m/%d/%Y",
+    "%d/%m/%Y",
+    "%Y%m%d%H%M%S",
+];
+
+/// Parse one value with `format` into UTC epoch microseconds.
+///
+/// Formats with an offset (`%z`, `%:z`) are converted to UTC; formats without a time part
+/// are read as midnight.
+pub fn parse_datetime_micros(value: &str, format: &str) -> Option<i64> {
+    let value = value.trim();
+    if let Ok(dt) = DateTime::parse_from_str(value, format) {
+        return Some(dt.timestamp_micros());
+    }
+    if let Ok(ndt) = NaiveDateTime::parse_from_str(value, format) {
+        return Some(ndt.and_utc().timestamp_micros());
+    }
+    NaiveDate::parse_from_str(value, format)
+        .ok()
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|ndt| ndt.and_utc().timestamp_micros())
+}
+
+/// Parse one value with the first auto-detect format that matches
+pub fn parse_datetime_auto(value: &str) -> Option<i64> {
+    AUTO_DATETIME_FORMATS.iter().find_map(|fmt| parse_datetime_micros(value, fmt))
+}
+
+/// Parse a column of optional strings.
+///
+/// With an explicit format, every value uses it. Otherwise the auto-detect format that parses
+/// the most values is used for the whole column, so ambiguous day/month orders stay consistent.
+/// Values that do not parse become null. Returns the parsed values and the format used.
+pub fn parse_datetime_values(values: &[Option<&str>], format: Option<&str>) -> Result<(Vec<Option<i64>>, String)> {
+    let non_empty = values.iter().flatten().filter(|v| !v.trim().is_empty()).count();
+    if non_empty == 0 {
+        return Err(eyre!("Column has no values to parse"));
+    }
+    let parse_all = |fmt: &str| -> Vec<Option<i64>> {
+        values
+            .iter()
+            .map(|v| v.and_then(|s| parse_datetime_micros(s, fmt)))
+            .collect()
+    };
+    let (parsed, used) = match format.map(str::trim).filter(|f| !f.is_empty()) {
+        Some(fmt) => (parse_all(fmt), fmt.to_string()),
+        // Reversed so ties go to the earlier (preferred) format
+        None => AUTO_DATETIME_FORMATS
+            .iter()
+            .rev()
+            .map(|fmt| (parse_all(fmt), fmt.to_string()))
+            .max_by_key(|(parsed, _)| parsed.iter().flatten().count())
+            .unwrap_or_default(),
+    };
+    if parsed.iter().all(Option::is_none) {
+        return Err(match format {
+            Some(fmt) if !fmt.trim().is_empty() => eyre!("No values matched format '{}'", fmt.trim()),
+            _ => eyre!("Could not detect a datetime format for this column"),
+        });
+    }
+    Ok((parsed, used))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_with_explicit_format() {
+        let values = [Some("2024-03-05 10:20:30"), None, Some("garbage")];
+        let (parsed, used) = parse_datetime_values(&values, Some("%Y-%m-%d %H:%M:%S")).unwrap();
+        assert_eq!(used, "%Y-%m-%d %H:%M:%S");
+        assert_eq!(parsed[0], Some(1_709_634_030_000_000));
+        assert_eq!(parsed[1], None);
+        assert_eq!(parsed[2], None);
+        assert!(parse_datetime_values(&[Some("x")], Some("%Y")).is_err());
+    }
+
+    #[test]
+    fn test_auto_detect_picks_best_format() {
+        // Day-first values: 13/01 cannot be month-first
+        let values = [Some("13/01/2024 00:00:00"), Some("02/01/2024 00:00:00")];
+        let (parsed, used) = parse_datetime_values(&values, None).unwrap();
+        assert_eq!(used, "%d/%m/%Y %H:%M:%S%.f");
+        assert_eq!(parsed[1], parse_datetime_micros("2024-01-02", "%Y-%m-%d"));
+
+        // Ambiguous values fall back to the first matching format (month-first)
+        let (_, used) = parse_datetime_values(&[Some("02/01/2024 00:00:00")], None).unwrap();
+        assert_eq!(used, "%m/%d/%Y %H:%M:%S%.f");
+
+        let (parsed, _) = parse_datetime_values(&[Some("2024-01-02T03:04:05+02:00")], None).unwrap();
+        assert_eq!(parsed[0], parse_datetime_micros("2024-01-02 01:04:05", "%Y-%m-%d %H:%M:%S"));
+    }
+}
@@ -0,0 +1,96 @@
+//! SHA-256 of imported source files, recorded at import and re-checked on demand, so an analyst
+//! can show the data they worked from is unchanged.
+
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Hash of one source file when it was imported
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileHash {
+    pub path: PathBuf,
+    /// Lowercase hex SHA-256
+    pub sha256: String,
+    pub size: u64,
+    pub hashed_at: DateTime<Utc>,
+}
+
+/// Result of re-hashing a recorded file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HashCheck {
+    Match,
+    /// The file changed; its current hash
+    Mismatch(String),
+    /// The file could not be read
+    Unreadable(String),
+}
+
+impl HashCheck {
+    pub fn label(&self) -> &'static str {
+        match self {
+            HashCheck::Match => "Verified",
+            HashCheck::Mismatch(_) => "MISMATCH",
+            HashCheck::Unreadable(_) => "Unreadable",
+        }
+    }
+}
+
+/// Stream `path` through SHA-256
+pub fn sha256_file(path: &Path) -> Result<(String, u64)> {
+    let mut file = File::open(path).map_err(|e| eyre!("Failed to open {}: {}", path.display(), e))?;
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0u8; 1 << 16];
+    let mut size = 0u64;
+    loop {
+        let n = file.read(&mut buf).map_err(|e| eyre!("Failed to read {}: {}", path.display(), e))?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+        size += n as u64;
+    }
+    Ok((hasher.finalize().iter().map(|b| format!("{b:02x}")).collect(), size))
+}
+
+impl FileHash {
+    pub fn record(path: &Path) -> Result<Self> {
+        let (sha256, size) = sha256_file(path)?;
+        Ok(Self { path: path.to_path_buf(), sha256, size, hashed_at: Utc::now() })
+    }
+
+    /// Re-hash the file and compare with the recorded hash
+    pub fn verify(&self) -> HashCheck {
+        match sha256_file(&self.path) {
+            Ok((sha256, _)) if sha256 == self.sha256 => HashCheck::Match,
+            Ok((sha256, _)) => HashCheck::Mismatch(sha256),
+            Err(e) => HashCheck::Unreadable(e.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_and_verify() {
+        let path = std::env::temp_dir().join(format!("datatui_evidence_{}.csv", std::process::id()));
+        std::fs::write(&path, "a,b\n1,2\n").unwrap();
+        let recorded = FileHash::record(&path).unwrap();
+        assert_eq!(recorded.size, 8);
+        assert_eq!(recorded.sha256.len(), 64);
+        assert_eq!(recorded.verify(), HashCheck::Match);
+
+        std::fs::write(&path, "a,b\n1,3\n").unwrap();
+        assert!(matches!(recorded.verify(), HashCheck::Mismatch(current) if current != recorded.sha256));
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(recorded.verify(), HashCheck::Unreadable(_)));
+    }
+}
@@ -0,0 +1,207 @@
+//! Shadow index of a view's cell text for Find
+//!
+//! The first search over a column formats its cells once, keeping the text, a lowercased copy
+//! and the rows of each distinct (lowercased) value. Later `FindNext`, count and `FindAll` calls
+//! match against those instead of formatting every cell again. The index belongs to the view
+//! cache, so any transform of the view drops it.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use color_eyre::Result;
+use polars::prelude::*;
+use regex::Regex;
+
+use crate::dialog::find_dialog::{FindOptions, SearchMode};
+
+/// Cell text of one column, by view row
+#[derive(Debug, Default)]
+pub struct IndexedColumn {
+    /// Cells as displayed to Find (`AnyValue::str_value`)
+    pub text: Vec<String>,
+    /// `text` lowercased, for case-insensitive matching
+    pub lower: Vec<String>,
+    /// Rows of each distinct lowercased value, in row order
+    values: HashMap<String, Vec<usize>>,
+}
+
+impl IndexedColumn {
+    pub fn build(column: &Column) -> Self {
+        let mut indexed = Self::default();
+        for row in 0..column.len() {
+            let text = column.get(row).map(|v| v.str_value().to_string()).unwrap_or_default();
+            let lower = text.to_lowercase();
+            indexed.values.entry(lower.clone()).or_default().push(row);
+            indexed.text.push(text);
+            indexed.lower.push(lower);
+        }
+        indexed
+    }
+
+    pub fn len(&self) -> usize {
+        self.text.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+}
+
+/// Indexed columns of the current view, built as searches need them
+#[derive(Debug, Clone, Default)]
+pub struct FindIndex {
+    columns: HashMap<String, Arc<IndexedColumn>>,
+}
+
+impl FindIndex {
+    pub fn get(&self, column: &str) -> Option<Arc<IndexedColumn>> {
+        self.columns.get(column).cloned()
+    }
+
+    /// Names in `columns` that have not been indexed yet
+    pub fn missing(&self, columns: &[String]) -> Vec<String> {
+        columns.iter().filter(|c| !self.columns.contains_key(*c)).cloned().collect()
+    }
+
+    /// Index every column of `df`
+    pub fn add(&mut self, df: &DataFrame) {
+        for column in df.get_columns() {
+            self.columns.insert(column.name().to_string(), Arc::new(IndexedColumn::build(column)));
+        }
+    }
+}
+
+/// A Find pattern, compiled once per search
+pub enum CellMatcher {
+    /// Plain text, compared without a regex
+    Text { pattern: String, match_case: bool, entire_cell: bool, anchored: bool },
+    /// Regular expressions, and text that has to match whole words
+    Regex(Regex),
+}
+
+impl CellMatcher {
+    pub fn new(pattern: &str, options: &FindOptions, search_mode: &SearchMode) -> Result<Self> {
+        if *search_mode == SearchMode::Normal && !options.whole_word {
+            return Ok(Self::Text {
+                pattern: if options.match_case { pattern.to_string() } else { pattern.to_lowercase() },
+                match_case: options.match_case,
+                entire_cell: options.entire_cell,
+                anchored: options.anchored,
+            });
+        }
+        let mut source = match search_mode {
+            SearchMode::Normal => regex::escape(pattern),
+            SearchMode::Regex => format!("(?:{pattern})"),
+        };
+        if options.whole_word {
+            source = format!(r"\b{source}\b");
+        }
+        if options.entire_cell {
+            source = format!("^{source}$");
+        } else if options.anchored {
+            source = format!("^{source}");
+        }
+        if !options.match_case {
+            source = format!("(?i){source}");
+        }
+        Ok(Self::Regex(Regex::new(&source)?))
+    }
+
+    /// Whether the cell at `row` of `column` matches
+    pub fn matches(&self, column: &IndexedColumn, row: usize) -> bool {
+        match self {
+            Self::Text { pattern, match_case, entire_cell, anchored } => {
+                let cell = if *match_case { &column.text[row] } else { &column.lower[row] };
+                if *entire_cell {
+                    cell == pattern
+                } else if *anchored {
+                    cell.starts_with(pattern.as_str())
+                } else {
+                    cell.contains(pattern.as_str())
+                }
+            }
+            Self::Regex(re) => re.is_match(&column.text[row]),
+        }
+    }
+
+    /// Byte range of the first match in `cell`
+    pub fn find(&self, cell: &str) -> Option<std::ops::Range<usize>> {
+        match self {
+            Self::Text { pattern, match_case, entire_cell, anchored } => {
+                let lowered;
+                let haystack = if *match_case {
+                    cell
+                } else {
+                    lowered = cell.to_lowercase();
+                    &lowered
+                };
+                let start = if *entire_cell {
+                    (haystack == pattern).then_some(0)
+                } else if *anchored {
+                    haystack.starts_with(pattern.as_str()).then_some(0)
+                } else {
+                    haystack.find(pattern.as_str())
+                }?;
+                // Lowercasing can change byte lengths; fall back to the whole cell then
+                let end = start + pattern.len();
+                if cell.is_char_boundary(start) && cell.is_char_boundary(end.min(cell.len())) {
+                    Some(start..end.min(cell.len()))
+                } else {
+                    Some(0..cell.len())
+                }
+            }
+            Self::Regex(re) => re.find(cell).map(|m| m.range()),
+        }
+    }
+
+    /// Rows of `column` that match, in order. Entire-cell text matches are looked up by value.
+    pub fn matching_rows(&self, column: &IndexedColumn) -> Vec<usize> {
+        match self {
+            Self::Text { pattern, match_case, entire_cell: true, .. } => {
+                let rows = column.values.get(&pattern.to_lowercase()).map(Vec::as_slice).unwrap_or_default();
+                rows.iter().copied().filter(|&row| !*match_case || column.text[row] == *pattern).collect()
+            }
+            _ => (0..column.len()).filter(|&row| self.matches(column, row)).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matcher_over_indexed_column() {
+        let df = df!("user" => ["Admin", "admin", "administrator", "guest admin", "a.min"]).unwrap();
+        let mut index = FindIndex::default();
+        assert_eq!(index.missing(&["user".to_string()]), vec!["user".to_string()]);
+        index.add(&df);
+        assert!(index.missing(&["user".to_string()]).is_empty());
+        let column = index.get("user").unwrap();
+
+        let find = |pattern: &str, options: &FindOptions, mode: SearchMode| {
+            CellMatcher::new(pattern, options, &mode).unwrap().matching_rows(&column)
+        };
+        let options = FindOptions::default();
+        assert_eq!(find("ADMIN", &options, SearchMode::Normal), vec![0, 1, 2, 3]);
+        let whole_word = FindOptions { whole_word: true, ..options.clone() };
+        assert_eq!(find("admin", &whole_word, SearchMode::Normal), vec![0, 1, 3]);
+        let entire_cell = FindOptions { entire_cell: true, ..options.clone() };
+        assert_eq!(find("admin", &entire_cell, SearchMode::Normal), vec![0, 1]);
+        let exact = FindOptions { match_case: true, ..entire_cell.clone() };
+        assert_eq!(find("admin", &exact, SearchMode::Normal), vec![1]);
+        let anchored = FindOptions { anchored: true, ..options.clone() };
+        assert_eq!(find("adm", &anchored, SearchMode::Normal), vec![0, 1, 2]);
+
+        // Regex metacharacters are literal in Normal mode, also for whole words
+        assert_eq!(find("a.min", &whole_word, SearchMode::Normal), vec![4]);
+        assert_eq!(find("a.min", &options, SearchMode::Regex), vec![0, 1, 2, 3, 4]);
+        assert_eq!(find("adm|gue", &anchored, SearchMode::Regex), vec![0, 1, 2, 3]);
+        assert_eq!(find("adm.*|guest", &entire_cell, SearchMode::Regex), vec![0, 1, 2]);
+        assert_eq!(find("admin", &whole_word, SearchMode::Regex), vec![0, 1, 3]);
+        assert_eq!(
+            CellMatcher::new("ADMIN", &options, &SearchMode::Normal).unwrap().find("guest admin"),
+            Some(6..11)
+        );
+    }
+}
@@ -0,0 +1,352 @@
+//! Case report of a dataset's findings: tagged rows with their notes, the filter and SQL behind
+//! the current view, and the transform history, rendered as Markdown or HTML.
+
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use polars::prelude::*;
+
+use crate::dataframe::row_tags::RowTags;
+use crate::dataframe::transform_history::{TransformHistory, TransformStep};
+use crate::dialog::filter_dialog::FilterExpr;
+
+/// Rows embedded per table; the report notes how many were left out
+pub const MAX_REPORT_ROWS: usize = 200;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReportFormat {
+    #[default]
+    Markdown,
+    Html,
+}
+
+impl ReportFormat {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ReportFormat::Markdown => "Markdown",
+            ReportFormat::Html => "HTML",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ReportFormat::Markdown => "md",
+            ReportFormat::Html => "html",
+        }
+    }
+
+    pub fn toggle(self) -> Self {
+        match self {
+            ReportFormat::Markdown => ReportFormat::Html,
+            ReportFormat::Html => ReportFormat::Markdown,
+        }
+    }
+}
+
+/// A table embedded in the report, already formatted as text
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReportTable {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+    /// Rows beyond `MAX_REPORT_ROWS` that were left out
+    pub omitted: usize,
+}
+
+impl ReportTable {
+    pub fn from_dataframe(df: &DataFrame) -> Self {
+        let headers = df.get_column_names().iter().map(|c| c.to_string()).collect();
+        let shown = df.height().min(MAX_REPORT_ROWS);
+        let rows = (0..shown)
+            .map(|row| df.get_columns().iter().map(|c| c.get(row).map(|v| cell_text(&v)).unwrap_or_default()).collect())
+            .collect();
+        Self { headers, rows, omitted: df.height() - shown }
+    }
+}
+
+fn cell_text(value: &AnyValue) -> String {
+    match value {
+        AnyValue::Null => String::new(),
+        AnyValue::String(s) => s.to_string(),
+        AnyValue::StringOwned(s) => s.to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Everything the report says about one dataset, gathered when the report dialog opens
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Findings {
+    pub dataset_name: String,
+    pub source_path: Option<PathBuf>,
+    /// Rows and columns of the current view
+    pub view_shape: (usize, usize),
+    /// Filter active on the current view
+    pub filter: Option<String>,
+    pub sql_queries: Vec<String>,
+    /// (step kind, summary, applied at)
+    pub history: Vec<(String, String, DateTime<Utc>)>,
+    pub label_counts: Vec<(String, usize)>,
+    /// Tagged rows of the original data, led by their labels, note and tag time
+    pub tagged_rows: ReportTable,
+}
+
+impl Findings {
+    /// Gather the findings; `base` is the original data, so rows filtered out of the view still
+    /// appear when tagged
+    pub fn collect(
+        dataset_name: &str,
+        source_path: Option<PathBuf>,
+        base: &DataFrame,
+        view_shape: (usize, usize),
+        filter: Option<&FilterExpr>,
+        history: &TransformHistory,
+        tags: &RowTags,
+    ) -> Result<Self> {
+        let sql_queries = history
+            .records
+            .iter()
+            .filter_map(|record| match &record.step {
+                TransformStep::Sql(query) => Some(query.trim().to_string()),
+                _ => None,
+            })
+            .collect();
+        Ok(Self {
+            dataset_name: dataset_name.to_string(),
+            source_path,
+            view_shape,
+            filter: filter.map(|expr| TransformStep::Filter(expr.clone()).summary()),
+            sql_queries,
+            history: history
+                .records
+                .iter()
+                .map(|record| (record.step.kind_name().to_string(), record.step.summary(), record.applied_at))
+                .collect(),
+            label_counts: tags.label_counts(),
+            tagged_rows: ReportTable::from_dataframe(&tagged_rows(base, tags)?),
+        })
+    }
+}
+
+/// Rows of `df` that carry a tag, with the tag's labels, note and time as leading columns
+fn tagged_rows(df: &DataFrame, tags: &RowTags) -> Result<DataFrame> {
+    let found: Vec<(usize, &crate::dataframe::row_tags::RowTag)> = tags
+        .row_keys(df)
+        .into_iter()
+        .enumerate()
+        .filter_map(|(row, key)| Some((row, tags.tags.get(&key?)?)))
+        .collect();
+    let idx = IdxCa::from_vec("idx".into(), found.iter().map(|(row, _)| *row as IdxSize).collect());
+    let mut rows = df.take(&idx).map_err(|e| eyre!("Failed to select tagged rows: {}", e))?;
+    let leading = [
+        Column::new("labels".into(), found.iter().map(|(_, t)| t.labels.join(", ")).collect::<Vec<_>>()),
+        Column::new("note".into(), found.iter().map(|(_, t)| t.note.clone()).collect::<Vec<_>>()),
+        Column::new(
+            "tagged_at".into(),
+            found.iter().map(|(_, t)| t.tagged_at.format("%Y-%m-%d %H:%M:%S").to_string()).collect::<Vec<_>>(),
+        ),
+    ];
+    for (i, column) in leading.into_iter().enumerate() {
+        let name = column.name().clone();
+        if rows.column(&name).is_ok() {
+            continue;
+        }
+        rows.insert_column(i, column).map_err(|e| eyre!("Failed to add {} column: {}", name, e))?;
+    }
+    Ok(rows)
+}
+
+/// What the analyst adds in the report dialog
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReportOptions {
+    pub title: String,
+    /// Free-text summary placed at the top of the report
+    pub summary: String,
+    pub format: ReportFormat,
+}
+
+pub fn render_report(findings: &Findings, options: &ReportOptions, generated_at: DateTime<Utc>) -> String {
+    match options.format {
+        ReportFormat::Markdown => render_markdown(findings, options, generated_at),
+        ReportFormat::Html => render_html(findings, options, generated_at),
+    }
+}
+
+fn title(findings: &Findings, options: &ReportOptions) -> String {
+    match options.title.trim() {
+        "" => format!("Findings: {}", findings.dataset_name),
+        title => title.to_string(),
+    }
+}
+
+fn facts(findings: &Findings, generated_at: DateTime<Utc>) -> Vec<(&'static str, String)> {
+    let mut facts = vec![("Dataset", findings.dataset_name.clone())];
+    if let Some(path) = &findings.source_path {
+        facts.push(("Source", path.display().to_string()));
+    }
+    facts.push(("Current view", format!("{} rows x {} columns", findings.view_shape.0, findings.view_shape.1)));
+    facts.push(("Filter", findings.filter.clone().unwrap_or_else(|| "none".to_string())));
+    facts.push(("Generated", generated_at.format("%Y-%m-%d %H:%M:%S UTC").to_string()));
+    facts
+}
+
+fn md_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+fn render_markdown(findings: &Findings, options: &ReportOptions, generated_at: DateTime<Utc>) -> String {
+    let mut out = format!("# {}\n\n", title(findings, options));
+    for (name, value) in facts(findings, generated_at) {
+        out.push_str(&format!("- **{name}:** {}\n", md_cell(&value)));
+    }
+    if !options.summary.trim().is_empty() {
+        out.push_str(&format!("\n## Summary\n\n{}\n", options.summary.trim()));
+    }
+
+    out.push_str("\n## Tagged Rows\n\n");
+    if findings.tagged_rows.rows.is_empty() {
+        out.push_str("No rows are tagged.\n");
+    } else {
+        let labels = findings.label_counts.iter().map(|(l, n)| format!("{} ({n})", md_cell(l))).collect::<Vec<_>>();
+        out.push_str(&format!("Labels: {}\n\n", labels.join(", ")));
+        let table = &findings.tagged_rows;
+        out.push_str(&format!("| {} |\n", table.headers.iter().map(|h| md_cell(h)).collect::<Vec<_>>().join(" | ")));
+        out.push_str(&format!("|{}\n", "---|".repeat(table.headers.len())));
+        for row in &table.rows {
+            out.push_str(&format!("| {} |\n", row.iter().map(|c| md_cell(c)).collect::<Vec<_>>().join(" | ")));
+        }
+        if table.omitted > 0 {
+            out.push_str(&format!("\n_{} more rows not shown._\n", table.omitted));
+        }
+    }
+
+    if !findings.sql_queries.is_empty() {
+        out.push_str("\n## SQL Queries\n");
+        for query in &findings.sql_queries {
+            out.push_str(&format!("\n```sql\n{query}\n```\n"));
+        }
+    }
+
+    out.push_str("\n## Transform History\n\n");
+    if findings.history.is_empty() {
+        out.push_str("No transforms were applied.\n");
+    } else {
+        for (i, (kind, summary, applied_at)) in findings.history.iter().enumerate() {
+            out.push_str(&format!(
+                "{}. **{kind}** {} _({})_\n",
+                i + 1,
+                md_cell(summary),
+                applied_at.format("%Y-%m-%d %H:%M:%S")
+            ));
+        }
+    }
+    out
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+fn render_html(findings: &Findings, options: &ReportOptions, generated_at: DateTime<Utc>) -> String {
+    let title = escape_html(&title(findings, options));
+    let mut out = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>\n\
+         body {{ font-family: sans-serif; margin: 2em; }}\n\
+         table {{ border-collapse: collapse; }}\n\
+         th, td {{ border: 1px solid #ccc; padding: 2px 6px; text-align: left; vertical-align: top; }}\n\
+         th {{ background: #eee; }}\n\
+         pre {{ background: #f6f6f6; padding: 0.5em; }}\n\
+         </style>\n</head>\n<body>\n<h1>{title}</h1>\n<ul>\n"
+    );
+    for (name, value) in facts(findings, generated_at) {
+        out.push_str(&format!("<li><b>{name}:</b> {}</li>\n", escape_html(&value)));
+    }
+    out.push_str("</ul>\n");
+    if !options.summary.trim().is_empty() {
+        out.push_str("<h2>Summary</h2>\n");
+        for paragraph in options.summary.trim().split("\n\n") {
+            out.push_str(&format!("<p>{}</p>\n", escape_html(paragraph).replace('\n', "<br>")));
+        }
+    }
+
+    out.push_str("<h2>Tagged Rows</h2>\n");
+    if findings.tagged_rows.rows.is_empty() {
+        out.push_str("<p>No rows are tagged.</p>\n");
+    } else {
+        let labels = findings.label_counts.iter().map(|(l, n)| format!("{} ({n})", escape_html(l))).collect::<Vec<_>>();
+        out.push_str(&format!("<p>Labels: {}</p>\n<table>\n<tr>", labels.join(", ")));
+        let table = &findings.tagged_rows;
+        for header in &table.headers {
+            out.push_str(&format!("<th>{}</th>", escape_html(header)));
+        }
+        out.push_str("</tr>\n");
+        for row in &table.rows {
+            out.push_str("<tr>");
+            for cell in row {
+                out.push_str(&format!("<td>{}</td>", escape_html(cell)));
+            }
+            out.push_str("</tr>\n");
+        }
+        out.push_str("</table>\n");
+        if table.omitted > 0 {
+            out.push_str(&format!("<p><i>{} more rows not shown.</i></p>\n", table.omitted));
+        }
+    }
+
+    if !findings.sql_queries.is_empty() {
+        out.push_str("<h2>SQL Queries</h2>\n");
+        for query in &findings.sql_queries {
+            out.push_str(&format!("<pre><code>{}</code></pre>\n", escape_html(query)));
+        }
+    }
+
+    out.push_str("<h2>Transform History</h2>\n");
+    if findings.history.is_empty() {
+        out.push_str("<p>No transforms were applied.</p>\n");
+    } else {
+        out.push_str("<ol>\n");
+        for (kind, summary, applied_at) in &findings.history {
+            out.push_str(&format!(
+                "<li><b>{}</b> {} <i>({})</i></li>\n",
+                kind,
+                escape_html(summary),
+                applied_at.format("%Y-%m-%d %H:%M:%S")
+            ));
+        }
+        out.push_str("</ol>\n");
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_lists_tagged_rows_and_queries() {
+        let base = df!("host" => ["a", "b|c", "<d>"], "bytes" => [1i64, 2, 3]).unwrap();
+        let key_columns = vec!["host".to_string(), "bytes".to_string()];
+        let mut tags = RowTags::default();
+        tags.set(&base, 1, &key_columns, vec!["c2".to_string()], "beacon every 60s".to_string()).unwrap();
+        tags.set(&base, 2, &key_columns, vec!["c2".to_string(), "exfil".to_string()], String::new()).unwrap();
+        let mut history = TransformHistory::default();
+        history.push(TransformStep::Sql("SELECT *\nFROM df".to_string()), 3, 2);
+
+        let findings = Findings::collect("logs", None, &base, (3, 2), None, &history, &tags).unwrap();
+        assert_eq!(findings.tagged_rows.headers, ["labels", "note", "tagged_at", "host", "bytes"]);
+        assert_eq!(findings.tagged_rows.rows.len(), 2);
+
+        let options = ReportOptions { title: String::new(), summary: "Two hosts of interest.".to_string(), format: ReportFormat::Markdown };
+        let markdown = render_report(&findings, &options, Utc::now());
+        assert!(markdown.starts_with("# Findings: logs\n"));
+        assert!(markdown.contains("Labels: c2 (2), exfil (1)"));
+        assert!(markdown.contains("| c2 | beacon every 60s |"));
+        assert!(markdown.contains("| b\\|c | 2 |"));
+        assert!(markdown.contains("```sql\nSELECT *\nFROM df\n```"));
+
+        let html = render_report(&findings, &ReportOptions { format: ReportFormat::Html, ..options }, Utc::now());
+        assert!(html.contains("<td>&lt;d&gt;</td>"));
+        assert!(html.contains("<p>Two hosts of interest.</p>"));
+    }
+}
@@ -0,0 +1,144 @@
+//! Group counts and aggregates backing the group view, computed by Polars one tree level at a time.
+
+use color_eyre::Result;
+use polars::chunked_array::cast::CastOptions;
+use polars::prelude::*;
+
+/// Shown in place of a null group key
+pub const NULL_KEY: &str = "<NULL>";
+
+const ROW_INDEX: &str = "__group_row";
+const COUNT: &str = "__group_count";
+const FIRST_ROW: &str = "__group_first_row";
+const SUM: &str = "__group_sum";
+const MEAN: &str = "__group_mean";
+const NUMERIC: &str = "__group_numeric";
+
+/// One group of the tree
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupRow {
+    /// Key of every level from the first group column down to this group; None is a null key
+    pub path: Vec<Option<String>>,
+    /// Number of rows in the group
+    pub count: usize,
+    /// Index of the group's first row in the grouped view
+    pub first_row: usize,
+    /// Sum and mean of the aggregate column's numeric values; None when the group has none
+    pub sum: Option<f64>,
+    pub mean: Option<f64>,
+}
+
+impl GroupRow {
+    /// Key of the group's own level, as displayed
+    pub fn key(&self) -> &str {
+        self.path.last().and_then(|k| k.as_deref()).unwrap_or(NULL_KEY)
+    }
+}
+
+/// Subgroups of the group at `path` (the top-level groups when `path` is empty), grouped by
+/// the next of `group_columns`, in key order
+pub fn group_children(
+    plan: LazyFrame,
+    group_columns: &[String],
+    path: &[Option<String>],
+    aggregate_column: Option<&str>,
+) -> Result<Vec<GroupRow>> {
+    let keys = &group_columns[..(path.len() + 1).min(group_columns.len())];
+    let mut plan = plan.with_row_index(ROW_INDEX, None);
+    for (column, key) in keys.iter().zip(path) {
+        let value = col(column.as_str()).cast(DataType::String);
+        plan = plan.filter(match key {
+            Some(key) => value.eq(lit(key.as_str())),
+            None => value.is_null(),
+        });
+    }
+    grouped(plan, keys, aggregate_column)
+}
+
+/// Every group at `depth` (0 is the first group column) across the whole view, in key order
+pub fn group_level(
+    plan: LazyFrame,
+    group_columns: &[String],
+    depth: usize,
+    aggregate_column: Option<&str>,
+) -> Result<Vec<GroupRow>> {
+    let keys = &group_columns[..(depth + 1).min(group_columns.len())];
+    grouped(plan.with_row_index(ROW_INDEX, None), keys, aggregate_column)
+}
+
+/// Group `plan` (which has the row index column) by `keys` as text
+fn grouped(plan: LazyFrame, keys: &[String], aggregate_column: Option<&str>) -> Result<Vec<GroupRow>> {
+    let key_exprs: Vec<Expr> = keys.iter().map(|k| col(k.as_str()).cast(DataType::String)).collect();
+    let mut aggs = vec![len().alias(COUNT), col(ROW_INDEX).min().alias(FIRST_ROW)];
+    if let Some(column) = aggregate_column {
+        let values = col(column).cast_with_options(DataType::Float64, CastOptions::NonStrict);
+        aggs.push(values.clone().sum().alias(SUM));
+        aggs.push(values.clone().mean().alias(MEAN));
+        aggs.push(values.count().alias(NUMERIC));
+    }
+    let order = SortMultipleOptions::default().with_nulls_last(true).with_maintain_order(true);
+    let df = plan
+        .group_by(key_exprs)
+        .agg(aggs)
+        .sort(keys.iter().map(String::as_str).collect::<Vec<_>>(), order)
+        .collect()?;
+
+    let key_columns = keys.iter().map(|k| df.column(k)?.str().cloned()).collect::<PolarsResult<Vec<_>>>()?;
+    let counts = df.column(COUNT)?.idx()?;
+    let first_rows = df.column(FIRST_ROW)?.idx()?;
+    let figures = match aggregate_column {
+        Some(_) => Some((df.column(SUM)?.f64()?, df.column(MEAN)?.f64()?, df.column(NUMERIC)?.idx()?)),
+        None => None,
+    };
+    Ok((0..df.height())
+        .map(|i| {
+            let (sum, mean) = match &figures {
+                Some((sums, means, numeric)) if numeric.get(i).unwrap_or(0) > 0 => (sums.get(i), means.get(i)),
+                _ => (None, None),
+            };
+            GroupRow {
+                path: key_columns.iter().map(|c| c.get(i).map(str::to_string)).collect(),
+                count: counts.get(i).unwrap_or(0) as usize,
+                first_row: first_rows.get(i).unwrap_or(0) as usize,
+                sum,
+                mean,
+            }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_plan() -> LazyFrame {
+        df! {
+            "host" => [Some("a"), Some("b"), Some("a"), Some("a"), None],
+            "user" => ["x", "y", "y", "x", "y"],
+            "bytes" => [Some(10i64), Some(20), Some(30), Some(40), None],
+        }
+        .unwrap()
+        .lazy()
+    }
+
+    #[test]
+    fn test_groups_one_level_at_a_time() {
+        let columns = vec!["host".to_string(), "user".to_string()];
+        let top = group_children(sample_plan(), &columns, &[], Some("bytes")).unwrap();
+        let keys: Vec<&str> = top.iter().map(GroupRow::key).collect();
+        assert_eq!(keys, ["a", "b", NULL_KEY]);
+        assert_eq!((top[0].count, top[0].first_row, top[0].sum, top[0].mean), (3, 0, Some(80.0), Some(80.0 / 3.0)));
+        assert_eq!((top[2].count, top[2].first_row, top[2].sum), (1, 4, None));
+
+        let under_a = group_children(sample_plan(), &columns, &top[0].path, Some("bytes")).unwrap();
+        let paths: Vec<_> = under_a.iter().map(|g| (g.key(), g.count, g.first_row)).collect();
+        assert_eq!(paths, [("x", 2, 0), ("y", 1, 2)]);
+        assert_eq!(under_a[1].path, [Some("a".to_string()), Some("y".to_string())]);
+
+        let under_null = group_children(sample_plan(), &columns, &top[2].path, None).unwrap();
+        assert_eq!(under_null.len(), 1);
+        assert_eq!(under_null[0].first_row, 4);
+
+        assert_eq!(group_level(sample_plan(), &columns, 1, None).unwrap().len(), 4);
+    }
+}
@@ -0,0 +1,70 @@
+//! Hex digests of cell values for the hashing column operation.
+
+use md5::Md5;
+use serde::{Deserialize, Serialize};
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+/// Digest algorithm used by the hashing column operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum HashAlgorithm {
+    Md5,
+    Sha1,
+    #[default]
+    Sha256,
+}
+
+impl HashAlgorithm {
+    pub const ALL: [HashAlgorithm; 3] = [HashAlgorithm::Md5, HashAlgorithm::Sha1, HashAlgorithm::Sha256];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            HashAlgorithm::Md5 => "MD5",
+            HashAlgorithm::Sha1 => "SHA1",
+            HashAlgorithm::Sha256 => "SHA256",
+        }
+    }
+
+    /// Cycle to the next (or previous) algorithm
+    pub fn cycle(&self, forward: bool) -> HashAlgorithm {
+        let pos = Self::ALL.iter().position(|a| a == self).unwrap_or(0);
+        let len = Self::ALL.len();
+        Self::ALL[if forward { (pos + 1) % len } else { (pos + len - 1) % len }]
+    }
+
+    /// Lowercase hex digest of `bytes`
+    pub fn hex_digest(&self, bytes: &[u8]) -> String {
+        let digest: Vec<u8> = match self {
+            HashAlgorithm::Md5 => Md5::digest(bytes).to_vec(),
+            HashAlgorithm::Sha1 => Sha1::digest(bytes).to_vec(),
+            HashAlgorithm::Sha256 => Sha256::digest(bytes).to_vec(),
+        };
+        digest.iter().map(|b| format!("{b:02x}")).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_digests() {
+        assert_eq!(HashAlgorithm::Md5.hex_digest(b""), "d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(HashAlgorithm::Md5.hex_digest(b"abc"), "900150983cd24fb0d6963f7d28e17f72");
+        assert_eq!(
+            HashAlgorithm::Md5.hex_digest(b"12345678901234567890123456789012345678901234567890123456789012345678901234567890"),
+            "57edf4a22be3c955ac49da2e2107b67a"
+        );
+        assert_eq!(HashAlgorithm::Sha1.hex_digest(b"abc"), "a9993e364706816aba3e25717850c26c9cd0d89d");
+        assert_eq!(
+            HashAlgorithm::Sha256.hex_digest(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_cycle_wraps() {
+        assert_eq!(HashAlgorithm::Sha256.cycle(true), HashAlgorithm::Md5);
+        assert_eq!(HashAlgorithm::Md5.cycle(false), HashAlgorithm::Sha256);
+    }
+}
@@ -0,0 +1,92 @@
+//! Bucketed counts behind the chart dialog: numeric histograms and value-count bars.
+
+use std::collections::HashMap;
+
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use polars::prelude::*;
+
+/// One bar of a chart: its label and how many rows fall into it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChartBar {
+    pub label: String,
+    pub count: u64,
+}
+
+fn fmt_edge(v: f64) -> String {
+    let s = format!("{v:.3}");
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+    if s == "-0" { "0".to_string() } else { s.to_string() }
+}
+
+/// Equal-width histogram of the finite values of a numeric column.
+/// The last bucket includes the maximum; a constant column yields a single bucket.
+pub fn histogram(column: &Column, buckets: usize) -> Result<Vec<ChartBar>> {
+    if !column.dtype().is_primitive_numeric() {
+        return Err(eyre!("Histogram needs a numeric column, '{}' is {}", column.name(), column.dtype()));
+    }
+    let floats = column.cast(&DataType::Float64).map_err(|e| eyre!("{}", e))?;
+    let values: Vec<f64> = floats
+        .f64()
+        .map_err(|e| eyre!("{}", e))?
+        .into_iter()
+        .flatten()
+        .filter(|v| v.is_finite())
+        .collect();
+    if values.is_empty() {
+        return Ok(Vec::new());
+    }
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let buckets = if min == max { 1 } else { buckets.max(1) };
+    let width = (max - min) / buckets as f64;
+    let mut counts = vec![0u64; buckets];
+    for v in &values {
+        let idx = if width > 0.0 { ((v - min) / width) as usize } else { 0 };
+        counts[idx.min(buckets - 1)] += 1;
+    }
+    Ok(counts
+        .into_iter()
+        .enumerate()
+        .map(|(i, count)| {
+            let lo = min + width * i as f64;
+            let hi = if i + 1 == buckets { max } else { min + width * (i + 1) as f64 };
+            let close = if i + 1 == buckets { "]" } else { ")" };
+            ChartBar { label: format!("[{}, {}{close}", fmt_edge(lo), fmt_edge(hi)), count }
+        })
+        .collect())
+}
+
+/// The `limit` most frequent values (compared as text), most frequent first; nulls are skipped
+pub fn value_counts(column: &Column, limit: usize) -> Result<Vec<ChartBar>> {
+    let text = column.cast(&DataType::String).map_err(|e| eyre!("{}", e))?;
+    let mut counts: HashMap<&str, u64> = HashMap::new();
+    for v in text.str().map_err(|e| eyre!("{}", e))?.into_iter().flatten() {
+        *counts.entry(v).or_insert(0) += 1;
+    }
+    let mut bars: Vec<ChartBar> = counts.into_iter().map(|(v, count)| ChartBar { label: v.to_string(), count }).collect();
+    bars.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.label.cmp(&b.label)));
+    bars.truncate(limit);
+    Ok(bars)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histogram_and_value_counts() {
+        let n = Column::new("n".into(), [Some(0.0f64), Some(1.0), Some(2.5), Some(4.0), None, Some(f64::NAN)]);
+        let bars = histogram(&n, 4).unwrap();
+        let counts: Vec<u64> = bars.iter().map(|b| b.count).collect();
+        assert_eq!(counts, [1, 1, 1, 1]);
+        assert_eq!(bars[0].label, "[0, 1)");
+        assert_eq!(bars[3].label, "[3, 4]");
+        assert_eq!(histogram(&Column::new("c".into(), [5i64, 5]), 10).unwrap().len(), 1);
+
+        let s = Column::new("s".into(), ["b", "a", "b", "c", "b", "a"]);
+        assert!(histogram(&s, 4).is_err());
+        let bars = value_counts(&s, 2).unwrap();
+        assert_eq!(bars, [ChartBar { label: "b".to_string(), count: 3 }, ChartBar { label: "a".to_string(), count: 2 }]);
+    }
+}
@@ -0,0 +1,127 @@
+//! Key → value enrichment from another loaded dataset (VLOOKUP-style).
+
+use std::collections::HashMap;
+
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use polars::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// User-facing options for the lookup column operation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct LookupOptions {
+    /// Name (or alias) of the dataset holding the mapping
+    pub dataset: String,
+    /// Column of that dataset matched against the source column
+    pub key_column: String,
+    /// Comma-separated columns to append; empty appends every column except the key
+    pub value_columns: String,
+}
+
+impl LookupOptions {
+    /// Columns to append from `lookup`, in the order given
+    pub fn value_column_names(&self, lookup: &DataFrame) -> Result<Vec<String>> {
+        let requested: Vec<String> = self
+            .value_columns
+            .split(',')
+            .map(str::trim)
+            .filter(|c| !c.is_empty())
+            .map(str::to_string)
+            .collect();
+        if requested.is_empty() {
+            return Ok(lookup
+                .get_column_names_owned()
+                .into_iter()
+                .map(|c| c.to_string())
+                .filter(|c| c != &self.key_column)
+                .collect());
+        }
+        for name in &requested {
+            if lookup.column(name).is_err() {
+                return Err(eyre!("Column '{}' not found in '{}'", name, self.dataset));
+            }
+        }
+        Ok(requested)
+    }
+}
+
+/// Keys compared as text so e.g. an Int64 user_id matches a String one
+fn keys_as_text(column: &Column) -> Result<StringChunked> {
+    let cast = column.cast(&DataType::String).map_err(|e| eyre!("{}", e))?;
+    Ok(cast.str().map_err(|e| eyre!("{}", e))?.clone())
+}
+
+/// Append the looked-up columns of `lookup` to `df`, matching `df[source_column]` against
+/// `lookup[options.key_column]`. Rows without a match get nulls; duplicate keys use the first row.
+/// Appended columns are named `<prefix>_<column>` when a prefix is given.
+pub fn lookup_columns(df: &DataFrame, source_column: &str, lookup: &DataFrame, options: &LookupOptions, prefix: &str) -> Result<DataFrame> {
+    let source = df.column(source_column).map_err(|e| eyre!("{}", e))?;
+    let key = lookup
+        .column(&options.key_column)
+        .map_err(|_| eyre!("Key column '{}' not found in '{}'", options.key_column, options.dataset))?;
+    let value_columns = options.value_column_names(lookup)?;
+    if value_columns.is_empty() {
+        return Err(eyre!("'{}' has no columns to look up besides the key", options.dataset));
+    }
+
+    let keys = keys_as_text(key)?;
+    let mut index: HashMap<&str, IdxSize> = HashMap::new();
+    for (row, k) in keys.into_iter().enumerate() {
+        if let Some(k) = k {
+            index.entry(k).or_insert(row as IdxSize);
+        }
+    }
+    let rows: Vec<Option<IdxSize>> = keys_as_text(source)?
+        .into_iter()
+        .map(|v| v.and_then(|v| index.get(v).copied()))
+        .collect();
+    let rows = IdxCa::from_iter_options(PlSmallStr::EMPTY, rows.into_iter());
+
+    let found = lookup
+        .select(value_columns.iter().map(|c| PlSmallStr::from_str(c)))
+        .and_then(|selected| selected.take(&rows))
+        .map_err(|e| eyre!("{}", e))?;
+    let existing = df.get_column_names_owned();
+    let mut cols: Vec<Column> = df.get_columns().to_vec();
+    for column in found.get_columns() {
+        let mut name = if prefix.trim().is_empty() { column.name().to_string() } else { format!("{}_{}", prefix.trim(), column.name()) };
+        if existing.iter().any(|n| n.as_str() == name) { name = format!("{name}__lookup"); }
+        cols.push(column.clone().with_name(PlSmallStr::from_str(&name)));
+    }
+    DataFrame::new(cols).map_err(|e| eyre!("Failed to build DataFrame: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_matches_across_key_types() {
+        let df = df!("user_id" => [Some(2i64), Some(1), Some(9), None]).unwrap();
+        let users = df!(
+            "id" => ["1", "2", "2"],
+            "display_name" => ["alice", "bob", "bobby"],
+            "team" => ["ir", "soc", "soc"]
+        )
+        .unwrap();
+        let options = LookupOptions { dataset: "users".to_string(), key_column: "id".to_string(), value_columns: String::new() };
+        let out = lookup_columns(&df, "user_id", &users, &options, "").unwrap();
+        let names: Vec<String> = out.get_column_names_owned().into_iter().map(|c| c.to_string()).collect();
+        assert_eq!(names, ["user_id", "display_name", "team"]);
+        let display: Vec<Option<&str>> = out.column("display_name").unwrap().str().unwrap().into_iter().collect();
+        assert_eq!(display, [Some("bob"), Some("alice"), None, None]);
+    }
+
+    #[test]
+    fn test_selected_columns_and_prefix() {
+        let df = df!("ip" => ["10.0.0.1"], "owner" => ["x"]).unwrap();
+        let assets = df!("ip" => ["10.0.0.1"], "owner" => ["ops"], "site" => ["hq"]).unwrap();
+        let options = LookupOptions { dataset: "assets".to_string(), key_column: "ip".to_string(), value_columns: "owner".to_string() };
+        let out = lookup_columns(&df, "ip", &assets, &options, "").unwrap();
+        assert_eq!(out.column("owner__lookup").unwrap().str().unwrap().get(0), Some("ops"));
+        let out = lookup_columns(&df, "ip", &assets, &options, "asset").unwrap();
+        assert!(out.column("asset_owner").is_ok());
+        let missing = LookupOptions { value_columns: "nope".to_string(), ..options };
+        assert!(lookup_columns(&df, "ip", &assets, &missing, "").is_err());
+    }
+}
@@ -10,6 +10,15 @@ use std::sync::Arc;
 use crate::dialog::sort_dialog::SortColumn;
 use crate::dialog::filter_dialog::FilterExpr;
 use crate::dialog::column_width_dialog::ColumnWidthConfig;
+use crate::dialog::heatmap_dialog::HeatmapConfig;
+use crate::dialog::display_settings_dialog::DisplaySettings;
+use crate::dataframe::checkpoints::Checkpoint;
+use crate::dataframe::evidence::FileHash;
+use crate::dataframe::find_index::{FindIndex, IndexedColumn};
+use crate::dataframe::row_tags::RowTags;
+use crate::dataframe::validation::{CellValidator, ValidationRule};
+use crate::dataframe::spill::{spill_to_parquet, SpillFile};
+use crate::dataframe::transform_history::{TransformHistory, TransformStep};
 
 /// Metadata for a managed DataFrame.
 #[derive(Debug, Clone)]
@@ -19,20 +28,83 @@ pub struct DataFrameMetadata {
     pub source_path: Option<PathBuf>,
     pub creation_time: DateTime<Utc>,
     pub last_modified: DateTime<Utc>,
+    /// SHA-256 of the source files recorded at import
+    pub file_hashes: Vec<FileHash>,
+    /// Analyst notes on the dataset
+    pub notes: Option<String>,
+}
+
+/// Rows collected on either side of the requested window of a lazy view
+pub const WINDOW_MARGIN: usize = 200;
+
+/// A run of collected rows of the current view, starting at row `offset`
+#[derive(Debug, Clone)]
+pub struct ViewWindow {
+    pub offset: usize,
+    pub df: Arc<DataFrame>,
+}
+
+impl ViewWindow {
+    /// Whether rows `offset..offset + len` are all in the window
+    pub fn covers(&self, offset: usize, len: usize) -> bool {
+        offset >= self.offset && offset + len <= self.offset + self.df.height()
+    }
+
+    /// Value at view row `row`; Null outside the window
+    pub fn get(&self, column: &str, row: usize) -> AnyValue<'_> {
+        row.checked_sub(self.offset)
+            .and_then(|i| self.df.column(column).ok()?.get(i).ok())
+            .unwrap_or(AnyValue::Null)
+    }
+}
+
+/// What is known about a lazy view without collecting it in full
+#[derive(Debug, Clone, Default)]
+struct ViewCache {
+    height: Option<usize>,
+    schema: Option<SchemaRef>,
+    window: Option<ViewWindow>,
+    /// Columns the cached window was collected with; None for all of them
+    window_columns: Option<Vec<String>>,
+    bounds: std::collections::HashMap<String, Option<(f64, f64)>>,
+    group_starts: std::collections::HashMap<String, Option<Arc<Vec<usize>>>>,
+    find_index: FindIndex,
 }
 
 /// A managed DataFrame with metadata and state.
+///
+/// The current view is either materialized in `current_df`, or a lazy plan in `view` (the base
+/// scan plus sort, filter and column selection) of which only the rows on screen are collected.
 #[derive(Clone)]
 pub struct ManagedDataFrame {
     /// Base dataset as a lazy query plan
     pub df: LazyFrame,
+    /// Estimated in-memory size of the base dataset
+    pub base_size: usize,
     /// Materialized view for display; None means not yet collected
     pub current_df: Option<Arc<DataFrame>>,
+    /// Lazy plan of the current view when it has not been materialized
+    pub view: Option<LazyFrame>,
+    view_cache: ViewCache,
+    /// Temporary Parquet files the view scans; kept until the view no longer reads them
+    pub spill_files: Vec<Arc<SpillFile>>,
     pub metadata: DataFrameMetadata,
     pub last_sort: Option<Vec<SortColumn>>,
     pub filter: Option<FilterExpr>,
     pub last_sql_query: Option<String>,
     pub column_width_config: ColumnWidthConfig,
+    pub heatmap_config: HeatmapConfig,
+    pub display_settings: DisplaySettings,
+    /// Transforms applied to this dataset, in order
+    pub history: TransformHistory,
+    /// Named snapshots of intermediate views
+    pub checkpoints: Vec<Checkpoint>,
+    /// Analyst labels and notes on rows
+    pub row_tags: RowTags,
+    /// Per-column validation rules
+    pub validation_rules: Vec<ValidationRule>,
+    /// Rules compiled by the last validation run, used to highlight failing cells
+    pub validator: Option<Arc<CellValidator>>,
 }
 
 impl ManagedDataFrame {
@@ -40,6 +112,183 @@ impl ManagedDataFrame {
     /// Reset the current DataFrame to the base lazy frame
     pub fn reset_current_df(&mut self) {
         self.current_df = None;
+        self.view = None;
+        self.view_cache = ViewCache::default();
+        self.spill_files.clear();
+    }
+
+    /// Lazy plan producing the current view
+    pub fn view_plan(&self) -> LazyFrame {
+        match (&self.current_df, &self.view) {
+            (Some(df), _) => df.as_ref().clone().lazy(),
+            (None, Some(view)) => view.clone(),
+            (None, None) => self.df.clone(),
+        }
+    }
+
+    /// Make `plan` the current view; it is collected only as far as it is displayed
+    pub fn set_view(&mut self, plan: LazyFrame) {
+        self.current_df = None;
+        self.view = Some(plan);
+        self.view_cache = ViewCache::default();
+    }
+
+    /// Whether the view differs from the base data, materialized or not
+    pub fn has_view(&self) -> bool {
+        self.current_df.is_some() || self.view.is_some()
+    }
+
+    fn view_schema(&mut self) -> color_eyre::Result<SchemaRef> {
+        if let Some(df) = &self.current_df {
+            return Ok(df.schema().clone());
+        }
+        if let Some(schema) = &self.view_cache.schema {
+            return Ok(schema.clone());
+        }
+        let schema = self.view_plan().collect_schema()?;
+        self.view_cache.schema = Some(schema.clone());
+        Ok(schema)
+    }
+
+    /// Column names of the current view
+    pub fn view_columns(&mut self) -> color_eyre::Result<Vec<String>> {
+        Ok(self.view_schema()?.iter_names().map(|n| n.to_string()).collect())
+    }
+
+    /// Number of rows in the current view; a lazy view is counted once and remembered
+    pub fn view_height(&mut self) -> color_eyre::Result<usize> {
+        if let Some(df) = &self.current_df {
+            return Ok(df.height());
+        }
+        if let Some(height) = self.view_cache.height {
+            return Ok(height);
+        }
+        let height = count_rows(self.view_plan())?;
+        self.view_cache.height = Some(height);
+        Ok(height)
+    }
+
+    /// Rows `offset..offset + len` of the current view, without collecting the rest of it
+    pub fn collect_rows(&self, offset: usize, len: usize) -> color_eyre::Result<DataFrame> {
+        if let Some(df) = &self.current_df {
+            return Ok(df.slice(offset as i64, len));
+        }
+        self.view_plan()
+            .slice(offset as i64, len as IdxSize)
+            .collect()
+            .map_err(|e| color_eyre::eyre::eyre!("Collect error: {}", e))
+    }
+
+    /// Rows `offset..offset + len` of the current view. A lazy view is collected with
+    /// `WINDOW_MARGIN` extra rows on each side, so scrolling nearby reuses the same window.
+    pub fn window(&mut self, offset: usize, len: usize) -> color_eyre::Result<ViewWindow> {
+        self.projected_window(offset, len, None)
+    }
+
+    /// Like `window`, but only `columns` are collected (all of them when None). The projection
+    /// is part of the plan, so a lazy view does not read or compute the other columns.
+    pub fn projected_window(&mut self, offset: usize, len: usize, columns: Option<&[String]>) -> color_eyre::Result<ViewWindow> {
+        if let Some(df) = &self.current_df {
+            let df = df.slice(offset as i64, len);
+            let df = match columns {
+                Some(columns) => df.select(columns.iter().map(String::as_str))?,
+                None => df,
+            };
+            return Ok(ViewWindow { offset, df: Arc::new(df) });
+        }
+        let len = len.min(self.view_height()?.saturating_sub(offset));
+        let projection = columns.map(<[String]>::to_vec);
+        if let Some(window) = &self.view_cache.window
+            && window.covers(offset, len)
+            && self.view_cache.window_columns == projection
+        {
+            return Ok(window.clone());
+        }
+        let start = offset.saturating_sub(WINDOW_MARGIN);
+        let plan = match columns {
+            Some(columns) => self.view_plan().select(columns.iter().map(|c| col(c.as_str())).collect::<Vec<_>>()),
+            None => self.view_plan(),
+        };
+        let df = plan
+            .slice(start as i64, (offset - start + len + WINDOW_MARGIN) as IdxSize)
+            .collect()
+            .map_err(|e| color_eyre::eyre::eyre!("Collect error: {}", e))?;
+        let window = ViewWindow { offset: start, df: Arc::new(df) };
+        self.view_cache.window = Some(window.clone());
+        self.view_cache.window_columns = projection;
+        Ok(window)
+    }
+
+    /// Bounds of `column` over the whole view, computed by `compute`. For a lazy view only that
+    /// column is collected, and the result is kept until the view changes.
+    pub fn column_bounds(
+        &mut self,
+        column: &str,
+        compute: impl Fn(&Column) -> Option<(f64, f64)>,
+    ) -> Option<(f64, f64)> {
+        if let Some(df) = &self.current_df {
+            return df.column(column).ok().and_then(compute);
+        }
+        if let Some(bounds) = self.view_cache.bounds.get(column) {
+            return *bounds;
+        }
+        let bounds = self
+            .view_plan()
+            .select([col(column)])
+            .collect()
+            .ok()
+            .and_then(|df| df.column(column).ok().and_then(&compute));
+        self.view_cache.bounds.insert(column.to_string(), bounds);
+        bounds
+    }
+
+    /// View rows where `column` takes a different value than on the row before, starting with
+    /// row 0. Only that column is collected, and the result is kept until the view changes.
+    pub fn group_starts(&mut self, column: &str) -> Option<Arc<Vec<usize>>> {
+        if let Some(starts) = self.view_cache.group_starts.get(column) {
+            return starts.clone();
+        }
+        let starts = self
+            .view_plan()
+            .select([col(column).neq_missing(col(column).shift(lit(1))).alias("changed")])
+            .with_row_index("row", None)
+            .filter(col("changed").or(col("row").eq(lit(0))))
+            .select([col("row")])
+            .collect()
+            .ok()
+            .and_then(|df| {
+                let rows = df.column("row").ok()?.idx().ok()?.into_no_null_iter().map(|r| r as usize).collect();
+                Some(Arc::new(rows))
+            });
+        self.view_cache.group_starts.insert(column.to_string(), starts.clone());
+        starts
+    }
+
+    /// Find's text index of `columns`, in that order. Columns not indexed since the view last
+    /// changed are collected (only those columns) and indexed first.
+    pub fn find_index(&mut self, columns: &[String]) -> color_eyre::Result<Vec<Arc<IndexedColumn>>> {
+        let missing = self.view_cache.find_index.missing(columns);
+        if !missing.is_empty() {
+            let df = match &self.current_df {
+                Some(df) => df.select(missing.iter().map(String::as_str))?,
+                None => self
+                    .view_plan()
+                    .select(missing.iter().map(|c| col(c.as_str())).collect::<Vec<_>>())
+                    .collect()
+                    .map_err(|e| color_eyre::eyre::eyre!("Collect error: {}", e))?,
+            };
+            self.view_cache.find_index.add(&df);
+        }
+        columns
+            .iter()
+            .map(|c| self.view_cache.find_index.get(c).ok_or_else(|| color_eyre::eyre::eyre!("Column '{}' not found in DataFrame", c)))
+            .collect()
+    }
+
+    /// Append a step to the transform history, noting the shape of the current view
+    pub fn record_transform(&mut self, step: TransformStep) {
+        let (rows, columns) = (self.row_count(), self.column_count());
+        self.history.push(step, rows, columns);
     }
 
     /// Set the column width configuration
@@ -47,49 +296,32 @@ impl ManagedDataFrame {
         self.column_width_config = config;
     }
 
-    /// Returns the number of rows in the DataFrame.
+    /// Returns the number of rows in the current view.
     pub fn row_count(&self) -> usize {
         if let Some(df) = self.current_df.as_ref() {
             df.height()
+        } else if let Some(height) = self.view_cache.height {
+            height
         } else {
-            // Fallback: get row count from the lazy frame if not materialized
-            // Note: This will trigger collection, so only use if necessary
-            match self.df.clone().collect() {
-                Ok(df) => df.height(),
-                Err(_) => 0,
-            }
+            // Counting a lazy view runs the query, but does not keep the rows
+            count_rows(self.view_plan()).unwrap_or(0)
         }
     }
-    /// Returns the number of columns in the DataFrame.
+    /// Returns the number of columns in the current view.
     pub fn column_count(&self) -> usize {
-        if let Some(df) = self.current_df.as_ref() {
-            df.width()
-        } else {
-            // Fallback: get column count from the lazy frame if not materialized
-            match self.df.clone().collect() {
-                Ok(df) => df.width(),
-                Err(_) => 0,
-            }
-        }
+        self.column_types().len()
     }
     /// Returns a Vec of (column name, DataType) for all columns.
     pub fn column_types(&self) -> Vec<(String, DataType)> {
-        if let Some(df) = &self.current_df {
-            df.get_columns()
-                .iter()
-                .map(|s| (s.name().to_string(), s.dtype().clone()))
-                .collect()
-        } else {
-            // Fallback: get column types from the lazy frame if not materialized
-            match self.df.clone().collect() {
-                Ok(df) => df
-                    .get_columns()
-                    .iter()
-                    .map(|s| (s.name().to_string(), s.dtype().clone()))
-                    .collect(),
-                Err(_) => Vec::new(),
-            }
-        }
+        let schema = match (&self.current_df, &self.view_cache.schema) {
+            (Some(df), _) => df.schema().clone(),
+            (None, Some(schema)) => schema.clone(),
+            (None, None) => match self.view_plan().collect_schema() {
+                Ok(schema) => schema,
+                Err(_) => return Vec::new(),
+            },
+        };
+        schema.iter().map(|(name, dtype)| (name.to_string(), dtype.clone())).collect()
     }
     
     /// Returns a summary string with row/column count and column types.
@@ -106,16 +338,16 @@ impl ManagedDataFrame {
         s
     }
 
+    /// The whole current view. A lazy view is collected in full on every call; use
+    /// `ensure_current_df` to keep the result, or `window` when only some rows are needed.
     pub fn get_dataframe(&self) ->  color_eyre::Result<Arc<DataFrame>> {
-        if self.current_df.is_none() {
-            // Populate current_df from the base lazy frame
-            let collected = Arc::new(self.collect_base_df()?);
-            // We need to mutate self.dataframe to set current_df
-            // But self is &self, so we can't mutate here.
-            // Instead, return the collected DataFrame directly.
-            return Ok(collected);
+        if let Some(df) = &self.current_df {
+            return Ok(df.clone());
         }
-        Ok(self.current_df.as_ref().unwrap().clone())
+        self.view_plan()
+            .collect()
+            .map(Arc::new)
+            .map_err(|e| color_eyre::eyre::eyre!("Collect error: {}", e))
     }
 
     pub fn new(df: DataFrame, name: String, description: Option<String>, source_path: Option<PathBuf>) -> Self {
@@ -126,22 +358,80 @@ impl ManagedDataFrame {
             source_path,
             creation_time: now,
             last_modified: now,
+            file_hashes: Vec::new(),
+            notes: None,
         };
+        let base_size = df.estimated_size();
         let lazy = df.clone().lazy();
         Self {
             df: lazy,
+            base_size,
             metadata,
             last_sort: None,
             filter: None,
             last_sql_query: None,
             current_df: None,
+            view: None,
+            view_cache: ViewCache::default(),
+            spill_files: Vec::new(),
             column_width_config: ColumnWidthConfig::default(),
+            heatmap_config: HeatmapConfig::default(),
+            display_settings: DisplaySettings::default(),
+            history: TransformHistory::default(),
+            checkpoints: Vec::new(),
+            row_tags: RowTags::default(),
+            validation_rules: Vec::new(),
+            validator: None,
         }
     }
 
     /// Set the current DataFrame
     pub fn set_current_df(&mut self, df: DataFrame) {
-        self.current_df = Some(Arc::new(df));
+        self.set_current_arc(Arc::new(df));
+    }
+
+    /// Set the current DataFrame without copying it
+    pub fn set_current_arc(&mut self, df: Arc<DataFrame>) {
+        self.current_df = Some(df);
+        self.view = None;
+        self.view_cache = ViewCache::default();
+        self.spill_files.clear();
+    }
+
+    /// Show `df` as the current view. When it is larger than `spill_bytes`, its rows are written
+    /// to a temporary Parquet file and the view scans that file instead of holding them.
+    pub fn set_result(&mut self, df: DataFrame, spill_bytes: Option<usize>) -> color_eyre::Result<()> {
+        match spill_bytes {
+            Some(limit) if df.estimated_size() > limit => {
+                let (scan, spill) = spill_to_parquet(df)?;
+                self.set_spilled_view(scan, spill);
+            }
+            _ => self.set_current_df(df),
+        }
+        Ok(())
+    }
+
+    /// Make `scan` of `spill` the current view; the file is kept until the view changes
+    pub fn set_spilled_view(&mut self, scan: LazyFrame, spill: SpillFile) {
+        self.set_view(scan);
+        self.spill_files = vec![Arc::new(spill)];
+    }
+
+    /// Whether a sort, filter or query should stream its result to disk (see `sink_to_parquet`)
+    /// rather than collect it: its input, the base data when `from_base` and the current view
+    /// otherwise, is larger than `spill_bytes` or was itself spilled. Sorting and filtering never
+    /// grow their input, so its size bounds the result's.
+    pub fn spills_result(&self, from_base: bool, spill_bytes: Option<usize>) -> bool {
+        let Some(limit) = spill_bytes else {
+            return false;
+        };
+        if from_base {
+            return self.base_size > limit;
+        }
+        match &self.current_df {
+            Some(df) => df.estimated_size() > limit,
+            None => !self.spill_files.is_empty() || self.base_size > limit,
+        }
     }
     
     /// Create a new ManagedDataFrame from an Arc<DataFrame>
@@ -153,19 +443,44 @@ impl ManagedDataFrame {
             source_path,
             creation_time: now,
             last_modified: now,
+            file_hashes: Vec::new(),
+            notes: None,
         };
         let lazy = df.as_ref().clone().lazy();
         Self {
             df: lazy,
+            base_size: df.estimated_size(),
             metadata,
             last_sort: None,
             filter: None,
             last_sql_query: None,
             current_df: None,
+            view: None,
+            view_cache: ViewCache::default(),
+            spill_files: Vec::new(),
             column_width_config: ColumnWidthConfig::default(),
+            heatmap_config: HeatmapConfig::default(),
+            display_settings: DisplaySettings::default(),
+            history: TransformHistory::default(),
+            checkpoints: Vec::new(),
+            row_tags: RowTags::default(),
+            validation_rules: Vec::new(),
+            validator: None,
         }
     }
 
+    /// Attach the source file hashes recorded at import
+    pub fn with_file_hashes(mut self, file_hashes: Vec<FileHash>) -> Self {
+        self.metadata.file_hashes = file_hashes;
+        self
+    }
+
+    /// Attach the analyst notes kept with the dataset
+    pub fn with_notes(mut self, notes: Option<String>) -> Self {
+        self.metadata.notes = notes;
+        self
+    }
+
     /// Collect the base lazy frame into a DataFrame.
     pub fn collect_base_df(&self) -> color_eyre::Result<DataFrame> {
         self.df
@@ -174,37 +489,32 @@ impl ManagedDataFrame {
             .map_err(|e| color_eyre::eyre::eyre!("Collect error: {}", e))
     }
 
-    /// Ensure `current_df` is populated by collecting from base if needed.
+    /// Ensure `current_df` is populated by collecting the view if needed.
     pub fn ensure_current_df(&mut self) -> color_eyre::Result<Arc<DataFrame>> {
         if let Some(df) = &self.current_df {
             return Ok(df.clone());
         }
-        let collected = Arc::new(self.collect_base_df()?);
-        self.current_df = Some(collected.clone());
+        let collected = self.get_dataframe()?;
+        self.set_current_arc(collected.clone());
         Ok(collected)
     }
 
-    /// Reorder columns in the DataFrame according to the provided column names
-    pub fn reorder_columns(&mut self, column_order: &[String]) -> color_eyre::Result<()> {
-        // Validate that all provided column names exist in the DataFrame
-        let current = self.ensure_current_df()?;
-        let existing_columns: std::collections::HashSet<_> = current
-            .get_column_names()
-            .iter()
-            .map(|s| s.to_string())
-            .collect();
-        
-        for col_name in column_order {
-            if !existing_columns.contains(col_name) {
-                return Err(color_eyre::eyre::eyre!("Column '{}' not found in DataFrame", col_name));
+    /// Error unless every name in `columns` is a column of the view
+    fn check_columns<'a>(&mut self, columns: impl IntoIterator<Item = &'a String>) -> color_eyre::Result<()> {
+        let schema = self.view_schema()?;
+        for name in columns {
+            if !schema.contains(name) {
+                return Err(color_eyre::eyre::eyre!("Column '{}' not found in DataFrame", name));
             }
         }
-        
-        // Select columns in the new order
-        let columns: Vec<String> = column_order.to_vec();
-        let new_df = current.select(&columns)?;
-        self.current_df = Some(Arc::new(new_df));
-        
+        Ok(())
+    }
+
+    /// Reorder columns in the DataFrame according to the provided column names
+    pub fn reorder_columns(&mut self, column_order: &[String]) -> color_eyre::Result<()> {
+        self.check_columns(column_order)?;
+        let exprs: Vec<Expr> = column_order.iter().map(|name| col(name.as_str())).collect();
+        self.set_view(self.view_plan().select(exprs));
         Ok(())
     }
 
@@ -227,7 +537,7 @@ impl ManagedDataFrame {
         }
         let new_df = DataFrame::new(cols)
             .map_err(|e| color_eyre::eyre::eyre!("Rebuild error after cast: {}", e))?;
-        self.current_df = Some(Arc::new(new_df));
+        self.set_current_df(new_df);
         Ok(())
     }
 }
@@ -243,7 +553,10 @@ impl fmt::Debug for ManagedDataFrame {
             .field("last_sort", &self.last_sort)
             .field("filter", &self.filter)
             .field("column_width_config", &self.column_width_config)
+            .field("heatmap_config", &self.heatmap_config)
+            .field("display_settings", &self.display_settings)
             .field("current_shape", &current_shape)
+            .field("lazy_view", &self.view.is_some())
             .finish()
     }
 }
@@ -294,20 +607,176 @@ impl SortableDataFrame for ManagedDataFrame {
         if columns.is_empty() {
             return Ok(());
         }
+        let plan = self.sorted_plan(columns)?;
+        self.set_view(plan);
+        self.last_sort = Some(columns.to_vec());
+        Ok(())
+    }
+}
+
+impl ManagedDataFrame {
+    /// Plan sorting the current view by `columns`, without changing the view
+    pub fn sorted_plan(&mut self, columns: &[SortColumn]) -> color_eyre::Result<LazyFrame> {
         let by: Vec<String> = columns.iter().map(|c| c.name.clone()).collect();
         let reverse: Vec<bool> = columns.iter().map(|c: &SortColumn| !c.ascending).collect();
-        let nulls_last: Vec<bool> = columns.iter().map(|c: &SortColumn| !c.ascending).collect();
+        let nulls_last: Vec<bool> = columns.iter().map(|c: &SortColumn| c.nulls.nulls_last(c.ascending)).collect();
         let options = SortMultipleOptions::default()
             .with_order_descending_multi(reverse)
             .with_nulls_last_multi(nulls_last);
-        let current = self.ensure_current_df()?;
-        let sorted = current.sort(&by, options)?;
-        self.current_df = Some(Arc::new(sorted));
-        self.last_sort = Some(columns.to_vec());
+        self.check_columns(&by)?;
+        let schema = self.view_schema()?;
+        let exprs: Vec<Expr> = columns
+            .iter()
+            .map(|c| {
+                let is_text = matches!(schema.get(&c.name), Some(DataType::String | DataType::Categorical(..)));
+                sort_key_expr(c, is_text)
+            })
+            .collect();
+        Ok(self.view_plan().sort_by_exprs(exprs, options))
+    }
+
+    /// Check `filter` against a sample row so unsupported conditions are reported before any
+    /// rows are filtered
+    pub fn check_filter(&self, filter: &FilterExpr) -> color_eyre::Result<()> {
+        let sample = self.df.clone().limit(1).collect()?;
+        filter.create_mask(&sample)?;
         Ok(())
     }
 }
 
+/// Rows per chunk when filtering with progress reports
+const FILTER_CHUNK_ROWS: usize = 250_000;
+
+/// Plan keeping the rows of `base` that match `filter`. Conditions only look at their own row,
+/// so the mask is computed batch by batch when the plan runs on the streaming engine.
+pub fn filtered_plan(base: LazyFrame, filter: FilterExpr) -> LazyFrame {
+    base.map(
+        move |df| {
+            let mask = filter
+                .create_mask(&df)
+                .map_err(|e| polars_err!(ComputeError: "{}", e))?;
+            df.filter(&mask)
+        },
+        AllowedOptimizations::default() | OptFlags::NEW_STREAMING,
+        None,
+        Some("filter"),
+    )
+}
+
+/// Collect `base` and keep the rows matching `filter`, one chunk at a time. `on_progress` is
+/// given the fraction done after each chunk and returns false to stop.
+pub fn filter_with_progress(
+    base: LazyFrame,
+    filter: &FilterExpr,
+    mut on_progress: impl FnMut(f64) -> bool,
+) -> color_eyre::Result<DataFrame> {
+    let df = base.collect()?;
+    let height = df.height();
+    let mut out = df.clear();
+    let mut offset = 0;
+    while offset < height {
+        let chunk = df.slice(offset as i64, FILTER_CHUNK_ROWS);
+        let mask = filter.create_mask(&chunk)?;
+        out.vstack_mut(&chunk.filter(&mask)?)?;
+        offset += chunk.height();
+        if !on_progress(offset as f64 / height as f64) {
+            return Err(color_eyre::eyre::eyre!("Cancelled"));
+        }
+    }
+    out.align_chunks();
+    Ok(out)
+}
+
+/// `df` with `column` nulled on the rows that do not match `filter`, so a per-row column
+/// operation only computes the matching rows. Returns the rows and the column as it was.
+pub fn null_outside_filter(mut df: DataFrame, column: &str, filter: &FilterExpr) -> color_eyre::Result<(DataFrame, Column)> {
+    let original = df
+        .column(column)
+        .map_err(|_| color_eyre::eyre::eyre!("'{}' is not a column of the table", column))?
+        .clone();
+    let keep = filter.create_mask(&df)?;
+    let nulls = Series::full_null(original.name().clone(), original.len(), original.dtype());
+    df.replace(column, original.as_materialized_series().zip_with(&keep, &nulls)?)?;
+    Ok((df, original))
+}
+
+/// Key that orders text naturally: runs of digits are zero-padded so they compare by value
+pub fn natural_sort_key(text: &str, case_insensitive: bool) -> String {
+    const WIDTH: usize = 20;
+    let mut key = String::with_capacity(text.len() + WIDTH);
+    let mut digits = String::new();
+    let flush = |digits: &mut String, key: &mut String| {
+        if !digits.is_empty() {
+            let trimmed = digits.trim_start_matches('0');
+            key.extend(std::iter::repeat_n('0', WIDTH.saturating_sub(trimmed.len())));
+            key.push_str(trimmed);
+            digits.clear();
+        }
+    };
+    for ch in text.chars() {
+        if ch.is_ascii_digit() {
+            digits.push(ch);
+            continue;
+        }
+        flush(&mut digits, &mut key);
+        if case_insensitive {
+            key.extend(ch.to_lowercase());
+        } else {
+            key.push(ch);
+        }
+    }
+    flush(&mut digits, &mut key);
+    key
+}
+
+/// Expression a column is sorted by; natural and case-insensitive ordering apply to text only
+fn sort_key_expr(column: &SortColumn, is_text: bool) -> Expr {
+    let expr = col(column.name.as_str());
+    if !is_text {
+        return expr;
+    }
+    if column.natural {
+        let case_insensitive = column.case_insensitive;
+        return expr.cast(DataType::String).map(
+            move |c: Column| {
+                let keys: StringChunked = c
+                    .str()?
+                    .into_iter()
+                    .map(|v| v.map(|v| natural_sort_key(v, case_insensitive)))
+                    .collect();
+                Ok(Some(keys.with_name(c.name().clone()).into_column()))
+            },
+            GetOutput::from_type(DataType::String),
+        );
+    }
+    if column.case_insensitive {
+        return expr.cast(DataType::String).str().to_lowercase();
+    }
+    expr
+}
+
+/// Sort columns after a quick sort on `column`: ascending, then descending, then no longer
+/// sorted by it. Without `append` the column replaces the current sort; with it the column is
+/// added to (or toggled within) the current multi-column sort.
+pub fn next_quick_sort(current: &[SortColumn], column: &str, append: bool) -> Vec<SortColumn> {
+    let existing = current.iter().position(|sc| sc.name == column);
+    let mut columns: Vec<SortColumn> = if append {
+        current.to_vec()
+    } else {
+        existing.map(|i| vec![current[i].clone()]).unwrap_or_default()
+    };
+    match columns.iter().position(|sc| sc.name == column) {
+        // Alone in a multi-column sort counts as a fresh single-column sort
+        Some(_) if !append && current.len() > 1 => columns[0].ascending = true,
+        Some(i) if columns[i].ascending => columns[i].ascending = false,
+        Some(i) => {
+            columns.remove(i);
+        }
+        None => columns.push(SortColumn::new(column, true)),
+    }
+    columns
+}
+
 impl ManagedDataFrame {
     /// Toggle sorting for a single column. First press sorts ascending.
     /// Pressing again on the same column reverses the direction.
@@ -318,15 +787,14 @@ impl ManagedDataFrame {
             && last.len() == 1 && last[0].name == col_name {
             ascending = !last[0].ascending;
         }
-        let source: Arc<DataFrame> = self.ensure_current_df()?;
         let by = vec![col_name.clone()];
+        self.check_columns(&by)?;
         let reverse = vec![!ascending];
         let options = SortMultipleOptions::default()
             .with_order_descending_multi(reverse.clone())
             .with_nulls_last_multi(reverse);
-        let sorted = source.sort(&by, options)?;
-        self.current_df = Some(Arc::new(sorted));
-        self.last_sort = Some(vec![SortColumn { name: col_name, ascending }]);
+        self.set_view(self.view_plan().sort(by, options));
+        self.last_sort = Some(vec![SortColumn::new(col_name, ascending)]);
         Ok(())
     }
 }
@@ -337,28 +805,31 @@ pub trait FilterableDataFrame {
 }
 
 impl FilterableDataFrame for ManagedDataFrame {
+    /// Filter the base data. The mask is evaluated when the view is collected; a sample row is
+    /// checked up front so that unsupported conditions are reported here.
     fn apply_filter(&mut self, filter: FilterExpr) -> color_eyre::Result<()> {
-        let base_df = self.collect_base_df()?;
-        let mask = filter.create_mask(&base_df)?;
-        let new_df = base_df.filter(&mask)?;
-        self.current_df = Some(Arc::new(new_df));
+        self.check_filter(&filter)?;
+        self.set_view(filtered_plan(self.df.clone(), filter.clone()));
+        self.filter = Some(filter);
         Ok(())
     }
     fn clear_filter(&mut self) {
-        match self.collect_base_df() {
-            Ok(df) => {
-                self.current_df = Some(Arc::new(df));
-                self.filter = None;
-            }
-            Err(_) => {
-                // If collect fails, just clear to None
-                self.current_df = None;
-                self.filter = None;
-            }
-        }
+        self.reset_current_df();
+        self.filter = None;
     }
 }
 
+/// Row count of a lazy plan, without keeping its rows
+fn count_rows(plan: LazyFrame) -> color_eyre::Result<usize> {
+    let counted = plan.select([len()]).collect()?;
+    Ok(counted
+        .get_columns()
+        .first()
+        .and_then(|c| c.get(0).ok())
+        .and_then(|v| v.extract::<usize>())
+        .unwrap_or(0))
+}
+
 /// Concrete implementation of DataFrameManager using a BTreeMap.
 pub struct DataFrameManagerImpl {
     dataframes: BTreeMap<usize, ManagedDataFrame>,
@@ -392,15 +863,28 @@ impl DataFrameManager for DataFrameManagerImpl {
             source_path: source,
             creation_time: now,
             last_modified: now,
+            file_hashes: Vec::new(),
+            notes: None,
         };
         let managed = ManagedDataFrame {
             df: df.clone().lazy(),
+            base_size: df.estimated_size(),
             metadata,
             last_sort: None,
             filter: None,
             last_sql_query: None,
             current_df: Some(Arc::new(df)),
+            view: None,
+            view_cache: ViewCache::default(),
+            spill_files: Vec::new(),
             column_width_config: ColumnWidthConfig::default(),
+            heatmap_config: HeatmapConfig::default(),
+            display_settings: DisplaySettings::default(),
+            history: TransformHistory::default(),
+            checkpoints: Vec::new(),
+            row_tags: RowTags::default(),
+            validation_rules: Vec::new(),
+            validator: None,
         };
         self.dataframes.insert(id, managed);
         id
@@ -465,6 +949,55 @@ mod tests {
         assert!(df_str.contains("Rows: 3"));
     }
 
+    #[test]
+    fn test_next_quick_sort() {
+        let sc = |name: &str, ascending: bool| SortColumn::new(name, ascending);
+        let asc = next_quick_sort(&[], "a", false);
+        assert_eq!(asc, [sc("a", true)]);
+        let desc = next_quick_sort(&asc, "a", false);
+        assert_eq!(desc, [sc("a", false)]);
+        assert!(next_quick_sort(&desc, "a", false).is_empty());
+        assert_eq!(next_quick_sort(&desc, "b", false), [sc("b", true)]);
+
+        let multi = next_quick_sort(&desc, "b", true);
+        assert_eq!(multi, [sc("a", false), sc("b", true)]);
+        assert_eq!(next_quick_sort(&multi, "b", true), [sc("a", false), sc("b", false)]);
+        assert_eq!(next_quick_sort(&multi, "a", true), [sc("b", true)]);
+        assert_eq!(next_quick_sort(&multi, "b", false), [sc("b", true)]);
+    }
+
+    #[test]
+    fn test_sort_options() {
+        use crate::dialog::sort_dialog::NullsOrder;
+        assert_eq!(natural_sort_key("File007b", true), format!("file{}7b", "0".repeat(19)));
+        let df = df!("f" => [Some("file10"), None, Some("File2"), Some("file1")]).unwrap();
+        let sorted = |column: SortColumn| {
+            let mut managed = ManagedDataFrame::new(df.clone(), "files".to_string(), None, None);
+            managed.sort_by_columns(&[column]).unwrap();
+            let out = managed.get_dataframe().unwrap();
+            out.column("f").unwrap().str().unwrap().into_iter().map(|v| v.map(str::to_string)).collect::<Vec<_>>()
+        };
+        let names = |v: &[Option<&str>]| v.iter().map(|v| v.map(str::to_string)).collect::<Vec<_>>();
+
+        let plain = sorted(SortColumn::new("f", true));
+        assert_eq!(plain, names(&[None, Some("File2"), Some("file1"), Some("file10")]));
+        let natural = SortColumn { natural: true, case_insensitive: true, nulls: NullsOrder::Last, ..SortColumn::new("f", true) };
+        assert_eq!(sorted(natural), names(&[Some("file1"), Some("File2"), Some("file10"), None]));
+        let ignore_case = SortColumn { case_insensitive: true, ..SortColumn::new("f", false) };
+        assert_eq!(sorted(ignore_case), names(&[Some("File2"), Some("file10"), Some("file1"), None]));
+    }
+
+    #[test]
+    fn test_group_starts() {
+        let df = df!("session" => [Some("a"), Some("a"), Some("b"), None, None, Some("a")]).unwrap();
+        let mut managed = ManagedDataFrame::new(df, "sessions".to_string(), None, None);
+        assert_eq!(managed.group_starts("session").as_deref(), Some(&vec![0, 2, 3, 5]));
+        assert!(managed.group_starts("missing").is_none());
+        // A sorted view is grouped afresh
+        managed.sort_by_columns(&[SortColumn::new("session", true)]).unwrap();
+        assert_eq!(managed.group_starts("session").as_deref(), Some(&vec![0, 2, 5]));
+    }
+
     #[test]
     fn test_list_and_remove_dataframes() {
         let mut manager = DataFrameManagerImpl::new();
@@ -478,4 +1011,114 @@ mod tests {
         assert!(manager.get_dataframe(id1).is_none());
         assert_eq!(manager.list_dataframes().len(), 1);
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_lazy_view_collects_only_window() {
+        use crate::dialog::filter_dialog::{ColumnFilter, FilterCondition};
+        let n = 2000i64;
+        let df = df!("n" => (0..n).collect::<Vec<_>>(), "even" => (0..n).map(|i| i % 2 == 0).collect::<Vec<_>>()).unwrap();
+        let mut managed = ManagedDataFrame::new(df, "big".to_string(), None, None);
+        managed
+            .sort_by_columns(&[SortColumn::new("n", false)])
+            .unwrap();
+        assert!(managed.current_df.is_none());
+        assert_eq!(managed.view_height().unwrap(), 2000);
+
+        let window = managed.window(1000, 20).unwrap();
+        assert!(window.covers(1000, 20));
+        assert!(window.df.height() < 1000);
+        assert_eq!(window.get("n", 1000), AnyValue::Int64(999));
+        // Scrolling within the margin reuses the collected rows
+        assert_eq!(managed.window(1010, 20).unwrap().offset, window.offset);
+        // Only the requested columns are collected
+        let projected = managed.projected_window(1000, 20, Some(&["even".to_string()])).unwrap();
+        assert_eq!(projected.df.get_column_names(), vec!["even"]);
+        assert_eq!(projected.get("even", 1001), AnyValue::Boolean(true));
+        assert!(managed.sort_by_columns(&[SortColumn::new("missing", true)]).is_err());
+
+        let filter = FilterExpr::Condition(ColumnFilter {
+            column: "n".to_string(),
+            condition: FilterCondition::LessThan { value: "10".to_string() },
+        });
+        managed.apply_filter(filter).unwrap();
+        assert_eq!(managed.view_height().unwrap(), 10);
+        assert_eq!(managed.row_count(), 10);
+        managed.reorder_columns(&["even".to_string(), "n".to_string()]).unwrap();
+        assert_eq!(managed.view_columns().unwrap(), vec!["even", "n"]);
+        assert_eq!(managed.ensure_current_df().unwrap().height(), 10);
+        assert!(managed.view.is_none());
+    }
+
+    #[test]
+    fn test_filter_with_progress_chunks_and_stops() {
+        use crate::dialog::filter_dialog::{ColumnFilter, FilterCondition};
+        let n = (FILTER_CHUNK_ROWS * 2 + 10) as i64;
+        let base = df!("n" => (0..n).collect::<Vec<_>>()).unwrap().lazy();
+        let filter = FilterExpr::Condition(ColumnFilter {
+            column: "n".to_string(),
+            condition: FilterCondition::GreaterThan { value: (n - 5).to_string() },
+        });
+        let mut reports = Vec::new();
+        let df = filter_with_progress(base.clone(), &filter, |done| {
+            reports.push(done);
+            true
+        })
+        .unwrap();
+        assert_eq!(df.height(), 4);
+        assert_eq!(reports.len(), 3);
+        assert_eq!(reports.last(), Some(&1.0));
+        assert!(filter_with_progress(base, &filter, |_| false).is_err());
+    }
+
+    #[test]
+    fn test_null_outside_filter_keeps_matching_rows() {
+        use crate::dialog::filter_dialog::{ColumnFilter, FilterCondition};
+        let base = df!("status" => ["500", "200", "500"], "ip" => ["1.1.1.1", "2.2.2.2", "3.3.3.3"]).unwrap();
+        let filter = FilterExpr::Condition(ColumnFilter {
+            column: "status".to_string(),
+            condition: FilterCondition::Equals { value: "500".to_string(), case_sensitive: false },
+        });
+        let (masked, original) = null_outside_filter(base.clone(), "ip", &filter).unwrap();
+        let ips: Vec<Option<&str>> = masked.column("ip").unwrap().str().unwrap().into_iter().collect();
+        assert_eq!(ips, vec![Some("1.1.1.1"), None, Some("3.3.3.3")]);
+        assert_eq!(original.null_count(), 0);
+        assert!(null_outside_filter(base, "missing", &filter).is_err());
+    }
+
+    #[test]
+    fn test_large_result_spills_to_parquet() {
+        let mut managed = ManagedDataFrame::new(sample_df(), "small".to_string(), None, None);
+        managed.set_result(sample_df(), Some(usize::MAX)).unwrap();
+        assert!(managed.current_df.is_some());
+
+        managed.set_result(sample_df(), Some(0)).unwrap();
+        assert!(managed.current_df.is_none());
+        let path = managed.spill_files[0].path.clone();
+        assert!(path.exists());
+        assert_eq!(managed.window(0, 3).unwrap().get("b", 2), AnyValue::String("z"));
+        managed.reset_current_df();
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_large_filter_streams_to_parquet_without_collecting() {
+        use crate::dataframe::spill::sink_to_parquet;
+        use crate::dialog::filter_dialog::{ColumnFilter, FilterCondition};
+        let mut managed = ManagedDataFrame::new(sample_df(), "events".to_string(), None, None);
+        assert!(!managed.spills_result(true, None));
+        assert!(!managed.spills_result(true, Some(usize::MAX)));
+        assert!(managed.spills_result(true, Some(0)));
+
+        let filter = FilterExpr::Condition(ColumnFilter {
+            column: "b".to_string(),
+            condition: FilterCondition::InList { values: vec!["y".to_string(), "z".to_string()], case_sensitive: true },
+        });
+        let (scan, spill) = sink_to_parquet(filtered_plan(managed.df.clone(), filter)).unwrap();
+        managed.set_spilled_view(scan, spill);
+        assert!(managed.current_df.is_none());
+        assert_eq!(managed.view_height().unwrap(), 2);
+        assert_eq!(managed.window(0, 2).unwrap().get("b", 1), AnyValue::String("z"));
+        // A sort of a spilled view streams too, whatever its estimated size
+        assert!(managed.spills_result(false, Some(usize::MAX - 1)));
+    }
+}
@@ -0,0 +1,111 @@
+//! Per-column missing-data counts (nulls and empty strings) and the drop/fill fixes for them.
+
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use polars::prelude::*;
+
+/// Missing values in one column
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnMissing {
+    pub name: String,
+    pub dtype: String,
+    pub nulls: usize,
+    /// Blank (empty or whitespace-only) strings; always 0 for non-text columns
+    pub empty: usize,
+    pub rows: usize,
+}
+
+impl ColumnMissing {
+    pub fn missing(&self) -> usize {
+        self.nulls + self.empty
+    }
+
+    /// Share of missing values, 0-100
+    pub fn percent(&self) -> f64 {
+        if self.rows == 0 { 0.0 } else { self.missing() as f64 * 100.0 / self.rows as f64 }
+    }
+}
+
+/// Missing counts for every column of `df`, in column order
+pub fn missing_report(df: &DataFrame) -> Result<Vec<ColumnMissing>> {
+    df.get_columns()
+        .iter()
+        .map(|column| {
+            let empty = match column.dtype() {
+                DataType::String => column
+                    .str()
+                    .map_err(|e| eyre!("{}", e))?
+                    .into_iter()
+                    .filter(|v| v.is_some_and(|s| s.trim().is_empty()))
+                    .count(),
+                _ => 0,
+            };
+            Ok(ColumnMissing {
+                name: column.name().to_string(),
+                dtype: column.dtype().to_string(),
+                nulls: column.null_count(),
+                empty,
+                rows: column.len(),
+            })
+        })
+        .collect()
+}
+
+/// Names of the columns whose missing share is above `threshold` percent
+pub fn columns_over_threshold(report: &[ColumnMissing], threshold: f64) -> Vec<String> {
+    report.iter().filter(|c| c.percent() > threshold).map(|c| c.name.clone()).collect()
+}
+
+/// `df` without `columns`
+pub fn drop_columns(df: &DataFrame, columns: &[String]) -> Result<DataFrame> {
+    Ok(df.drop_many(columns.iter().map(|c| c.as_str())))
+}
+
+/// `df` with nulls (and blank strings, for text columns) in `columns` replaced by `value`,
+/// cast to each column's type
+pub fn fill_missing(df: &DataFrame, columns: &[String], value: &str) -> Result<DataFrame> {
+    let mut exprs = Vec::with_capacity(columns.len());
+    for name in columns {
+        let dtype = df.column(name).map_err(|_| eyre!("Column '{}' not found", name))?.dtype().clone();
+        let expr = match dtype {
+            DataType::String => when(col(name.as_str()).str().strip_chars(lit(NULL)).eq(lit("")))
+                .then(lit(value))
+                .otherwise(col(name.as_str()))
+                .fill_null(lit(value)),
+            dtype => col(name.as_str()).fill_null(lit(value).strict_cast(dtype)),
+        };
+        exprs.push(expr.alias(name.as_str()));
+    }
+    df.clone()
+        .lazy()
+        .with_columns(exprs)
+        .collect()
+        .map_err(|e| eyre!("Fill failed: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_drop_and_fill() {
+        let df = df!(
+            "host" => [Some("a"), Some(" "), None, Some("b")],
+            "port" => [Some(80i64), None, None, None],
+            "id" => [1i64, 2, 3, 4]
+        )
+        .unwrap();
+        let report = missing_report(&df).unwrap();
+        assert_eq!((report[0].nulls, report[0].empty, report[0].percent()), (1, 1, 50.0));
+        assert_eq!(report[1].percent(), 75.0);
+        assert_eq!(report[2].missing(), 0);
+        assert_eq!(columns_over_threshold(&report, 50.0), ["port"]);
+
+        assert_eq!(drop_columns(&df, &["port".to_string()]).unwrap().get_column_names(), ["host", "id"]);
+        let filled = fill_missing(&df, &["host".to_string(), "port".to_string()], "0").unwrap();
+        let hosts: Vec<Option<&str>> = filled.column("host").unwrap().str().unwrap().into_iter().collect();
+        assert_eq!(hosts, [Some("a"), Some("0"), Some("0"), Some("b")]);
+        assert_eq!(filled.column("port").unwrap().i64().unwrap().get(3), Some(0));
+        assert!(fill_missing(&df, &["port".to_string()], "n/a").is_err());
+    }
+}
@@ -1,2 +1,33 @@
+pub mod benford;
+pub mod categorical;
+pub mod checkpoints;
+pub mod cluster_metrics;
+pub mod column_stats;
+pub mod crosstab;
+pub mod datetime_parse;
+pub mod evidence;
+pub mod find_index;
+pub mod findings_report;
+pub mod grouping;
+pub mod hashing;
+pub mod histogram;
+pub mod lookup;
 pub mod manager;
-pub mod meta;
\ No newline at end of file
+pub mod meta;
+pub mod missing;
+pub mod outliers;
+pub mod pattern_sets;
+pub mod pipeline_script;
+pub mod redaction;
+pub mod resample;
+pub mod row_tags;
+pub mod sampling;
+pub mod schema_edit;
+pub mod scripting;
+pub mod sigma;
+pub mod spill;
+pub mod timeline;
+pub mod timestamp_normalize;
+pub mod transform_history;
+pub mod tsne;
+pub mod validation;
@@ -0,0 +1,145 @@
+//! Z-score and IQR outlier scoring for numeric columns.
+
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use serde::{Deserialize, Serialize};
+
+/// How outlier scores are computed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum OutlierMethod {
+    /// |x - mean| / standard deviation
+    #[default]
+    ZScore,
+    /// Distance outside the interquartile range, in IQR units (0 inside [Q1, Q3])
+    Iqr,
+}
+
+impl OutlierMethod {
+    pub fn label(&self) -> &'static str {
+        match self {
+            OutlierMethod::ZScore => "Z-Score",
+            OutlierMethod::Iqr => "IQR",
+        }
+    }
+
+    /// Conventional cut-off: 3 standard deviations, or Tukey's 1.5 × IQR fences
+    pub fn default_threshold(&self) -> &'static str {
+        match self {
+            OutlierMethod::ZScore => "3",
+            OutlierMethod::Iqr => "1.5",
+        }
+    }
+}
+
+/// What the outlier operation writes into the new column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum OutlierOutput {
+    /// Boolean: score above the threshold
+    #[default]
+    Flag,
+    /// The raw Float64 score
+    Score,
+}
+
+/// User-facing options for the outlier column operation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutlierOptions {
+    pub method: OutlierMethod,
+    /// Kept as text so it round-trips the dialog input; parsed with `threshold_value`
+    pub threshold: String,
+    pub output: OutlierOutput,
+    /// Sort the table by the new column (descending) afterwards
+    pub sort_by_score: bool,
+}
+
+impl Default for OutlierOptions {
+    fn default() -> Self {
+        Self {
+            method: OutlierMethod::ZScore,
+            threshold: OutlierMethod::ZScore.default_threshold().to_string(),
+            output: OutlierOutput::Flag,
+            sort_by_score: true,
+        }
+    }
+}
+
+impl OutlierOptions {
+    pub fn threshold_value(&self) -> Result<f64> {
+        let t: f64 = self
+            .threshold
+            .trim()
+            .parse()
+            .map_err(|_| eyre!("Threshold '{}' is not a number", self.threshold.trim()))?;
+        if !t.is_finite() || t < 0.0 {
+            return Err(eyre!("Threshold must be a non-negative number"));
+        }
+        Ok(t)
+    }
+}
+
+/// Linear-interpolated quantile of sorted values
+pub(crate) fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let pos = q * (sorted.len() - 1) as f64;
+    let lo = pos.floor() as usize;
+    let hi = pos.ceil() as usize;
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (pos - lo as f64)
+}
+
+/// Outlier score for every value; nulls and NaNs stay None. A constant column scores 0.
+pub fn outlier_scores(values: &[Option<f64>], method: OutlierMethod) -> Vec<Option<f64>> {
+    let mut present: Vec<f64> = values.iter().flatten().copied().filter(|v| v.is_finite()).collect();
+    if present.is_empty() {
+        return vec![None; values.len()];
+    }
+    let score: Box<dyn Fn(f64) -> f64> = match method {
+        OutlierMethod::ZScore => {
+            let n = present.len() as f64;
+            let mean = present.iter().sum::<f64>() / n;
+            let std = (present.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n).sqrt();
+            Box::new(move |v| if std > 0.0 { (v - mean).abs() / std } else { 0.0 })
+        }
+        OutlierMethod::Iqr => {
+            present.sort_by(|a, b| a.total_cmp(b));
+            let q1 = quantile(&present, 0.25);
+            let q3 = quantile(&present, 0.75);
+            let iqr = q3 - q1;
+            Box::new(move |v| {
+                let beyond = (q1 - v).max(v - q3).max(0.0);
+                if iqr > 0.0 { beyond / iqr } else if beyond > 0.0 { f64::INFINITY } else { 0.0 }
+            })
+        }
+    };
+    values
+        .iter()
+        .map(|v| v.filter(|x| x.is_finite()).map(&score))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zscore_and_iqr_scores() {
+        let values = [Some(1.0), Some(2.0), Some(3.0), Some(4.0), None, Some(100.0)];
+        let z = outlier_scores(&values, OutlierMethod::ZScore);
+        assert_eq!(z[4], None);
+        let max_idx = z.iter().enumerate().max_by(|a, b| a.1.partial_cmp(b.1).unwrap()).unwrap().0;
+        assert_eq!(max_idx, 5);
+
+        let iqr = outlier_scores(&values, OutlierMethod::Iqr);
+        // Q1 = 2, Q3 = 4, IQR = 2: inside values score 0, 100 scores (100 - 4) / 2
+        assert_eq!(iqr[1], Some(0.0));
+        assert_eq!(iqr[5], Some(48.0));
+    }
+
+    #[test]
+    fn test_threshold_parsing() {
+        let mut options = OutlierOptions::default();
+        assert_eq!(options.threshold_value().unwrap(), 3.0);
+        options.threshold = "abc".to_string();
+        assert!(options.threshold_value().is_err());
+        options.threshold = "-1".to_string();
+        assert!(options.threshold_value().is_err());
+    }
+}
@@ -0,0 +1,227 @@
+//! Named regex pattern sets (grep -f with names): every pattern of a file is tried against the
+//! chosen columns at once, and each row gets the names of the patterns it matched.
+//!
+//! A pattern file has one pattern per line, either `name = regex` or a bare regex (named after
+//! its line). Blank lines and lines starting with `#` are ignored.
+
+use std::fmt;
+use std::path::Path;
+
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use polars::prelude::*;
+use regex::{RegexSet, RegexSetBuilder};
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_PATTERNS_COLUMN: &str = "matched_patterns";
+
+/// What to do with the matches
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum PatternOutput {
+    /// Add the matched pattern names as a column
+    #[default]
+    Column,
+    /// Keep only rows matching at least one pattern
+    Filter,
+    ColumnAndFilter,
+}
+
+impl PatternOutput {
+    pub const ALL: [PatternOutput; 3] = [PatternOutput::Column, PatternOutput::Filter, PatternOutput::ColumnAndFilter];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PatternOutput::Column => "Add Column",
+            PatternOutput::Filter => "Filter to Matches",
+            PatternOutput::ColumnAndFilter => "Add Column and Filter",
+        }
+    }
+
+    pub fn cycle(&self, forward: bool) -> PatternOutput {
+        let pos = Self::ALL.iter().position(|o| o == self).unwrap_or(0);
+        let len = Self::ALL.len();
+        Self::ALL[if forward { (pos + 1) % len } else { (pos + len - 1) % len }]
+    }
+
+    fn adds_column(&self) -> bool {
+        matches!(self, PatternOutput::Column | PatternOutput::ColumnAndFilter)
+    }
+
+    fn filters(&self) -> bool {
+        matches!(self, PatternOutput::Filter | PatternOutput::ColumnAndFilter)
+    }
+}
+
+/// User-facing options for applying a pattern set
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct PatternSetOptions {
+    pub patterns_path: String,
+    /// Columns searched; a row matches a pattern when any of them does
+    pub columns: Vec<String>,
+    pub case_insensitive: bool,
+    pub output: PatternOutput,
+    /// Name of the matches column; empty uses `matched_patterns`
+    pub column_name: String,
+}
+
+impl PatternSetOptions {
+    pub fn column_name(&self) -> String {
+        let name = self.column_name.trim();
+        if name.is_empty() { DEFAULT_PATTERNS_COLUMN.to_string() } else { name.to_string() }
+    }
+}
+
+/// Parsed pattern file: names in file order and the compiled set
+#[derive(Debug, Clone)]
+pub struct PatternSet {
+    pub names: Vec<String>,
+    set: RegexSet,
+}
+
+impl PatternSet {
+    pub fn parse(text: &str, case_insensitive: bool) -> Result<Self> {
+        let mut names: Vec<String> = Vec::new();
+        let mut patterns: Vec<String> = Vec::new();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (name, pattern) = match line.split_once(" = ") {
+                Some((name, pattern)) if !name.trim().is_empty() => (name.trim().to_string(), pattern.trim()),
+                _ => (format!("line_{}", i + 1), line),
+            };
+            if let Err(e) = regex::Regex::new(pattern) {
+                return Err(eyre!("Invalid pattern '{}' on line {}: {}", name, i + 1, e));
+            }
+            names.push(name);
+            patterns.push(pattern.to_string());
+        }
+        if patterns.is_empty() {
+            return Err(eyre!("The pattern file has no patterns"));
+        }
+        let set = RegexSetBuilder::new(&patterns)
+            .case_insensitive(case_insensitive)
+            .build()
+            .map_err(|e| eyre!("Failed to compile the pattern set: {}", e))?;
+        Ok(Self { names, set })
+    }
+
+    pub fn load(path: &Path, case_insensitive: bool) -> Result<Self> {
+        let text = std::fs::read_to_string(path).map_err(|e| eyre!("Failed to read {}: {}", path.display(), e))?;
+        Self::parse(&text, case_insensitive)
+    }
+
+    /// Indexes of the patterns matching `text`
+    fn matches(&self, text: &str) -> Vec<usize> {
+        self.set.matches(text).into_iter().collect()
+    }
+}
+
+/// Rows matched per pattern
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PatternSetSummary {
+    pub rows: usize,
+    pub matched_rows: usize,
+    /// (pattern name, matched rows) in file order
+    pub hits: Vec<(String, usize)>,
+}
+
+impl fmt::Display for PatternSetSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} pattern(s), {} of {} row(s) matched", self.hits.len(), self.matched_rows, self.rows)?;
+        let hits: Vec<&(String, usize)> = self.hits.iter().filter(|(_, n)| *n > 0).collect();
+        if !hits.is_empty() {
+            writeln!(f)?;
+            for (name, n) in hits {
+                write!(f, "\n  {name}: {n}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Names of the patterns each row matched in any of `columns`, joined with ", "
+pub fn match_pattern_set(df: &DataFrame, columns: &[String], set: &PatternSet) -> Result<(Vec<Option<String>>, PatternSetSummary)> {
+    if columns.is_empty() {
+        return Err(eyre!("Choose at least one column to search"));
+    }
+    let texts = columns
+        .iter()
+        .map(|name| {
+            let column = df.column(name).map_err(|e| eyre!("{}", e))?.cast(&DataType::String).map_err(|e| eyre!("{}", e))?;
+            Ok(column.str().map_err(|e| eyre!("{}", e))?.clone())
+        })
+        .collect::<Result<Vec<StringChunked>>>()?;
+    let mut hits = vec![0usize; set.names.len()];
+    let matches: Vec<Option<String>> = (0..df.height())
+        .map(|row| {
+            let mut matched = vec![false; set.names.len()];
+            for text in texts.iter().filter_map(|t| t.get(row)) {
+                for i in set.matches(text) {
+                    matched[i] = true;
+                }
+            }
+            let names: Vec<&str> = matched
+                .iter()
+                .enumerate()
+                .filter(|(_, m)| **m)
+                .map(|(i, _)| {
+                    hits[i] += 1;
+                    set.names[i].as_str()
+                })
+                .collect();
+            (!names.is_empty()).then(|| names.join(", "))
+        })
+        .collect();
+    let summary = PatternSetSummary {
+        rows: df.height(),
+        matched_rows: matches.iter().filter(|m| m.is_some()).count(),
+        hits: set.names.iter().cloned().zip(hits).collect(),
+    };
+    Ok((matches, summary))
+}
+
+/// Load the pattern file and apply it to `df` as `options` says
+pub fn run_pattern_set(df: &DataFrame, options: &PatternSetOptions) -> Result<(DataFrame, PatternSetSummary)> {
+    let set = PatternSet::load(Path::new(options.patterns_path.trim()), options.case_insensitive)?;
+    let (matches, summary) = match_pattern_set(df, &options.columns, &set)?;
+    let matched: BooleanChunked = matches.iter().map(|m| Some(m.is_some())).collect();
+    let mut out = df.clone();
+    if options.output.adds_column() {
+        out.with_column(Series::new(options.column_name().as_str().into(), matches))
+            .map_err(|e| eyre!("Failed to add matches column: {}", e))?;
+    }
+    if options.output.filters() {
+        out = out.filter(&matched).map_err(|e| eyre!("Failed to select matched rows: {}", e))?;
+    }
+    Ok((out, summary))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pattern_set_across_columns() {
+        let patterns = "# indicators\nbase64_ps = -enc(odedcommand)? \n\nmimikatz = sekurlsa::\\w+\n(?:\\d{1,3}\\.){3}\\d{1,3}\n";
+        let set = PatternSet::parse(patterns, true).unwrap();
+        assert_eq!(set.names, ["base64_ps", "mimikatz", "line_5"]);
+        assert!(PatternSet::parse("bad = (", false).unwrap_err().to_string().contains("line 1"));
+
+        let df = df!(
+            "cmd" => [Some("powershell -EncodedCommand AAA"), Some("SEKURLSA::logonpasswords"), None, Some("dir")],
+            "dst" => ["10.0.0.1", "", "192.168.1.5", "example.org"]
+        )
+        .unwrap();
+        let columns = vec!["cmd".to_string(), "dst".to_string()];
+        let (matches, summary) = match_pattern_set(&df, &columns, &set).unwrap();
+        assert_eq!(matches, [Some("base64_ps, line_5".to_string()), Some("mimikatz".to_string()), Some("line_5".to_string()), None]);
+        assert_eq!(summary.hits, [("base64_ps".to_string(), 1), ("mimikatz".to_string(), 1), ("line_5".to_string(), 2)]);
+        assert_eq!(summary.matched_rows, 3);
+
+        let case_sensitive = PatternSet::parse(patterns, false).unwrap();
+        let (matches, _) = match_pattern_set(&df, &columns[..1], &case_sensitive).unwrap();
+        assert_eq!(matches, [None, None, None, None]);
+    }
+}
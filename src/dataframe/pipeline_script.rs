@@ -0,0 +1,394 @@
+//! Export a transform history as a standalone Python/Polars script
+//!
+//! The script loads the original source and applies each recorded step, so findings can be
+//! reproduced outside the TUI. Steps without a Polars equivalent are kept as comments.
+
+use std::path::Path;
+
+use crate::dataframe::schema_edit::ColumnSchema;
+use crate::dataframe::transform_history::{TransformHistory, TransformStep};
+use crate::dialog::TransformScope;
+use crate::dialog::column_operation_options_dialog::OperationOptions;
+use crate::dialog::filter_dialog::{ColumnFilter, CompareOp, FilterCondition, FilterExpr};
+
+/// Helpers mirroring how datatui compares filter values and stringifies JMESPath results
+const HELPERS: &str = r#"def _value(src, column, value):
+    """Filter values are typed after the column, as in datatui."""
+    dtype = src.schema[column]
+    if dtype.is_float():
+        return float(value)
+    if dtype.is_integer():
+        return int(value)
+    return value
+
+
+def _equals(src, column, value, case_sensitive):
+    if src.schema[column] == pl.Utf8 and not case_sensitive:
+        return pl.col(column).str.to_lowercase() == value.lower()
+    return pl.col(column) == _value(src, column, value)
+
+
+def _in_list(src, column, values, case_sensitive):
+    if src.schema[column] == pl.Utf8 and not case_sensitive:
+        return pl.col(column).str.to_lowercase().is_in([v.lower() for v in values])
+    return pl.col(column).is_in([_value(src, column, v) for v in values])
+
+
+def _text(value):
+    if value is None:
+        return ""
+    return value if isinstance(value, str) else json.dumps(value)
+
+
+def _jmes_transform(src, query):
+    return pl.DataFrame([jmespath.search(query, row) for row in src.iter_rows(named=True)])
+
+
+def _jmes_add_columns(src, pairs):
+    rows = []
+    for row in src.iter_rows(named=True):
+        out = {k: _text(v) for k, v in row.items()}
+        for name, query in pairs:
+            result = jmespath.search(query, row)
+            if name:
+                out[name] = "" if result is None else json.dumps(result)
+            elif isinstance(result, dict):
+                out.update({k: _text(v) for k, v in result.items()})
+        rows.append(out)
+    return pl.DataFrame(rows)
+
+
+def _regex_extract(df, source, prefix, pattern):
+    groups = df.select(pl.col(source).str.extract_groups(pattern)).to_series().struct.unnest()
+    names = {
+        g: f"{prefix}_{g}" if prefix else (f"{source}_{g}" if g.isdigit() else g)
+        for g in groups.columns
+    }
+    return pl.concat([df, groups.rename(names)], how="horizontal")
+
+
+def _natural_key(value, case_insensitive):
+    text = value.lower() if case_insensitive else value
+    return re.sub(r"\d+", lambda m: m.group().lstrip("0").rjust(20, "0"), text)
+
+
+def _sort_key(src, column, natural, case_insensitive):
+    """Natural and case-insensitive ordering apply to text columns only, as in datatui."""
+    if src.schema[column] != pl.Utf8:
+        return pl.col(column)
+    if natural:
+        return pl.col(column).map_elements(lambda v: _natural_key(v, case_insensitive), return_dtype=pl.Utf8)
+    return pl.col(column).str.to_lowercase() if case_insensitive else pl.col(column)
+
+
+def _digest(algorithm):
+    def digest(value):
+        data = value.encode() if isinstance(value, str) else value
+        return hashlib.new(algorithm, data).hexdigest()
+    return digest
+"#;
+
+/// Python string literal (JSON escaping is valid Python)
+fn py_str(s: &str) -> String {
+    serde_json::to_string(s).unwrap_or_else(|_| format!("{s:?}"))
+}
+
+fn py_bool(b: bool) -> &'static str {
+    if b { "True" } else { "False" }
+}
+
+/// Polars dtype expression for a schema editor type name
+fn py_dtype(type_name: &str) -> String {
+    match type_name {
+        "String" => "pl.Utf8".to_string(),
+        "Datetime" => "pl.Datetime(\"ms\")".to_string(),
+        "Duration" => "pl.Duration(\"ms\")".to_string(),
+        other => format!("pl.{other}"),
+    }
+}
+
+/// Select expression for one column of a schema edit. A datetime format means the column is
+/// parsed from text, as the schema editor does for text columns.
+fn schema_column_expr(column: &ColumnSchema) -> String {
+    let source = format!("pl.col({})", py_str(&column.source));
+    let expr = match (column.dtype.as_deref(), column.datetime_format.as_deref()) {
+        (None, _) => source,
+        (Some(dtype @ ("Datetime" | "Date")), Some(format)) => format!(
+            "{source}.str.to_datetime({}, time_unit=\"us\", time_zone=\"UTC\", strict=False){}",
+            py_str(format),
+            if dtype == "Date" { ".dt.date()" } else { "" }
+        ),
+        (Some(dtype), _) => format!("{source}.cast({}, strict=False)", py_dtype(dtype)),
+    };
+    if column.is_renamed() { format!("{expr}.alias({})", py_str(&column.name)) } else { expr }
+}
+
+fn py_op(op: &CompareOp) -> &'static str {
+    match op {
+        CompareOp::Eq => "==",
+        CompareOp::Ne => "!=",
+        CompareOp::Lt => "<",
+        CompareOp::Gt => ">",
+        CompareOp::Lte => "<=",
+        CompareOp::Gte => ">=",
+    }
+}
+
+/// Reader call for the source file, picked by extension
+fn read_source(path: &Path) -> String {
+    let literal = py_str(&path.to_string_lossy());
+    let ext = path.extension().map(|e| e.to_string_lossy().to_lowercase()).unwrap_or_default();
+    match ext.as_str() {
+        "parquet" => format!("pl.read_parquet({literal})"),
+        "json" => format!("pl.read_json({literal})"),
+        "jsonl" | "ndjson" => format!("pl.read_ndjson({literal})"),
+        "xlsx" | "xls" => format!("pl.read_excel({literal})"),
+        "tsv" => format!("pl.read_csv({literal}, separator=\"\\t\")"),
+        _ => format!("pl.read_csv({literal})"),
+    }
+}
+
+fn condition_expr(column: &str, condition: &FilterCondition) -> String {
+    let col = format!("pl.col({})", py_str(column));
+    let text = format!("{col}.cast(pl.Utf8)");
+    let value = |v: &str| format!("_value(original, {}, {})", py_str(column), py_str(v));
+    match condition {
+        FilterCondition::Contains { value, case_sensitive: true } => {
+            format!("{text}.str.contains({}, literal=True)", py_str(value))
+        }
+        FilterCondition::Contains { value, case_sensitive: false } => {
+            format!("{text}.str.to_lowercase().str.contains({}, literal=True)", py_str(&value.to_lowercase()))
+        }
+        FilterCondition::Regex { pattern, case_sensitive } => {
+            let pattern = if *case_sensitive { pattern.clone() } else { format!("(?i){pattern}") };
+            format!("{text}.str.contains({})", py_str(&pattern))
+        }
+        FilterCondition::Equals { value, case_sensitive } => {
+            format!("_equals(original, {}, {}, {})", py_str(column), py_str(value), py_bool(*case_sensitive))
+        }
+        FilterCondition::GreaterThan { value: v } => format!("({col} > {})", value(v)),
+        FilterCondition::LessThan { value: v } => format!("({col} < {})", value(v)),
+        FilterCondition::GreaterThanOrEqual { value: v } => format!("({col} >= {})", value(v)),
+        FilterCondition::LessThanOrEqual { value: v } => format!("({col} <= {})", value(v)),
+        // datatui treats these as constant masks
+        FilterCondition::IsEmpty => "pl.lit(True)".to_string(),
+        FilterCondition::IsNotEmpty => "pl.lit(False)".to_string(),
+        FilterCondition::NotNull => format!("{col}.is_not_null()"),
+        FilterCondition::IsNull => format!("{col}.is_null()"),
+        FilterCondition::Between { min, max, inclusive } => format!(
+            "{col}.is_between({}, {}, closed={})",
+            value(min),
+            value(max),
+            if *inclusive { "\"both\"" } else { "\"none\"" }
+        ),
+        FilterCondition::InList { values, case_sensitive } => format!(
+            "_in_list(original, {}, [{}], {})",
+            py_str(column),
+            values.iter().map(|v| py_str(v)).collect::<Vec<_>>().join(", "),
+            py_bool(*case_sensitive)
+        ),
+        FilterCondition::Not(inner) => format!("~({})", condition_expr(column, inner)),
+        FilterCondition::CompareColumns { other_column, operator } => {
+            format!("({col} {} pl.col({}))", py_op(operator), py_str(other_column))
+        }
+        FilterCondition::StringLength { operator, length } => {
+            format!("({text}.str.len_chars() {} {length})", py_op(operator))
+        }
+    }
+}
+
+/// Polars expression equivalent to a filter expression
+pub fn filter_expr(expr: &FilterExpr) -> String {
+    let join = |children: &[FilterExpr], op: &str| {
+        if children.is_empty() {
+            return "pl.lit(True)".to_string();
+        }
+        format!("({})", children.iter().map(filter_expr).collect::<Vec<_>>().join(op))
+    };
+    match expr {
+        FilterExpr::Condition(ColumnFilter { column, condition }) => condition_expr(column, condition),
+        FilterExpr::And(children) => join(children, " & "),
+        FilterExpr::Or(children) => join(children, " | "),
+    }
+}
+
+fn scope_source(scope: &TransformScope) -> &'static str {
+    match scope {
+        TransformScope::Original => "original",
+        TransformScope::Current => "df",
+    }
+}
+
+fn name_or(new_column_name: &str, default: String) -> String {
+    if new_column_name.trim().is_empty() { default } else { new_column_name.to_string() }
+}
+
+/// Python statements for one step, or None when it has no Polars equivalent
+fn step_code(step: &TransformStep, table_name: &str) -> Option<String> {
+    let code = match step {
+        TransformStep::Filter(expr) => format!("df = original.filter({})", filter_expr(expr)),
+        TransformStep::Sort(columns) => {
+            let names = columns
+                .iter()
+                .map(|c| match c.natural || c.case_insensitive {
+                    true => format!("_sort_key(df, {}, {}, {})", py_str(&c.name), py_bool(c.natural), py_bool(c.case_insensitive)),
+                    false => py_str(&c.name),
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            let descending = columns.iter().map(|c| py_bool(!c.ascending)).collect::<Vec<_>>().join(", ");
+            let nulls_last = columns.iter().map(|c| py_bool(c.nulls.nulls_last(c.ascending))).collect::<Vec<_>>().join(", ");
+            format!("df = df.sort([{names}], descending=[{descending}], nulls_last=[{nulls_last}])")
+        }
+        TransformStep::Sql(query) => format!(
+            "# Register any other tables the query joins alongside {table}\ndf = pl.SQLContext(frames={{{table}: original}}).execute({query}, eager=True)",
+            table = py_str(table_name),
+            query = py_str(query)
+        ),
+        TransformStep::JmesTransform { query, scope } => {
+            format!("df = _jmes_transform({}, {})", scope_source(scope), py_str(query))
+        }
+        TransformStep::JmesAddColumns { pairs, scope } => format!(
+            "df = _jmes_add_columns({}, [{}])",
+            scope_source(scope),
+            pairs
+                .iter()
+                .map(|p| format!("({}, {})", py_str(&p.name), py_str(&p.value)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        // Row-limited operations rebuild the view from the original rows; not exported yet
+        TransformStep::ColumnOperation(cfg) if cfg.only_rows.is_some() => return None,
+        TransformStep::ColumnOperation(cfg) => {
+            let source = &cfg.source_column;
+            match &cfg.options {
+                OperationOptions::RegexExtract { pattern } => format!(
+                    "df = _regex_extract(df, {}, {}, {})",
+                    py_str(source),
+                    py_str(cfg.new_column_name.trim()),
+                    py_str(pattern)
+                ),
+                OperationOptions::ParseDatetime { format } => format!(
+                    "df = df.with_columns(pl.col({}).str.to_datetime({}time_unit=\"us\", time_zone=\"UTC\", strict=False).alias({}))",
+                    py_str(source),
+                    format.as_deref().map(|f| format!("{}, ", py_str(f))).unwrap_or_default(),
+                    py_str(&name_or(&cfg.new_column_name, format!("{source}_datetime")))
+                ),
+                OperationOptions::Hash { algorithm } => {
+                    let algorithm = algorithm.label().to_lowercase();
+                    format!(
+                        "df = df.with_columns(pl.col({}).map_elements(_digest({}), return_dtype=pl.Utf8).alias({}))",
+                        py_str(source),
+                        py_str(&algorithm),
+                        py_str(&name_or(&cfg.new_column_name, format!("{source}_{algorithm}")))
+                    )
+                }
+                _ => return None,
+            }
+        }
+        TransformStep::Schema(edit) => format!(
+            "df = df.select([{}, pl.exclude([{}])])",
+            edit.columns.iter().map(schema_column_expr).collect::<Vec<_>>().join(", "),
+            edit.columns.iter().map(|c| py_str(&c.source)).collect::<Vec<_>>().join(", ")
+        ),
+        // Rhai scripts, Sigma rules and pattern sets have no Python equivalent
+        TransformStep::Script { .. } | TransformStep::Sigma(_) | TransformStep::PatternSet(_) => return None,
+        TransformStep::Reset => "df = original".to_string(),
+    };
+    Some(code)
+}
+
+/// The full script for `history`. Without a source file the original data is expected as a
+/// Parquet export next to the script.
+pub fn python_script(history: &TransformHistory, table_name: &str, source_path: Option<&Path>) -> String {
+    let uses_jmes = history
+        .records
+        .iter()
+        .any(|r| matches!(r.step, TransformStep::JmesTransform { .. } | TransformStep::JmesAddColumns { .. }));
+    let mut out = String::new();
+    out.push_str("#!/usr/bin/env python3\n");
+    out.push_str(&format!(
+        "\"\"\"Transform pipeline for {}, exported from datatui {}.\"\"\"\n",
+        table_name.replace('"', "'"),
+        chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC")
+    ));
+    out.push_str("import hashlib\nimport json\nimport re\n\n");
+    if uses_jmes {
+        out.push_str("import jmespath\n");
+    }
+    out.push_str("import polars as pl\n\n\n");
+    out.push_str(HELPERS);
+    out.push_str("\n\n");
+    match source_path {
+        Some(path) => out.push_str(&format!("original = {}\n", read_source(path))),
+        None => out.push_str(&format!(
+            "# This dataset was not loaded from a file; export its original data to Parquet first\noriginal = pl.read_parquet({})\n",
+            py_str(&format!("{table_name}.parquet"))
+        )),
+    }
+    out.push_str("df = original\n");
+    for (i, record) in history.records.iter().enumerate() {
+        out.push_str(&format!("\n# {}. {}: {}\n", i + 1, record.step.kind_name(), record.step.summary().replace('\n', " ")));
+        match step_code(&record.step, table_name) {
+            Some(code) => {
+                out.push_str(&code);
+                out.push('\n');
+            }
+            None => out.push_str("# Not exported: run this step again in datatui\n"),
+        }
+    }
+    out.push_str("\nprint(df)\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialog::column_operation_options_dialog::ColumnOperationConfig;
+    use crate::dialog::column_operations_dialog::ColumnOperationKind;
+    use crate::dialog::sort_dialog::SortColumn;
+
+    #[test]
+    fn test_python_script_covers_steps() {
+        let mut history = TransformHistory::default();
+        history.push(
+            TransformStep::Filter(FilterExpr::And(vec![
+                FilterExpr::Condition(ColumnFilter {
+                    column: "status".to_string(),
+                    condition: FilterCondition::GreaterThan { value: "499".to_string() },
+                }),
+                FilterExpr::Condition(ColumnFilter {
+                    column: "path".to_string(),
+                    condition: FilterCondition::Not(Box::new(FilterCondition::Regex {
+                        pattern: r"\.png$".to_string(),
+                        case_sensitive: true,
+                    })),
+                }),
+            ])),
+            4,
+            3,
+        );
+        history.push(TransformStep::Sort(vec![SortColumn::new("ts", false)]), 4, 3);
+        history.push(
+            TransformStep::ColumnOperation(ColumnOperationConfig {
+                operation: ColumnOperationKind::Cluster,
+                new_column_name: "cluster".to_string(),
+                source_column: "emb".to_string(),
+                hide_new_column: false,
+                only_rows: None,
+                options: OperationOptions::Cluster { algorithm: crate::dialog::ClusterAlgorithm::Kmeans, kmeans: None, dbscan: None },
+            }),
+            4,
+            4,
+        );
+
+        let script = python_script(&history, "access", Some(Path::new("/cases/access.parquet")));
+        assert!(script.contains("original = pl.read_parquet(\"/cases/access.parquet\")"));
+        assert!(script.contains(
+            "df = original.filter(((pl.col(\"status\") > _value(original, \"status\", \"499\")) & ~(pl.col(\"path\").cast(pl.Utf8).str.contains(\"\\\\.png$\"))))"
+        ));
+        assert!(script.contains("df = df.sort([\"ts\"], descending=[True], nulls_last=[True])"));
+        assert!(script.contains("# Not exported: run this step again in datatui"));
+        assert!(!script.contains("import jmespath"));
+    }
+}
@@ -0,0 +1,237 @@
+//! Time-bucketed aggregation ("events per hour per host") over a datetime column.
+
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use polars::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::dataframe::datetime_parse::parse_datetime_values;
+use crate::dataframe::histogram::ChartBar;
+
+/// How rows falling into one bucket are combined
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ResampleAgg {
+    /// Number of rows
+    #[default]
+    Count,
+    Sum,
+    Mean,
+    Min,
+    Max,
+    /// Number of distinct values of the value column
+    DistinctCount,
+}
+
+impl ResampleAgg {
+    pub const ALL: [ResampleAgg; 6] = [
+        ResampleAgg::Count,
+        ResampleAgg::Sum,
+        ResampleAgg::Mean,
+        ResampleAgg::Min,
+        ResampleAgg::Max,
+        ResampleAgg::DistinctCount,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ResampleAgg::Count => "Count",
+            ResampleAgg::Sum => "Sum",
+            ResampleAgg::Mean => "Mean",
+            ResampleAgg::Min => "Min",
+            ResampleAgg::Max => "Max",
+            ResampleAgg::DistinctCount => "Distinct Count",
+        }
+    }
+
+    /// Whether the aggregation reads a value column (Count only counts rows)
+    pub fn needs_value_column(&self) -> bool {
+        !matches!(self, ResampleAgg::Count)
+    }
+
+    /// Cycle to the next (or previous) aggregation
+    pub fn cycle(&self, forward: bool) -> ResampleAgg {
+        let pos = Self::ALL.iter().position(|a| a == self).unwrap_or(0);
+        let len = Self::ALL.len();
+        Self::ALL[if forward { (pos + 1) % len } else { (pos + len - 1) % len }]
+    }
+
+    fn suffix(&self) -> &'static str {
+        match self {
+            ResampleAgg::Count => "count",
+            ResampleAgg::Sum => "sum",
+            ResampleAgg::Mean => "mean",
+            ResampleAgg::Min => "min",
+            ResampleAgg::Max => "max",
+            ResampleAgg::DistinctCount => "distinct",
+        }
+    }
+}
+
+/// User-facing options for the resample dialog
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResampleOptions {
+    /// Datetime or Date column; text columns are parsed with the auto-detected format
+    pub time_column: String,
+    /// Bucket size as a Polars duration string, e.g. `15m`, `1h`, `1d`
+    pub every: String,
+    /// Optional column splitting each bucket (e.g. host); empty for none
+    pub group_by: String,
+    pub aggregation: ResampleAgg,
+    /// Column read by every aggregation except Count
+    pub value_column: String,
+}
+
+impl Default for ResampleOptions {
+    fn default() -> Self {
+        Self {
+            time_column: String::new(),
+            every: "1h".to_string(),
+            group_by: String::new(),
+            aggregation: ResampleAgg::Count,
+            value_column: String::new(),
+        }
+    }
+}
+
+impl ResampleOptions {
+    /// Name of the aggregated column in the result
+    pub fn output_column(&self) -> String {
+        match self.aggregation {
+            ResampleAgg::Count => "count".to_string(),
+            agg => format!("{}_{}", self.value_column, agg.suffix()),
+        }
+    }
+}
+
+/// `df[column]` as a Datetime/Date column, parsing text values when needed
+fn time_column(df: &DataFrame, column: &str) -> Result<Column> {
+    let col = df.column(column).map_err(|_| eyre!("Column '{}' not found", column))?;
+    match col.dtype() {
+        DataType::Datetime(_, _) | DataType::Date => Ok(col.clone()),
+        DataType::String => {
+            let values: Vec<Option<&str>> = col.str().map_err(|e| eyre!("{}", e))?.into_iter().collect();
+            let (parsed, _) = parse_datetime_values(&values, None)?;
+            Ok(Int64Chunked::from_iter_options(PlSmallStr::from_str(column), parsed.into_iter())
+                .into_datetime(TimeUnit::Microseconds, Some(TimeZone::UTC))
+                .into_series()
+                .into_column())
+        }
+        other => Err(eyre!("'{}' is {}, not a datetime column", column, other)),
+    }
+}
+
+/// Bucket `df` by `options.time_column` truncated to `options.every` (and `group_by`, if set),
+/// aggregate each bucket, and return the buckets in time order.
+pub fn resample(df: &DataFrame, options: &ResampleOptions) -> Result<DataFrame> {
+    let every = options.every.trim();
+    if every.is_empty() {
+        return Err(eyre!("Bucket size is required, e.g. 1h"));
+    }
+    let time = options.time_column.as_str();
+    let group = options.group_by.trim();
+    let value = options.value_column.trim();
+    if options.aggregation.needs_value_column() && df.column(value).is_err() {
+        return Err(eyre!("{} needs a value column", options.aggregation.label()));
+    }
+    if !group.is_empty() && df.column(group).is_err() {
+        return Err(eyre!("Group by column '{}' not found", group));
+    }
+
+    let mut cols = vec![time_column(df, time)?];
+    let mut keys = vec![col(time)];
+    if !group.is_empty() && group != time {
+        cols.push(df.column(group).map_err(|e| eyre!("{}", e))?.clone());
+        keys.push(col(group));
+    }
+    if options.aggregation.needs_value_column() && !cols.iter().any(|c| c.name().as_str() == value) {
+        cols.push(df.column(value).map_err(|e| eyre!("{}", e))?.clone());
+    }
+    let out_name = options.output_column();
+    let agg = match options.aggregation {
+        ResampleAgg::Count => len(),
+        ResampleAgg::Sum => col(value).sum(),
+        ResampleAgg::Mean => col(value).mean(),
+        ResampleAgg::Min => col(value).min(),
+        ResampleAgg::Max => col(value).max(),
+        ResampleAgg::DistinctCount => col(value).n_unique(),
+    }
+    .alias(out_name.as_str());
+
+    DataFrame::new(cols)
+        .map_err(|e| eyre!("{}", e))?
+        .lazy()
+        .filter(col(time).is_not_null())
+        .with_column(col(time).dt().truncate(lit(every)).alias(time))
+        .group_by(keys.clone())
+        .agg([agg])
+        .sort_by_exprs(keys, SortMultipleOptions::default())
+        .collect()
+        .map_err(|e| eyre!("Resample failed: {}", e))
+}
+
+/// One bar per bucket (and group) of a `resample` result, labelled `<bucket> <group>`.
+/// Values are rounded to whole numbers; negatives chart as zero.
+pub fn resample_chart_bars(buckets: &DataFrame, options: &ResampleOptions) -> Result<Vec<ChartBar>> {
+    let times = buckets.column(&options.time_column).map_err(|e| eyre!("{}", e))?;
+    let group = options.group_by.trim();
+    let groups = if group.is_empty() || group == options.time_column { None } else { Some(buckets.column(group).map_err(|e| eyre!("{}", e))?) };
+    let values = buckets
+        .column(&options.output_column())
+        .and_then(|c| c.cast(&DataType::Float64))
+        .map_err(|e| eyre!("{}", e))?;
+    let values = values.f64().map_err(|e| eyre!("{}", e))?;
+    let mut bars = Vec::with_capacity(buckets.height());
+    for (row, value) in values.into_iter().enumerate() {
+        let time = times.get(row).map(|v| v.str_value().to_string()).unwrap_or_default();
+        let label = match groups.and_then(|g| g.get(row).ok()) {
+            Some(AnyValue::Null) | None => time,
+            Some(g) => format!("{time} {}", g.str_value()),
+        };
+        bars.push(ChartBar { label, count: value.unwrap_or(0.0).max(0.0).round() as u64 });
+    }
+    Ok(bars)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn events() -> DataFrame {
+        df!(
+            "ts" => ["2024-01-01 10:05:00", "2024-01-01 10:40:00", "2024-01-01 11:10:00", "2024-01-01 10:59:59", "bad"],
+            "host" => ["a", "a", "a", "b", "b"],
+            "bytes" => [10i64, 20, 5, 7, 1]
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_events_per_hour_per_host() {
+        let options = ResampleOptions { time_column: "ts".to_string(), group_by: "host".to_string(), ..Default::default() };
+        let out = resample(&events(), &options).unwrap();
+        let hosts: Vec<Option<&str>> = out.column("host").unwrap().str().unwrap().into_iter().collect();
+        let counts: Vec<u32> = out.column("count").unwrap().cast(&DataType::UInt32).unwrap().u32().unwrap().into_no_null_iter().collect();
+        assert_eq!(hosts, [Some("a"), Some("b"), Some("a")]);
+        assert_eq!(counts, [2, 1, 1]);
+        let bars = resample_chart_bars(&out, &options).unwrap();
+        assert_eq!(bars[0], ChartBar { label: "2024-01-01 10:00:00 UTC a".to_string(), count: 2 });
+    }
+
+    #[test]
+    fn test_sum_without_group_and_errors() {
+        let options = ResampleOptions {
+            time_column: "ts".to_string(),
+            aggregation: ResampleAgg::Sum,
+            value_column: "bytes".to_string(),
+            ..Default::default()
+        };
+        let out = resample(&events(), &options).unwrap();
+        let sums: Vec<i64> = out.column("bytes_sum").unwrap().i64().unwrap().into_no_null_iter().collect();
+        assert_eq!(sums, [37, 5]);
+
+        let no_value = ResampleOptions { value_column: String::new(), ..options.clone() };
+        assert!(resample(&events(), &no_value).is_err());
+        let not_time = ResampleOptions { time_column: "bytes".to_string(), ..options };
+        assert!(resample(&events(), &not_time).is_err());
+    }
+}
@@ -0,0 +1,190 @@
+//! Analyst tags on rows: labels plus a free-text note, keyed by a hash of the row's values
+//!
+//! The key covers only the dataset's own columns (captured when the first row is tagged), so a
+//! tag follows its row through sorting, filtering and added columns. Rows with identical values
+//! in those columns share their tags.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use polars::prelude::*;
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+
+/// Name of the synthetic column listing each row's labels
+pub const TAGS_COLUMN: &str = "tags";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RowTag {
+    pub labels: Vec<String>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub note: String,
+    pub tagged_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RowTags {
+    /// Columns hashed into each row key
+    pub key_columns: Vec<String>,
+    /// Row key → tag
+    pub tags: BTreeMap<String, RowTag>,
+}
+
+/// Split comma-separated labels, trimmed and without duplicates
+pub fn parse_labels(text: &str) -> Vec<String> {
+    let mut labels: Vec<String> = Vec::new();
+    for label in text.split(',').map(str::trim).filter(|l| !l.is_empty()) {
+        if !labels.iter().any(|l| l == label) {
+            labels.push(label.to_string());
+        }
+    }
+    labels
+}
+
+impl RowTags {
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
+
+    /// Key of row `row` of `df`; None when a key column is missing from `df`
+    pub fn row_key(&self, df: &DataFrame, row: usize) -> Option<String> {
+        let columns = self
+            .key_columns
+            .iter()
+            .map(|name| df.column(name).ok())
+            .collect::<Option<Vec<&Column>>>()?;
+        let mut hasher = Sha1::new();
+        for column in columns {
+            let value = column.get(row).ok()?;
+            match value {
+                AnyValue::Null => hasher.update([0u8]),
+                value => hasher.update(value.to_string().as_bytes()),
+            }
+            hasher.update([0x1f]);
+        }
+        Some(hasher.finalize().iter().map(|b| format!("{b:02x}")).collect())
+    }
+
+    /// Keys of every row of `df`
+    pub fn row_keys(&self, df: &DataFrame) -> Vec<Option<String>> {
+        (0..df.height()).map(|row| self.row_key(df, row)).collect()
+    }
+
+    pub fn get(&self, df: &DataFrame, row: usize) -> Option<&RowTag> {
+        self.row_key(df, row).and_then(|key| self.tags.get(&key))
+    }
+
+    /// Tag row `row` of `df`; no labels and no note removes its tag. `key_columns` are the
+    /// dataset's own columns, used when this is the first tag.
+    pub fn set(&mut self, df: &DataFrame, row: usize, key_columns: &[String], labels: Vec<String>, note: String) -> Result<()> {
+        if self.key_columns.is_empty() {
+            self.key_columns = key_columns.iter().filter(|c| c.as_str() != TAGS_COLUMN).cloned().collect();
+        }
+        let key = self
+            .row_key(df, row)
+            .ok_or_else(|| eyre!("Rows can only be tagged while the columns {} are present", self.key_columns.join(", ")))?;
+        if labels.is_empty() && note.trim().is_empty() {
+            self.tags.remove(&key);
+        } else {
+            self.tags.insert(key, RowTag { labels, note: note.trim().to_string(), tagged_at: Utc::now() });
+        }
+        Ok(())
+    }
+
+    /// Every label with the number of tagged rows carrying it, by label
+    pub fn label_counts(&self) -> Vec<(String, usize)> {
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for tag in self.tags.values() {
+            for label in &tag.labels {
+                *counts.entry(label.as_str()).or_default() += 1;
+            }
+        }
+        counts.into_iter().map(|(label, n)| (label.to_string(), n)).collect()
+    }
+
+    pub fn rename_label(&mut self, from: &str, to: &str) {
+        for tag in self.tags.values_mut() {
+            if let Some(i) = tag.labels.iter().position(|l| l == from) {
+                if tag.labels.iter().any(|l| l == to) {
+                    tag.labels.remove(i);
+                } else {
+                    tag.labels[i] = to.to_string();
+                }
+            }
+        }
+    }
+
+    /// Remove `label` everywhere; rows left with neither labels nor a note lose their tag
+    pub fn remove_label(&mut self, label: &str) {
+        for tag in self.tags.values_mut() {
+            tag.labels.retain(|l| l != label);
+        }
+        self.tags.retain(|_, tag| !tag.labels.is_empty() || !tag.note.is_empty());
+    }
+
+    /// `df` with the synthetic tags column set (labels joined with ", ", then the note)
+    pub fn with_tags_column(&self, df: &DataFrame) -> Result<DataFrame> {
+        let values: Vec<Option<String>> = self
+            .row_keys(df)
+            .into_iter()
+            .map(|key| {
+                let tag = self.tags.get(&key?)?;
+                let labels = tag.labels.join(", ");
+                Some(match (labels.is_empty(), tag.note.is_empty()) {
+                    (_, true) => labels,
+                    (true, false) => format!("({})", tag.note),
+                    (false, false) => format!("{labels} ({})", tag.note),
+                })
+            })
+            .collect();
+        let mut out = df.clone();
+        out.with_column(Series::new(TAGS_COLUMN.into(), values))
+            .map_err(|e| eyre!("Failed to add tags column: {}", e))?;
+        Ok(out)
+    }
+
+    /// Rows of `df` tagged with `label`
+    pub fn filter_by_label(&self, df: &DataFrame, label: &str) -> Result<DataFrame> {
+        let mask: BooleanChunked = self
+            .row_keys(df)
+            .into_iter()
+            .map(|key| Some(key.and_then(|k| self.tags.get(&k)).is_some_and(|t| t.labels.iter().any(|l| l == label))))
+            .collect();
+        df.filter(&mask).map_err(|e| eyre!("Failed to filter by tag: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tags_follow_rows_and_persist() {
+        let df = df!("host" => ["a", "b", "c"], "bytes" => [Some(1i64), None, Some(3)]).unwrap();
+        let base: Vec<String> = vec!["host".to_string(), "bytes".to_string()];
+        let mut tags = RowTags::default();
+        tags.set(&df, 1, &base, parse_labels("suspicious, lateral, suspicious"), String::new()).unwrap();
+        tags.set(&df, 2, &base, parse_labels("suspicious"), " check owner ".to_string()).unwrap();
+        assert_eq!(tags.get(&df, 1).unwrap().labels, ["suspicious", "lateral"]);
+
+        // Same rows after a sort and an added column
+        let sorted = df.sort(["host"], SortMultipleOptions::default().with_order_descending(true)).unwrap();
+        let mut sorted = sorted.clone();
+        sorted.with_column(Series::new("extra".into(), [9i64, 9, 9])).unwrap();
+        let shown = tags.with_tags_column(&sorted).unwrap();
+        let column: Vec<Option<&str>> = shown.column(TAGS_COLUMN).unwrap().str().unwrap().into_iter().collect();
+        assert_eq!(column, [Some("suspicious (check owner)"), Some("suspicious, lateral"), None]);
+        assert_eq!(tags.filter_by_label(&shown, "lateral").unwrap().height(), 1);
+
+        assert_eq!(tags.label_counts(), [("lateral".to_string(), 1), ("suspicious".to_string(), 2)]);
+        tags.rename_label("lateral", "suspicious");
+        tags.remove_label("suspicious");
+        assert_eq!(tags.tags.len(), 1);
+
+        let restored: RowTags = serde_json::from_str(&serde_json::to_string(&tags).unwrap()).unwrap();
+        assert_eq!(restored.get(&df, 2).unwrap().note, "check owner");
+        assert!(restored.get(&df, 1).is_none());
+    }
+}
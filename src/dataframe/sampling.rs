@@ -0,0 +1,172 @@
+//! Head, tail and random row samples for lighter working copies of large datasets.
+
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use polars::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Which rows a sample keeps
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum SampleMethod {
+    /// The first N rows
+    #[default]
+    Head,
+    /// The last N rows
+    Tail,
+    /// N rows chosen at random, kept in their original order
+    RandomRows,
+    /// A random percentage of the rows, kept in their original order
+    RandomPercent,
+}
+
+impl SampleMethod {
+    pub const ALL: [SampleMethod; 4] = [SampleMethod::Head, SampleMethod::Tail, SampleMethod::RandomRows, SampleMethod::RandomPercent];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SampleMethod::Head => "First N",
+            SampleMethod::Tail => "Last N",
+            SampleMethod::RandomRows => "Random N",
+            SampleMethod::RandomPercent => "Random %",
+        }
+    }
+
+    /// Short suffix for the new dataset name
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            SampleMethod::Head => "head",
+            SampleMethod::Tail => "tail",
+            SampleMethod::RandomRows | SampleMethod::RandomPercent => "sample",
+        }
+    }
+
+    /// Cycle to the next (or previous) method
+    pub fn cycle(&self, forward: bool) -> SampleMethod {
+        let pos = Self::ALL.iter().position(|m| m == self).unwrap_or(0);
+        let len = Self::ALL.len();
+        Self::ALL[if forward { (pos + 1) % len } else { (pos + len - 1) % len }]
+    }
+}
+
+/// User-facing options for the sample action
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SampleOptions {
+    pub method: SampleMethod,
+    /// Row count, or a percentage for `RandomPercent`; kept as text so it round-trips the dialog input
+    pub amount: String,
+    /// Seed for random samples; empty picks a fresh one
+    pub seed: String,
+}
+
+impl Default for SampleOptions {
+    fn default() -> Self {
+        Self { method: SampleMethod::Head, amount: "1000".to_string(), seed: String::new() }
+    }
+}
+
+impl SampleOptions {
+    /// Number of rows to keep out of `height`
+    pub fn row_count(&self, height: usize) -> Result<usize> {
+        let text = self.amount.trim().trim_end_matches('%');
+        match self.method {
+            SampleMethod::RandomPercent => {
+                let pct: f64 = text.parse().map_err(|_| eyre!("Percentage '{}' is not a number", self.amount.trim()))?;
+                if !(0.0..=100.0).contains(&pct) {
+                    return Err(eyre!("Percentage must be between 0 and 100"));
+                }
+                Ok(((height as f64) * pct / 100.0).round() as usize)
+            }
+            _ => {
+                let n: usize = text.parse().map_err(|_| eyre!("Row count '{}' is not a whole number", self.amount.trim()))?;
+                Ok(n.min(height))
+            }
+        }
+    }
+
+    fn seed_value(&self) -> Result<u64> {
+        match self.seed.trim() {
+            "" => Ok(uuid::Uuid::new_v4().as_u128() as u64),
+            s => s.parse().map_err(|_| eyre!("Seed '{}' is not a whole number", s)),
+        }
+    }
+}
+
+/// xorshift64*: enough to pick rows, no extra dependency
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed.max(1))
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+}
+
+/// `count` distinct row indices out of `height`, ascending
+fn random_rows(height: usize, count: usize, seed: u64) -> Vec<IdxSize> {
+    let mut rng = Rng::new(seed);
+    let mut rows: Vec<IdxSize> = (0..height as IdxSize).collect();
+    // Partial Fisher-Yates: the first `count` slots end up a uniform sample
+    for i in 0..count {
+        let j = i + (rng.next() % (height - i) as u64) as usize;
+        rows.swap(i, j);
+    }
+    rows.truncate(count);
+    rows.sort_unstable();
+    rows
+}
+
+/// Sample `df` per `options`
+pub fn sample(df: &DataFrame, options: &SampleOptions) -> Result<DataFrame> {
+    let count = options.row_count(df.height())?;
+    match options.method {
+        SampleMethod::Head => Ok(df.head(Some(count))),
+        SampleMethod::Tail => Ok(df.tail(Some(count))),
+        SampleMethod::RandomRows | SampleMethod::RandomPercent => {
+            let rows = random_rows(df.height(), count, options.seed_value()?);
+            let idx = IdxCa::from_vec(PlSmallStr::EMPTY, rows);
+            df.take(&idx).map_err(|e| eyre!("{}", e))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(df: &DataFrame) -> Vec<i64> {
+        df.column("n").unwrap().i64().unwrap().into_no_null_iter().collect()
+    }
+
+    #[test]
+    fn test_head_tail_and_percent() {
+        let df = df!("n" => (0i64..10).collect::<Vec<_>>()).unwrap();
+        let head = SampleOptions { amount: "3".to_string(), ..Default::default() };
+        assert_eq!(values(&sample(&df, &head).unwrap()), [0, 1, 2]);
+        let tail = SampleOptions { method: SampleMethod::Tail, amount: "20".to_string(), ..Default::default() };
+        assert_eq!(sample(&df, &tail).unwrap().height(), 10);
+        let pct = SampleOptions { method: SampleMethod::RandomPercent, amount: "30%".to_string(), seed: "7".to_string() };
+        let rows = values(&sample(&df, &pct).unwrap());
+        assert_eq!(rows.len(), 3);
+        assert!(rows.windows(2).all(|w| w[0] < w[1]));
+        assert!(SampleOptions { amount: "x".to_string(), ..Default::default() }.row_count(10).is_err());
+    }
+
+    #[test]
+    fn test_random_sample_is_seeded() {
+        let df = df!("n" => (0i64..1000).collect::<Vec<_>>()).unwrap();
+        let options = SampleOptions { method: SampleMethod::RandomRows, amount: "50".to_string(), seed: "42".to_string() };
+        let a = values(&sample(&df, &options).unwrap());
+        let b = values(&sample(&df, &options).unwrap());
+        assert_eq!(a, b);
+        assert_eq!(a.len(), 50);
+        let mut distinct = a.clone();
+        distinct.dedup();
+        assert_eq!(distinct.len(), 50);
+    }
+}
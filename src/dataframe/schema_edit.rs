@@ -0,0 +1,234 @@
+//! Schema edits: cast, rename and reorder a frame's columns in one step
+//!
+//! Casts are lenient: values that cannot be converted become null. `cast_failures` counts those
+//! values per column so they can be reviewed before the edit is applied.
+
+use std::collections::HashSet;
+
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use polars::chunked_array::cast::CastOptions;
+use polars::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::dataframe::datetime_parse::parse_datetime_values;
+
+/// Types offered by the schema editor, in the order they are cycled through
+pub const TYPE_NAMES: &[&str] = &[
+    "String", "Int64", "Int32", "Int16", "Int8", "UInt64", "UInt32", "UInt16", "UInt8", "Float64", "Float32", "Boolean",
+    "Date", "Datetime", "Time", "Duration",
+];
+
+/// Failed values shown per column
+const MAX_EXAMPLES: usize = 3;
+
+/// DataType for a type name as listed in `TYPE_NAMES` or written by `DataType`'s Debug output
+pub fn parse_dtype(name: &str) -> Option<DataType> {
+    match name {
+        "Int8" => Some(DataType::Int8),
+        "Int16" => Some(DataType::Int16),
+        "Int32" => Some(DataType::Int32),
+        "Int64" => Some(DataType::Int64),
+        "Int128" => Some(DataType::Int128),
+        "UInt8" => Some(DataType::UInt8),
+        "UInt16" => Some(DataType::UInt16),
+        "UInt32" => Some(DataType::UInt32),
+        "UInt64" => Some(DataType::UInt64),
+        "Float32" => Some(DataType::Float32),
+        "Float64" => Some(DataType::Float64),
+        "Boolean" => Some(DataType::Boolean),
+        "String" | "Utf8" => Some(DataType::String),
+        "Date" => Some(DataType::Date),
+        s if s == "Datetime" || s.starts_with("Datetime(") => Some(DataType::Datetime(TimeUnit::Milliseconds, None)),
+        "Time" => Some(DataType::Time),
+        s if s == "Duration" || s.starts_with("Duration(") => Some(DataType::Duration(TimeUnit::Milliseconds)),
+        _ => None,
+    }
+}
+
+/// What becomes of one column
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColumnSchema {
+    /// Column name in the data before the edit
+    pub source: String,
+    /// Name after the edit
+    pub name: String,
+    /// Target type from `TYPE_NAMES`; None keeps the current type
+    #[serde(default)]
+    pub dtype: Option<String>,
+    /// strftime format for text read as Datetime or Date; None detects the format
+    #[serde(default)]
+    pub datetime_format: Option<String>,
+}
+
+impl ColumnSchema {
+    pub fn is_renamed(&self) -> bool {
+        self.name != self.source
+    }
+}
+
+/// Columns in their new order. Columns of the data that are not listed keep their type and
+/// follow the listed ones.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaEdit {
+    pub columns: Vec<ColumnSchema>,
+}
+
+impl SchemaEdit {
+    /// An edit that leaves `df` unchanged, to start editing from
+    pub fn from_frame(df: &DataFrame) -> Self {
+        let columns = df
+            .get_column_names()
+            .into_iter()
+            .map(|name| ColumnSchema { source: name.to_string(), name: name.to_string(), dtype: None, datetime_format: None })
+            .collect();
+        Self { columns }
+    }
+
+    /// One-line description for the history dialog
+    pub fn summary(&self) -> String {
+        let casts: Vec<String> = self
+            .columns
+            .iter()
+            .filter_map(|c| c.dtype.as_ref().map(|t| format!("{} as {t}", c.source)))
+            .collect();
+        let renames: Vec<String> = self
+            .columns
+            .iter()
+            .filter(|c| c.is_renamed())
+            .map(|c| format!("{} -> {}", c.source, c.name))
+            .collect();
+        let mut parts = Vec::new();
+        if !casts.is_empty() {
+            parts.push(format!("cast {}", casts.join(", ")));
+        }
+        if !renames.is_empty() {
+            parts.push(format!("rename {}", renames.join(", ")));
+        }
+        if parts.is_empty() {
+            parts.push("reorder columns".to_string());
+        }
+        parts.join("; ")
+    }
+
+    /// The edited frame
+    pub fn apply(&self, df: &DataFrame) -> Result<DataFrame> {
+        let mut names = HashSet::new();
+        let mut listed = HashSet::new();
+        let mut columns = Vec::with_capacity(df.width());
+        for spec in &self.columns {
+            let name = spec.name.trim();
+            if name.is_empty() {
+                return Err(eyre!("Column '{}' needs a name", spec.source));
+            }
+            if !names.insert(name.to_string()) {
+                return Err(eyre!("Duplicate column name '{}'", name));
+            }
+            let source = df.column(&spec.source).map_err(|e| eyre!("{}", e))?;
+            listed.insert(spec.source.as_str());
+            let mut converted = convert(source, spec)?;
+            converted.rename(PlSmallStr::from_str(name));
+            columns.push(converted);
+        }
+        for column in df.get_columns() {
+            if listed.contains(column.name().as_str()) {
+                continue;
+            }
+            if !names.insert(column.name().to_string()) {
+                return Err(eyre!("Duplicate column name '{}'", column.name()));
+            }
+            columns.push(column.clone());
+        }
+        DataFrame::new(columns).map_err(|e| eyre!("Failed to build DataFrame: {}", e))
+    }
+}
+
+/// Values a cast would turn into nulls
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CastFailures {
+    pub column: String,
+    pub failed: usize,
+    /// A few of the values that failed, as text
+    pub examples: Vec<String>,
+}
+
+/// `column` converted as `spec` asks, under its original name
+fn convert(column: &Column, spec: &ColumnSchema) -> Result<Column> {
+    let Some(type_name) = &spec.dtype else { return Ok(column.clone()) };
+    let target = parse_dtype(type_name).ok_or_else(|| eyre!("Unsupported dtype: {}", type_name))?;
+    let reads_text = matches!(column.dtype(), DataType::String) && matches!(target, DataType::Datetime(..) | DataType::Date);
+    if !reads_text {
+        return column.cast_with_options(&target, CastOptions::NonStrict).map_err(|e| eyre!("{}", e));
+    }
+    // Text is parsed like the Parse Datetime column operation; a column where nothing parses
+    // is left all null rather than failing the whole edit
+    let values: Vec<Option<&str>> = column.str().map_err(|e| eyre!("{}", e))?.into_iter().collect();
+    let parsed = parse_datetime_values(&values, spec.datetime_format.as_deref())
+        .map(|(parsed, _)| parsed)
+        .unwrap_or_else(|_| vec![None; values.len()]);
+    let datetimes = Int64Chunked::from_iter_options(column.name().clone(), parsed.into_iter())
+        .into_datetime(TimeUnit::Microseconds, Some(TimeZone::UTC))
+        .into_series()
+        .into_column();
+    match target {
+        DataType::Date => datetimes.cast(&DataType::Date).map_err(|e| eyre!("{}", e)),
+        _ => Ok(datetimes),
+    }
+}
+
+/// Per cast column, the values that would become null; columns without failures are left out
+pub fn cast_failures(df: &DataFrame, edit: &SchemaEdit) -> Result<Vec<CastFailures>> {
+    let mut failures = Vec::new();
+    for spec in edit.columns.iter().filter(|c| c.dtype.is_some()) {
+        let source = df.column(&spec.source).map_err(|e| eyre!("{}", e))?;
+        let converted = convert(source, spec)?;
+        let lost = &source.is_not_null() & &converted.is_null();
+        let failed = lost.sum().unwrap_or(0) as usize;
+        if failed == 0 {
+            continue;
+        }
+        let lost_values = source.filter(&lost).map_err(|e| eyre!("{}", e))?;
+        let examples = (0..lost_values.len().min(MAX_EXAMPLES))
+            .filter_map(|i| lost_values.get(i).ok().map(|v| v.str_value().to_string()))
+            .collect();
+        failures.push(CastFailures { column: spec.source.clone(), failed, examples });
+    }
+    Ok(failures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cast_rename_reorder_and_failures() {
+        let df = df!(
+            "id" => ["1", "2", "x"],
+            "seen" => ["05/03/2024", "bad", "06/03/2024"],
+            "note" => ["a", "b", "c"]
+        )
+        .unwrap();
+        let mut edit = SchemaEdit::from_frame(&df);
+        edit.columns.swap(0, 2);
+        edit.columns[2].dtype = Some("Int64".to_string());
+        edit.columns[2].name = "row_id".to_string();
+        edit.columns[1].dtype = Some("Datetime".to_string());
+        edit.columns[1].datetime_format = Some("%d/%m/%Y".to_string());
+
+        let failures = cast_failures(&df, &edit).unwrap();
+        assert_eq!(failures, vec![
+            CastFailures { column: "seen".to_string(), failed: 1, examples: vec!["bad".to_string()] },
+            CastFailures { column: "id".to_string(), failed: 1, examples: vec!["x".to_string()] },
+        ]);
+
+        let out = edit.apply(&df).unwrap();
+        assert_eq!(out.get_column_names_owned(), vec!["note", "seen", "row_id"]);
+        assert_eq!(out.column("row_id").unwrap().dtype(), &DataType::Int64);
+        assert!(matches!(out.column("seen").unwrap().dtype(), DataType::Datetime(..)));
+        assert_eq!(out.column("seen").unwrap().null_count(), 1);
+        assert_eq!(edit.summary(), "cast seen as Datetime, id as Int64; rename id -> row_id");
+
+        edit.columns[0].name = "row_id".to_string();
+        assert!(edit.apply(&df).unwrap_err().to_string().contains("Duplicate column name 'row_id'"));
+    }
+}
@@ -0,0 +1,249 @@
+//! Rhai scripts for custom transforms
+//!
+//! A script works on the current DataFrame in one of two ways:
+//!
+//! - Row-wise: define `fn row(r)`. It is called once per row with a map of the row's values
+//!   and returns a map of output values, or `()` for no output from that row.
+//! - Column-wise: any other script. The columns are available as `df`, a map from column name
+//!   to an array of values, along with the row count as `height`. The script's last expression
+//!   is a map from output column name to an array of values.
+//!
+//! Outputs either become new columns on the current data (replacing columns with the same
+//! name) or a new dataset of just the output columns. Output maps are ordered by column name.
+//!
+//! ```rhai
+//! fn row(r) {
+//!     #{ domain: r.url.split("/")[2], slow: r.ms > 1000 }
+//! }
+//! ```
+
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use polars::prelude::*;
+use rhai::{Array, CallFnOptions, Dynamic, Engine, Map, Scope, AST};
+use serde::{Deserialize, Serialize};
+
+/// Name of the function that makes a script row-wise
+pub const ROW_FN: &str = "row";
+
+/// A script saved to the project for reuse
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedScript {
+    pub name: String,
+    pub source: String,
+}
+
+/// What a script's output becomes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScriptTarget {
+    /// New columns on the current data
+    AddColumns,
+    /// A new dataset of just the output columns
+    NewDataset,
+}
+
+fn to_dynamic(value: AnyValue) -> Dynamic {
+    match value {
+        AnyValue::Null => Dynamic::UNIT,
+        AnyValue::Boolean(b) => b.into(),
+        AnyValue::Float32(f) => (f as f64).into(),
+        AnyValue::Float64(f) => f.into(),
+        v if v.dtype().is_integer() => v.extract::<i64>().map(Dynamic::from).unwrap_or(Dynamic::UNIT),
+        v => v.str_value().to_string().into(),
+    }
+}
+
+/// Build a column from script values, typed by what the values hold: all integers become
+/// Int64, numbers Float64, booleans Boolean, anything else String. `()` is null.
+fn to_column(name: &str, values: Vec<Dynamic>) -> Column {
+    let present = || values.iter().filter(|v| !v.is_unit());
+    let name = PlSmallStr::from(name);
+    if present().next().is_some() && present().all(|v| v.is_int()) {
+        let data: Vec<Option<i64>> = values.iter().map(|v| v.as_int().ok()).collect();
+        Column::new(name, data)
+    } else if present().next().is_some() && present().all(|v| v.is_int() || v.is_float()) {
+        let data: Vec<Option<f64>> =
+            values.iter().map(|v| v.as_float().ok().or_else(|| v.as_int().ok().map(|i| i as f64))).collect();
+        Column::new(name, data)
+    } else if present().next().is_some() && present().all(|v| v.is_bool()) {
+        let data: Vec<Option<bool>> = values.iter().map(|v| v.as_bool().ok()).collect();
+        Column::new(name, data)
+    } else {
+        let data: Vec<Option<String>> = values
+            .into_iter()
+            .map(|v| if v.is_unit() { None } else if v.is_string() { v.into_string().ok() } else { Some(v.to_string()) })
+            .collect();
+        Column::new(name, data)
+    }
+}
+
+fn output_map(value: Dynamic, context: &str) -> Result<Map> {
+    let type_name = value.type_name();
+    value
+        .try_cast::<Map>()
+        .ok_or_else(|| eyre!("{context} must be a map of column names to values, got {type_name}"))
+}
+
+fn compile(engine: &Engine, source: &str) -> Result<AST> {
+    engine.compile(source).map_err(|e| eyre!("Script error: {e}"))
+}
+
+/// Whether `ast` defines the row function
+fn is_row_wise(ast: &AST) -> bool {
+    ast.iter_functions().any(|f| f.name == ROW_FN && f.params.len() == 1)
+}
+
+/// Output columns of a row-wise script. Rows returning `()` are null in every output column
+/// when adding columns, and left out of a new dataset.
+fn run_rows(engine: &Engine, ast: &AST, df: &DataFrame, target: ScriptTarget) -> Result<Vec<Column>> {
+    let columns = df.get_columns();
+    let mut outputs: Vec<(String, Vec<Dynamic>)> = Vec::new();
+    let mut out_rows = 0;
+    for i in 0..df.height() {
+        let mut row = Map::new();
+        for column in columns {
+            row.insert(column.name().as_str().into(), to_dynamic(column.get(i)?));
+        }
+        let result = engine
+            .call_fn_with_options::<Dynamic>(CallFnOptions::new().eval_ast(false), &mut Scope::new(), ast, ROW_FN, (row,))
+            .map_err(|e| eyre!("Script error at row {}: {e}", i + 1))?;
+        if result.is_unit() {
+            if target == ScriptTarget::AddColumns {
+                out_rows += 1;
+                for (_, values) in &mut outputs {
+                    values.push(Dynamic::UNIT);
+                }
+            }
+            continue;
+        }
+        let map = output_map(result, &format!("row() result at row {}", i + 1))?;
+        for (key, value) in map {
+            if !outputs.iter().any(|(name, _)| name == key.as_str()) {
+                outputs.push((key.to_string(), vec![Dynamic::UNIT; out_rows]));
+            }
+            outputs.iter_mut().find(|(name, _)| name == key.as_str()).unwrap().1.push(value);
+        }
+        out_rows += 1;
+        for (_, values) in &mut outputs {
+            values.resize(out_rows, Dynamic::UNIT);
+        }
+    }
+    Ok(outputs.into_iter().map(|(name, values)| to_column(&name, values)).collect())
+}
+
+/// Output columns of a column-wise script
+fn run_columns(engine: &Engine, ast: &AST, df: &DataFrame) -> Result<Vec<Column>> {
+    let mut input = Map::new();
+    for column in df.get_columns() {
+        let values: Array = (0..column.len()).map(|i| column.get(i).map(to_dynamic)).collect::<PolarsResult<_>>()?;
+        input.insert(column.name().as_str().into(), values.into());
+    }
+    let mut scope = Scope::new();
+    scope.push_constant("df", input);
+    scope.push_constant("height", df.height() as i64);
+    let result = engine.eval_ast_with_scope::<Dynamic>(&mut scope, ast).map_err(|e| eyre!("Script error: {e}"))?;
+    let map = output_map(result, "The script result")?;
+    map.into_iter()
+        .map(|(name, values)| {
+            let type_name = values.type_name();
+            let values = values
+                .into_array()
+                .map_err(|_| eyre!("Output column '{name}' must be an array, got {type_name}"))?;
+            Ok(to_column(&name, values))
+        })
+        .collect()
+}
+
+/// Run `source` over `df`. With `AddColumns` the result is `df` with the output columns added;
+/// with `NewDataset` it is the output columns alone.
+pub fn run_script(df: &DataFrame, source: &str, target: ScriptTarget) -> Result<DataFrame> {
+    run_script_cancellable(df, source, target, || false)
+}
+
+/// Like `run_script`, for a worker thread: `is_cancelled` is checked between script operations,
+/// so a script that never returns (e.g. `loop {}`) can still be stopped.
+pub fn run_script_cancellable(
+    df: &DataFrame,
+    source: &str,
+    target: ScriptTarget,
+    is_cancelled: impl Fn() -> bool + 'static,
+) -> Result<DataFrame> {
+    let mut engine = Engine::new();
+    engine.on_progress(move |_| is_cancelled().then(|| Dynamic::from("Cancelled")));
+    let ast = compile(&engine, source)?;
+    let outputs = if is_row_wise(&ast) { run_rows(&engine, &ast, df, target)? } else { run_columns(&engine, &ast, df)? };
+    if outputs.is_empty() {
+        return Err(eyre!("The script produced no columns"));
+    }
+    match target {
+        ScriptTarget::AddColumns => {
+            let mut result = df.clone();
+            for column in outputs {
+                if column.len() != df.height() {
+                    return Err(eyre!(
+                        "Output column '{}' has {} values; the data has {} rows",
+                        column.name(),
+                        column.len(),
+                        df.height()
+                    ));
+                }
+                result.with_column(column)?;
+            }
+            Ok(result)
+        }
+        ScriptTarget::NewDataset => {
+            DataFrame::new(outputs).map_err(|e| eyre!("Output columns must have the same length: {e}"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> DataFrame {
+        df!(
+            "url" => ["https://a.example/x", "https://b.example/y", "https://a.example/z"],
+            "ms" => [120i64, 2400, 800]
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_row_script_adds_columns_and_filters_new_dataset() {
+        let source = r#"
+            fn row(r) {
+                if r.ms < 200 { return (); }
+                #{ domain: r.url.split("/")[2], slow: r.ms > 1000, seconds: r.ms / 1000.0 }
+            }
+        "#;
+        let added = run_script(&sample(), source, ScriptTarget::AddColumns).unwrap();
+        assert_eq!(added.get_column_names(), ["url", "ms", "domain", "seconds", "slow"]);
+        assert_eq!(added.column("domain").unwrap().get(0).unwrap(), AnyValue::Null);
+        assert_eq!(added.column("domain").unwrap().get(1).unwrap().str_value(), "b.example");
+        assert_eq!(added.column("slow").unwrap().dtype(), &DataType::Boolean);
+        assert_eq!(added.column("seconds").unwrap().dtype(), &DataType::Float64);
+
+        let new = run_script(&sample(), source, ScriptTarget::NewDataset).unwrap();
+        assert_eq!(new.height(), 2);
+        assert_eq!(new.width(), 3);
+    }
+
+    #[test]
+    fn test_column_script_and_errors() {
+        let source = r#"
+            let total = 0;
+            for v in df.ms { total += v; }
+            #{ ms_share: df.ms.map(|v| v * 100 / total), rank: [3, 1, 2] }
+        "#;
+        let added = run_script(&sample(), source, ScriptTarget::AddColumns).unwrap();
+        assert_eq!(added.column("ms_share").unwrap().dtype(), &DataType::Int64);
+        assert_eq!(added.column("ms_share").unwrap().get(1).unwrap().str_value(), "72");
+
+        let err = run_script(&sample(), "#{ short: [1] }", ScriptTarget::AddColumns).unwrap_err();
+        assert!(err.to_string().contains("has 1 values"));
+        assert!(run_script(&sample(), "42", ScriptTarget::NewDataset).is_err());
+        assert!(run_script(&sample(), "fn row(r) {", ScriptTarget::NewDataset).is_err());
+        assert!(run_script_cancellable(&sample(), "loop {}", ScriptTarget::NewDataset, || true).is_err());
+    }
+}
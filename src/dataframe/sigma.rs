@@ -0,0 +1,667 @@
+//! Sigma rule evaluation: load detection rules from YAML, map their fields to dataset columns and
+//! mark the rows each rule matches.
+//!
+//! Supported: field selections (maps and lists of maps), keyword lists, the `contains`,
+//! `startswith`, `endswith`, `re` and `all` modifiers, `*`/`?` wildcards, and conditions built
+//! from `and`, `or`, `not`, parentheses, `1 of` / `all of` a pattern or `them`. Aggregations
+//! (`| count()`) and encoding modifiers are reported as unsupported and the rule is skipped.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use polars::prelude::*;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_yaml::Value;
+
+pub const DEFAULT_SIGMA_COLUMN: &str = "sigma_matches";
+
+/// Where matched rule titles go
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum SigmaOutput {
+    /// Append a column to the current data
+    #[default]
+    Column,
+    /// Open the matching rows as a new dataset
+    Dataset,
+}
+
+impl SigmaOutput {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SigmaOutput::Column => "Add Column",
+            SigmaOutput::Dataset => "Matched Rows as New Dataset",
+        }
+    }
+
+    pub fn toggle(&self) -> SigmaOutput {
+        match self {
+            SigmaOutput::Column => SigmaOutput::Dataset,
+            SigmaOutput::Dataset => SigmaOutput::Column,
+        }
+    }
+}
+
+/// User-facing options for a Sigma run
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct SigmaOptions {
+    /// A rule file, or a directory searched recursively for `.yml`/`.yaml` files
+    pub rules_path: String,
+    /// `SigmaField=column` pairs separated by commas or newlines; unmapped fields are matched
+    /// to columns by name, ignoring case
+    pub field_mapping: String,
+    pub output: SigmaOutput,
+    /// Name of the matches column; empty uses `sigma_matches`
+    pub column_name: String,
+}
+
+impl SigmaOptions {
+    /// Lowercased Sigma field → column
+    pub fn mapping(&self) -> Result<HashMap<String, String>> {
+        self.field_mapping
+            .split([',', '\n'])
+            .map(str::trim)
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (field, column) = pair
+                    .split_once('=')
+                    .ok_or_else(|| eyre!("Field mapping '{}' must look like SigmaField=column", pair))?;
+                Ok((field.trim().to_lowercase(), column.trim().to_string()))
+            })
+            .collect()
+    }
+
+    pub fn column_name(&self) -> String {
+        let name = self.column_name.trim();
+        if name.is_empty() { DEFAULT_SIGMA_COLUMN.to_string() } else { name.to_string() }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Matcher {
+    Pattern(Regex),
+    /// A `null` value: matches null and empty cells
+    Null,
+}
+
+/// One `field|modifiers: values` entry; `field` is None for keywords, which search every text column
+#[derive(Debug, Clone)]
+struct FieldTest {
+    field: Option<String>,
+    matchers: Vec<Matcher>,
+    /// `|all`: every value must match instead of any
+    all: bool,
+}
+
+/// OR over alternatives, each an AND of field tests
+#[derive(Debug, Clone)]
+struct Selection {
+    alternatives: Vec<Vec<FieldTest>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Condition {
+    Ref(String),
+    Not(Box<Condition>),
+    And(Vec<Condition>),
+    Or(Vec<Condition>),
+    /// `1 of pattern`; `them` is the pattern `*`
+    AnyOf(String),
+    AllOf(String),
+}
+
+/// A parsed rule, ready to evaluate
+#[derive(Debug, Clone)]
+pub struct SigmaRule {
+    pub title: String,
+    pub level: Option<String>,
+    selections: Vec<(String, Selection)>,
+    condition: Condition,
+}
+
+/// Regex for a Sigma wildcard pattern: `*` and `?` are wildcards, `\` escapes them. Matching
+/// ignores case, like Sigma's default string matching.
+fn wildcard_regex(pattern: &str, anchor_start: bool, anchor_end: bool) -> Result<Regex> {
+    let mut out = String::from("(?is)");
+    if anchor_start {
+        out.push('^');
+    }
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' => out.push_str(".*"),
+            '?' => out.push('.'),
+            '\\' if matches!(chars.peek(), Some('*' | '?' | '\\')) => {
+                out.push_str(&regex::escape(&chars.next().unwrap_or('\\').to_string()))
+            }
+            c => out.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    if anchor_end {
+        out.push('$');
+    }
+    Regex::new(&out).map_err(|e| eyre!("Invalid pattern '{}': {}", pattern, e))
+}
+
+fn scalar_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+fn parse_field_test(key: &str, value: &Value) -> Result<FieldTest> {
+    let mut parts = key.split('|');
+    let field = parts.next().unwrap_or_default().trim().to_string();
+    let (mut anchor_start, mut anchor_end, mut is_regex, mut all) = (true, true, false, false);
+    for modifier in parts {
+        match modifier.trim() {
+            "contains" => (anchor_start, anchor_end) = (false, false),
+            "startswith" => anchor_end = false,
+            "endswith" => anchor_start = false,
+            "re" => is_regex = true,
+            "all" => all = true,
+            other => return Err(eyre!("Unsupported modifier '{}' on field '{}'", other, field)),
+        }
+    }
+    let values: Vec<&Value> = match value {
+        Value::Sequence(items) => items.iter().collect(),
+        other => vec![other],
+    };
+    let matchers = values
+        .into_iter()
+        .map(|v| match v {
+            Value::Null => Ok(Matcher::Null),
+            v => {
+                let text = scalar_text(v).ok_or_else(|| eyre!("Field '{}' has a value that is not text or a number", field))?;
+                let regex = if is_regex {
+                    Regex::new(&text).map_err(|e| eyre!("Invalid regex '{}': {}", text, e))?
+                } else {
+                    wildcard_regex(&text, anchor_start, anchor_end)?
+                };
+                Ok(Matcher::Pattern(regex))
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(FieldTest { field: Some(field), matchers, all })
+}
+
+fn keyword_test(values: &[Value]) -> Result<FieldTest> {
+    let matchers = values
+        .iter()
+        .map(|v| {
+            let text = scalar_text(v).ok_or_else(|| eyre!("Keyword lists may only hold text"))?;
+            Ok(Matcher::Pattern(wildcard_regex(&text, false, false)?))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(FieldTest { field: None, matchers, all: false })
+}
+
+fn parse_map(map: &serde_yaml::Mapping) -> Result<Vec<FieldTest>> {
+    map.iter()
+        .map(|(k, v)| {
+            let key = k.as_str().ok_or_else(|| eyre!("Selection keys must be field names"))?;
+            parse_field_test(key, v)
+        })
+        .collect()
+}
+
+fn parse_selection(value: &Value) -> Result<Selection> {
+    let alternatives = match value {
+        Value::Mapping(map) => vec![parse_map(map)?],
+        Value::Sequence(items) if items.iter().all(Value::is_mapping) => items
+            .iter()
+            .filter_map(Value::as_mapping)
+            .map(parse_map)
+            .collect::<Result<Vec<_>>>()?,
+        Value::Sequence(items) => vec![vec![keyword_test(items)?]],
+        scalar => vec![vec![keyword_test(std::slice::from_ref(scalar))?]],
+    };
+    Ok(Selection { alternatives })
+}
+
+fn tokenize(condition: &str) -> Vec<String> {
+    condition
+        .replace('(', " ( ")
+        .replace(')', " ) ")
+        .split_whitespace()
+        .map(str::to_string)
+        .collect()
+}
+
+struct ConditionParser {
+    tokens: Vec<String>,
+    pos: usize,
+}
+
+impl ConditionParser {
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        self.tokens.get(self.pos).is_some_and(|t| t.eq_ignore_ascii_case(keyword))
+    }
+
+    fn next(&mut self) -> Option<String> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn parse_or(&mut self) -> Result<Condition> {
+        let mut terms = vec![self.parse_and()?];
+        while self.peek_keyword("or") {
+            self.pos += 1;
+            terms.push(self.parse_and()?);
+        }
+        Ok(if terms.len() == 1 { terms.remove(0) } else { Condition::Or(terms) })
+    }
+
+    fn parse_and(&mut self) -> Result<Condition> {
+        let mut terms = vec![self.parse_not()?];
+        while self.peek_keyword("and") {
+            self.pos += 1;
+            terms.push(self.parse_not()?);
+        }
+        Ok(if terms.len() == 1 { terms.remove(0) } else { Condition::And(terms) })
+    }
+
+    fn parse_not(&mut self) -> Result<Condition> {
+        if self.peek_keyword("not") {
+            self.pos += 1;
+            return Ok(Condition::Not(Box::new(self.parse_not()?)));
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<Condition> {
+        let token = self.next().ok_or_else(|| eyre!("Condition ends unexpectedly"))?;
+        if token == "(" {
+            let inner = self.parse_or()?;
+            return match self.next().as_deref() {
+                Some(")") => Ok(inner),
+                _ => Err(eyre!("Missing ')' in condition")),
+            };
+        }
+        let quantifier = token.to_lowercase();
+        if matches!(quantifier.as_str(), "1" | "any" | "all") && self.peek_keyword("of") {
+            self.pos += 1;
+            let target = self.next().ok_or_else(|| eyre!("'{} of' needs a selection pattern", token))?;
+            let pattern = if target.eq_ignore_ascii_case("them") { "*".to_string() } else { target };
+            return Ok(if quantifier == "all" { Condition::AllOf(pattern) } else { Condition::AnyOf(pattern) });
+        }
+        if token == "|" || token.starts_with('|') {
+            return Err(eyre!("Aggregation conditions are not supported"));
+        }
+        Ok(Condition::Ref(token))
+    }
+}
+
+fn parse_condition(condition: &str) -> Result<Condition> {
+    if condition.contains('|') {
+        return Err(eyre!("Aggregation conditions are not supported"));
+    }
+    let mut parser = ConditionParser { tokens: tokenize(condition), pos: 0 };
+    let parsed = parser.parse_or()?;
+    if parser.pos < parser.tokens.len() {
+        return Err(eyre!("Unexpected '{}' in condition", parser.tokens[parser.pos]));
+    }
+    Ok(parsed)
+}
+
+/// Parse one rule document
+pub fn parse_rule(doc: &Value) -> Result<SigmaRule> {
+    let title = doc.get("title").and_then(Value::as_str).unwrap_or("Untitled rule").to_string();
+    let level = doc.get("level").and_then(Value::as_str).map(str::to_string);
+    let detection = doc
+        .get("detection")
+        .and_then(Value::as_mapping)
+        .ok_or_else(|| eyre!("Rule '{}' has no detection section", title))?;
+    let mut selections = Vec::new();
+    let mut conditions = Vec::new();
+    for (key, value) in detection {
+        let key = key.as_str().unwrap_or_default();
+        match key {
+            "condition" => match value {
+                Value::Sequence(items) => conditions.extend(items.iter().filter_map(Value::as_str).map(str::to_string)),
+                other => conditions.extend(other.as_str().map(str::to_string)),
+            },
+            "timeframe" => {}
+            name => selections.push((name.to_string(), parse_selection(value).map_err(|e| eyre!("Rule '{}': {}", title, e))?)),
+        }
+    }
+    if conditions.is_empty() {
+        return Err(eyre!("Rule '{}' has no condition", title));
+    }
+    let mut parsed = conditions
+        .iter()
+        .map(|c| parse_condition(c))
+        .collect::<Result<Vec<_>>>()
+        .map_err(|e| eyre!("Rule '{}': {}", title, e))?;
+    let condition = if parsed.len() == 1 { parsed.remove(0) } else { Condition::Or(parsed) };
+    Ok(SigmaRule { title, level, selections, condition })
+}
+
+fn rule_files(path: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    if path.is_dir() {
+        let mut entries: Vec<PathBuf> = std::fs::read_dir(path)?.filter_map(|e| e.ok().map(|e| e.path())).collect();
+        entries.sort();
+        for entry in entries {
+            rule_files(&entry, files)?;
+        }
+    } else if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("yml") || ext.eq_ignore_ascii_case("yaml")) {
+        files.push(path.to_path_buf());
+    }
+    Ok(())
+}
+
+/// Every rule under `path`, plus a warning per document that could not be used
+pub fn load_rules(path: &Path) -> Result<(Vec<SigmaRule>, Vec<String>)> {
+    if !path.exists() {
+        return Err(eyre!("Rules path '{}' does not exist", path.display()));
+    }
+    let mut files = Vec::new();
+    if path.is_file() {
+        files.push(path.to_path_buf());
+    } else {
+        rule_files(path, &mut files)?;
+    }
+    let mut rules = Vec::new();
+    let mut warnings = Vec::new();
+    for file in files {
+        let text = std::fs::read_to_string(&file).map_err(|e| eyre!("Failed to read {}: {}", file.display(), e))?;
+        for doc in serde_yaml::Deserializer::from_str(&text) {
+            match Value::deserialize(doc) {
+                // Collection headers (action: global etc.) carry no detection
+                Ok(value) if value.get("detection").is_none() => {}
+                Ok(value) => match parse_rule(&value) {
+                    Ok(rule) => rules.push(rule),
+                    Err(e) => warnings.push(format!("{}: {}", file.display(), e)),
+                },
+                Err(e) => warnings.push(format!("{}: {}", file.display(), e)),
+            }
+        }
+    }
+    if rules.is_empty() {
+        return Err(match warnings.first() {
+            Some(first) => eyre!("No usable Sigma rules in '{}' ({})", path.display(), first),
+            None => eyre!("No Sigma rules found in '{}'", path.display()),
+        });
+    }
+    Ok((rules, warnings))
+}
+
+/// Rows matched per rule and the per-row titles
+#[derive(Debug, Clone, Default)]
+pub struct SigmaEvaluation {
+    /// Titles of the rules each row matched, joined with "; "
+    pub matches: Vec<Option<String>>,
+    /// (rule title, matched rows) for every rule that was evaluated
+    pub rule_hits: Vec<(String, usize)>,
+    /// Rules skipped because a field has no column, or that failed to parse
+    pub warnings: Vec<String>,
+}
+
+impl SigmaEvaluation {
+    pub fn matched_rows(&self) -> usize {
+        self.matches.iter().filter(|m| m.is_some()).count()
+    }
+}
+
+impl fmt::Display for SigmaEvaluation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} rule(s) evaluated, {} row(s) matched", self.rule_hits.len(), self.matched_rows())?;
+        let mut hits: Vec<&(String, usize)> = self.rule_hits.iter().filter(|(_, n)| *n > 0).collect();
+        hits.sort_by_key(|(_, n)| std::cmp::Reverse(*n));
+        if !hits.is_empty() {
+            writeln!(f)?;
+            for (title, n) in hits {
+                write!(f, "\n  {title}: {n}")?;
+            }
+        }
+        if !self.warnings.is_empty() {
+            write!(f, "\n\nSkipped:")?;
+            for warning in &self.warnings {
+                write!(f, "\n  {warning}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Cell text per column, cast once and shared by every rule
+struct ColumnTexts<'a> {
+    df: &'a DataFrame,
+    mapping: &'a HashMap<String, String>,
+    cache: HashMap<String, Vec<Option<String>>>,
+}
+
+impl ColumnTexts<'_> {
+    fn resolve(&self, field: &str) -> Option<String> {
+        if let Some(column) = self.mapping.get(&field.to_lowercase()) {
+            return self.df.column(column).is_ok().then(|| column.clone());
+        }
+        self.df
+            .get_column_names()
+            .into_iter()
+            .find(|c| c.as_str().eq_ignore_ascii_case(field))
+            .map(|c| c.to_string())
+    }
+
+    fn texts(&mut self, column: &str) -> Result<&Vec<Option<String>>> {
+        if !self.cache.contains_key(column) {
+            let cast = self.df.column(column).map_err(|e| eyre!("{}", e))?.cast(&DataType::String).map_err(|e| eyre!("{}", e))?;
+            let texts = cast.str().map_err(|e| eyre!("{}", e))?.into_iter().map(|v| v.map(str::to_string)).collect();
+            self.cache.insert(column.to_string(), texts);
+        }
+        Ok(&self.cache[column])
+    }
+
+    fn text_columns(&self) -> Vec<String> {
+        self.df
+            .get_columns()
+            .iter()
+            .filter(|c| c.dtype() == &DataType::String)
+            .map(|c| c.name().to_string())
+            .collect()
+    }
+}
+
+fn matcher_hits(matcher: &Matcher, value: Option<&str>) -> bool {
+    match matcher {
+        Matcher::Null => value.is_none_or(str::is_empty),
+        Matcher::Pattern(regex) => value.is_some_and(|v| regex.is_match(v)),
+    }
+}
+
+fn test_mask(test: &FieldTest, texts: &mut ColumnTexts, rows: usize) -> Result<Vec<bool>> {
+    let columns = match &test.field {
+        Some(field) => vec![texts.resolve(field).ok_or_else(|| eyre!("no column for field '{}'", field))?],
+        None => texts.text_columns(),
+    };
+    let mut mask = vec![false; rows];
+    for column in columns {
+        let values = texts.texts(&column)?;
+        for (hit, value) in mask.iter_mut().zip(values) {
+            let value = value.as_deref();
+            let matched = if test.all {
+                test.matchers.iter().all(|m| matcher_hits(m, value))
+            } else {
+                test.matchers.iter().any(|m| matcher_hits(m, value))
+            };
+            *hit |= matched;
+        }
+    }
+    Ok(mask)
+}
+
+fn selection_mask(selection: &Selection, texts: &mut ColumnTexts, rows: usize) -> Result<Vec<bool>> {
+    let mut mask = vec![false; rows];
+    for alternative in &selection.alternatives {
+        let mut alt = vec![true; rows];
+        for test in alternative {
+            for (a, t) in alt.iter_mut().zip(test_mask(test, texts, rows)?) {
+                *a &= t;
+            }
+        }
+        for (m, a) in mask.iter_mut().zip(alt) {
+            *m |= a;
+        }
+    }
+    Ok(mask)
+}
+
+fn condition_mask(condition: &Condition, masks: &HashMap<&str, Vec<bool>>, rows: usize) -> Result<Vec<bool>> {
+    let combine = |terms: Vec<Vec<bool>>, and: bool| {
+        (0..rows).map(|i| if and { terms.iter().all(|t| t[i]) } else { terms.iter().any(|t| t[i]) }).collect()
+    };
+    let matching = |pattern: &str| -> Result<Vec<Vec<bool>>> {
+        let re = wildcard_regex(pattern, true, true)?;
+        Ok(masks.iter().filter(|(name, _)| re.is_match(name)).map(|(_, m)| m.clone()).collect())
+    };
+    Ok(match condition {
+        Condition::Ref(name) => masks.get(name.as_str()).cloned().ok_or_else(|| eyre!("condition names unknown selection '{}'", name))?,
+        Condition::Not(inner) => condition_mask(inner, masks, rows)?.into_iter().map(|b| !b).collect(),
+        Condition::And(terms) => combine(terms.iter().map(|t| condition_mask(t, masks, rows)).collect::<Result<_>>()?, true),
+        Condition::Or(terms) => combine(terms.iter().map(|t| condition_mask(t, masks, rows)).collect::<Result<_>>()?, false),
+        Condition::AnyOf(pattern) => combine(matching(pattern)?, false),
+        Condition::AllOf(pattern) => {
+            let terms = matching(pattern)?;
+            if terms.is_empty() { vec![false; rows] } else { combine(terms, true) }
+        }
+    })
+}
+
+/// Evaluate every rule against `df`. A rule whose fields cannot all be mapped to columns is
+/// skipped with a warning rather than failing the run.
+pub fn evaluate_rules(df: &DataFrame, rules: &[SigmaRule], mapping: &HashMap<String, String>) -> Result<SigmaEvaluation> {
+    let rows = df.height();
+    let mut texts = ColumnTexts { df, mapping, cache: HashMap::new() };
+    let mut titles: Vec<Vec<&str>> = vec![Vec::new(); rows];
+    let mut evaluation = SigmaEvaluation::default();
+    'rules: for rule in rules {
+        let mut masks: HashMap<&str, Vec<bool>> = HashMap::new();
+        for (name, selection) in &rule.selections {
+            match selection_mask(selection, &mut texts, rows) {
+                Ok(mask) => {
+                    masks.insert(name.as_str(), mask);
+                }
+                Err(e) => {
+                    evaluation.warnings.push(format!("{}: {}", rule.title, e));
+                    continue 'rules;
+                }
+            }
+        }
+        let mask = match condition_mask(&rule.condition, &masks, rows) {
+            Ok(mask) => mask,
+            Err(e) => {
+                evaluation.warnings.push(format!("{}: {}", rule.title, e));
+                continue;
+            }
+        };
+        let mut hits = 0;
+        for (row, matched) in mask.into_iter().enumerate() {
+            if matched {
+                titles[row].push(&rule.title);
+                hits += 1;
+            }
+        }
+        evaluation.rule_hits.push((rule.title.clone(), hits));
+    }
+    evaluation.matches = titles.into_iter().map(|t| (!t.is_empty()).then(|| t.join("; "))).collect();
+    Ok(evaluation)
+}
+
+/// Load the rules, evaluate them and build the output: `df` with the matches column added, or
+/// only the matched rows (with the column) for `SigmaOutput::Dataset`
+pub fn run_sigma(df: &DataFrame, options: &SigmaOptions) -> Result<(DataFrame, SigmaEvaluation)> {
+    let mapping = options.mapping()?;
+    let (rules, load_warnings) = load_rules(Path::new(options.rules_path.trim()))?;
+    let mut evaluation = evaluate_rules(df, &rules, &mapping)?;
+    evaluation.warnings.splice(0..0, load_warnings);
+    let column = Series::new(options.column_name().as_str().into(), evaluation.matches.clone());
+    let mut out = df.clone();
+    out.with_column(column).map_err(|e| eyre!("Failed to add matches column: {}", e))?;
+    if options.output == SigmaOutput::Dataset {
+        let matched: BooleanChunked = evaluation.matches.iter().map(|m| Some(m.is_some())).collect();
+        out = out.filter(&matched).map_err(|e| eyre!("Failed to select matched rows: {}", e))?;
+    }
+    Ok((out, evaluation))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RULES: &str = r#"
+title: Encoded PowerShell
+level: high
+detection:
+    selection:
+        Image|endswith: '\powershell.exe'
+        CommandLine|contains:
+            - ' -enc '
+            - ' -EncodedCommand '
+    condition: selection
+---
+title: Cmd without parent
+detection:
+    sel_cmd:
+        Image|endswith: '\cmd.exe'
+    sel_parent:
+        ParentImage: null
+    filter:
+        User: 'SYSTEM'
+    condition: all of sel_* and not filter
+---
+title: Mimikatz keyword
+detection:
+    keywords:
+        - 'sekurlsa::*'
+    condition: keywords
+---
+title: Unmapped
+detection:
+    selection:
+        TargetFilename: 'x'
+    condition: selection
+"#;
+
+    #[test]
+    fn test_rules_mapping_and_output() {
+        let df = df!(
+            "process" => ["C:\\Windows\\powershell.exe", "C:\\Windows\\System32\\cmd.exe", "C:\\Windows\\System32\\cmd.exe", "notepad.exe"],
+            "cmdline" => ["powershell -ENC aGk=", "cmd /c whoami", "cmd", "notepad sekurlsa::logonpasswords"],
+            "parent" => [Some("explorer.exe"), None, Some(""), Some("explorer.exe")],
+            "user" => ["alice", "bob", "SYSTEM", "alice"]
+        )
+        .unwrap();
+        let dir = std::env::temp_dir().join(format!("datatui_sigma_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("rules.yml"), RULES).unwrap();
+        let mut options = SigmaOptions {
+            rules_path: dir.display().to_string(),
+            field_mapping: "Image=process, CommandLine=cmdline\nParentImage=parent".to_string(),
+            ..SigmaOptions::default()
+        };
+
+        let (out, evaluation) = run_sigma(&df, &options).unwrap();
+        let matches: Vec<Option<&str>> = out.column(DEFAULT_SIGMA_COLUMN).unwrap().str().unwrap().into_iter().collect();
+        assert_eq!(matches, [Some("Encoded PowerShell"), Some("Cmd without parent"), None, Some("Mimikatz keyword")]);
+        assert_eq!(evaluation.rule_hits.len(), 3);
+        assert_eq!(evaluation.warnings.len(), 1);
+        assert!(evaluation.warnings[0].contains("TargetFilename"));
+
+        options.output = SigmaOutput::Dataset;
+        options.column_name = "hits".to_string();
+        let (out, _) = run_sigma(&df, &options).unwrap();
+        assert_eq!(out.height(), 3);
+        assert!(out.column("hits").is_ok());
+
+        assert!(parse_condition("selection | count() > 5").is_err());
+        assert!(SigmaOptions { field_mapping: "Image".to_string(), ..SigmaOptions::default() }.mapping().is_err());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,90 @@
+//! Out-of-core results: large intermediate DataFrames are written to a temporary Parquet file
+//! and scanned lazily, so only the rows on screen are held in memory.
+
+use std::fs::{self, File};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use color_eyre::Result;
+use polars::prelude::*;
+use tracing::warn;
+use uuid::Uuid;
+
+/// Folder under the system temp directory holding spilled results
+const SPILL_DIR: &str = "datatui-spill";
+
+/// A temporary Parquet file, deleted when dropped
+#[derive(Debug)]
+pub struct SpillFile {
+    pub path: PathBuf,
+}
+
+impl SpillFile {
+    /// Lazy scan of the file's rows
+    pub fn scan(&self) -> Result<LazyFrame> {
+        Ok(LazyFrame::scan_parquet(&self.path, ScanArgsParquet::default())?)
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        if let Err(e) = fs::remove_file(&self.path) {
+            warn!("Failed to remove spill file {}: {}", self.path.display(), e);
+        }
+    }
+}
+
+fn new_spill_file() -> Result<SpillFile> {
+    let dir = std::env::temp_dir().join(SPILL_DIR);
+    fs::create_dir_all(&dir)?;
+    Ok(SpillFile { path: dir.join(format!("{}.parquet", Uuid::new_v4())) })
+}
+
+/// Write `df` to a new spill file and return a lazy scan of it. The scan reads the file, so
+/// the returned `SpillFile` must outlive any plan built on it.
+pub fn spill_to_parquet(mut df: DataFrame) -> Result<(LazyFrame, SpillFile)> {
+    let spill = new_spill_file()?;
+    ParquetWriter::new(File::create(&spill.path)?).finish(&mut df)?;
+    Ok((spill.scan()?, spill))
+}
+
+/// Run `plan` on the streaming engine straight into a new spill file, so its result is never
+/// held in memory as a whole, and return a lazy scan of the file.
+pub fn sink_to_parquet(plan: LazyFrame) -> Result<(LazyFrame, SpillFile)> {
+    let spill = new_spill_file()?;
+    plan.sink_parquet(
+        SinkTarget::Path(Arc::new(spill.path.clone())),
+        ParquetWriteOptions::default(),
+        None,
+        SinkOptions::default(),
+    )?
+    .collect()?;
+    Ok((spill.scan()?, spill))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spill_roundtrip_and_cleanup() {
+        let df = df!("n" => (0..500i64).collect::<Vec<_>>(), "s" => (0..500).map(|i| format!("row {i}")).collect::<Vec<_>>()).unwrap();
+        let (scan, spill) = spill_to_parquet(df.clone()).unwrap();
+        let path = spill.path.clone();
+        assert!(path.exists());
+        assert!(scan.slice(0, 500).collect().unwrap().equals(&df));
+        drop(spill);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_sink_streams_plan_to_file() {
+        let df = df!("n" => (0..500i64).collect::<Vec<_>>()).unwrap();
+        let plan = df.lazy().filter(col("n").gt(lit(249))).sort(["n"], SortMultipleOptions::default().with_order_descending(true));
+        let (scan, spill) = sink_to_parquet(plan).unwrap();
+        assert!(spill.path.exists());
+        let rows = scan.collect().unwrap();
+        assert_eq!(rows.height(), 250);
+        assert_eq!(rows.column("n").unwrap().get(0).unwrap(), AnyValue::Int64(499));
+    }
+}
@@ -0,0 +1,88 @@
+//! Event-density bins over a datetime column, backing the timeline panel.
+
+use chrono::DateTime;
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use polars::prelude::*;
+
+/// Format used for range bounds handed to the filter; one of the auto-detected datetime formats
+pub const TIMELINE_BOUND_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.6f";
+
+/// Equal-width event counts between the earliest and latest timestamp (epoch microseconds)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimelineBins {
+    pub start: i64,
+    pub end: i64,
+    pub counts: Vec<u64>,
+}
+
+impl TimelineBins {
+    /// Time span covered by bins `first..=last`
+    pub fn range(&self, first: usize, last: usize) -> (i64, i64) {
+        let n = self.counts.len().max(1) as i128;
+        let span = (self.end - self.start) as i128;
+        let edge = |i: usize| self.start + (span * i as i128 / n) as i64;
+        let (first, last) = (first.min(last), first.max(last));
+        let hi = if last + 1 >= self.counts.len() { self.end } else { edge(last + 1) };
+        (edge(first), hi)
+    }
+
+    pub fn max_count(&self) -> u64 {
+        self.counts.iter().copied().max().unwrap_or(0)
+    }
+}
+
+/// Microseconds since the epoch as `TIMELINE_BOUND_FORMAT` (UTC)
+pub fn format_micros(micros: i64) -> String {
+    DateTime::from_timestamp_micros(micros)
+        .map(|dt| dt.naive_utc().format(TIMELINE_BOUND_FORMAT).to_string())
+        .unwrap_or_default()
+}
+
+/// Non-null values of a Datetime or Date column as epoch microseconds
+pub fn datetime_micros(column: &Column) -> Result<Vec<i64>> {
+    let micros = match column.dtype() {
+        DataType::Datetime(_, tz) => column.cast(&DataType::Datetime(TimeUnit::Microseconds, tz.clone())),
+        DataType::Date => column.cast(&DataType::Datetime(TimeUnit::Microseconds, None)),
+        other => return Err(eyre!("'{}' is {}, not a datetime column", column.name(), other)),
+    }
+    .and_then(|c| c.cast(&DataType::Int64))
+    .map_err(|e| eyre!("{}", e))?;
+    Ok(micros.i64().map_err(|e| eyre!("{}", e))?.into_iter().flatten().collect())
+}
+
+/// Count events of `column` into `bins` equal-width buckets
+pub fn timeline_bins(column: &Column, bins: usize) -> Result<TimelineBins> {
+    let values = datetime_micros(column)?;
+    let (Some(&start), Some(&end)) = (values.iter().min(), values.iter().max()) else {
+        return Err(eyre!("'{}' has no timestamps", column.name()));
+    };
+    let n = bins.max(1);
+    let span = (end - start) as i128;
+    let mut counts = vec![0u64; n];
+    for v in values {
+        let idx = if span == 0 { 0 } else { ((v - start) as i128 * n as i128 / span) as usize };
+        counts[idx.min(n - 1)] += 1;
+    }
+    Ok(TimelineBins { start, end, counts })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bins_and_ranges() {
+        let hour = 3_600_000_000i64;
+        let micros = Int64Chunked::from_iter_options("ts".into(), [Some(0), Some(hour / 2), None, Some(3 * hour), Some(4 * hour)].into_iter())
+            .into_datetime(TimeUnit::Microseconds, None)
+            .into_series()
+            .into_column();
+        let bins = timeline_bins(&micros, 4).unwrap();
+        assert_eq!(bins.counts, [2, 0, 0, 2]);
+        assert_eq!(bins.range(1, 2), (hour, 3 * hour));
+        assert_eq!(bins.range(3, 3), (3 * hour, 4 * hour));
+        assert_eq!(format_micros(hour), "1970-01-01 01:00:00.000000");
+        assert!(timeline_bins(&Column::new("n".into(), [1i64]), 4).is_err());
+    }
+}
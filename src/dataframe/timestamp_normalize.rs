@@ -0,0 +1,205 @@
+//! Normalize mixed timestamp representations (text, Unix epochs, Windows FILETIME, Chrome/WebKit
+//! time) into UTC epoch microseconds.
+
+use std::fmt;
+
+use chrono::DateTime;
+
+use crate::dataframe::datetime_parse::parse_datetime_auto;
+
+/// Microseconds between 1601-01-01 (the FILETIME and WebKit epoch) and 1970-01-01
+const EPOCH_1601_OFFSET_MICROS: i64 = 11_644_473_600_000_000;
+/// Accepted window for numeric values, in Unix microseconds: 1973-03-03 (so counts and ids are
+/// not read as dates) to 2100-01-01. Numbers outside it are tried as the next finer unit.
+const MIN_MICROS: i64 = 100_000_000_000_000;
+const MAX_MICROS: i64 = 4_102_444_800_000_000;
+/// Unparseable rows kept in the report; the count covers all of them
+pub const MAX_REPORTED_UNPARSEABLE: usize = 20;
+
+/// How a value was recognised
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TimestampKind {
+    /// ISO 8601 / RFC 3339 or another auto-detected text format
+    Text,
+    EpochSeconds,
+    EpochMillis,
+    EpochMicros,
+    /// 100-nanosecond ticks since 1601-01-01
+    FileTime,
+    /// Chrome/WebKit: microseconds since 1601-01-01
+    WebKit,
+}
+
+impl TimestampKind {
+    pub const ALL: [TimestampKind; 6] = [
+        TimestampKind::Text,
+        TimestampKind::EpochSeconds,
+        TimestampKind::EpochMillis,
+        TimestampKind::EpochMicros,
+        TimestampKind::FileTime,
+        TimestampKind::WebKit,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TimestampKind::Text => "Text (ISO 8601 etc.)",
+            TimestampKind::EpochSeconds => "Epoch seconds",
+            TimestampKind::EpochMillis => "Epoch milliseconds",
+            TimestampKind::EpochMicros => "Epoch microseconds",
+            TimestampKind::FileTime => "Windows FILETIME",
+            TimestampKind::WebKit => "Chrome/WebKit",
+        }
+    }
+}
+
+/// Numbers are told apart by magnitude: each unit is only accepted if it lands in the window,
+/// checked from the coarsest unit up. WebKit and FILETIME values are large enough that
+/// they never overlap the Unix ranges. Integers are converted exactly; FILETIME drops its
+/// sub-microsecond ticks.
+fn classify_integer(n: i128) -> Option<(i64, TimestampKind)> {
+    let offset = EPOCH_1601_OFFSET_MICROS as i128;
+    [
+        (n * 1_000_000, TimestampKind::EpochSeconds),
+        (n * 1_000, TimestampKind::EpochMillis),
+        (n, TimestampKind::EpochMicros),
+        (n - offset, TimestampKind::WebKit),
+        (n / 10 - offset, TimestampKind::FileTime),
+    ]
+    .into_iter()
+    .find(|(micros, _)| (MIN_MICROS as i128..=MAX_MICROS as i128).contains(micros))
+    .map(|(micros, kind)| (micros as i64, kind))
+}
+
+/// Fractional numbers are only read as epoch seconds or milliseconds
+fn classify_fraction(n: f64) -> Option<(i64, TimestampKind)> {
+    [(n * 1e6, TimestampKind::EpochSeconds), (n * 1e3, TimestampKind::EpochMillis)]
+        .into_iter()
+        .find(|(micros, _)| (MIN_MICROS as f64..=MAX_MICROS as f64).contains(micros))
+        .map(|(micros, kind)| (micros.round() as i64, kind))
+}
+
+/// Parse one value into UTC epoch microseconds, with the representation it was read as
+pub fn normalize_timestamp(value: &str) -> Option<(i64, TimestampKind)> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+    if let Some(hex) = value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")) {
+        return u64::from_str_radix(hex, 16).ok().and_then(|n| classify_integer(n as i128));
+    }
+    if let Ok(n) = value.parse::<i64>() {
+        return classify_integer(n as i128);
+    }
+    if let Ok(n) = value.parse::<f64>() {
+        return if n.is_finite() { classify_fraction(n) } else { None };
+    }
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some((dt.timestamp_micros(), TimestampKind::Text));
+    }
+    parse_datetime_auto(value).map(|micros| (micros, TimestampKind::Text))
+}
+
+/// What a normalization pass recognised and what it could not
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NormalizeReport {
+    /// Values per representation, in `TimestampKind::ALL` order, zero counts omitted
+    pub counts: Vec<(TimestampKind, usize)>,
+    pub nulls: usize,
+    pub unparseable: usize,
+    /// First `MAX_REPORTED_UNPARSEABLE` failures as (0-based row, value)
+    pub examples: Vec<(usize, String)>,
+}
+
+impl fmt::Display for NormalizeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (kind, count) in &self.counts {
+            writeln!(f, "{}: {}", kind.label(), count)?;
+        }
+        writeln!(f, "Null: {}", self.nulls)?;
+        write!(f, "Unparseable: {}", self.unparseable)?;
+        if !self.examples.is_empty() {
+            writeln!(f)?;
+            for (row, value) in &self.examples {
+                write!(f, "\n  row {}: {}", row + 1, value)?;
+            }
+            if self.unparseable > self.examples.len() {
+                write!(f, "\n  ... and {} more", self.unparseable - self.examples.len())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Normalize a column of optional strings; blank values count as null
+pub fn normalize_timestamps(values: &[Option<String>]) -> (Vec<Option<i64>>, NormalizeReport) {
+    let mut report = NormalizeReport::default();
+    let mut counts = [0usize; TimestampKind::ALL.len()];
+    let parsed = values
+        .iter()
+        .enumerate()
+        .map(|(row, value)| {
+            let Some(value) = value.as_deref().filter(|v| !v.trim().is_empty()) else {
+                report.nulls += 1;
+                return None;
+            };
+            match normalize_timestamp(value) {
+                Some((micros, kind)) => {
+                    counts[kind as usize] += 1;
+                    Some(micros)
+                }
+                None => {
+                    report.unparseable += 1;
+                    if report.examples.len() < MAX_REPORTED_UNPARSEABLE {
+                        report.examples.push((row, value.to_string()));
+                    }
+                    None
+                }
+            }
+        })
+        .collect();
+    report.counts = TimestampKind::ALL.into_iter().zip(counts).filter(|(_, n)| *n > 0).collect();
+    (parsed, report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mixed_representations() {
+        // 2024-03-05 10:20:30 UTC in every supported representation
+        let expected = 1_709_634_030_000_000;
+        let values: Vec<Option<String>> = [
+            Some("2024-03-05T10:20:30Z"),
+            Some("2024-03-05T12:20:30+02:00"),
+            Some("1709634030"),
+            Some("1709634030000"),
+            Some("1709634030000000"),
+            Some("13354107630000000"),
+            Some("133541076300000000"),
+            Some("0x01DA6EE6BFC8EB00"),
+            None,
+            Some("  "),
+            Some("yesterday"),
+            Some("42"),
+        ]
+        .into_iter()
+        .map(|v| v.map(str::to_string))
+        .collect();
+        let (parsed, report) = normalize_timestamps(&values);
+        assert!(parsed[..8].iter().all(|v| *v == Some(expected)), "{parsed:?}");
+        assert_eq!(
+            report.counts,
+            [
+                (TimestampKind::Text, 2),
+                (TimestampKind::EpochSeconds, 1),
+                (TimestampKind::EpochMillis, 1),
+                (TimestampKind::EpochMicros, 1),
+                (TimestampKind::FileTime, 2),
+                (TimestampKind::WebKit, 1),
+            ]
+        );
+        assert_eq!((report.nulls, report.unparseable), (2, 2));
+        assert_eq!(report.examples, [(10, "yesterday".to_string()), (11, "42".to_string())]);
+    }
+}
@@ -0,0 +1,207 @@
+//! Transform history: the ordered pipeline of transforms applied to a dataset
+//!
+//! Each tab records the filters, sorts, SQL queries, JMESPath transforms, column operations and
+//! scripts applied to it, so an analysis can be reviewed and replayed against a re-imported source.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::dataframe::pattern_sets::PatternSetOptions;
+use crate::dataframe::schema_edit::SchemaEdit;
+use crate::dataframe::sigma::SigmaOptions;
+use crate::dialog::TransformScope;
+use crate::dialog::column_operation_options_dialog::ColumnOperationConfig;
+use crate::dialog::column_operations_dialog::ColumnOperationKind;
+use crate::dialog::filter_dialog::FilterExpr;
+use crate::dialog::jmes_dialog::JmesPathKeyValuePair;
+use crate::dialog::sort_dialog::SortColumn;
+
+fn filter_summary(expr: &FilterExpr) -> String {
+    let group = |children: &[FilterExpr], op: &str| {
+        format!("({})", children.iter().map(filter_summary).collect::<Vec<_>>().join(op))
+    };
+    match expr {
+        FilterExpr::Condition(filter) => filter.summary(),
+        FilterExpr::And(children) => group(children, " AND "),
+        FilterExpr::Or(children) => group(children, " OR "),
+    }
+}
+
+/// One transform, with everything needed to apply it again
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TransformStep {
+    /// Filter the original data (replaces earlier transforms, like the filter dialog does)
+    Filter(FilterExpr),
+    Sort(Vec<SortColumn>),
+    /// SQL query over the loaded datasets
+    Sql(String),
+    JmesTransform { query: String, scope: TransformScope },
+    JmesAddColumns { pairs: Vec<JmesPathKeyValuePair>, scope: TransformScope },
+    ColumnOperation(ColumnOperationConfig),
+    /// Columns cast, renamed and reordered in the schema editor
+    Schema(SchemaEdit),
+    /// Rhai script whose output columns were added to the data; `name` is empty when unsaved
+    Script { name: String, source: String },
+    /// Sigma rules evaluated into a matches column (the rules are re-read on replay)
+    Sigma(SigmaOptions),
+    /// Named regex pattern set matched against columns (the pattern file is re-read on replay)
+    PatternSet(PatternSetOptions),
+    /// Back to the original data
+    Reset,
+}
+
+impl TransformStep {
+    pub fn kind_name(&self) -> &'static str {
+        match self {
+            Self::Filter(_) => "Filter",
+            Self::Sort(_) => "Sort",
+            Self::Sql(_) => "SQL",
+            Self::JmesTransform { .. } => "JMESPath",
+            Self::JmesAddColumns { .. } => "JMESPath Columns",
+            Self::ColumnOperation(_) => "Column Op",
+            Self::Schema(_) => "Schema",
+            Self::Script { .. } => "Script",
+            Self::Sigma(_) => "Sigma",
+            Self::PatternSet(_) => "Pattern Set",
+            Self::Reset => "Reset",
+        }
+    }
+
+    /// One-line description for the history dialog
+    pub fn summary(&self) -> String {
+        match self {
+            Self::Filter(expr) => filter_summary(expr),
+            Self::Sort(columns) => columns
+                .iter()
+                .map(|c| format!("{} {}", c.name, if c.ascending { "asc" } else { "desc" }))
+                .collect::<Vec<_>>()
+                .join(", "),
+            Self::Sql(query) => query.split_whitespace().collect::<Vec<_>>().join(" "),
+            Self::JmesTransform { query, scope } => format!("{query} ({scope})"),
+            Self::JmesAddColumns { pairs, scope } => format!(
+                "{} ({scope})",
+                pairs.iter().map(|p| p.name.as_str()).collect::<Vec<_>>().join(", ")
+            ),
+            Self::ColumnOperation(cfg) => {
+                let rows = if cfg.only_rows.is_some() { " (filtered rows)" } else { "" };
+                format!("{:?} on {}{rows} -> {}", cfg.operation, cfg.source_column, cfg.new_column_name)
+            }
+            Self::Schema(edit) => edit.summary(),
+            Self::Script { name, source } if name.is_empty() => {
+                source.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or_default().to_string()
+            }
+            Self::Script { name, .. } => name.clone(),
+            Self::Sigma(options) => format!("{} -> {}", options.rules_path, options.column_name()),
+            Self::PatternSet(options) => {
+                format!("{} on {} ({})", options.patterns_path, options.columns.join(", "), options.output.label())
+            }
+            Self::Reset => "Restore original data".to_string(),
+        }
+    }
+
+    /// Whether replay can apply this step directly. Operations that call out to an LLM provider
+    /// or run as background jobs (embeddings, PCA, t-SNE, clustering) are listed but skipped.
+    pub fn is_replayable(&self) -> bool {
+        match self {
+            Self::ColumnOperation(cfg) => matches!(
+                cfg.operation,
+                ColumnOperationKind::RegexExtract
+                    | ColumnOperationKind::ParseDatetime
+                    | ColumnOperationKind::NormalizeTimestamps
+                    | ColumnOperationKind::Hash
+                    | ColumnOperationKind::Outliers
+                    | ColumnOperationKind::Lookup
+                    | ColumnOperationKind::Plugin
+            ),
+            _ => true,
+        }
+    }
+}
+
+/// A step as it was applied, with the shape of the result
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransformRecord {
+    pub step: TransformStep,
+    pub applied_at: DateTime<Utc>,
+    pub rows: usize,
+    pub columns: usize,
+}
+
+/// Ordered transforms applied to one dataset
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TransformHistory {
+    pub records: Vec<TransformRecord>,
+}
+
+impl TransformHistory {
+    pub fn push(&mut self, step: TransformStep, rows: usize, columns: usize) {
+        self.records.push(TransformRecord { step, applied_at: Utc::now(), rows, columns });
+    }
+
+    pub fn clear(&mut self) {
+        self.records.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// The steps in the order they were applied
+    pub fn steps(&self) -> Vec<TransformStep> {
+        self.records.iter().map(|r| r.step.clone()).collect()
+    }
+
+    /// The pipeline as pretty JSON, for exporting alongside findings
+    pub fn to_json(&self) -> color_eyre::Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn from_json(json: &str) -> color_eyre::Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialog::column_operation_options_dialog::OperationOptions;
+    use crate::dialog::filter_dialog::{ColumnFilter, FilterCondition};
+
+    #[test]
+    fn test_history_round_trips_and_flags_replayable_steps() {
+        let mut history = TransformHistory::default();
+        history.push(
+            TransformStep::Filter(FilterExpr::Condition(ColumnFilter {
+                column: "status".to_string(),
+                condition: FilterCondition::Equals { value: "500".to_string(), case_sensitive: false },
+            })),
+            10,
+            3,
+        );
+        history.push(TransformStep::Sort(vec![SortColumn::new("ts", false)]), 10, 3);
+        history.push(
+            TransformStep::ColumnOperation(ColumnOperationConfig {
+                operation: ColumnOperationKind::GenerateEmbeddings,
+                new_column_name: "msg_emb".to_string(),
+                source_column: "msg".to_string(),
+                hide_new_column: false,
+                only_rows: None,
+                options: OperationOptions::GenerateEmbeddings { model_name: "m".to_string(), num_dimensions: 0 },
+            }),
+            10,
+            4,
+        );
+
+        assert_eq!(history.records[1].step.summary(), "ts desc");
+        assert!(history.records[0].step.is_replayable());
+        assert!(!history.records[2].step.is_replayable());
+
+        let restored: TransformHistory = serde_json::from_str(&history.to_json().unwrap()).unwrap();
+        assert_eq!(restored, history);
+        assert_eq!(restored.steps().len(), 3);
+    }
+}
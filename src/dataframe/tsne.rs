@@ -0,0 +1,229 @@
+//! Exact t-SNE projection of embedding vectors to two dimensions.
+//!
+//! This is the O(n²) formulation (no Barnes-Hut), so it is limited to
+//! `MAX_TSNE_ROWS` rows; that is enough for exploring a filtered sample.
+
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+
+/// Largest number of rows accepted by `tsne_2d`
+pub const MAX_TSNE_ROWS: usize = 3000;
+
+const EARLY_EXAGGERATION: f64 = 12.0;
+const EXAGGERATION_ITERATIONS: usize = 100;
+const MOMENTUM_SWITCH_ITERATION: usize = 250;
+const LEARNING_RATE: f64 = 200.0;
+const PERPLEXITY_TOLERANCE: f64 = 1e-5;
+
+/// Options for a t-SNE run
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TsneParams {
+    pub perplexity: f64,
+    pub iterations: usize,
+    pub seed: u64,
+}
+
+impl Default for TsneParams {
+    fn default() -> Self {
+        Self { perplexity: 30.0, iterations: 500, seed: 42 }
+    }
+}
+
+/// Small deterministic generator so projections are reproducible without an extra dependency
+struct XorShift(u64);
+
+impl XorShift {
+    fn next_f64(&mut self) -> f64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Standard normal sample (Box-Muller)
+    fn next_gaussian(&mut self) -> f64 {
+        let u1 = self.next_f64().max(f64::MIN_POSITIVE);
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
+}
+
+fn squared_distances(data: &[Vec<f64>]) -> Vec<f64> {
+    let n = data.len();
+    let mut d = vec![0.0; n * n];
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let dist: f64 = data[i].iter().zip(&data[j]).map(|(a, b)| (a - b) * (a - b)).sum();
+            d[i * n + j] = dist;
+            d[j * n + i] = dist;
+        }
+    }
+    d
+}
+
+/// Conditional probabilities p(j|i), with each row's bandwidth found by binary search
+/// so that its entropy matches log(perplexity). Returned symmetrized and normalized.
+fn joint_probabilities(distances: &[f64], n: usize, perplexity: f64) -> Vec<f64> {
+    let target_entropy = perplexity.ln();
+    let mut p = vec![0.0; n * n];
+    for i in 0..n {
+        let row = &distances[i * n..(i + 1) * n];
+        let (mut beta, mut lo, mut hi) = (1.0_f64, f64::NEG_INFINITY, f64::INFINITY);
+        for _ in 0..50 {
+            let mut sum = 0.0;
+            let mut weighted = 0.0;
+            for j in 0..n {
+                if j == i { continue; }
+                let v = (-row[j] * beta).exp();
+                p[i * n + j] = v;
+                sum += v;
+                weighted += row[j] * v;
+            }
+            let sum = sum.max(f64::MIN_POSITIVE);
+            let entropy = sum.ln() + beta * weighted / sum;
+            for j in 0..n { p[i * n + j] /= sum; }
+            let diff = entropy - target_entropy;
+            if diff.abs() < PERPLEXITY_TOLERANCE { break; }
+            if diff > 0.0 {
+                lo = beta;
+                beta = if hi.is_finite() { (beta + hi) / 2.0 } else { beta * 2.0 };
+            } else {
+                hi = beta;
+                beta = if lo.is_finite() { (beta + lo) / 2.0 } else { beta / 2.0 };
+            }
+        }
+    }
+    let mut joint = vec![0.0; n * n];
+    let norm = 2.0 * n as f64;
+    for i in 0..n {
+        for j in 0..n {
+            joint[i * n + j] = ((p[i * n + j] + p[j * n + i]) / norm).max(1e-12);
+        }
+    }
+    joint
+}
+
+/// Project `data` (one equally sized vector per row) to 2-D coordinates with t-SNE
+pub fn tsne_2d(data: &[Vec<f64>], params: TsneParams) -> Result<Vec<[f64; 2]>> {
+    tsne_2d_with_progress(data, params, |_| true)
+}
+
+/// Like `tsne_2d`, calling `on_iteration` with the completed iteration count after each
+/// gradient step; returning false stops the run with a "Cancelled" error
+pub fn tsne_2d_with_progress(
+    data: &[Vec<f64>],
+    params: TsneParams,
+    mut on_iteration: impl FnMut(usize) -> bool,
+) -> Result<Vec<[f64; 2]>> {
+    let n = data.len();
+    if n == 0 {
+        return Ok(Vec::new());
+    }
+    if n > MAX_TSNE_ROWS {
+        return Err(eyre!("t-SNE supports at most {} rows (got {}); filter the data first", MAX_TSNE_ROWS, n));
+    }
+    if n < 3 {
+        return Err(eyre!("t-SNE needs at least 3 rows"));
+    }
+    let dim = data[0].len();
+    if dim == 0 || data.iter().any(|v| v.len() != dim) {
+        return Err(eyre!("All vectors must have the same non-zero length"));
+    }
+    // Perplexity must leave room for neighbours
+    let perplexity = params.perplexity.clamp(1.0, ((n - 1) as f64 / 3.0).max(1.0));
+    let p = joint_probabilities(&squared_distances(data), n, perplexity);
+
+    let mut rng = XorShift(params.seed.max(1));
+    let mut y: Vec<[f64; 2]> = (0..n).map(|_| [rng.next_gaussian() * 1e-4, rng.next_gaussian() * 1e-4]).collect();
+    let mut velocity = vec![[0.0_f64; 2]; n];
+    let mut gains = vec![[1.0_f64; 2]; n];
+    let mut num = vec![0.0; n * n];
+
+    for iter in 0..params.iterations.max(1) {
+        let exaggeration = if iter < EXAGGERATION_ITERATIONS { EARLY_EXAGGERATION } else { 1.0 };
+        let momentum = if iter < MOMENTUM_SWITCH_ITERATION { 0.5 } else { 0.8 };
+
+        // Student-t affinities in the embedding
+        let mut sum_q = 0.0;
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let dx = y[i][0] - y[j][0];
+                let dy = y[i][1] - y[j][1];
+                let v = 1.0 / (1.0 + dx * dx + dy * dy);
+                num[i * n + j] = v;
+                num[j * n + i] = v;
+                sum_q += 2.0 * v;
+            }
+        }
+        let sum_q = sum_q.max(f64::MIN_POSITIVE);
+
+        for i in 0..n {
+            let mut grad = [0.0; 2];
+            for j in 0..n {
+                if i == j { continue; }
+                let w = num[i * n + j];
+                let q = (w / sum_q).max(1e-12);
+                let mult = 4.0 * (exaggeration * p[i * n + j] - q) * w;
+                grad[0] += mult * (y[i][0] - y[j][0]);
+                grad[1] += mult * (y[i][1] - y[j][1]);
+            }
+            for k in 0..2 {
+                gains[i][k] = if (grad[k] > 0.0) != (velocity[i][k] > 0.0) {
+                    gains[i][k] + 0.2
+                } else {
+                    (gains[i][k] * 0.8).max(0.01)
+                };
+                velocity[i][k] = momentum * velocity[i][k] - LEARNING_RATE * gains[i][k] * grad[k];
+            }
+        }
+        for i in 0..n {
+            y[i][0] += velocity[i][0];
+            y[i][1] += velocity[i][1];
+        }
+        // Keep the embedding centered
+        let (mx, my) = y.iter().fold((0.0, 0.0), |(ax, ay), p| (ax + p[0], ay + p[1]));
+        let (mx, my) = (mx / n as f64, my / n as f64);
+        for point in y.iter_mut() {
+            point[0] -= mx;
+            point[1] -= my;
+        }
+        if !on_iteration(iter + 1) {
+            return Err(eyre!("Cancelled"));
+        }
+    }
+    Ok(y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tsne_separates_clusters() {
+        let mut data = Vec::new();
+        for i in 0..10 {
+            let jitter = i as f64 * 0.01;
+            data.push(vec![0.0 + jitter, 0.0, 0.0]);
+            data.push(vec![10.0 + jitter, 10.0, 10.0]);
+        }
+        let params = TsneParams { perplexity: 5.0, iterations: 300, seed: 7 };
+        let y = tsne_2d(&data, params).unwrap();
+        assert_eq!(y.len(), 20);
+        let dist = |a: [f64; 2], b: [f64; 2]| ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)).sqrt();
+        // Rows alternate between the two clusters
+        let within = dist(y[0], y[2]);
+        let between = dist(y[0], y[1]);
+        assert!(between > within * 2.0, "within={within} between={between}");
+        // Same seed gives the same projection
+        assert_eq!(tsne_2d(&data, params).unwrap(), y);
+    }
+
+    #[test]
+    fn test_tsne_rejects_bad_input() {
+        assert!(tsne_2d(&[vec![1.0], vec![2.0]], TsneParams::default()).is_err());
+        assert!(tsne_2d(&[vec![1.0], vec![2.0, 3.0], vec![4.0]], TsneParams::default()).is_err());
+        assert!(tsne_2d(&[], TsneParams::default()).unwrap().is_empty());
+        let data = vec![vec![0.0], vec![1.0], vec![2.0], vec![3.0]];
+        assert!(tsne_2d_with_progress(&data, TsneParams::default(), |iter| iter < 10).is_err());
+    }
+}
@@ -0,0 +1,277 @@
+//! Validation rules: per-column checks run on demand over the current view
+//!
+//! A run produces a violations dataset (one row per failing cell) and a `CellValidator` that the
+//! table uses to highlight failing cells. The validator works on a cell's display text, so the
+//! highlighting follows the rows through sorting and filtering until the rules are run again.
+
+use std::collections::{HashMap, HashSet};
+
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use polars::prelude::*;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// Checks offered by the rule editor, in the order they are cycled through
+pub const CHECK_NAMES: &[&str] = &["not null", "unique", "regex", "range", "reference"];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ValidationCheck {
+    /// Fails on null and blank values
+    NotNull,
+    /// Fails on every occurrence of a value that appears more than once
+    Unique,
+    /// Fails on values the pattern does not match
+    Regex { pattern: String },
+    /// Fails on values outside the bounds, or that are not numbers
+    Range { min: Option<f64>, max: Option<f64> },
+    /// Fails on values missing from `column` of the loaded dataset named `dataset`
+    Reference { dataset: String, column: String },
+}
+
+impl ValidationCheck {
+    /// Index into `CHECK_NAMES`
+    pub fn kind_index(&self) -> usize {
+        match self {
+            ValidationCheck::NotNull => 0,
+            ValidationCheck::Unique => 1,
+            ValidationCheck::Regex { .. } => 2,
+            ValidationCheck::Range { .. } => 3,
+            ValidationCheck::Reference { .. } => 4,
+        }
+    }
+
+    /// The argument as typed in the rule editor
+    pub fn argument(&self) -> String {
+        match self {
+            ValidationCheck::NotNull | ValidationCheck::Unique => String::new(),
+            ValidationCheck::Regex { pattern } => pattern.clone(),
+            ValidationCheck::Range { min, max } => format!(
+                "{}..{}",
+                min.map(|v| v.to_string()).unwrap_or_default(),
+                max.map(|v| v.to_string()).unwrap_or_default()
+            ),
+            ValidationCheck::Reference { dataset, column } => format!("{dataset}.{column}"),
+        }
+    }
+
+    /// Build the check named `CHECK_NAMES[kind]` from its argument: a pattern, `min..max` with
+    /// either bound optional, or `dataset.column`
+    pub fn parse(kind: usize, argument: &str) -> Result<Self> {
+        let argument = argument.trim();
+        match kind {
+            0 => Ok(ValidationCheck::NotNull),
+            1 => Ok(ValidationCheck::Unique),
+            2 => {
+                Regex::new(argument).map_err(|e| eyre!("Invalid regex: {}", e))?;
+                Ok(ValidationCheck::Regex { pattern: argument.to_string() })
+            }
+            3 => {
+                let (min, max) = argument.split_once("..").ok_or_else(|| eyre!("Range is written min..max"))?;
+                let bound = |text: &str| -> Result<Option<f64>> {
+                    let text = text.trim();
+                    if text.is_empty() {
+                        return Ok(None);
+                    }
+                    text.parse().map(Some).map_err(|_| eyre!("'{}' is not a number", text))
+                };
+                let (min, max) = (bound(min)?, bound(max)?);
+                if min.is_none() && max.is_none() {
+                    return Err(eyre!("Range needs a minimum, a maximum or both"));
+                }
+                Ok(ValidationCheck::Range { min, max })
+            }
+            4 => {
+                // Dataset names may contain dots, column names rarely do
+                let (dataset, column) = argument.rsplit_once('.').ok_or_else(|| eyre!("Reference is written dataset.column"))?;
+                if dataset.trim().is_empty() || column.trim().is_empty() {
+                    return Err(eyre!("Reference is written dataset.column"));
+                }
+                Ok(ValidationCheck::Reference { dataset: dataset.trim().to_string(), column: column.trim().to_string() })
+            }
+            _ => Err(eyre!("Unknown check")),
+        }
+    }
+
+    /// Short description for lists and the violations dataset
+    pub fn label(&self) -> String {
+        match self {
+            ValidationCheck::NotNull => "not null".to_string(),
+            ValidationCheck::Unique => "unique".to_string(),
+            ValidationCheck::Regex { pattern } => format!("matches /{pattern}/"),
+            ValidationCheck::Range { .. } => format!("in range {}", self.argument()),
+            ValidationCheck::Reference { dataset, column } => format!("in {dataset}.{column}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidationRule {
+    pub column: String,
+    pub check: ValidationCheck,
+}
+
+impl ValidationRule {
+    pub fn summary(&self) -> String {
+        format!("{} {}", self.column, self.check.label())
+    }
+}
+
+/// A check with what it needs from the data precomputed
+#[derive(Debug)]
+enum CompiledCheck {
+    NotNull,
+    /// Values that appear more than once
+    Duplicates(HashSet<String>),
+    Regex(Regex),
+    Range(Option<f64>, Option<f64>),
+    /// Values of the referenced column
+    Allowed(HashSet<String>),
+}
+
+impl CompiledCheck {
+    /// Whether a cell with this text fails; only NotNull looks at null and blank cells
+    fn fails(&self, text: Option<&str>) -> bool {
+        let text = text.filter(|t| !t.trim().is_empty());
+        match (self, text) {
+            (CompiledCheck::NotNull, text) => text.is_none(),
+            (_, None) => false,
+            (CompiledCheck::Duplicates(values), Some(text)) => values.contains(text),
+            (CompiledCheck::Regex(regex), Some(text)) => !regex.is_match(text),
+            (CompiledCheck::Range(min, max), Some(text)) => match text.trim().parse::<f64>() {
+                Ok(v) => min.is_some_and(|m| v < m) || max.is_some_and(|m| v > m),
+                Err(_) => true,
+            },
+            (CompiledCheck::Allowed(values), Some(text)) => !values.contains(text),
+        }
+    }
+}
+
+/// Cell text as the table shows it; None for null
+fn cell_text(value: AnyValue) -> Option<String> {
+    match value {
+        AnyValue::Null => None,
+        other => Some(other.str_value().to_string()),
+    }
+}
+
+fn column_texts(df: &DataFrame, column: &str) -> Result<Vec<Option<String>>> {
+    let series = df.column(column).map_err(|e| eyre!("{}", e))?.as_materialized_series().rechunk();
+    Ok(series.iter().map(cell_text).collect())
+}
+
+/// Rules compiled against one frame, keyed by column
+#[derive(Debug, Default)]
+pub struct CellValidator {
+    checks: HashMap<String, Vec<(usize, CompiledCheck)>>,
+}
+
+impl CellValidator {
+    /// Compile `rules` against `df`. `dataset` looks up the frame of a loaded dataset by name
+    /// for reference checks.
+    pub fn compile(
+        df: &DataFrame,
+        rules: &[ValidationRule],
+        dataset: impl Fn(&str) -> Option<Arc<DataFrame>>,
+    ) -> Result<Self> {
+        let mut checks: HashMap<String, Vec<(usize, CompiledCheck)>> = HashMap::new();
+        for (index, rule) in rules.iter().enumerate() {
+            if df.column(&rule.column).is_err() {
+                return Err(eyre!("Column '{}' not found", rule.column));
+            }
+            let compiled = match &rule.check {
+                ValidationCheck::NotNull => CompiledCheck::NotNull,
+                ValidationCheck::Unique => {
+                    let mut seen = HashSet::new();
+                    let mut duplicates = HashSet::new();
+                    for text in column_texts(df, &rule.column)?.into_iter().flatten() {
+                        if !seen.insert(text.clone()) {
+                            duplicates.insert(text);
+                        }
+                    }
+                    CompiledCheck::Duplicates(duplicates)
+                }
+                ValidationCheck::Regex { pattern } => {
+                    CompiledCheck::Regex(Regex::new(pattern).map_err(|e| eyre!("Invalid regex for {}: {}", rule.column, e))?)
+                }
+                ValidationCheck::Range { min, max } => CompiledCheck::Range(*min, *max),
+                ValidationCheck::Reference { dataset: name, column } => {
+                    let other = dataset(name).ok_or_else(|| eyre!("Dataset '{}' is not loaded", name))?;
+                    CompiledCheck::Allowed(column_texts(&other, column)?.into_iter().flatten().collect())
+                }
+            };
+            checks.entry(rule.column.clone()).or_default().push((index, compiled));
+        }
+        Ok(Self { checks })
+    }
+
+    /// Whether a cell of `column` showing `text` fails any rule; blank text counts as null
+    pub fn fails(&self, column: &str, text: &str) -> bool {
+        self.checks.get(column).is_some_and(|checks| checks.iter().any(|(_, c)| c.fails(Some(text))))
+    }
+
+    /// One row per failing cell of `df`: its 1-based row, column, rule and value, ordered by
+    /// rule then row. Also returns the number of failures per rule.
+    pub fn violations(&self, df: &DataFrame, rules: &[ValidationRule]) -> Result<(DataFrame, Vec<usize>)> {
+        let mut counts = vec![0; rules.len()];
+        let (mut rows, mut columns, mut labels, mut values) = (Vec::new(), Vec::new(), Vec::new(), Vec::new());
+        let mut checks: Vec<(&String, &(usize, CompiledCheck))> =
+            self.checks.iter().flat_map(|(column, checks)| checks.iter().map(move |c| (column, c))).collect();
+        checks.sort_by_key(|(_, (index, _))| *index);
+        for (column, (index, check)) in checks {
+            for (row, text) in column_texts(df, column)?.into_iter().enumerate() {
+                if check.fails(text.as_deref()) {
+                    counts[*index] += 1;
+                    rows.push(row as u64 + 1);
+                    columns.push(column.clone());
+                    labels.push(rules[*index].check.label());
+                    values.push(text);
+                }
+            }
+        }
+        let violations = df!("row" => rows, "column" => columns, "rule" => labels, "value" => values)
+            .map_err(|e| eyre!("Failed to build violations: {}", e))?;
+        Ok((violations, counts))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rules_violations_and_cell_checks() {
+        let df = df!(
+            "id" => [Some(1i64), Some(2), Some(2), None],
+            "email" => ["a@x.org", "bad", "c@x.org", ""],
+            "user" => ["u1", "u2", "u9", "u1"]
+        )
+        .unwrap();
+        let users = Arc::new(df!("name" => ["u1", "u2"]).unwrap());
+        let rules = vec![
+            ValidationRule { column: "id".to_string(), check: ValidationCheck::NotNull },
+            ValidationRule { column: "id".to_string(), check: ValidationCheck::Unique },
+            ValidationRule { column: "id".to_string(), check: ValidationCheck::parse(3, "..1.5").unwrap() },
+            ValidationRule { column: "email".to_string(), check: ValidationCheck::parse(2, "^[^@]+@").unwrap() },
+            ValidationRule { column: "user".to_string(), check: ValidationCheck::parse(4, "users.name").unwrap() },
+        ];
+        let lookup = |name: &str| (name == "users").then(|| users.clone());
+        let validator = CellValidator::compile(&df, &rules, lookup).unwrap();
+
+        let (violations, counts) = validator.violations(&df, &rules).unwrap();
+        assert_eq!(counts, vec![1, 2, 2, 1, 1]);
+        assert_eq!(violations.height(), 7);
+        let rows: Vec<Option<u64>> = violations.column("row").unwrap().u64().unwrap().into_iter().collect();
+        assert_eq!(rows, vec![Some(4), Some(2), Some(3), Some(2), Some(3), Some(2), Some(3)]);
+        assert_eq!(violations.column("rule").unwrap().str().unwrap().get(6), Some("in users.name"));
+
+        assert!(validator.fails("id", "2") && validator.fails("id", ""));
+        assert!(!validator.fails("id", "1") && !validator.fails("note", "anything"));
+        assert!(!validator.fails("email", "") && validator.fails("email", "nope"));
+
+        assert!(ValidationCheck::parse(3, "..").is_err());
+        assert_eq!(ValidationCheck::parse(3, "0..10").unwrap().argument(), "0..10");
+        let missing = vec![ValidationRule { column: "user".to_string(), check: ValidationCheck::parse(4, "hosts.name").unwrap() }];
+        assert!(CellValidator::compile(&df, &missing, lookup).unwrap_err().to_string().contains("'hosts' is not loaded"));
+    }
+}
@@ -25,6 +25,12 @@ pub struct AliasEditDialog {
     pub show_instructions: bool,
     pub cursor_index: usize,
     pub cursor_visible: bool,
+    /// SQL table names of the other datasets; an alias matching one of them is refused
+    #[serde(default)]
+    pub taken_names: Vec<String>,
+    /// Shown under the input, e.g. why an alias was suggested or refused
+    #[serde(default)]
+    pub notice: Option<String>,
     #[serde(skip)]
     pub config: Config,
 }
@@ -43,10 +49,30 @@ impl AliasEditDialog {
             show_instructions: true,
             cursor_index: initial_len,
             cursor_visible: true,
+            taken_names: Vec::new(),
+            notice: None,
             config: Config::default(),
         }
     }
 
+    /// Refuse aliases that would clash with these table names (compared ignoring case)
+    pub fn with_taken_names(mut self, taken_names: Vec<String>) -> Self {
+        self.taken_names = taken_names;
+        self
+    }
+
+    /// Show a note under the input when the dialog opens
+    pub fn with_notice(mut self, notice: String) -> Self {
+        self.notice = Some(notice);
+        self
+    }
+
+    /// The table name the dataset would get, if another dataset already uses it
+    fn clashing_name(&self) -> Option<String> {
+        let name = self.get_alias().unwrap_or_else(|| self.dataset_name.clone());
+        self.taken_names.iter().any(|t| t.eq_ignore_ascii_case(&name)).then_some(name)
+    }
+
     /// Render the dialog
     pub fn render(&self, area: Rect, buf: &mut Buffer) {
         // Clear the background for the popup
@@ -112,10 +138,11 @@ impl AliasEditDialog {
             }
         }
 
-        let paragraph = Paragraph::new(vec![
-                Line::from(current_line),
-                Line::from(new_alias_spans),
-            ])
+        let mut lines = vec![Line::from(current_line), Line::from(new_alias_spans)];
+        if let Some(notice) = &self.notice {
+            lines.push(Line::styled(notice.as_str(), Style::default().fg(Color::LightRed)));
+        }
+        let paragraph = Paragraph::new(lines)
             .style(Style::default().fg(Color::White))
             .wrap(Wrap { trim: true });
         paragraph.render(content_inner_area, buf);
@@ -214,6 +241,10 @@ impl Component for AliasEditDialog {
                 match global_action {
                     Action::Escape => return Ok(Some(Action::DialogClose)),
                     Action::Enter => {
+                        if let Some(name) = self.clashing_name() {
+                            self.notice = Some(format!("'{name}' is already used by another dataset"));
+                            return Ok(None);
+                        }
                         // Save the alias
                         let alias = self.get_alias();
                         return Ok(Some(Action::EditDatasetAlias {
@@ -373,4 +404,23 @@ mod tests {
         dialog.input_buffer = "  Valid Alias  ".to_string();
         assert_eq!(dialog.get_alias(), Some("Valid Alias".to_string()));
     }
+
+    #[test]
+    fn test_alias_clashing_with_another_dataset_is_refused() {
+        let mut dialog = AliasEditDialog::new(1, "dataset_id".to_string(), "users".to_string(), Some("Users".to_string()))
+            .with_taken_names(vec!["users".to_string(), "hosts".to_string()]);
+        dialog.config.reset_keybindings_to_default();
+        let enter = KeyEvent::new(KeyCode::Enter, crossterm::event::KeyModifiers::NONE);
+        assert_eq!(dialog.handle_key_event(enter).unwrap(), None);
+        assert_eq!(dialog.notice.as_deref(), Some("'Users' is already used by another dataset"));
+
+        // Clearing the alias falls back to the dataset name, which clashes too
+        dialog.clear();
+        assert_eq!(dialog.handle_key_event(enter).unwrap(), None);
+        dialog.input_buffer = "users_2".to_string();
+        assert_eq!(
+            dialog.handle_key_event(enter).unwrap(),
+            Some(Action::EditDatasetAlias { source_id: 1, dataset_id: "dataset_id".to_string(), alias: Some("users_2".to_string()) })
+        );
+    }
 }
@@ -0,0 +1,295 @@
+//! AskDataDialog: ask a question in plain language and have the configured LLM write the SQL
+//!
+//! The generated query is not run here; it is handed to the SQL dialog for review and editing.
+
+use crossterm::event::{KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Clear, Paragraph, Wrap};
+use tui_textarea::TextArea;
+
+use crate::action::Action;
+use crate::components::background_job::BackgroundJob;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+use crate::dialog::llm::RequestRunner;
+use crate::dialog::LlmProvider;
+
+const SYSTEM_PROMPT: &str = "You translate questions about tabular data into a single Polars SQL \
+SELECT statement. Use only the tables and columns provided, quoting column names with double quotes. \
+Reply with the SQL only, without explanation.";
+
+/// Prompt describing the table and the question
+pub fn build_sql_prompt(table_name: &str, schema: &[(String, String)], other_tables: &[String], question: &str) -> String {
+    let mut prompt = format!("Table \"{table_name}\" has columns:\n");
+    for (name, dtype) in schema {
+        prompt.push_str(&format!("- \"{name}\" ({dtype})\n"));
+    }
+    if !other_tables.is_empty() {
+        prompt.push_str(&format!("Other tables that can be joined: {}\n", other_tables.join(", ")));
+    }
+    prompt.push_str(&format!("\nQuestion: {}", question.trim()));
+    prompt
+}
+
+/// The SQL in a model reply, without Markdown code fences or a trailing semicolon
+pub fn extract_sql(reply: &str) -> String {
+    let trimmed = reply.trim();
+    let body = match trimmed.find("```") {
+        Some(start) => {
+            let after = &trimmed[start + 3..];
+            // Skip the fence's language tag line, e.g. ```sql
+            let after = after.split_once('\n').map(|(_, rest)| rest).unwrap_or(after);
+            after.split("```").next().unwrap_or(after)
+        }
+        None => trimmed,
+    };
+    body.trim().trim_end_matches(';').trim().to_string()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AskDataDialogMode {
+    Input,
+    /// Waiting on the LLM
+    Generating,
+    Error(String),
+}
+
+/// AskDataDialog: question input plus provider choice; produces SQL on a worker thread
+#[derive(Debug)]
+pub struct AskDataDialog {
+    pub table_name: String,
+    /// (column name, dtype) of the current table
+    pub schema: Vec<(String, String)>,
+    pub other_tables: Vec<String>,
+    pub providers: Vec<LlmProvider>,
+    pub selected_provider: usize,
+    pub question_input: TextArea<'static>,
+    pub mode: AskDataDialogMode,
+    pub show_instructions: bool,
+    pub config: Config,
+    job: Option<BackgroundJob<String>>,
+}
+
+impl AskDataDialog {
+    pub fn new(table_name: String, schema: Vec<(String, String)>, other_tables: Vec<String>) -> Self {
+        let mut question_input = TextArea::default();
+        question_input.set_block(Block::default());
+        Self {
+            table_name,
+            schema,
+            other_tables,
+            providers: Vec::new(),
+            selected_provider: 0,
+            question_input,
+            mode: AskDataDialogMode::Input,
+            show_instructions: true,
+            config: Config::default(),
+            job: None,
+        }
+    }
+
+    /// Register config handler; the configured LLM providers become the provider choices
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.providers = config.llm_config.configured_list().unwrap_or_default();
+        self.selected_provider = self.selected_provider.min(self.providers.len().saturating_sub(1));
+        self.config = config;
+        Ok(())
+    }
+
+    pub fn question(&self) -> String {
+        self.question_input.lines().join(" ")
+    }
+
+    fn build_instructions_from_config(&self) -> String {
+        self.config.actions_to_instructions(&[
+            (Mode::Global, Action::Enter),
+            (Mode::Global, Action::Tab),
+            (Mode::Global, Action::Escape),
+            (Mode::Global, Action::ToggleInstructions),
+        ])
+    }
+
+    /// Send the question to the selected provider on a worker thread
+    fn start_generation(&mut self) {
+        let Some(provider) = self.providers.get(self.selected_provider).cloned() else {
+            self.mode = AskDataDialogMode::Error("No LLM provider is configured".to_string());
+            return;
+        };
+        let question = self.question();
+        if question.trim().is_empty() {
+            return;
+        }
+        let prompt = build_sql_prompt(&self.table_name, &self.schema, &self.other_tables, &question);
+        let settings = self.config.llm_config.provider_settings();
+        let model = self.config.llm_config.default_completion_model_for(&provider).to_string();
+        self.job = Some(BackgroundJob::spawn(false, move |ctx| {
+            let llm_config = settings.into_config();
+            let is_cancelled = || ctx.is_cancelled();
+            let mut runner = RequestRunner::new(llm_config.request_policy.clone(), &is_cancelled);
+            let reply = runner.run(|| llm_config.fetch_completion_via_provider(provider.clone(), &model, SYSTEM_PROMPT, &prompt))?;
+            let sql = extract_sql(&reply);
+            if sql.is_empty() {
+                return Err(color_eyre::eyre::eyre!("The model did not return a query"));
+            }
+            Ok(sql)
+        }));
+        self.mode = AskDataDialogMode::Generating;
+    }
+
+    /// The generated SQL once the worker has finished successfully
+    pub fn poll_generation(&mut self) -> Option<String> {
+        let result = self.job.as_ref()?.try_finish()?;
+        self.job = None;
+        match result {
+            Ok(sql) => {
+                self.mode = AskDataDialogMode::Input;
+                Some(sql)
+            }
+            Err(e) => {
+                self.mode = AskDataDialogMode::Error(format!("{e}"));
+                None
+            }
+        }
+    }
+
+    /// Render the dialog
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title("Ask Your Data")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let content = layout.content_area;
+
+        let provider_name = self
+            .providers
+            .get(self.selected_provider)
+            .map(|p| p.display_name())
+            .unwrap_or("(none configured)");
+        buf.set_string(content.x + 1, content.y, "Provider:", Style::default().fg(Color::Yellow));
+        buf.set_string(content.x + 11, content.y, provider_name, Style::default());
+        buf.set_string(
+            content.x + 1,
+            content.y + 1,
+            format!("Table: {} ({} columns)", self.table_name, self.schema.len()),
+            Style::default().fg(Color::Gray),
+        );
+
+        let question_area = Rect {
+            x: content.x,
+            y: content.y + 3,
+            width: content.width,
+            height: content.height.saturating_sub(3).min(5),
+        };
+        let block = Block::default().title("Question").borders(Borders::ALL);
+        let question_inner = block.inner(question_area);
+        block.render(question_area, buf);
+        self.question_input.render(question_inner, buf);
+
+        let status_y = question_area.y + question_area.height;
+        if status_y < content.y + content.height {
+            let status_area = Rect {
+                x: content.x + 1,
+                y: status_y,
+                width: content.width.saturating_sub(2),
+                height: content.y + content.height - status_y,
+            };
+            let status = match &self.mode {
+                AskDataDialogMode::Input => Paragraph::new("The generated SQL opens in the SQL dialog for review before it runs."),
+                AskDataDialogMode::Generating => Paragraph::new(format!("Generating SQL with {provider_name}... (Esc to cancel)"))
+                    .style(Style::default().fg(Color::Cyan)),
+                AskDataDialogMode::Error(msg) => Paragraph::new(format!("Error: {msg}"))
+                    .style(Style::default().fg(Color::Red)),
+            };
+            status.wrap(Wrap { trim: true }).render(status_area, buf);
+        }
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Handle keyboard events for the dialog
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        let global_action = self.config.action_for_key(Mode::Global, key);
+        match self.mode {
+            AskDataDialogMode::Generating => {
+                if global_action == Some(Action::Escape) {
+                    if let Some(job) = self.job.take() {
+                        job.cancel();
+                    }
+                    self.mode = AskDataDialogMode::Input;
+                }
+                return None;
+            }
+            AskDataDialogMode::Error(_) => {
+                if matches!(global_action, Some(Action::Escape | Action::Enter)) {
+                    self.mode = AskDataDialogMode::Input;
+                }
+                return None;
+            }
+            AskDataDialogMode::Input => {}
+        }
+        match global_action {
+            Some(Action::Escape) => return Some(Action::DialogClose),
+            Some(Action::Enter) => {
+                self.start_generation();
+                return None;
+            }
+            Some(Action::Tab) => {
+                if !self.providers.is_empty() {
+                    self.selected_provider = (self.selected_provider + 1) % self.providers.len();
+                }
+                return None;
+            }
+            Some(Action::ToggleInstructions) => {
+                self.show_instructions = !self.show_instructions;
+                return None;
+            }
+            _ => {}
+        }
+        self.question_input.input(tui_textarea::Input::from(key));
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_sql() {
+        assert_eq!(extract_sql("SELECT * FROM t;"), "SELECT * FROM t");
+        assert_eq!(
+            extract_sql("Here you go:\n```sql\nSELECT \"a\" FROM t\nWHERE \"b\" > 1;\n```\nDone."),
+            "SELECT \"a\" FROM t\nWHERE \"b\" > 1"
+        );
+    }
+
+    #[test]
+    fn test_prompt_and_no_provider_error() {
+        let schema = vec![("user".to_string(), "str".to_string()), ("bytes".to_string(), "i64".to_string())];
+        let prompt = build_sql_prompt("logs", &schema, &["hosts".to_string()], " top users by bytes ");
+        assert!(prompt.contains("Table \"logs\""));
+        assert!(prompt.contains("- \"bytes\" (i64)"));
+        assert!(prompt.contains("hosts"));
+        assert!(prompt.ends_with("Question: top users by bytes"));
+
+        let mut dialog = AskDataDialog::new("logs".to_string(), schema, Vec::new());
+        dialog.question_input.insert_str("how many rows?");
+        dialog.start_generation();
+        assert!(matches!(dialog.mode, AskDataDialogMode::Error(_)));
+    }
+}
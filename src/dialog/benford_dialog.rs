@@ -0,0 +1,189 @@
+//! BenfordDialog: first-digit distribution of the selected column against Benford's law
+
+use crossterm::event::{KeyEvent, KeyEventKind};
+use polars::prelude::Column;
+use ratatui::prelude::*;
+use ratatui::widgets::{Bar, BarChart, BarGroup, Block, Borders, BorderType, Clear, Paragraph, Wrap};
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+use crate::dataframe::benford::{benford, expected_share, BenfordAnalysis, CHI_SQUARE_CRITICAL_5};
+
+/// BenfordDialog: observed and expected share per leading digit, with the chi-square score
+#[derive(Debug)]
+pub struct BenfordDialog {
+    pub column_name: String,
+    pub analysis: Option<BenfordAnalysis>,
+    pub error: Option<String>,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+impl BenfordDialog {
+    pub fn new(column: &Column) -> Self {
+        let (analysis, error) = match benford(column) {
+            Ok(analysis) => (Some(analysis), None),
+            Err(e) => (None, Some(e.to_string())),
+        };
+        Self {
+            column_name: column.name().to_string(),
+            analysis,
+            error,
+            show_instructions: true,
+            config: Config::default(),
+        }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn build_instructions_from_config(&self) -> String {
+        format!(
+            "{}  Bars: observed (cyan) / expected (gray)",
+            self.config.actions_to_instructions(&[
+                (Mode::Global, Action::Escape),
+                (Mode::Global, Action::ToggleInstructions),
+            ])
+        )
+    }
+
+    fn summary_lines(analysis: &BenfordAnalysis) -> Vec<Line<'static>> {
+        let verdict_color = if analysis.total() < 100 {
+            Color::Yellow
+        } else if analysis.conforms() {
+            Color::Green
+        } else {
+            Color::Red
+        };
+        vec![
+            Line::from(format!(
+                "{} values ({} null, zero or non-finite skipped)",
+                analysis.total(),
+                analysis.skipped
+            )),
+            Line::from(vec![
+                Span::raw(format!(
+                    "Chi-square {:.2} (8 df, 5% critical {CHI_SQUARE_CRITICAL_5}), p = {:.4}: ",
+                    analysis.chi_square, analysis.p_value
+                )),
+                Span::styled(analysis.verdict(), Style::default().fg(verdict_color).add_modifier(Modifier::BOLD)),
+            ]),
+            Line::from(
+                (1..=9)
+                    .map(|d| format!("{d}: {:.1}/{:.1}%", analysis.observed_share(d) * 100.0, expected_share(d) * 100.0))
+                    .collect::<Vec<_>>()
+                    .join("  "),
+            ),
+        ]
+    }
+
+    /// Render the dialog
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title(format!("Benford's Law: {}", self.column_name))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+
+        match &self.analysis {
+            Some(analysis) => {
+                let chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Length(5), Constraint::Min(5)])
+                    .split(layout.content_area);
+                Paragraph::new(Self::summary_lines(analysis))
+                    .block(Block::default().title("Fit").borders(Borders::ALL))
+                    .wrap(Wrap { trim: true })
+                    .render(chunks[0], buf);
+
+                // Shares in tenths of a percent so the bars keep some resolution
+                let groups: Vec<BarGroup> = (1..=9)
+                    .map(|d| {
+                        let observed = analysis.observed_share(d) * 100.0;
+                        let expected = expected_share(d) * 100.0;
+                        BarGroup::default().label(Line::from(d.to_string())).bars(&[
+                            Bar::default()
+                                .value((observed * 10.0).round() as u64)
+                                .text_value(format!("{observed:.0}"))
+                                .style(Style::default().fg(Color::Cyan))
+                                .value_style(Style::default().fg(Color::Black).bg(Color::Cyan)),
+                            Bar::default()
+                                .value((expected * 10.0).round() as u64)
+                                .text_value(format!("{expected:.0}"))
+                                .style(Style::default().fg(Color::DarkGray))
+                                .value_style(Style::default().fg(Color::Black).bg(Color::DarkGray)),
+                        ])
+                    })
+                    .collect();
+                let mut chart = BarChart::default()
+                    .block(Block::default().title("First digit share (%)").borders(Borders::ALL))
+                    .bar_width(3)
+                    .bar_gap(0)
+                    .group_gap(2);
+                for group in groups {
+                    chart = chart.data(group);
+                }
+                chart.render(chunks[1], buf);
+            }
+            None => {
+                let error = self.error.as_deref().unwrap_or("No values to analyse");
+                Paragraph::new(format!("Error: {error}"))
+                    .style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
+                    .wrap(Wrap { trim: true })
+                    .render(layout.content_area, buf);
+            }
+        }
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Handle keyboard events for the dialog
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        match self.config.action_for_key(Mode::Global, key) {
+            Some(Action::Escape) => return Some(Action::DialogClose),
+            Some(Action::ToggleInstructions) => self.show_instructions = !self.show_instructions,
+            _ => {}
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    #[test]
+    fn test_render_and_close() {
+        let mut dialog = BenfordDialog::new(&Column::new("amount".into(), (1i64..=300).collect::<Vec<_>>()));
+        dialog.config.reset_keybindings_to_default();
+        assert_eq!(dialog.analysis.as_ref().map(|a| a.total()), Some(300));
+        let area = Rect::new(0, 0, 100, 30);
+        let mut buf = Buffer::empty(area);
+        dialog.render(area, &mut buf);
+        let text: String = buf.content().iter().map(|c| c.symbol()).collect();
+        assert!(text.contains("Chi-square"));
+
+        let text_column = BenfordDialog::new(&Column::new("s".into(), ["a"]));
+        assert!(text_column.analysis.is_none() && text_column.error.is_some());
+        assert_eq!(dialog.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)), Some(Action::DialogClose));
+    }
+}
@@ -0,0 +1,553 @@
+//! CellViewerDialog: Full-screen viewer for the selected cell with JSON folding and syntax highlighting
+
+use std::collections::HashSet;
+use std::io::Read;
+
+use base64::Engine;
+use base64::engine::general_purpose::{STANDARD, STANDARD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD};
+use flate2::read::GzDecoder;
+
+use crossterm::event::{KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Clear, Paragraph, Wrap};
+use serde_json::Value;
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::components::syntax_highlight::{detect_content_kind, highlight_line, ContentKind};
+use crate::config::{Config, Mode};
+use crate::dialog::hex_viewer_dialog::{format_hex_line, parse_hex_string, BYTES_PER_LINE};
+
+const INDENT: &str = "  ";
+
+/// A single rendered line of the viewer, with the fold path if the line opens an object/array
+#[derive(Debug, Clone)]
+pub struct ViewerLine {
+    pub line: Line<'static>,
+    pub fold_path: Option<String>,
+}
+
+/// Try to interpret a cell as JSON. Only objects and arrays are treated as JSON documents.
+pub fn parse_json_cell(value: &Value) -> Option<Value> {
+    match value {
+        Value::Object(_) | Value::Array(_) => Some(value.clone()),
+        Value::String(s) => {
+            let trimmed = s.trim_start();
+            if !(trimmed.starts_with('{') || trimmed.starts_with('[')) {
+                return None;
+            }
+            serde_json::from_str::<Value>(s)
+                .ok()
+                .filter(|v| v.is_object() || v.is_array())
+        }
+        _ => None,
+    }
+}
+
+fn json_scalar_span(value: &Value) -> Span<'static> {
+    match value {
+        Value::String(s) => Span::styled(
+            serde_json::to_string(s).unwrap_or_default(),
+            Style::default().fg(Color::Green),
+        ),
+        Value::Number(n) => Span::styled(n.to_string(), Style::default().fg(Color::Magenta)),
+        Value::Bool(b) => Span::styled(b.to_string(), Style::default().fg(Color::Yellow)),
+        Value::Null => Span::styled("null", Style::default().fg(Color::DarkGray)),
+        other => Span::raw(other.to_string()),
+    }
+}
+
+/// Flatten a JSON value into pretty-printed lines, honouring collapsed paths
+pub fn json_to_lines(value: &Value, collapsed: &HashSet<String>) -> Vec<ViewerLine> {
+    let mut out = Vec::new();
+    push_json_lines(value, None, String::new(), 0, false, collapsed, &mut out);
+    out
+}
+
+fn push_json_lines(
+    value: &Value,
+    key: Option<&str>,
+    path: String,
+    depth: usize,
+    trailing_comma: bool,
+    collapsed: &HashSet<String>,
+    out: &mut Vec<ViewerLine>,
+) {
+    let mut prefix = vec![Span::raw(INDENT.repeat(depth))];
+    if let Some(k) = key {
+        prefix.push(Span::styled(
+            serde_json::to_string(k).unwrap_or_default(),
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        ));
+        prefix.push(Span::raw(": "));
+    }
+    let comma = if trailing_comma { "," } else { "" };
+
+    let (open, close, len) = match value {
+        Value::Object(map) => ("{", "}", map.len()),
+        Value::Array(items) => ("[", "]", items.len()),
+        scalar => {
+            prefix.push(json_scalar_span(scalar));
+            prefix.push(Span::raw(comma));
+            out.push(ViewerLine { line: Line::from(prefix), fold_path: None });
+            return;
+        }
+    };
+
+    if len == 0 {
+        prefix.push(Span::raw(format!("{open}{close}{comma}")));
+        out.push(ViewerLine { line: Line::from(prefix), fold_path: None });
+        return;
+    }
+
+    if collapsed.contains(&path) {
+        let summary = if value.is_object() { format!("{len} keys") } else { format!("{len} items") };
+        prefix.push(Span::raw(format!("{open}…{close}{comma} ")));
+        prefix.push(Span::styled(format!("// {summary}"), Style::default().fg(Color::DarkGray)));
+        out.push(ViewerLine { line: Line::from(prefix), fold_path: Some(path) });
+        return;
+    }
+
+    prefix.push(Span::raw(open));
+    out.push(ViewerLine { line: Line::from(prefix), fold_path: Some(path.clone()) });
+    match value {
+        Value::Object(map) => {
+            for (i, (k, v)) in map.iter().enumerate() {
+                let child_path = format!("{path}/{}", k.replace('~', "~0").replace('/', "~1"));
+                push_json_lines(v, Some(k), child_path, depth + 1, i + 1 < len, collapsed, out);
+            }
+        }
+        Value::Array(items) => {
+            for (i, v) in items.iter().enumerate() {
+                push_json_lines(v, None, format!("{path}/{i}"), depth + 1, i + 1 < len, collapsed, out);
+            }
+        }
+        _ => {}
+    }
+    out.push(ViewerLine {
+        line: Line::from(format!("{}{close}{comma}", INDENT.repeat(depth))),
+        fold_path: None,
+    });
+}
+
+/// A decoded rendering of a cell value, labelled with the decoding steps applied
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedPreview {
+    pub label: String,
+    pub text: String,
+}
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+/// Cap on inflated gzip output so a hostile cell can't exhaust memory
+const MAX_INFLATED_BYTES: u64 = 1 << 20;
+
+fn decode_base64(s: &str) -> Option<Vec<u8>> {
+    let trimmed = s.trim();
+    if trimmed.len() < 4
+        || !trimmed.bytes().all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'/' | b'-' | b'_' | b'='))
+    {
+        return None;
+    }
+    [STANDARD, STANDARD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD]
+        .iter()
+        .find_map(|engine| engine.decode(trimmed).ok())
+        .filter(|bytes| !bytes.is_empty())
+}
+
+fn inflate_gzip(bytes: &[u8]) -> Option<Vec<u8>> {
+    if !bytes.starts_with(&GZIP_MAGIC) {
+        return None;
+    }
+    let mut out = Vec::new();
+    GzDecoder::new(bytes).take(MAX_INFLATED_BYTES).read_to_end(&mut out).ok()?;
+    Some(out)
+}
+
+/// Render bytes as text when they are printable UTF-8, otherwise as a hex dump
+fn bytes_to_preview(bytes: &[u8]) -> (&'static str, String) {
+    if let Ok(text) = std::str::from_utf8(bytes)
+        && text.chars().all(|c| !c.is_control() || c.is_whitespace())
+    {
+        return ("text", text.to_string());
+    }
+    let dump = bytes
+        .chunks(BYTES_PER_LINE)
+        .enumerate()
+        .map(|(i, chunk)| format_hex_line(i * BYTES_PER_LINE, chunk))
+        .collect::<Vec<_>>()
+        .join("\n");
+    ("hex", dump)
+}
+
+/// Try URL, base64, hex and gzip decodings of a cell value.
+///
+/// Only decodings that produce something different from the raw value are returned.
+pub fn decode_previews(raw: &str) -> Vec<DecodedPreview> {
+    let mut previews = Vec::new();
+
+    if raw.contains('%')
+        && let Ok(decoded) = percent_encoding::percent_decode_str(raw).decode_utf8()
+        && decoded != raw
+    {
+        previews.push(DecodedPreview { label: "URL-decoded".to_string(), text: decoded.into_owned() });
+    }
+
+    // Hex strings are also valid base64, so try hex first
+    let encoded = parse_hex_string(raw)
+        .map(|bytes| ("Hex", bytes))
+        .or_else(|| decode_base64(raw).map(|bytes| ("Base64", bytes)));
+    if let Some((encoding, bytes)) = encoded {
+        match inflate_gzip(&bytes) {
+            Some(inflated) => {
+                let (kind, text) = bytes_to_preview(&inflated);
+                previews.push(DecodedPreview { label: format!("{encoding} → gzip → {kind}"), text });
+            }
+            None => {
+                let (kind, text) = bytes_to_preview(&bytes);
+                // Short words are often valid base64; only show binary output for longer values
+                if kind == "text" || raw.trim().len() >= 16 {
+                    previews.push(DecodedPreview { label: format!("{encoding} → {kind}"), text });
+                }
+            }
+        }
+    }
+    previews
+}
+
+/// Collect the paths of every non-empty object/array in a JSON value
+fn collect_fold_paths(value: &Value, path: String, out: &mut HashSet<String>) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (k, v) in map {
+                collect_fold_paths(v, format!("{path}/{}", k.replace('~', "~0").replace('/', "~1")), out);
+            }
+            out.insert(path);
+        }
+        Value::Array(items) if !items.is_empty() => {
+            for (i, v) in items.iter().enumerate() {
+                collect_fold_paths(v, format!("{path}/{i}"), out);
+            }
+            out.insert(path);
+        }
+        _ => {}
+    }
+}
+
+/// CellViewerDialog: view the full content of the selected cell
+#[derive(Debug)]
+pub struct CellViewerDialog {
+    pub column: String,
+    pub row: usize,
+    pub raw: String,
+    pub json: Option<Value>,
+    /// Detected content kind used for syntax highlighting of non-JSON text
+    pub kind: ContentKind,
+    /// JSON pointer paths of collapsed objects/arrays
+    pub collapsed: HashSet<String>,
+    /// Show decoded renderings next to the raw value
+    pub show_decoded: bool,
+    pub decoded: Vec<DecodedPreview>,
+    pub selected: usize,
+    pub scroll_offset: usize,
+    pub visible_rows: usize,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+impl CellViewerDialog {
+    pub fn new(column: String, row: usize, value: Value) -> Self {
+        let json = parse_json_cell(&value);
+        let raw = match value {
+            Value::String(s) => s,
+            Value::Null => String::new(),
+            v => v.to_string(),
+        };
+        let kind = detect_content_kind(&raw);
+        let decoded = decode_previews(&raw);
+        Self {
+            column,
+            row,
+            raw,
+            json,
+            kind,
+            collapsed: HashSet::new(),
+            show_decoded: false,
+            decoded,
+            selected: 0,
+            scroll_offset: 0,
+            visible_rows: 10,
+            show_instructions: true,
+            config: Config::default(),
+        }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    /// Lines currently shown: pretty-printed JSON when available, otherwise the highlighted raw text
+    pub fn lines(&self) -> Vec<ViewerLine> {
+        match &self.json {
+            Some(json) => json_to_lines(json, &self.collapsed),
+            None => self
+                .raw
+                .lines()
+                .map(|l| ViewerLine { line: highlight_line(l, self.kind), fold_path: None })
+                .collect(),
+        }
+    }
+
+    /// Toggle folding of the object/array opened on the selected line
+    pub fn toggle_fold(&mut self) {
+        let Some(path) = self.lines().get(self.selected).and_then(|l| l.fold_path.clone()) else {
+            return;
+        };
+        if !self.collapsed.remove(&path) {
+            self.collapsed.insert(path);
+        }
+    }
+
+    pub fn expand_all(&mut self) {
+        self.collapsed.clear();
+    }
+
+    /// Collapse every nested object/array, leaving the top level open
+    pub fn collapse_all(&mut self) {
+        if let Some(json) = &self.json {
+            let mut paths = HashSet::new();
+            collect_fold_paths(json, String::new(), &mut paths);
+            paths.remove("");
+            self.collapsed = paths;
+        }
+        self.selected = 0;
+        self.scroll_offset = 0;
+    }
+
+    fn update_scroll_offset(&mut self) {
+        let visible_rows = self.visible_rows.max(1);
+        if self.selected < self.scroll_offset {
+            self.scroll_offset = self.selected;
+        } else if self.selected >= self.scroll_offset + visible_rows {
+            self.scroll_offset = self.selected + 1 - visible_rows;
+        }
+    }
+
+    /// Build instructions string from configured keybindings
+    fn build_instructions_from_config(&self) -> String {
+        self.config.actions_to_instructions(&[
+            (Mode::Global, Action::Escape),
+            (Mode::CellViewer, Action::ToggleFold),
+            (Mode::CellViewer, Action::ExpandAllFolds),
+            (Mode::CellViewer, Action::CollapseAllFolds),
+            (Mode::CellViewer, Action::ToggleDecodedView),
+            (Mode::CellViewer, Action::GoToFirst),
+            (Mode::CellViewer, Action::GoToLast),
+            (Mode::Global, Action::ToggleInstructions),
+        ])
+    }
+
+    /// Render the dialog
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let kind = match (&self.json, self.kind) {
+            (Some(_), _) | (None, ContentKind::Json) => " [JSON]",
+            (None, ContentKind::Xml) => " [XML]",
+            (None, ContentKind::Sql) => " [SQL]",
+            (None, ContentKind::Url) => " [URL]",
+            (None, ContentKind::Plain) => "",
+        };
+        let outer_block = Block::default()
+            .title(format!("{} (row {}){kind}", self.column, self.row + 1))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions,
+            if instructions.is_empty() { None } else { Some(instructions.as_str()) });
+        let mut content = layout.content_area;
+
+        if self.show_decoded {
+            let decoded_area = Rect {
+                x: content.x + content.width / 2,
+                width: content.width - content.width / 2,
+                ..content
+            };
+            content.width /= 2;
+            self.render_decoded(decoded_area, buf);
+        }
+
+        let lines = self.lines();
+        self.visible_rows = (content.height as usize).max(1);
+        self.selected = self.selected.min(lines.len().saturating_sub(1));
+        self.update_scroll_offset();
+
+        if self.json.is_some() {
+            // One JSON line per row so the cursor maps directly to a fold target
+            let end = (self.scroll_offset + self.visible_rows).min(lines.len());
+            for (i, idx) in (self.scroll_offset..end).enumerate() {
+                let y = content.y + i as u16;
+                buf.set_line(content.x, y, &lines[idx].line, content.width);
+                if idx == self.selected {
+                    buf.set_style(Rect { x: content.x, y, width: content.width, height: 1 }, Style::default().bg(Color::Rgb(40, 40, 60)));
+                }
+            }
+        } else {
+            let text: Vec<Line> = lines.into_iter().map(|l| l.line).collect();
+            Paragraph::new(text)
+                .wrap(Wrap { trim: false })
+                .scroll((self.scroll_offset as u16, 0))
+                .render(content, buf);
+        }
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Render the decoded previews panel
+    fn render_decoded(&self, area: Rect, buf: &mut Buffer) {
+        let block = Block::default().title("Decoded").borders(Borders::ALL);
+        let mut lines: Vec<Line> = Vec::new();
+        if self.decoded.is_empty() {
+            lines.push(Line::styled("No URL, base64, hex or gzip content detected", Style::default().fg(Color::DarkGray)));
+        }
+        for preview in &self.decoded {
+            lines.push(Line::styled(preview.label.clone(), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)));
+            lines.extend(preview.text.lines().map(|l| Line::from(l.to_string())));
+            lines.push(Line::from(""));
+        }
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false })
+            .render(area, buf);
+    }
+
+    /// Handle keyboard events for the dialog
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        let total = self.lines().len();
+        if let Some(global_action) = self.config.action_for_key(Mode::Global, key) {
+            match global_action {
+                Action::Escape => return Some(Action::DialogClose),
+                Action::Enter => {
+                    self.toggle_fold();
+                    return None;
+                }
+                Action::Up => {
+                    self.selected = self.selected.saturating_sub(1);
+                    self.update_scroll_offset();
+                    return None;
+                }
+                Action::Down => {
+                    if self.selected + 1 < total {
+                        self.selected += 1;
+                        self.update_scroll_offset();
+                    }
+                    return None;
+                }
+                Action::ToggleInstructions => {
+                    self.show_instructions = !self.show_instructions;
+                    return None;
+                }
+                _ => {}
+            }
+        }
+        if let Some(dialog_action) = self.config.action_for_key(Mode::CellViewer, key) {
+            let page_size = self.visible_rows.saturating_sub(1).max(1);
+            match dialog_action {
+                Action::ToggleFold => self.toggle_fold(),
+                Action::ExpandAllFolds => self.expand_all(),
+                Action::CollapseAllFolds => self.collapse_all(),
+                Action::ToggleDecodedView => self.show_decoded = !self.show_decoded,
+                Action::GoToFirst => self.selected = 0,
+                Action::GoToLast => self.selected = total.saturating_sub(1),
+                Action::PageUp => self.selected = self.selected.saturating_sub(page_size),
+                Action::PageDown => self.selected = (self.selected + page_size).min(total.saturating_sub(1)),
+                _ => return None,
+            }
+            self.update_scroll_offset();
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_text(line: &ViewerLine) -> String {
+        line.line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_json_string_is_pretty_printed() {
+        let dialog = CellViewerDialog::new(
+            "payload".to_string(),
+            0,
+            Value::String(r#"{"a": 1, "b": [true, null]}"#.to_string()),
+        );
+        assert!(dialog.json.is_some());
+        let text: Vec<String> = dialog.lines().iter().map(line_text).collect();
+        assert_eq!(text, vec![
+            "{",
+            "  \"a\": 1,",
+            "  \"b\": [",
+            "    true,",
+            "    null",
+            "  ]",
+            "}",
+        ]);
+        let plain = CellViewerDialog::new("c".to_string(), 0, Value::String("{not json".to_string()));
+        assert!(plain.json.is_none());
+    }
+
+    #[test]
+    fn test_decode_previews() {
+        let previews = decode_previews("a%20b%2Fc");
+        assert_eq!(previews, vec![DecodedPreview { label: "URL-decoded".to_string(), text: "a b/c".to_string() }]);
+
+        let previews = decode_previews(&STANDARD.encode("hello world"));
+        assert_eq!(previews[0].label, "Base64 → text");
+        assert_eq!(previews[0].text, "hello world");
+
+        let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        std::io::Write::write_all(&mut gz, b"inflated!").unwrap();
+        let previews = decode_previews(&STANDARD.encode(gz.finish().unwrap()));
+        assert_eq!(previews[0].label, "Base64 → gzip → text");
+        assert_eq!(previews[0].text, "inflated!");
+
+        assert!(decode_previews("plain words").is_empty());
+        assert!(decode_previews("test").is_empty());
+    }
+
+    #[test]
+    fn test_fold_and_unfold() {
+        let mut dialog = CellViewerDialog::new(
+            "payload".to_string(),
+            0,
+            Value::String(r#"{"a": {"x": 1, "y": 2}, "b": 3}"#.to_string()),
+        );
+        dialog.selected = 1;
+        dialog.toggle_fold();
+        let text: Vec<String> = dialog.lines().iter().map(line_text).collect();
+        assert_eq!(text[1], "  \"a\": {…}, // 2 keys");
+        assert_eq!(text.len(), 4);
+        dialog.toggle_fold();
+        assert_eq!(dialog.lines().len(), 7);
+
+        dialog.collapse_all();
+        assert_eq!(dialog.lines().len(), 4);
+        dialog.expand_all();
+        assert_eq!(dialog.lines().len(), 7);
+    }
+}
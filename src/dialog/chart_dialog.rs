@@ -0,0 +1,262 @@
+//! ChartDialog: histogram or value-count bar chart of the selected column
+
+use crossterm::event::{KeyEvent, KeyEventKind};
+use polars::prelude::Column;
+use ratatui::prelude::*;
+use ratatui::widgets::{Bar, BarChart, BarGroup, Block, Borders, BorderType, Clear, Paragraph, Wrap};
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+use crate::dataframe::histogram::{histogram, value_counts, ChartBar};
+
+const MIN_BUCKETS: usize = 1;
+const MAX_BUCKETS: usize = 100;
+
+/// What the chart shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChartKind {
+    /// Equal-width buckets of a numeric column
+    Histogram,
+    /// The most frequent values of any column
+    ValueCounts,
+    /// Bars computed elsewhere (e.g. a resampled time series)
+    Series,
+}
+
+impl ChartKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChartKind::Histogram => "Histogram",
+            ChartKind::ValueCounts => "Value Counts",
+            ChartKind::Series => "Series",
+        }
+    }
+}
+
+/// ChartDialog: bar chart of one column with bucket-count and log-scale options
+#[derive(Debug)]
+pub struct ChartDialog {
+    pub title: String,
+    /// Source values; None for precomputed series
+    pub column: Option<Column>,
+    pub kind: ChartKind,
+    /// Histogram buckets, or the number of values shown for value counts
+    pub buckets: usize,
+    pub log_scale: bool,
+    pub bars: Vec<ChartBar>,
+    pub error: Option<String>,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+impl ChartDialog {
+    /// Numeric columns open as a histogram, everything else as value counts
+    pub fn new(column: Column) -> Self {
+        let kind = if column.dtype().is_primitive_numeric() { ChartKind::Histogram } else { ChartKind::ValueCounts };
+        let mut dialog = Self {
+            title: column.name().to_string(),
+            column: Some(column),
+            kind,
+            buckets: 10,
+            log_scale: false,
+            bars: Vec::new(),
+            error: None,
+            show_instructions: true,
+            config: Config::default(),
+        };
+        dialog.recompute();
+        dialog
+    }
+
+    /// Chart precomputed bars as-is; bucket and kind options do not apply
+    pub fn with_bars(title: impl Into<String>, bars: Vec<ChartBar>) -> Self {
+        Self {
+            title: title.into(),
+            column: None,
+            kind: ChartKind::Series,
+            buckets: bars.len(),
+            log_scale: false,
+            bars,
+            error: None,
+            show_instructions: true,
+            config: Config::default(),
+        }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn recompute(&mut self) {
+        let Some(column) = &self.column else { return };
+        let result = match self.kind {
+            ChartKind::Histogram => histogram(column, self.buckets),
+            ChartKind::ValueCounts | ChartKind::Series => value_counts(column, self.buckets),
+        };
+        match result {
+            Ok(bars) => {
+                self.bars = bars;
+                self.error = None;
+            }
+            Err(e) => {
+                self.bars.clear();
+                self.error = Some(e.to_string());
+            }
+        }
+    }
+
+    fn toggle_kind(&mut self) {
+        let Some(column) = &self.column else { return };
+        if !column.dtype().is_primitive_numeric() {
+            self.error = Some("Histograms need a numeric column".to_string());
+            return;
+        }
+        self.kind = match self.kind {
+            ChartKind::Histogram => ChartKind::ValueCounts,
+            ChartKind::ValueCounts | ChartKind::Series => ChartKind::Histogram,
+        };
+        self.recompute();
+    }
+
+    fn set_buckets(&mut self, buckets: usize) {
+        let buckets = buckets.clamp(MIN_BUCKETS, MAX_BUCKETS);
+        if self.column.is_some() && buckets != self.buckets {
+            self.buckets = buckets;
+            self.recompute();
+        }
+    }
+
+    /// Bar height; log scale keeps small buckets visible next to dominant ones
+    fn bar_value(&self, count: u64) -> u64 {
+        if self.log_scale { ((count as f64 + 1.0).ln() * 1000.0) as u64 } else { count }
+    }
+
+    fn build_instructions_from_config(&self) -> String {
+        format!(
+            "{}  Buckets: Left/Right",
+            self.config.actions_to_instructions(&[
+                (Mode::ChartDialog, Action::ToggleChartKind),
+                (Mode::ChartDialog, Action::ToggleChartLogScale),
+                (Mode::Global, Action::Escape),
+                (Mode::Global, Action::ToggleInstructions),
+            ])
+        )
+    }
+
+    /// Render the dialog
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title(format!("Chart: {}", self.title))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let unit = match self.kind {
+            ChartKind::Histogram => "buckets",
+            ChartKind::ValueCounts => "values",
+            ChartKind::Series => "bars",
+        };
+        let scale = if self.log_scale { "log" } else { "linear" };
+        let block = Block::default()
+            .title(format!("{} ({} {unit}, {scale} scale)", self.kind.label(), self.buckets))
+            .borders(Borders::ALL);
+        let content = block.inner(layout.content_area);
+        block.render(layout.content_area, buf);
+
+        if let Some(error) = &self.error {
+            buf.set_string(content.x + 1, content.y, format!("Error: {error}"), Style::default().fg(Color::Red).add_modifier(Modifier::BOLD));
+        } else if self.bars.is_empty() {
+            buf.set_string(content.x + 1, content.y, "No values to chart", Style::default().fg(Color::DarkGray));
+        } else {
+            let bars: Vec<Bar> = self
+                .bars
+                .iter()
+                .map(|b| {
+                    Bar::default()
+                        .label(Line::from(b.label.clone()))
+                        .value(self.bar_value(b.count))
+                        .text_value(b.count.to_string())
+                        .style(Style::default().fg(Color::Cyan))
+                        .value_style(Style::default().fg(Color::Black).bg(Color::Cyan))
+                })
+                .collect();
+            BarChart::default()
+                .direction(Direction::Horizontal)
+                .bar_width(1)
+                .bar_gap(0)
+                .data(BarGroup::default().bars(&bars))
+                .render(content, buf);
+        }
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Handle keyboard events for the dialog
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        match self.config.action_for_key(Mode::ChartDialog, key) {
+            Some(Action::ToggleChartKind) => {
+                self.toggle_kind();
+                return None;
+            }
+            Some(Action::ToggleChartLogScale) => {
+                self.log_scale = !self.log_scale;
+                return None;
+            }
+            _ => {}
+        }
+        match self.config.action_for_key(Mode::Global, key) {
+            Some(Action::Escape) => return Some(Action::DialogClose),
+            Some(Action::Left) => self.set_buckets(self.buckets.saturating_sub(1)),
+            Some(Action::Right) => self.set_buckets(self.buckets + 1),
+            Some(Action::ToggleInstructions) => self.show_instructions = !self.show_instructions,
+            _ => {}
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    #[test]
+    fn test_buckets_kind_and_log_scale() {
+        let mut dialog = ChartDialog::new(Column::new("n".into(), (0i64..100).collect::<Vec<_>>()));
+        dialog.config.reset_keybindings_to_default();
+        assert_eq!(dialog.kind, ChartKind::Histogram);
+        assert_eq!(dialog.bars.len(), 10);
+
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Left, KeyModifiers::NONE));
+        assert_eq!(dialog.bars.len(), 9);
+
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Char('l'), KeyModifiers::CONTROL));
+        assert!(dialog.log_scale);
+        assert!(dialog.bar_value(1000) < dialog.bar_value(10) * 10);
+
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Char('k'), KeyModifiers::CONTROL));
+        assert_eq!(dialog.kind, ChartKind::ValueCounts);
+        assert_eq!(dialog.bars.len(), 9);
+
+        let mut text = ChartDialog::new(Column::new("s".into(), ["a", "b"]));
+        text.toggle_kind();
+        assert_eq!(text.kind, ChartKind::ValueCounts);
+        assert!(text.error.is_some());
+    }
+}
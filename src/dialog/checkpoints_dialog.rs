@@ -0,0 +1,234 @@
+//! CheckpointsDialog: save the current view under a name and restore saved views later
+
+use crossterm::event::{KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Cell, Clear, Paragraph, Row, Table, TableState, Wrap};
+use tui_textarea::TextArea;
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+use crate::dataframe::checkpoints::Checkpoint;
+
+#[derive(Debug)]
+pub enum CheckpointsDialogMode {
+    List,
+    /// Naming a new checkpoint of the current view
+    NameInput(Box<TextArea<'static>>),
+}
+
+/// CheckpointsDialog: list of a dataset's checkpoints
+#[derive(Debug)]
+pub struct CheckpointsDialog {
+    pub checkpoints: Vec<Checkpoint>,
+    pub selected: usize,
+    pub mode: CheckpointsDialogMode,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+impl CheckpointsDialog {
+    pub fn new(checkpoints: Vec<Checkpoint>) -> Self {
+        Self {
+            checkpoints,
+            selected: 0,
+            mode: CheckpointsDialogMode::List,
+            show_instructions: true,
+            config: Config::default(),
+        }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    /// Show the updated list, selecting the newest checkpoint
+    pub fn set_checkpoints(&mut self, checkpoints: Vec<Checkpoint>) {
+        self.selected = checkpoints.len().saturating_sub(1);
+        self.checkpoints = checkpoints;
+    }
+
+    fn build_instructions_from_config(&self) -> String {
+        match self.mode {
+            CheckpointsDialogMode::List => format!(
+                "Enter: Restore  {}",
+                self.config.actions_to_instructions(&[
+                    (Mode::CheckpointsDialog, Action::AddCheckpoint),
+                    (Mode::CheckpointsDialog, Action::DeleteCheckpoint),
+                    (Mode::CheckpointsDialog, Action::ToggleCheckpointParquet),
+                    (Mode::Global, Action::Escape),
+                    (Mode::Global, Action::ToggleInstructions),
+                ])
+            ),
+            CheckpointsDialogMode::NameInput(_) => self.config.actions_to_instructions(&[
+                (Mode::Global, Action::Enter),
+                (Mode::Global, Action::Escape),
+            ]),
+        }
+    }
+
+    /// Render the dialog
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title("Checkpoints")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let content = layout.content_area;
+
+        if let CheckpointsDialogMode::NameInput(input) = &self.mode {
+            let input_area = Rect { height: content.height.min(3), ..content };
+            let block = Block::default().title("Checkpoint name").borders(Borders::ALL);
+            let input_inner = block.inner(input_area);
+            block.render(input_area, buf);
+            input.render(input_inner, buf);
+        } else if self.checkpoints.is_empty() {
+            Paragraph::new("No checkpoints yet. Add one to save the current view.")
+                .style(Style::default().fg(Color::Gray))
+                .render(content, buf);
+        } else {
+            let rows = self.checkpoints.iter().map(|cp| {
+                Row::new(vec![
+                    Cell::from(cp.name.clone()),
+                    Cell::from(format!("{} x {}", cp.rows, cp.columns)),
+                    Cell::from(cp.history.len().to_string()),
+                    Cell::from(if cp.store_parquet { "Parquet" } else { "History" }),
+                    Cell::from(cp.created_at.format("%Y-%m-%d %H:%M:%S").to_string()),
+                ])
+            });
+            let table = Table::new(
+                rows,
+                [
+                    Constraint::Min(16),
+                    Constraint::Length(14),
+                    Constraint::Length(6),
+                    Constraint::Length(9),
+                    Constraint::Length(19),
+                ],
+            )
+            .header(
+                Row::new(vec!["Name", "Rows x Cols", "Steps", "Saved as", "Created"])
+                    .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            )
+            .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+            let mut state = TableState::default().with_selected(Some(self.selected));
+            StatefulWidget::render(table, content, buf, &mut state);
+        }
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Handle keyboard events for the dialog
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        let global_action = self.config.action_for_key(Mode::Global, key);
+        if let CheckpointsDialogMode::NameInput(input) = &mut self.mode {
+            match global_action {
+                Some(Action::Escape) => self.mode = CheckpointsDialogMode::List,
+                Some(Action::Enter) => {
+                    let name = input.lines().join(" ").trim().to_string();
+                    if !name.is_empty() {
+                        self.mode = CheckpointsDialogMode::List;
+                        return Some(Action::CreateCheckpoint(name));
+                    }
+                }
+                _ => {
+                    input.input(tui_textarea::Input::from(key));
+                }
+            }
+            return None;
+        }
+
+        match global_action {
+            Some(Action::Escape) => return Some(Action::DialogClose),
+            Some(Action::Enter) => {
+                return (self.selected < self.checkpoints.len()).then_some(Action::RestoreCheckpoint(self.selected));
+            }
+            Some(Action::Up) => {
+                self.selected = self.selected.saturating_sub(1);
+                return None;
+            }
+            Some(Action::Down) => {
+                if self.selected + 1 < self.checkpoints.len() {
+                    self.selected += 1;
+                }
+                return None;
+            }
+            Some(Action::ToggleInstructions) => {
+                self.show_instructions = !self.show_instructions;
+                return None;
+            }
+            _ => {}
+        }
+
+        match self.config.action_for_key(Mode::CheckpointsDialog, key) {
+            Some(Action::AddCheckpoint) => {
+                let mut input = TextArea::default();
+                input.set_block(Block::default());
+                self.mode = CheckpointsDialogMode::NameInput(Box::new(input));
+                None
+            }
+            Some(Action::DeleteCheckpoint) if self.selected < self.checkpoints.len() => {
+                self.checkpoints.remove(self.selected);
+                self.selected = self.selected.min(self.checkpoints.len().saturating_sub(1));
+                Some(Action::SaveWorkspaceState)
+            }
+            Some(Action::ToggleCheckpointParquet) => {
+                let checkpoint = self.checkpoints.get_mut(self.selected)?;
+                checkpoint.store_parquet = !checkpoint.store_parquet;
+                Some(Action::SaveWorkspaceState)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dataframe::transform_history::TransformHistory;
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use polars::prelude::*;
+    use std::sync::Arc;
+
+    fn press(dialog: &mut CheckpointsDialog, code: KeyCode, modifiers: KeyModifiers) -> Option<Action> {
+        dialog.handle_key_event(KeyEvent::new(code, modifiers))
+    }
+
+    #[test]
+    fn test_name_restore_and_delete() {
+        let df = Arc::new(df!("a" => [1i64, 2]).unwrap());
+        let mut dialog = CheckpointsDialog::new(vec![Checkpoint::new("first".to_string(), df, TransformHistory::default())]);
+        dialog.config.reset_keybindings_to_default();
+
+        press(&mut dialog, KeyCode::Char('a'), KeyModifiers::CONTROL);
+        for c in "after dedup".chars() {
+            press(&mut dialog, KeyCode::Char(c), KeyModifiers::NONE);
+        }
+        assert_eq!(
+            press(&mut dialog, KeyCode::Enter, KeyModifiers::NONE),
+            Some(Action::CreateCheckpoint("after dedup".to_string()))
+        );
+        assert_eq!(press(&mut dialog, KeyCode::Enter, KeyModifiers::NONE), Some(Action::RestoreCheckpoint(0)));
+
+        press(&mut dialog, KeyCode::Char('t'), KeyModifiers::CONTROL);
+        assert!(!dialog.checkpoints[0].store_parquet);
+        assert_eq!(press(&mut dialog, KeyCode::Char('d'), KeyModifiers::CONTROL), Some(Action::SaveWorkspaceState));
+        assert!(dialog.checkpoints.is_empty());
+    }
+}
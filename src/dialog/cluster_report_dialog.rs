@@ -0,0 +1,180 @@
+//! ClusterReportDialog: inertia/silhouette for a KMeans run, or the elbow curve for a k sweep
+
+use crossterm::event::{KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::symbols;
+use ratatui::widgets::{Axis, Block, Borders, BorderType, Chart, Clear, Dataset, GraphType, Paragraph, Row, Table, Wrap};
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+use crate::dataframe::cluster_metrics::ClusterQuality;
+
+/// ClusterReportDialog: read-only summary of one or more clustering runs
+#[derive(Debug)]
+pub struct ClusterReportDialog {
+    pub column: String,
+    pub results: Vec<ClusterQuality>,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+impl ClusterReportDialog {
+    pub fn new(column: String, results: Vec<ClusterQuality>) -> Self {
+        Self {
+            column,
+            results,
+            show_instructions: true,
+            config: Config::default(),
+        }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    /// k with the highest silhouette, if any run has one
+    pub fn best_silhouette_k(&self) -> Option<usize> {
+        self.results
+            .iter()
+            .filter_map(|r| r.silhouette.map(|s| (r.k, s)))
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(k, _)| k)
+    }
+
+    /// Build instructions string from configured keybindings
+    fn build_instructions_from_config(&self) -> String {
+        self.config.actions_to_instructions(&[
+            (Mode::Global, Action::Escape),
+            (Mode::Global, Action::Enter),
+            (Mode::Global, Action::ToggleInstructions),
+        ])
+    }
+
+    /// Render the dialog
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let title = if self.results.len() > 1 {
+            format!("Elbow Sweep: {}", self.column)
+        } else {
+            format!("Cluster Quality: {}", self.column)
+        };
+        let outer_block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let content = layout.content_area;
+
+        let best_k = self.best_silhouette_k();
+        let rows: Vec<Row> = self
+            .results
+            .iter()
+            .map(|r| {
+                let silhouette = r.silhouette.map(|s| format!("{s:.4}")).unwrap_or_else(|| "-".to_string());
+                let row = Row::new(vec![r.k.to_string(), format!("{:.4}", r.inertia), silhouette]);
+                if self.results.len() > 1 && Some(r.k) == best_k {
+                    row.style(Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))
+                } else {
+                    row
+                }
+            })
+            .collect();
+        let table_height = (self.results.len() as u16 + 3).min(content.height);
+        let table_area = Rect { height: table_height, ..content };
+        let table = Table::new(rows, [Constraint::Length(6), Constraint::Length(20), Constraint::Length(12)])
+            .header(Row::new(vec!["k", "Inertia", "Silhouette"]).style(Style::default().fg(Color::Yellow)))
+            .block(Block::default().borders(Borders::ALL));
+        Widget::render(table, table_area, buf);
+
+        // Only a sweep has a curve to draw
+        let chart_area = Rect {
+            y: content.y + table_height,
+            height: content.height.saturating_sub(table_height),
+            ..content
+        };
+        if self.results.len() > 1 && chart_area.height >= 5 {
+            let points: Vec<(f64, f64)> = self.results.iter().map(|r| (r.k as f64, r.inertia)).collect();
+            let min_k = points.first().map(|p| p.0).unwrap_or(0.0);
+            let max_k = points.last().map(|p| p.0).unwrap_or(1.0);
+            let max_inertia = points.iter().map(|p| p.1).fold(0.0, f64::max).max(f64::MIN_POSITIVE);
+            let datasets = vec![Dataset::default()
+                .name("inertia")
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::Cyan))
+                .data(&points)];
+            let title = match best_k {
+                Some(k) => format!("Inertia by k (best silhouette at k = {k})"),
+                None => "Inertia by k".to_string(),
+            };
+            Chart::new(datasets)
+                .block(Block::default().borders(Borders::ALL).title(title))
+                .x_axis(
+                    Axis::default()
+                        .title("k")
+                        .bounds([min_k, max_k])
+                        .labels([format!("{min_k}"), format!("{max_k}")]),
+                )
+                .y_axis(
+                    Axis::default()
+                        .bounds([0.0, max_inertia])
+                        .labels(["0".to_string(), format!("{max_inertia:.1}")]),
+                )
+                .render(chart_area, buf);
+        }
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Handle keyboard events for the dialog
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        if let Some(global_action) = self.config.action_for_key(Mode::Global, key) {
+            match global_action {
+                Action::Escape | Action::Enter => return Some(Action::DialogClose),
+                Action::ToggleInstructions => self.show_instructions = !self.show_instructions,
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    #[test]
+    fn test_best_silhouette_and_close() {
+        let results = vec![
+            ClusterQuality { k: 2, inertia: 40.0, silhouette: Some(0.4) },
+            ClusterQuality { k: 3, inertia: 12.0, silhouette: Some(0.7) },
+            ClusterQuality { k: 4, inertia: 10.0, silhouette: None },
+        ];
+        let mut dialog = ClusterReportDialog::new("emb".to_string(), results);
+        dialog.config.reset_keybindings_to_default();
+        assert_eq!(dialog.best_silhouette_k(), Some(3));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 60, 30));
+        dialog.render(Rect::new(0, 0, 60, 30), &mut buf);
+
+        let action = dialog.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert_eq!(action, Some(Action::DialogClose));
+    }
+}
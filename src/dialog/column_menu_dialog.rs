@@ -0,0 +1,336 @@
+//! ColumnMenuDialog: quick actions on the selected column (sort, filter, value counts, hide, pin,
+//! wrap, color rows by group, cast, rename) from one menu, each listed with its direct key where it has one
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Clear, List, ListItem, ListState, Paragraph, Wrap};
+use tui_textarea::TextArea;
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+use crate::dataframe::schema_edit::TYPE_NAMES;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnMenuItem {
+    SortAscending,
+    SortDescending,
+    FilterOnValue,
+    ValueCounts,
+    Hide,
+    Pin,
+    Wrap,
+    GroupRows,
+    Cast,
+    Rename,
+}
+
+impl ColumnMenuItem {
+    pub const ALL: [ColumnMenuItem; 10] = [
+        ColumnMenuItem::SortAscending,
+        ColumnMenuItem::SortDescending,
+        ColumnMenuItem::FilterOnValue,
+        ColumnMenuItem::ValueCounts,
+        ColumnMenuItem::Hide,
+        ColumnMenuItem::Pin,
+        ColumnMenuItem::Wrap,
+        ColumnMenuItem::GroupRows,
+        ColumnMenuItem::Cast,
+        ColumnMenuItem::Rename,
+    ];
+
+    /// Table action that does the same from the keyboard
+    fn shortcut(self) -> Option<Action> {
+        match self {
+            ColumnMenuItem::SortAscending => Some(Action::QuickSortCurrentColumn),
+            ColumnMenuItem::FilterOnValue => Some(Action::QuickFilterEqualsCurrentValue),
+            ColumnMenuItem::ValueCounts => Some(Action::OpenValueCounts),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ColumnMenuMode {
+    List,
+    /// Picking the type to cast to, as an index into `TYPE_NAMES`
+    Cast(usize),
+    Rename(Box<TextArea<'static>>),
+}
+
+/// ColumnMenuDialog: returns the chosen action for the container to run on the selected column
+#[derive(Debug)]
+pub struct ColumnMenuDialog {
+    pub column: String,
+    /// Current type, as DataType's Debug output
+    pub dtype: String,
+    /// Selected cell value, offered by the filter item
+    pub value: String,
+    pub pinned: bool,
+    pub wrapped: bool,
+    /// Whether rows are colored by groups of this column
+    pub grouped: bool,
+    pub selected: usize,
+    pub mode: ColumnMenuMode,
+    pub error: Option<String>,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+impl ColumnMenuDialog {
+    pub fn new(column: String, dtype: String, value: String, pinned: bool, wrapped: bool, grouped: bool) -> Self {
+        Self {
+            column,
+            dtype,
+            value,
+            pinned,
+            wrapped,
+            grouped,
+            selected: 0,
+            mode: ColumnMenuMode::List,
+            error: None,
+            show_instructions: true,
+            config: Config::default(),
+        }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn item_label(&self, item: ColumnMenuItem) -> String {
+        match item {
+            ColumnMenuItem::SortAscending => "Sort ascending".to_string(),
+            ColumnMenuItem::SortDescending => "Sort descending".to_string(),
+            ColumnMenuItem::FilterOnValue => format!("Filter on \"{}\"", self.value),
+            ColumnMenuItem::ValueCounts => "Value counts".to_string(),
+            ColumnMenuItem::Hide => "Hide column".to_string(),
+            ColumnMenuItem::Pin if self.pinned => "Unpin column".to_string(),
+            ColumnMenuItem::Pin => "Pin column".to_string(),
+            ColumnMenuItem::Wrap if self.wrapped => "Show text on one line".to_string(),
+            ColumnMenuItem::Wrap => "Wrap text".to_string(),
+            ColumnMenuItem::GroupRows if self.grouped => "Stop coloring rows by group".to_string(),
+            ColumnMenuItem::GroupRows => "Color rows by value groups".to_string(),
+            ColumnMenuItem::Cast => format!("Cast ({})...", self.dtype),
+            ColumnMenuItem::Rename => "Rename...".to_string(),
+        }
+    }
+
+    fn build_instructions_from_config(&self) -> String {
+        let actions: &[(Mode, Action)] = match self.mode {
+            ColumnMenuMode::List | ColumnMenuMode::Cast(_) => &[
+                (Mode::Global, Action::Up),
+                (Mode::Global, Action::Down),
+                (Mode::Global, Action::Enter),
+                (Mode::Global, Action::Escape),
+                (Mode::Global, Action::ToggleInstructions),
+            ],
+            ColumnMenuMode::Rename(_) => &[(Mode::Global, Action::Enter), (Mode::Global, Action::Escape)],
+        };
+        self.config.actions_to_instructions(actions)
+    }
+
+    /// Render the dialog
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title(format!("Column: {}", self.column))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(if self.error.is_some() { 2 } else { 0 })])
+            .split(layout.content_area);
+
+        let highlight = Style::default().add_modifier(Modifier::REVERSED);
+        match &self.mode {
+            ColumnMenuMode::List => {
+                let width = chunks[0].width as usize;
+                let items: Vec<ListItem> = ColumnMenuItem::ALL
+                    .iter()
+                    .map(|item| {
+                        let label = self.item_label(*item);
+                        let key = item
+                            .shortcut()
+                            .and_then(|a| self.config.key_for_action(Mode::DataTableContainer, &a))
+                            .unwrap_or_default();
+                        let gap = width.saturating_sub(label.chars().count() + key.chars().count()).max(1);
+                        ListItem::new(Line::from(vec![
+                            Span::raw(label),
+                            Span::raw(" ".repeat(gap)),
+                            Span::styled(key, Style::default().fg(Color::DarkGray)),
+                        ]))
+                    })
+                    .collect();
+                let mut state = ListState::default().with_selected(Some(self.selected));
+                StatefulWidget::render(List::new(items).highlight_style(highlight), chunks[0], buf, &mut state);
+            }
+            ColumnMenuMode::Cast(selected) => {
+                let items: Vec<ListItem> = TYPE_NAMES.iter().map(|t| ListItem::new(*t)).collect();
+                let mut state = ListState::default().with_selected(Some(*selected));
+                let list = List::new(items)
+                    .block(Block::default().title(format!("Cast from {} to", self.dtype)).borders(Borders::ALL))
+                    .highlight_style(highlight);
+                StatefulWidget::render(list, chunks[0], buf, &mut state);
+            }
+            ColumnMenuMode::Rename(input) => {
+                let input_area = Rect { height: chunks[0].height.min(3), ..chunks[0] };
+                let block = Block::default().title("New name").borders(Borders::ALL);
+                let inner = block.inner(input_area);
+                block.render(input_area, buf);
+                input.render(inner, buf);
+            }
+        }
+
+        if let Some(error) = &self.error {
+            Paragraph::new(format!("Error: {error}"))
+                .style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
+                .wrap(Wrap { trim: true })
+                .render(chunks[1], buf);
+        }
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Handle keyboard events for the dialog
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        let global_action = self.config.action_for_key(Mode::Global, key);
+        match &mut self.mode {
+            ColumnMenuMode::Rename(input) => {
+                match global_action {
+                    Some(Action::Escape) => self.mode = ColumnMenuMode::List,
+                    Some(Action::Enter) => {
+                        let name = input.lines().join(" ").trim().to_string();
+                        if name.is_empty() || name == self.column {
+                            self.mode = ColumnMenuMode::List;
+                        } else {
+                            return Some(Action::ColumnRenameRequested { column: self.column.clone(), name });
+                        }
+                    }
+                    _ => {
+                        if matches!(key.code, KeyCode::Char(_) | KeyCode::Backspace | KeyCode::Delete | KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End) {
+                            input.input(tui_textarea::Input::from(key));
+                            self.error = None;
+                        }
+                    }
+                }
+                return None;
+            }
+            ColumnMenuMode::Cast(selected) => {
+                match global_action {
+                    Some(Action::Escape) => self.mode = ColumnMenuMode::List,
+                    Some(Action::Up) => *selected = selected.saturating_sub(1),
+                    Some(Action::Down) => *selected = (*selected + 1).min(TYPE_NAMES.len() - 1),
+                    Some(Action::Enter) => {
+                        return Some(Action::ColumnCastRequested { column: self.column.clone(), dtype: TYPE_NAMES[*selected].to_string() });
+                    }
+                    _ => {}
+                }
+                return None;
+            }
+            ColumnMenuMode::List => {}
+        }
+
+        match global_action {
+            Some(Action::Escape) => Some(Action::DialogClose),
+            Some(Action::Up) => {
+                self.selected = self.selected.saturating_sub(1);
+                None
+            }
+            Some(Action::Down) => {
+                self.selected = (self.selected + 1).min(ColumnMenuItem::ALL.len() - 1);
+                None
+            }
+            Some(Action::ToggleInstructions) => {
+                self.show_instructions = !self.show_instructions;
+                None
+            }
+            Some(Action::Enter) => match ColumnMenuItem::ALL[self.selected] {
+                ColumnMenuItem::SortAscending => Some(Action::SortSelectedColumn { ascending: true }),
+                ColumnMenuItem::SortDescending => Some(Action::SortSelectedColumn { ascending: false }),
+                ColumnMenuItem::FilterOnValue => Some(Action::QuickFilterEqualsCurrentValue),
+                ColumnMenuItem::ValueCounts => Some(Action::OpenValueCounts),
+                ColumnMenuItem::Hide => Some(Action::HideSelectedColumn),
+                ColumnMenuItem::Pin => Some(Action::TogglePinSelectedColumn),
+                ColumnMenuItem::Wrap => Some(Action::ToggleWrapSelectedColumn),
+                ColumnMenuItem::GroupRows => Some(Action::ToggleGroupRowsBySelectedColumn),
+                ColumnMenuItem::Cast => {
+                    let current = TYPE_NAMES.iter().position(|t| self.dtype.starts_with(t)).unwrap_or(0);
+                    self.mode = ColumnMenuMode::Cast(current);
+                    None
+                }
+                ColumnMenuItem::Rename => {
+                    let mut input = TextArea::default();
+                    input.set_block(Block::default());
+                    input.insert_str(&self.column);
+                    self.mode = ColumnMenuMode::Rename(Box::new(input));
+                    None
+                }
+            },
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn press(dialog: &mut ColumnMenuDialog, code: KeyCode) -> Option<Action> {
+        dialog.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn test_menu_items_cast_and_rename() {
+        let mut dialog = ColumnMenuDialog::new("bytes".to_string(), "String".to_string(), "42".to_string(), false, false, false);
+        dialog.config.reset_keybindings_to_default();
+
+        press(&mut dialog, KeyCode::Down);
+        assert_eq!(press(&mut dialog, KeyCode::Enter), Some(Action::SortSelectedColumn { ascending: false }));
+        for _ in 0..4 {
+            press(&mut dialog, KeyCode::Down);
+        }
+        assert_eq!(press(&mut dialog, KeyCode::Enter), Some(Action::TogglePinSelectedColumn));
+        press(&mut dialog, KeyCode::Down);
+        assert_eq!(press(&mut dialog, KeyCode::Enter), Some(Action::ToggleWrapSelectedColumn));
+        press(&mut dialog, KeyCode::Down);
+        assert_eq!(press(&mut dialog, KeyCode::Enter), Some(Action::ToggleGroupRowsBySelectedColumn));
+
+        press(&mut dialog, KeyCode::Down);
+        assert_eq!(press(&mut dialog, KeyCode::Enter), None);
+        press(&mut dialog, KeyCode::Down);
+        assert_eq!(
+            press(&mut dialog, KeyCode::Enter),
+            Some(Action::ColumnCastRequested { column: "bytes".to_string(), dtype: "Int64".to_string() })
+        );
+
+        press(&mut dialog, KeyCode::Esc);
+        press(&mut dialog, KeyCode::Down);
+        press(&mut dialog, KeyCode::Enter);
+        press(&mut dialog, KeyCode::Char('_'));
+        press(&mut dialog, KeyCode::Char('n'));
+        assert_eq!(
+            press(&mut dialog, KeyCode::Enter),
+            Some(Action::ColumnRenameRequested { column: "bytes".to_string(), name: "bytes_n".to_string() })
+        );
+        assert_eq!(press(&mut dialog, KeyCode::Esc), None);
+        assert_eq!(press(&mut dialog, KeyCode::Esc), Some(Action::DialogClose));
+    }
+}
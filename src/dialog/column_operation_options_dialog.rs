@@ -13,6 +13,12 @@ use crate::style::StyleConfig;
 use crate::action::Action;
 use super::column_operations_dialog::ColumnOperationKind;
 use crate::dialog::LlmProvider;
+use crate::dataframe::hashing::HashAlgorithm;
+use crate::threat_intel::ThreatIntelProvider;
+use crate::dataframe::lookup::LookupOptions;
+use crate::dataframe::outliers::{OutlierMethod, OutlierOptions, OutlierOutput};
+use crate::dataframe::redaction::{RedactMethod, RedactionOptions};
+use crate::dialog::filter_dialog::FilterExpr;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ClusterAlgorithm {
@@ -25,6 +31,9 @@ pub struct KmeansOptions {
     pub number_of_clusters: usize,
     pub runs: usize,
     pub tolerance: usize,
+    /// Run k = 2..=number_of_clusters and report inertia/silhouette instead of adding a column
+    #[serde(default)]
+    pub elbow_sweep: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -43,8 +52,18 @@ pub struct EmbeddingParams {
 pub enum OperationOptions {
     GenerateEmbeddings { model_name: String, num_dimensions: usize },
     Pca { target_embedding_size: usize },
+    Tsne { perplexity: usize, iterations: usize },
+    RegexExtract { pattern: String },
+    ParseDatetime { format: Option<String> },
+    NormalizeTimestamps,
+    Hash { algorithm: HashAlgorithm },
+    Redact { redaction: RedactionOptions },
+    Outliers { outliers: OutlierOptions },
+    Lookup { lookup: LookupOptions },
+    ThreatIntel { provider: ThreatIntelProvider },
     Cluster { algorithm: ClusterAlgorithm, kmeans: Option<KmeansOptions>, dbscan: Option<DbscanOptions> },
     SortByPromptSimilarity,
+    Plugin { plugin: String, operation: String },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -54,6 +73,20 @@ pub struct ColumnOperationConfig {
     pub source_column: String,
     pub hide_new_column: bool,
     pub options: OperationOptions,
+    /// Run only on the rows matching this tab filter; the other rows of the original data get
+    /// nulls in the new column(s)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub only_rows: Option<FilterExpr>,
+}
+
+/// A configured column operation saved to the project under a name
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColumnOperationPreset {
+    pub name: String,
+    pub config: ColumnOperationConfig,
+    /// Embedding provider, which the operation options do not carry
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider: Option<LlmProvider>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -62,6 +95,13 @@ pub enum ColumnOperationOptionsMode {
     Error(String),
 }
 
+/// Replace the text of a single-line input, keeping its placeholder
+fn replace_text(input: &mut TextArea<'static>, value: &str) {
+    input.select_all();
+    input.cut();
+    input.insert_str(value);
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ColumnOperationOptionsDialog {
     pub styles: StyleConfig,
@@ -73,6 +113,9 @@ pub struct ColumnOperationOptionsDialog {
     pub new_column_input: TextArea<'static>,
     pub selected_field_index: usize,
     pub hide_new_column: bool,
+    /// The tab's filter, offered to operations that can run on its rows only
+    pub row_filter: Option<FilterExpr>,
+    pub only_filtered_rows: bool,
     pub selected_provider: LlmProvider,
     pub model_name: String,
     #[serde(skip)]
@@ -83,6 +126,36 @@ pub struct ColumnOperationOptionsDialog {
     pub target_embedding_size: usize,
     #[serde(skip)]
     pub target_embedding_size_input: TextArea<'static>,
+    pub tsne_perplexity: usize,
+    #[serde(skip)]
+    pub tsne_perplexity_input: TextArea<'static>,
+    pub tsne_iterations: usize,
+    #[serde(skip)]
+    pub tsne_iterations_input: TextArea<'static>,
+    pub regex_pattern: String,
+    #[serde(skip)]
+    pub regex_pattern_input: TextArea<'static>,
+    pub datetime_format: String,
+    #[serde(skip)]
+    pub datetime_format_input: TextArea<'static>,
+    pub hash_algorithm: HashAlgorithm,
+    pub threat_intel_provider: ThreatIntelProvider,
+    pub redaction: RedactionOptions,
+    #[serde(skip)]
+    pub redact_pattern_input: TextArea<'static>,
+    pub outliers: OutlierOptions,
+    #[serde(skip)]
+    pub outlier_threshold_input: TextArea<'static>,
+    /// Other loaded datasets as (name, columns) for the lookup operation
+    pub lookup_datasets: Vec<(String, Vec<String>)>,
+    pub lookup_dataset_index: usize,
+    pub lookup_key_index: usize,
+    pub lookup_value_columns: String,
+    #[serde(skip)]
+    pub lookup_value_columns_input: TextArea<'static>,
+    /// Loaded plugin operations as (plugin, operation) pairs
+    pub plugin_operations: Vec<(String, String)>,
+    pub plugin_operation_index: usize,
     pub cluster_algorithm: ClusterAlgorithm,
     pub kmeans: KmeansOptions,
     pub dbscan: DbscanOptions,
@@ -100,6 +173,9 @@ pub struct ColumnOperationOptionsDialog {
     pub selected_button: usize,
     pub columns: Vec<String>,
     pub selected_column_index: usize,
+    /// Name prompt shown while saving the options as a preset
+    #[serde(skip)]
+    pub preset_name: Option<TextArea<'static>>,
     #[serde(skip)]
     pub config: crate::config::Config,
 }
@@ -119,6 +195,8 @@ impl ColumnOperationOptionsDialog {
             },
             selected_field_index: 0,
             hide_new_column: false,
+            row_filter: None,
+            only_filtered_rows: false,
             selected_provider: LlmProvider::OpenAI,
             model_name: String::from("text-embedding-3-small"),
             model_name_input: {
@@ -141,8 +219,62 @@ impl ColumnOperationOptionsDialog {
                 t.insert_str("0");
                 t
             },
+            tsne_perplexity: 30,
+            tsne_perplexity_input: {
+                let mut t = TextArea::default();
+                t.set_block(Block::default());
+                t.insert_str("30");
+                t
+            },
+            tsne_iterations: 500,
+            tsne_iterations_input: {
+                let mut t = TextArea::default();
+                t.set_block(Block::default());
+                t.insert_str("500");
+                t
+            },
+            regex_pattern: String::new(),
+            regex_pattern_input: {
+                let mut t = TextArea::default();
+                t.set_block(Block::default());
+                t
+            },
+            datetime_format: String::new(),
+            datetime_format_input: {
+                let mut t = TextArea::default();
+                t.set_block(Block::default());
+                t.set_placeholder_text("auto-detect");
+                t
+            },
+            hash_algorithm: HashAlgorithm::default(),
+            threat_intel_provider: ThreatIntelProvider::default(),
+            redaction: RedactionOptions::default(),
+            redact_pattern_input: {
+                let mut t = TextArea::default();
+                t.set_block(Block::default());
+                t
+            },
+            outliers: OutlierOptions::default(),
+            outlier_threshold_input: {
+                let mut t = TextArea::default();
+                t.set_block(Block::default());
+                t.insert_str(OutlierMethod::ZScore.default_threshold());
+                t
+            },
+            lookup_datasets: Vec::new(),
+            lookup_dataset_index: 0,
+            lookup_key_index: 0,
+            lookup_value_columns: String::new(),
+            lookup_value_columns_input: {
+                let mut t = TextArea::default();
+                t.set_block(Block::default());
+                t.set_placeholder_text("all columns");
+                t
+            },
+            plugin_operations: crate::plugins::operations(),
+            plugin_operation_index: 0,
             cluster_algorithm: ClusterAlgorithm::Kmeans,
-            kmeans: KmeansOptions { number_of_clusters: 8, runs: 1, tolerance: 1 },
+            kmeans: KmeansOptions { number_of_clusters: 8, runs: 1, tolerance: 1, elbow_sweep: false },
             dbscan: DbscanOptions { minimum_points: 5, tolerance: 1 },
             kmeans_number_of_clusters_input: {
                 let mut t = TextArea::default();
@@ -178,6 +310,7 @@ impl ColumnOperationOptionsDialog {
             selected_button: 0,
             columns: Vec::new(),
             selected_column_index: 0,
+            preset_name: None,
             config: crate::config::Config::default(),
         }
     }
@@ -189,9 +322,60 @@ impl ColumnOperationOptionsDialog {
         s
     }
 
+    /// Datasets the lookup operation can read from, with their columns. The key column starts on
+    /// a column named like the source column when there is one.
+    pub fn set_lookup_datasets(&mut self, datasets: Vec<(String, Vec<String>)>) {
+        self.lookup_datasets = datasets;
+        self.lookup_dataset_index = 0;
+        self.select_default_lookup_key();
+    }
+
+    /// Filter of the tab the operation runs in. Per-row operations then offer to run on the
+    /// matching rows only.
+    pub fn set_row_filter(&mut self, filter: Option<FilterExpr>) {
+        self.row_filter = filter;
+        self.only_filtered_rows = false;
+    }
+
+    /// Index of the "Only Filtered Rows" toggle, the last field when it is shown
+    fn filtered_rows_field_index(&self) -> Option<usize> {
+        (self.row_filter.is_some() && self.operation.runs_per_row()).then(|| self.fields_for_operation().len() - 1)
+    }
+
+    fn select_default_lookup_key(&mut self) {
+        let source = self.columns.get(self.selected_column_index).cloned().unwrap_or_default();
+        self.lookup_key_index = self
+            .selected_lookup_dataset()
+            .and_then(|(_, columns)| columns.iter().position(|c| c == &source))
+            .unwrap_or(0);
+    }
+
+    fn selected_lookup_dataset(&self) -> Option<&(String, Vec<String>)> {
+        self.lookup_datasets.get(self.lookup_dataset_index)
+    }
+
+    fn selected_lookup_key(&self) -> Option<&String> {
+        self.selected_lookup_dataset().and_then(|(_, columns)| columns.get(self.lookup_key_index))
+    }
+
+    fn cycle_lookup_dataset(&mut self, forward: bool) {
+        let len = self.lookup_datasets.len().max(1);
+        self.lookup_dataset_index = if forward { (self.lookup_dataset_index + 1) % len } else { (self.lookup_dataset_index + len - 1) % len };
+        self.select_default_lookup_key();
+    }
+
+    fn cycle_lookup_key(&mut self, forward: bool) {
+        let len = self.selected_lookup_dataset().map(|(_, columns)| columns.len()).unwrap_or(0).max(1);
+        self.lookup_key_index = if forward { (self.lookup_key_index + 1) % len } else { (self.lookup_key_index + len - 1) % len };
+    }
+
     fn fields_for_operation(&self) -> Vec<String> {
         let mut fields = vec![
-            "New Column Name:".to_string(),
+            match self.operation {
+                ColumnOperationKind::Redact => "New Dataset Name:",
+                ColumnOperationKind::Lookup => "Column Prefix:",
+                _ => "New Column Name:",
+            }.to_string(),
             format!("Source Column: {}", self.columns.get(self.selected_column_index).cloned().unwrap_or_default()),
         ];
         match self.operation {
@@ -204,13 +388,51 @@ impl ColumnOperationOptionsDialog {
             ColumnOperationKind::Pca => {
                 fields.push(format!("Target Embedding Size: {}", self.target_embedding_size));
             }
+            ColumnOperationKind::Tsne => {
+                fields.push(format!("Perplexity: {}", self.tsne_perplexity));
+                fields.push(format!("Iterations: {}", self.tsne_iterations));
+            }
+            ColumnOperationKind::RegexExtract => {
+                fields.push("Pattern:".to_string());
+            }
+            ColumnOperationKind::ParseDatetime => {
+                fields.push("Format:".to_string());
+            }
+            ColumnOperationKind::NormalizeTimestamps => {
+                // Only the source column; each value's representation is detected
+            }
+            ColumnOperationKind::Hash => {
+                fields.push(format!("Algorithm: {}", self.hash_algorithm.label()));
+            }
+            ColumnOperationKind::ThreatIntel => {
+                fields.push(format!("Provider: {}", self.threat_intel_provider.label()));
+            }
+            ColumnOperationKind::Redact => {
+                let on_off = |b: bool| if b { "On" } else { "Off" };
+                fields.push(format!("Columns: {}", if self.redaction.all_text_columns { "All Text Columns" } else { "Source Column Only" }));
+                fields.push(format!("Method: {}", self.redaction.method.label()));
+                fields.push(format!("Emails: {}", on_off(self.redaction.emails)));
+                fields.push(format!("SSNs: {}", on_off(self.redaction.ssns)));
+                fields.push("Custom Pattern:".to_string());
+            }
+            ColumnOperationKind::Outliers => {
+                fields.push(format!("Method: {}", self.outliers.method.label()));
+                fields.push("Threshold:".to_string());
+                fields.push(format!("Output: {}", match self.outliers.output { OutlierOutput::Flag => "Flag (Boolean)", OutlierOutput::Score => "Score (Float)" }));
+                fields.push(format!("Sort by Result: {}", if self.outliers.sort_by_score { "On" } else { "Off" }));
+            }
             ColumnOperationKind::Cluster => {
                 fields.push(format!("Algorithm: {}", match self.cluster_algorithm { ClusterAlgorithm::Kmeans => "Kmeans", ClusterAlgorithm::Dbscan => "Dbscan" }));
                 match self.cluster_algorithm {
                     ClusterAlgorithm::Kmeans => {
-                        fields.push(format!("Number of Clusters: {}", self.kmeans.number_of_clusters));
+                        if self.kmeans.elbow_sweep {
+                            fields.push(format!("Max Clusters (sweep 2..N): {}", self.kmeans.number_of_clusters));
+                        } else {
+                            fields.push(format!("Number of Clusters: {}", self.kmeans.number_of_clusters));
+                        }
                         fields.push(format!("Runs: {}", self.kmeans.runs));
                         fields.push(format!("Tolerance: {}", self.kmeans.tolerance));
+                        fields.push(format!("Elbow Sweep: {}", if self.kmeans.elbow_sweep { "On" } else { "Off" }));
                     }
                     ClusterAlgorithm::Dbscan => {
                         fields.push(format!("Minimum Points: {}", self.dbscan.minimum_points));
@@ -218,13 +440,29 @@ impl ColumnOperationOptionsDialog {
                     }
                 }
             }
+            ColumnOperationKind::Lookup => {
+                fields.push(format!("Lookup Dataset: {}", self.selected_lookup_dataset().map(|(name, _)| name.as_str()).unwrap_or("(none loaded)")));
+                fields.push(format!("Key Column: {}", self.selected_lookup_key().cloned().unwrap_or_default()));
+                fields.push("Value Columns:".to_string());
+            }
             ColumnOperationKind::SortByPromptSimilarity => {
                 // No extra fields; handled by dedicated dialog
             }
+            ColumnOperationKind::Plugin => {
+                let label = self.selected_plugin_operation().map(|(plugin, op)| format!("{plugin}/{op}")).unwrap_or_default();
+                fields.push(format!("Operation: {label}"));
+            }
+        }
+        if self.row_filter.is_some() && self.operation.runs_per_row() {
+            fields.push(self.filtered_rows_line());
         }
         fields
     }
 
+    fn filtered_rows_line(&self) -> String {
+        format!("Only Filtered Rows: {}", if self.only_filtered_rows { "On (others left null)" } else { "Off" })
+    }
+
     pub fn render(&self, area: Rect, buf: &mut Buffer) {
         Clear.render(area, buf);
 
@@ -250,7 +488,7 @@ impl ColumnOperationOptionsDialog {
         match &self.mode {
             ColumnOperationOptionsMode::Input => {
                 let block = Block::default()
-                    .title(match self.operation { ColumnOperationKind::GenerateEmbeddings => "Generate Embeddings", ColumnOperationKind::Pca => "PCA", ColumnOperationKind::Cluster => "Cluster", ColumnOperationKind::SortByPromptSimilarity => "Prompt Similarity" })
+                    .title(match self.operation { ColumnOperationKind::GenerateEmbeddings => "Generate Embeddings", ColumnOperationKind::Pca => "PCA", ColumnOperationKind::Tsne => "t-SNE", ColumnOperationKind::Cluster => "Cluster", ColumnOperationKind::RegexExtract => "Regex Extract", ColumnOperationKind::ParseDatetime => "Parse Datetime", ColumnOperationKind::NormalizeTimestamps => "Normalize Timestamps", ColumnOperationKind::Hash => "Hash", ColumnOperationKind::Redact => "Redact PII", ColumnOperationKind::Outliers => "Outliers", ColumnOperationKind::Lookup => "Lookup Enrichment", ColumnOperationKind::ThreatIntel => "Threat-Intel Enrichment", ColumnOperationKind::SortByPromptSimilarity => "Prompt Similarity", ColumnOperationKind::Plugin => "Plugin Operation" })
                     .borders(Borders::ALL);
                 let inner = block.inner(content_area);
                 block.render(content_area, buf);
@@ -329,27 +567,36 @@ impl ColumnOperationOptionsDialog {
                     let mut ta = self.get_number_input_by_index(i).clone();
                     if !is_selected { ta.set_cursor_style(Style::default().fg(Color::Gray)); }
                     ta.render(input_area, buf);
+
+                    // Row below the block: Only Filtered Rows (toggle, when the tab is filtered)
+                    if let Some(i) = self.filtered_rows_field_index() {
+                        let is_selected = !self.buttons_mode && i == self.selected_field_index;
+                        let style = if is_selected { Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD) } else { Style::default() };
+                        buf.set_string(inner.x + 1, inner.y + 9, self.filtered_rows_line(), style);
+                    }
                 } else {
                     let lines = self.fields_for_operation();
                     for (i, line) in lines.iter().enumerate() {
                         let y = inner.y + i as u16;
                         if y >= inner.y + inner.height { break; }
                         let is_selected = !self.buttons_mode && i == self.selected_field_index;
-                        if (i == 0) || (self.operation == ColumnOperationKind::GenerateEmbeddings && i == 4) {
+                        if self.is_index_text_field(i) {
                             let label = line.trim_end_matches(':').to_string() + ":";
                             let label_style = if is_selected { Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD) } else { Style::default() };
                             buf.set_string(inner.x + 1, y, label.clone(), label_style);
                             let label_width = label.len() as u16 + 2;
                             let input_area = Rect { x: inner.x + 1 + label_width, y, width: inner.width.saturating_sub(label_width + 2), height: 1 };
-                            if i == 0 {
-                                let mut ta = self.new_column_input.clone();
-                                if !is_selected { ta.set_cursor_style(Style::default().fg(Color::Gray)); }
-                                ta.render(input_area, buf);
-                            } else {
-                                let mut ta = self.model_name_input.clone();
-                                if !is_selected { ta.set_cursor_style(Style::default().fg(Color::Gray)); }
-                                ta.render(input_area, buf);
-                            }
+                            let mut ta = match i {
+                                0 => self.new_column_input.clone(),
+                                _ if self.operation == ColumnOperationKind::RegexExtract => self.regex_pattern_input.clone(),
+                                _ if self.operation == ColumnOperationKind::ParseDatetime => self.datetime_format_input.clone(),
+                                _ if self.operation == ColumnOperationKind::Redact => self.redact_pattern_input.clone(),
+                                _ if self.operation == ColumnOperationKind::Outliers => self.outlier_threshold_input.clone(),
+                                _ if self.operation == ColumnOperationKind::Lookup => self.lookup_value_columns_input.clone(),
+                                _ => self.model_name_input.clone(),
+                            };
+                            if !is_selected { ta.set_cursor_style(Style::default().fg(Color::Gray)); }
+                            ta.render(input_area, buf);
                         } else if self.is_index_number_field(i) {
                             let label = line.split(':').next().unwrap_or("").to_string() + ":";
                             let label_style = if is_selected { Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD) } else { Style::default() };
@@ -391,7 +638,7 @@ impl ColumnOperationOptionsDialog {
             }
             ColumnOperationOptionsMode::Error(msg) => {
                 let block = Block::default()
-                    .title(match self.operation { ColumnOperationKind::GenerateEmbeddings => "Generate Embeddings", ColumnOperationKind::Pca => "PCA", ColumnOperationKind::Cluster => "Cluster", ColumnOperationKind::SortByPromptSimilarity => "Prompt Similarity" })
+                    .title(match self.operation { ColumnOperationKind::GenerateEmbeddings => "Generate Embeddings", ColumnOperationKind::Pca => "PCA", ColumnOperationKind::Tsne => "t-SNE", ColumnOperationKind::Cluster => "Cluster", ColumnOperationKind::RegexExtract => "Regex Extract", ColumnOperationKind::ParseDatetime => "Parse Datetime", ColumnOperationKind::NormalizeTimestamps => "Normalize Timestamps", ColumnOperationKind::Hash => "Hash", ColumnOperationKind::Redact => "Redact PII", ColumnOperationKind::Outliers => "Outliers", ColumnOperationKind::Lookup => "Lookup Enrichment", ColumnOperationKind::ThreatIntel => "Threat-Intel Enrichment", ColumnOperationKind::SortByPromptSimilarity => "Prompt Similarity", ColumnOperationKind::Plugin => "Plugin Operation" })
                     .borders(Borders::ALL);
                 let inner = block.inner(content_area);
                 block.render(content_area, buf);
@@ -469,27 +716,36 @@ impl ColumnOperationOptionsDialog {
                     let mut ta = self.get_number_input_by_index(i).clone();
                     if !is_selected { ta.set_cursor_style(Style::default().fg(Color::Gray)); }
                     ta.render(input_area, buf);
+
+                    // Row below the block: Only Filtered Rows (toggle, when the tab is filtered)
+                    if let Some(i) = self.filtered_rows_field_index() {
+                        let is_selected = !self.buttons_mode && i == self.selected_field_index;
+                        let style = if is_selected { Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD) } else { Style::default() };
+                        buf.set_string(inner.x + 1, base_y + 8, self.filtered_rows_line(), style);
+                    }
                 } else {
                     let lines = self.fields_for_operation();
                     for (i, line) in lines.iter().enumerate() {
                         let y = inner.y + 1 + i as u16;
                         if y >= inner.y + inner.height { break; }
                         let is_selected = !self.buttons_mode && i == self.selected_field_index;
-                        if (i == 0) || (self.operation == ColumnOperationKind::GenerateEmbeddings && i == 4) {
+                        if self.is_index_text_field(i) {
                             let label = line.trim_end_matches(':').to_string() + ":";
                             let label_style = if is_selected { Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD) } else { Style::default() };
                             buf.set_string(inner.x + 1, y, label.clone(), label_style);
                             let label_width = label.len() as u16 + 2;
                             let input_area = Rect { x: inner.x + 1 + label_width, y, width: inner.width.saturating_sub(label_width + 2), height: 1 };
-                            if i == 0 {
-                                let mut ta = self.new_column_input.clone();
-                                if !is_selected { ta.set_cursor_style(Style::default().fg(Color::Gray)); }
-                                ta.render(input_area, buf);
-                            } else {
-                                let mut ta = self.model_name_input.clone();
-                                if !is_selected { ta.set_cursor_style(Style::default().fg(Color::Gray)); }
-                                ta.render(input_area, buf);
-                            }
+                            let mut ta = match i {
+                                0 => self.new_column_input.clone(),
+                                _ if self.operation == ColumnOperationKind::RegexExtract => self.regex_pattern_input.clone(),
+                                _ if self.operation == ColumnOperationKind::ParseDatetime => self.datetime_format_input.clone(),
+                                _ if self.operation == ColumnOperationKind::Redact => self.redact_pattern_input.clone(),
+                                _ if self.operation == ColumnOperationKind::Outliers => self.outlier_threshold_input.clone(),
+                                _ if self.operation == ColumnOperationKind::Lookup => self.lookup_value_columns_input.clone(),
+                                _ => self.model_name_input.clone(),
+                            };
+                            if !is_selected { ta.set_cursor_style(Style::default().fg(Color::Gray)); }
+                            ta.render(input_area, buf);
                         } else if self.is_index_number_field(i) {
                             let label = line.split(':').next().unwrap_or("").to_string() + ":";
                             let label_style = if is_selected { Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD) } else { Style::default() };
@@ -532,6 +788,10 @@ impl ColumnOperationOptionsDialog {
                 }
             }
         }
+
+        if let Some(input) = &self.preset_name {
+            self.render_preset_name(input, content_area, buf);
+        }
     }
 
     /// Build instructions string from configured keybindings
@@ -545,6 +805,7 @@ impl ColumnOperationOptionsDialog {
                     (crate::config::Mode::Global, crate::action::Action::Paste),
                     (crate::config::Mode::ColumnOperationOptions, crate::action::Action::ToggleField),
                     (crate::config::Mode::ColumnOperationOptions, crate::action::Action::ToggleButtons),
+                    (crate::config::Mode::ColumnOperationOptions, crate::action::Action::SaveColumnOperationPreset),
                 ]);
 
                 // Add operation-specific instructions
@@ -555,19 +816,49 @@ impl ColumnOperationOptionsDialog {
                     ColumnOperationKind::Pca => {
                         "  • Target Size: Numeric input"
                     }
+                    ColumnOperationKind::Tsne => {
+                        "  • Perplexity/Iterations: Numeric input  • Adds <name>_x and <name>_y columns"
+                    }
+                    ColumnOperationKind::RegexExtract => {
+                        "  • Pattern: one new column per capture group  • New Column Name: optional prefix"
+                    }
+                    ColumnOperationKind::ParseDatetime => {
+                        "  • Format: chrono strftime, e.g. %Y-%m-%d %H:%M:%S (empty = auto-detect)"
+                    }
+                    ColumnOperationKind::NormalizeTimestamps => {
+                        "  • Each value is detected on its own  • Unparseable rows become null and are listed afterwards"
+                    }
+                    ColumnOperationKind::Hash => {
+                        "  • Algorithm: Left/Right or Space to cycle MD5/SHA1/SHA256"
+                    }
+                    ColumnOperationKind::ThreatIntel => {
+                        "  • Provider: Left/Right or Space to cycle  • Responses are cached on disk; API keys live in Project Settings"
+                    }
+                    ColumnOperationKind::Redact => {
+                        "  • Columns/Method/Emails/SSNs: Space to toggle  • Custom Pattern: optional regex"
+                    }
+                    ColumnOperationKind::Outliers => {
+                        "  • Method/Output/Sort: Space to toggle  • Threshold: z-score cut-off or IQR multiplier"
+                    }
                     ColumnOperationKind::Cluster => {
                         match self.cluster_algorithm {
                             ClusterAlgorithm::Kmeans => {
-                                "  • Algorithm: Space to toggle  • Clusters/Runs/Tolerance: Numeric input"
+                                "  • Algorithm/Elbow Sweep: Space to toggle  • Clusters/Runs/Tolerance: Numeric input"
                             }
                             ClusterAlgorithm::Dbscan => {
                                 "  • Algorithm: Space to toggle  • Min Points/Tolerance: Numeric input"
                             }
                         }
                     }
+                    ColumnOperationKind::Lookup => {
+                        "  • Dataset/Key Column: Left/Right or Space to cycle  • Value Columns: comma-separated (empty = all)  • Column Prefix: optional"
+                    }
                     ColumnOperationKind::SortByPromptSimilarity => {
                         "  • Source Column: Left/Right to select"
                     }
+                    ColumnOperationKind::Plugin => {
+                        "  • Operation: Left/Right or Space to cycle loaded plugin operations"
+                    }
                 };
 
                 if base_instructions.is_empty() {
@@ -594,6 +885,8 @@ impl ColumnOperationOptionsDialog {
                 ("nomic-embed-text", 768),
                 ("mxbai-embed-large", 1024),
             ],
+            // Whatever the endpoint serves; an empty model uses the one in its config
+            LlmProvider::OpenAICompatible => vec![("", 0)],
         }
     }
 
@@ -617,6 +910,11 @@ impl ColumnOperationOptionsDialog {
     }
 
     fn apply(&self) -> Action {
+        Action::ColumnOperationOptionsApplied(self.current_config())
+    }
+
+    /// The operation as configured in the dialog
+    pub fn current_config(&self) -> ColumnOperationConfig {
         let options = match self.operation {
             ColumnOperationKind::GenerateEmbeddings => OperationOptions::GenerateEmbeddings {
                 model_name: self.model_name.clone(),
@@ -625,6 +923,29 @@ impl ColumnOperationOptionsDialog {
             ColumnOperationKind::Pca => OperationOptions::Pca {
                 target_embedding_size: self.target_embedding_size
             },
+            ColumnOperationKind::Tsne => OperationOptions::Tsne {
+                perplexity: self.tsne_perplexity,
+                iterations: self.tsne_iterations
+            },
+            ColumnOperationKind::RegexExtract => OperationOptions::RegexExtract {
+                pattern: self.regex_pattern.clone()
+            },
+            ColumnOperationKind::ParseDatetime => OperationOptions::ParseDatetime {
+                format: Some(self.datetime_format.trim().to_string()).filter(|f| !f.is_empty())
+            },
+            ColumnOperationKind::NormalizeTimestamps => OperationOptions::NormalizeTimestamps,
+            ColumnOperationKind::Hash => OperationOptions::Hash {
+                algorithm: self.hash_algorithm
+            },
+            ColumnOperationKind::ThreatIntel => OperationOptions::ThreatIntel {
+                provider: self.threat_intel_provider
+            },
+            ColumnOperationKind::Redact => OperationOptions::Redact {
+                redaction: self.redaction.clone()
+            },
+            ColumnOperationKind::Outliers => OperationOptions::Outliers {
+                outliers: self.outliers.clone()
+            },
             ColumnOperationKind::Cluster => OperationOptions::Cluster {
                 algorithm: self.cluster_algorithm.clone(),
                 kmeans: if matches!(self.cluster_algorithm, ClusterAlgorithm::Kmeans) {
@@ -634,23 +955,160 @@ impl ColumnOperationOptionsDialog {
                     Some(self.dbscan.clone())
                 } else { None }
             },
+            ColumnOperationKind::Lookup => OperationOptions::Lookup {
+                lookup: LookupOptions {
+                    dataset: self.selected_lookup_dataset().map(|(name, _)| name.clone()).unwrap_or_default(),
+                    key_column: self.selected_lookup_key().cloned().unwrap_or_default(),
+                    value_columns: self.lookup_value_columns.trim().to_string(),
+                }
+            },
             ColumnOperationKind::SortByPromptSimilarity => OperationOptions::SortByPromptSimilarity,
+            ColumnOperationKind::Plugin => {
+                let (plugin, operation) = self.selected_plugin_operation().cloned().unwrap_or_default();
+                OperationOptions::Plugin { plugin, operation }
+            }
         };
         let source_column = self.columns.get(self.selected_column_index)
             .cloned()
             .unwrap_or_default();
-        let cfg = ColumnOperationConfig {
+        ColumnOperationConfig {
             operation: self.operation.clone(),
             new_column_name: self.new_column_name.clone(),
             source_column,
             hide_new_column: self.hide_new_column,
-            options
+            options,
+            only_rows: self.filtered_rows_field_index()
+                .filter(|_| self.only_filtered_rows)
+                .and(self.row_filter.clone()),
+        }
+    }
+
+    /// The dialog's options saved under `name`. The row filter belongs to the tab, so it is left out.
+    fn preset(&self, name: String) -> ColumnOperationPreset {
+        let config = ColumnOperationConfig { only_rows: None, ..self.current_config() };
+        let provider = (self.operation == ColumnOperationKind::GenerateEmbeddings).then(|| self.selected_provider.clone());
+        ColumnOperationPreset { name, config, provider }
+    }
+
+    /// Fill the dialog from a saved preset. Columns, lookup datasets and plugin operations should
+    /// be set first; values that are not available keep their defaults.
+    pub fn load_preset(&mut self, preset: &ColumnOperationPreset) {
+        let cfg = &preset.config;
+        self.new_column_name = cfg.new_column_name.clone();
+        replace_text(&mut self.new_column_input, &cfg.new_column_name);
+        if let Some(idx) = self.columns.iter().position(|c| c == &cfg.source_column) {
+            self.selected_column_index = idx;
+        }
+        self.hide_new_column = cfg.hide_new_column;
+        if let Some(provider) = &preset.provider {
+            self.selected_provider = provider.clone();
+        }
+        match &cfg.options {
+            OperationOptions::GenerateEmbeddings { model_name, num_dimensions } => self.set_model_and_dimensions(model_name, *num_dimensions),
+            OperationOptions::Pca { target_embedding_size } => {
+                self.target_embedding_size = *target_embedding_size;
+                replace_text(&mut self.target_embedding_size_input, &target_embedding_size.to_string());
+            }
+            OperationOptions::Tsne { perplexity, iterations } => {
+                self.tsne_perplexity = *perplexity;
+                self.tsne_iterations = *iterations;
+                replace_text(&mut self.tsne_perplexity_input, &perplexity.to_string());
+                replace_text(&mut self.tsne_iterations_input, &iterations.to_string());
+            }
+            OperationOptions::RegexExtract { pattern } => {
+                self.regex_pattern = pattern.clone();
+                replace_text(&mut self.regex_pattern_input, pattern);
+            }
+            OperationOptions::ParseDatetime { format } => {
+                self.datetime_format = format.clone().unwrap_or_default();
+                replace_text(&mut self.datetime_format_input, &self.datetime_format.clone());
+            }
+            OperationOptions::NormalizeTimestamps | OperationOptions::SortByPromptSimilarity => {}
+            OperationOptions::Hash { algorithm } => self.hash_algorithm = *algorithm,
+            OperationOptions::ThreatIntel { provider } => self.threat_intel_provider = *provider,
+            OperationOptions::Redact { redaction } => {
+                self.redaction = redaction.clone();
+                replace_text(&mut self.redact_pattern_input, &redaction.custom_pattern);
+            }
+            OperationOptions::Outliers { outliers } => {
+                self.outliers = outliers.clone();
+                replace_text(&mut self.outlier_threshold_input, &outliers.threshold);
+            }
+            OperationOptions::Cluster { algorithm, kmeans, dbscan } => {
+                self.cluster_algorithm = algorithm.clone();
+                if let Some(kmeans) = kmeans {
+                    self.kmeans = kmeans.clone();
+                    replace_text(&mut self.kmeans_number_of_clusters_input, &kmeans.number_of_clusters.to_string());
+                    replace_text(&mut self.kmeans_runs_input, &kmeans.runs.to_string());
+                    replace_text(&mut self.kmeans_tolerance_input, &kmeans.tolerance.to_string());
+                }
+                if let Some(dbscan) = dbscan {
+                    self.dbscan = dbscan.clone();
+                    replace_text(&mut self.dbscan_minimum_points_input, &dbscan.minimum_points.to_string());
+                    replace_text(&mut self.dbscan_tolerance_input, &dbscan.tolerance.to_string());
+                }
+            }
+            OperationOptions::Lookup { lookup } => {
+                if let Some(idx) = self.lookup_datasets.iter().position(|(name, _)| name == &lookup.dataset) {
+                    self.lookup_dataset_index = idx;
+                    self.select_default_lookup_key();
+                }
+                if let Some(idx) = self.selected_lookup_dataset().and_then(|(_, columns)| columns.iter().position(|c| c == &lookup.key_column)) {
+                    self.lookup_key_index = idx;
+                }
+                self.lookup_value_columns = lookup.value_columns.clone();
+                replace_text(&mut self.lookup_value_columns_input, &lookup.value_columns);
+            }
+            OperationOptions::Plugin { plugin, operation } => {
+                if let Some(idx) = self.plugin_operations.iter().position(|(p, op)| p == plugin && op == operation) {
+                    self.plugin_operation_index = idx;
+                }
+            }
+        }
+    }
+
+    fn handle_preset_name_key(&mut self, key: KeyEvent) -> Option<Action> {
+        let input = self.preset_name.as_mut()?;
+        match self.config.action_for_key(crate::config::Mode::Global, key) {
+            Some(Action::Escape) => self.preset_name = None,
+            Some(Action::Enter) => {
+                let name = input.lines().join(" ").trim().to_string();
+                if name.is_empty() {
+                    return None;
+                }
+                self.preset_name = None;
+                return Some(Action::ColumnOperationPresetSaved(self.preset(name)));
+            }
+            _ => {
+                input.input(tui_textarea::Input::from(key));
+            }
+        }
+        None
+    }
+
+    fn render_preset_name(&self, input: &TextArea<'static>, area: Rect, buf: &mut Buffer) {
+        let width = area.width.saturating_sub(area.width / 3).max(30).min(area.width);
+        let overlay = Rect {
+            x: area.x + area.width.saturating_sub(width) / 2,
+            y: area.y + area.height.saturating_sub(3) / 2,
+            width,
+            height: 3.min(area.height),
         };
-        Action::ColumnOperationOptionsApplied(cfg)
+        Clear.render(overlay, buf);
+        let block = Block::default()
+            .title("Save Operation as Preset")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow));
+        let inner = block.inner(overlay);
+        block.render(overlay, buf);
+        ratatui::widgets::Widget::render(input, inner, buf);
     }
 
     pub fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
         if key.kind != KeyEventKind::Press { return Ok(None); }
+        if self.preset_name.is_some() {
+            return Ok(self.handle_preset_name_key(key));
+        }
         // Intercept Tab on Model Name to cycle models before config actions can toggle buttons
         if matches!(self.operation, ColumnOperationKind::GenerateEmbeddings)
             && self.selected_field_index == 4
@@ -780,6 +1238,12 @@ impl ColumnOperationOptionsDialog {
                     self.toggle_current_field();
                     return Ok(None);
                 }
+                Action::SaveColumnOperationPreset if self.mode == ColumnOperationOptionsMode::Input => {
+                    let mut input = TextArea::default();
+                    input.set_block(Block::default());
+                    self.preset_name = Some(input);
+                    return Ok(None);
+                }
                 Action::ToggleButtons => {
                     if self.buttons_mode {
                         self.buttons_mode = false;
@@ -819,6 +1283,11 @@ impl ColumnOperationOptionsDialog {
     }
 
     fn modify_current_field(&mut self, increment: bool) {
+        if self.filtered_rows_field_index() == Some(self.selected_field_index) {
+            // Off on Left, On on Right, like Hide New Column
+            self.only_filtered_rows = increment;
+            return;
+        }
         // Index 0 is always new column name; other fields depend on operation
         if self.selected_field_index == 0 {
             // Text field: ignore numeric adjust; cursor movement is handled by Char/Backspace
@@ -830,7 +1299,7 @@ impl ColumnOperationOptionsDialog {
                 match idx {
                     2 => {
                         // Provider selection rotate
-                        let order = [LlmProvider::Azure, LlmProvider::OpenAI, LlmProvider::Ollama];
+                        let order = [LlmProvider::Azure, LlmProvider::OpenAI, LlmProvider::Ollama, LlmProvider::OpenAICompatible];
                         let mut pos = order.iter().position(|p| p == &self.selected_provider).unwrap_or(1);
                         if increment { pos = (pos + 1) % order.len(); } else { pos = (pos + order.len() - 1) % order.len(); }
                         self.selected_provider = order[pos].clone();
@@ -850,6 +1319,24 @@ impl ColumnOperationOptionsDialog {
             ColumnOperationKind::Pca => {
                 if idx == 0 { if increment { self.target_embedding_size = self.target_embedding_size.saturating_add(1); } else { self.target_embedding_size = self.target_embedding_size.saturating_sub(1); } }
             }
+            ColumnOperationKind::Tsne => {
+                match idx {
+                    1 => { if increment { self.tsne_perplexity = self.tsne_perplexity.saturating_add(1); } else { self.tsne_perplexity = self.tsne_perplexity.saturating_sub(1); } }
+                    2 => { if increment { self.tsne_iterations = self.tsne_iterations.saturating_add(50); } else { self.tsne_iterations = self.tsne_iterations.saturating_sub(50); } }
+                    _ => {}
+                }
+            }
+            ColumnOperationKind::RegexExtract | ColumnOperationKind::ParseDatetime | ColumnOperationKind::NormalizeTimestamps => {
+                // Only text fields besides the source column selector
+            }
+            ColumnOperationKind::Hash => {
+                if idx == 1 { self.hash_algorithm = self.hash_algorithm.cycle(increment); }
+            }
+            ColumnOperationKind::ThreatIntel => {
+                if idx == 1 { self.threat_intel_provider = self.threat_intel_provider.cycle(increment); }
+            }
+            ColumnOperationKind::Redact => self.toggle_redaction_field(idx + 1),
+            ColumnOperationKind::Outliers => self.toggle_outlier_field(idx + 1),
             ColumnOperationKind::Cluster => {
                 match idx {
                     0 => {
@@ -872,16 +1359,40 @@ impl ColumnOperationOptionsDialog {
                     4 => {
                         if matches!(self.cluster_algorithm, ClusterAlgorithm::Dbscan) { if increment { self.dbscan.tolerance = self.dbscan.tolerance.saturating_add(1); } else { self.dbscan.tolerance = self.dbscan.tolerance.saturating_sub(1); } }
                     }
+                    5 => {
+                        if matches!(self.cluster_algorithm, ClusterAlgorithm::Kmeans) { self.kmeans.elbow_sweep = !self.kmeans.elbow_sweep; }
+                    }
                     _ => {}
                 }
             }
             ColumnOperationKind::SortByPromptSimilarity => {
                 // No adjustable fields in this dialog for this operation
             }
+            ColumnOperationKind::Lookup => match idx {
+                1 => self.cycle_lookup_dataset(increment),
+                2 => self.cycle_lookup_key(increment),
+                _ => {}
+            },
+            ColumnOperationKind::Plugin => {
+                if idx == 1 { self.cycle_plugin_operation(increment); }
+            }
         }
     }
 
+    fn selected_plugin_operation(&self) -> Option<&(String, String)> {
+        self.plugin_operations.get(self.plugin_operation_index)
+    }
+
+    fn cycle_plugin_operation(&mut self, forward: bool) {
+        let len = self.plugin_operations.len().max(1);
+        self.plugin_operation_index = if forward { (self.plugin_operation_index + 1) % len } else { (self.plugin_operation_index + len - 1) % len };
+    }
+
     fn toggle_current_field(&mut self) {
+        if self.filtered_rows_field_index() == Some(self.selected_field_index) {
+            self.only_filtered_rows = !self.only_filtered_rows;
+            return;
+        }
         // Space toggles algorithm when on that field
         if self.operation == ColumnOperationKind::Cluster {
             // Algorithm line is index 1 (0 = new column)
@@ -890,18 +1401,78 @@ impl ColumnOperationOptionsDialog {
                     ClusterAlgorithm::Kmeans => ClusterAlgorithm::Dbscan,
                     ClusterAlgorithm::Dbscan => ClusterAlgorithm::Kmeans,
                 };
+            } else if self.selected_field_index == 6 && matches!(self.cluster_algorithm, ClusterAlgorithm::Kmeans) {
+                self.kmeans.elbow_sweep = !self.kmeans.elbow_sweep;
             }
         } else if self.operation == ColumnOperationKind::GenerateEmbeddings {
             // Hide New Column toggle at index 2
             if self.selected_field_index == 2 {
                 self.hide_new_column = !self.hide_new_column;
             }
+        } else if self.operation == ColumnOperationKind::Hash && self.selected_field_index == 2 {
+            self.hash_algorithm = self.hash_algorithm.cycle(true);
+        } else if self.operation == ColumnOperationKind::ThreatIntel && self.selected_field_index == 2 {
+            self.threat_intel_provider = self.threat_intel_provider.cycle(true);
+        } else if self.operation == ColumnOperationKind::Plugin && self.selected_field_index == 2 {
+            self.cycle_plugin_operation(true);
+        } else if self.operation == ColumnOperationKind::Lookup && self.selected_field_index == 2 {
+            self.cycle_lookup_dataset(true);
+        } else if self.operation == ColumnOperationKind::Lookup && self.selected_field_index == 3 {
+            self.cycle_lookup_key(true);
+        } else if self.operation == ColumnOperationKind::Redact {
+            self.toggle_redaction_field(self.selected_field_index);
+        } else if self.operation == ColumnOperationKind::Outliers {
+            self.toggle_outlier_field(self.selected_field_index);
+        }
+    }
+
+    fn toggle_outlier_field(&mut self, index: usize) {
+        match index {
+            2 => {
+                let previous = self.outliers.method;
+                self.outliers.method = match previous {
+                    OutlierMethod::ZScore => OutlierMethod::Iqr,
+                    OutlierMethod::Iqr => OutlierMethod::ZScore,
+                };
+                // Swap in the new method's default unless the user typed a custom threshold
+                if self.outliers.threshold.trim() == previous.default_threshold() {
+                    self.outliers.threshold = self.outliers.method.default_threshold().to_string();
+                    let mut t = TextArea::default();
+                    t.set_block(Block::default());
+                    t.insert_str(&self.outliers.threshold);
+                    self.outlier_threshold_input = t;
+                }
+            }
+            4 => {
+                self.outliers.output = match self.outliers.output {
+                    OutlierOutput::Flag => OutlierOutput::Score,
+                    OutlierOutput::Score => OutlierOutput::Flag,
+                };
+            }
+            5 => self.outliers.sort_by_score = !self.outliers.sort_by_score,
+            _ => {}
+        }
+    }
+
+    fn toggle_redaction_field(&mut self, index: usize) {
+        match index {
+            2 => self.redaction.all_text_columns = !self.redaction.all_text_columns,
+            3 => {
+                self.redaction.method = match self.redaction.method {
+                    RedactMethod::Mask => RedactMethod::Hash,
+                    RedactMethod::Hash => RedactMethod::Mask,
+                };
+            }
+            4 => self.redaction.emails = !self.redaction.emails,
+            5 => self.redaction.ssns = !self.redaction.ssns,
+            _ => {}
         }
     }
 
     fn current_field_kind(&self) -> &'static str {
         // Return "text" | "number" | "enum"
         if self.selected_field_index == 0 { return "text"; }
+        if self.filtered_rows_field_index() == Some(self.selected_field_index) { return "enum"; }
         match self.operation {
             ColumnOperationKind::GenerateEmbeddings => {
                 match self.selected_field_index {
@@ -920,9 +1491,44 @@ impl ColumnOperationOptionsDialog {
                     _ => "number",
                 }
             }
+            ColumnOperationKind::Tsne => {
+                match self.selected_field_index {
+                    1 => "enum", // source column selector
+                    _ => "number", // perplexity, iterations
+                }
+            }
+            ColumnOperationKind::Hash => "enum", // source column, algorithm
+            ColumnOperationKind::NormalizeTimestamps => "enum", // source column
+            ColumnOperationKind::ThreatIntel => "enum", // source column, provider
+            ColumnOperationKind::Plugin => "enum", // source column, operation
+            ColumnOperationKind::Redact => {
+                match self.selected_field_index {
+                    6 => "text", // custom pattern
+                    _ => "enum", // source column and toggles
+                }
+            }
+            ColumnOperationKind::Outliers => {
+                match self.selected_field_index {
+                    3 => "text", // threshold
+                    _ => "enum", // source column and toggles
+                }
+            }
+            ColumnOperationKind::Lookup => {
+                match self.selected_field_index {
+                    4 => "text", // value columns
+                    _ => "enum", // source column, dataset, key column
+                }
+            }
+            ColumnOperationKind::RegexExtract | ColumnOperationKind::ParseDatetime => {
+                match self.selected_field_index {
+                    1 => "enum", // source column selector
+                    _ => "text", // pattern / format
+                }
+            }
             ColumnOperationKind::Cluster => {
                 match self.selected_field_index {
                     1 => "enum", // source column selector
+                    6 if matches!(self.cluster_algorithm, ClusterAlgorithm::Kmeans) => "enum", // elbow sweep toggle
                     _ => {
                         if matches!(self.cluster_algorithm, ClusterAlgorithm::Kmeans) {
                             // 2,3,4 are numbers for KMeans
@@ -945,10 +1551,23 @@ impl ColumnOperationOptionsDialog {
 
     fn is_current_field_text(&self) -> bool { self.current_field_kind() == "text" }
 
+    fn is_index_text_field(&self, index: usize) -> bool {
+        match self.operation {
+            ColumnOperationKind::GenerateEmbeddings => index == 0 || index == 4,
+            ColumnOperationKind::RegexExtract | ColumnOperationKind::ParseDatetime => index == 0 || index == 2,
+            ColumnOperationKind::Redact => index == 0 || index == 6,
+            ColumnOperationKind::Outliers => index == 0 || index == 3,
+            ColumnOperationKind::Lookup => index == 0 || index == 4,
+            _ => index == 0,
+        }
+    }
+
     fn is_index_number_field(&self, index: usize) -> bool {
         match self.operation {
             ColumnOperationKind::GenerateEmbeddings => index == 5,
             ColumnOperationKind::Pca => index == 2,
+            ColumnOperationKind::Tsne => index == 2 || index == 3,
+            ColumnOperationKind::RegexExtract | ColumnOperationKind::ParseDatetime | ColumnOperationKind::NormalizeTimestamps | ColumnOperationKind::Hash | ColumnOperationKind::ThreatIntel | ColumnOperationKind::Redact | ColumnOperationKind::Outliers | ColumnOperationKind::Lookup | ColumnOperationKind::Plugin => false,
             ColumnOperationKind::Cluster => {
                 if matches!(self.cluster_algorithm, ClusterAlgorithm::Kmeans) {
                     index == 3 || index == 4 || index == 5
@@ -964,6 +1583,8 @@ impl ColumnOperationOptionsDialog {
         match self.operation {
             ColumnOperationKind::GenerateEmbeddings => &self.num_dimensions_input,
             ColumnOperationKind::Pca => &self.target_embedding_size_input,
+            ColumnOperationKind::Tsne => if index == 3 { &self.tsne_iterations_input } else { &self.tsne_perplexity_input },
+            ColumnOperationKind::RegexExtract | ColumnOperationKind::ParseDatetime | ColumnOperationKind::NormalizeTimestamps | ColumnOperationKind::Hash | ColumnOperationKind::ThreatIntel | ColumnOperationKind::Redact | ColumnOperationKind::Outliers | ColumnOperationKind::Lookup | ColumnOperationKind::Plugin => &self.num_dimensions_input,
             ColumnOperationKind::Cluster => {
                 if matches!(self.cluster_algorithm, ClusterAlgorithm::Kmeans) {
                     match index {
@@ -988,6 +1609,8 @@ impl ColumnOperationOptionsDialog {
         match self.operation {
             ColumnOperationKind::GenerateEmbeddings => &mut self.num_dimensions_input,
             ColumnOperationKind::Pca => &mut self.target_embedding_size_input,
+            ColumnOperationKind::Tsne => if index == 3 { &mut self.tsne_iterations_input } else { &mut self.tsne_perplexity_input },
+            ColumnOperationKind::RegexExtract | ColumnOperationKind::ParseDatetime | ColumnOperationKind::NormalizeTimestamps | ColumnOperationKind::Hash | ColumnOperationKind::ThreatIntel | ColumnOperationKind::Redact | ColumnOperationKind::Outliers | ColumnOperationKind::Lookup | ColumnOperationKind::Plugin => &mut self.num_dimensions_input,
             ColumnOperationKind::Cluster => {
                 if matches!(self.cluster_algorithm, ClusterAlgorithm::Kmeans) {
                     match index {
@@ -1022,7 +1645,21 @@ impl ColumnOperationOptionsDialog {
 
     fn copy_current_text_to_clipboard(&mut self) {
         if self.current_field_kind() != "text" { return; }
-        let text = if self.selected_field_index == 0 { self.new_column_name.clone() } else { self.model_name.clone() };
+        let text = if self.selected_field_index == 0 {
+            self.new_column_name.clone()
+        } else if self.operation == ColumnOperationKind::RegexExtract {
+            self.regex_pattern.clone()
+        } else if self.operation == ColumnOperationKind::ParseDatetime {
+            self.datetime_format.clone()
+        } else if self.operation == ColumnOperationKind::Redact {
+            self.redaction.custom_pattern.clone()
+        } else if self.operation == ColumnOperationKind::Outliers {
+            self.outliers.threshold.clone()
+        } else if self.operation == ColumnOperationKind::Lookup {
+            self.lookup_value_columns.clone()
+        } else {
+            self.model_name.clone()
+        };
         if let Ok(mut clipboard) = Clipboard::new() { let _ = clipboard.set_text(text); }
     }
 
@@ -1034,6 +1671,11 @@ impl ColumnOperationOptionsDialog {
             let first_line = text.lines().next().unwrap_or("").to_string();
             if self.selected_field_index == 0 { self.new_column_input.insert_str(&first_line); self.new_column_name = self.new_column_input.lines().join("\n"); }
             if self.operation == ColumnOperationKind::GenerateEmbeddings && self.selected_field_index == 2 { self.model_name_input.insert_str(&first_line); self.model_name = self.model_name_input.lines().join("\n"); }
+            if self.operation == ColumnOperationKind::RegexExtract && self.selected_field_index == 2 { self.regex_pattern_input.insert_str(&first_line); self.regex_pattern = self.regex_pattern_input.lines().join(""); }
+            if self.operation == ColumnOperationKind::ParseDatetime && self.selected_field_index == 2 { self.datetime_format_input.insert_str(&first_line); self.datetime_format = self.datetime_format_input.lines().join(""); }
+            if self.operation == ColumnOperationKind::Redact && self.selected_field_index == 6 { self.redact_pattern_input.insert_str(&first_line); self.redaction.custom_pattern = self.redact_pattern_input.lines().join(""); }
+            if self.operation == ColumnOperationKind::Outliers && self.selected_field_index == 3 { self.outlier_threshold_input.insert_str(&first_line); self.outliers.threshold = self.outlier_threshold_input.lines().join(""); }
+            if self.operation == ColumnOperationKind::Lookup && self.selected_field_index == 4 { self.lookup_value_columns_input.insert_str(&first_line); self.lookup_value_columns = self.lookup_value_columns_input.lines().join(""); }
         }
     }
 
@@ -1081,7 +1723,12 @@ impl ColumnOperationOptionsDialog {
         let kev = KeyEvent::new(code, mods);
         let inp = tui_textarea::Input::from(kev);
         if self.selected_field_index == 0 { self.new_column_input.input(inp.clone()); self.new_column_name = self.new_column_input.lines().join("\n"); }
-        if self.operation == ColumnOperationKind::GenerateEmbeddings && self.selected_field_index == 2 { self.model_name_input.input(inp); self.model_name = self.model_name_input.lines().join("\n"); }
+        if self.operation == ColumnOperationKind::GenerateEmbeddings && self.selected_field_index == 2 { self.model_name_input.input(inp.clone()); self.model_name = self.model_name_input.lines().join("\n"); }
+        if self.operation == ColumnOperationKind::RegexExtract && self.selected_field_index == 2 { self.regex_pattern_input.input(inp.clone()); self.regex_pattern = self.regex_pattern_input.lines().join(""); }
+        if self.operation == ColumnOperationKind::ParseDatetime && self.selected_field_index == 2 { self.datetime_format_input.input(inp.clone()); self.datetime_format = self.datetime_format_input.lines().join(""); }
+        if self.operation == ColumnOperationKind::Redact && self.selected_field_index == 6 { self.redact_pattern_input.input(inp.clone()); self.redaction.custom_pattern = self.redact_pattern_input.lines().join(""); }
+        if self.operation == ColumnOperationKind::Outliers && self.selected_field_index == 3 { self.outlier_threshold_input.input(inp.clone()); self.outliers.threshold = self.outlier_threshold_input.lines().join(""); }
+        if self.operation == ColumnOperationKind::Lookup && self.selected_field_index == 4 { self.lookup_value_columns_input.input(inp); self.lookup_value_columns = self.lookup_value_columns_input.lines().join(""); }
     }
 
     fn sync_numbers_from_inputs(&mut self) {
@@ -1089,6 +1736,9 @@ impl ColumnOperationOptionsDialog {
         if let Ok(v) = self.num_dimensions_input.lines().join("").parse::<usize>() { self.num_dimensions = v; }
         // PCA
         if let Ok(v) = self.target_embedding_size_input.lines().join("").parse::<usize>() { self.target_embedding_size = v; }
+        // t-SNE
+        if let Ok(v) = self.tsne_perplexity_input.lines().join("").parse::<usize>() { self.tsne_perplexity = v; }
+        if let Ok(v) = self.tsne_iterations_input.lines().join("").parse::<usize>() { self.tsne_iterations = v; }
         // KMeans
         if let Ok(v) = self.kmeans_number_of_clusters_input.lines().join("").parse::<usize>() { self.kmeans.number_of_clusters = v; }
         if let Ok(v) = self.kmeans_runs_input.lines().join("").parse::<usize>() { self.kmeans.runs = v; }
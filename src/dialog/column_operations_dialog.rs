@@ -8,14 +8,47 @@ use crate::components::Component;
 use crate::components::dialog_layout::split_dialog_area;
 use crate::dialog::error_dialog::{ErrorDialog, render_error_dialog};
 use crate::style::StyleConfig;
+use crate::dialog::column_operation_options_dialog::ColumnOperationPreset;
 use serde::{Serialize, Deserialize};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ColumnOperationKind {
     GenerateEmbeddings,
     Pca,
+    Tsne,
     Cluster,
+    RegexExtract,
+    ParseDatetime,
+    /// Convert mixed text, epoch, FILETIME and WebKit timestamps into one UTC Datetime column
+    NormalizeTimestamps,
+    Hash,
+    Redact,
+    Outliers,
+    /// Map values through a key column of another loaded dataset
+    Lookup,
+    /// Look up hashes, IPs or domains with a threat-intel provider
+    ThreatIntel,
     SortByPromptSimilarity,
+    /// A column operation exported by a WASM plugin
+    Plugin,
+}
+
+impl ColumnOperationKind {
+    /// Whether each row's result depends only on that row, so the operation can be limited to
+    /// the rows of a filter
+    pub fn runs_per_row(&self) -> bool {
+        matches!(
+            self,
+            Self::GenerateEmbeddings
+                | Self::RegexExtract
+                | Self::ParseDatetime
+                | Self::NormalizeTimestamps
+                | Self::Hash
+                | Self::Lookup
+                | Self::ThreatIntel
+                | Self::Plugin
+        )
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -31,6 +64,8 @@ pub struct ColumnOperationsDialog {
     pub show_instructions: bool,
     pub selected_index: usize,
     pub operations: Vec<ColumnOperationKind>,
+    /// Saved presets, listed after the operations
+    pub presets: Vec<ColumnOperationPreset>,
     #[serde(skip)]
     pub config: crate::config::Config,
 }
@@ -45,19 +80,67 @@ impl ColumnOperationsDialog {
             operations: vec![
                 ColumnOperationKind::GenerateEmbeddings,
                 ColumnOperationKind::Pca,
+                ColumnOperationKind::Tsne,
                 ColumnOperationKind::Cluster,
+                ColumnOperationKind::RegexExtract,
+                ColumnOperationKind::ParseDatetime,
+                ColumnOperationKind::NormalizeTimestamps,
+                ColumnOperationKind::Hash,
+                ColumnOperationKind::Redact,
+                ColumnOperationKind::Outliers,
+                ColumnOperationKind::Lookup,
+                ColumnOperationKind::ThreatIntel,
                 ColumnOperationKind::SortByPromptSimilarity,
             ],
+            presets: Vec::new(),
             config: crate::config::Config::default(),
         }
+        .with_plugin_operations()
+    }
+
+    /// List plugin operations when any plugin provides them
+    fn with_plugin_operations(mut self) -> Self {
+        if !crate::plugins::operations().is_empty() {
+            self.operations.push(ColumnOperationKind::Plugin);
+        }
+        self
+    }
+
+    pub fn set_presets(&mut self, presets: Vec<ColumnOperationPreset>) {
+        self.presets = presets;
+        self.selected_index = self.selected_index.min(self.item_count().saturating_sub(1));
+    }
+
+    fn item_count(&self) -> usize {
+        self.operations.len() + self.presets.len()
+    }
+
+    fn selected_preset(&self) -> Option<&ColumnOperationPreset> {
+        self.selected_index.checked_sub(self.operations.len()).and_then(|i| self.presets.get(i))
+    }
+
+    fn preset_description(preset: &ColumnOperationPreset) -> String {
+        let cfg = &preset.config;
+        let target = if cfg.new_column_name.trim().is_empty() { String::new() } else { format!(" -> {}", cfg.new_column_name) };
+        format!("{} on {}{target}", Self::operation_label(&cfg.operation), cfg.source_column)
     }
 
     fn operation_label(op: &ColumnOperationKind) -> &'static str {
         match op {
             ColumnOperationKind::GenerateEmbeddings => "Generate Embeddings",
             ColumnOperationKind::Pca => "PCA (Principal Component Analysis)",
+            ColumnOperationKind::Tsne => "t-SNE 2-D Projection",
             ColumnOperationKind::Cluster => "Cluster",
+            ColumnOperationKind::RegexExtract => "Regex Extract",
+            ColumnOperationKind::ParseDatetime => "Parse as Datetime",
+            ColumnOperationKind::NormalizeTimestamps => "Normalize Timestamps",
+            ColumnOperationKind::Hash => "Hash Values",
+            ColumnOperationKind::Redact => "Redact PII",
+            ColumnOperationKind::Outliers => "Detect Outliers",
+            ColumnOperationKind::Lookup => "Lookup Enrichment",
+            ColumnOperationKind::ThreatIntel => "Threat-Intel Enrichment",
             ColumnOperationKind::SortByPromptSimilarity => "Sort by Prompt Similarity",
+            ColumnOperationKind::Plugin => "Plugin Operation",
         }
     }
 
@@ -65,8 +148,18 @@ impl ColumnOperationsDialog {
         match op {
             ColumnOperationKind::GenerateEmbeddings => "Convert text data into numerical vectors for machine learning",
             ColumnOperationKind::Pca => "Reduce dimensionality while preserving most of the data variance",
+            ColumnOperationKind::Tsne => "Project embeddings to x/y coordinates that keep similar rows close together",
             ColumnOperationKind::Cluster => "Group similar data points together using clustering algorithms",
+            ColumnOperationKind::RegexExtract => "Split a text column into one new column per regex capture group",
+            ColumnOperationKind::ParseDatetime => "Convert text timestamps into a Datetime column for temporal sorting and filtering",
+            ColumnOperationKind::NormalizeTimestamps => "Detect ISO 8601, epoch seconds/millis/micros, Windows FILETIME and Chrome/WebKit values row by row and convert them all to UTC",
+            ColumnOperationKind::Hash => "Compute MD5/SHA1/SHA256 of each value for matching indicator lists or verifying evidence",
+            ColumnOperationKind::Redact => "Mask or hash emails, SSNs and custom patterns into a sanitized copy of the dataset",
+            ColumnOperationKind::Outliers => "Flag or score unusual values using z-score or IQR fences",
+            ColumnOperationKind::Lookup => "Append columns looked up by key in another loaded dataset, e.g. user_id to display name",
+            ColumnOperationKind::ThreatIntel => "Query VirusTotal, AbuseIPDB or OTX for each distinct hash, IP or domain and append a verdict column",
             ColumnOperationKind::SortByPromptSimilarity => "Compute cosine similarity of an embedding column to a user prompt and create a score column you can sort by",
+            ColumnOperationKind::Plugin => "Add a column computed from each value by a loaded WASM plugin",
         }
     }
 
@@ -74,8 +167,18 @@ impl ColumnOperationsDialog {
         match op {
             ColumnOperationKind::GenerateEmbeddings => "Requires: Text columns, OpenAI API key",
             ColumnOperationKind::Pca => "Requires: Numerical columns only",
+            ColumnOperationKind::Tsne => "Requires: Vector column, at most 3000 rows",
             ColumnOperationKind::Cluster => "Requires: Numerical columns, specify number of clusters",
+            ColumnOperationKind::RegexExtract => "Requires: Text column, pattern with (?P<name>...) groups",
+            ColumnOperationKind::ParseDatetime => "Requires: Text column; leave format empty to auto-detect",
+            ColumnOperationKind::NormalizeTimestamps => "Requires: Text or integer column; unparseable rows are reported",
+            ColumnOperationKind::Hash => "Requires: Text or binary column",
+            ColumnOperationKind::Redact => "Requires: Text columns; creates a new dataset tab",
+            ColumnOperationKind::Outliers => "Requires: Numeric column",
+            ColumnOperationKind::Lookup => "Requires: A second loaded dataset holding the key column",
+            ColumnOperationKind::ThreatIntel => "Requires: Text column of hashes, IPs or domains; an API key under Project Settings",
             ColumnOperationKind::SortByPromptSimilarity => "Requires: At least one embedding column to reference",
+            ColumnOperationKind::Plugin => "Requires: A plugin in the plugin directory; values are passed as text",
         }
     }
 
@@ -92,7 +195,10 @@ impl ColumnOperationsDialog {
         
         // Render operation details for selected item
         if let Some(selected_op) = self.operations.get(self.selected_index) {
-            self.render_operation_details(selected_op, inner, buf);
+            self.render_operation_details(Self::operation_description(selected_op), Self::operation_requirements(selected_op), inner, buf);
+        } else if let Some(preset) = self.selected_preset() {
+            let description = format!("Preset: {}", Self::preset_description(preset));
+            self.render_operation_details(&description, Self::operation_requirements(&preset.config.operation), inner, buf);
         } else {
             // Show helpful message when no operation is selected
             self.render_no_selection_message(inner, buf);
@@ -100,24 +206,28 @@ impl ColumnOperationsDialog {
     }
 
     fn render_operations_list(&self, area: Rect, buf: &mut Buffer) {
-        let list_height = (area.height.saturating_sub(8)).min(self.operations.len() as u16);
-        
-        for (i, op) in self.operations
-                .iter()
+        let list_height = (area.height.saturating_sub(8)).min(self.item_count() as u16);
+        // Scroll so the selected item stays in view once presets make the list longer
+        let first = self.selected_index.saturating_sub((list_height as usize).saturating_sub(1));
+        let labels = self.operations
+            .iter()
+            .map(|op| Self::operation_label(op).to_string())
+            .chain(self.presets.iter().map(|p| format!("★ {}", p.name)));
+
+        for (row, (i, label)) in labels
                 .enumerate()
+                .skip(first)
                 .take(list_height as usize)
+                .enumerate()
         {
-            let y = area.y + i as u16;
-            if y >= area.y + list_height { break; }
-            
+            let y = area.y + row as u16;
             let is_selected = i == self.selected_index;
-            self.render_operation_item(op, area.x + 1, y, area.width - 2, is_selected, buf);
+            self.render_operation_item(&label, area.x + 1, y, area.width - 2, is_selected, buf);
         }
     }
 
-    fn render_operation_item(&self, op: &ColumnOperationKind, x: u16, y: u16, _width: u16, is_selected: bool, buf: &mut Buffer) {
+    fn render_operation_item(&self, label: &str, x: u16, y: u16, _width: u16, is_selected: bool, buf: &mut Buffer) {
         let marker = if is_selected { "▶" } else { " " };
-        let label = Self::operation_label(op);
         
         let style = if is_selected {
             Style::default()
@@ -133,10 +243,7 @@ impl ColumnOperationsDialog {
         buf.set_string(x, y, line, style);
     }
 
-    fn render_operation_details(&self, op: &ColumnOperationKind, area: Rect, buf: &mut Buffer) {
-        // Calculate required content
-        let requirements = Self::operation_requirements(op);
-        let description = Self::operation_description(op);
+    fn render_operation_details(&self, description: &str, requirements: &str, area: Rect, buf: &mut Buffer) {
 
         // Calculate text width (accounting for borders and padding)
         let text_width = (area.width - 4).max(20) as usize; // 2 for borders + 2 for padding
@@ -326,12 +433,25 @@ impl ColumnOperationsDialog {
     }
 
     fn apply_selected(&self) -> Option<Action> {
+        if let Some(preset) = self.selected_preset() {
+            return Some(Action::ColumnOperationPresetSelected(preset.clone()));
+        }
         if let Some(op) = self.operations.get(self.selected_index) {
             let op_name = match op {
                 ColumnOperationKind::GenerateEmbeddings => "GenerateEmbeddings".to_string(),
                 ColumnOperationKind::Pca => "Pca".to_string(),
+                ColumnOperationKind::Tsne => "Tsne".to_string(),
                 ColumnOperationKind::Cluster => "Cluster".to_string(),
+                ColumnOperationKind::RegexExtract => "RegexExtract".to_string(),
+                ColumnOperationKind::ParseDatetime => "ParseDatetime".to_string(),
+                ColumnOperationKind::NormalizeTimestamps => "NormalizeTimestamps".to_string(),
+                ColumnOperationKind::Hash => "Hash".to_string(),
+                ColumnOperationKind::Redact => "Redact".to_string(),
+                ColumnOperationKind::Outliers => "Outliers".to_string(),
+                ColumnOperationKind::Lookup => "Lookup".to_string(),
+                ColumnOperationKind::ThreatIntel => "ThreatIntel".to_string(),
                 ColumnOperationKind::SortByPromptSimilarity => "SortByPromptSimilarity".to_string(),
+                ColumnOperationKind::Plugin => "Plugin".to_string(),
             };
             return Some(Action::ColumnOperationRequested(op_name));
         }
@@ -342,13 +462,17 @@ impl ColumnOperationsDialog {
     fn build_instructions_from_config(&self) -> String {
         match self.mode {
             ColumnOperationsMode::SelectOperation => {
-                self.config.actions_to_instructions(&[
+                let mut actions = vec![
                     (crate::config::Mode::Global, crate::action::Action::Up),
                     (crate::config::Mode::Global, crate::action::Action::Down),
                     (crate::config::Mode::Global, crate::action::Action::Enter),
                     (crate::config::Mode::Global, crate::action::Action::Escape),
                     (crate::config::Mode::Global, crate::action::Action::ToggleInstructions),
-                ])
+                ];
+                if !self.presets.is_empty() {
+                    actions.push((crate::config::Mode::ColumnOperations, crate::action::Action::DeleteColumnOperationPreset));
+                }
+                self.config.actions_to_instructions(&actions)
             }
             ColumnOperationsMode::Error(_) => String::new(),
         }
@@ -365,13 +489,13 @@ impl ColumnOperationsDialog {
                         return Ok(None);
                     }
                     Action::Up => {
-                        if !self.operations.is_empty() {
-                            if self.selected_index == 0 { self.selected_index = self.operations.len() - 1; } else { self.selected_index -= 1; }
+                        if self.item_count() > 0 {
+                            if self.selected_index == 0 { self.selected_index = self.item_count() - 1; } else { self.selected_index -= 1; }
                         }
                         return Ok(None);
                     }
                     Action::Down => {
-                        if !self.operations.is_empty() { self.selected_index = (self.selected_index + 1) % self.operations.len(); }
+                        if self.item_count() > 0 { self.selected_index = (self.selected_index + 1) % self.item_count(); }
                         return Ok(None);
                     }
                     Action::ToggleInstructions => {
@@ -382,8 +506,13 @@ impl ColumnOperationsDialog {
                 }
             }
 
-            // No dialog-specific actions for this simple dialog
-            // All functionality is handled by Global actions
+            if self.config.action_for_key(crate::config::Mode::ColumnOperations, key) == Some(Action::DeleteColumnOperationPreset)
+                && let Some(preset) = self.selected_preset() {
+                    let name = preset.name.clone();
+                    self.presets.retain(|p| p.name != name);
+                    self.selected_index = self.selected_index.min(self.item_count().saturating_sub(1));
+                    return Ok(Some(Action::ColumnOperationPresetDeleted(name)));
+                }
         }
         Ok(None)
     }
@@ -408,3 +537,50 @@ impl Component for ColumnOperationsDialog {
     fn update(&mut self, _action: Action) -> Result<Option<Action>> { Ok(None) }
     fn draw(&mut self, frame: &mut ratatui::Frame, area: Rect) -> Result<()> { self.render(area, frame.buffer_mut()); Ok(()) }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dataframe::hashing::HashAlgorithm;
+    use crate::dialog::ColumnOperationOptionsDialog;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn test_save_select_and_delete_preset() {
+        let mut options = ColumnOperationOptionsDialog::new_with_columns(ColumnOperationKind::Hash, vec!["ip".to_string(), "user".to_string()], 1);
+        options.config.reset_keybindings_to_default();
+        options.hash_algorithm = HashAlgorithm::Md5;
+        options.handle_key_event(key(KeyCode::Char('s'), KeyModifiers::CONTROL)).unwrap();
+        for c in "user md5".chars() {
+            options.handle_key_event(key(KeyCode::Char(c), KeyModifiers::NONE)).unwrap();
+        }
+        let Some(Action::ColumnOperationPresetSaved(preset)) = options.handle_key_event(key(KeyCode::Enter, KeyModifiers::NONE)).unwrap() else {
+            panic!("expected a saved preset");
+        };
+        assert_eq!(preset.name, "user md5");
+        assert_eq!(preset.config.source_column, "user");
+
+        // Loading the preset into a fresh dialog restores the same operation
+        let mut fresh = ColumnOperationOptionsDialog::new_with_columns(ColumnOperationKind::Hash, vec!["ip".to_string(), "user".to_string()], 0);
+        fresh.load_preset(&preset);
+        assert_eq!(fresh.current_config(), preset.config);
+
+        let mut dialog = ColumnOperationsDialog::new();
+        dialog.config.reset_keybindings_to_default();
+        dialog.set_presets(vec![preset.clone()]);
+        dialog.selected_index = dialog.operations.len();
+        assert_eq!(
+            dialog.handle_key_event(key(KeyCode::Enter, KeyModifiers::NONE)).unwrap(),
+            Some(Action::ColumnOperationPresetSelected(preset))
+        );
+        assert_eq!(
+            dialog.handle_key_event(key(KeyCode::Delete, KeyModifiers::NONE)).unwrap(),
+            Some(Action::ColumnOperationPresetDeleted("user md5".to_string()))
+        );
+        assert!(dialog.presets.is_empty());
+    }
+}
@@ -14,6 +14,7 @@ use ratatui::Frame;
 use ratatui::layout::Size;
 use tokio::sync::mpsc::UnboundedSender;
 use crate::components::Component;
+use crate::components::column_search::ColumnSearch;
 use crate::components::dialog_layout::split_dialog_area;
 
 /// Represents column width configuration
@@ -25,6 +26,16 @@ pub struct ColumnWidthConfig {
     pub manual_widths: HashMap<String, u16>,
     /// Hidden columns (column name -> hidden status)
     pub hidden_columns: HashMap<String, bool>,
+    /// Columns kept on screen while scrolling sideways; pinning moves a column to the front
+    #[serde(default)]
+    pub pinned_columns: Vec<String>,
+    /// Columns whose text wraps onto several lines instead of being cut to one
+    #[serde(default)]
+    pub wrapped_columns: Vec<String>,
+    /// Column whose groups of equal values alternate the row background, in place of every
+    /// other row
+    #[serde(default)]
+    pub group_column: Option<String>,
 }
 
 impl Default for ColumnWidthConfig {
@@ -33,6 +44,9 @@ impl Default for ColumnWidthConfig {
             auto_expand: true,
             manual_widths: HashMap::new(),
             hidden_columns: HashMap::new(),
+            pinned_columns: Vec::new(),
+            wrapped_columns: Vec::new(),
+            group_column: None,
         }
     }
 }
@@ -78,6 +92,9 @@ pub struct ColumnWidthDialog {
     pub show_instructions: bool, // new: show instructions area (default true)
     /// Current calculated widths for each column (used to lock widths when auto_expand is disabled)
     pub current_calculated_widths: HashMap<String, u16>,
+    /// Type-to-jump over the column list
+    #[serde(skip)]
+    pub column_search: ColumnSearch,
     #[serde(skip)]
     pub key_config: Config,
 }
@@ -96,6 +113,7 @@ impl ColumnWidthDialog {
             editing_column: None,
             show_instructions: true,
             current_calculated_widths: HashMap::new(),
+            column_search: ColumnSearch::default(),
             key_config: Config::default(),
         }
     }
@@ -129,6 +147,16 @@ impl ColumnWidthDialog {
         self.current_calculated_widths = widths;
     }
 
+    /// Set every column to its exact width, computed over all rows
+    pub fn apply_exact_widths(&mut self, widths: HashMap<String, u16>) {
+        for col in &self.columns {
+            if let Some(&width) = widths.get(col) {
+                self.config.manual_widths.insert(col.clone(), width);
+            }
+        }
+        self.current_calculated_widths = widths;
+    }
+
     /// Lock all columns to their current calculated widths (sets manual widths for columns without one)
     fn lock_all_columns(&mut self) {
         for col in &self.columns {
@@ -218,8 +246,11 @@ impl ColumnWidthDialog {
         self.key_config.actions_to_instructions(&[
             (Mode::ColumnWidthDialog, Action::ToggleAutoExpand),
             (Mode::ColumnWidthDialog, Action::ToggleColumnHidden),
+            (Mode::ColumnWidthDialog, Action::ToggleColumnWrap),
             (Mode::ColumnWidthDialog, Action::MoveColumnUp),
             (Mode::ColumnWidthDialog, Action::MoveColumnDown),
+            (Mode::ColumnWidthDialog, Action::ComputeExactColumnWidths),
+            (Mode::ColumnWidthDialog, Action::SearchColumns),
             (Mode::Global, Action::ToggleInstructions),
             (Mode::Global, Action::Escape),
             (Mode::Global, Action::Enter),
@@ -247,7 +278,7 @@ impl ColumnWidthDialog {
         let instructions_area = layout.instructions_area;
         // Draw dialog frame
         let block = Block::default()
-            .title("Configuration")
+            .title(self.column_search.title().unwrap_or_else(|| "Configuration".to_string()))
             .borders(Borders::ALL);
         block.render(content_area, buf);
 
@@ -308,6 +339,11 @@ impl ColumnWidthDialog {
                         Some(w) => format!("{w}"),
                         None => "auto".to_string(),
                     };
+                    let width_display = if self.config.wrapped_columns.contains(col) {
+                        format!("{width_display}, wrap")
+                    } else {
+                        width_display
+                    };
                     
                     // Create toggle box for hidden status
                     let toggle_box = if is_hidden { "[✓]" } else { "[ ]" };
@@ -357,6 +393,17 @@ impl ColumnWidthDialog {
         use crossterm::event::KeyCode;
         
         if key.kind == KeyEventKind::Press {
+            // While searching, keys edit the query and move to the best matching column
+            if self.column_search.is_active() {
+                if let Some(i) = self.column_search.handle_key(key, &self.columns) {
+                    self.active_index = i + 1; // +1 because index 0 is auto-expand toggle
+                    if i < self.scroll_offset || i >= self.scroll_offset + max_rows {
+                        self.scroll_offset = i.saturating_sub(max_rows / 2);
+                    }
+                }
+                return None;
+            }
+
             // First, honor config-driven Global actions
             if let Some(global_action) = self.key_config.action_for_key(crate::config::Mode::Global, key) {
                 match global_action {
@@ -480,6 +527,20 @@ impl ColumnWidthDialog {
                         }
                         return None;
                     }
+                    Action::ToggleColumnWrap => {
+                        if self.active_index > 0 {
+                            let col_idx = self.active_index - 1; // -1 because index 0 is auto-expand
+                            if let Some(col_name) = self.columns.get(col_idx) {
+                                let wrapped = &mut self.config.wrapped_columns;
+                                if let Some(i) = wrapped.iter().position(|c| c == col_name) {
+                                    wrapped.remove(i);
+                                } else {
+                                    wrapped.push(col_name.clone());
+                                }
+                            }
+                        }
+                        return None;
+                    }
                     Action::MoveColumnUp => {
                         // Ctrl+Up: Move column up
                         if self.active_index > 0 {
@@ -492,6 +553,17 @@ impl ColumnWidthDialog {
                         }
                         return None;
                     }
+                    Action::ComputeExactColumnWidths => {
+                        self.editing_column = None;
+                        self.input_buffer.clear();
+                        return Some(Action::ComputeExactColumnWidths);
+                    }
+                    Action::SearchColumns => {
+                        self.editing_column = None;
+                        self.input_buffer.clear();
+                        self.column_search.start();
+                        return None;
+                    }
                     Action::MoveColumnDown => {
                         // Ctrl+Down: Move column down
                         if self.active_index > 0 {
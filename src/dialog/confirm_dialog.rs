@@ -0,0 +1,164 @@
+//! ConfirmDialog: yes/no prompt shown before an irreversible operation, with a
+//! "don't ask again" option that is remembered in the config
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Clear, Paragraph, Wrap};
+use serde::{Deserialize, Serialize};
+
+use crate::action::Action;
+use crate::config::{Config, Mode};
+
+/// Operations that ask for confirmation first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConfirmKind {
+    /// Replacing the current view with SQL or JMESPath results
+    OverwriteView,
+    DropColumns,
+    ResetFilters,
+    /// Closing tabs with generated data, transforms or row tags
+    CloseUnsavedTabs,
+}
+
+impl ConfirmKind {
+    pub fn title(&self) -> &'static str {
+        match self {
+            ConfirmKind::OverwriteView => "Overwrite View",
+            ConfirmKind::DropColumns => "Drop Columns",
+            ConfirmKind::ResetFilters => "Reset Filters",
+            ConfirmKind::CloseUnsavedTabs => "Close Tabs",
+        }
+    }
+}
+
+/// ConfirmDialog: holds the action to run once the user agrees
+#[derive(Debug, Clone)]
+pub struct ConfirmDialog {
+    pub kind: ConfirmKind,
+    pub message: String,
+    /// Action the owner runs on `Action::ConfirmDialogAccepted`
+    pub pending: Action,
+    pub dont_ask_again: bool,
+    pub config: Config,
+}
+
+impl ConfirmDialog {
+    pub fn new(kind: ConfirmKind, message: impl Into<String>, pending: Action) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            pending,
+            dont_ask_again: false,
+            config: Config::default(),
+        }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    /// Store the "don't ask again" choice in `config` (and the config file) once accepted
+    pub fn remember(&self, config: &mut Config) -> std::io::Result<()> {
+        if self.dont_ask_again {
+            config.skip_confirmation(self.kind)?;
+        }
+        Ok(())
+    }
+
+    fn hint(&self) -> String {
+        let keys = self.config.actions_to_instructions(&[
+            (Mode::Global, Action::Enter),
+            (Mode::Global, Action::Escape),
+        ]);
+        format!("y: yes  n: no  Space: don't ask again  {keys}")
+    }
+
+    fn modal_area(&self, area: Rect) -> Rect {
+        let width = area.width.saturating_sub(10).clamp(20, 70).min(area.width);
+        let wrap_width = width.saturating_sub(4).max(1) as usize;
+        let lines = textwrap::wrap(&self.message, wrap_width).len() as u16;
+        // Borders, message, blank line, checkbox, blank line, hint
+        let height = lines.saturating_add(6).min(area.height);
+        Rect {
+            x: area.x + area.width.saturating_sub(width) / 2,
+            y: area.y + area.height.saturating_sub(height) / 2,
+            width,
+            height,
+        }
+    }
+
+    /// Render the dialog centered in `area`
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        let modal = self.modal_area(area);
+        Clear.render(modal, buf);
+        let block = Block::default()
+            .title(self.kind.title())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double)
+            .border_style(Style::default().fg(Color::Yellow));
+        let inner = block.inner(modal);
+        block.render(modal, buf);
+        let body = Rect { x: inner.x + 1, width: inner.width.saturating_sub(2), ..inner };
+        let checkbox = if self.dont_ask_again { "[x]" } else { "[ ]" };
+        Paragraph::new(vec![
+            Line::raw(self.message.as_str()),
+            Line::raw(""),
+            Line::styled(format!("{checkbox} Don't ask again"), Style::default().fg(Color::Cyan)),
+        ])
+        .wrap(Wrap { trim: false })
+        .render(body, buf);
+        if inner.height >= 2 {
+            buf.set_stringn(body.x, inner.bottom() - 1, self.hint(), body.width as usize, Style::default().fg(Color::Gray));
+        }
+    }
+
+    /// Handle keyboard events: `Action::ConfirmDialogAccepted` when confirmed,
+    /// `Action::DialogClose` when declined
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        match self.config.action_for_key(Mode::Global, key) {
+            Some(Action::Enter) => return Some(Action::ConfirmDialogAccepted),
+            Some(Action::Escape) => return Some(Action::DialogClose),
+            _ => {}
+        }
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => Some(Action::ConfirmDialogAccepted),
+            KeyCode::Char('n') | KeyCode::Char('N') => Some(Action::DialogClose),
+            KeyCode::Char(' ') => {
+                self.dont_ask_again = !self.dont_ask_again;
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    #[test]
+    fn test_confirm_and_decline() {
+        let mut dialog = ConfirmDialog::new(ConfirmKind::ResetFilters, "Remove all filter conditions?", Action::ResetFilters);
+        dialog.config.reset_keybindings_to_default();
+        assert!(dialog.config.needs_confirmation(ConfirmKind::ResetFilters));
+        assert_eq!(dialog.handle_key_event(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE)), None);
+        assert!(dialog.dont_ask_again);
+
+        let area = Rect::new(0, 0, 80, 24);
+        let mut buf = Buffer::empty(area);
+        dialog.render(area, &mut buf);
+        let text: String = buf.content().iter().map(|c| c.symbol()).collect();
+        assert!(text.contains("Reset Filters") && text.contains("[x] Don't ask again"));
+
+        assert_eq!(dialog.handle_key_event(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::NONE)), Some(Action::DialogClose));
+        assert_eq!(dialog.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)), Some(Action::ConfirmDialogAccepted));
+        dialog.config.skip_confirmations.push(ConfirmKind::ResetFilters);
+        assert!(!dialog.config.needs_confirmation(ConfirmKind::ResetFilters));
+    }
+}
@@ -0,0 +1,337 @@
+//! CrosstabDialog: pivot two columns against each other (counts or an aggregated metric)
+
+use std::sync::Arc;
+
+use crossterm::event::{KeyEvent, KeyEventKind};
+use polars::prelude::DataFrame;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Cell, Clear, Paragraph, Row, Table, Wrap};
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+use crate::dataframe::crosstab::{crosstab, Crosstab, CrosstabOptions};
+use crate::dataframe::resample::ResampleAgg;
+
+const FIELD_ROW: usize = 0;
+const FIELD_COL: usize = 1;
+const FIELD_AGG: usize = 2;
+const FIELD_VALUE: usize = 3;
+const FIELD_COUNT: usize = 4;
+
+const MAX_KEY_WIDTH: usize = 30;
+const MAX_CELL_WIDTH: usize = 16;
+
+fn cycle_index(idx: usize, len: usize, forward: bool) -> usize {
+    if len == 0 {
+        return 0;
+    }
+    if forward { (idx + 1) % len } else { (idx + len - 1) % len }
+}
+
+fn format_cell(value: Option<f64>) -> String {
+    match value {
+        None => String::new(),
+        Some(v) if v.fract() == 0.0 && v.abs() < 1e15 => format!("{}", v as i64),
+        Some(v) => format!("{v:.2}"),
+    }
+}
+
+fn clip(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        text.to_string()
+    } else {
+        let mut s: String = text.chars().take(width.saturating_sub(1)).collect();
+        s.push('…');
+        s
+    }
+}
+
+/// CrosstabDialog: options on top, the pivoted table below. Tab moves focus between the two.
+#[derive(Debug)]
+pub struct CrosstabDialog {
+    pub dataset_name: String,
+    pub columns: Vec<String>,
+    pub row_idx: usize,
+    pub col_idx: usize,
+    pub aggregation: ResampleAgg,
+    pub value_idx: usize,
+    pub selected_field: usize,
+    /// Whether arrow keys scroll the table instead of editing options
+    pub table_focused: bool,
+    pub row_offset: usize,
+    pub col_offset: usize,
+    pub table: Option<Crosstab>,
+    pub error: Option<String>,
+    pub show_instructions: bool,
+    pub config: Config,
+    df: Arc<DataFrame>,
+}
+
+impl CrosstabDialog {
+    pub fn new(dataset_name: &str, df: Arc<DataFrame>, row_idx: usize) -> Self {
+        let columns: Vec<String> = df.get_column_names_owned().into_iter().map(|c| c.to_string()).collect();
+        let row_idx = row_idx.min(columns.len().saturating_sub(1));
+        let col_idx = if columns.len() > 1 { (row_idx + 1) % columns.len() } else { 0 };
+        let mut dialog = Self {
+            dataset_name: dataset_name.to_string(),
+            columns,
+            row_idx,
+            col_idx,
+            aggregation: ResampleAgg::Count,
+            value_idx: 0,
+            selected_field: FIELD_COL,
+            table_focused: false,
+            row_offset: 0,
+            col_offset: 0,
+            table: None,
+            error: None,
+            show_instructions: true,
+            config: Config::default(),
+            df,
+        };
+        dialog.recompute();
+        dialog
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn column(&self, idx: usize) -> String {
+        self.columns.get(idx).cloned().unwrap_or_default()
+    }
+
+    pub fn options(&self) -> CrosstabOptions {
+        CrosstabOptions {
+            row_column: self.column(self.row_idx),
+            col_column: self.column(self.col_idx),
+            aggregation: self.aggregation,
+            value_column: if self.aggregation.needs_value_column() { self.column(self.value_idx) } else { String::new() },
+        }
+    }
+
+    fn recompute(&mut self) {
+        self.row_offset = 0;
+        self.col_offset = 0;
+        match crosstab(&self.df, &self.options()) {
+            Ok(table) => {
+                self.table = Some(table);
+                self.error = None;
+            }
+            Err(e) => {
+                self.table = None;
+                self.error = Some(e.to_string());
+            }
+        }
+    }
+
+    fn cycle_field(&mut self, forward: bool) {
+        let n = self.columns.len();
+        match self.selected_field {
+            FIELD_ROW => self.row_idx = cycle_index(self.row_idx, n, forward),
+            FIELD_COL => self.col_idx = cycle_index(self.col_idx, n, forward),
+            FIELD_AGG => self.aggregation = self.aggregation.cycle(forward),
+            FIELD_VALUE => self.value_idx = cycle_index(self.value_idx, n, forward),
+            _ => return,
+        }
+        self.recompute();
+    }
+
+    fn build_instructions_from_config(&self) -> String {
+        format!(
+            "{}  Options: Left/Right to cycle  Table: arrows to scroll  Enter: open as new dataset",
+            self.config.actions_to_instructions(&[
+                (Mode::Global, Action::Tab),
+                (Mode::Global, Action::Escape),
+                (Mode::Global, Action::ToggleInstructions),
+            ])
+        )
+    }
+
+    /// Render the dialog
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title("Crosstab")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(FIELD_COUNT as u16 + 2), Constraint::Min(3)])
+            .split(layout.content_area);
+        self.render_options(chunks[0], buf);
+        self.render_table(chunks[1], buf);
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    fn render_options(&self, area: Rect, buf: &mut Buffer) {
+        let border_style = if self.table_focused { Style::default() } else { Style::default().fg(Color::Cyan) };
+        let block = Block::default().title("Options").borders(Borders::ALL).border_style(border_style);
+        let content = block.inner(area);
+        block.render(area, buf);
+
+        let labels = ["Rows:", "Columns:", "Aggregation:", "Value Column:"];
+        let label_width = labels.iter().map(|l| l.len()).max().unwrap_or(0) as u16 + 2;
+        for (i, label) in labels.iter().enumerate() {
+            let y = content.y + i as u16;
+            if y >= content.bottom() {
+                break;
+            }
+            let style = if i == self.selected_field && !self.table_focused {
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+            } else if i == FIELD_VALUE && !self.aggregation.needs_value_column() {
+                Style::default().fg(Color::DarkGray)
+            } else {
+                Style::default()
+            };
+            let choice = match i {
+                FIELD_ROW => self.column(self.row_idx),
+                FIELD_COL => self.column(self.col_idx),
+                FIELD_AGG => self.aggregation.label().to_string(),
+                _ => self.column(self.value_idx),
+            };
+            buf.set_string(content.x + 1, y, *label, style);
+            buf.set_string(content.x + 1 + label_width, y, format!("< {choice} >"), style);
+        }
+    }
+
+    fn render_table(&self, area: Rect, buf: &mut Buffer) {
+        let border_style = if self.table_focused { Style::default().fg(Color::Cyan) } else { Style::default() };
+        let Some(table) = &self.table else {
+            let message = self.error.clone().unwrap_or_default();
+            Paragraph::new(format!("Error: {message}"))
+                .style(Style::default().fg(Color::Red))
+                .wrap(Wrap { trim: true })
+                .block(Block::default().title("Table").borders(Borders::ALL).border_style(border_style))
+                .render(area, buf);
+            return;
+        };
+        let mut title = format!("{} rows × {} columns", table.row_keys.len(), table.col_keys.len());
+        if table.omitted_columns > 0 {
+            title.push_str(&format!(" ({} rarer column values hidden)", table.omitted_columns));
+        }
+        let block = Block::default().title(title).borders(Borders::ALL).border_style(border_style);
+
+        let key_width = table.row_keys.iter().map(|k| k.chars().count()).chain([self.column(self.row_idx).chars().count()]).max().unwrap_or(0).min(MAX_KEY_WIDTH);
+        let visible_cols: Vec<usize> = (self.col_offset..table.col_keys.len()).collect();
+        let mut widths = vec![Constraint::Length(key_width as u16)];
+        let mut header = vec![Cell::from(clip(&self.column(self.row_idx), key_width)).style(Style::default().add_modifier(Modifier::BOLD))];
+        for &c in &visible_cols {
+            let width = table.cells.iter().map(|r| format_cell(r[c]).len()).chain([table.col_keys[c].chars().count()]).max().unwrap_or(1).min(MAX_CELL_WIDTH);
+            widths.push(Constraint::Length(width as u16));
+            header.push(Cell::from(clip(&table.col_keys[c], width)).style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)));
+        }
+        let rows: Vec<Row> = table
+            .row_keys
+            .iter()
+            .zip(&table.cells)
+            .skip(self.row_offset)
+            .map(|(key, cells)| {
+                let mut row = vec![Cell::from(clip(key, key_width)).style(Style::default().fg(Color::Cyan))];
+                for &c in &visible_cols {
+                    row.push(Cell::from(Text::from(format_cell(cells[c])).alignment(Alignment::Right)));
+                }
+                Row::new(row)
+            })
+            .collect();
+        Widget::render(Table::new(rows, widths).header(Row::new(header)).block(block).column_spacing(2), area, buf);
+    }
+
+    fn scroll(&mut self, action: &Action) {
+        let Some(table) = &self.table else { return };
+        match action {
+            Action::Up => self.row_offset = self.row_offset.saturating_sub(1),
+            Action::Down => self.row_offset = (self.row_offset + 1).min(table.row_keys.len().saturating_sub(1)),
+            Action::Left => self.col_offset = self.col_offset.saturating_sub(1),
+            Action::Right => self.col_offset = (self.col_offset + 1).min(table.col_keys.len().saturating_sub(1)),
+            _ => {}
+        }
+    }
+
+    /// Handle keyboard events for the dialog
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        match self.config.action_for_key(Mode::Global, key) {
+            Some(Action::Escape) => return Some(Action::DialogClose),
+            Some(Action::Tab) => self.table_focused = !self.table_focused,
+            Some(Action::ToggleInstructions) => self.show_instructions = !self.show_instructions,
+            Some(Action::Enter) => {
+                let table = self.table.as_ref()?;
+                match table.to_dataframe(&self.column(self.row_idx)) {
+                    Ok(dataframe) => {
+                        return Some(Action::SqlDialogAppliedNewDataset {
+                            dataset_name: format!("{}_crosstab", self.dataset_name),
+                            dataframe: Arc::new(dataframe),
+                        });
+                    }
+                    Err(e) => self.error = Some(e.to_string()),
+                }
+            }
+            Some(action @ (Action::Up | Action::Down | Action::Left | Action::Right)) if self.table_focused => self.scroll(&action),
+            Some(Action::Up) => self.selected_field = self.selected_field.saturating_sub(1),
+            Some(Action::Down) => self.selected_field = (self.selected_field + 1).min(FIELD_COUNT - 1),
+            Some(Action::Left) => self.cycle_field(false),
+            Some(Action::Right) => self.cycle_field(true),
+            _ => {}
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use polars::prelude::*;
+
+    fn press(dialog: &mut CrosstabDialog, code: KeyCode) -> Option<Action> {
+        dialog.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn test_status_by_path_dataset() {
+        let df = df!(
+            "path" => ["/a", "/a", "/b"],
+            "status" => [200i64, 404, 200],
+            "bytes" => [10i64, 1, 5]
+        )
+        .unwrap();
+        let mut dialog = CrosstabDialog::new("access", Arc::new(df), 0);
+        dialog.config.reset_keybindings_to_default();
+        assert_eq!(dialog.options().col_column, "status");
+        assert_eq!(dialog.table.as_ref().unwrap().col_keys, ["200", "404"]);
+
+        press(&mut dialog, KeyCode::Down);
+        press(&mut dialog, KeyCode::Right);
+        press(&mut dialog, KeyCode::Down);
+        press(&mut dialog, KeyCode::Right);
+        press(&mut dialog, KeyCode::Right);
+        let options = dialog.options();
+        assert_eq!((options.col_column.as_str(), options.aggregation, options.value_column.as_str()), ("status", ResampleAgg::Sum, "bytes"));
+
+        match press(&mut dialog, KeyCode::Enter) {
+            Some(Action::SqlDialogAppliedNewDataset { dataset_name, dataframe }) => {
+                assert_eq!(dataset_name, "access_crosstab");
+                assert_eq!(dataframe.column("200").unwrap().f64().unwrap().get(0), Some(10.0));
+            }
+            other => panic!("expected a new dataset, got {other:?}"),
+        }
+    }
+}
@@ -5,9 +5,11 @@ use ratatui::widgets::{Block, Borders, Clear, Table, Row, Cell, Paragraph, Wrap}
 use serde::{Deserialize, Serialize};
 use crate::action::Action;
 use crate::config::Config;
+use crate::recent_files::RecentFiles;
 use crate::tui::Event;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use uuid::Uuid;
 use polars::prelude::*;
 use color_eyre::Result;
@@ -18,11 +20,16 @@ use tracing::{debug, info, warn, error};
 use tokio::sync::mpsc::UnboundedSender;
 use crate::components::Component;
 use crate::data_import_types::DataImportConfig;
+use crate::dataframe::categorical::{categorize_strings, format_bytes};
+use crate::dataframe::evidence::FileHash;
+use crate::workspace::is_generated_path;
 use crate::dialog::{
     data_import_dialog::DataImportDialog,
     alias_edit_dialog::AliasEditDialog,
+    dataset_notes_dialog::DatasetNotesDialog,
 };
 use crate::components::dialog_layout::split_dialog_area;
+use crate::sql::{sql_table_name, unique_table_name};
 use calamine::Reader;
 use crate::dialog::MessageDialog;
 
@@ -38,6 +45,9 @@ pub struct Dataset {
     pub status: DatasetStatus,
     #[serde(default)]
     pub error_message: Option<String>,
+    /// Analyst notes: where the dataset came from and what has been done to it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
 }
 
 /// Status of a dataset
@@ -81,6 +91,12 @@ pub struct DataSource {
     pub imported_datasets: usize,
     pub failed_datasets: usize,
     pub data_import_config: DataImportConfig,
+    /// Store repetitive string columns as Categorical when loading
+    #[serde(default)]
+    pub categorical_strings: bool,
+    /// SHA-256 of each source file, recorded when the source was added
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub file_hashes: Vec<FileHash>,
 }
 
 impl DataSource {
@@ -156,6 +172,7 @@ impl DataSource {
                     column_count: 0,
                     status: DatasetStatus::Pending,
                     error_message: None,
+                    notes: None,
                 }];
                 (name, text_config.file_path.to_string_lossy().to_string(), "Text File".to_string(), datasets)
             }
@@ -172,6 +189,7 @@ impl DataSource {
                     column_count: worksheet.column_count,
                     status: if worksheet.load { DatasetStatus::Pending } else { DatasetStatus::Failed },
                     error_message: if worksheet.load { None } else { Some("Worksheet not marked for import".to_string()) },
+                    notes: None,
                 }).collect();
                 (name, excel_config.file_path.to_string_lossy().to_string(), "Excel File".to_string(), datasets)
             }
@@ -192,6 +210,7 @@ impl DataSource {
                         column_count: 0,
                         status: DatasetStatus::Pending,
                         error_message: None,
+                        notes: None,
                     }];
                     (dataset_name, datasets)
                 } else {
@@ -204,6 +223,7 @@ impl DataSource {
                         column_count: 0,
                         status: DatasetStatus::Pending,
                         error_message: None,
+                        notes: None,
                     }];
                     (file_name, datasets)
                 };
@@ -223,6 +243,7 @@ impl DataSource {
                     column_count: 0,
                     status: DatasetStatus::Pending,
                     error_message: None,
+                    notes: None,
                 }];
                 (name, parquet_config.file_path.to_string_lossy().to_string(), "Parquet File".to_string(), datasets)
             }
@@ -243,6 +264,7 @@ impl DataSource {
                     column_count: 0,
                     status: DatasetStatus::Pending,
                     error_message: None,
+                    notes: None,
                 }];
                 (name, json_config.file_path.to_string_lossy().to_string(), "JSON File".to_string(), datasets)
             }
@@ -261,7 +283,9 @@ impl DataSource {
             total_datasets,
             imported_datasets,
             failed_datasets,
-            data_import_config: config.clone()
+            data_import_config: config.clone(),
+            categorical_strings: false,
+            file_hashes: Vec::new(),
         }
     }
 
@@ -312,6 +336,7 @@ impl DataSource {
                         data_source: self.clone(),
                         dataset: dataset.clone(),
                         dataframe: df_arc,
+                        generation: next_generation(),
                     };
                     result.insert(dataset.id.clone(), loaded_dataset);
                 }
@@ -916,8 +941,32 @@ impl DataSource {
             }
         };
 
-        Ok((Arc::new(df), warning))
+        Ok((Arc::new(self.finish_frame(df)?), warning))
     }
+
+    /// Apply the per-source load options to a freshly read DataFrame
+    pub fn finish_frame(&self, df: DataFrame) -> Result<DataFrame> {
+        if self.categorical_strings {
+            categorize_strings(df)
+        } else {
+            Ok(df)
+        }
+    }
+}
+
+static NEXT_GENERATION: AtomicU64 = AtomicU64::new(1);
+
+/// A number no loaded DataFrame has been given before
+pub fn next_generation() -> u64 {
+    NEXT_GENERATION.fetch_add(1, Ordering::Relaxed)
+}
+
+/// A DataFrame held in the dataset cache
+#[derive(Debug, Clone)]
+pub struct CachedDataFrame {
+    pub dataframe: Arc<DataFrame>,
+    /// Set when the DataFrame is loaded; a reload gets a new generation
+    pub generation: u64,
 }
 
 /// Represents a loaded dataset with its associated data
@@ -926,6 +975,9 @@ pub struct LoadedDataset {
     pub data_source: DataSource,
     pub dataset: Dataset,
     pub dataframe: Arc<DataFrame>,
+    /// Changes whenever the dataset's DataFrame is (re)loaded
+    #[serde(skip, default = "next_generation")]
+    pub generation: u64,
 }
 
 impl LoadedDataset {
@@ -951,6 +1003,11 @@ pub struct DataManagementDialog {
     #[serde(skip)]
     pub alias_edit_dialog: Option<AliasEditDialog>,
     #[serde(skip)]
+    pub notes_dialog: Option<DatasetNotesDialog>,
+    /// Imported datasets whose alias was changed to avoid a clash: (source_id, dataset_id, clashing name)
+    #[serde(skip)]
+    pub alias_reviews: VecDeque<(usize, String, String)>,
+    #[serde(skip)]
     pub message_dialog: Option<MessageDialog>,
     #[serde(skip)]
     pub config: Config,
@@ -987,7 +1044,10 @@ pub struct DataManagementDialog {
     pub update_status: Option<Option<crate::update_check::UpdateInfo>>, // None = not checked yet, Some(None) = up-to-date, Some(Some(...)) = update available
     /// Cache of loaded DataFrames keyed by dataset ID to avoid re-loading from disk
     #[serde(skip)]
-    pub dataframe_cache: HashMap<String, Arc<DataFrame>>,
+    pub dataframe_cache: HashMap<String, CachedDataFrame>,
+    /// Set once the soft memory limit warning has been shown, so the next load goes ahead
+    #[serde(skip)]
+    pub memory_limit_confirmed: bool,
 }
 
 impl Default for DataManagementDialog {
@@ -1080,6 +1140,8 @@ impl DataManagementDialog {
             show_instructions: true,
             data_import_dialog: None,
             alias_edit_dialog: None,
+            notes_dialog: None,
+            alias_reviews: VecDeque::new(),
             message_dialog: None,
             config: Config::default(),
             busy_active: false,
@@ -1097,6 +1159,7 @@ impl DataManagementDialog {
             current_json_options: None,
             update_status: None,
             dataframe_cache: HashMap::new(),
+            memory_limit_confirmed: false,
         }
     }
 
@@ -1109,11 +1172,12 @@ impl DataManagementDialog {
             for dataset in &data_source.datasets {
                 if dataset.status == DatasetStatus::Imported {
                     // First try the cache
-                    if let Some(df_arc) = self.dataframe_cache.get(&dataset.id) {
+                    if let Some(cached) = self.dataframe_cache.get(&dataset.id) {
                         let loaded_dataset = LoadedDataset {
                             data_source: data_source.clone(),
                             dataset: dataset.clone(),
-                            dataframe: df_arc.clone(),
+                            dataframe: cached.dataframe.clone(),
+                            generation: cached.generation,
                         };
                         result.insert(dataset.id.clone(), loaded_dataset);
                     } else {
@@ -1124,6 +1188,7 @@ impl DataManagementDialog {
                                 data_source: data_source.clone(),
                                 dataset: dataset.clone(),
                                 dataframe: df_arc,
+                                generation: next_generation(),
                             };
                             result.insert(dataset.id.clone(), loaded_dataset);
                         }
@@ -1142,11 +1207,12 @@ impl DataManagementDialog {
             for dataset in &data_source.datasets {
                 if dataset.status == DatasetStatus::Imported {
                     // First try the cache
-                    if let Some(df_arc) = self.dataframe_cache.get(&dataset.id) {
+                    if let Some(cached) = self.dataframe_cache.get(&dataset.id) {
                         let loaded_dataset = LoadedDataset {
                             data_source: data_source.clone(),
                             dataset: dataset.clone(),
-                            dataframe: df_arc.clone(),
+                            dataframe: cached.dataframe.clone(),
+                            generation: cached.generation,
                         };
                         result.insert(dataset.id.clone(), loaded_dataset);
                     } else {
@@ -1154,11 +1220,16 @@ impl DataManagementDialog {
                         // This happens when workspace state is loaded from disk on app startup
                         if let Ok(df_arc) = data_source.load_dataset(dataset) {
                             // Cache the loaded dataframe for future use
-                            self.dataframe_cache.insert(dataset.id.clone(), df_arc.clone());
+                            let generation = next_generation();
+                            self.dataframe_cache.insert(
+                                dataset.id.clone(),
+                                CachedDataFrame { dataframe: df_arc.clone(), generation },
+                            );
                             let loaded_dataset = LoadedDataset {
                                 data_source: data_source.clone(),
                                 dataset: dataset.clone(),
                                 dataframe: df_arc,
+                                generation,
                             };
                             result.insert(dataset.id.clone(), loaded_dataset);
                         }
@@ -1171,7 +1242,8 @@ impl DataManagementDialog {
 
     /// Store a dataframe in the cache
     pub fn cache_dataframe(&mut self, dataset_id: &str, dataframe: Arc<DataFrame>) {
-        self.dataframe_cache.insert(dataset_id.to_string(), dataframe);
+        self.dataframe_cache
+            .insert(dataset_id.to_string(), CachedDataFrame { dataframe, generation: next_generation() });
     }
 
     /// Clear the dataframe cache (useful when removing data sources)
@@ -1189,14 +1261,17 @@ impl DataManagementDialog {
         self.config.actions_to_instructions(&[
             (crate::config::Mode::Global, crate::action::Action::Escape),
             (crate::config::Mode::DataManagement, crate::action::Action::EditSelectedAlias),
+            (crate::config::Mode::DataManagement, crate::action::Action::EditSelectedNotes),
             (crate::config::Mode::DataManagement, crate::action::Action::DeleteSelectedSource),
             (crate::config::Mode::DataManagement, crate::action::Action::OpenDataImportDialog),
             (crate::config::Mode::DataManagement, crate::action::Action::LoadAllPendingDatasets),
+            (crate::config::Mode::DataManagement, crate::action::Action::ToggleCategoricalStrings),
         ])
     }
 
     /// Add a new data source from a DataImportConfig
     pub fn add_data_source(&mut self, config: DataImportConfig) {
+        let file_hashes = Self::hash_source_files(&config);
         // Special handling for SQLite to create individual data sources for each table
         if let DataImportConfig::Sqlite(sqlite_config) = &config {
             if sqlite_config.options.import_all_tables {
@@ -1208,16 +1283,12 @@ impl DataManagementDialog {
                             sqlite_config.options.clone(),
                             table_name
                         );
-                        let id = self.data_sources.len();
-                        let data_source: DataSource = DataSource::from_import_config(id, &table_config);
-                        self.data_sources.push(data_source);
+                        self.push_data_source(&table_config, &file_hashes);
                     }
                     return;
                 } else {
                     // Fallback to original behavior if we can't read tables
-                    let id = self.data_sources.len();
-                    let data_source: DataSource = DataSource::from_import_config(id, &config);
-                    self.data_sources.push(data_source);
+                    self.push_data_source(&config, &file_hashes);
                     return;
                 }
             } else if !sqlite_config.options.selected_tables.is_empty() {
@@ -1228,20 +1299,109 @@ impl DataManagementDialog {
                         sqlite_config.options.clone(),
                         table_name.clone()
                     );
-                    let id = self.data_sources.len();
-                    let data_source: DataSource = DataSource::from_import_config(id, &table_config);
-                    self.data_sources.push(data_source);
+                    self.push_data_source(&table_config, &file_hashes);
                 }
                 return;
             }
         }
         
         // Default behavior for all other import types
-        let id = self.data_sources.len();
-        let data_source: DataSource = DataSource::from_import_config(id, &config);
+        self.push_data_source(&config, &file_hashes);
+    }
+
+    /// Add a source for an interactive import. Its datasets get SQL-safe aliases derived from their
+    /// names; an alias changed to avoid clashing with another dataset is offered for editing.
+    pub fn import_data_source(&mut self, config: DataImportConfig) -> Result<()> {
+        let first_new = self.data_sources.len();
+        self.add_data_source(config);
+        for source_index in first_new..self.data_sources.len() {
+            for dataset_index in 0..self.data_sources[source_index].datasets.len() {
+                let dataset = &self.data_sources[source_index].datasets[dataset_index];
+                if dataset.status != DatasetStatus::Pending || dataset.alias.is_some() {
+                    continue;
+                }
+                let base = sql_table_name(&dataset.name);
+                let alias = unique_table_name(&base, &self.table_names_except(&dataset.id));
+                if alias != base {
+                    self.alias_reviews.push_back((self.data_sources[source_index].id, dataset.id.clone(), base));
+                }
+                let dataset = &mut self.data_sources[source_index].datasets[dataset_index];
+                if alias != dataset.name {
+                    dataset.alias = Some(alias);
+                }
+            }
+        }
+        self.open_next_alias_review()
+    }
+
+    /// SQL table names (alias, or name) of every dataset other than `dataset_id`
+    pub fn table_names_except(&self, dataset_id: &str) -> Vec<String> {
+        self.get_all_datasets()
+            .into_iter()
+            .filter(|(_, _, dataset)| dataset.id != dataset_id)
+            .map(|(_, _, dataset)| dataset.alias.clone().unwrap_or_else(|| dataset.name.clone()))
+            .collect()
+    }
+
+    /// Open the alias dialog for the next dataset whose alias was changed on import
+    fn open_next_alias_review(&mut self) -> Result<()> {
+        if self.alias_edit_dialog.is_some() {
+            return Ok(());
+        }
+        while let Some((source_id, dataset_id, clashing)) = self.alias_reviews.pop_front() {
+            let Some((_, _, dataset)) = self.get_all_datasets().into_iter().find(|(id, _, d)| *id == source_id && d.id == dataset_id) else {
+                continue;
+            };
+            let mut dialog = AliasEditDialog::new(source_id, dataset.id.clone(), dataset.name.clone(), dataset.alias.clone())
+                .with_taken_names(self.table_names_except(&dataset_id))
+                .with_notice(format!("'{clashing}' is already used by another dataset"));
+            dialog.register_config_handler(self.config.clone())?;
+            self.alias_edit_dialog = Some(dialog);
+            break;
+        }
+        Ok(())
+    }
+
+    /// Hash every file `config` reads; files that cannot be read are logged and left out
+    fn hash_source_files(config: &DataImportConfig) -> Vec<FileHash> {
+        config
+            .paths()
+            .into_iter()
+            .filter(|path| !is_generated_path(path))
+            .filter_map(|path| match FileHash::record(path) {
+                Ok(hash) => Some(hash),
+                Err(e) => {
+                    warn!("Failed to hash source file: {}", e);
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Append a source for `config`, using the configured default for Categorical strings
+    fn push_data_source(&mut self, config: &DataImportConfig, file_hashes: &[FileHash]) {
+        let mut data_source = DataSource::from_import_config(self.data_sources.len(), config);
+        data_source.categorical_strings = self.config.categorical_strings;
+        data_source.file_hashes = file_hashes.to_vec();
         self.data_sources.push(data_source);
     }
 
+    /// Toggle Categorical strings for a source. Its datasets go back to Pending so the next load
+    /// reads them with the new setting.
+    fn toggle_categorical_strings(&mut self, source_id: usize) {
+        let Some(source) = self.data_sources.iter_mut().find(|s| s.id == source_id) else { return };
+        source.categorical_strings = !source.categorical_strings;
+        let mut reload: Vec<String> = Vec::new();
+        for dataset in source.datasets.iter_mut().filter(|d| d.status == DatasetStatus::Imported) {
+            dataset.status = DatasetStatus::Pending;
+            reload.push(dataset.id.clone());
+        }
+        source.update_counts();
+        for id in reload {
+            self.dataframe_cache.remove(&id);
+        }
+    }
+
     /// Get table names from a SQLite database
     fn get_sqlite_table_names(file_path: &std::path::PathBuf) -> Result<Vec<String>> {
         use rusqlite::Connection;
@@ -1325,6 +1485,20 @@ impl DataManagementDialog {
         }
     }
 
+    /// Remove a single dataset; its source is removed too once it has no datasets left
+    pub fn remove_dataset(&mut self, dataset_id: &str) {
+        self.dataframe_cache.remove(dataset_id);
+        let Some(source) = self.data_sources.iter_mut().find(|s| s.datasets.iter().any(|d| d.id == dataset_id)) else {
+            return;
+        };
+        source.datasets.retain(|d| d.id != dataset_id);
+        source.update_counts();
+        if source.datasets.is_empty() {
+            let id = source.id;
+            self.remove_data_source(id);
+        }
+    }
+
     /// Get all datasets from all sources as a flat list with source info
     pub fn get_all_datasets(&self) -> Vec<(usize, &DataSource, &Dataset)> {
         let mut all_datasets = Vec::new();
@@ -1372,8 +1546,20 @@ impl DataManagementDialog {
         }
     }
 
+    /// Update dataset notes for a specific source and dataset
+    pub fn update_dataset_notes(&mut self, source_id: usize, dataset_id: &str, notes: Option<String>) {
+        if let Some(source) = self.data_sources.iter_mut().find(|s| s.id == source_id)
+            && let Some(dataset) = source.datasets.iter_mut().find(|d| d.id == dataset_id)
+        {
+            dataset.notes = notes;
+        }
+    }
+
     /// Load all pending datasets into the dataframe_mapping (used for both manual and auto-loading)
     pub fn load_all_pending_datasets(&mut self) -> Result<()> {
+        if self.check_memory_limit()? {
+            return Ok(());
+        }
         // Collect all pending datasets first to avoid borrow checker issues
         let mut pending_datasets = Vec::new();
         let mut load_errors: Vec<String> = Vec::new();
@@ -1428,9 +1614,58 @@ impl DataManagementDialog {
         Ok(())
     }
 
+    /// Estimated memory of the loaded datasets in bytes
+    pub fn loaded_memory(&self) -> usize {
+        self.dataframe_cache.values().map(|cached| cached.dataframe.estimated_size()).sum()
+    }
+
+    /// Rough size in bytes of the pending datasets, taken from their files on disk
+    fn pending_import_size(&self) -> usize {
+        self.data_sources
+            .iter()
+            .filter(|s| s.datasets.iter().any(|d| d.status == DatasetStatus::Pending))
+            .flat_map(|s| s.data_import_config.paths())
+            .filter_map(|p| std::fs::metadata(p).ok())
+            .map(|m| m.len() as usize)
+            .sum()
+    }
+
+    /// Warn when loading the pending datasets would go over the soft memory limit. Returns true
+    /// when the load should wait; loading again after the warning goes ahead.
+    fn check_memory_limit(&mut self) -> Result<bool> {
+        let Some(limit_mb) = self.config.memory_soft_limit_mb else { return Ok(false) };
+        if std::mem::take(&mut self.memory_limit_confirmed) {
+            return Ok(false);
+        }
+        let limit = limit_mb as usize * 1024 * 1024;
+        let loaded = self.loaded_memory();
+        let pending = self.pending_import_size();
+        if loaded + pending <= limit {
+            return Ok(false);
+        }
+        let load_keys = self.config.actions_to_instructions(&[
+            (crate::config::Mode::DataManagement, crate::action::Action::LoadAllPendingDatasets),
+        ]);
+        let message = format!(
+            "The pending datasets are about {} on disk and {} is already loaded, over the {} soft limit.\n\nLoad again ({}) to import anyway.",
+            format_bytes(pending),
+            format_bytes(loaded),
+            format_bytes(limit),
+            load_keys
+        );
+        let mut msg = MessageDialog::with_title(message, "Memory Limit");
+        msg.register_config_handler(self.config.clone())?;
+        self.message_dialog = Some(msg);
+        self.memory_limit_confirmed = true;
+        Ok(true)
+    }
+
     /// Begin queued import of all pending datasets, showing a busy overlay and
     /// processing one dataset per Render update.
     pub fn begin_queued_import(&mut self) -> Result<()> {
+        if self.check_memory_limit()? {
+            return Ok(());
+        }
         // Build queue of all Pending datasets
         self.pending_queue.clear();
         self.load_errors.clear();
@@ -1541,7 +1776,7 @@ impl DataManagementDialog {
                                 return Ok(());
                             }
                             // Finalize DataFrame from accumulated objects
-                            let df = Self::build_df_from_json_maps_local(&self.current_json_objects)?;
+                            let df = ds_ref.finish_frame(Self::build_df_from_json_maps_local(&self.current_json_objects)?)?;
                             self.update_dataset_status(source_id, &dataset_name, DatasetStatus::Imported);
                             self.update_dataset_data(source_id, &dataset_name, df.height(), df.width());
                             // Cache the dataframe to avoid reloading
@@ -1660,8 +1895,8 @@ impl DataManagementDialog {
             import_dialog.render(import_dialog_area, buf);
         } else if let Some(ref alias_dialog) = self.alias_edit_dialog {
             // Create a smaller centered dialog area for alias editing
-            let dialog_width = 50.min(area.width.saturating_sub(4));
-            let dialog_height = 10.min(area.height.saturating_sub(4));
+            let dialog_width = 60.min(area.width.saturating_sub(4));
+            let dialog_height = 12.min(area.height.saturating_sub(4));
             let alias_dialog_area = Rect::new(
                 area.x + (area.width.saturating_sub(dialog_width)) / 2,
                 area.y + (area.height.saturating_sub(dialog_height)) / 2,
@@ -1671,6 +1906,18 @@ impl DataManagementDialog {
             self.render_datasets_table(inner_area, buf);
             self.render_instructions(&instructions, instructions_area, buf);
             alias_dialog.render(alias_dialog_area, buf);
+        } else if let Some(ref notes_dialog) = self.notes_dialog {
+            let dialog_width = 70.min(area.width.saturating_sub(4));
+            let dialog_height = 16.min(area.height.saturating_sub(4));
+            let notes_dialog_area = Rect::new(
+                area.x + (area.width.saturating_sub(dialog_width)) / 2,
+                area.y + (area.height.saturating_sub(dialog_height)) / 2,
+                dialog_width,
+                dialog_height,
+            );
+            self.render_datasets_table(inner_area, buf);
+            self.render_instructions(&instructions, instructions_area, buf);
+            notes_dialog.render(notes_dialog_area, buf);
         } else {
             self.render_datasets_table(inner_area, buf);
             self.render_instructions(&instructions, instructions_area, buf);
@@ -1765,6 +2012,25 @@ impl DataManagementDialog {
             return;
         }
 
+        // Notes of the selected dataset go in a box under the table
+        let notes = self.selected_dataset().and_then(|(_, _, dataset)| dataset.notes.clone());
+        let (area, notes_area) = match &notes {
+            Some(notes) => {
+                let wrapped = textwrap::wrap(notes, area.width.saturating_sub(2).max(1) as usize).len() as u16;
+                let height = (wrapped + 2).min(area.height / 3);
+                let [table_area, notes_area] = Layout::vertical([Constraint::Min(1), Constraint::Length(height)]).areas(area);
+                (table_area, Some(notes_area))
+            }
+            None => (area, None),
+        };
+        if let (Some(notes), Some(notes_area)) = (notes, notes_area) {
+            Paragraph::new(notes)
+                .block(Block::default().borders(Borders::ALL).title("Notes"))
+                .style(Style::default().fg(Color::White))
+                .wrap(Wrap { trim: false })
+                .render(notes_area, buf);
+        }
+
         // Create table headers
         let headers = Row::new(vec![
             Cell::from("Source"),
@@ -1774,6 +2040,7 @@ impl DataManagementDialog {
             Cell::from("Status"),
             Cell::from("Rows"),
             Cell::from("Columns"),
+            Cell::from("Memory"),
             Cell::from("File Path"),
         ]).style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD));
 
@@ -1795,10 +2062,20 @@ impl DataManagementDialog {
                 Cell::from(source.name.as_str()),
                 Cell::from(dataset.name.as_str()),
                 Cell::from(dataset.alias.as_deref().unwrap_or("")),
-                Cell::from(source.import_type.as_str()),
+                Cell::from(if source.categorical_strings {
+                    format!("{} (cat)", source.import_type)
+                } else {
+                    source.import_type.clone()
+                }),
                 Cell::from(dataset.status.display_name()),
                 Cell::from(format!("{row_count}", row_count = dataset.row_count)),
                 Cell::from(format!("{col_count}", col_count = dataset.column_count)),
+                Cell::from(
+                    self.dataframe_cache
+                        .get(&dataset.id)
+                        .map(|cached| format_bytes(cached.dataframe.estimated_size()))
+                        .unwrap_or_default(),
+                ),
                 Cell::from(source.file_path.as_str()),
             ]).style(style)
         }).collect();
@@ -1812,6 +2089,7 @@ impl DataManagementDialog {
             Constraint::Length(12),
             Constraint::Length(8),
             Constraint::Length(8),
+            Constraint::Length(10),
             Constraint::Min(20),
         ])
         .header(headers)
@@ -1853,6 +2131,7 @@ impl Component for DataManagementDialog {
         // Propagate to child dialogs if they exist
         if let Some(ref mut d) = self.data_import_dialog { let _ = d.register_config_handler(self.config.clone()); }
         if let Some(ref mut d) = self.alias_edit_dialog { let _ = d.register_config_handler(self.config.clone()); }
+        if let Some(ref mut d) = self.notes_dialog { let _ = d.register_config_handler(self.config.clone()); }
         if let Some(ref mut d) = self.message_dialog { let _ = d.register_config_handler(self.config.clone()); }
         Ok(())
     }
@@ -1892,12 +2171,14 @@ impl Component for DataManagementDialog {
                 match action {
                     Action::DialogClose => {
                         self.alias_edit_dialog = None;
+                        self.open_next_alias_review()?;
                         return Ok(None);
                     }
                     Action::EditDatasetAlias { source_id, dataset_id, alias } => {
                         // Update the dataset alias
                         self.update_dataset_alias(source_id, &dataset_id, alias);
                         self.alias_edit_dialog = None;
+                        self.open_next_alias_review()?;
                         return Ok(None);
                     }
                     _ => {
@@ -1908,6 +2189,19 @@ impl Component for DataManagementDialog {
             return Ok(None);
         }
 
+        // Handle notes dialog if it's open
+        if let Some(ref mut notes_dialog) = self.notes_dialog {
+            match notes_dialog.handle_key_event(key) {
+                Some(Action::DialogClose) => self.notes_dialog = None,
+                Some(Action::EditDatasetNotes { source_id, dataset_id, notes }) => {
+                    self.update_dataset_notes(source_id, &dataset_id, notes);
+                    self.notes_dialog = None;
+                }
+                _ => {}
+            }
+            return Ok(None);
+        }
+
         // Handle data import dialog if it's open
         if let Some(ref mut import_dialog) = self.data_import_dialog {
             if let Some(action) = import_dialog.handle_key_event(key)? {
@@ -1918,7 +2212,8 @@ impl Component for DataManagementDialog {
                     }
                     Action::AddDataImportConfig { config } => {
                         // Add the data source from the import config
-                        self.add_data_source(config);
+                        RecentFiles::remember_import(&config);
+                        self.import_data_source(config)?;
                         self.data_import_dialog = None;
                     // Begin queued import; progress advances on Render updates
                     let _ = self.begin_queued_import();
@@ -1984,6 +2279,12 @@ impl Component for DataManagementDialog {
                     self.load_all_pending_datasets()?;
                     return Ok(None);
                 }
+                Action::ToggleCategoricalStrings => {
+                    if let Some((source_id, _source, _dataset)) = self.selected_dataset() {
+                        self.toggle_categorical_strings(source_id);
+                    }
+                    return Ok(None);
+                }
                 Action::EditSelectedAlias => {
                     if let Some((source_id, _source, dataset)) = self.selected_dataset() {
                         let mut _dialog = AliasEditDialog::new(
@@ -1991,12 +2292,26 @@ impl Component for DataManagementDialog {
                             dataset.id.clone(),
                             dataset.name.clone(),
                             dataset.alias.clone(),
-                        );
+                        )
+                        .with_taken_names(self.table_names_except(&dataset.id));
                         _dialog.register_config_handler(self.config.clone())?;
                         self.alias_edit_dialog = Some(_dialog);
                     }
                     return Ok(None);
                 }
+                Action::EditSelectedNotes => {
+                    if let Some((source_id, _source, dataset)) = self.selected_dataset() {
+                        let mut dialog = DatasetNotesDialog::new(
+                            source_id,
+                            dataset.id.clone(),
+                            dataset.name.clone(),
+                            dataset.notes.clone(),
+                        );
+                        dialog.register_config_handler(self.config.clone())?;
+                        self.notes_dialog = Some(dialog);
+                    }
+                    return Ok(None);
+                }
                 _ => {
                     info!("DataManagementDialog unhandled DataManagement action: {:?} for key: {:?}", dm_action, key);
                 }
@@ -2137,6 +2452,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_memory_soft_limit_warns_once() {
+        let mut dialog = DataManagementDialog::new();
+        dialog.config.memory_soft_limit_mb = Some(0);
+        dialog.add_data_source(make_text_import_config_with_temp_file());
+
+        dialog.load_all_pending_datasets().unwrap();
+        assert!(dialog.message_dialog.is_some());
+        assert_eq!(dialog.data_sources[0].datasets[0].status, DatasetStatus::Pending);
+
+        dialog.load_all_pending_datasets().unwrap();
+        assert_eq!(dialog.data_sources[0].datasets[0].status, DatasetStatus::Imported);
+        assert!(dialog.loaded_memory() > 0);
+    }
+
     #[test]
     fn test_dataset_alias_functionality() {
         let mut dialog = DataManagementDialog::new();
@@ -2160,4 +2490,20 @@ mod tests {
         dialog.update_dataset_alias(0, &dataset_id, None);
         assert_eq!(dialog.data_sources[0].datasets[0].alias, None);
     }
+
+    #[test]
+    fn test_import_assigns_unique_sql_aliases() {
+        let mut dialog = DataManagementDialog::new();
+        dialog.import_data_source(make_text_import_config_with_temp_file()).unwrap();
+        assert_eq!(dialog.data_sources[0].datasets[0].alias.as_deref(), Some("pokemon_data"));
+        assert!(dialog.alias_edit_dialog.is_none());
+
+        // A second file with the same name gets a suffix and is offered for editing
+        dialog.import_data_source(make_text_import_config_with_temp_file()).unwrap();
+        assert_eq!(dialog.data_sources[1].datasets[0].alias.as_deref(), Some("pokemon_data_2"));
+        let review = dialog.alias_edit_dialog.as_ref().expect("clash should open the alias dialog");
+        assert_eq!(review.input_buffer, "pokemon_data_2");
+        assert_eq!(review.notice.as_deref(), Some("'pokemon_data' is already used by another dataset"));
+        assert_eq!(review.taken_names, vec!["pokemon_data".to_string()]);
+    }
 } 
\ No newline at end of file
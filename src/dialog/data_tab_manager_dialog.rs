@@ -11,7 +11,7 @@ use crate::action::Action;
 use crate::config::Config;
 use crate::tui::Event;
 use color_eyre::Result;
-use crossterm::event::{KeyEvent, MouseEvent};
+use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::Frame;
 use ratatui::layout::Size;
 use tokio::sync::mpsc::UnboundedSender;
@@ -21,12 +21,14 @@ use crate::components::Component;
 use crate::components::datatable_container::DataTableContainer;
 use crate::components::datatable::DataTable;
 use crate::components::dialog_layout::split_dialog_area;
+use crate::dataframe::categorical::format_bytes;
 use crate::dataframe::manager::ManagedDataFrame;
 use crate::dialog::data_management_dialog::{LoadedDataset, DataManagementDialog};
 use crate::dialog::project_settings_dialog::{ProjectSettingsDialog, ProjectSettingsConfig};
 use crate::dialog::data_export_dialog::{DataExportDialog, DataExportMode};
 use crate::dialog::styling::{StyleSetManager, StyleSetManagerDialog};
-use crate::style::StyleConfig;
+use crate::dialog::styling::templates::{create_template_styleset, TemplateCategory, IOC_TEMPLATE_ID};
+use crate::style::{StyleConfig, downgrade_buffer};
 use std::collections::HashMap;
 use std::sync::Arc;
 use crate::data_import_types::DataImportConfig;
@@ -36,6 +38,13 @@ use std::io::{Write, BufWriter};
 use std::path::PathBuf;
 use csv::WriterBuilder;
 use crate::workspace::WorkspaceState;
+use crate::dialog::path_remap_dialog::PathRemapDialog;
+use crate::dialog::alias_edit_dialog::AliasEditDialog;
+use crate::dialog::quick_open_dialog::QuickOpenDialog;
+use crate::dialog::tab_switcher_dialog::{TabSwitcherDialog, TabSwitcherEntry};
+use crate::sql::session::{SharedSqlSession, SqlSession};
+use crate::dialog::confirm_dialog::{ConfirmDialog, ConfirmKind};
+use crate::recent_files::{RecentEntry, RecentFiles, RecentKind};
 use serde_json;
 use polars::prelude::IntoColumn;
 
@@ -91,6 +100,24 @@ pub struct DataTabManagerDialog {
     pub style_set_manager: StyleSetManager,
     pub style_set_manager_dialog: StyleSetManagerDialog,
     pub show_style_set_manager: bool,
+    /// Shown when a loaded workspace references data files that no longer exist
+    pub path_remap_dialog: Option<PathRemapDialog>,
+    /// Workspace state waiting for the missing files to be located
+    pub pending_workspace_state: Option<WorkspaceState>,
+    /// Renaming the active tab (sets the dataset alias)
+    pub rename_dialog: Option<AliasEditDialog>,
+    /// Where the tab bar was last drawn, for mouse hit testing
+    pub tab_bar_area: Rect,
+    /// Tab being dragged with the mouse
+    pub dragging_tab: Option<usize>,
+    /// Recent files and projects quick-open overlay
+    pub quick_open_dialog: Option<QuickOpenDialog>,
+    /// Fuzzy search over the open tabs
+    pub tab_switcher_dialog: Option<TabSwitcherDialog>,
+    /// SQL context shared by every tab, holding materialized views between queries
+    pub sql_session: SharedSqlSession,
+    /// Asks before closing tabs whose data or changes would be lost
+    pub confirm_dialog: Option<ConfirmDialog>,
 }
 
 impl DataTabManagerDialog {
@@ -113,6 +140,15 @@ impl DataTabManagerDialog {
             style_set_manager: StyleSetManager::new(),
             style_set_manager_dialog: StyleSetManagerDialog::new(StyleSetManager::new()),
             show_style_set_manager: false,
+            path_remap_dialog: None,
+            pending_workspace_state: None,
+            rename_dialog: None,
+            tab_bar_area: Rect::default(),
+            dragging_tab: None,
+            quick_open_dialog: None,
+            tab_switcher_dialog: None,
+            sql_session: SqlSession::shared(),
+            confirm_dialog: None,
         }
     }
     
@@ -121,17 +157,49 @@ impl DataTabManagerDialog {
         self.config.actions_to_instructions(&[
             (crate::config::Mode::Global, crate::action::Action::OpenKeybindings),
             (crate::config::Mode::DataTabManager, crate::action::Action::OpenDataManagementDialog),
+            (crate::config::Mode::DataTabManager, crate::action::Action::OpenQuickOpenDialog),
+            (crate::config::Mode::DataTabManager, crate::action::Action::OpenTabSwitcher),
             (crate::config::Mode::DataTabManager, crate::action::Action::OpenProjectSettingsDialog),
             (crate::config::Mode::DataTabManager, crate::action::Action::MoveTabToFront),
             (crate::config::Mode::DataTabManager, crate::action::Action::MoveTabToBack),
             (crate::config::Mode::DataTabManager, crate::action::Action::MoveTabLeft),
             (crate::config::Mode::DataTabManager, crate::action::Action::MoveTabRight),
+            (crate::config::Mode::DataTabManager, crate::action::Action::RenameTab),
+            (crate::config::Mode::DataTabManager, crate::action::Action::DuplicateTab),
+            (crate::config::Mode::DataTabManager, crate::action::Action::CloseTab),
+            (crate::config::Mode::DataTabManager, crate::action::Action::CloseOtherTabs),
+            (crate::config::Mode::DataTabManager, crate::action::Action::CloseTabsToRight),
             (crate::config::Mode::DataTabManager, crate::action::Action::PrevTab),
             (crate::config::Mode::DataTabManager, crate::action::Action::NextTab),
             (crate::config::Mode::DataTabManager, crate::action::Action::OpenStyleSetManagerDialog),
+            (crate::config::Mode::DataTabManager, crate::action::Action::ToggleIocHighlighting),
         ])
     }
 
+    /// Turn the built-in IOC Highlighting style set on or off, adding it on first use.
+    /// Returns whether it is now enabled.
+    pub fn toggle_ioc_highlighting(&mut self) -> bool {
+        let active_tab_id = self.tabs.get(self.active_tab_index).map(|tab| tab.id());
+        self.style_set_manager.set_active_tab(active_tab_id);
+        let existing = self
+            .style_set_manager
+            .get_all_sets()
+            .into_iter()
+            .find(|(_, set, _)| set.id == IOC_TEMPLATE_ID)
+            .map(|(identifier, _, enabled)| (identifier.clone(), enabled));
+        match existing {
+            Some((identifier, true)) => {
+                self.style_set_manager.disable_style_set(&identifier);
+                false
+            }
+            Some((identifier, false)) => self.style_set_manager.enable_style_set(&identifier),
+            None => {
+                let identifier = self.style_set_manager.add_set(create_template_styleset(TemplateCategory::Ioc));
+                self.style_set_manager.enable_style_set(&identifier)
+            }
+        }
+    }
+
     /// Save the current workspace state (data sources and dialog states) to the workspace folder
     pub fn save_workspace_state(&self) -> color_eyre::Result<()> {
         let Some(workspace_path) = self.project_settings_dialog.config.workspace_path.as_ref() else {
@@ -146,7 +214,8 @@ impl DataTabManagerDialog {
         let mut state = WorkspaceState::from_dialogs(self)?;
         for tab in &mut state.tabs {
             if let Some(container) = self.containers.get(&tab.dataset_id) {
-                if let Some(current) = container.datatable.dataframe.current_df.as_ref() {
+                if container.datatable.dataframe.has_view() {
+                    let current = container.datatable.get_dataframe()?;
                     let parquet_name = format!("{}.parquet", &tab.dataset_id);
                     let parquet_path = parquet_root.join(&parquet_name);
                     polars::prelude::ParquetWriter::new(File::create(&parquet_path)?)
@@ -155,6 +224,18 @@ impl DataTabManagerDialog {
                 } else {
                     tab.current_df_parquet = None;
                 }
+                for (i, checkpoint) in tab.checkpoints.iter_mut().enumerate() {
+                    checkpoint.parquet = None;
+                    if !checkpoint.store_parquet {
+                        continue;
+                    }
+                    if let Some(df) = checkpoint.dataframe.as_ref() {
+                        let parquet_name = format!("{}.checkpoint-{}.parquet", &tab.dataset_id, i);
+                        polars::prelude::ParquetWriter::new(File::create(parquet_root.join(&parquet_name))?)
+                            .finish(&mut df.as_ref().clone())?;
+                        checkpoint.parquet = Some(parquet_name);
+                    }
+                }
             }
         }
 
@@ -167,16 +248,43 @@ impl DataTabManagerDialog {
             return Ok(());
         };
         if let Some(state) = WorkspaceState::load_from(workspace_path)? {
-            state.apply_to(self)?;
-            // Ensure containers see latest datasets and refresh table scroll so next draw reflects state
-            let _ = self.update_all_containers_dataframes();
-            if let Some(container) = self.get_active_container() {
-                let _ = container.datatable.scroll_to_selection();
+            let missing = state.missing_source_paths();
+            if missing.is_empty() {
+                self.apply_workspace_state(state)?;
+            } else {
+                // Let the user locate moved files before the datasets are loaded
+                let mut dialog = PathRemapDialog::new(missing);
+                dialog.register_config_handler(self.config.clone())?;
+                self.path_remap_dialog = Some(dialog);
+                self.pending_workspace_state = Some(state);
             }
         }
         Ok(())
     }
 
+    fn apply_workspace_state(&mut self, state: WorkspaceState) -> color_eyre::Result<()> {
+        state.apply_to(self)?;
+        // Ensure containers see latest datasets and refresh table scroll so next draw reflects state
+        let _ = self.update_all_containers_dataframes();
+        if let Some(container) = self.get_active_container() {
+            let _ = container.datatable.scroll_to_selection();
+        }
+        Ok(())
+    }
+
+    /// Apply the located files (if any) to the pending workspace state and load it
+    fn finish_path_remap(&mut self, apply: bool) -> color_eyre::Result<()> {
+        let remaps = match self.path_remap_dialog.take() {
+            Some(dialog) if apply => dialog.remaps(),
+            _ => HashMap::new(),
+        };
+        if let Some(mut state) = self.pending_workspace_state.take() {
+            state.map_source_paths(|path| remaps.get(path).cloned().unwrap_or_else(|| path.to_path_buf()));
+            self.apply_workspace_state(state)?;
+        }
+        Ok(())
+    }
+
     /// Get all available DataFrames from the data management dialog
     pub fn get_available_datasets(&self) -> Result<HashMap<String, LoadedDataset>> {
         let mut available_datasets = HashMap::new();
@@ -191,6 +299,20 @@ impl DataTabManagerDialog {
 
     /// Handle creation of a new dataset from SQL query results
     pub fn handle_new_dataset_creation(&mut self, dataset_name: String, dataframe: Arc<DataFrame>) -> Result<Option<Action>> {
+        let file_path = format!("sql://{dataset_name}");
+        self.add_generated_dataset(dataset_name, dataframe, "SQL Generated", "SQL Query", file_path)?;
+        Ok(None)
+    }
+
+    /// Add a dataset created inside the app as its own data source and sync tabs; returns its id
+    fn add_generated_dataset(
+        &mut self,
+        dataset_name: String,
+        dataframe: Arc<DataFrame>,
+        source_name: &str,
+        import_type: &str,
+        file_path: String,
+    ) -> Result<String> {
         // Create a new dataset in the data management dialog
         let new_dataset = crate::dialog::data_management_dialog::Dataset {
             id: Uuid::new_v4().to_string(),
@@ -200,43 +322,49 @@ impl DataTabManagerDialog {
             column_count: dataframe.width(),
             status: crate::dialog::data_management_dialog::DatasetStatus::Imported,
             error_message: None,
+            notes: None,
         };
         
-        // Create a new data source for SQL-generated datasets
+        // Create a new data source for the generated dataset
         let sql_source_id = self.data_management_dialog.data_sources.len();
         let sql_data_source = crate::dialog::data_management_dialog::DataSource {
             id: sql_source_id,
-            name: "SQL Generated".to_string(),
-            file_path: format!("sql://{dataset_name}"),
-            import_type: "SQL Query".to_string(),
+            name: source_name.to_string(),
+            file_path: file_path.clone(),
+            import_type: import_type.to_string(),
             datasets: vec![new_dataset.clone()],
             total_datasets: 1,
             imported_datasets: 1,
             failed_datasets: 0,
             data_import_config: DataImportConfig::Text(crate::data_import_types::TextImportConfig {
-                file_path: std::path::PathBuf::from(format!("sql://{dataset_name}")),
+                file_path: std::path::PathBuf::from(file_path),
                 options: crate::dialog::csv_options_dialog::CsvImportOptions::default(),
                 additional_paths: Vec::new(),
                 merge: false,
             }),
+            categorical_strings: false,
+            file_hashes: Vec::new(),
         };
 
-        // Add the data source
+        // Add the data source; there is no file to load it from, so it lives in the cache
         self.data_management_dialog.data_sources.push(sql_data_source);
+        self.data_management_dialog.cache_dataframe(&new_dataset.id, dataframe);
         
         // Sync tabs to include the new dataset
         self.sync_tabs_from_data_management()?;
         
-        Ok(None)
+        Ok(new_dataset.id)
     }
 
     /// Sync tabs with loaded DataFrames from DataManagementDialog
     pub fn sync_tabs_from_data_management(&mut self) -> Result<()> {
         // Preserve existing containers temporarily so we can retain dialog state (e.g., SQL text)
         let old_containers = std::mem::take(&mut self.containers);
+        // Keep the current tab order and active tab across the rebuild
+        let previous_order = std::mem::take(&mut self.tab_order);
+        let previous_active = self.active_tab().map(|tab| tab.id());
         // Clear existing tabs and order
         self.tabs.clear();
-        self.tab_order.clear();
         
         // Get all available DataFrames for SQL context
         let available_datasets = self.get_available_datasets()?;
@@ -252,7 +380,9 @@ impl DataTabManagerDialog {
                 loaded_dataset.dataset.name.clone(),
                 Some(format!("From {}", loaded_dataset.data_source.name)),
                 Some(loaded_dataset.data_source.file_path.clone().into()),
-            );
+            )
+            .with_file_hashes(loaded_dataset.data_source.file_hashes.clone())
+            .with_notes(loaded_dataset.dataset.notes.clone());
             
             // Create DataTab
             let tab = DataTab::new(
@@ -263,11 +393,12 @@ impl DataTabManagerDialog {
             // Create DataTable and DataTableContainer for this tab
             let mut datatable = DataTable::new(managed_df, self.style.clone());
             // Set enabled style sets
-            let enabled_sets = self.style_set_manager.get_enabled_sets();
+            let enabled_sets = self.style_set_manager.get_enabled_sets_for_tab(&loaded_dataset.dataset.id);
             datatable.set_style_sets(enabled_sets.into_iter().cloned().collect());
             let mut container = DataTableContainer::new_with_dataframes(
                 datatable, self.style.clone(), available_datasets.clone()
             );
+            container.sql_session = self.sql_session.clone();
             // Register config with the new container
             let _ = container.register_config_handler(self.config.clone());
             // If we had an existing container for this dataset, carry over transient UI state
@@ -278,7 +409,14 @@ impl DataTabManagerDialog {
                     container.sql_dialog.set_textarea_content(prev_sql);
                 }
                 // Preserve datatable state (current_df, sort, filter, widths, last_sql)
-                container.datatable.dataframe.current_df = prev.datatable.dataframe.current_df.clone();
+                match (&prev.datatable.dataframe.current_df, &prev.datatable.dataframe.view) {
+                    (Some(df), _) => container.datatable.dataframe.set_current_arc(df.clone()),
+                    (None, Some(view)) => {
+                        container.datatable.dataframe.set_view(view.clone());
+                        container.datatable.dataframe.spill_files = prev.datatable.dataframe.spill_files.clone();
+                    }
+                    (None, None) => {}
+                }
                 container.datatable.dataframe.last_sort = prev.datatable.dataframe.last_sort.clone();
                 container.datatable.dataframe.filter = prev.datatable.dataframe.filter.clone();
                 // Ensure the FilterDialog reflects any existing filter
@@ -286,6 +424,8 @@ impl DataTabManagerDialog {
                     container.set_filter_expression(f);
                 }
                 container.datatable.dataframe.column_width_config = prev.datatable.dataframe.column_width_config.clone();
+                container.datatable.dataframe.heatmap_config = prev.datatable.dataframe.heatmap_config.clone();
+                container.datatable.dataframe.display_settings = prev.datatable.dataframe.display_settings.clone();
                 container.datatable.dataframe.last_sql_query = prev.datatable.dataframe.last_sql_query.clone();
                 // Preserve selection/scroll so view doesn't jump
                 container.datatable.selection = prev.datatable.selection;
@@ -312,17 +452,224 @@ impl DataTabManagerDialog {
             self.containers.insert(loaded_dataset.dataset.id.clone(), container);
         }
         
-        // Set the first tab as active if we have any tabs
-        if !self.tabs.is_empty() {
-            self.active_tab_index = 0;
-            if let Some(first_tab) = self.tabs.first_mut() {
-                first_tab.is_active = true;
+        // New tabs follow the existing ones, in Data Management order
+        let mut order = previous_order;
+        for (_, _, dataset) in self.data_management_dialog.get_all_datasets() {
+            if !order.contains(&dataset.id) {
+                order.push(dataset.id.clone());
             }
         }
+        self.active_tab_index = 0;
+        self.set_tab_order(&order);
+
+        // Keep the previously active tab if it still exists, otherwise the first tab
+        let active = previous_active.and_then(|id| self.get_tab_index_by_id(&id)).unwrap_or(0);
+        self.active_tab_index = active;
+        for (i, tab) in self.tabs.iter_mut().enumerate() {
+            tab.is_active = i == active;
+        }
         
         Ok(())
     }
 
+    /// Arrange tabs in the order of `ids`; tabs not listed keep their relative order at the end
+    pub fn set_tab_order(&mut self, ids: &[String]) {
+        let active_id = self.active_tab().map(|tab| tab.id());
+        self.tabs.sort_by_key(|tab| ids.iter().position(|id| *id == tab.id()).unwrap_or(usize::MAX));
+        self.tab_order = self.tabs.iter().map(|tab| tab.id()).collect();
+        if let Some(index) = active_id.and_then(|id| self.get_tab_index_by_id(&id)) {
+            self.active_tab_index = index;
+        }
+        for (i, tab) in self.tabs.iter_mut().enumerate() {
+            tab.is_active = i == self.active_tab_index;
+        }
+    }
+
+    /// Close tabs by dataset id; their datasets are removed from Data Management
+    pub fn close_tabs(&mut self, ids: &[String]) -> Result<()> {
+        for id in ids {
+            if let Some(index) = self.get_tab_index_by_id(id) {
+                self.remove_tab(index)?;
+            }
+            self.data_management_dialog.remove_dataset(id);
+        }
+        self.update_all_containers_dataframes()
+    }
+
+    /// Ids of the tabs a CloseTab, CloseOtherTabs or CloseTabsToRight action would close
+    fn tab_ids_to_close(&self, action: &Action) -> Vec<String> {
+        match action {
+            Action::CloseTab => self.active_tab().map(|tab| tab.id()).into_iter().collect(),
+            Action::CloseOtherTabs => self.tab_order.iter().enumerate()
+                .filter(|(i, _)| *i != self.active_tab_index)
+                .map(|(_, id)| id.clone())
+                .collect(),
+            Action::CloseTabsToRight => self.tab_order.iter().skip(self.active_tab_index + 1).cloned().collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Whether closing the tab loses work: generated data with no file behind it, or a view
+    /// with applied transforms or row tags
+    fn is_unsaved_tab(&self, id: &str) -> bool {
+        let generated = self.tabs.iter().any(|tab| {
+            tab.id() == id && crate::workspace::is_generated_path(std::path::Path::new(&tab.loaded_dataset.data_source.file_path))
+        });
+        let changed = self.containers.get(id).is_some_and(|c| {
+            !c.datatable.dataframe.history.is_empty() || !c.datatable.dataframe.row_tags.is_empty()
+        });
+        generated || changed
+    }
+
+    /// Close the tabs for a close action, asking first when any of them is unsaved
+    fn request_close_tabs(&mut self, action: Action) -> Result<()> {
+        let ids = self.tab_ids_to_close(&action);
+        let unsaved = ids.iter().filter(|id| self.is_unsaved_tab(id)).count();
+        if unsaved > 0 && self.config.needs_confirmation(ConfirmKind::CloseUnsavedTabs) {
+            let message = format!("{unsaved} of the {} tab(s) to close have generated data or unsaved changes. Close anyway?", ids.len());
+            let mut dialog = ConfirmDialog::new(ConfirmKind::CloseUnsavedTabs, message, action);
+            dialog.register_config_handler(self.config.clone())?;
+            self.confirm_dialog = Some(dialog);
+            return Ok(());
+        }
+        self.close_tabs(&ids)
+    }
+
+    /// Add the active tab's current view as a new dataset in a tab right after it
+    pub fn duplicate_active_tab(&mut self) -> Result<()> {
+        let Some(tab) = self.active_tab() else { return Ok(()) };
+        let (tab_id, name) = (tab.id(), format!("{} (copy)", tab.display_name()));
+        let Some(dataframe) = self.containers.get(&tab_id).and_then(|c| c.datatable.get_dataframe().ok()) else {
+            return Ok(());
+        };
+        let new_id = Uuid::new_v4().to_string();
+        let copy_id = self.add_generated_dataset(name, dataframe, "Tab Copy", "Tab Copy", format!("copy://{new_id}"))?;
+        if let (Some(from), Some(source)) = (self.get_tab_index_by_id(&copy_id), self.get_tab_index_by_id(&tab_id)) {
+            self.reorder_tab(from, source + 1)?;
+            self.switch_tab(source + 1)?;
+        }
+        self.update_all_containers_dataframes()
+    }
+
+    /// Open the recent files and projects dialog
+    fn open_quick_open_dialog(&mut self) -> Result<()> {
+        let mut dialog = QuickOpenDialog::new(RecentFiles::load().entries);
+        dialog.register_config_handler(self.config.clone())?;
+        self.quick_open_dialog = Some(dialog);
+        Ok(())
+    }
+
+    /// Open the tab switcher listing every tab with its current row and column counts
+    fn open_tab_switcher(&mut self) -> Result<()> {
+        let mut entries = Vec::with_capacity(self.tabs.len());
+        for tab in &self.tabs {
+            let (rows, columns) = match self.containers.get_mut(&tab.id()) {
+                Some(container) => {
+                    let dataframe = &mut container.datatable.dataframe;
+                    (dataframe.view_height().unwrap_or(0), dataframe.column_count())
+                }
+                None => (0, 0),
+            };
+            entries.push(TabSwitcherEntry {
+                title: tab.display_name(),
+                dataset: tab.loaded_dataset.dataset.name.clone(),
+                rows,
+                columns,
+            });
+        }
+        let mut dialog = TabSwitcherDialog::new(entries, self.active_tab_index);
+        dialog.register_config_handler(self.config.clone())?;
+        self.tab_switcher_dialog = Some(dialog);
+        Ok(())
+    }
+
+    /// Re-open a recent entry: data files are imported with their saved options, projects are loaded
+    fn open_recent(&mut self, entry: RecentEntry) -> Result<()> {
+        match entry.kind {
+            RecentKind::Import(config) => {
+                RecentFiles::remember_import(&config);
+                self.data_management_dialog.import_data_source(config)?;
+                // Show Data Management so progress is visible while the import runs
+                self.show_data_management = true;
+                self.data_management_dialog.begin_queued_import()?;
+            }
+            RecentKind::Project => {
+                RecentFiles::remember_project(&entry.path);
+                self.project_settings_dialog.config.workspace_path = Some(entry.path);
+                self.load_workspace_state()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Open the rename dialog for the active tab
+    fn open_rename_dialog(&mut self) -> Result<()> {
+        if let Some(tab) = self.active_tab() {
+            let dataset = &tab.loaded_dataset.dataset;
+            let mut dialog = AliasEditDialog::new(
+                tab.loaded_dataset.data_source.id,
+                dataset.id.clone(),
+                dataset.name.clone(),
+                dataset.alias.clone(),
+            )
+            .with_taken_names(self.data_management_dialog.table_names_except(&dataset.id));
+            dialog.register_config_handler(self.config.clone())?;
+            self.rename_dialog = Some(dialog);
+        }
+        Ok(())
+    }
+
+    /// Set a tab's alias in both the tab and Data Management so it is saved with the workspace
+    pub fn rename_tab(&mut self, source_id: usize, dataset_id: &str, alias: Option<String>) -> Result<()> {
+        self.data_management_dialog.update_dataset_alias(source_id, dataset_id, alias.clone());
+        if let Some(tab) = self.get_tab_by_id_mut(dataset_id) {
+            tab.loaded_dataset.dataset.alias = alias;
+        }
+        self.update_all_containers_dataframes()
+    }
+
+    /// First and one-past-last tab shown in a tab bar of `width` columns
+    fn visible_tab_range(&self, width: u16) -> (usize, usize) {
+        let total_tabs = self.tabs.len();
+        // Calculate actual tab width based on tab titles and divider
+        let total_tab_content_width: usize = self.tabs.iter().map(|tab| tab.display_name().len()).sum();
+        let total_divider_width = total_tabs.saturating_sub(1);
+        let total_required_width = total_tab_content_width + total_divider_width;
+        // Calculate estimated width per tab (with some padding for safety)
+        let estimated_tab_width = if total_tabs > 0 {
+            (total_required_width / total_tabs).max(1) + 2 // Add 2 for padding
+        } else {
+            15 // Fallback to original value if no tabs
+        };
+        let max_visible_tabs = width as usize / estimated_tab_width;
+        if max_visible_tabs >= total_tabs {
+            return (0, total_tabs);
+        }
+        // Show a subset of tabs around the active one
+        let start_index = if self.active_tab_index >= max_visible_tabs {
+            self.active_tab_index.saturating_sub(max_visible_tabs / 2)
+        } else {
+            0
+        };
+        (start_index, (start_index + max_visible_tabs).min(total_tabs))
+    }
+
+    /// Tab under terminal column `x` of the tab bar
+    fn tab_at_column(&self, x: u16) -> Option<usize> {
+        let area = self.tab_bar_area;
+        let (start, end) = self.visible_tab_range(area.width);
+        let mut left = area.x;
+        for index in start..end {
+            // Tabs pads each title with a space on both sides, then a one column divider
+            let width = Line::from(self.tabs[index].display_name()).width() as u16 + 2;
+            if x >= left && x < left + width {
+                return Some(index);
+            }
+            left += width + 1;
+        }
+        None
+    }
+
     /// Update all containers with the latest available dataframes
     pub fn update_all_containers_dataframes(&mut self) -> Result<()> {
         let latest = self.get_available_datasets()?;
@@ -562,6 +909,7 @@ impl DataTabManagerDialog {
             let block = Block::default()
                     .title("📊 DataTUI ")
                     .title_alignment(Alignment::Center)
+                    .title_bottom(self.memory_status_line())
                     .borders(Borders::ALL);
 
             // Calculate areas for tabs and content first
@@ -578,6 +926,7 @@ impl DataTabManagerDialog {
             };
 
             // Render tabs
+            self.tab_bar_area = Rect { height: tab_height, ..tab_area };
             self.render_tabs(tab_area, frame.buffer_mut());
             // Render content for active tab
             self.render_active_tab_content(frame, content_area, &instructions)?;
@@ -625,10 +974,51 @@ impl DataTabManagerDialog {
                 }
             }
 
+            // Render tab rename overlay if active
+            if let Some(dialog) = &self.rename_dialog {
+                let width = area.width.min(60);
+                let height = area.height.min(12);
+                let rename_area = Rect::new(
+                    area.x + (area.width - width) / 2,
+                    area.y + (area.height - height) / 2,
+                    width,
+                    height,
+                );
+                dialog.render(rename_area, frame.buffer_mut());
+            }
+
             Ok(())
         }
     }
 
+    /// Estimated memory held by loaded datasets and materialized views, counting shared frames once
+    pub fn memory_in_use(&self) -> usize {
+        let mut seen = std::collections::HashSet::new();
+        self.data_management_dialog
+            .dataframe_cache
+            .values()
+            .map(|cached| &cached.dataframe)
+            .chain(self.tabs.iter().map(|tab| &tab.loaded_dataset.dataframe))
+            .chain(self.containers.values().filter_map(|c| c.datatable.dataframe.current_df.as_ref()))
+            .filter(|df| seen.insert(Arc::as_ptr(df)))
+            .map(|df| df.estimated_size())
+            .sum()
+    }
+
+    /// Status bar text for the bottom border: memory in use and the soft limit, if set
+    fn memory_status_line(&self) -> Line<'static> {
+        let used = self.memory_in_use();
+        let (text, style) = match self.config.memory_soft_limit_mb {
+            Some(limit_mb) => {
+                let limit = limit_mb as usize * 1024 * 1024;
+                let style = if used > limit { Style::default().fg(Color::Red) } else { Style::default() };
+                (format!(" Memory: {} / {} ", format_bytes(used), format_bytes(limit)), style)
+            }
+            None => (format!(" Memory: {} ", format_bytes(used)), Style::default()),
+        };
+        Line::from(Span::styled(text, style)).right_aligned()
+    }
+
     /// Render the tab bar
     fn render_tabs(&self, area: Rect, buf: &mut Buffer) {
         let tab_area = Rect {
@@ -662,31 +1052,10 @@ impl DataTabManagerDialog {
         }).collect();
 
         // Calculate how many tabs can fit in the available width
-        let available_width = tab_area.width as usize;
         let total_tabs = self.tabs.len();
+        let (start_index, end_index) = self.visible_tab_range(tab_area.width);
         
-        // Calculate actual tab width based on tab titles and divider
-        let divider_width = 1; // Single space character " "
-        let total_tab_content_width: usize = tab_titles.iter()
-            .map(|title| title.len())
-            .sum();
-        let total_divider_width = if total_tabs > 1 { (total_tabs - 1) * divider_width } else { 0 };
-        let total_required_width = total_tab_content_width + total_divider_width;
-        
-        // Calculate estimated width per tab (with some padding for safety)
-        let estimated_tab_width = if total_tabs > 0 {
-            (total_required_width / total_tabs).max(1) + 2 // Add 2 for padding
-        } else {
-            15 // Fallback to original value if no tabs
-        };
-        
-        let max_visible_tabs = if estimated_tab_width > 0 {
-            available_width / estimated_tab_width
-        } else {
-            1
-        };
-        
-        if max_visible_tabs >= total_tabs {
+        if end_index - start_index >= total_tabs {
             // All tabs can fit, render normally
             let tabs_block = Block::default()
                 .borders(Borders::BOTTOM);
@@ -704,15 +1073,6 @@ impl DataTabManagerDialog {
 
             tabs.render(tab_area, buf);
         } else {
-            // Need to implement scrolling - show a subset of tabs around the active one
-            let start_index = if self.active_tab_index >= max_visible_tabs {
-                self.active_tab_index.saturating_sub(max_visible_tabs / 2)
-            } else {
-                0
-            };
-            
-            let end_index = (start_index + max_visible_tabs).min(total_tabs);
-            
             // Create visible tab titles
             let visible_titles: Vec<Line> = self.tabs.iter()
                 .enumerate()
@@ -792,7 +1152,7 @@ impl DataTabManagerDialog {
                 container.additional_instructions = Some(instructions.to_string());
 
                 // Sync style sets to datatable
-                let enabled_sets = self.style_set_manager.get_enabled_sets();
+                let enabled_sets = self.style_set_manager.get_enabled_sets_for_tab(&active_tab.id());
                 container.datatable.set_style_sets(enabled_sets.into_iter().cloned().collect());
 
                 // Render the container in the content area
@@ -801,6 +1161,10 @@ impl DataTabManagerDialog {
                     .config
                     .data_viewer
                     .auto_exapand_value_display;
+                container.datatable.cell_peek = self.project_settings_dialog
+                    .config
+                    .data_viewer
+                    .cell_peek;
                 container.draw(frame, area)?;
         }
 
@@ -891,6 +1255,10 @@ impl Component for DataTabManagerDialog {
     fn register_config_handler(&mut self, config: Config) -> Result<()> {
         self.project_settings_dialog.register_config_handler(config.clone())?;
         self.data_management_dialog.register_config_handler(config.clone())?;
+        self.style = config.style_config.clone();
+        for container in self.containers.values_mut() {
+            container.register_config_handler(config.clone())?;
+        }
         self.config = config;
         Ok(())
     }
@@ -900,15 +1268,78 @@ impl Component for DataTabManagerDialog {
     }
 
     fn handle_events(&mut self, event: Option<Event>) -> Result<Option<Action>> {
-        if let Some(Event::Key(key)) = event {
-            self.handle_key_event(key)
-        } else {
-            Ok(None)
+        match event {
+            Some(Event::Key(key)) => self.handle_key_event(key),
+            Some(Event::Mouse(mouse)) => self.handle_mouse_event(mouse),
+            _ => Ok(None),
         }
     }
 
     fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
         debug!("DataTabManagerDialog handle_key_event: {:?}", key);
+        if let Some(dialog) = &mut self.path_remap_dialog {
+            match dialog.handle_key_event(key) {
+                Some(Action::ApplyPathRemap) => self.finish_path_remap(true)?,
+                Some(Action::DialogClose) => self.finish_path_remap(false)?,
+                _ => {}
+            }
+            return Ok(None);
+        }
+        if let Some(dialog) = &mut self.confirm_dialog {
+            match dialog.handle_key_event(key) {
+                Some(Action::ConfirmDialogAccepted) => {
+                    let remembered = dialog.remember(&mut self.config);
+                    let pending = dialog.pending.clone();
+                    self.confirm_dialog = None;
+                    let ids = self.tab_ids_to_close(&pending);
+                    self.close_tabs(&ids)?;
+                    if let Err(e) = remembered {
+                        return Ok(Some(Action::Error(format!("Failed to save confirmation setting: {e}"))));
+                    }
+                }
+                Some(Action::DialogClose) => self.confirm_dialog = None,
+                _ => {}
+            }
+            return Ok(None);
+        }
+        if let Some(dialog) = &mut self.quick_open_dialog {
+            match dialog.handle_key_event(key) {
+                Some(Action::DialogClose) => self.quick_open_dialog = None,
+                Some(Action::QuickOpenRecent(index)) => {
+                    let entry = dialog.entries[index].clone();
+                    self.quick_open_dialog = None;
+                    self.open_recent(entry)?;
+                }
+                Some(Action::ForgetRecentEntry) => {
+                    let recent = RecentFiles { entries: dialog.entries.clone() };
+                    recent.save_to(&RecentFiles::default_path())?;
+                }
+                _ => {}
+            }
+            return Ok(None);
+        }
+        if let Some(dialog) = &mut self.tab_switcher_dialog {
+            match dialog.handle_key_event(key) {
+                Some(Action::DialogClose) => self.tab_switcher_dialog = None,
+                Some(Action::GoToTab(index)) => {
+                    self.tab_switcher_dialog = None;
+                    self.switch_tab(index)?;
+                }
+                _ => {}
+            }
+            return Ok(None);
+        }
+        if let Some(dialog) = &mut self.rename_dialog {
+            match dialog.handle_key_event(key)? {
+                Some(Action::DialogClose) => self.rename_dialog = None,
+                Some(Action::EditDatasetAlias { source_id, dataset_id, alias }) => {
+                    self.rename_dialog = None;
+                    self.rename_tab(source_id, &dataset_id, alias)?;
+                }
+                _ => {}
+            }
+            return Ok(None);
+        }
         if let Some(action) = self.config.action_for_key(
             crate::config::Mode::DataTabManager,
             key
@@ -924,6 +1355,9 @@ impl Component for DataTabManagerDialog {
                     return Ok(None);
                 }
                 Action::OpenStyleSetManagerDialog => {
+                    // Enabling and disabling acts on the active tab if it has its own style sets
+                    let active_tab_id = self.tabs.get(self.active_tab_index).map(|tab| tab.id());
+                    self.style_set_manager.set_active_tab(active_tab_id);
                     // Sync manager and register config
                     self.style_set_manager_dialog.sync_manager(&self.style_set_manager);
                     self.style_set_manager_dialog.register_config_handler(self.config.clone())?;
@@ -939,6 +1373,20 @@ impl Component for DataTabManagerDialog {
                     self.show_style_set_manager = true;
                     return Ok(None);
                 }
+                Action::ToggleIocHighlighting => {
+                    self.toggle_ioc_highlighting();
+                    return Ok(None);
+                }
+                Action::OpenQuickOpenDialog => {
+                    self.open_quick_open_dialog()?;
+                    return Ok(None);
+                }
+                Action::OpenTabSwitcher => {
+                    if !self.tabs.is_empty() {
+                        self.open_tab_switcher()?;
+                    }
+                    return Ok(None);
+                }
                 _ => {}
             }
         }
@@ -952,13 +1400,17 @@ impl Component for DataTabManagerDialog {
                     match action {
                         Action::DialogClose => {
                             self.show_project_settings = false;
+                            // Pick up theme and update check changes made in the dialog
+                            let config = self.project_settings_dialog.keybindings_config.clone();
+                            self.register_config_handler(config)?;
                             return Ok(None);
                         }
                         Action::ProjectSettingsApplied(_cfg) => {
                             // Apply and load workspace if valid
                             self.show_project_settings = false;
                             // If workspace path exists, try loading workspace state
-                            if self.project_settings_dialog.config.workspace_path.as_ref().is_some_and(|p| p.is_dir()) {
+                            if let Some(path) = self.project_settings_dialog.config.workspace_path.clone().filter(|p| p.is_dir()) {
+                                RecentFiles::remember_project(&path);
                                 let _ = self.load_workspace_state();
                             }
                             return Ok(result);
@@ -1057,8 +1509,16 @@ impl Component for DataTabManagerDialog {
                 }
             }
             let latest = self.get_available_datasets()?;
+            let saved_scripts = self.project_settings_dialog.config.scripts.clone();
+            let jmes_history = self.project_settings_dialog.config.jmes_history.clone();
+            let find_history = self.project_settings_dialog.config.find_history.clone();
+            let column_operation_presets = self.project_settings_dialog.config.column_operation_presets.clone();
             if let Some(container) = self.get_active_container() {
                 container.set_available_datasets(latest);
+                container.set_saved_scripts(saved_scripts);
+                container.set_jmes_history(jmes_history);
+                container.set_find_history(find_history);
+                container.set_column_operation_presets(column_operation_presets);
                 // Forward the key event to the active container
                 if let Some(action) = container.handle_key_event(key)? {
                     match action {
@@ -1069,6 +1529,56 @@ impl Component for DataTabManagerDialog {
                             // Handle new dataset creation
                             return self.handle_new_dataset_creation(dataset_name, dataframe);
                         }
+                        Action::ScriptSaved(script) => {
+                            let scripts = &mut self.project_settings_dialog.config.scripts;
+                            match scripts.iter_mut().find(|s| s.name == script.name) {
+                                Some(existing) => *existing = script,
+                                None => scripts.push(script),
+                            }
+                            if self.project_settings_dialog.config.workspace_path.as_ref().is_some_and(|p| p.is_dir()) {
+                                let _ = self.save_workspace_state();
+                            }
+                            return Ok(None);
+                        }
+                        Action::ScriptDeleted(name) => {
+                            self.project_settings_dialog.config.scripts.retain(|s| s.name != name);
+                            if self.project_settings_dialog.config.workspace_path.as_ref().is_some_and(|p| p.is_dir()) {
+                                let _ = self.save_workspace_state();
+                            }
+                            return Ok(None);
+                        }
+                        Action::ColumnOperationPresetSaved(preset) => {
+                            let presets = &mut self.project_settings_dialog.config.column_operation_presets;
+                            match presets.iter_mut().find(|p| p.name == preset.name) {
+                                Some(existing) => *existing = preset,
+                                None => presets.push(preset),
+                            }
+                            if self.project_settings_dialog.config.workspace_path.as_ref().is_some_and(|p| p.is_dir()) {
+                                let _ = self.save_workspace_state();
+                            }
+                            return Ok(None);
+                        }
+                        Action::ColumnOperationPresetDeleted(name) => {
+                            self.project_settings_dialog.config.column_operation_presets.retain(|p| p.name != name);
+                            if self.project_settings_dialog.config.workspace_path.as_ref().is_some_and(|p| p.is_dir()) {
+                                let _ = self.save_workspace_state();
+                            }
+                            return Ok(None);
+                        }
+                        Action::JmesHistoryChanged(history) => {
+                            self.project_settings_dialog.config.jmes_history = history;
+                            if self.project_settings_dialog.config.workspace_path.as_ref().is_some_and(|p| p.is_dir()) {
+                                let _ = self.save_workspace_state();
+                            }
+                            return Ok(None);
+                        }
+                        Action::FindHistoryChanged(history) => {
+                            self.project_settings_dialog.config.find_history = history;
+                            if self.project_settings_dialog.config.workspace_path.as_ref().is_some_and(|p| p.is_dir()) {
+                                let _ = self.save_workspace_state();
+                            }
+                            return Ok(None);
+                        }
                         Action::SaveWorkspaceState => {
                             // Ensure last SQL text is stored on the dataframe for capture
                             if let Some(active_tab) = self.tabs.get(self.active_tab_index) {
@@ -1142,6 +1652,20 @@ impl Component for DataTabManagerDialog {
                         }
                         return Ok(None);
                     }
+                    Action::RenameTab => {
+                        self.open_rename_dialog()?;
+                        return Ok(None);
+                    }
+                    Action::DuplicateTab => {
+                        if let Err(e) = self.duplicate_active_tab() {
+                            return Ok(Some(Action::Error(format!("Failed to duplicate tab: {e}"))));
+                        }
+                        return Ok(None);
+                    }
+                    Action::CloseTab | Action::CloseOtherTabs | Action::CloseTabsToRight => {
+                        self.request_close_tabs(action)?;
+                        return Ok(None);
+                    }
                     Action::SyncTabs => {
                         if let Err(e) = self.sync_tabs_from_data_management() {
                             return Ok(Some(Action::Error(format!("Failed to sync tabs: {e}"))));
@@ -1163,7 +1687,40 @@ impl Component for DataTabManagerDialog {
         Ok(None)
     }
 
-    fn handle_mouse_event(&mut self, _mouse: MouseEvent) -> Result<Option<Action>> {
+    fn handle_mouse_event(&mut self, mouse: MouseEvent) -> Result<Option<Action>> {
+        // Tabs can be clicked and dragged only while the tab view has no overlay
+        let overlay_open = self.show_data_management
+            || self.show_project_settings
+            || self.show_style_set_manager
+            || self.show_data_export_dialog
+            || self.rename_dialog.is_some()
+            || self.quick_open_dialog.is_some()
+            || self.tab_switcher_dialog.is_some()
+            || self.confirm_dialog.is_some()
+            || self.path_remap_dialog.is_some();
+        if overlay_open {
+            self.dragging_tab = None;
+            return Ok(None);
+        }
+        let on_tab_bar = mouse.row == self.tab_bar_area.y;
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) if on_tab_bar => {
+                if let Some(index) = self.tab_at_column(mouse.column) {
+                    self.switch_tab(index)?;
+                    self.dragging_tab = Some(index);
+                }
+            }
+            MouseEventKind::Drag(MouseButton::Left) => {
+                if let Some(from) = self.dragging_tab
+                    && let Some(to) = self.tab_at_column(mouse.column).filter(|to| on_tab_bar && *to != from)
+                {
+                    self.reorder_tab(from, to)?;
+                    self.dragging_tab = Some(to);
+                }
+            }
+            MouseEventKind::Up(MouseButton::Left) => self.dragging_tab = None,
+            _ => {}
+        }
         Ok(None)
     }
 
@@ -1185,7 +1742,39 @@ impl Component for DataTabManagerDialog {
     fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
         // First render the basic structure (tabs, instructions, etc.)
         self.render(frame, area)?;
-        
+        if let Some(dialog) = &self.quick_open_dialog {
+            let popup = Rect {
+                x: area.x + area.width / 10,
+                y: area.y + area.height / 10,
+                width: area.width - area.width / 5,
+                height: area.height - area.height / 5,
+            };
+            dialog.render(popup, frame.buffer_mut());
+        }
+        if let Some(dialog) = &self.tab_switcher_dialog {
+            let popup = Rect {
+                x: area.x + area.width / 6,
+                y: area.y + area.height / 8,
+                width: area.width - area.width / 3,
+                height: area.height - area.height / 4,
+            };
+            dialog.render(popup, frame.buffer_mut());
+        }
+        if let Some(dialog) = &self.path_remap_dialog {
+            let popup = Rect {
+                x: area.x + area.width / 10,
+                y: area.y + area.height / 10,
+                width: area.width - area.width / 5,
+                height: area.height - area.height / 5,
+            };
+            dialog.render(popup, frame.buffer_mut());
+        }
+        if let Some(dialog) = &self.confirm_dialog {
+            dialog.render(area, frame.buffer_mut());
+        }
+        if !self.config.use_truecolor() {
+            downgrade_buffer(frame.buffer_mut());
+        }
         Ok(())
     }
 }
@@ -1226,6 +1815,7 @@ mod tests {
             column_count: 2,
             status: DatasetStatus::Imported,
             error_message: None,
+            notes: None,
         };
         
         let s1 = Series::new("col1".into(), &[1, 2, 3]);
@@ -1236,6 +1826,7 @@ mod tests {
             data_source,
             dataset,
             dataframe: Arc::new(df),
+            generation: crate::dialog::data_management_dialog::next_generation(),
         }
     }
 
@@ -1504,4 +2095,49 @@ mod tests {
         assert_eq!(dialog.tabs[1].loaded_dataset.dataset.alias, Some("Customer Info".to_string()));
         assert_eq!(dialog.tabs[2].loaded_dataset.dataset.alias, Some("".to_string()));
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_toggle_ioc_highlighting() {
+        let mut dialog = DataTabManagerDialog::new(StyleConfig::default());
+        assert!(dialog.toggle_ioc_highlighting());
+        assert_eq!(dialog.style_set_manager.get_enabled_sets()[0].id, IOC_TEMPLATE_ID);
+        assert!(!dialog.toggle_ioc_highlighting());
+        assert!(dialog.style_set_manager.get_enabled_sets().is_empty());
+        // Toggling back on reuses the set added the first time
+        assert!(dialog.toggle_ioc_highlighting());
+        assert_eq!(dialog.style_set_manager.get_all_sets().len(), 1);
+    }
+
+    #[test]
+    fn test_tab_order_rename_duplicate_and_close() {
+        let mut dialog = DataTabManagerDialog::new(StyleConfig::default());
+        for name in ["a", "b", "c"] {
+            let df = Arc::new(create_test_dataframe().get_dataframe().unwrap().as_ref().clone());
+            dialog.handle_new_dataset_creation(name.to_string(), df).unwrap();
+        }
+        let names = |d: &DataTabManagerDialog| d.tabs.iter().map(|t| t.display_name()).collect::<Vec<_>>();
+        assert_eq!(names(&dialog), ["a", "b", "c"]);
+
+        // Order and the active tab survive a rebuild of the tabs
+        dialog.move_tab_to_front(2).unwrap();
+        dialog.switch_tab(1).unwrap();
+        dialog.sync_tabs_from_data_management().unwrap();
+        assert_eq!(names(&dialog), ["c", "a", "b"]);
+        assert_eq!(dialog.active_tab().unwrap().display_name(), "a");
+
+        let (source_id, id) = {
+            let tab = dialog.active_tab().unwrap();
+            (tab.loaded_dataset.data_source.id, tab.id())
+        };
+        dialog.rename_tab(source_id, &id, Some("alpha".to_string())).unwrap();
+        dialog.duplicate_active_tab().unwrap();
+        assert_eq!(names(&dialog), ["c", "alpha", "alpha (copy)", "b"]);
+        assert_eq!(dialog.active_tab_index, 2);
+
+        dialog.switch_tab(1).unwrap();
+        let right: Vec<String> = dialog.tab_order[2..].to_vec();
+        dialog.close_tabs(&right).unwrap();
+        assert_eq!(names(&dialog), ["c", "alpha"]);
+        assert_eq!(dialog.data_management_dialog.get_all_datasets().len(), 2);
+    }
+}
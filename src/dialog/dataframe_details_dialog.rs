@@ -10,6 +10,10 @@ use crossterm::event::{KeyEvent, KeyEventKind, KeyCode};
 use polars::prelude::*;
 use std::sync::Arc;
 use crate::dialog::table_export_dialog::TableExportDialog;
+use crate::dialog::schema_editor_dialog::SchemaEditorDialog;
+use crate::dataframe::categorical::{format_bytes, memory_usage};
+use crate::dataframe::column_stats::{column_stats, ColumnStats};
+use crate::dataframe::evidence::{FileHash, HashCheck};
 use crate::style::StyleConfig;
 use crate::dialog::filter_dialog::{ColumnFilter, FilterCondition};
 use serde::{Deserialize, Serialize};
@@ -20,8 +24,11 @@ pub enum DetailsTab {
     UniqueValues,
     Columns,
     Describe,
+    Statistics,
     Heatmap,
     Embeddings,
+    /// Source file hashes recorded at import
+    Evidence,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -49,6 +56,9 @@ pub struct DataFrameDetailsDialog {
     pub scroll_offset: usize,
     #[serde(skip)]
     unique_counts: Vec<(String, u64)>,
+    /// Sum of `unique_counts`, kept with it so percentages are not re-summed per value
+    #[serde(skip)]
+    unique_total: u64,
     #[serde(skip)]
     pub export_dialog: Option<TableExportDialog>,
     // Sorting state
@@ -59,6 +69,9 @@ pub struct DataFrameDetailsDialog {
     // Columns info for Columns tab
     #[serde(skip)]
     columns_info: Vec<(String, String)>,
+    // Estimated memory in bytes: (current, with Categorical columns as plain strings)
+    #[serde(skip)]
+    memory_usage: Option<(usize, usize)>,
     // Styles
     #[serde(skip)]
     style: StyleConfig,
@@ -69,6 +82,11 @@ pub struct DataFrameDetailsDialog {
     describe_col_offset: usize,
     // Maximum allowed horizontal scroll offset (computed during render based on visible width)
     describe_col_max_offset: usize,
+    // Statistics tab: computed per column on first view, cleared when the DataFrame changes
+    #[serde(skip)]
+    column_stats_cache: std::collections::HashMap<String, ColumnStats>,
+    #[serde(skip)]
+    column_stats_error: Option<String>,
     // Heatmap state
     heatmap_x_col_idx: usize,
     heatmap_y_col_idx: usize,
@@ -83,12 +101,23 @@ pub struct DataFrameDetailsDialog {
     cast_selected_idx: usize,
     #[serde(skip)]
     cast_error: Option<crate::dialog::error_dialog::ErrorDialog>,
+    // Schema editor (Columns tab): cast, rename and reorder several columns at once
+    #[serde(skip)]
+    pub schema_editor: Option<SchemaEditorDialog>,
     // Config
     #[serde(skip)]
     pub config: crate::config::Config,
     // Embeddings mapping for Embeddings tab
     #[serde(skip)]
     pub embedding_column_config_mapping: std::collections::HashMap<String, crate::components::datatable_container::EmbeddingColumnConfig>,
+    // Evidence tab: hashes recorded at import and the result of the last verify, per file
+    #[serde(skip)]
+    file_hashes: Vec<FileHash>,
+    #[serde(skip)]
+    evidence_checks: Vec<Option<HashCheck>>,
+    /// Analyst notes on the dataset, shown on the Evidence tab above the hashes
+    #[serde(skip)]
+    pub notes: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -107,6 +136,9 @@ impl Default for DataFrameDetailsDialog {
 }
 
 impl DataFrameDetailsDialog {
+    /// Tallest the notes box on the Evidence tab grows, borders included
+    const MAX_NOTES_HEIGHT: u16 = 8;
+
     pub fn new() -> Self {
         Self {
             df: None,
@@ -118,15 +150,19 @@ impl DataFrameDetailsDialog {
             selected_row: 0,
             scroll_offset: 0,
             unique_counts: Vec::new(),
+            unique_total: 0,
             export_dialog: None,
             sort_by: SortBy::Count,
             sort_choice_open: false,
             sort_choice_index: 1,
             columns_info: Vec::new(),
+            memory_usage: None,
             style: StyleConfig::default(),
             describe_rows: Vec::new(),
             describe_col_offset: 0,
             describe_col_max_offset: 5, // Default: assume all 6 stats columns, max offset = 5
+            column_stats_cache: std::collections::HashMap::new(),
+            column_stats_error: None,
             heatmap_x_col_idx: 0,
             heatmap_y_col_idx: 0,
             heatmap_cols: Vec::new(),
@@ -135,8 +171,12 @@ impl DataFrameDetailsDialog {
             cast_options: Vec::new(),
             cast_selected_idx: 0,
             cast_error: None,
+            schema_editor: None,
             config: crate::config::Config::default(),
             embedding_column_config_mapping: std::collections::HashMap::new(),
+            file_hashes: Vec::new(),
+            evidence_checks: Vec::new(),
+            notes: None,
         }
     }
 
@@ -171,6 +211,7 @@ impl DataFrameDetailsDialog {
                     (crate::config::Mode::DataFrameDetails, crate::action::Action::SwitchToPrevTab),
                     (crate::config::Mode::DataFrameDetails, crate::action::Action::SwitchToNextTab),
                     (crate::config::Mode::DataFrameDetails, crate::action::Action::OpenCastOverlay),
+                    (crate::config::Mode::DataFrameDetails, crate::action::Action::OpenSchemaEditor),
                     (crate::config::Mode::DataFrameDetails, crate::action::Action::ExportCurrentTab),
                     (crate::config::Mode::Global, crate::action::Action::CopyText),
                     (crate::config::Mode::Global, crate::action::Action::ToggleInstructions),
@@ -189,6 +230,18 @@ impl DataFrameDetailsDialog {
                     (crate::config::Mode::Global, crate::action::Action::Escape),
                 ])
             }
+            DetailsTab::Statistics => {
+                self.config.actions_to_instructions(&[
+                    (crate::config::Mode::DataFrameDetails, crate::action::Action::SwitchToPrevTab),
+                    (crate::config::Mode::DataFrameDetails, crate::action::Action::SwitchToNextTab),
+                    (crate::config::Mode::DataFrameDetails, crate::action::Action::ChangeColumnLeft),
+                    (crate::config::Mode::DataFrameDetails, crate::action::Action::ChangeColumnRight),
+                    (crate::config::Mode::DataFrameDetails, crate::action::Action::ExportCurrentTab),
+                    (crate::config::Mode::Global, crate::action::Action::CopyText),
+                    (crate::config::Mode::Global, crate::action::Action::ToggleInstructions),
+                    (crate::config::Mode::Global, crate::action::Action::Escape),
+                ])
+            }
             DetailsTab::Heatmap => {
                 self.config.actions_to_instructions(&[
                     (crate::config::Mode::DataFrameDetails, crate::action::Action::SwitchToPrevTab),
@@ -214,6 +267,16 @@ impl DataFrameDetailsDialog {
                     (crate::config::Mode::Global, crate::action::Action::Escape),
                 ])
             }
+            DetailsTab::Evidence => {
+                self.config.actions_to_instructions(&[
+                    (crate::config::Mode::DataFrameDetails, crate::action::Action::SwitchToPrevTab),
+                    (crate::config::Mode::DataFrameDetails, crate::action::Action::SwitchToNextTab),
+                    (crate::config::Mode::DataFrameDetails, crate::action::Action::VerifyEvidence),
+                    (crate::config::Mode::Global, crate::action::Action::CopyText),
+                    (crate::config::Mode::Global, crate::action::Action::ToggleInstructions),
+                    (crate::config::Mode::Global, crate::action::Action::Escape),
+                ])
+            }
         }
     }
 
@@ -222,6 +285,27 @@ impl DataFrameDetailsDialog {
         self.cast_error = None;
     }
 
+    /// Open the schema editor on the current data, starting at the selected column
+    fn open_schema_editor(&mut self) {
+        let Some(df) = &self.df else { return };
+        let mut editor = SchemaEditorDialog::new(df.clone());
+        let _ = editor.register_config_handler(self.config.clone());
+        if let Some((name, _)) = self.columns_info.get(self.selected_row) {
+            editor.selected = editor.edit.columns.iter().position(|c| &c.source == name).unwrap_or(0);
+        }
+        self.schema_editor = Some(editor);
+    }
+
+    pub fn close_schema_editor(&mut self) {
+        self.schema_editor = None;
+    }
+
+    pub fn set_schema_error(&mut self, message: impl Into<String>) {
+        if let Some(editor) = &mut self.schema_editor {
+            editor.set_error(message);
+        }
+    }
+
     pub fn set_columns(&mut self, columns: Vec<String>, current_index: usize) {
         self.columns = columns;
         self.selected_column_idx = current_index.min(self.columns.len().saturating_sub(1));
@@ -238,18 +322,56 @@ impl DataFrameDetailsDialog {
 
     pub fn set_dataframe(&mut self, df: Arc<DataFrame>) {
         self.df = Some(df);
+        self.column_stats_cache.clear();
+        self.column_stats_error = None;
         self.recompute_unique_counts();
         self.recompute_columns_info();
+        self.memory_usage = self.df.as_deref().and_then(|df| memory_usage(df).ok());
         self.recompute_describe();
         self.recompute_heatmap();
     }
 
+    /// Show the source file hashes of the dataset; earlier verify results are kept for the same files
+    pub fn set_file_hashes(&mut self, file_hashes: Vec<FileHash>) {
+        if file_hashes != self.file_hashes {
+            self.evidence_checks = vec![None; file_hashes.len()];
+            self.file_hashes = file_hashes;
+        }
+    }
+
+    /// Lines the notes box takes on the Evidence tab at `width`, borders included; 0 without notes
+    fn notes_height(&self, width: u16) -> u16 {
+        match &self.notes {
+            Some(notes) => (textwrap::wrap(notes, width.saturating_sub(2).max(1) as usize).len() as u16 + 2).min(Self::MAX_NOTES_HEIGHT),
+            None => 0,
+        }
+    }
+
+    /// Re-hash every recorded source file
+    pub fn verify_evidence(&mut self) {
+        self.evidence_checks = self.file_hashes.iter().map(|hash| Some(hash.verify())).collect();
+    }
+
+    /// Jump straight to the Unique Values tab with the table focused (value counts view)
+    pub fn show_value_counts(&mut self) {
+        self.tab = DetailsTab::UniqueValues;
+        self.focus = FocusField::Table;
+        self.selected_row = 0;
+        self.scroll_offset = 0;
+    }
+
+    /// Share of all rows taken by `count`, in percent
+    fn unique_percent(&self, count: u64) -> f64 {
+        if self.unique_total == 0 { 0.0 } else { count as f64 * 100.0 / self.unique_total as f64 }
+    }
+
     fn current_column_name(&self) -> Option<&str> {
         self.columns.get(self.selected_column_idx).map(|s| s.as_str())
     }
 
     fn recompute_unique_counts(&mut self) {
         self.unique_counts.clear();
+        self.unique_total = 0;
         let df = match &self.df { Some(df) => df, None => return };
         let Some(col_name) = self.current_column_name() else { return };
         // Compute unique values and counts by scanning the Series
@@ -274,6 +396,7 @@ impl DataFrameDetailsDialog {
                 pairs.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| b.1.cmp(&a.1)));
             }
         }
+        self.unique_total = pairs.iter().map(|(_, c)| *c).sum();
         self.unique_counts = pairs;
         self.selected_row = 0;
         self.scroll_offset = 0;
@@ -412,6 +535,30 @@ impl DataFrameDetailsDialog {
         self.scroll_offset = 0;
     }
 
+    /// Statistics for the selected column, computed on first request and cached
+    fn ensure_column_stats(&mut self) -> Option<&ColumnStats> {
+        let col_name = self.current_column_name()?.to_string();
+        if !self.column_stats_cache.contains_key(&col_name) {
+            let df = self.df.as_ref()?;
+            match column_stats(df, &col_name, 10) {
+                Ok(stats) => {
+                    self.column_stats_cache.insert(col_name.clone(), stats);
+                    self.column_stats_error = None;
+                }
+                Err(e) => {
+                    self.column_stats_error = Some(e.to_string());
+                    return None;
+                }
+            }
+        }
+        self.column_stats_cache.get(&col_name)
+    }
+
+    /// Statistics rows for the selected column (empty until computed)
+    fn column_stats_rows(&mut self) -> Vec<(String, String)> {
+        self.ensure_column_stats().map(|s| s.rows()).unwrap_or_default()
+    }
+
     fn recompute_heatmap(&mut self) {
         self.heatmap_cols.clear();
         self.heatmap_matrix.clear();
@@ -494,12 +641,17 @@ impl DataFrameDetailsDialog {
     }
 
     pub fn render(&mut self, area: Rect, buf: &mut Buffer) -> usize {
+        let palette = self.config.palette();
         Clear.render(area, buf);
         if let Some(export) = &self.export_dialog {
             // While export dialog is open, render it full-screen and short-circuit
             export.render(area, buf);
             return 0;
         }
+        if let Some(editor) = &self.schema_editor {
+            editor.render(area, buf);
+            return 0;
+        }
         // Outer container with double border around entire dialog
         let outer_block = Block::default()
             .title("DataFrame Details")
@@ -532,39 +684,75 @@ impl DataFrameDetailsDialog {
         let t1 = "[Unique Values]";
         let t2 = "[Columns]";
         let t3 = "[Describe]";
+        let t3b = "[Statistics]";
         let t4 = "[Heatmap]";
         let t5 = "[Embeddings]";
-        let t1_style = if matches!(self.tab, DetailsTab::UniqueValues) { Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD) } else { Style::default().fg(Color::DarkGray) };
-        let t2_style = if matches!(self.tab, DetailsTab::Columns) { Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD) } else { Style::default().fg(Color::DarkGray) };
+        let t1_style = if matches!(self.tab, DetailsTab::UniqueValues) { Style::default().fg(palette.header).add_modifier(Modifier::BOLD) } else { Style::default().fg(palette.hint) };
+        let t2_style = if matches!(self.tab, DetailsTab::Columns) { Style::default().fg(palette.header).add_modifier(Modifier::BOLD) } else { Style::default().fg(palette.hint) };
         buf.set_string(inner.x, header_y, t1, t1_style);
         let t2_x = inner.x + t1.len() as u16 + 2;
         buf.set_string(t2_x, header_y, t2, t2_style);
         let t3_x = t2_x + t2.len() as u16 + 2;
-        let t3_style = if matches!(self.tab, DetailsTab::Describe) { Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD) } else { Style::default().fg(Color::DarkGray) };
+        let t3_style = if matches!(self.tab, DetailsTab::Describe) { Style::default().fg(palette.header).add_modifier(Modifier::BOLD) } else { Style::default().fg(palette.hint) };
         buf.set_string(t3_x, header_y, t3, t3_style);
-        let t4_x = t3_x + t3.len() as u16 + 2;
-        let t4_style = if matches!(self.tab, DetailsTab::Heatmap) { Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD) } else { Style::default().fg(Color::DarkGray) };
+        let t3b_x = t3_x + t3.len() as u16 + 2;
+        let t3b_style = if matches!(self.tab, DetailsTab::Statistics) { Style::default().fg(palette.header).add_modifier(Modifier::BOLD) } else { Style::default().fg(palette.hint) };
+        buf.set_string(t3b_x, header_y, t3b, t3b_style);
+        let t4_x = t3b_x + t3b.len() as u16 + 2;
+        let t4_style = if matches!(self.tab, DetailsTab::Heatmap) { Style::default().fg(palette.header).add_modifier(Modifier::BOLD) } else { Style::default().fg(palette.hint) };
         buf.set_string(t4_x, header_y, t4, t4_style);
         let t5_x = t4_x + t4.len() as u16 + 2;
-        let t5_style = if matches!(self.tab, DetailsTab::Embeddings) { Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD) } else { Style::default().fg(Color::DarkGray) };
+        let t5_style = if matches!(self.tab, DetailsTab::Embeddings) { Style::default().fg(palette.header).add_modifier(Modifier::BOLD) } else { Style::default().fg(palette.hint) };
         buf.set_string(t5_x, header_y, t5, t5_style);
+        let t6 = "[Evidence]";
+        let t6_x = t5_x + t5.len() as u16 + 2;
+        let t6_style = if matches!(self.tab, DetailsTab::Evidence) { Style::default().fg(palette.header).add_modifier(Modifier::BOLD) } else { Style::default().fg(palette.hint) };
+        buf.set_string(t6_x, header_y, t6, t6_style);
 
         // Column dropdown line (UniqueValues) or axes line (Heatmap)
-        if matches!(self.tab, DetailsTab::UniqueValues) {
+        if matches!(self.tab, DetailsTab::Statistics) {
+            let col_label = format!("   Column: {}", self.current_column_name().unwrap_or("<none>"));
+            buf.set_string(inner.x, header_y + 1, col_label, Style::default().fg(palette.foreground).add_modifier(Modifier::BOLD));
+        } else if matches!(self.tab, DetailsTab::UniqueValues) {
             let col_label = format!(
                 "   Column: {}",
                 self.current_column_name().unwrap_or("<none>")
             );
             let mut col_style = Style::default();
             if matches!(self.focus, FocusField::ColumnDropdown) {
-                col_style = col_style.fg(Color::Black).bg(Color::Cyan).add_modifier(Modifier::BOLD);
+                col_style = col_style.fg(palette.selection_fg).bg(palette.accent).add_modifier(Modifier::BOLD);
             }
             buf.set_string(inner.x, header_y + 1, col_label, col_style);
+        } else if matches!(self.tab, DetailsTab::Columns) {
+            let memory_label = match self.memory_usage {
+                Some((current, plain)) if plain > current => format!(
+                    "   Memory: {} ({} as plain strings, {} saved by Categorical columns)",
+                    format_bytes(current),
+                    format_bytes(plain),
+                    format_bytes(plain - current)
+                ),
+                Some((current, _)) => format!("   Memory: {}", format_bytes(current)),
+                None => "   Memory: unknown".to_string(),
+            };
+            buf.set_string(inner.x, header_y + 1, memory_label, Style::default().fg(palette.foreground));
+        } else if matches!(self.tab, DetailsTab::Evidence) {
+            let mismatched = self.evidence_checks.iter().flatten().filter(|c| !matches!(c, HashCheck::Match)).count();
+            let verified = self.evidence_checks.iter().flatten().count();
+            let (label, style) = if self.file_hashes.is_empty() {
+                ("   No source file hashes were recorded for this dataset".to_string(), Style::default().fg(palette.hint))
+            } else if verified == 0 {
+                (format!("   {} source files, SHA-256 recorded at import (not verified yet)", self.file_hashes.len()), Style::default().fg(palette.foreground))
+            } else if mismatched > 0 {
+                (format!("   {mismatched} of {verified} source files FAILED verification"), Style::default().fg(palette.error).add_modifier(Modifier::BOLD))
+            } else {
+                (format!("   All {verified} source files match their recorded hashes"), Style::default().fg(palette.success).add_modifier(Modifier::BOLD))
+            };
+            buf.set_string(inner.x, header_y + 1, label, style);
         } else if matches!(self.tab, DetailsTab::Heatmap) {
             let x_name = self.heatmap_cols.get(self.heatmap_x_col_idx).cloned().unwrap_or_else(|| "<none>".to_string());
             let y_name = self.heatmap_cols.get(self.heatmap_y_col_idx).cloned().unwrap_or_else(|| "<none>".to_string());
             let axes_label = format!("   X: {x_name}   vs   Y: {y_name}");
-            let axes_style = Style::default().fg(Color::White).add_modifier(Modifier::BOLD);
+            let axes_style = Style::default().fg(palette.foreground).add_modifier(Modifier::BOLD);
             buf.set_string(inner.x, header_y + 1, axes_label, axes_style);
 			// Draw color legend on the next line (diverging palette for correlation [-1,1])
 			let legend_y = header_y + 2;
@@ -603,7 +791,16 @@ impl DataFrameDetailsDialog {
         }
 
         // Table area depends on tab (header height differs)
-        let header_height = if matches!(self.tab, DetailsTab::UniqueValues | DetailsTab::Heatmap) { 3 } else { 2 };
+        let mut header_height = if matches!(self.tab, DetailsTab::UniqueValues | DetailsTab::Columns | DetailsTab::Statistics | DetailsTab::Heatmap | DetailsTab::Evidence) { 3 } else { 2 };
+        if matches!(self.tab, DetailsTab::Evidence) && let Some(notes) = &self.notes {
+            let notes_height = self.notes_height(inner.width).min(inner.height.saturating_sub(header_height));
+            let notes_area = Rect { x: inner.x, y: inner.y + header_height, width: inner.width, height: notes_height };
+            Paragraph::new(notes.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Notes"))
+                .wrap(ratatui::widgets::Wrap { trim: false })
+                .render(notes_area, buf);
+            header_height += notes_height;
+        }
         let table_area = Rect {
             x: inner.x,
             y: inner.y + header_height,
@@ -616,14 +813,16 @@ impl DataFrameDetailsDialog {
             DetailsTab::UniqueValues => self.render_unique_values_table(table_area, buf, max_rows),
             DetailsTab::Columns => self.render_columns_table(table_area, buf, max_rows),
             DetailsTab::Describe => self.render_describe_table(table_area, buf, max_rows),
+            DetailsTab::Statistics => self.render_statistics_table(table_area, buf, max_rows),
             DetailsTab::Heatmap => self.render_heatmap(table_area, buf),
             DetailsTab::Embeddings => self.render_embeddings_columns(table_area, buf, max_rows),
+            DetailsTab::Evidence => self.render_evidence_table(table_area, buf, max_rows),
         }
 
         if let Some(instructions_area) = instructions_area {
             let p = Paragraph::new(instructions)
                 .block(Block::default().borders(Borders::ALL).title("Instructions"))
-                .style(Style::default().fg(Color::Yellow))
+                .style(Style::default().fg(palette.header))
                 .wrap(ratatui::widgets::Wrap { trim: true });
             p.render(instructions_area, buf);
         }
@@ -638,22 +837,22 @@ impl DataFrameDetailsDialog {
             // Fill background
             for y in sort_area.y..sort_area.y + sort_area.height {
                 let line = " ".repeat(sort_area.width as usize);
-                buf.set_string(sort_area.x, y, &line, Style::default().bg(Color::White));
+                buf.set_string(sort_area.x, y, &line, Style::default().bg(palette.cursor_bg));
             }
             let sort_block = Block::default()
                 .title("Sort Unique Values")
                 .borders(Borders::ALL)
                 .border_type(ratatui::widgets::BorderType::Double)
-                .border_style(Style::default().fg(Color::Black))
-                .style(Style::default().bg(Color::White));
+                .border_style(Style::default().fg(palette.cursor_fg))
+                .style(Style::default().bg(palette.cursor_bg));
             sort_block.render(sort_area, buf);
             // Content
             let start_x = sort_area.x + 2;
             let mut y = sort_area.y + 2;
             let sort_by_style = Style::default()
-                .fg(Color::Black)
+                .fg(palette.cursor_fg)
                 .add_modifier(Modifier::BOLD)
-                .bg(Color::White);
+                .bg(palette.cursor_bg);
             buf.set_string(start_x, y, "Sort by:", sort_by_style);
             y += 1;
             let options = ["Value", "Count"];
@@ -661,9 +860,9 @@ impl DataFrameDetailsDialog {
                 let selected = idx == self.sort_choice_index;
                 let radio = if selected { "(o)" } else { "( )" };
                 let style = if selected {
-                    Style::default().fg(Color::Black).bg(Color::Cyan).add_modifier(Modifier::BOLD)
+                    Style::default().fg(palette.selection_fg).bg(palette.accent).add_modifier(Modifier::BOLD)
                 } else {
-                    Style::default().fg(Color::Black).bg(Color::White)
+                    Style::default().fg(palette.cursor_fg).bg(palette.cursor_bg)
                 };
                 buf.set_string(start_x, y, radio, style);
                 buf.set_string(start_x + 4, y, *label, style);
@@ -671,7 +870,7 @@ impl DataFrameDetailsDialog {
             }
             // Footer hint
             let hint = "Enter: Apply  Esc: Cancel";
-            buf.set_string(start_x, sort_area.y + sort_area.height - 2, hint, Style::default().fg(Color::DarkGray).bg(Color::White));
+            buf.set_string(start_x, sort_area.y + sort_area.height - 2, hint, Style::default().fg(palette.hint).bg(palette.cursor_bg));
         }
         // Cast overlay
         if self.cast_overlay_open {
@@ -683,14 +882,14 @@ impl DataFrameDetailsDialog {
             let modal = Rect { x: block_x, y: block_y, width: block_width, height: block_height };
             for y in modal.y..modal.y + modal.height {
                 let line = " ".repeat(modal.width as usize);
-                buf.set_string(modal.x, y, &line, Style::default().bg(Color::Black));
+                buf.set_string(modal.x, y, &line, Style::default().bg(palette.background));
             }
             let block = Block::default()
                 .title("Cast Column Type")
                 .borders(Borders::ALL)
                 .border_type(ratatui::widgets::BorderType::Double)
-                .border_style(Style::default().fg(Color::White))
-                .style(Style::default().bg(Color::Black));
+                .border_style(Style::default().fg(palette.foreground))
+                .style(Style::default().bg(palette.background));
             let inner = block.inner(modal);
             block.render(modal, buf);
             // Header with current column/type
@@ -704,7 +903,7 @@ impl DataFrameDetailsDialog {
             } else { None };
             if let Some((name, dtype)) = cur {
                 let header = format!("{name} [{dtype}]");
-                buf.set_string(inner.x, inner.y, &header, Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+                buf.set_string(inner.x, inner.y, &header, Style::default().fg(palette.header).add_modifier(Modifier::BOLD));
             }
             // List options
             let list_start_y = inner.y + 2;
@@ -713,7 +912,7 @@ impl DataFrameDetailsDialog {
             let end_idx = self.cast_options.len();
             for (i, (label, _dt)) in self.cast_options[start_idx..end_idx].iter().enumerate() {
                 let selected = i == self.cast_selected_idx;
-                let style = if selected { Style::default().fg(Color::Black).bg(Color::Cyan) } else { Style::default().fg(Color::White) };
+                let style = if selected { Style::default().fg(palette.selection_fg).bg(palette.accent) } else { Style::default().fg(palette.foreground) };
                 if y < inner.y + inner.height.saturating_sub(2) {
                     buf.set_string(inner.x, y, label, style);
                 }
@@ -722,7 +921,7 @@ impl DataFrameDetailsDialog {
             // Footer
             let hint = "Enter: Apply  Esc: Cancel";
             if inner.height >= 2 {
-                buf.set_string(inner.x, inner.y + inner.height - 1, hint, Style::default().fg(Color::DarkGray));
+                buf.set_string(inner.x, inner.y + inner.height - 1, hint, Style::default().fg(palette.hint));
             }
             // Inline error overlay on top
             if let Some(err) = &self.cast_error {
@@ -733,6 +932,7 @@ impl DataFrameDetailsDialog {
     }
 
     fn render_unique_values_table(&self, area: Rect, buf: &mut Buffer, max_rows: usize) {
+        let palette = self.config.palette();
         // Scroll handling values
         let total_items = self.unique_counts.len();
         let start_idx = self.scroll_offset.min(total_items);
@@ -750,12 +950,12 @@ impl DataFrameDetailsDialog {
                 (self.scroll_offset * (visible_items - thumb_size)) / (total_items - visible_items)
             } else { 0 };
             for y in scroll_bar_y_start..scroll_bar_y_start + scroll_bar_height as u16 {
-                buf.set_string(scroll_bar_x, y, "│", Style::default().fg(Color::DarkGray));
+                buf.set_string(scroll_bar_x, y, "│", Style::default().fg(palette.hint));
             }
             let thumb_start = scroll_bar_y_start + thumb_position as u16;
             let thumb_end = (thumb_start + thumb_size as u16).min(scroll_bar_y_start + scroll_bar_height as u16);
             for y in thumb_start..thumb_end {
-                buf.set_string(scroll_bar_x, y, "█", Style::default().fg(Color::Cyan));
+                buf.set_string(scroll_bar_x, y, "█", Style::default().fg(palette.accent));
             }
         }
 
@@ -776,16 +976,18 @@ impl DataFrameDetailsDialog {
                 Row::new(vec![
                     Cell::from(value.to_string()).style(style),
                     Cell::from(format!("{count}")).style(style),
+                    Cell::from(format!("{:.2}%", self.unique_percent(*count))).style(style),
                 ])
             })
             .collect();
 
         let unique_total = self.unique_counts.len();
         let value_header = format!("Value [{unique_total}]");
-        let table = Table::new(rows, [Constraint::Percentage(80), Constraint::Percentage(20)])
+        let table = Table::new(rows, [Constraint::Percentage(66), Constraint::Percentage(17), Constraint::Percentage(17)])
             .header(Row::new(vec![
                 Cell::from(value_header).style(self.style.table_header),
                 Cell::from("Count").style(self.style.table_header),
+                Cell::from("Percent").style(self.style.table_header),
             ]))
             .column_spacing(1);
 
@@ -794,6 +996,7 @@ impl DataFrameDetailsDialog {
     }
 
     fn render_embeddings_columns(&self, area: Rect, buf: &mut Buffer, max_rows: usize) {
+        let palette = self.config.palette();
         let mut cols: Vec<(String, String, String, String)> = self
             .embedding_column_config_mapping
             .iter()
@@ -822,12 +1025,12 @@ impl DataFrameDetailsDialog {
                 (self.scroll_offset * (visible_items - thumb_size)) / (total_items - visible_items)
             } else { 0 };
             for y in scroll_bar_y_start..scroll_bar_y_start + scroll_bar_height as u16 {
-                buf.set_string(scroll_bar_x, y, "│", Style::default().fg(Color::DarkGray));
+                buf.set_string(scroll_bar_x, y, "│", Style::default().fg(palette.hint));
             }
             let thumb_start = scroll_bar_y_start + thumb_position as u16;
             let thumb_end = (thumb_start + thumb_size as u16).min(scroll_bar_y_start + scroll_bar_height as u16);
             for y in thumb_start..thumb_end {
-                buf.set_string(scroll_bar_x, y, "█", Style::default().fg(Color::Cyan));
+                buf.set_string(scroll_bar_x, y, "█", Style::default().fg(palette.accent));
             }
         }
 
@@ -869,7 +1072,62 @@ impl DataFrameDetailsDialog {
         ratatui::prelude::Widget::render(table, render_area, buf);
     }
 
+    fn render_evidence_table(&self, area: Rect, buf: &mut Buffer, max_rows: usize) {
+        let palette = self.config.palette();
+        let start_idx = self.scroll_offset.min(self.file_hashes.len());
+        let end_idx = (start_idx + max_rows).min(self.file_hashes.len());
+        let rows: Vec<Row> = self.file_hashes[start_idx..end_idx]
+            .iter()
+            .enumerate()
+            .map(|(i, hash)| {
+                let row_idx = start_idx + i;
+                let is_selected = matches!(self.focus, FocusField::Table) && row_idx == self.selected_row;
+                let style = if is_selected { self.style.selected_row } else if row_idx.is_multiple_of(2) { self.style.table_row_even } else { self.style.table_row_odd };
+                let (status, status_style) = match self.evidence_checks.get(row_idx).and_then(Option::as_ref) {
+                    None => ("Not verified".to_string(), style),
+                    Some(check) => {
+                        let detail = match check {
+                            HashCheck::Match => String::new(),
+                            HashCheck::Mismatch(current) => format!(" (now {})", &current[..current.len().min(16)]),
+                            HashCheck::Unreadable(e) => format!(": {e}"),
+                        };
+                        let color = if matches!(check, HashCheck::Match) { palette.success } else { palette.error };
+                        (format!("{}{detail}", check.label()), style.fg(color).add_modifier(Modifier::BOLD))
+                    }
+                };
+                Row::new(vec![
+                    Cell::from(hash.path.display().to_string()).style(style),
+                    Cell::from(format_bytes(hash.size as usize)).style(style),
+                    Cell::from(hash.sha256.clone()).style(style),
+                    Cell::from(hash.hashed_at.format("%Y-%m-%d %H:%M:%S").to_string()).style(style),
+                    Cell::from(status).style(status_style),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Percentage(25),
+                Constraint::Length(10),
+                Constraint::Length(64),
+                Constraint::Length(19),
+                Constraint::Min(12),
+            ],
+        )
+        .header(Row::new(vec![
+            Cell::from("File").style(self.style.table_header),
+            Cell::from("Size").style(self.style.table_header),
+            Cell::from("SHA-256").style(self.style.table_header),
+            Cell::from("Recorded").style(self.style.table_header),
+            Cell::from("Status").style(self.style.table_header),
+        ]))
+        .column_spacing(1);
+        ratatui::prelude::Widget::render(table, area, buf);
+    }
+
     fn render_columns_table(&self, area: Rect, buf: &mut Buffer, max_rows: usize) {
+        let palette = self.config.palette();
         let total_items = self.columns_info.len();
         let start_idx = self.scroll_offset.min(total_items);
         let end_idx = (start_idx + max_rows).min(total_items);
@@ -886,12 +1144,12 @@ impl DataFrameDetailsDialog {
                 (self.scroll_offset * (visible_items - thumb_size)) / (total_items - visible_items)
             } else { 0 };
             for y in scroll_bar_y_start..scroll_bar_y_start + scroll_bar_height as u16 {
-                buf.set_string(scroll_bar_x, y, "│", Style::default().fg(Color::DarkGray));
+                buf.set_string(scroll_bar_x, y, "│", Style::default().fg(palette.hint));
             }
             let thumb_start = scroll_bar_y_start + thumb_position as u16;
             let thumb_end = (thumb_start + thumb_size as u16).min(scroll_bar_y_start + scroll_bar_height as u16);
             for y in thumb_start..thumb_end {
-                buf.set_string(scroll_bar_x, y, "█", Style::default().fg(Color::Cyan));
+                buf.set_string(scroll_bar_x, y, "█", Style::default().fg(palette.accent));
             }
         }
 
@@ -928,6 +1186,7 @@ impl DataFrameDetailsDialog {
     }
 
     fn render_describe_table(&mut self, area: Rect, buf: &mut Buffer, max_rows: usize) {
+        let palette = self.config.palette();
         let total_items = self.describe_rows.len();
         let start_idx = self.scroll_offset.min(total_items);
         let end_idx = (start_idx + max_rows).min(total_items);
@@ -945,12 +1204,12 @@ impl DataFrameDetailsDialog {
                 (self.scroll_offset * (visible_items - thumb_size)) / (total_items - visible_items)
             } else { 0 };
             for y in scroll_bar_y_start..scroll_bar_y_start + scroll_bar_height as u16 {
-                buf.set_string(scroll_bar_x, y, "│", Style::default().fg(Color::DarkGray));
+                buf.set_string(scroll_bar_x, y, "│", Style::default().fg(palette.hint));
             }
             let thumb_start = scroll_bar_y_start + thumb_position as u16;
             let thumb_end = (thumb_start + thumb_size as u16).min(scroll_bar_y_start + scroll_bar_height as u16);
             for y in thumb_start..thumb_end {
-                buf.set_string(scroll_bar_x, y, "█", Style::default().fg(Color::Cyan));
+                buf.set_string(scroll_bar_x, y, "█", Style::default().fg(palette.accent));
             }
         }
 
@@ -1051,15 +1310,50 @@ impl DataFrameDetailsDialog {
             let track_len = table_width as usize;
             // Draw track
             let track_str = "─".repeat(track_len);
-            buf.set_string(track_x, track_y, &track_str, Style::default().fg(Color::DarkGray));
+            buf.set_string(track_x, track_y, &track_str, Style::default().fg(palette.hint));
             // Thumb
             let thumb_size = std::cmp::max(1, (track_len * visible_capacity) / total_stats);
             let thumb_pos = (self.describe_col_offset.min(offset_max) * (track_len.saturating_sub(thumb_size))) / offset_max;
             let thumb = "█".repeat(thumb_size);
-            buf.set_string(track_x + thumb_pos as u16, track_y, &thumb, Style::default().fg(Color::Cyan));
+            buf.set_string(track_x + thumb_pos as u16, track_y, &thumb, Style::default().fg(palette.accent));
         }
     }
 
+    fn render_statistics_table(&mut self, area: Rect, buf: &mut Buffer, max_rows: usize) {
+        let palette = self.config.palette();
+        let stats_rows = self.column_stats_rows();
+        if let Some(err) = &self.column_stats_error {
+            buf.set_string(area.x, area.y, format!("Error: {err}"), Style::default().fg(palette.error));
+            return;
+        }
+        let total_items = stats_rows.len();
+        let start_idx = self.scroll_offset.min(total_items);
+        let end_idx = (start_idx + max_rows).min(total_items);
+
+        let rows: Vec<Row> = stats_rows[start_idx..end_idx]
+            .iter()
+            .enumerate()
+            .map(|(i, (stat, value))| {
+                let row_idx = start_idx + i;
+                let is_selected = matches!(self.focus, FocusField::Table) && row_idx == self.selected_row;
+                let is_zebra = row_idx.is_multiple_of(2);
+                let style = if is_selected { self.style.selected_row } else if is_zebra { self.style.table_row_even } else { self.style.table_row_odd };
+                Row::new(vec![
+                    Cell::from(stat.to_string()).style(style),
+                    Cell::from(value.to_string()).style(style),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(rows, [Constraint::Length(12), Constraint::Min(10)])
+            .header(Row::new(vec![
+                Cell::from("Statistic").style(self.style.table_header),
+                Cell::from("Value").style(self.style.table_header),
+            ]))
+            .column_spacing(1);
+        ratatui::prelude::Widget::render(table, area, buf);
+    }
+
     fn render_heatmap(&self, area: Rect, buf: &mut Buffer) {
         // Guard: need a computed square correlation matrix
         if self.heatmap_cols.is_empty() || self.heatmap_matrix.is_empty() { return; }
@@ -1167,7 +1461,7 @@ impl DataFrameDetailsDialog {
     ///    - Toggle instructions visibility
     ///
     /// 3. **Tab Switching**
-    ///    - Navigate between UniqueValues, Columns, Describe, Heatmap, Embeddings
+    ///    - Navigate between UniqueValues, Columns, Describe, Statistics, Heatmap, Embeddings
     ///
     /// 4. **Tab-Specific Actions** (delegated to specialized handlers)
     ///    - Each tab has its own navigation and action handling
@@ -1196,12 +1490,23 @@ impl DataFrameDetailsDialog {
             return self.handle_export_dialog_input(key);
         }
 
-        // 1b. Sort choice overlay - value/count sorting selector
+        // 1b. Schema editor - batched cast/rename/reorder of columns
+        if let Some(editor) = &mut self.schema_editor {
+            return match editor.handle_key_event(key) {
+                Some(Action::DialogClose) => {
+                    self.schema_editor = None;
+                    None
+                }
+                other => other,
+            };
+        }
+
+        // 1c. Sort choice overlay - value/count sorting selector
         if self.sort_choice_open {
             return self.handle_sort_choice_input(key);
         }
 
-        // 1c. Cast overlay - column type casting selector
+        // 1d. Cast overlay - column type casting selector
         if self.cast_overlay_open {
             return self.handle_cast_overlay_input(key);
         }
@@ -1232,6 +1537,12 @@ impl DataFrameDetailsDialog {
                         DetailsTab::Describe => {
                             self.copy_describe();
                         }
+                        DetailsTab::Statistics => {
+                            self.copy_statistics();
+                        }
+                        DetailsTab::Evidence => {
+                            self.copy_evidence();
+                        }
                         _ => {}
                     }
                     return None;
@@ -1270,8 +1581,10 @@ impl DataFrameDetailsDialog {
             DetailsTab::UniqueValues => self.handle_unique_values_input(key, max_rows),
             DetailsTab::Columns => self.handle_columns_input(key, max_rows),
             DetailsTab::Describe => self.handle_describe_input(key, max_rows),
+            DetailsTab::Statistics => self.handle_statistics_input(key, max_rows),
             DetailsTab::Heatmap => self.handle_heatmap_input(key),
             DetailsTab::Embeddings => self.handle_embeddings_input(key, max_rows),
+            DetailsTab::Evidence => self.handle_evidence_input(key, max_rows),
         }
     }
 
@@ -1318,8 +1631,8 @@ impl DataFrameDetailsDialog {
     /// Allows selecting a target data type for column casting.
     fn handle_cast_overlay_input(&mut self, key: KeyEvent) -> Option<Action> {
         // If an error overlay is present, only allow dismissing it
-        if self.cast_error.is_some() {
-            if matches!(key.code, KeyCode::Enter | KeyCode::Esc) {
+        if let Some(err) = &mut self.cast_error {
+            if let Ok(Some(Action::DialogClose)) = crate::components::Component::handle_key_event(err, key) {
                 self.clear_cast_error();
             }
             return None;
@@ -1365,9 +1678,11 @@ impl DataFrameDetailsDialog {
         self.tab = match self.tab {
             DetailsTab::UniqueValues => DetailsTab::Columns,
             DetailsTab::Columns => DetailsTab::Describe,
-            DetailsTab::Describe => DetailsTab::Heatmap,
+            DetailsTab::Describe => DetailsTab::Statistics,
+            DetailsTab::Statistics => DetailsTab::Heatmap,
             DetailsTab::Heatmap => DetailsTab::Embeddings,
-            DetailsTab::Embeddings => DetailsTab::UniqueValues,
+            DetailsTab::Embeddings => DetailsTab::Evidence,
+            DetailsTab::Evidence => DetailsTab::UniqueValues,
         };
         self.reset_tab_state();
     }
@@ -1375,10 +1690,12 @@ impl DataFrameDetailsDialog {
     /// Switch to the previous tab in sequence.
     fn switch_to_prev_tab(&mut self) {
         self.tab = match self.tab {
-            DetailsTab::UniqueValues => DetailsTab::Embeddings,
+            DetailsTab::UniqueValues => DetailsTab::Evidence,
+            DetailsTab::Evidence => DetailsTab::Embeddings,
             DetailsTab::Columns => DetailsTab::UniqueValues,
             DetailsTab::Describe => DetailsTab::Columns,
-            DetailsTab::Heatmap => DetailsTab::Describe,
+            DetailsTab::Statistics => DetailsTab::Describe,
+            DetailsTab::Heatmap => DetailsTab::Statistics,
             DetailsTab::Embeddings => DetailsTab::Heatmap,
         };
         self.reset_tab_state();
@@ -1457,6 +1774,9 @@ impl DataFrameDetailsDialog {
                     self.navigate_page_down(list_len, max_rows);
                     return None;
                 }
+                Action::Enter if matches!(self.focus, FocusField::Table) => {
+                    return self.create_filter_from_selected_value();
+                }
                 Action::Tab => {
                     // Toggle between column dropdown and table focus
                     self.focus = match self.focus {
@@ -1484,6 +1804,10 @@ impl DataFrameDetailsDialog {
                     self.open_cast_overlay();
                     return None;
                 }
+                Action::OpenSchemaEditor => {
+                    self.open_schema_editor();
+                    return None;
+                }
                 Action::ExportCurrentTab => {
                     self.export_columns();
                     return None;
@@ -1543,6 +1867,45 @@ impl DataFrameDetailsDialog {
         self.handle_list_navigation(key, list_len, max_rows)
     }
 
+    /// Handle input for the Statistics tab.
+    /// Supports: vertical navigation, column switching, export.
+    fn handle_statistics_input(&mut self, key: KeyEvent, max_rows: usize) -> Option<Action> {
+        if let Some(action) = self.config.action_for_key(crate::config::Mode::DataFrameDetails, key) {
+            match action {
+                Action::ChangeColumnLeft => {
+                    self.navigate_stats_column(-1);
+                    return None;
+                }
+                Action::ChangeColumnRight => {
+                    self.navigate_stats_column(1);
+                    return None;
+                }
+                Action::ExportCurrentTab => {
+                    self.export_statistics();
+                    return None;
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(action) = self.config.action_for_key(crate::config::Mode::Global, key) {
+            match action {
+                Action::Left => {
+                    self.navigate_stats_column(-1);
+                    return None;
+                }
+                Action::Right => {
+                    self.navigate_stats_column(1);
+                    return None;
+                }
+                _ => {}
+            }
+        }
+
+        let list_len = self.column_stats_rows().len();
+        self.handle_list_navigation(key, list_len, max_rows)
+    }
+
     /// Handle input for the Heatmap tab.
     /// Supports: 2D grid navigation using X/Y column indices.
     fn handle_heatmap_input(&mut self, key: KeyEvent) -> Option<Action> {
@@ -1643,6 +2006,16 @@ impl DataFrameDetailsDialog {
         self.handle_list_navigation(key, list_len, max_rows)
     }
 
+    /// Handle input for the Evidence tab.
+    /// Supports: vertical navigation, re-hashing the source files.
+    fn handle_evidence_input(&mut self, key: KeyEvent, max_rows: usize) -> Option<Action> {
+        if let Some(Action::VerifyEvidence) = self.config.action_for_key(crate::config::Mode::DataFrameDetails, key) {
+            self.verify_evidence();
+            return None;
+        }
+        self.handle_list_navigation(key, self.file_hashes.len(), max_rows)
+    }
+
     // ═══════════════════════════════════════════════════════════════════════════
     // Navigation Helpers
     // ═══════════════════════════════════════════════════════════════════════════
@@ -1717,6 +2090,14 @@ impl DataFrameDetailsDialog {
         self.recompute_unique_counts();
     }
 
+    /// Navigate to adjacent column (Statistics tab); stats are computed on the next render.
+    fn navigate_stats_column(&mut self, direction: i32) {
+        self.navigate_column(direction);
+        self.column_stats_error = None;
+        self.selected_row = 0;
+        self.scroll_offset = 0;
+    }
+
     // ═══════════════════════════════════════════════════════════════════════════
     // Action Helpers
     // ═══════════════════════════════════════════════════════════════════════════
@@ -1770,10 +2151,10 @@ impl DataFrameDetailsDialog {
 
     /// Export unique values tab data.
     fn export_unique_values(&mut self) {
-        let headers = vec!["Value".to_string(), "Count".to_string()];
+        let headers = vec!["Value".to_string(), "Count".to_string(), "Percent".to_string()];
         let rows: Vec<Vec<String>> = self.unique_counts
             .iter()
-            .map(|(v, c)| vec![v.clone(), c.to_string()])
+            .map(|(v, c)| vec![v.clone(), c.to_string(), format!("{:.2}", self.unique_percent(*c))])
             .collect();
         let suggested = self.current_column_name()
             .map(|c| format!("unique_values_{c}.csv"))
@@ -1823,6 +2204,21 @@ impl DataFrameDetailsDialog {
         self.export_dialog = Some(dialog);
     }
 
+    /// Export statistics tab data for the selected column.
+    fn export_statistics(&mut self) {
+        let headers = vec!["Statistic".to_string(), "Value".to_string()];
+        let rows: Vec<Vec<String>> = self.column_stats_rows()
+            .into_iter()
+            .map(|(k, v)| vec![k, v])
+            .collect();
+        let suggested = self.current_column_name()
+            .map(|c| format!("statistics_{c}.csv"))
+            .or(Some("statistics.csv".to_string()));
+        let mut dialog = TableExportDialog::new(headers, rows, suggested);
+        let _ = dialog.register_config_handler(self.config.clone());
+        self.export_dialog = Some(dialog);
+    }
+
     /// Export embeddings tab data.
     fn export_embeddings(&mut self) {
         let mut cols: Vec<(String, String, String, String)> = self.embedding_column_config_mapping
@@ -1857,11 +2253,11 @@ impl DataFrameDetailsDialog {
 
     /// Copy unique values tab data to clipboard in tabular format.
     fn copy_unique_values(&self) {
-        let mut text = String::from("Value\tCount\n");
+        let mut text = String::from("Value\tCount\tPercent\n");
         for (value, count) in &self.unique_counts {
             // Escape tabs and newlines in values
             let escaped_value = value.replace('\t', " ").replace('\n', " ").replace('\r', " ");
-            text.push_str(&format!("{escaped_value}\t{count}\n"));
+            text.push_str(&format!("{escaped_value}\t{count}\t{:.2}\n", self.unique_percent(*count)));
         }
         if let Ok(mut clipboard) = Clipboard::new() {
             let _ = clipboard.set_text(text);
@@ -1900,6 +2296,30 @@ impl DataFrameDetailsDialog {
             let _ = clipboard.set_text(text);
         }
     }
+
+    /// Copy statistics tab data to clipboard in tabular format.
+    fn copy_statistics(&mut self) {
+        let mut text = String::from("Statistic\tValue\n");
+        for (stat, value) in self.column_stats_rows() {
+            let escaped_value = value.replace(['\t', '\n', '\r'], " ");
+            text.push_str(&format!("{stat}\t{escaped_value}\n"));
+        }
+        if let Ok(mut clipboard) = Clipboard::new() {
+            let _ = clipboard.set_text(text);
+        }
+    }
+
+    /// Copy the recorded hashes in `sha256sum` format
+    fn copy_evidence(&mut self) {
+        let text: String = self
+            .file_hashes
+            .iter()
+            .map(|hash| format!("{}  {}\n", hash.sha256, hash.path.display()))
+            .collect();
+        if let Ok(mut clipboard) = Clipboard::new() {
+            let _ = clipboard.set_text(text);
+        }
+    }
 }
 
 impl Component for DataFrameDetailsDialog {
@@ -1919,4 +2339,50 @@ impl Component for DataFrameDetailsDialog {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    #[test]
+    fn test_value_counts_percent_and_enter_filter() {
+        let df = df!("kind" => ["a", "b", "a", "a"]).unwrap();
+        let mut dialog = DataFrameDetailsDialog::new();
+        dialog.config.reset_keybindings_to_default();
+        dialog.set_columns(vec!["kind".to_string()], 0);
+        dialog.set_dataframe(Arc::new(df));
+        dialog.show_value_counts();
+
+        assert_eq!(dialog.unique_counts[0], ("a".to_string(), 3));
+        assert_eq!(dialog.unique_percent(3), 75.0);
+
+        let action = dialog.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE), 10);
+        match action {
+            Some(Action::AddFilterCondition(filter)) => {
+                assert_eq!(filter.column, "kind");
+                assert!(matches!(filter.condition, FilterCondition::Equals { ref value, .. } if value == "a"));
+            }
+            other => panic!("expected AddFilterCondition, got {other:?}"),
+        }
+    }
 
+    #[test]
+    fn test_statistics_tab_computes_lazily_per_column() {
+        let df = df!("n" => [1i64, 2, 3], "s" => ["x", "y", "x"]).unwrap();
+        let mut dialog = DataFrameDetailsDialog::new();
+        dialog.config.reset_keybindings_to_default();
+        dialog.set_columns(vec!["n".to_string(), "s".to_string()], 0);
+        dialog.set_dataframe(Arc::new(df));
+        assert!(dialog.column_stats_cache.is_empty());
+
+        dialog.tab = DetailsTab::Statistics;
+        let rows = dialog.column_stats_rows();
+        assert!(rows.contains(&("mean".to_string(), "2.000000".to_string())));
+        assert_eq!(dialog.column_stats_cache.len(), 1);
+
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Right, KeyModifiers::NONE), 10);
+        let rows = dialog.column_stats_rows();
+        assert!(rows.contains(&("top 1".to_string(), "x (2)".to_string())));
+        assert_eq!(dialog.column_stats_cache.len(), 2);
+    }
+}
@@ -0,0 +1,138 @@
+//! DatasetNotesDialog: free-text notes on a dataset, such as where it came from and what was done to it
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Clear, Paragraph, Wrap};
+use tui_textarea::TextArea;
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+
+/// DatasetNotesDialog: returns EditDatasetNotes with the edited notes, None when left blank
+#[derive(Debug)]
+pub struct DatasetNotesDialog {
+    pub source_id: usize,
+    pub dataset_id: String,
+    pub dataset_name: String,
+    pub input: TextArea<'static>,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+impl DatasetNotesDialog {
+    pub fn new(source_id: usize, dataset_id: String, dataset_name: String, notes: Option<String>) -> Self {
+        let mut input = TextArea::from(notes.unwrap_or_default().lines().map(str::to_string).collect::<Vec<_>>());
+        input.set_block(Block::default());
+        input.set_placeholder_text("Where the data came from, what was done to it...");
+        input.move_cursor(tui_textarea::CursorMove::Bottom);
+        input.move_cursor(tui_textarea::CursorMove::End);
+        Self { source_id, dataset_id, dataset_name, input, show_instructions: true, config: Config::default() }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    /// Notes as typed, with surrounding blank space removed; None when empty
+    pub fn notes(&self) -> Option<String> {
+        let notes = self.input.lines().join("\n").trim().to_string();
+        (!notes.is_empty()).then_some(notes)
+    }
+
+    fn build_instructions_from_config(&self) -> String {
+        format!(
+            "Alt+Enter: New line  {}",
+            self.config.actions_to_instructions(&[
+                (Mode::Global, Action::Enter),
+                (Mode::Global, Action::Escape),
+                (Mode::Global, Action::ToggleInstructions),
+            ])
+        )
+    }
+
+    /// Render the dialog
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title("Notes")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let block = Block::default()
+            .title(format!("Notes for: {}", self.dataset_name))
+            .borders(Borders::ALL);
+        let inner = block.inner(layout.content_area);
+        block.render(layout.content_area, buf);
+        self.input.render(inner, buf);
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Handle keyboard events; Enter saves, Alt+Enter starts a new line
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        if key.code == KeyCode::Enter && key.modifiers.contains(KeyModifiers::ALT) {
+            self.input.insert_newline();
+            return None;
+        }
+        match self.config.action_for_key(Mode::Global, key) {
+            Some(Action::Escape) => Some(Action::DialogClose),
+            Some(Action::Enter) => Some(Action::EditDatasetNotes {
+                source_id: self.source_id,
+                dataset_id: self.dataset_id.clone(),
+                notes: self.notes(),
+            }),
+            Some(Action::ToggleInstructions) => {
+                self.show_instructions = !self.show_instructions;
+                None
+            }
+            _ => {
+                self.input.input(tui_textarea::Input::from(key));
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_notes_over_several_lines() {
+        let mut dialog = DatasetNotesDialog::new(3, "ds".to_string(), "auth.log".to_string(), Some("Pulled from web01".to_string()));
+        dialog.config.reset_keybindings_to_default();
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::ALT));
+        for c in "filtered ".chars() {
+            dialog.handle_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        assert_eq!(
+            dialog.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)),
+            Some(Action::EditDatasetNotes { source_id: 3, dataset_id: "ds".to_string(), notes: Some("Pulled from web01\nfiltered".to_string()) })
+        );
+
+        let mut dialog = DatasetNotesDialog::new(3, "ds".to_string(), "auth.log".to_string(), None);
+        dialog.config.reset_keybindings_to_default();
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE));
+        assert_eq!(
+            dialog.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)),
+            Some(Action::EditDatasetNotes { source_id: 3, dataset_id: "ds".to_string(), notes: None })
+        );
+        assert_eq!(dialog.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)), Some(Action::DialogClose));
+    }
+}
@@ -0,0 +1,639 @@
+//! DisplaySettingsDialog: Per-column (and table default) display formatting applied at render time
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Local, Utc};
+use chrono_tz::Tz;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use polars::prelude::{AnyValue, TimeUnit};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Clear, Paragraph, Wrap};
+use serde::{Deserialize, Serialize};
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+
+/// Default datetime format used when only a timezone is configured
+pub const DEFAULT_DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.f";
+
+/// Display formatting options. Unset fields fall back to the table default, then to the raw value.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColumnDisplayFormat {
+    /// chrono strftime format for Date/Datetime values
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub datetime_format: Option<String>,
+    /// Timezone to display datetimes in: "UTC", "Local", or an IANA name (e.g. "Europe/Berlin")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// Text shown in place of null values
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub null_placeholder: Option<String>,
+    /// Separator inserted between groups of thousands in numeric values (e.g. ",")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thousands_separator: Option<String>,
+    /// Number of decimal places shown for floating point values
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub float_precision: Option<usize>,
+}
+
+impl ColumnDisplayFormat {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Overlay `self` on top of `base`, taking each field from `self` when set
+    fn or(&self, base: &ColumnDisplayFormat) -> ColumnDisplayFormat {
+        ColumnDisplayFormat {
+            datetime_format: self.datetime_format.clone().or_else(|| base.datetime_format.clone()),
+            timezone: self.timezone.clone().or_else(|| base.timezone.clone()),
+            null_placeholder: self.null_placeholder.clone().or_else(|| base.null_placeholder.clone()),
+            thousands_separator: self.thousands_separator.clone().or_else(|| base.thousands_separator.clone()),
+            float_precision: self.float_precision.or(base.float_precision),
+        }
+    }
+}
+
+/// Validate a timezone name as accepted by [`ColumnDisplayFormat::timezone`]
+pub fn validate_timezone(name: &str) -> Result<(), String> {
+    if name.eq_ignore_ascii_case("utc") || name.eq_ignore_ascii_case("local") || name.parse::<Tz>().is_ok() {
+        Ok(())
+    } else {
+        Err(format!("Unknown timezone: {name}"))
+    }
+}
+
+/// Validate a strftime format as accepted by [`ColumnDisplayFormat::datetime_format`]
+pub fn validate_datetime_format(format: &str) -> Result<(), String> {
+    if chrono::format::StrftimeItems::new(format).any(|i| matches!(i, chrono::format::Item::Error)) {
+        Err(format!("Invalid datetime format: {format}"))
+    } else {
+        Ok(())
+    }
+}
+
+/// Insert `sep` between groups of three digits in the integer part of a plain decimal string.
+///
+/// Strings that are not plain decimals (e.g. scientific notation, inf/NaN) are returned unchanged.
+pub fn group_thousands(number: &str, sep: &str) -> String {
+    let (sign, rest) = match number.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", number),
+    };
+    let (int_part, frac_part) = match rest.split_once('.') {
+        Some((i, f)) => (i, Some(f)),
+        None => (rest, None),
+    };
+    if int_part.is_empty()
+        || !int_part.chars().all(|c| c.is_ascii_digit())
+        || !frac_part.is_none_or(|f| f.chars().all(|c| c.is_ascii_digit()))
+    {
+        return number.to_string();
+    }
+    let mut grouped = String::with_capacity(int_part.len() + int_part.len() / 3 * sep.len());
+    for (i, c) in int_part.chars().enumerate() {
+        if i > 0 && (int_part.len() - i).is_multiple_of(3) {
+            grouped.push_str(sep);
+        }
+        grouped.push(c);
+    }
+    match frac_part {
+        Some(f) => format!("{sign}{grouped}.{f}"),
+        None => format!("{sign}{grouped}"),
+    }
+}
+
+fn format_integer(value: impl std::fmt::Display, fmt: &ColumnDisplayFormat) -> String {
+    let raw = value.to_string();
+    match fmt.thousands_separator.as_deref() {
+        Some(sep) => group_thousands(&raw, sep),
+        None => raw,
+    }
+}
+
+fn format_float(value: f64, fmt: &ColumnDisplayFormat) -> String {
+    let raw = match fmt.float_precision {
+        Some(precision) => format!("{value:.precision$}"),
+        None => format!("{value}"),
+    };
+    match fmt.thousands_separator.as_deref() {
+        Some(sep) => group_thousands(&raw, sep),
+        None => raw,
+    }
+}
+
+fn datetime_from_timestamp(ts: i64, unit: TimeUnit) -> Option<DateTime<Utc>> {
+    match unit {
+        TimeUnit::Nanoseconds => Some(DateTime::<Utc>::from_timestamp_nanos(ts)),
+        TimeUnit::Microseconds => DateTime::<Utc>::from_timestamp_micros(ts),
+        TimeUnit::Milliseconds => DateTime::<Utc>::from_timestamp_millis(ts),
+    }
+}
+
+/// Write `formatted` into a String. chrono's `to_string` panics when a specifier cannot be
+/// formatted (e.g. `%H` on a date), so the error is returned instead.
+fn write_formatted(formatted: impl std::fmt::Display) -> Option<String> {
+    use std::fmt::Write;
+    let mut out = String::new();
+    write!(out, "{formatted}").ok()?;
+    Some(out)
+}
+
+fn format_datetime(dt: DateTime<Utc>, format: &str, timezone: Option<&str>) -> Option<String> {
+    match timezone {
+        Some(tz) if tz.eq_ignore_ascii_case("local") => write_formatted(dt.with_timezone(&Local).format(format)),
+        Some(tz) if tz.eq_ignore_ascii_case("utc") => write_formatted(dt.format(format)),
+        Some(tz) => match tz.parse::<Tz>() {
+            Ok(tz) => write_formatted(dt.with_timezone(&tz).format(format)),
+            Err(_) => write_formatted(dt.format(format)),
+        },
+        None => write_formatted(dt.format(format)),
+    }
+}
+
+/// Display settings for a table: a default applying to all columns plus per-column overrides
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DisplaySettings {
+    #[serde(default)]
+    pub default: ColumnDisplayFormat,
+    #[serde(default)]
+    pub columns: HashMap<String, ColumnDisplayFormat>,
+}
+
+impl DisplaySettings {
+    /// Effective format for a column (column overrides layered on the default)
+    pub fn effective_format(&self, column: &str) -> ColumnDisplayFormat {
+        match self.columns.get(column) {
+            Some(fmt) => fmt.or(&self.default),
+            None => self.default.clone(),
+        }
+    }
+
+    /// Format a value for display in `column` without altering the underlying data
+    pub fn format_value(&self, column: &str, value: &AnyValue) -> String {
+        let fmt = self.effective_format(column);
+        match value {
+            AnyValue::Null => fmt.null_placeholder.unwrap_or_default(),
+            AnyValue::String(s) => s.to_string(),
+            AnyValue::Int8(v) => format_integer(v, &fmt),
+            AnyValue::Int16(v) => format_integer(v, &fmt),
+            AnyValue::Int32(v) => format_integer(v, &fmt),
+            AnyValue::Int64(v) => format_integer(v, &fmt),
+            AnyValue::UInt8(v) => format_integer(v, &fmt),
+            AnyValue::UInt16(v) => format_integer(v, &fmt),
+            AnyValue::UInt32(v) => format_integer(v, &fmt),
+            AnyValue::UInt64(v) => format_integer(v, &fmt),
+            AnyValue::Float32(v) if fmt.float_precision.is_some() || fmt.thousands_separator.is_some() => {
+                format_float(*v as f64, &fmt)
+            }
+            AnyValue::Float64(v) if fmt.float_precision.is_some() || fmt.thousands_separator.is_some() => {
+                format_float(*v, &fmt)
+            }
+            AnyValue::Datetime(ts, unit, _) | AnyValue::DatetimeOwned(ts, unit, _)
+                if fmt.datetime_format.is_some() || fmt.timezone.is_some() =>
+            {
+                let format = fmt.datetime_format.as_deref().unwrap_or(DEFAULT_DATETIME_FORMAT);
+                datetime_from_timestamp(*ts, *unit)
+                    .and_then(|dt| format_datetime(dt, format, fmt.timezone.as_deref()))
+                    .unwrap_or_else(|| format!("{value}"))
+            }
+            AnyValue::Date(days) if fmt.datetime_format.is_some() => {
+                let format = fmt.datetime_format.as_deref().unwrap_or(DEFAULT_DATETIME_FORMAT);
+                DateTime::<Utc>::from_timestamp(*days as i64 * 86_400, 0)
+                    .and_then(|dt| write_formatted(dt.date_naive().format(format)))
+                    .unwrap_or_else(|| format!("{value}"))
+            }
+            AnyValue::Categorical(..) | AnyValue::CategoricalOwned(..) => value.str_value().to_string(),
+            other => format!("{other}"),
+        }
+    }
+}
+
+/// Editable fields in the DisplaySettingsDialog
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisplaySettingsField {
+    DatetimeFormat,
+    Timezone,
+    NullPlaceholder,
+    ThousandsSeparator,
+    FloatPrecision,
+}
+
+impl DisplaySettingsField {
+    pub const ALL: [DisplaySettingsField; 5] = [
+        DisplaySettingsField::DatetimeFormat,
+        DisplaySettingsField::Timezone,
+        DisplaySettingsField::NullPlaceholder,
+        DisplaySettingsField::ThousandsSeparator,
+        DisplaySettingsField::FloatPrecision,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DisplaySettingsField::DatetimeFormat => "Datetime format",
+            DisplaySettingsField::Timezone => "Timezone",
+            DisplaySettingsField::NullPlaceholder => "Null placeholder",
+            DisplaySettingsField::ThousandsSeparator => "Thousands sep.",
+            DisplaySettingsField::FloatPrecision => "Float precision",
+        }
+    }
+}
+
+/// Which pane of the dialog has focus
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisplaySettingsFocus {
+    Targets,
+    Fields,
+}
+
+/// DisplaySettingsDialog: edit display formatting for the table default and individual columns
+#[derive(Debug)]
+pub struct DisplaySettingsDialog {
+    pub settings: DisplaySettings,
+    pub columns: Vec<String>,
+    /// 0 = table default, n = columns[n - 1]
+    pub selected_target: usize,
+    pub selected_field: usize,
+    pub focus: DisplaySettingsFocus,
+    pub error: Option<String>,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+impl DisplaySettingsDialog {
+    pub fn new(settings: DisplaySettings, columns: Vec<String>, current_column: Option<usize>) -> Self {
+        Self {
+            settings,
+            selected_target: current_column.map(|i| (i + 1).min(columns.len())).unwrap_or(0),
+            columns,
+            selected_field: 0,
+            focus: DisplaySettingsFocus::Fields,
+            error: None,
+            show_instructions: true,
+            config: Config::default(),
+        }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn target_label(&self, idx: usize) -> String {
+        if idx == 0 {
+            "(All columns)".to_string()
+        } else {
+            self.columns.get(idx - 1).cloned().unwrap_or_default()
+        }
+    }
+
+    fn target_format(&self) -> Option<&ColumnDisplayFormat> {
+        if self.selected_target == 0 {
+            Some(&self.settings.default)
+        } else {
+            self.columns
+                .get(self.selected_target - 1)
+                .and_then(|c| self.settings.columns.get(c))
+        }
+    }
+
+    fn target_format_mut(&mut self) -> &mut ColumnDisplayFormat {
+        if self.selected_target == 0 {
+            &mut self.settings.default
+        } else {
+            let column = self.columns[self.selected_target - 1].clone();
+            self.settings.columns.entry(column).or_default()
+        }
+    }
+
+    fn field_value(&self, field: DisplaySettingsField) -> Option<String> {
+        let fmt = self.target_format()?;
+        match field {
+            DisplaySettingsField::DatetimeFormat => fmt.datetime_format.clone(),
+            DisplaySettingsField::Timezone => fmt.timezone.clone(),
+            DisplaySettingsField::NullPlaceholder => fmt.null_placeholder.clone(),
+            DisplaySettingsField::ThousandsSeparator => fmt.thousands_separator.clone(),
+            DisplaySettingsField::FloatPrecision => fmt.float_precision.map(|p| p.to_string()),
+        }
+    }
+
+    fn set_field_value(&mut self, field: DisplaySettingsField, value: Option<String>) {
+        let value = value.filter(|v| !v.is_empty());
+        let fmt = self.target_format_mut();
+        match field {
+            DisplaySettingsField::DatetimeFormat => fmt.datetime_format = value,
+            DisplaySettingsField::Timezone => fmt.timezone = value,
+            DisplaySettingsField::NullPlaceholder => fmt.null_placeholder = value,
+            DisplaySettingsField::ThousandsSeparator => fmt.thousands_separator = value,
+            DisplaySettingsField::FloatPrecision => fmt.float_precision = value.and_then(|v| v.parse().ok()),
+        }
+    }
+
+    fn current_field(&self) -> DisplaySettingsField {
+        DisplaySettingsField::ALL[self.selected_field.min(DisplaySettingsField::ALL.len() - 1)]
+    }
+
+    fn push_char(&mut self, c: char) {
+        let field = self.current_field();
+        let mut value = self.field_value(field).unwrap_or_default();
+        if field == DisplaySettingsField::FloatPrecision {
+            // Precision is limited to two digits so it always parses
+            if !c.is_ascii_digit() || value.len() >= 2 {
+                return;
+            }
+        }
+        value.push(c);
+        self.set_field_value(field, Some(value));
+        self.error = None;
+    }
+
+    fn pop_char(&mut self) {
+        let field = self.current_field();
+        let mut value = self.field_value(field).unwrap_or_default();
+        value.pop();
+        self.set_field_value(field, Some(value));
+        self.error = None;
+    }
+
+    /// Drop empty per-column entries and validate the result
+    pub fn build_settings(&self) -> Result<DisplaySettings, String> {
+        let mut settings = self.settings.clone();
+        settings.columns.retain(|_, fmt| !fmt.is_empty());
+        let all = std::iter::once(&settings.default).chain(settings.columns.values());
+        for fmt in all {
+            if let Some(tz) = &fmt.timezone {
+                validate_timezone(tz)?;
+            }
+            if let Some(format) = &fmt.datetime_format {
+                validate_datetime_format(format)?;
+            }
+        }
+        Ok(settings)
+    }
+
+    /// Build instructions string from configured keybindings
+    fn build_instructions_from_config(&self) -> String {
+        self.config.actions_to_instructions(&[
+            (Mode::Global, Action::Enter),
+            (Mode::Global, Action::Escape),
+            (Mode::Global, Action::Tab),
+            (Mode::DisplaySettingsDialog, Action::ClearDisplayFormat),
+            (Mode::Global, Action::ToggleInstructions),
+        ])
+    }
+
+    /// Render the dialog
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title("Display Settings")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions,
+            if instructions.is_empty() { None } else { Some(instructions.as_str()) });
+        let content = layout.content_area;
+
+        let list_width = (content.width / 3).max(12).min(content.width);
+        let list_area = Rect { width: list_width, ..content };
+        let form_area = Rect {
+            x: content.x + list_width,
+            width: content.width.saturating_sub(list_width),
+            ..content
+        };
+
+        let focused_border = |focused: bool| {
+            if focused { Style::default().fg(Color::Cyan) } else { Style::default() }
+        };
+
+        // Targets list
+        let list_block = Block::default()
+            .title("Apply To")
+            .borders(Borders::ALL)
+            .border_style(focused_border(self.focus == DisplaySettingsFocus::Targets));
+        let list_inner = list_block.inner(list_area);
+        list_block.render(list_area, buf);
+        let max_rows = list_inner.height as usize;
+        let total_targets = self.columns.len() + 1;
+        let start = if self.selected_target >= max_rows { self.selected_target + 1 - max_rows } else { 0 };
+        for idx in start..total_targets.min(start + max_rows) {
+            let y = list_inner.y + (idx - start) as u16;
+            let has_override = idx > 0
+                && self.settings.columns.get(&self.columns[idx - 1]).is_some_and(|f| !f.is_empty());
+            let marker = if has_override { "*" } else { " " };
+            let style = if idx == self.selected_target {
+                Style::default().fg(Color::Black).bg(Color::Cyan)
+            } else {
+                Style::default()
+            };
+            buf.set_stringn(list_inner.x, y, format!("{marker}{}", self.target_label(idx)), list_inner.width as usize, style);
+        }
+
+        // Field form
+        let form_block = Block::default()
+            .title(format!("Format: {}", self.target_label(self.selected_target)))
+            .borders(Borders::ALL)
+            .border_style(focused_border(self.focus == DisplaySettingsFocus::Fields));
+        let form_inner = form_block.inner(form_area);
+        form_block.render(form_area, buf);
+        let mut lines: Vec<Line> = Vec::new();
+        for (i, field) in DisplaySettingsField::ALL.iter().enumerate() {
+            let value = self.field_value(*field);
+            let (shown, value_style) = match &value {
+                Some(v) => (v.clone(), Style::default().fg(Color::White)),
+                None => ("<inherit>".to_string(), Style::default().fg(Color::DarkGray)),
+            };
+            let value_style = if self.focus == DisplaySettingsFocus::Fields && i == self.selected_field {
+                value_style.bg(Color::Blue)
+            } else {
+                value_style
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("{:<18}", field.label()), Style::default().fg(Color::Yellow)),
+                Span::styled(shown, value_style),
+            ]));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::styled(
+            "Datetime format uses strftime syntax, e.g. %Y-%m-%d %H:%M:%S",
+            Style::default().fg(Color::Gray),
+        ));
+        lines.push(Line::styled(
+            "Timezone: UTC, Local, or an IANA name such as America/New_York",
+            Style::default().fg(Color::Gray),
+        ));
+        lines.push(Line::styled(
+            "Null placeholder and number formats apply at render time only",
+            Style::default().fg(Color::Gray),
+        ));
+        if let Some(err) = &self.error {
+            lines.push(Line::from(""));
+            lines.push(Line::styled(err.clone(), Style::default().fg(Color::Red)));
+        }
+        Paragraph::new(lines).wrap(Wrap { trim: false }).render(form_inner, buf);
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Handle keyboard events for the dialog
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        if let Some(global_action) = self.config.action_for_key(Mode::Global, key) {
+            match global_action {
+                Action::Escape => return Some(Action::DialogClose),
+                Action::Enter => {
+                    return match self.build_settings() {
+                        Ok(settings) => Some(Action::DisplaySettingsApplied(settings)),
+                        Err(e) => {
+                            self.error = Some(e);
+                            None
+                        }
+                    };
+                }
+                Action::Tab => {
+                    self.focus = match self.focus {
+                        DisplaySettingsFocus::Targets => DisplaySettingsFocus::Fields,
+                        DisplaySettingsFocus::Fields => DisplaySettingsFocus::Targets,
+                    };
+                    return None;
+                }
+                Action::Up => {
+                    match self.focus {
+                        DisplaySettingsFocus::Targets => self.selected_target = self.selected_target.saturating_sub(1),
+                        DisplaySettingsFocus::Fields => self.selected_field = self.selected_field.saturating_sub(1),
+                    }
+                    return None;
+                }
+                Action::Down => {
+                    match self.focus {
+                        DisplaySettingsFocus::Targets => {
+                            if self.selected_target < self.columns.len() {
+                                self.selected_target += 1;
+                            }
+                        }
+                        DisplaySettingsFocus::Fields => {
+                            if self.selected_field + 1 < DisplaySettingsField::ALL.len() {
+                                self.selected_field += 1;
+                            }
+                        }
+                    }
+                    return None;
+                }
+                Action::Backspace => {
+                    if self.focus == DisplaySettingsFocus::Fields {
+                        self.pop_char();
+                    }
+                    return None;
+                }
+                Action::ToggleInstructions => {
+                    self.show_instructions = !self.show_instructions;
+                    return None;
+                }
+                _ => {}
+            }
+        }
+        if let Some(Action::ClearDisplayFormat) = self.config.action_for_key(Mode::DisplaySettingsDialog, key) {
+            *self.target_format_mut() = ColumnDisplayFormat::default();
+            self.error = None;
+            return None;
+        }
+        if self.focus == DisplaySettingsFocus::Fields
+            && let KeyCode::Char(c) = key.code
+            && !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        {
+            self.push_char(c);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2024-01-02 03:04:05 UTC
+    const TS_MS: i64 = 1_704_164_645_000;
+
+    #[test]
+    fn test_format_value_datetime_with_timezone() {
+        let mut settings = DisplaySettings::default();
+        let value = AnyValue::Datetime(TS_MS, TimeUnit::Milliseconds, None);
+        // Untouched when nothing is configured
+        assert_eq!(settings.format_value("ts", &value), format!("{value}"));
+
+        settings.default.datetime_format = Some("%Y-%m-%d %H:%M".to_string());
+        assert_eq!(settings.format_value("ts", &value), "2024-01-02 03:04");
+
+        settings.columns.insert("ts".to_string(), ColumnDisplayFormat {
+            datetime_format: None,
+            timezone: Some("Asia/Tokyo".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(settings.format_value("ts", &value), "2024-01-02 12:04");
+        assert_eq!(settings.format_value("other", &value), "2024-01-02 03:04");
+    }
+
+    #[test]
+    fn test_format_value_nulls_and_numbers() {
+        let mut settings = DisplaySettings::default();
+        assert_eq!(settings.format_value("n", &AnyValue::Null), "");
+        assert_eq!(settings.format_value("n", &AnyValue::Int64(1234567)), "1234567");
+
+        settings.default.null_placeholder = Some("<null>".to_string());
+        settings.default.thousands_separator = Some(",".to_string());
+        settings.columns.insert("price".to_string(), ColumnDisplayFormat {
+            float_precision: Some(2),
+            ..Default::default()
+        });
+        assert_eq!(settings.format_value("n", &AnyValue::Null), "<null>");
+        assert_eq!(settings.format_value("n", &AnyValue::Int64(-1234567)), "-1,234,567");
+        assert_eq!(settings.format_value("price", &AnyValue::Float64(12345.678)), "12,345.68");
+        assert_eq!(settings.format_value("n", &AnyValue::Float64(1234.5)), "1,234.5");
+        assert_eq!(settings.format_value("n", &AnyValue::String("1234")), "1234");
+        assert_eq!(group_thousands("NaN", ","), "NaN");
+    }
+
+    #[test]
+    fn test_build_settings_rejects_unknown_timezone() {
+        let mut dialog = DisplaySettingsDialog::new(DisplaySettings::default(), vec!["ts".to_string()], Some(0));
+        assert_eq!(dialog.selected_target, 1);
+        dialog.selected_field = 1;
+        for c in "Mars/Olympus".chars() {
+            dialog.push_char(c);
+        }
+        assert!(dialog.build_settings().is_err());
+        while dialog.field_value(DisplaySettingsField::Timezone).is_some() {
+            dialog.pop_char();
+        }
+        // Empty column entries are dropped
+        assert_eq!(dialog.build_settings().unwrap(), DisplaySettings::default());
+    }
+
+    #[test]
+    fn test_bad_datetime_formats_do_not_panic() {
+        let mut settings = DisplaySettings::default();
+        settings.default.datetime_format = Some("%Y-%m-%d %H:%M:%S".to_string());
+        // Time specifiers cannot format a date; the raw value is shown
+        let date = AnyValue::Date(19_724);
+        assert_eq!(settings.format_value("d", &date), format!("{date}"));
+
+        settings.default.datetime_format = Some("%Q %".to_string());
+        let value = AnyValue::Datetime(TS_MS, TimeUnit::Milliseconds, None);
+        assert_eq!(settings.format_value("ts", &value), format!("{value}"));
+
+        let dialog = DisplaySettingsDialog::new(settings, vec!["ts".to_string()], None);
+        assert!(dialog.build_settings().is_err());
+    }
+}
@@ -90,12 +90,16 @@ impl EmbeddingsPromptDialog {
         let cfg = maybe_cfg.unwrap();
         let prompt = self.prompt_input.lines().join("\n");
         let dims_opt = if cfg.num_dimensions > 0 { Some(cfg.num_dimensions) } else { None };
-        let vecs = self.config.llm_config.fetch_embeddings_via_provider(
+        let llm_config = &self.config.llm_config;
+        let not_cancelled = || false;
+        let mut runner = crate::dialog::llm::RequestRunner::new(llm_config.request_policy.clone(), &not_cancelled);
+        let inputs = vec![prompt];
+        let vecs = runner.run(|| llm_config.fetch_embeddings_via_provider(
             cfg.provider.clone(),
             &cfg.model_name,
-            &vec![prompt],
+            &inputs,
             dims_opt,
-        )?;
+        ))?;
         let prompt_embedding = vecs.into_iter().next().unwrap_or_default();
         Ok(Action::EmbeddingsPromptDialogApplied {
             source_column,
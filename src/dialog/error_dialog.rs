@@ -6,42 +6,163 @@ use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
 use crate::action::Action;
 use crate::components::Component;
 
-/// Simple reusable dialog for displaying error messages.
+/// Remove ANSI escape sequences (color_eyre colors its report output)
+fn strip_ansi(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            if chars.peek() == Some(&'[') {
+                chars.next();
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            continue;
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Reusable dialog for displaying errors: the message, its cause chain, and an optional
+/// collapsible details section (e.g. the color_eyre report with its backtrace).
 #[derive(Debug, Clone)]
 pub struct ErrorDialog {
     message: String,
     title: String,
+    /// Underlying causes, outermost first
+    chain: Vec<String>,
+    details: Option<String>,
+    show_details: bool,
+    details_scroll: u16,
+    /// Feedback from the last copy attempt
+    status: Option<String>,
 }
 
 impl ErrorDialog {
     pub fn new(message: impl Into<String>) -> Self {
-        Self {
-            message: message.into(),
-            title: "Error".to_string(),
-        }
+        Self::with_title(message, "Error")
     }
 
     pub fn with_title(message: impl Into<String>, title: impl Into<String>) -> Self {
         Self {
             message: message.into(),
             title: title.into(),
+            chain: Vec::new(),
+            details: None,
+            show_details: false,
+            details_scroll: 0,
+            status: None,
         }
     }
 
+    /// Error dialog for a color_eyre report: its chain of causes, with the full report
+    /// (including any backtrace) as details
+    pub fn from_report(report: &color_eyre::Report) -> Self {
+        let mut dialog = Self::new(report.to_string());
+        dialog.chain = report.chain().skip(1).map(|e| e.to_string()).collect();
+        dialog.details = Some(strip_ansi(&format!("{report:?}")));
+        dialog
+    }
+
+    /// Error dialog for any error, following its `source()` chain
+    pub fn from_error(error: &dyn std::error::Error) -> Self {
+        let mut dialog = Self::new(error.to_string());
+        let mut source = error.source();
+        while let Some(e) = source {
+            dialog.chain.push(e.to_string());
+            source = e.source();
+        }
+        dialog
+    }
+
     pub fn set_message(&mut self, message: impl Into<String>) {
         self.message = message.into();
     }
 
+    pub fn with_details(mut self, details: impl Into<String>) -> Self {
+        self.details = Some(details.into());
+        self
+    }
+
+    pub fn show_details(&self) -> bool {
+        self.show_details
+    }
+
+    pub fn toggle_details(&mut self) {
+        if self.details.is_some() {
+            self.show_details = !self.show_details;
+            self.details_scroll = 0;
+        }
+    }
+
+    /// Title, message, causes and details as plain text
+    pub fn full_text(&self) -> String {
+        let mut text = format!("{}: {}", self.title, self.message);
+        if !self.chain.is_empty() {
+            text.push_str("\n\nCaused by:");
+            for (i, cause) in self.chain.iter().enumerate() {
+                text.push_str(&format!("\n  {i}: {cause}"));
+            }
+        }
+        if let Some(details) = &self.details {
+            text.push_str("\n\nDetails:\n");
+            text.push_str(details);
+        }
+        text
+    }
+
+    pub fn copy_to_clipboard(&mut self) {
+        let result = arboard::Clipboard::new().and_then(|mut clipboard| clipboard.set_text(self.full_text()));
+        self.status = Some(match result {
+            Ok(()) => "Copied to clipboard".to_string(),
+            Err(e) => format!("Copy failed: {e}"),
+        });
+    }
+
+    fn body_lines(&self) -> Vec<Line<'_>> {
+        let mut lines = vec![Line::styled(self.message.as_str(), Style::default().fg(Color::Red))];
+        if !self.chain.is_empty() {
+            lines.push(Line::raw(""));
+            lines.push(Line::styled("Caused by:", Style::default().fg(Color::Yellow)));
+            for (i, cause) in self.chain.iter().enumerate() {
+                lines.push(Line::styled(format!("  {i}: {cause}"), Style::default().fg(Color::LightRed)));
+            }
+        }
+        lines
+    }
+
+    fn hint(&self) -> String {
+        let mut hint = "Enter/Esc: close  c: copy".to_string();
+        if self.details.is_some() {
+            hint.push_str(if self.show_details { "  d: hide details  Up/Down: scroll" } else { "  d: show details" });
+        }
+        if let Some(status) = &self.status {
+            hint.push_str(&format!("  [{status}]"));
+        }
+        hint
+    }
+
     fn modal_area(&self, area: Rect) -> Rect {
-        let max_width = area.width.saturating_sub(10).clamp(20, 80);
-        let wrap_width = max_width.saturating_sub(4) as usize;
-        let wrapped = textwrap::wrap(&self.message, wrap_width);
-        let content_lines = wrapped.len() as u16;
-        let height = content_lines
-            .saturating_add(4) // top/bottom padding + hint line
-            .clamp(5, area.height.saturating_sub(4));
-
-        let width = max_width;
+        let max_width = if self.show_details { area.width.saturating_sub(6).max(20) } else { area.width.saturating_sub(10).clamp(20, 80) };
+        let wrap_width = max_width.saturating_sub(4).max(1) as usize;
+        let mut content_lines = textwrap::wrap(&self.message, wrap_width).len();
+        if !self.chain.is_empty() {
+            content_lines += 2 + self.chain.iter().map(|c| textwrap::wrap(c, wrap_width.saturating_sub(5).max(1)).len()).sum::<usize>();
+        }
+        let height = if self.show_details {
+            area.height.saturating_sub(4)
+        } else {
+            (content_lines as u16)
+                .saturating_add(4) // top/bottom padding + hint line
+                .clamp(5, area.height.saturating_sub(4).max(5))
+        };
+
+        let width = max_width.min(area.width);
+        let height = height.min(area.height);
         let x = area.x + (area.width.saturating_sub(width)) / 2;
         let y = area.y + (area.height.saturating_sub(height)) / 2;
         Rect { x, y, width, height }
@@ -64,17 +185,34 @@ impl ErrorDialog {
             .style(Style::default().bg(Color::Black));
         let inner = block.inner(modal);
         block.render(modal, buf);
+        if inner.height == 0 {
+            return;
+        }
+        let body = Rect { height: inner.height.saturating_sub(1), ..inner };
 
-        let hint = "Press Enter or Esc to close";
-        let wrap = Paragraph::new(self.message.as_str())
+        let message = Paragraph::new(self.body_lines())
             .wrap(Wrap { trim: false })
-            .style(Style::default().fg(Color::Red).bg(Color::Black));
-        wrap.render(inner, buf);
+            .style(Style::default().bg(Color::Black));
+        match (&self.details, self.show_details) {
+            (Some(details), true) => {
+                let chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Percentage(35), Constraint::Min(3)])
+                    .split(body);
+                message.render(chunks[0], buf);
+                Paragraph::new(details.as_str())
+                    .block(Block::default().title("Details").borders(Borders::TOP))
+                    .style(Style::default().fg(Color::Gray).bg(Color::Black))
+                    .scroll((self.details_scroll, 0))
+                    .render(chunks[1], buf);
+            }
+            _ => message.render(body, buf),
+        }
 
         if inner.height >= 2 {
             let hint_y = inner.y + inner.height - 1;
             let hint_x = inner.x + 1;
-            buf.set_string(hint_x, hint_y, hint, Style::default().fg(Color::Gray).bg(Color::Black));
+            buf.set_stringn(hint_x, hint_y, self.hint(), inner.width.saturating_sub(1) as usize, Style::default().fg(Color::Gray).bg(Color::Black));
         }
     }
 }
@@ -89,6 +227,12 @@ impl Component for ErrorDialog {
         if key.kind == KeyEventKind::Press {
             match key.code {
                 KeyCode::Enter | KeyCode::Esc => return Ok(Some(Action::DialogClose)),
+                KeyCode::Char('c') => self.copy_to_clipboard(),
+                KeyCode::Char('d') => self.toggle_details(),
+                KeyCode::Up if self.show_details => self.details_scroll = self.details_scroll.saturating_sub(1),
+                KeyCode::Down if self.show_details => self.details_scroll = self.details_scroll.saturating_add(1),
+                KeyCode::PageUp if self.show_details => self.details_scroll = self.details_scroll.saturating_sub(10),
+                KeyCode::PageDown if self.show_details => self.details_scroll = self.details_scroll.saturating_add(10),
                 _ => {}
             }
         }
@@ -101,4 +245,37 @@ impl Component for ErrorDialog {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use color_eyre::eyre::WrapErr;
+    use crossterm::event::KeyModifiers;
+
+    #[test]
+    fn test_report_chain_and_details() {
+        let report = std::fs::read("/nonexistent/datatui.csv").wrap_err("Failed to load dataset").unwrap_err();
+        let mut dialog = ErrorDialog::from_report(&report);
+        assert_eq!(dialog.message, "Failed to load dataset");
+        assert_eq!(dialog.chain.len(), 1);
+        assert!(!dialog.details.as_deref().unwrap().contains('\u{1b}'));
+        assert!(dialog.full_text().contains("Caused by:\n  0: "));
+
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE)).unwrap();
+        assert!(dialog.show_details());
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE)).unwrap();
+        assert_eq!(dialog.details_scroll, 1);
+        let area = Rect::new(0, 0, 80, 24);
+        let mut buf = Buffer::empty(area);
+        dialog.render(area, &mut buf);
+        let text: String = buf.content().iter().map(|c| c.symbol()).collect();
+        assert!(text.contains("Caused by:") && text.contains("Details"));
 
+        assert_eq!(strip_ansi("\u{1b}[31mred\u{1b}[0m text"), "red text");
+        let plain = ErrorDialog::new("oops");
+        assert!(!plain.hint().contains("details"));
+        assert_eq!(
+            ErrorDialog::new("x").handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)).unwrap(),
+            Some(Action::DialogClose)
+        );
+    }
+}
@@ -7,6 +7,7 @@ use crate::action::Action;
 use crate::config::Config;
 
 use crate::components::Component;
+use crate::components::column_search::ColumnSearch;
 use color_eyre::Result;
 use crossterm::event::{KeyEvent, KeyEventKind};
 // use std::cell::RefCell; // no longer used
@@ -15,6 +16,7 @@ use std::fs::File;
 use std::path::Path;
 use serde_json;
 use crate::dialog::file_browser_dialog::{FileBrowserDialog, FileBrowserAction, FileBrowserMode};
+use crate::dialog::confirm_dialog::{ConfirmDialog, ConfirmKind};
 use crate::components::dialog_layout::split_dialog_area;
 use tracing::error;
 
@@ -196,6 +198,10 @@ pub struct FilterDialog {
     // Free column mode: allows typing a JMESPath query for the column
     pub enabled_free_column: bool,
     pub add_column_text: String, // stores the free-typed column (JMESPath query)
+    /// Shown before the conditions are reset
+    pub confirm_dialog: Option<ConfirmDialog>,
+    /// Type-to-jump over the columns of the Column field
+    pub column_search: ColumnSearch,
 }
 
 impl FilterDialog {
@@ -219,6 +225,8 @@ impl FilterDialog {
             config: Config::default(),
             enabled_free_column: false,
             add_column_text: String::new(),
+            confirm_dialog: None,
+            column_search: ColumnSearch::default(),
         }
     }
 
@@ -273,6 +281,12 @@ impl FilterDialog {
         self.mode = FilterDialogMode::List;
     }
 
+    fn reset_filters(&mut self) {
+        self.root_expr = FilterExpr::And(vec![]);
+        self.selected_path.clear();
+        self.scroll_offset = 0;
+    }
+
     /// Render the dialog (UI for List and Add/Edit modes)
     pub fn render(&self, area: Rect, buf: &mut Buffer) -> usize {
         Clear.render(area, buf);
@@ -354,6 +368,10 @@ impl FilterDialog {
                             .unwrap_or(&"".to_string())
                     )
                 };
+                let col_label = match self.column_search.title() {
+                    Some(search) => format!("{col_label}    {search}"),
+                    None => col_label,
+                };
                 buf.set_string(start_x, field_y, col_label, highlight(FilterDialogField::Column));
                 // Field 2: Type
                 let type_label = format!("Type: {}", match self.add_condition {
@@ -398,6 +416,10 @@ impl FilterDialog {
                 } else {
                     format!("Column: {}", self.columns.get(self.add_column_index).unwrap_or(&"".to_string()))
                 };
+                let col_label = match self.column_search.title() {
+                    Some(search) => format!("{col_label}    {search}"),
+                    None => col_label,
+                };
                 buf.set_string(start_x, field_y, col_label, highlight(FilterDialogField::Column));
                 // Field 2: Type
                 let type_label = format!("Type: {}", match self.add_condition {
@@ -448,12 +470,30 @@ impl FilterDialog {
                 instructions_paragraph.render(instructions_area, buf);
             }
         // --- End instructions ---
+        if let Some(dialog) = &self.confirm_dialog {
+            dialog.render(area, buf);
+        }
         max_rows
     }
 
     /// Handle a key event. Returns Some(Action) if the dialog should close and apply, None otherwise.
     pub fn handle_key_event(&mut self, key: KeyEvent, max_rows: usize) -> Option<Action> {
         use crossterm::event::KeyCode;
+
+        if let Some(dialog) = &mut self.confirm_dialog {
+            match dialog.handle_key_event(key) {
+                Some(Action::ConfirmDialogAccepted) => {
+                    if let Err(e) = dialog.remember(&mut self.config) {
+                        error!("Failed to save confirmation setting: {}", e);
+                    }
+                    self.confirm_dialog = None;
+                    self.reset_filters();
+                }
+                Some(Action::DialogClose) => self.confirm_dialog = None,
+                _ => {}
+            }
+            return None;
+        }
         
         // Handle FileBrowser mode first - if file browser is open, pass keys to it
         if let FilterDialogMode::FileBrowser(browser) = &mut self.mode {
@@ -492,6 +532,17 @@ impl FilterDialog {
             }
             return None;
         }
+
+        // While searching, keys edit the query and move the Column field to the best match
+        if self.column_search.is_active() {
+            if let Some(i) = self.column_search.handle_key(key, &self.columns) {
+                self.add_column_index = i;
+                if self.enabled_free_column {
+                    self.add_column_text = self.columns[i].clone();
+                }
+            }
+            return None;
+        }
         
         // First, honor config-driven actions (Global + Filter)
         if let Some(global_action) = self.config.action_for_key(crate::config::Mode::Global, key) {
@@ -772,6 +823,13 @@ impl FilterDialog {
         // Next, check for Filter mode specific actions
         if let Some(filter_action) = self.config.action_for_key(crate::config::Mode::Filter, key) {
             match filter_action {
+                Action::SearchColumns => {
+                    if matches!(self.mode, FilterDialogMode::Add | FilterDialogMode::Edit(_)) {
+                        self.focus_field = FilterDialogField::Column;
+                        self.column_search.start();
+                        return None;
+                    }
+                }
                 Action::AddFilter => {
                     if matches!(self.mode, FilterDialogMode::List) {
                         let node = self.root_expr.get_mut(&self.selected_path);
@@ -905,9 +963,14 @@ impl FilterDialog {
                 }
                 Action::ResetFilters => {
                     if matches!(self.mode, FilterDialogMode::List) {
-                        self.root_expr = FilterExpr::And(vec![]);
-                        self.selected_path.clear();
-                        self.scroll_offset = 0;
+                        let has_conditions = !matches!(&self.root_expr, FilterExpr::And(children) if children.is_empty());
+                        if has_conditions && self.config.needs_confirmation(ConfirmKind::ResetFilters) {
+                            let mut dialog = ConfirmDialog::new(ConfirmKind::ResetFilters, "Remove all filter conditions?", Action::ResetFilters);
+                            let _ = dialog.register_config_handler(self.config.clone());
+                            self.confirm_dialog = Some(dialog);
+                        } else {
+                            self.reset_filters();
+                        }
                     }
                     return None;
                 }
@@ -1005,11 +1068,15 @@ impl FilterDialog {
                     (crate::config::Mode::Filter, crate::action::Action::ResetFilters),
                 ])
             }
-            FilterDialogMode::Add => {
-                "Enter: OK  Esc: Cancel".to_string()
-            }
-            FilterDialogMode::Edit(_) => {
-                "Enter: OK  Esc: Cancel".to_string()
+            FilterDialogMode::Add | FilterDialogMode::Edit(_) => {
+                let search = self.config.actions_to_instructions(&[
+                    (crate::config::Mode::Filter, crate::action::Action::SearchColumns),
+                ]);
+                if search.is_empty() {
+                    "Enter: OK  Esc: Cancel".to_string()
+                } else {
+                    format!("{search}  Enter: OK  Esc: Cancel")
+                }
             }
             FilterDialogMode::AddGroup => {
                 let instructions = self.config.actions_to_instructions(&[
@@ -1056,6 +1123,14 @@ impl Component for FilterDialog {
     }
 }
 
+/// Categorical columns are matched on their text, like String columns
+fn as_text_if_categorical(column: &Column) -> PolarsResult<Column> {
+    match column.dtype() {
+        DataType::Categorical(..) | DataType::Enum(..) => column.cast(&DataType::String),
+        _ => Ok(column.clone()),
+    }
+}
+
 impl ColumnFilter {
     /// Format a filter as a summary string for the list
     pub fn summary(&self) -> String {
@@ -1129,7 +1204,7 @@ impl ColumnFilter {
 
     /// Create a boolean mask for this filter condition
     pub fn create_mask(&self, df: &DataFrame) -> color_eyre::Result<BooleanChunked> {
-        let column = df.column(&self.column)?;
+        let column = &as_text_if_categorical(df.column(&self.column)?)?;
         let column_type = column.dtype();
         
         // We have to handle the different types of columns differently.
@@ -1382,6 +1457,22 @@ impl ColumnFilter {
                             Ok(col.gt(min_str) & col.lt(max_str))
                         }
                     }
+                    DataType::Datetime(_, _) | DataType::Date => {
+                        // Bounds are text timestamps, compared as epoch microseconds
+                        use crate::dataframe::datetime_parse::parse_datetime_auto;
+                        let min_val = parse_datetime_auto(min).ok_or_else(|| color_eyre::eyre::eyre!("Invalid min timestamp: {}", min))?;
+                        let max_val = parse_datetime_auto(max).ok_or_else(|| color_eyre::eyre::eyre!("Invalid max timestamp: {}", max))?;
+                        let tz = match column_type { DataType::Datetime(_, tz) => tz.clone(), _ => None };
+                        let micros = column
+                            .cast(&DataType::Datetime(TimeUnit::Microseconds, tz))?
+                            .cast(&DataType::Int64)?;
+                        let col = micros.i64()?;
+                        if *inclusive {
+                            Ok(col.gt_eq(min_val) & col.lt_eq(max_val))
+                        } else {
+                            Ok(col.gt(min_val) & col.lt(max_val))
+                        }
+                    }
                     _ => {
                         Err(color_eyre::eyre::eyre!("Unsupported column type for Between: {}", column_type))
                     }
@@ -1453,10 +1544,10 @@ impl ColumnFilter {
             },
             // Phase 2: Advanced conditions
             FilterCondition::CompareColumns { other_column, operator } => {
-                let other = df.column(other_column)?;
+                let other = &as_text_if_categorical(df.column(other_column)?)?;
                 
                 // Try numeric comparison first
-                if let (Ok(col_f64), Ok(other_f64)) = (column.cast(&DataType::Float64), other.cast(&DataType::Float64)) {
+                if let (Ok(col_f64), Ok(other_f64)) = (column.strict_cast(&DataType::Float64), other.strict_cast(&DataType::Float64)) {
                     let col = col_f64.f64()?;
                     let other = other_f64.f64()?;
                     match operator {
@@ -1503,6 +1594,18 @@ impl ColumnFilter {
     /// Evaluate this filter condition against a single row's data
     /// row_data: Map of column names to string values
     pub fn evaluate_row(&self, row_data: &std::collections::BTreeMap<String, String>) -> color_eyre::Result<bool> {
+        // A glob column (e.g. "*" in style templates) matches when any matching column satisfies the condition
+        if self.column.contains(['*', '?']) && !row_data.contains_key(&self.column) {
+            let patterns = [self.column.clone()];
+            for name in row_data.keys().filter(|name| crate::dialog::styling::matches_column(name, &patterns)) {
+                let filter = ColumnFilter { column: name.clone(), condition: self.condition.clone() };
+                if filter.evaluate_row(row_data)? {
+                    return Ok(true);
+                }
+            }
+            return Ok(false);
+        }
+
         let cell_value = row_data.get(&self.column)
             .map(|s| s.as_str())
             .unwrap_or("");
@@ -1665,6 +1768,26 @@ mod tests {
     use super::*;
     use std::collections::HashSet;
 
+    #[test]
+    fn filters_and_renders_categorical_columns_as_text() {
+        use crate::dataframe::categorical::categorize_strings;
+        use crate::dialog::display_settings_dialog::DisplaySettings;
+
+        let hosts: Vec<&str> = (0..120).map(|i| if i % 3 == 0 { "web-01" } else { "db-02" }).collect();
+        let df = categorize_strings(df! { "host" => hosts, "other" => vec!["web-01"; 120] }.unwrap()).unwrap();
+        assert!(matches!(df.column("host").unwrap().dtype(), DataType::Categorical(..)));
+
+        let mask = |condition| ColumnFilter { column: "host".to_string(), condition }.create_mask(&df).unwrap().sum();
+        assert_eq!(mask(FilterCondition::Equals { value: "web-01".to_string(), case_sensitive: true }), Some(40));
+        assert_eq!(mask(FilterCondition::Contains { value: "DB".to_string(), case_sensitive: false }), Some(80));
+        assert_eq!(mask(FilterCondition::Regex { pattern: "^web".to_string(), case_sensitive: true }), Some(40));
+        let compare = FilterCondition::CompareColumns { other_column: "other".to_string(), operator: CompareOp::Eq };
+        assert_eq!(mask(compare), Some(40));
+
+        let value = df.column("host").unwrap().get(0).unwrap();
+        assert_eq!(DisplaySettings::default().format_value("host", &value), "web-01");
+    }
+
     #[test]
     fn forward_and_backward_are_inverses() {
         for kind in condition_cycle() {
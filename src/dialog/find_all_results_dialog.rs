@@ -54,6 +54,7 @@ impl FindAllResultsDialog {
             (crate::config::Mode::FindAllResults, crate::action::Action::GoToLast),
             (crate::config::Mode::FindAllResults, crate::action::Action::PageUp),
             (crate::config::Mode::FindAllResults, crate::action::Action::PageDown),
+            (crate::config::Mode::FindAllResults, crate::action::Action::OpenFindDialog),
         ])
     }
 
@@ -292,21 +293,11 @@ impl FindAllResultsDialog {
                     });
                 }
                 Action::Up => {
-                    // Move selection up
-                    if self.selected > 0 {
-                        self.selected -= 1;
-                        // Adjust scroll if needed
-                        self.update_scroll_offset(max_rows);
-                    }
+                    self.select_previous(max_rows);
                     return None;
                 }
                 Action::Down => {
-                    // Move selection down
-                    if self.selected < self.results.len().saturating_sub(1) {
-                        self.selected += 1;
-                        // Adjust scroll if needed
-                        self.update_scroll_offset(max_rows);
-                    }
+                    self.select_next(max_rows);
                     return None;
                 }
                 Action::ToggleInstructions => {
@@ -320,6 +311,17 @@ impl FindAllResultsDialog {
         // Next, check for FindAllResults-specific actions
         if let Some(dialog_action) = self.config.action_for_key(crate::config::Mode::FindAllResults, key) {
             match dialog_action {
+                // Letter keys from a keymap profile (vim's j/k)
+                Action::Up => {
+                    self.select_previous(max_rows);
+                    return None;
+                }
+                Action::Down => {
+                    self.select_next(max_rows);
+                    return None;
+                }
+                // Back to the Find dialog to refine the search
+                Action::OpenFindDialog => return Some(Action::OpenFindDialog),
                 Action::GoToFirst => {
                     // Go to first result
                     self.selected = 0;
@@ -365,6 +367,20 @@ impl FindAllResultsDialog {
         None
     }
 
+    fn select_previous(&mut self, max_rows: usize) {
+        if self.selected > 0 {
+            self.selected -= 1;
+            self.update_scroll_offset(max_rows);
+        }
+    }
+
+    fn select_next(&mut self, max_rows: usize) {
+        if self.selected < self.results.len().saturating_sub(1) {
+            self.selected += 1;
+            self.update_scroll_offset(max_rows);
+        }
+    }
+
     /// Get the currently selected result
     pub fn get_selected_result(&self) -> Option<&FindAllResult> {
         self.results.get(self.selected)
@@ -12,6 +12,21 @@ use ratatui::layout::Size;
 use tokio::sync::mpsc::UnboundedSender;
 use crate::components::dialog_layout::split_dialog_area;
 use serde::{Serialize, Deserialize};
+use std::time::{Duration, Instant};
+
+/// Pause in typing after which an incremental search runs
+pub const INCREMENTAL_FIND_DELAY: Duration = Duration::from_millis(150);
+
+/// Recent searches kept before the oldest is dropped
+pub const MAX_FIND_HISTORY: usize = 30;
+
+/// A search run from the Find dialog, recalled with Up/Down in the pattern field
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FindHistoryEntry {
+    pub pattern: String,
+    pub options: FindOptions,
+    pub search_mode: SearchMode,
+}
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum FindDialogMode {
@@ -38,13 +53,32 @@ pub struct FindDialog {
     pub searching: bool,
     pub search_progress: f64,
     pub action_selected: FindActionSelected, // New field
+    /// Search as the pattern is typed, jumping to the first match
+    pub incremental: bool,
+    /// Outcome of the last incremental search, shown under the actions
+    pub incremental_status: Option<String>,
+    /// When the pattern or options last changed with an incremental search still to run
+    incremental_pending: Option<Instant>,
+    /// The project's recent searches, most recent first
+    pub history: Vec<FindHistoryEntry>,
+    /// Entry of `history` shown in the pattern field while browsing it
+    history_pos: Option<usize>,
+    /// Pattern being typed before browsing started, restored after the newest entry
+    history_draft: String,
     pub config: Config,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FindOptions {
     pub backward: bool,
+    /// Match only where the pattern is bounded by non-word characters
     pub whole_word: bool,
+    /// The pattern has to match the whole cell
+    #[serde(default)]
+    pub entire_cell: bool,
+    /// The pattern has to match at the start of the cell
+    #[serde(default)]
+    pub anchored: bool,
     pub match_case: bool,
     pub wrap_around: bool,
 }
@@ -54,8 +88,11 @@ pub enum FindDialogField {
     Pattern,
     Backward,
     WholeWord,
+    EntireCell,
+    Anchored,
     MatchCase,
     WrapAround,
+    Incremental,
     SearchMode,
     ActionsRow, // New: represents the row of actions
 }
@@ -72,6 +109,8 @@ impl Default for FindOptions {
         Self {
             backward: false,
             whole_word: false,
+            entire_cell: false,
+            anchored: false,
             match_case: false,
             wrap_around: true,
         }
@@ -91,6 +130,12 @@ impl Default for FindDialog {
             searching: false,
             search_progress: 0.0,
             action_selected: FindActionSelected::FindNext, // New field
+            incremental: false,
+            incremental_status: None,
+            incremental_pending: None,
+            history: Vec::new(),
+            history_pos: None,
+            history_draft: String::new(),
             config: Config::default(),
         }
     }
@@ -106,9 +151,12 @@ impl FindDialog {
         match self.active_field {
             Pattern => Backward,
             Backward => WholeWord,
-            WholeWord => MatchCase,
+            WholeWord => EntireCell,
+            EntireCell => Anchored,
+            Anchored => MatchCase,
             MatchCase => WrapAround,
-            WrapAround => SearchMode,
+            WrapAround => Incremental,
+            Incremental => SearchMode,
             SearchMode => ActionsRow,
             ActionsRow => Pattern,
         }
@@ -120,13 +168,126 @@ impl FindDialog {
             Pattern => ActionsRow,
             Backward => Pattern,
             WholeWord => Backward,
-            MatchCase => WholeWord,
+            MatchCase => Anchored,
+            Anchored => EntireCell,
+            EntireCell => WholeWord,
             WrapAround => MatchCase,
-            SearchMode => WrapAround,
+            Incremental => WrapAround,
+            SearchMode => Incremental,
             ActionsRow => SearchMode,
         }
     }
 
+    /// The pattern was typed in: stop browsing history and search again if incremental
+    fn pattern_edited(&mut self) {
+        self.history_pos = None;
+        self.schedule_incremental();
+    }
+
+    /// Move the current search to the front of the history
+    fn record_history(&mut self) {
+        if self.search_pattern.is_empty() {
+            return;
+        }
+        let entry = FindHistoryEntry {
+            pattern: self.search_pattern.clone(),
+            options: self.options.clone(),
+            search_mode: self.search_mode.clone(),
+        };
+        self.history.retain(|e| e.pattern != entry.pattern || e.search_mode != entry.search_mode);
+        self.history.insert(0, entry);
+        self.history.truncate(MAX_FIND_HISTORY);
+        self.history_pos = None;
+    }
+
+    /// Show history entry `pos` in the dialog, with its options and mode
+    fn show_history_entry(&mut self, pos: usize) {
+        let entry = self.history[pos].clone();
+        self.search_pattern_cursor = entry.pattern.len();
+        self.search_pattern = entry.pattern;
+        self.options = entry.options;
+        self.search_mode = entry.search_mode;
+        self.history_pos = Some(pos);
+        self.schedule_incremental();
+    }
+
+    /// Recall the next older search; false when there is no history to browse
+    fn recall_older(&mut self) -> bool {
+        if self.history.is_empty() {
+            return false;
+        }
+        let pos = match self.history_pos {
+            None => {
+                self.history_draft = self.search_pattern.clone();
+                0
+            }
+            Some(pos) => (pos + 1).min(self.history.len() - 1),
+        };
+        self.show_history_entry(pos);
+        true
+    }
+
+    /// Recall the next newer search, or the typed pattern after the newest; false when not
+    /// browsing history
+    fn recall_newer(&mut self) -> bool {
+        match self.history_pos {
+            None => false,
+            Some(0) => {
+                self.search_pattern = std::mem::take(&mut self.history_draft);
+                self.search_pattern_cursor = self.search_pattern.len();
+                self.pattern_edited();
+                true
+            }
+            Some(pos) => {
+                self.show_history_entry(pos - 1);
+                true
+            }
+        }
+    }
+
+    /// Note that the pattern or options changed, so an incremental search is due
+    fn schedule_incremental(&mut self) {
+        if self.incremental {
+            self.incremental_pending = Some(Instant::now());
+        }
+    }
+
+    /// The pattern, options and mode to search for once typing has paused for
+    /// `INCREMENTAL_FIND_DELAY` as of `now`; each change is searched for once
+    pub fn take_incremental_search(&mut self, now: Instant) -> Option<(String, FindOptions, SearchMode)> {
+        let changed = self.incremental_pending?;
+        if now.duration_since(changed) < INCREMENTAL_FIND_DELAY {
+            return None;
+        }
+        self.incremental_pending = None;
+        Some((self.search_pattern.clone(), self.options.clone(), self.search_mode.clone()))
+    }
+
+    /// Flip the checkbox or radio under the cursor
+    fn toggle_active_field(&mut self) {
+        use FindDialogField::*;
+        match self.active_field {
+            Backward => self.options.backward = !self.options.backward,
+            WholeWord => self.options.whole_word = !self.options.whole_word,
+            EntireCell => self.options.entire_cell = !self.options.entire_cell,
+            Anchored => self.options.anchored = !self.options.anchored,
+            MatchCase => self.options.match_case = !self.options.match_case,
+            WrapAround => self.options.wrap_around = !self.options.wrap_around,
+            Incremental => {
+                self.incremental = !self.incremental;
+                self.incremental_status = None;
+            }
+            SearchMode => {
+                self.search_mode = match self.search_mode {
+                    self::SearchMode::Normal => self::SearchMode::Regex,
+                    self::SearchMode::Regex => self::SearchMode::Normal,
+                }
+            }
+            Pattern | ActionsRow => return,
+        }
+        self.schedule_incremental();
+    }
+
     pub fn render(&self, area: Rect, buf: &mut Buffer) -> usize {
         use ratatui::widgets::Gauge;
         use ratatui::widgets::Paragraph;
@@ -189,8 +350,11 @@ impl FindDialog {
         let options = [
             ("Backward direction", FindDialogField::Backward, self.options.backward),
             ("Match whole word only", FindDialogField::WholeWord, self.options.whole_word),
+            ("Match entire cell", FindDialogField::EntireCell, self.options.entire_cell),
+            ("Match at start of cell", FindDialogField::Anchored, self.options.anchored),
             ("Match case", FindDialogField::MatchCase, self.options.match_case),
             ("Wrap around", FindDialogField::WrapAround, self.options.wrap_around),
+            ("Search as you type", FindDialogField::Incremental, self.incremental),
         ];
         for (label, field, checked) in options.iter() {
             let check = if *checked { "[✓]" } else { "[ ]" };
@@ -237,6 +401,10 @@ impl FindDialog {
             buf.set_string(x, y, *label, style);
             x += label.len() as u16 + 4;
         }
+        if self.incremental
+            && let Some(status) = &self.incremental_status {
+            buf.set_string(start_x, y + 2, status, Style::default().fg(Color::DarkGray));
+        }
         // Overlay error block if in error mode
         if let FindDialogMode::Error(ref msg) = self.mode {
             let block_width = inner_area.width.saturating_sub(10).clamp(20, 40);
@@ -334,11 +502,10 @@ impl FindDialog {
             (crate::config::Mode::Global, crate::action::Action::ToggleInstructions),
         ]);
         
-        format!("Enter search pattern. {instructions}")
+        format!("Enter search pattern (Up/Down: recent searches). {instructions}")
     }
 
     pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
-        use SearchMode::*;
         use crossterm::event::{KeyCode, KeyModifiers};
         if key.kind != KeyEventKind::Press {
             return None;
@@ -374,6 +541,7 @@ impl FindDialog {
                 let cursor = self.search_pattern_cursor.min(self.search_pattern.len());
                 self.search_pattern.insert(cursor, c);
                 self.search_pattern_cursor = cursor + 1;
+                self.pattern_edited();
                 return None;
             }
         
@@ -384,6 +552,7 @@ impl FindDialog {
                     Action::Escape => return Some(Action::DialogClose),
                     Action::Enter => {
                         if self.active_field == FindDialogField::ActionsRow || self.active_field == FindDialogField::Pattern {
+                            self.record_history();
                             match self.action_selected {
                                 FindActionSelected::FindNext => {
                                     return Some(Action::FindNext {
@@ -410,10 +579,14 @@ impl FindDialog {
                         }
                     }
                     Action::Up => {
-                        self.active_field = self.prev_field();
+                        if !(self.active_field == FindDialogField::Pattern && self.recall_older()) {
+                            self.active_field = self.prev_field();
+                        }
                     }
                     Action::Down => {
-                        self.active_field = self.next_field();
+                        if !(self.active_field == FindDialogField::Pattern && self.recall_newer()) {
+                            self.active_field = self.next_field();
+                        }
                     }
                     Action::Left => {
                         if self.active_field == FindDialogField::Pattern {
@@ -428,20 +601,7 @@ impl FindDialog {
                                 FindAll => Count,
                             };
                         } else {
-                            use FindDialogField::*;
-                            match self.active_field {
-                                Backward => self.options.backward = !self.options.backward,
-                                WholeWord => self.options.whole_word = !self.options.whole_word,
-                                MatchCase => self.options.match_case = !self.options.match_case,
-                                WrapAround => self.options.wrap_around = !self.options.wrap_around,
-                                SearchMode => {
-                                    self.search_mode = match self.search_mode {
-                                        Normal => Regex,
-                                        Regex => Normal,
-                                    }
-                                }
-                                _ => {}
-                            }
+                            self.toggle_active_field();
                         }
                     }
                     Action::Right => {
@@ -457,20 +617,7 @@ impl FindDialog {
                                 FindAll => FindNext,
                             };
                         } else {
-                            use FindDialogField::*;
-                            match self.active_field {
-                                Backward => self.options.backward = !self.options.backward,
-                                WholeWord => self.options.whole_word = !self.options.whole_word,
-                                MatchCase => self.options.match_case = !self.options.match_case,
-                                WrapAround => self.options.wrap_around = !self.options.wrap_around,
-                                SearchMode => {
-                                    self.search_mode = match self.search_mode {
-                                        Normal => Regex,
-                                        Regex => Normal,
-                                    }
-                                }
-                                _ => {}
-                            }
+                            self.toggle_active_field();
                         }
                     }
                     Action::Backspace => {
@@ -481,6 +628,7 @@ impl FindDialog {
                             chars.remove(cursor - 1);
                             self.search_pattern = chars.into_iter().collect();
                             self.search_pattern_cursor -= 1;
+                            self.pattern_edited();
                         }
                     }
                     Action::ToggleInstructions => {
@@ -498,20 +646,7 @@ impl FindDialog {
                         return None;
                     }
                     Action::ToggleSpace => {
-                        use FindDialogField::*;
-                        match self.active_field {
-                            Backward => self.options.backward = !self.options.backward,
-                            WholeWord => self.options.whole_word = !self.options.whole_word,
-                            MatchCase => self.options.match_case = !self.options.match_case,
-                            WrapAround => self.options.wrap_around = !self.options.wrap_around,
-                            SearchMode => {
-                                self.search_mode = match self.search_mode {
-                                    Normal => Regex,
-                                    Regex => Normal,
-                                }
-                            }
-                            _ => {}
-                        }
+                        self.toggle_active_field();
                         return None;
                     }
                     Action::Delete => {
@@ -521,6 +656,7 @@ impl FindDialog {
                                 let mut chars: Vec<char> = self.search_pattern.chars().collect();
                                 chars.remove(cursor);
                                 self.search_pattern = chars.into_iter().collect();
+                                self.pattern_edited();
                             }
                         }
                         return None;
@@ -560,4 +696,57 @@ impl Component for FindDialog {
         self.render(area, frame.buffer_mut());
         Ok(())
     }
-} 
\ No newline at end of file
+} 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    #[test]
+    fn test_incremental_search_waits_for_pause_in_typing() {
+        let mut dialog = FindDialog::new();
+        dialog.config.reset_keybindings_to_default();
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE));
+        assert!(dialog.take_incremental_search(Instant::now() + INCREMENTAL_FIND_DELAY).is_none());
+
+        dialog.active_field = FindDialogField::Incremental;
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE));
+        assert!(dialog.incremental);
+        dialog.active_field = FindDialogField::Pattern;
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE));
+        assert!(dialog.take_incremental_search(Instant::now()).is_none());
+        let later = Instant::now() + INCREMENTAL_FIND_DELAY;
+        let (pattern, _, search_mode) = dialog.take_incremental_search(later).unwrap();
+        assert_eq!((pattern.as_str(), search_mode), ("ad", SearchMode::Normal));
+        assert!(dialog.take_incremental_search(later).is_none());
+    }
+
+    #[test]
+    fn test_history_recalls_pattern_and_options() {
+        let mut dialog = FindDialog::new();
+        dialog.config.reset_keybindings_to_default();
+        let press = |dialog: &mut FindDialog, code: KeyCode| dialog.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE));
+        for (pattern, match_case) in [("error", false), ("Warn", true)] {
+            dialog.search_pattern = pattern.to_string();
+            dialog.options.match_case = match_case;
+            assert!(matches!(press(&mut dialog, KeyCode::Enter), Some(Action::FindNext { .. })));
+        }
+        assert_eq!(dialog.history.len(), 2);
+
+        dialog.search_pattern = "draft".to_string();
+        dialog.options.match_case = false;
+        press(&mut dialog, KeyCode::Up);
+        assert_eq!((dialog.search_pattern.as_str(), dialog.options.match_case), ("Warn", true));
+        press(&mut dialog, KeyCode::Up);
+        press(&mut dialog, KeyCode::Up);
+        assert_eq!((dialog.search_pattern.as_str(), dialog.options.match_case), ("error", false));
+        press(&mut dialog, KeyCode::Down);
+        press(&mut dialog, KeyCode::Down);
+        assert_eq!(dialog.search_pattern, "draft");
+        assert_eq!(dialog.active_field, FindDialogField::Pattern);
+        // Searching again moves the entry to the front rather than repeating it
+        dialog.search_pattern = "error".to_string();
+        press(&mut dialog, KeyCode::Enter);
+        assert_eq!(dialog.history.iter().map(|e| e.pattern.as_str()).collect::<Vec<_>>(), vec!["error", "Warn"]);
+    }
+}
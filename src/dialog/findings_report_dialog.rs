@@ -0,0 +1,263 @@
+//! FindingsReportDialog: title and summarise a case report of the dataset's findings, then save it
+//! as Markdown or HTML through the file browser
+
+use crossterm::event::{KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Clear, Paragraph, Wrap};
+use tui_textarea::TextArea;
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+use crate::dataframe::findings_report::{render_report, Findings, ReportFormat, ReportOptions};
+use crate::dialog::file_browser_dialog::{FileBrowserAction, FileBrowserDialog, FileBrowserMode};
+
+const FIELD_TITLE: usize = 0;
+const FIELD_SUMMARY: usize = 1;
+const FIELD_FORMAT: usize = 2;
+const FIELD_COUNT: usize = 3;
+
+fn text_input(placeholder: &str) -> TextArea<'static> {
+    let mut t = TextArea::default();
+    t.set_block(Block::default());
+    t.set_placeholder_text(placeholder.to_string());
+    t
+}
+
+#[derive(Debug)]
+pub enum FindingsReportDialogMode {
+    Edit,
+    FileBrowser(Box<FileBrowserDialog>),
+    /// Result of the last save, shown until dismissed
+    Notice(String),
+}
+
+/// FindingsReportDialog: the findings are gathered by the container when the dialog opens
+#[derive(Debug)]
+pub struct FindingsReportDialog {
+    pub findings: Findings,
+    pub title_input: TextArea<'static>,
+    pub summary_input: TextArea<'static>,
+    pub format: ReportFormat,
+    pub selected_field: usize,
+    pub mode: FindingsReportDialogMode,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+impl FindingsReportDialog {
+    pub fn new(findings: Findings) -> Self {
+        let title_input = text_input(&format!("Findings: {}", findings.dataset_name));
+        Self {
+            findings,
+            title_input,
+            summary_input: text_input("what was found, scope, next steps"),
+            format: ReportFormat::default(),
+            selected_field: FIELD_TITLE,
+            mode: FindingsReportDialogMode::Edit,
+            show_instructions: true,
+            config: Config::default(),
+        }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    pub fn options(&self) -> ReportOptions {
+        ReportOptions {
+            title: self.title_input.lines().join(" ").trim().to_string(),
+            summary: self.summary_input.lines().join("\n"),
+            format: self.format,
+        }
+    }
+
+    fn write_report(&mut self, path: &std::path::Path) {
+        let report = render_report(&self.findings, &self.options(), chrono::Utc::now());
+        self.mode = FindingsReportDialogMode::Notice(match std::fs::write(path, report) {
+            Ok(()) => format!("Report written to {}", path.display()),
+            Err(e) => format!("Failed to write {}: {e}", path.display()),
+        });
+    }
+
+    fn open_file_browser(&mut self) {
+        let extension = self.format.extension();
+        let mut browser = FileBrowserDialog::new(None, Some(vec![extension]), false, FileBrowserMode::Save);
+        browser.register_config_handler(self.config.clone());
+        browser.filename_input = format!("{}_report.{extension}", self.findings.dataset_name);
+        browser.filename_cursor = browser.filename_input.len();
+        self.mode = FindingsReportDialogMode::FileBrowser(Box::new(browser));
+    }
+
+    fn build_instructions_from_config(&self) -> String {
+        format!(
+            "{}  Left/Right: format",
+            self.config.actions_to_instructions(&[
+                (Mode::FindingsReportDialog, Action::ExportFindingsReport),
+                (Mode::Global, Action::Up),
+                (Mode::Global, Action::Down),
+                (Mode::Global, Action::Escape),
+                (Mode::Global, Action::ToggleInstructions),
+            ])
+        )
+    }
+
+    /// Render the dialog
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        if let FindingsReportDialogMode::FileBrowser(browser) = &self.mode {
+            browser.render(area, buf);
+            return;
+        }
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title(format!("Findings Report - {}", self.findings.dataset_name))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let content = layout.content_area;
+
+        if let FindingsReportDialogMode::Notice(msg) = &self.mode {
+            Paragraph::new(format!("{msg}\n\nPress Enter or Esc to continue."))
+                .wrap(Wrap { trim: true })
+                .render(content, buf);
+        } else {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(3), Constraint::Min(4), Constraint::Length(3), Constraint::Length(2)])
+                .split(content);
+            let border = |field: usize, title: String| {
+                let style = if self.selected_field == field { Style::default().fg(Color::Yellow) } else { Style::default() };
+                Block::default().title(title).borders(Borders::ALL).border_style(style)
+            };
+            for (field, input, title) in [(FIELD_TITLE, &self.title_input, "Title"), (FIELD_SUMMARY, &self.summary_input, "Summary")] {
+                let block = border(field, title.to_string());
+                let inner = block.inner(chunks[field]);
+                block.render(chunks[field], buf);
+                let mut ta = input.clone();
+                if self.selected_field != field {
+                    ta.set_cursor_style(Style::default());
+                }
+                ta.render(inner, buf);
+            }
+            let block = border(FIELD_FORMAT, "Format".to_string());
+            Paragraph::new(format!("< {} >", self.format.label())).block(block).render(chunks[2], buf);
+            let findings = &self.findings;
+            Paragraph::new(format!(
+                "{} tagged rows, {} SQL queries, {} transforms{}",
+                findings.tagged_rows.rows.len() + findings.tagged_rows.omitted,
+                findings.sql_queries.len(),
+                findings.history.len(),
+                if findings.filter.is_some() { ", filtered view" } else { "" }
+            ))
+            .style(Style::default().fg(Color::DarkGray))
+            .render(chunks[3], buf);
+        }
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Handle keyboard events for the dialog
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        if let FindingsReportDialogMode::FileBrowser(browser) = &mut self.mode {
+            match browser.handle_key_event(key) {
+                Some(FileBrowserAction::Selected(path)) => self.write_report(&path),
+                Some(FileBrowserAction::Cancelled) => self.mode = FindingsReportDialogMode::Edit,
+                None => {}
+            }
+            return None;
+        }
+
+        let global_action = self.config.action_for_key(Mode::Global, key);
+        if let FindingsReportDialogMode::Notice(_) = self.mode {
+            if matches!(global_action, Some(Action::Escape | Action::Enter)) {
+                self.mode = FindingsReportDialogMode::Edit;
+            }
+            return None;
+        }
+
+        if let Some(Action::ExportFindingsReport) = self.config.action_for_key(Mode::FindingsReportDialog, key) {
+            self.open_file_browser();
+            return None;
+        }
+        match global_action {
+            Some(Action::Escape) => return Some(Action::DialogClose),
+            // Enter starts a new line in the summary and saves from the other fields
+            Some(Action::Enter) if self.selected_field != FIELD_SUMMARY => {
+                self.open_file_browser();
+                return None;
+            }
+            Some(Action::Up) => {
+                self.selected_field = self.selected_field.saturating_sub(1);
+                return None;
+            }
+            Some(Action::Down | Action::Tab) => {
+                self.selected_field = (self.selected_field + 1).min(FIELD_COUNT - 1);
+                return None;
+            }
+            Some(Action::Left | Action::Right) if self.selected_field == FIELD_FORMAT => {
+                self.format = self.format.toggle();
+                return None;
+            }
+            Some(Action::ToggleInstructions) => {
+                self.show_instructions = !self.show_instructions;
+                return None;
+            }
+            _ => {}
+        }
+        let input = match self.selected_field {
+            FIELD_TITLE => &mut self.title_input,
+            FIELD_SUMMARY => &mut self.summary_input,
+            _ => return None,
+        };
+        input.input(tui_textarea::Input::from(key));
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    fn press(dialog: &mut FindingsReportDialog, code: KeyCode) {
+        dialog.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE));
+    }
+
+    #[test]
+    fn test_summary_lines_and_save() {
+        let findings = Findings { dataset_name: "logs".to_string(), ..Findings::default() };
+        let mut dialog = FindingsReportDialog::new(findings);
+        dialog.config.reset_keybindings_to_default();
+        press(&mut dialog, KeyCode::Down);
+        for code in [KeyCode::Char('a'), KeyCode::Enter, KeyCode::Char('b')] {
+            press(&mut dialog, code);
+        }
+        press(&mut dialog, KeyCode::Down);
+        press(&mut dialog, KeyCode::Right);
+        assert_eq!(dialog.options(), ReportOptions { title: String::new(), summary: "a\nb".to_string(), format: ReportFormat::Html });
+
+        press(&mut dialog, KeyCode::Enter);
+        assert!(matches!(dialog.mode, FindingsReportDialogMode::FileBrowser(_)));
+        let path = std::env::temp_dir().join(format!("datatui_report_{}.html", std::process::id()));
+        dialog.write_report(&path);
+        let written = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert!(written.contains("<h1>Findings: logs</h1>"));
+        assert!(matches!(dialog.mode, FindingsReportDialogMode::Notice(_)));
+    }
+}
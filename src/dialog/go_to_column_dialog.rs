@@ -0,0 +1,165 @@
+//! GoToColumnDialog: move the selection to a column by typing part of its name
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Cell, Clear, Paragraph, Row, Table, TableState, Wrap};
+
+use crate::action::Action;
+use crate::components::column_search::rank_columns;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+
+#[derive(Debug)]
+pub struct GoToColumnDialog {
+    /// Visible columns in table order
+    pub columns: Vec<String>,
+    pub query: String,
+    /// Indices into `columns` matching the query, best match first
+    pub matches: Vec<usize>,
+    pub selected: usize,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+impl GoToColumnDialog {
+    /// `current` is the selected column, listed under the cursor until something is typed
+    pub fn new(columns: Vec<String>, current: usize) -> Self {
+        let matches = (0..columns.len()).collect();
+        Self {
+            columns,
+            query: String::new(),
+            matches,
+            selected: current,
+            show_instructions: true,
+            config: Config::default(),
+        }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn update_matches(&mut self) {
+        self.matches = if self.query.is_empty() {
+            (0..self.columns.len()).collect()
+        } else {
+            rank_columns(&self.query, &self.columns)
+        };
+        self.selected = 0;
+    }
+
+    fn build_instructions_from_config(&self) -> String {
+        format!(
+            "Type to filter  {}",
+            self.config.actions_to_instructions(&[
+                (Mode::Global, Action::Enter),
+                (Mode::Global, Action::Escape),
+                (Mode::Global, Action::ToggleInstructions),
+            ])
+        )
+    }
+
+    /// Render the dialog
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title("Go to Column")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(1)])
+            .split(layout.content_area);
+        Paragraph::new(format!("{}_", self.query))
+            .block(Block::default().borders(Borders::ALL).title(format!("{} of {} columns", self.matches.len(), self.columns.len())))
+            .render(chunks[0], buf);
+
+        let rows = self.matches.iter().map(|&i| {
+            Row::new(vec![
+                Cell::from(format!("{}", i + 1)).style(Style::default().fg(Color::DarkGray)),
+                Cell::from(self.columns[i].as_str()),
+            ])
+        });
+        let table = Table::new(rows, [Constraint::Length(6), Constraint::Min(10)])
+            .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        let mut state = TableState::default().with_selected(Some(self.selected));
+        StatefulWidget::render(table, chunks[1], buf, &mut state);
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Handle keyboard events; Enter returns `GoToColumn` with the index into `columns`
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        match self.config.action_for_key(Mode::Global, key) {
+            Some(Action::Escape) => return Some(Action::DialogClose),
+            Some(Action::Enter) => return self.matches.get(self.selected).map(|&i| Action::GoToColumn(i)),
+            Some(Action::Up) => {
+                self.selected = self.selected.saturating_sub(1);
+                return None;
+            }
+            Some(Action::Down) => {
+                if self.selected + 1 < self.matches.len() {
+                    self.selected += 1;
+                }
+                return None;
+            }
+            Some(Action::ToggleInstructions) => {
+                self.show_instructions = !self.show_instructions;
+                return None;
+            }
+            Some(Action::Backspace) => {
+                self.query.pop();
+                self.update_matches();
+                return None;
+            }
+            _ => {}
+        }
+        if let KeyCode::Char(c) = key.code
+            && !key.modifiers.intersects(crossterm::event::KeyModifiers::CONTROL | crossterm::event::KeyModifiers::ALT)
+        {
+            self.query.push(c);
+            self.update_matches();
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    #[test]
+    fn test_filter_and_go_to_column() {
+        let columns: Vec<String> = ["event_id", "source_ip", "dest_ip", "timestamp"].iter().map(|s| s.to_string()).collect();
+        let mut dialog = GoToColumnDialog::new(columns, 1);
+        dialog.config.reset_keybindings_to_default();
+        assert_eq!(dialog.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)), Some(Action::GoToColumn(1)));
+
+        for c in "sip".chars() {
+            dialog.handle_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        assert_eq!(dialog.matches, vec![1, 2]);
+        assert_eq!(dialog.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)), Some(Action::GoToColumn(1)));
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE));
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE));
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Char('t'), KeyModifiers::NONE));
+        assert_eq!(dialog.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)), Some(Action::GoToColumn(2)));
+    }
+}
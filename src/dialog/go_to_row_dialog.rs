@@ -0,0 +1,167 @@
+//! GoToRowDialog: jump to a row of the current view by number, percentage or offset
+
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Clear, Paragraph, Wrap};
+use tui_textarea::TextArea;
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+
+/// 0-based row for a target typed as a 1-based row number (`1200`), a percentage of the view
+/// (`50%`) or an offset from `current` (`+100`, `-100`). Targets past the end are clamped.
+pub fn parse_target(text: &str, current: usize, total: usize) -> Result<usize> {
+    let text = text.trim().replace(['_', ','], "");
+    if total == 0 {
+        return Err(eyre!("The view has no rows"));
+    }
+    let last = total - 1;
+    let number = |s: &str| s.trim().parse::<u64>().map_err(|_| eyre!("Type a row number, a percentage like 50% or an offset like +100"));
+    let row = if let Some(percent) = text.strip_suffix('%') {
+        let percent: f64 = percent.trim().parse().map_err(|_| eyre!("'{}' is not a percentage", text))?;
+        if !(0.0..=100.0).contains(&percent) {
+            return Err(eyre!("Percentage must be between 0 and 100"));
+        }
+        (percent / 100.0 * last as f64).round() as usize
+    } else if let Some(offset) = text.strip_prefix('+') {
+        current.saturating_add(number(offset)? as usize)
+    } else if let Some(offset) = text.strip_prefix('-') {
+        current.saturating_sub(number(offset)? as usize)
+    } else {
+        (number(&text)? as usize).saturating_sub(1)
+    };
+    Ok(row.min(last))
+}
+
+/// GoToRowDialog: returns GoToRow with the 0-based target row
+#[derive(Debug)]
+pub struct GoToRowDialog {
+    pub input: TextArea<'static>,
+    /// Selected row, 0-based
+    pub current: usize,
+    /// Rows in the view
+    pub total: usize,
+    pub error: Option<String>,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+impl GoToRowDialog {
+    pub fn new(current: usize, total: usize) -> Self {
+        let mut input = TextArea::default();
+        input.set_block(Block::default());
+        input.set_placeholder_text("row, 50% or +100");
+        Self { input, current, total, error: None, show_instructions: true, config: Config::default() }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn build_instructions_from_config(&self) -> String {
+        self.config.actions_to_instructions(&[
+            (Mode::Global, Action::Enter),
+            (Mode::Global, Action::Escape),
+            (Mode::Global, Action::ToggleInstructions),
+        ])
+    }
+
+    /// Render the dialog
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title("Go to Row")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(1)])
+            .split(layout.content_area);
+
+        let block = Block::default()
+            .title(format!("Row {} of {}", self.current + 1, self.total))
+            .borders(Borders::ALL);
+        let inner = block.inner(chunks[0]);
+        block.render(chunks[0], buf);
+        self.input.render(inner, buf);
+
+        let status = match &self.error {
+            Some(error) => Line::styled(format!("Error: {error}"), Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+            None => Line::styled("1200 goes to row 1200, 50% to the middle, +100 or -100 moves from the selected row", Style::default().fg(Color::DarkGray)),
+        };
+        Paragraph::new(status).wrap(Wrap { trim: true }).render(chunks[1], buf);
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Handle keyboard events for the dialog
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        match self.config.action_for_key(Mode::Global, key) {
+            Some(Action::Escape) => Some(Action::DialogClose),
+            Some(Action::Enter) => match parse_target(&self.input.lines().join(""), self.current, self.total) {
+                Ok(row) => Some(Action::GoToRow(row)),
+                Err(e) => {
+                    self.error = Some(e.to_string());
+                    None
+                }
+            },
+            Some(Action::ToggleInstructions) => {
+                self.show_instructions = !self.show_instructions;
+                None
+            }
+            _ => {
+                if matches!(key.code, KeyCode::Char(_) | KeyCode::Backspace | KeyCode::Delete | KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End) {
+                    self.input.input(tui_textarea::Input::from(key));
+                    self.error = None;
+                }
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    #[test]
+    fn test_parse_targets_and_enter() {
+        assert_eq!(parse_target("1", 5, 1000).unwrap(), 0);
+        assert_eq!(parse_target("1_200", 5, 10_000_000).unwrap(), 1199);
+        assert_eq!(parse_target("5000", 5, 1000).unwrap(), 999);
+        assert_eq!(parse_target("50%", 5, 1001).unwrap(), 500);
+        assert_eq!(parse_target("100 %", 5, 1001).unwrap(), 1000);
+        assert_eq!(parse_target("+10", 5, 1000).unwrap(), 15);
+        assert_eq!(parse_target("-10", 5, 1000).unwrap(), 0);
+        assert!(parse_target("150%", 5, 1000).is_err());
+        assert!(parse_target("abc", 5, 1000).is_err());
+        assert!(parse_target("1", 0, 0).is_err());
+
+        let mut dialog = GoToRowDialog::new(0, 20);
+        dialog.config.reset_keybindings_to_default();
+        for c in "25%".chars() {
+            dialog.handle_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        assert_eq!(dialog.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)), Some(Action::GoToRow(5)));
+    }
+}
@@ -0,0 +1,223 @@
+use crossterm::event::{KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Clear, Paragraph, Wrap};
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+
+/// GroupByDialog: chooses the columns the table is grouped by, in order, and an optional
+/// aggregate column. Enter switches the table to the group view.
+#[derive(Debug)]
+pub struct GroupByDialog {
+    pub columns: Vec<String>,
+    pub group_columns: Vec<String>,
+    pub aggregate_column: Option<String>,
+    pub column_cursor: usize,
+    pub visible_rows: usize,
+    /// Why the last grouping could not be computed
+    pub error: Option<String>,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+impl Default for GroupByDialog {
+    fn default() -> Self { Self::new() }
+}
+
+impl GroupByDialog {
+    pub fn new() -> Self {
+        Self {
+            columns: Vec::new(),
+            group_columns: Vec::new(),
+            aggregate_column: None,
+            column_cursor: 0,
+            visible_rows: 5,
+            error: None,
+            show_instructions: true,
+            config: Config::default(),
+        }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    /// Set available columns; the column at `current_index` starts as the first group key
+    /// if no group keys were chosen previously (or they no longer exist).
+    pub fn set_columns(&mut self, columns: Vec<String>, current_index: usize) {
+        self.group_columns.retain(|c| columns.contains(c));
+        if let Some(agg) = &self.aggregate_column
+            && !columns.contains(agg)
+        {
+            self.aggregate_column = None;
+        }
+        self.column_cursor = current_index.min(columns.len().saturating_sub(1));
+        if self.group_columns.is_empty()
+            && let Some(col) = columns.get(self.column_cursor)
+        {
+            self.group_columns.push(col.clone());
+        }
+        self.columns = columns;
+        self.error = None;
+    }
+
+    /// Build instructions string from configured keybindings
+    fn build_instructions_from_config(&self) -> String {
+        self.config.actions_to_instructions(&[
+            (Mode::Global, Action::Enter),
+            (Mode::Global, Action::Escape),
+            (Mode::GroupBy, Action::ToggleGroupColumn),
+            (Mode::GroupBy, Action::SetAggregateColumn),
+            (Mode::Global, Action::ToggleInstructions),
+        ])
+    }
+
+    /// Render the dialog
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title("Group By")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions,
+            if instructions.is_empty() { None } else { Some(instructions.as_str()) });
+
+        self.render_column_select(layout.content_area, buf);
+
+        if let Some(instructions_area) = layout.instructions_area {
+            let instructions_paragraph = Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true });
+            instructions_paragraph.render(instructions_area, buf);
+        }
+    }
+
+    fn render_column_select(&mut self, area: Rect, buf: &mut Buffer) {
+        let block = Block::default()
+            .title("Select Group Columns")
+            .borders(Borders::ALL);
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let mut max_rows = inner.height as usize;
+        if let Some(err) = &self.error
+            && max_rows > 0
+        {
+            max_rows -= 1;
+            buf.set_stringn(inner.x, inner.y + max_rows as u16, err, inner.width as usize, Style::default().fg(Color::Red));
+        }
+        self.visible_rows = max_rows;
+        let start = if self.column_cursor >= max_rows { self.column_cursor + 1 - max_rows } else { 0 };
+        for (i, name) in self.columns.iter().enumerate().skip(start).take(max_rows) {
+            let y = inner.y + (i - start) as u16;
+            let marker = match self.group_columns.iter().position(|c| c == name) {
+                Some(level) => format!("[{}]", level + 1),
+                None => "[ ]".to_string(),
+            };
+            let agg = if self.aggregate_column.as_deref() == Some(name.as_str()) { "  (aggregate)" } else { "" };
+            let style = if i == self.column_cursor {
+                Style::default().fg(Color::Black).bg(Color::Cyan).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            let line = format!("{marker} {name}{agg}");
+            buf.set_stringn(inner.x, y, line, inner.width as usize, style);
+        }
+    }
+
+    fn move_cursor(&mut self, action: Action) {
+        if action == Action::Up {
+            self.column_cursor = self.column_cursor.saturating_sub(1);
+        } else if self.column_cursor + 1 < self.columns.len() {
+            self.column_cursor += 1;
+        }
+    }
+
+    /// Handle keyboard events for the dialog
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        if let Some(global_action) = self.config.action_for_key(Mode::Global, key) {
+            match global_action {
+                Action::Escape => return Some(Action::DialogClose),
+                Action::Enter => {
+                    if self.group_columns.is_empty() {
+                        return None;
+                    }
+                    return Some(Action::GroupByDialogApplied {
+                        group_columns: self.group_columns.clone(),
+                        aggregate_column: self.aggregate_column.clone(),
+                    });
+                }
+                Action::Up | Action::Down => {
+                    self.move_cursor(global_action);
+                    return None;
+                }
+                Action::ToggleInstructions => {
+                    self.show_instructions = !self.show_instructions;
+                    return None;
+                }
+                _ => {}
+            }
+        }
+        if let Some(dialog_action) = self.config.action_for_key(Mode::GroupBy, key) {
+            let name = self.columns.get(self.column_cursor).cloned()?;
+            match dialog_action {
+                // Letter keys from a keymap profile (vim's j/k)
+                Action::Up | Action::Down => self.move_cursor(dialog_action),
+                Action::ToggleGroupColumn => {
+                    if let Some(pos) = self.group_columns.iter().position(|c| *c == name) {
+                        self.group_columns.remove(pos);
+                    } else {
+                        self.group_columns.push(name);
+                    }
+                }
+                Action::SetAggregateColumn => {
+                    if self.aggregate_column.as_deref() == Some(name.as_str()) {
+                        self.aggregate_column = None;
+                    } else {
+                        self.aggregate_column = Some(name);
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    #[test]
+    fn test_select_columns_and_apply() {
+        let mut dialog = GroupByDialog::new();
+        dialog.config.reset_keybindings_to_default();
+        dialog.set_columns(vec!["host".into(), "user".into(), "bytes".into()], 0);
+        assert_eq!(dialog.group_columns, ["host"]);
+
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE));
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL));
+        let action = dialog.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(
+            action,
+            Some(Action::GroupByDialogApplied {
+                group_columns: vec!["host".to_string(), "user".to_string()],
+                aggregate_column: Some("bytes".to_string()),
+            })
+        );
+    }
+}
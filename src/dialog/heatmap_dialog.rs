@@ -0,0 +1,270 @@
+//! HeatmapDialog: Enable numeric heatmap shading for a column with optional fixed bounds
+
+use std::collections::HashMap;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Clear, Paragraph, Wrap};
+use serde::{Deserialize, Serialize};
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+
+/// Heatmap settings for a single column. Missing bounds are computed from the data.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct HeatmapColumnConfig {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+}
+
+/// Per-column heatmap shading configuration (column name -> settings)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HeatmapConfig {
+    pub columns: HashMap<String, HeatmapColumnConfig>,
+}
+
+impl HeatmapConfig {
+    pub fn is_enabled(&self, column: &str) -> bool {
+        self.columns.contains_key(column)
+    }
+
+    pub fn get(&self, column: &str) -> Option<&HeatmapColumnConfig> {
+        self.columns.get(column)
+    }
+
+    pub fn set(&mut self, column: impl Into<String>, config: Option<HeatmapColumnConfig>) {
+        let column = column.into();
+        match config {
+            Some(c) => { self.columns.insert(column, c); }
+            None => { self.columns.remove(&column); }
+        }
+    }
+}
+
+/// Which bound input currently has focus
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeatmapDialogField {
+    Min,
+    Max,
+}
+
+/// HeatmapDialog: edit heatmap bounds for the selected column
+#[derive(Debug)]
+pub struct HeatmapDialog {
+    pub column: String,
+    pub enabled: bool,
+    pub min_input: String,
+    pub max_input: String,
+    pub data_bounds: Option<(f64, f64)>,
+    pub field: HeatmapDialogField,
+    pub error: Option<String>,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+impl HeatmapDialog {
+    /// Create a dialog for `column`, pre-filled from its existing heatmap settings
+    pub fn new(column: String, existing: Option<HeatmapColumnConfig>, data_bounds: Option<(f64, f64)>) -> Self {
+        let fmt_bound = |b: Option<f64>| b.map(|v| v.to_string()).unwrap_or_default();
+        Self {
+            column,
+            enabled: existing.is_some(),
+            min_input: fmt_bound(existing.and_then(|c| c.min)),
+            max_input: fmt_bound(existing.and_then(|c| c.max)),
+            data_bounds,
+            field: HeatmapDialogField::Min,
+            error: None,
+            show_instructions: true,
+            config: Config::default(),
+        }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn parse_bound(input: &str, label: &str) -> Result<Option<f64>, String> {
+        let trimmed = input.trim();
+        if trimmed.is_empty() {
+            return Ok(None);
+        }
+        trimmed
+            .parse::<f64>()
+            .map(Some)
+            .map_err(|_| format!("{label} bound is not a number: {trimmed}"))
+    }
+
+    /// Parse the inputs into a column config, validating that min < max when both are set
+    pub fn build_config(&self) -> Result<HeatmapColumnConfig, String> {
+        let min = Self::parse_bound(&self.min_input, "Min")?;
+        let max = Self::parse_bound(&self.max_input, "Max")?;
+        if let (Some(lo), Some(hi)) = (min, max)
+            && lo >= hi
+        {
+            return Err("Min bound must be less than max bound".to_string());
+        }
+        Ok(HeatmapColumnConfig { min, max })
+    }
+
+    fn active_input(&mut self) -> &mut String {
+        match self.field {
+            HeatmapDialogField::Min => &mut self.min_input,
+            HeatmapDialogField::Max => &mut self.max_input,
+        }
+    }
+
+    /// Build instructions string from configured keybindings
+    fn build_instructions_from_config(&self) -> String {
+        self.config.actions_to_instructions(&[
+            (Mode::Global, Action::Enter),
+            (Mode::Global, Action::Escape),
+            (Mode::Global, Action::Tab),
+            (Mode::HeatmapDialog, Action::DisableHeatmap),
+            (Mode::Global, Action::ToggleInstructions),
+        ])
+    }
+
+    /// Render the dialog
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title("Heatmap")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions,
+            if instructions.is_empty() { None } else { Some(instructions.as_str()) });
+
+        let block = Block::default()
+            .title(format!("Column: {}", self.column))
+            .borders(Borders::ALL);
+        let content = block.inner(layout.content_area);
+        block.render(layout.content_area, buf);
+
+        let status = if self.enabled { "Enabled" } else { "Disabled" };
+        let data_range = match self.data_bounds {
+            Some((lo, hi)) => format!("Data range: {lo} .. {hi}"),
+            None => "Data range: no numeric values".to_string(),
+        };
+        let field_line = |label: &str, value: &str, focused: bool| {
+            let style = if focused {
+                Style::default().fg(Color::Black).bg(Color::Cyan)
+            } else {
+                Style::default()
+            };
+            let shown = if value.is_empty() { "<auto>" } else { value };
+            Line::from(vec![
+                Span::raw(format!("{label}: ")),
+                Span::styled(shown.to_string(), style),
+            ])
+        };
+        let mut lines = vec![
+            Line::from(format!("Heatmap: {status}")),
+            Line::from(data_range),
+            Line::from(""),
+            field_line("Min", &self.min_input, self.field == HeatmapDialogField::Min),
+            field_line("Max", &self.max_input, self.field == HeatmapDialogField::Max),
+        ];
+        if let Some(err) = &self.error {
+            lines.push(Line::from(""));
+            lines.push(Line::styled(err.clone(), Style::default().fg(Color::Red)));
+        }
+        Paragraph::new(lines).wrap(Wrap { trim: false }).render(content, buf);
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Handle keyboard events for the dialog
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        if let Some(global_action) = self.config.action_for_key(Mode::Global, key) {
+            match global_action {
+                Action::Escape => return Some(Action::DialogClose),
+                Action::Enter => {
+                    return match self.build_config() {
+                        Ok(cfg) => Some(Action::HeatmapDialogApplied {
+                            column: self.column.clone(),
+                            config: Some(cfg),
+                        }),
+                        Err(e) => {
+                            self.error = Some(e);
+                            None
+                        }
+                    };
+                }
+                Action::Tab | Action::Up | Action::Down => {
+                    self.field = match self.field {
+                        HeatmapDialogField::Min => HeatmapDialogField::Max,
+                        HeatmapDialogField::Max => HeatmapDialogField::Min,
+                    };
+                    return None;
+                }
+                Action::Backspace => {
+                    self.active_input().pop();
+                    self.error = None;
+                    return None;
+                }
+                Action::ToggleInstructions => {
+                    self.show_instructions = !self.show_instructions;
+                    return None;
+                }
+                _ => {}
+            }
+        }
+        if let Some(Action::DisableHeatmap) = self.config.action_for_key(Mode::HeatmapDialog, key) {
+            return Some(Action::HeatmapDialogApplied { column: self.column.clone(), config: None });
+        }
+        if let KeyCode::Char(c) = key.code
+            && (c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E'))
+        {
+            self.active_input().push(c);
+            self.error = None;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    #[test]
+    fn test_build_config_validates_bounds() {
+        let mut dialog = HeatmapDialog::new("count".to_string(), None, Some((1.0, 9.0)));
+        assert_eq!(dialog.build_config().unwrap(), HeatmapColumnConfig::default());
+        dialog.min_input = "10".to_string();
+        dialog.max_input = "5".to_string();
+        assert!(dialog.build_config().is_err());
+        dialog.max_input = "abc".to_string();
+        assert!(dialog.build_config().is_err());
+    }
+
+    #[test]
+    fn test_enter_applies_typed_bounds() {
+        let mut dialog = HeatmapDialog::new("count".to_string(), None, None);
+        dialog.config.reset_keybindings_to_default();
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Char('2'), KeyModifiers::NONE));
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE));
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Char('8'), KeyModifiers::NONE));
+        let action = dialog.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(action, Some(Action::HeatmapDialogApplied {
+            column: "count".to_string(),
+            config: Some(HeatmapColumnConfig { min: Some(2.0), max: Some(8.0) }),
+        }));
+    }
+}
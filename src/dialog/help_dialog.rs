@@ -0,0 +1,282 @@
+//! HelpDialog: every bound action grouped by category, with its keys and a one-line description,
+//! filtered by fuzzy search
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Clear, Paragraph, Wrap};
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{key_event_to_string, ActionCategory, Config, Mode};
+use crate::recent_files::fuzzy_score;
+
+/// One bound action as listed in the help
+#[derive(Debug, Clone, PartialEq)]
+pub struct HelpEntry {
+    pub category: ActionCategory,
+    pub mode: Mode,
+    pub action: Action,
+    pub name: String,
+    /// All key sequences bound to the action in `mode`
+    pub keys: String,
+    pub description: String,
+}
+
+impl HelpEntry {
+    fn search_text(&self) -> String {
+        format!("{} {} {} {:?}", self.name, self.description, self.keys, self.mode)
+    }
+}
+
+/// Bound actions of `config`, sorted by category, mode and name
+pub fn help_entries(config: &Config) -> Vec<HelpEntry> {
+    let mut entries: Vec<HelpEntry> = Vec::new();
+    for (mode, bindings) in config.keybindings.0.iter() {
+        let mut by_action: Vec<(Action, Vec<String>)> = Vec::new();
+        for (keys, action) in bindings.iter() {
+            let keys = keys.iter().map(key_event_to_string).collect::<Vec<_>>().join(" ");
+            match by_action.iter_mut().find(|(a, _)| a == action) {
+                Some((_, all)) => all.push(keys),
+                None => by_action.push((action.clone(), vec![keys])),
+            }
+        }
+        for (action, mut keys) in by_action {
+            keys.sort();
+            let name = match config.action_to_friendly_name(&action) {
+                "Unknown" => action.to_string(),
+                name => name.to_string(),
+            };
+            let description = match config.action_description(&action) {
+                Some(description) => description.to_string(),
+                None => format!("{name} in {mode:?}"),
+            };
+            entries.push(HelpEntry { category: ActionCategory::of(*mode, &action), mode: *mode, action, name, keys: keys.join(", "), description });
+        }
+    }
+    entries.sort_by(|a, b| {
+        (a.category, format!("{:?}", a.mode), &a.name).cmp(&(b.category, format!("{:?}", b.mode), &b.name))
+    });
+    entries
+}
+
+#[derive(Debug)]
+pub struct HelpDialog {
+    pub entries: Vec<HelpEntry>,
+    pub query: String,
+    /// Indices into `entries` matching the query; grouped by category unless searching
+    pub matches: Vec<usize>,
+    pub selected: usize,
+    pub scroll_offset: usize,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+impl Default for HelpDialog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HelpDialog {
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            query: String::new(),
+            matches: Vec::new(),
+            selected: 0,
+            scroll_offset: 0,
+            show_instructions: true,
+            config: Config::default(),
+        }
+    }
+
+    /// Register config handler; the entries are rebuilt from its keybindings
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.entries = help_entries(&config);
+        self.config = config;
+        self.update_matches();
+        Ok(())
+    }
+
+    fn update_matches(&mut self) {
+        let mut scored: Vec<(i64, usize)> = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(i, entry)| fuzzy_score(&self.query, &entry.search_text()).map(|s| (s, i)))
+            .collect();
+        // Best matches first while searching; category order otherwise
+        if !self.query.trim().is_empty() {
+            scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        }
+        self.matches = scored.into_iter().map(|(_, i)| i).collect();
+        self.selected = 0;
+        self.scroll_offset = 0;
+    }
+
+    fn searching(&self) -> bool {
+        !self.query.trim().is_empty()
+    }
+
+    /// Lines of the list: category headers (when not searching) and entries, with the row of
+    /// the selected entry
+    fn list_lines(&self, key_width: usize) -> (Vec<Line<'static>>, usize) {
+        let mut lines = Vec::new();
+        let mut selected_line = 0;
+        let mut category = None;
+        for (n, &i) in self.matches.iter().enumerate() {
+            let entry = &self.entries[i];
+            if !self.searching() && category != Some(entry.category) {
+                category = Some(entry.category);
+                lines.push(Line::styled(
+                    entry.category.label(),
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+                ));
+            }
+            if n == self.selected {
+                selected_line = lines.len();
+            }
+            let style = if n == self.selected { Style::default().add_modifier(Modifier::REVERSED) } else { Style::default() };
+            lines.push(
+                Line::from(vec![
+                    Span::styled(format!("  {:<key_width$}  ", entry.keys), Style::default().fg(Color::Cyan)),
+                    Span::styled(format!("{:<24} ", entry.name), Style::default().add_modifier(Modifier::BOLD)),
+                    Span::raw(entry.description.clone()),
+                ])
+                .style(style),
+            );
+        }
+        (lines, selected_line)
+    }
+
+    fn build_instructions_from_config(&self) -> String {
+        format!(
+            "Type to search  PageUp/PageDown: scroll  {}",
+            self.config.actions_to_instructions(&[
+                (Mode::Global, Action::Escape),
+                (Mode::Global, Action::ToggleInstructions),
+            ])
+        )
+    }
+
+    /// Render the dialog
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title("Help")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(1)])
+            .split(layout.content_area);
+        Paragraph::new(format!("{}_", self.query))
+            .block(Block::default().borders(Borders::ALL).title(format!("Search ({} of {} actions)", self.matches.len(), self.entries.len())))
+            .render(chunks[0], buf);
+
+        let key_width = self.matches.iter().map(|&i| self.entries[i].keys.chars().count()).max().unwrap_or(0).min(24);
+        let (lines, selected_line) = self.list_lines(key_width);
+        let visible = chunks[1].height as usize;
+        if selected_line < self.scroll_offset {
+            // Keep the category header above the first entry in view
+            self.scroll_offset = selected_line.saturating_sub(1);
+        } else if visible > 0 && selected_line >= self.scroll_offset + visible {
+            self.scroll_offset = selected_line + 1 - visible;
+        }
+        if lines.is_empty() {
+            Paragraph::new("No matching actions").style(Style::default().fg(Color::DarkGray)).render(chunks[1], buf);
+        } else {
+            Paragraph::new(lines).scroll((self.scroll_offset as u16, 0)).render(chunks[1], buf);
+        }
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Handle keyboard events for the dialog
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        let last = self.matches.len().saturating_sub(1);
+        match key.code {
+            KeyCode::PageUp => {
+                self.selected = self.selected.saturating_sub(10);
+                return None;
+            }
+            KeyCode::PageDown => {
+                self.selected = (self.selected + 10).min(last);
+                return None;
+            }
+            _ => {}
+        }
+        match self.config.action_for_key(Mode::Global, key) {
+            Some(Action::Escape) => return Some(Action::DialogClose),
+            Some(Action::Up) => {
+                self.selected = self.selected.saturating_sub(1);
+                return None;
+            }
+            Some(Action::Down) => {
+                self.selected = (self.selected + 1).min(last);
+                return None;
+            }
+            Some(Action::ToggleInstructions) => {
+                self.show_instructions = !self.show_instructions;
+                return None;
+            }
+            Some(Action::Backspace) => {
+                self.query.pop();
+                self.update_matches();
+                return None;
+            }
+            _ => {}
+        }
+        if let KeyCode::Char(c) = key.code
+            && !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        {
+            self.query.push(c);
+            self.update_matches();
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entries_and_search() {
+        let mut config = Config::default();
+        config.reset_keybindings_to_default();
+        let mut dialog = HelpDialog::new();
+        dialog.register_config_handler(config).unwrap();
+
+        let sort = dialog.entries.iter().find(|e| e.action == Action::OpenSortDialog).unwrap();
+        assert_eq!(sort.category, ActionCategory::Table);
+        assert_eq!(sort.keys, "ctrl-s");
+        assert!(dialog.entries.windows(2).all(|w| w[0].category <= w[1].category));
+        assert_eq!(dialog.matches.len(), dialog.entries.len());
+
+        for c in "benford".chars() {
+            dialog.handle_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        assert_eq!(dialog.entries[dialog.matches[0]].action, Action::OpenBenfordDialog);
+        let area = Rect::new(0, 0, 100, 20);
+        let mut buf = Buffer::empty(area);
+        dialog.render(area, &mut buf);
+        let text: String = buf.content().iter().map(|c| c.symbol()).collect();
+        assert!(text.contains("Benford's law"));
+        assert_eq!(dialog.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)), Some(Action::DialogClose));
+    }
+}
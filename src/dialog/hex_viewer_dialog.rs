@@ -0,0 +1,309 @@
+//! HexViewerDialog: Hex + ASCII dump of a binary (or hex-encoded string) cell with offset navigation
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Clear, Paragraph, Wrap};
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+
+/// Number of bytes shown on each dump line
+pub const BYTES_PER_LINE: usize = 16;
+
+/// Decode a hex-looking string into bytes.
+///
+/// Accepts an optional `0x` prefix and ignores whitespace, `:` and `-` separators.
+/// Returns None if the string is empty, has an odd number of digits, or contains non-hex characters.
+pub fn parse_hex_string(s: &str) -> Option<Vec<u8>> {
+    let trimmed = s.trim();
+    let trimmed = trimmed
+        .strip_prefix("0x")
+        .or_else(|| trimmed.strip_prefix("0X"))
+        .unwrap_or(trimmed);
+    let digits: Vec<u8> = trimmed
+        .bytes()
+        .filter(|b| !b.is_ascii_whitespace() && !matches!(b, b':' | b'-'))
+        .collect();
+    if digits.is_empty() || !digits.len().is_multiple_of(2) || !digits.iter().all(u8::is_ascii_hexdigit) {
+        return None;
+    }
+    digits
+        .chunks(2)
+        .map(|pair| u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok())
+        .collect()
+}
+
+/// Format one dump line: offset, hex bytes (split in two groups of 8) and printable ASCII
+pub fn format_hex_line(offset: usize, chunk: &[u8]) -> String {
+    let mut hex = String::with_capacity(BYTES_PER_LINE * 3 + 1);
+    for i in 0..BYTES_PER_LINE {
+        if i == BYTES_PER_LINE / 2 {
+            hex.push(' ');
+        }
+        match chunk.get(i) {
+            Some(b) => hex.push_str(&format!("{b:02x} ")),
+            None => hex.push_str("   "),
+        }
+    }
+    let ascii: String = chunk
+        .iter()
+        .map(|&b| if b.is_ascii_graphic() || b == b' ' { b as char } else { '.' })
+        .collect();
+    format!("{offset:08x}  {hex} |{ascii}|")
+}
+
+/// HexViewerDialog: scrollable hex dump of a single cell
+#[derive(Debug)]
+pub struct HexViewerDialog {
+    pub column: String,
+    pub row: usize,
+    pub bytes: Vec<u8>,
+    /// Index of the first dump line shown
+    pub scroll_line: usize,
+    /// Hex offset typed by the user for "go to offset"
+    pub goto_input: String,
+    pub error: Option<String>,
+    pub show_instructions: bool,
+    pub visible_rows: usize,
+    pub config: Config,
+}
+
+impl HexViewerDialog {
+    pub fn new(column: String, row: usize, bytes: Vec<u8>) -> Self {
+        Self {
+            column,
+            row,
+            bytes,
+            scroll_line: 0,
+            goto_input: String::new(),
+            error: None,
+            show_instructions: true,
+            visible_rows: 16,
+            config: Config::default(),
+        }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn total_lines(&self) -> usize {
+        self.bytes.len().div_ceil(BYTES_PER_LINE)
+    }
+
+    fn max_scroll(&self) -> usize {
+        self.total_lines().saturating_sub(self.visible_rows.max(1))
+    }
+
+    /// Scroll so the line containing `offset` is at the top of the view
+    pub fn go_to_offset(&mut self, offset: usize) -> Result<(), String> {
+        if offset >= self.bytes.len() {
+            return Err(format!("Offset 0x{offset:x} is past the end (0x{:x} bytes)", self.bytes.len()));
+        }
+        self.scroll_line = (offset / BYTES_PER_LINE).min(self.max_scroll());
+        Ok(())
+    }
+
+    /// Build instructions string from configured keybindings
+    fn build_instructions_from_config(&self) -> String {
+        let base = self.config.actions_to_instructions(&[
+            (Mode::Global, Action::Escape),
+            (Mode::Global, Action::Enter),
+            (Mode::Global, Action::ToggleInstructions),
+            (Mode::HexViewer, Action::GoToFirst),
+            (Mode::HexViewer, Action::GoToLast),
+            (Mode::HexViewer, Action::PageUp),
+            (Mode::HexViewer, Action::PageDown),
+        ]);
+        if base.is_empty() {
+            "Type a hex offset and press Enter to jump".to_string()
+        } else {
+            format!("Type a hex offset to jump  {base}")
+        }
+    }
+
+    /// Render the dialog
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title(format!("Hex View: {} (row {}, {} bytes)", self.column, self.row + 1, self.bytes.len()))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let content = layout.content_area;
+
+        // Offset input line
+        let goto_area = Rect { height: 3.min(content.height), ..content };
+        let goto_text = match &self.error {
+            Some(err) => Line::styled(err.clone(), Style::default().fg(Color::Red)),
+            None => Line::from(format!("0x{}", self.goto_input)),
+        };
+        Paragraph::new(goto_text)
+            .block(Block::default().borders(Borders::ALL).title("Go To Offset"))
+            .render(goto_area, buf);
+
+        let dump_area = Rect {
+            y: content.y + goto_area.height,
+            height: content.height.saturating_sub(goto_area.height),
+            ..content
+        };
+        let block = Block::default().borders(Borders::ALL);
+        let dump_inner = block.inner(dump_area);
+        block.render(dump_area, buf);
+
+        self.visible_rows = (dump_inner.height as usize).max(1);
+        self.scroll_line = self.scroll_line.min(self.max_scroll());
+
+        if self.bytes.is_empty() {
+            Paragraph::new("(empty)")
+                .style(Style::default().fg(Color::DarkGray))
+                .render(dump_inner, buf);
+        } else {
+            let end_line = (self.scroll_line + self.visible_rows).min(self.total_lines());
+            for (i, line_idx) in (self.scroll_line..end_line).enumerate() {
+                let start = line_idx * BYTES_PER_LINE;
+                let end = (start + BYTES_PER_LINE).min(self.bytes.len());
+                let text = format_hex_line(start, &self.bytes[start..end]);
+                let (offset, rest) = text.split_at(8);
+                let line = Line::from(vec![
+                    Span::styled(offset.to_string(), Style::default().fg(Color::Yellow)),
+                    Span::raw(rest.to_string()),
+                ]);
+                buf.set_line(dump_inner.x, dump_inner.y + i as u16, &line, dump_inner.width);
+            }
+        }
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Handle keyboard events for the dialog
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        if let Some(global_action) = self.config.action_for_key(Mode::Global, key) {
+            match global_action {
+                Action::Escape => {
+                    if self.goto_input.is_empty() && self.error.is_none() {
+                        return Some(Action::DialogClose);
+                    }
+                    self.goto_input.clear();
+                    self.error = None;
+                    return None;
+                }
+                Action::Enter => {
+                    if self.goto_input.is_empty() {
+                        return None;
+                    }
+                    let result = usize::from_str_radix(&self.goto_input, 16)
+                        .map_err(|_| format!("Invalid offset: 0x{}", self.goto_input))
+                        .and_then(|offset| self.go_to_offset(offset));
+                    match result {
+                        Ok(()) => self.goto_input.clear(),
+                        Err(e) => self.error = Some(e),
+                    }
+                    return None;
+                }
+                Action::Up => {
+                    self.scroll_line = self.scroll_line.saturating_sub(1);
+                    return None;
+                }
+                Action::Down => {
+                    self.scroll_line = (self.scroll_line + 1).min(self.max_scroll());
+                    return None;
+                }
+                Action::Backspace => {
+                    self.goto_input.pop();
+                    self.error = None;
+                    return None;
+                }
+                Action::ToggleInstructions => {
+                    self.show_instructions = !self.show_instructions;
+                    return None;
+                }
+                _ => {}
+            }
+        }
+        if let Some(dialog_action) = self.config.action_for_key(Mode::HexViewer, key) {
+            let page_size = self.visible_rows.saturating_sub(1).max(1);
+            match dialog_action {
+                Action::GoToFirst => {
+                    self.scroll_line = 0;
+                    return None;
+                }
+                Action::GoToLast => {
+                    self.scroll_line = self.max_scroll();
+                    return None;
+                }
+                Action::PageUp => {
+                    self.scroll_line = self.scroll_line.saturating_sub(page_size);
+                    return None;
+                }
+                Action::PageDown => {
+                    self.scroll_line = (self.scroll_line + page_size).min(self.max_scroll());
+                    return None;
+                }
+                _ => {}
+            }
+        }
+        if let KeyCode::Char(c) = key.code
+            && c.is_ascii_hexdigit()
+            && self.goto_input.len() < 16
+        {
+            self.goto_input.push(c.to_ascii_lowercase());
+            self.error = None;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    #[test]
+    fn test_parse_hex_string() {
+        assert_eq!(parse_hex_string("0xDEADbeef"), Some(vec![0xde, 0xad, 0xbe, 0xef]));
+        assert_eq!(parse_hex_string("de:ad be-ef"), Some(vec![0xde, 0xad, 0xbe, 0xef]));
+        assert_eq!(parse_hex_string("abc"), None);
+        assert_eq!(parse_hex_string("zz"), None);
+        assert_eq!(parse_hex_string(""), None);
+    }
+
+    #[test]
+    fn test_format_line_and_goto_offset() {
+        let line = format_hex_line(0x10, b"AB\x00");
+        assert!(line.starts_with("00000010  41 42 00 "));
+        assert!(line.ends_with("|AB.|"));
+
+        let mut dialog = HexViewerDialog::new("blob".to_string(), 0, (0..=255).collect());
+        dialog.config.reset_keybindings_to_default();
+        dialog.visible_rows = 4;
+        for c in "a5".chars() {
+            dialog.handle_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(dialog.scroll_line, 0xa);
+        assert!(dialog.goto_input.is_empty());
+
+        for c in "fff".chars() {
+            dialog.handle_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert!(dialog.error.is_some());
+    }
+}
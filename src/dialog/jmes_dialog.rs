@@ -2,7 +2,7 @@ use color_eyre::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
 use ratatui::prelude::*;
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, BorderType, Clear, Paragraph, Wrap, Tabs, Table, Row, Cell};
+use ratatui::widgets::{Block, Borders, BorderType, Clear, Paragraph, Wrap, Tabs, Table, TableState, Row, Cell};
 use tui_textarea::TextArea;
 use serde::{Deserialize, Serialize};
 use crate::action::Action;
@@ -11,7 +11,9 @@ use crate::components::dialog_layout::split_dialog_area;
 use crate::config::Config;
 use crate::dialog::TransformScope;
 use crate::dialog::error_dialog::{ErrorDialog, render_error_dialog};
-use crate::style::StyleConfig;
+use crate::jmes::hints::{completions, json_shape, prefix_before, preview, type_name, Completion, Preview};
+use crate::jmes::history::{JmesExpression, JmesHistory};
+use crate::style::{StyleConfig, ThemePalette};
 
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -58,6 +60,22 @@ pub struct JmesPathDialog {
     add_pair_focus: AddPairFocus,
     add_pair_name: TextArea<'static>,
     add_pair_value: TextArea<'static>,
+    /// Top-level fields of the rows, offered by completion
+    pub columns: Vec<String>,
+    /// `json_shape` of the selected row, shown beside the expression
+    pub row_shape: Vec<String>,
+    /// The selected row and the rows after it, as the expression sees them
+    pub preview_rows: Vec<serde_json::Value>,
+    /// Preview on all `preview_rows` rather than the selected row only
+    pub preview_sample: bool,
+    /// Candidates for the identifier before the cursor
+    completions: Vec<Completion>,
+    /// The project's recent and saved expressions
+    pub history: JmesHistory,
+    history_open: bool,
+    history_selected: usize,
+    /// Name being typed for the expression to save
+    save_name: Option<TextArea<'static>>,
     pub config: Config,
 }
 
@@ -67,8 +85,9 @@ impl Default for JmesPathDialog {
 
 impl JmesPathDialog {
     pub fn new() -> Self {
+        let palette = ThemePalette::default();
         let mut textarea = TextArea::default();
-        textarea.set_line_number_style(Style::default().bg(Color::DarkGray));
+        textarea.set_line_number_style(Style::default().bg(palette.hint));
         Self {
             mode: JmesDialogMode::InputTransform,
             scope: TransformScope::Current,
@@ -84,6 +103,15 @@ impl JmesPathDialog {
             add_pair_focus: AddPairFocus::Name,
             add_pair_name: TextArea::default(),
             add_pair_value: TextArea::default(),
+            columns: Vec::new(),
+            row_shape: Vec::new(),
+            preview_rows: Vec::new(),
+            preview_sample: false,
+            completions: Vec::new(),
+            history: JmesHistory::default(),
+            history_open: false,
+            history_selected: 0,
+            save_name: None,
             config: Config::default(),
         }
     }
@@ -92,14 +120,337 @@ impl JmesPathDialog {
         self.mode = JmesDialogMode::Error(msg);
     }
 
+    /// The expression in the active tab
+    pub fn current_expression(&self) -> JmesExpression {
+        match self.mode {
+            JmesDialogMode::InputAddColumns => JmesExpression::AddColumns(self.add_columns.clone()),
+            _ => JmesExpression::Transform(self.textarea.lines().join("\n")),
+        }
+    }
+
+    /// Put a saved or recent expression back into its tab
+    pub fn load_expression(&mut self, expression: &JmesExpression) {
+        let palette = self.config.palette();
+        match expression {
+            JmesExpression::Transform(query) => {
+                self.textarea = TextArea::from(query.lines().map(str::to_string).collect::<Vec<_>>());
+                self.textarea.set_line_number_style(Style::default().bg(palette.hint));
+                self.mode = JmesDialogMode::InputTransform;
+            }
+            JmesExpression::AddColumns(pairs) => {
+                self.add_columns = pairs.clone();
+                self.selected_add_col = 0;
+                self.mode = JmesDialogMode::InputAddColumns;
+            }
+        }
+        self.focus = FocusArea::Body;
+        self.update_completions();
+    }
+
+    fn handle_save_name_key(&mut self, key: KeyEvent) -> Option<Action> {
+        let input = self.save_name.as_mut()?;
+        match self.config.action_for_key(crate::config::Mode::Global, key) {
+            Some(Action::Escape) => self.save_name = None,
+            Some(Action::Enter) => {
+                let name = input.lines().join(" ").trim().to_string();
+                if name.is_empty() {
+                    return None;
+                }
+                self.save_name = None;
+                self.history.save(name, self.current_expression());
+                return Some(Action::JmesHistoryChanged(self.history.clone()));
+            }
+            _ => {
+                input.input(tui_textarea::Input::from(key));
+            }
+        }
+        None
+    }
+
+    fn handle_history_key(&mut self, key: KeyEvent) -> Option<Action> {
+        let count = self.history.entries().len();
+        match self.config.action_for_key(crate::config::Mode::Global, key) {
+            Some(Action::Escape) => self.history_open = false,
+            Some(Action::Enter) => {
+                if let Some((_, expression)) = self.history.entries().get(self.history_selected) {
+                    let expression = (*expression).clone();
+                    self.load_expression(&expression);
+                    self.history_open = false;
+                }
+            }
+            Some(Action::Up) => self.history_selected = self.history_selected.saturating_sub(1),
+            Some(Action::Down) => {
+                if self.history_selected + 1 < count {
+                    self.history_selected += 1;
+                }
+            }
+            _ => {
+                if self.config.action_for_key(crate::config::Mode::JmesPath, key) == Some(Action::DeleteJmesHistoryEntry)
+                    && self.history_selected < count
+                {
+                    self.history.remove(self.history_selected);
+                    self.history_selected = self.history_selected.min(count.saturating_sub(2));
+                    return Some(Action::JmesHistoryChanged(self.history.clone()));
+                }
+            }
+        }
+        None
+    }
+
+    /// Saved and recent expressions over the dialog
+    fn render_history(&self, area: Rect, buf: &mut Buffer) {
+        let palette = self.config.palette();
+        let overlay = Rect {
+            x: area.x + area.width / 8,
+            y: area.y + area.height / 6,
+            width: area.width - area.width / 4,
+            height: area.height - area.height / 3,
+        };
+        Clear.render(overlay, buf);
+        let block = Block::default()
+            .title(" JMESPath History ")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double)
+            .style(self.styles.dialog);
+        let inner = block.inner(overlay);
+        block.render(overlay, buf);
+        let entries = self.history.entries();
+        if entries.is_empty() {
+            Paragraph::new("No saved or recent expressions in this project yet.")
+                .style(Style::default().fg(palette.muted))
+                .render(inner, buf);
+            return;
+        }
+        let rows = entries.iter().map(|(name, expression)| {
+            Row::new(vec![
+                Cell::from(name.unwrap_or("(recent)").to_string()),
+                Cell::from(expression.kind()),
+                Cell::from(expression.summary()),
+            ])
+        });
+        let table = Table::new(rows, [Constraint::Length(16), Constraint::Length(12), Constraint::Min(10)])
+            .header(
+                Row::new(vec!["Name", "Kind", "Expression"])
+                    .style(Style::default().fg(palette.header).add_modifier(Modifier::BOLD)),
+            )
+            .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        let mut state = TableState::default().with_selected(Some(self.history_selected));
+        StatefulWidget::render(table, inner, buf, &mut state);
+    }
+
+    fn render_save_name(&self, input: &TextArea<'static>, area: Rect, buf: &mut Buffer) {
+        let palette = self.config.palette();
+        let width = area.width.saturating_sub(area.width / 3).max(30).min(area.width);
+        let overlay = Rect {
+            x: area.x + area.width.saturating_sub(width) / 2,
+            y: area.y + area.height.saturating_sub(3) / 2,
+            width,
+            height: 3.min(area.height),
+        };
+        Clear.render(overlay, buf);
+        let block = Block::default()
+            .title(format!("Save {} as", self.current_expression().kind()))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(palette.header));
+        let inner = block.inner(overlay);
+        block.render(overlay, buf);
+        ratatui::widgets::Widget::render(input, inner, buf);
+    }
+
+    /// Columns to complete, and the rows (selected one first) whose shape and results are
+    /// shown while writing the expression
+    pub fn set_context(&mut self, columns: Vec<String>, rows: Vec<serde_json::Value>) {
+        self.columns = columns;
+        self.row_shape = rows.first().map(json_shape).unwrap_or_default();
+        self.preview_rows = rows;
+        self.update_completions();
+    }
+
+    fn preview_title(&self) -> String {
+        let key = self.config.actions_to_instructions(&[(crate::config::Mode::JmesPath, Action::TogglePreviewSample)]);
+        if self.preview_sample {
+            format!("Preview: {} rows ({key})", self.preview_rows.len())
+        } else {
+            format!("Preview: selected row ({key})")
+        }
+    }
+
+    /// Results of the expression in the active tab on the preview rows, with rows that would
+    /// fail or have the wrong shape flagged
+    fn preview_lines(&self) -> Vec<Line<'static>> {
+        let palette = self.config.palette();
+        let rows = if self.preview_sample { &self.preview_rows[..] } else { &self.preview_rows[..self.preview_rows.len().min(1)] };
+        let muted = Style::default().fg(palette.hint);
+        let error = Style::default().fg(palette.error);
+        let warning = Style::default().fg(palette.header);
+        if rows.is_empty() {
+            return vec![Line::styled("No rows to preview", muted)];
+        }
+        // One header per row when several are shown
+        let row_header = |lines: &mut Vec<Line<'static>>, i: usize| {
+            if rows.len() > 1 {
+                lines.push(Line::styled(format!("Row {}", i + 1), muted));
+            }
+        };
+        let mut lines = Vec::new();
+        match self.mode {
+            JmesDialogMode::InputAddColumns => {
+                let previews: Vec<(&JmesPathKeyValuePair, Preview)> = self
+                    .add_columns
+                    .iter()
+                    .filter(|p| !p.value.trim().is_empty())
+                    .map(|p| (p, preview(&p.value, rows)))
+                    .collect();
+                if previews.is_empty() {
+                    return vec![Line::styled("Add a column to preview it", muted)];
+                }
+                for (pair, result) in &previews {
+                    if let Preview::Invalid(e) = result {
+                        lines.push(Line::styled(format!("{}: {e}", pair.name), error));
+                    }
+                }
+                for i in 0..rows.len() {
+                    row_header(&mut lines, i);
+                    for (pair, result) in &previews {
+                        let Preview::Rows(results) = result else { continue };
+                        let label = if pair.name.is_empty() { "(merge)" } else { pair.name.as_str() };
+                        lines.push(match &results[i] {
+                            Ok(v) if pair.name.is_empty() && !(v.is_object() || v.is_null()) => {
+                                Line::styled(format!("{label}: {} - merging needs an object or null", type_name(v)), warning)
+                            }
+                            Ok(v) => Line::raw(format!("{label}: {v}")),
+                            Err(e) => Line::styled(format!("{label}: {e}"), error),
+                        });
+                    }
+                }
+            }
+            _ => {
+                let query = self.textarea.lines().join("\n");
+                if query.trim().is_empty() {
+                    return vec![Line::styled("Type an expression to preview it", muted)];
+                }
+                match preview(&query, rows) {
+                    Preview::Invalid(e) => lines.extend(e.lines().map(|l| Line::styled(l.to_string(), error))),
+                    Preview::Rows(results) => {
+                        for (i, result) in results.iter().enumerate() {
+                            row_header(&mut lines, i);
+                            match result {
+                                Ok(v) if !v.is_object() => lines.push(Line::styled(
+                                    format!("{} - the transform needs an object per row", type_name(v)),
+                                    warning,
+                                )),
+                                Ok(v) if rows.len() > 1 => lines.push(Line::raw(v.to_string())),
+                                Ok(v) => lines.extend(
+                                    serde_json::to_string_pretty(v).unwrap_or_default().lines().map(|l| Line::raw(l.to_string())),
+                                ),
+                                Err(e) => lines.push(Line::styled(e.clone(), error)),
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        lines
+    }
+
+    fn render_preview(&self, area: Rect, buf: &mut Buffer) {
+        Paragraph::new(self.preview_lines())
+            .block(Block::default().borders(Borders::ALL).title(self.preview_title()))
+            .wrap(Wrap { trim: false })
+            .render(area, buf);
+    }
+
+    fn update_completions(&mut self) {
+        let (row, col) = self.textarea.cursor();
+        self.completions = match self.textarea.lines().get(row) {
+            Some(line) => completions(line, col, &self.columns),
+            None => Vec::new(),
+        };
+    }
+
+    /// Replace the identifier before the cursor with the first completion
+    fn accept_completion(&mut self) -> bool {
+        let Some(completion) = self.completions.first().cloned() else {
+            return false;
+        };
+        let (row, col) = self.textarea.cursor();
+        let prefix = self.textarea.lines().get(row).map(|line| prefix_before(line, col)).unwrap_or_default();
+        for _ in prefix.chars() {
+            self.textarea.delete_char();
+        }
+        self.textarea.insert_str(&completion.insert);
+        self.update_completions();
+        true
+    }
+
+    /// Feed a key to the expression editor and refresh the completions
+    fn input_expression(&mut self, input: tui_textarea::Input) {
+        self.textarea.input(input);
+        self.update_completions();
+    }
+
+    /// Completions, row shape and preview beside the expression editor
+    fn render_hints(&self, area: Rect, buf: &mut Buffer) {
+        let palette = self.config.palette();
+        let completion_height = if self.completions.is_empty() { 0 } else { self.completions.len().min(6) as u16 + 2 };
+        let [completion_area, shape_area, preview_area] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(completion_height), Constraint::Percentage(40), Constraint::Min(3)])
+            .areas(area);
+        if !self.completions.is_empty() {
+            let key = self.config.actions_to_instructions(&[(crate::config::Mode::JmesPath, Action::AcceptCompletion)]);
+            let lines: Vec<Line> = self
+                .completions
+                .iter()
+                .enumerate()
+                .map(|(i, c)| {
+                    let style = if i == 0 { Style::default().add_modifier(Modifier::REVERSED) } else { Style::default() };
+                    Line::from(vec![
+                        Span::styled(c.insert.clone(), style.fg(palette.accent)),
+                        Span::styled(format!("  {}", c.detail), Style::default().fg(palette.hint)),
+                    ])
+                })
+                .collect();
+            Paragraph::new(lines)
+                .block(Block::default().borders(Borders::ALL).title(format!("Completions ({key})")))
+                .render(completion_area, buf);
+        }
+        let shape: Vec<Line> = if self.row_shape.is_empty() {
+            vec![Line::styled("No row selected", Style::default().fg(palette.hint))]
+        } else {
+            self.row_shape.iter().map(|l| Line::raw(l.as_str())).collect()
+        };
+        Paragraph::new(shape)
+            .block(Block::default().borders(Borders::ALL).title("Row Shape"))
+            .render(shape_area, buf);
+        self.render_preview(preview_area, buf);
+    }
+
     /// Build instructions string from configured keybindings
     fn build_instructions_from_config(&self) -> String {
-        
-        
+        if self.save_name.is_some() {
+            return self.config.actions_to_instructions(&[
+                (crate::config::Mode::Global, crate::action::Action::Enter),
+                (crate::config::Mode::Global, crate::action::Action::Escape),
+            ]);
+        }
+        if self.history_open {
+            return self.config.actions_to_instructions(&[
+                (crate::config::Mode::Global, crate::action::Action::Enter),
+                (crate::config::Mode::JmesPath, crate::action::Action::DeleteJmesHistoryEntry),
+                (crate::config::Mode::Global, crate::action::Action::Up),
+                (crate::config::Mode::Global, crate::action::Action::Down),
+                (crate::config::Mode::Global, crate::action::Action::Escape),
+            ]);
+        }
         match self.mode {
             JmesDialogMode::InputTransform => {
                 let base_instructions = self.config.actions_to_instructions(&[
                     (crate::config::Mode::JmesPath, crate::action::Action::ApplyTransform),
+                    (crate::config::Mode::JmesPath, crate::action::Action::AcceptCompletion),
+                    (crate::config::Mode::JmesPath, crate::action::Action::TogglePreviewSample),
+                    (crate::config::Mode::JmesPath, crate::action::Action::SaveJmesExpression),
+                    (crate::config::Mode::JmesPath, crate::action::Action::OpenJmesHistory),
                     (crate::config::Mode::Global, crate::action::Action::ToggleInstructions),
                 ]);
                 format!("Enter JMESPath expression. {base_instructions}  Space:Toggle Option")
@@ -110,6 +461,8 @@ impl JmesPathDialog {
                     (crate::config::Mode::JmesPath, crate::action::Action::EditColumn),
                     (crate::config::Mode::JmesPath, crate::action::Action::DeleteColumn),
                     (crate::config::Mode::JmesPath, crate::action::Action::ApplyTransform),
+                    (crate::config::Mode::JmesPath, crate::action::Action::SaveJmesExpression),
+                    (crate::config::Mode::JmesPath, crate::action::Action::OpenJmesHistory),
                     (crate::config::Mode::Global, crate::action::Action::Up),
                     (crate::config::Mode::Global, crate::action::Action::Down),
                     (crate::config::Mode::Global, crate::action::Action::Left),
@@ -124,6 +477,7 @@ impl JmesPathDialog {
     }
 
     pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let palette = self.config.palette();
         Clear.render(area, buf);
         // Instructions text per mode; toggled by self.show_instructions
         let instructions = self.build_instructions_from_config();
@@ -156,10 +510,10 @@ impl JmesPathDialog {
             Span::styled("Current Data Set ", Style::default()),
             {
                 let mut style = Style::default()
-                    .fg(Color::White)
+                    .fg(palette.foreground)
                     .add_modifier(Modifier::BOLD);
                 if self.focus == FocusArea::Scope && self.selected_option == 0 {
-                    style = style.bg(Color::Gray).fg(Color::Black);
+                    style = style.bg(palette.muted).fg(palette.selection_fg);
                 }
                 Span::styled(checkbox, style)
             },
@@ -167,7 +521,7 @@ impl JmesPathDialog {
         let scope_block = if self.focus == FocusArea::Scope {
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Yellow))
+                .border_style(Style::default().fg(palette.header))
                 .title("Options")
         } else {
             Block::default().borders(Borders::ALL).title("Options")
@@ -188,21 +542,21 @@ impl JmesPathDialog {
         let tabs_block = if self.focus == FocusArea::Tabs {
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Yellow))
+                .border_style(Style::default().fg(palette.header))
                 .title("JMESPath")
         } else {
             Block::default().borders(Borders::ALL).title("JMESPath")
         };
         let tabs = Tabs::new(titles)
             .block(tabs_block)
-            .highlight_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .highlight_style(Style::default().fg(palette.header).add_modifier(Modifier::BOLD))
             .select(selected_index);
         ratatui::widgets::Widget::render(&tabs, tabs_area, buf);
         // Render instructions inside the outer block when toggled on
         if self.show_instructions && let Some(instructions_area) = inner_layout.instructions_area {
             let instructions_paragraph = Paragraph::new(instructions)
                 .block(Block::default().borders(Borders::ALL).title("Instructions"))
-                .style(Style::default().fg(Color::Yellow))
+                .style(Style::default().fg(palette.header))
                 .wrap(Wrap { trim: true });
             instructions_paragraph.render(instructions_area, buf);
         }
@@ -213,22 +567,41 @@ impl JmesPathDialog {
                     Block::default()
                         .title("JMESPath Transform".to_string())
                         .borders(Borders::ALL)
-                        .border_style(Style::default().fg(Color::Yellow))
+                        .border_style(Style::default().fg(palette.header))
                 } else {
                     Block::default()
                         .title("JMESPath Transform".to_string())
                         .borders(Borders::ALL)
                 };
                 self.textarea.set_block(block);
-                self.textarea.set_line_number_style(Style::default().bg(Color::DarkGray));
-                ratatui::widgets::Widget::render(&self.textarea, body_area, buf);
+                self.textarea.set_line_number_style(Style::default().bg(palette.hint));
+                if self.columns.is_empty() {
+                    ratatui::widgets::Widget::render(&self.textarea, body_area, buf);
+                } else {
+                    let [editor_area, hints_area] = Layout::default()
+                        .direction(Direction::Horizontal)
+                        .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+                        .areas(body_area);
+                    ratatui::widgets::Widget::render(&self.textarea, editor_area, buf);
+                    self.render_hints(hints_area, buf);
+                }
             }
             JmesDialogMode::InputAddColumns => {
+                let body_area = if self.preview_rows.is_empty() {
+                    body_area
+                } else {
+                    let [table_area, preview_area] = Layout::default()
+                        .direction(Direction::Vertical)
+                        .constraints([Constraint::Min(5), Constraint::Percentage(40)])
+                        .areas(body_area);
+                    self.render_preview(preview_area, buf);
+                    table_area
+                };
                 let block = if self.focus == FocusArea::Body {
                     Block::default()
                         .title("JMESPath Add Columns".to_string())
                         .borders(Borders::ALL)
-                        .border_style(Style::default().fg(Color::Yellow))
+                        .border_style(Style::default().fg(palette.header))
                 } else {
                     Block::default()
                         .title("JMESPath Add Columns".to_string())
@@ -307,7 +680,7 @@ impl JmesPathDialog {
                         .title(overlay_title)
                         .borders(Borders::ALL)
                         .border_type(BorderType::Double)
-                        .border_style(Style::default().fg(Color::White));
+                        .border_style(Style::default().fg(palette.foreground));
                     let inner_ov = block.inner(overlay);
                     block.render(overlay, buf);
 
@@ -335,13 +708,13 @@ impl JmesPathDialog {
 
                     let name_block = if self.add_pair_focus == AddPairFocus::Name {
                         Block::default().borders(Borders::ALL).title("Name")
-                            .border_style(Style::default().fg(Color::Yellow))
+                            .border_style(Style::default().fg(palette.header))
                     } else {
                         Block::default().borders(Borders::ALL).title("Name")
                     };
                     let value_block = if self.add_pair_focus == AddPairFocus::Value {
                         Block::default().borders(Borders::ALL).title("Value")
-                            .border_style(Style::default().fg(Color::Yellow))
+                            .border_style(Style::default().fg(palette.header))
                     } else {
                         Block::default().borders(Borders::ALL).title("Value")
                     };
@@ -372,9 +745,9 @@ impl JmesPathDialog {
                         .areas(row3);
                     let is_button_focused = self.add_pair_focus == AddPairFocus::Button;
                     let button_style = if is_button_focused {
-                        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                        Style::default().fg(palette.header).add_modifier(Modifier::BOLD)
                     } else {
-                        Style::default().fg(Color::White)
+                        Style::default().fg(palette.foreground)
                     };
                     let button = Paragraph::new(button_label).style(button_style);
                     button.render(button_area, buf);
@@ -387,10 +760,24 @@ impl JmesPathDialog {
                 render_error_dialog(&err, inner_total_area, buf);
             }
         }
+        if !matches!(self.mode, JmesDialogMode::Error(_)) {
+            if self.history_open {
+                self.render_history(content_area, buf);
+            }
+            if let Some(input) = &self.save_name {
+                self.render_save_name(input, content_area, buf);
+            }
+        }
     }
 
     pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
         if key.kind == KeyEventKind::Press {
+            if self.save_name.is_some() {
+                return self.handle_save_name_key(key);
+            }
+            if self.history_open {
+                return self.handle_history_key(key);
+            }
             // Handle Global actions first
             if let Some(global_action) = self.config.action_for_key(crate::config::Mode::Global, key) {
                 match global_action {
@@ -461,6 +848,31 @@ impl JmesPathDialog {
                             _ => {}
                         }
                     }
+                    Action::SaveJmesExpression if !self.add_pair_open && !matches!(self.mode, JmesDialogMode::Error(_)) => {
+                        let mut input = TextArea::default();
+                        input.set_block(Block::default());
+                        self.save_name = Some(input);
+                        return None;
+                    }
+                    Action::TogglePreviewSample if !self.add_pair_open => {
+                        self.preview_sample = !self.preview_sample;
+                        return None;
+                    }
+                    Action::OpenJmesHistory if !self.add_pair_open && !matches!(self.mode, JmesDialogMode::Error(_)) => {
+                        self.history_open = true;
+                        self.history_selected = 0;
+                        return None;
+                    }
+                    // Falls through to focus rotation when there is nothing to complete
+                    Action::AcceptCompletion => {
+                        if !self.add_pair_open
+                            && matches!(self.mode, JmesDialogMode::InputTransform)
+                            && self.focus == FocusArea::Body
+                            && self.accept_completion()
+                        {
+                            return None;
+                        }
+                    }
                     Action::AddColumn => {
                         if matches!(self.mode, JmesDialogMode::InputAddColumns) {
                             self.add_pair_open = true;
@@ -552,7 +964,7 @@ impl JmesPathDialog {
                         match global_action {
                             Action::Enter => {
                                 let input: tui_textarea::Input = key.into();
-                                if self.focus == FocusArea::Body { self.textarea.input(input); }
+                                if self.focus == FocusArea::Body { self.input_expression(input); }
                                 return None;
                             }
                             Action::Up => {
@@ -564,7 +976,7 @@ impl JmesPathDialog {
                                     }
                                     // otherwise let textarea handle it
                                     let input: tui_textarea::Input = key.into();
-                                    self.textarea.input(input);
+                                    self.input_expression(input);
                                 }
                                 return None;
                             }
@@ -574,7 +986,7 @@ impl JmesPathDialog {
 
                     // Handle other input for textarea
                     let input: tui_textarea::Input = key.into();
-                    if self.focus == FocusArea::Body { self.textarea.input(input); }
+                    if self.focus == FocusArea::Body { self.input_expression(input); }
                     return None;
                 }
                 JmesDialogMode::InputAddColumns => {
@@ -774,6 +1186,7 @@ impl JmesPathDialog {
 impl Component for JmesPathDialog {
     fn register_action_handler(&mut self, _tx: tokio::sync::mpsc::UnboundedSender<Action>) -> Result<()> { Ok(()) }
     fn register_config_handler(&mut self, _config: crate::config::Config) -> Result<()> { 
+        self.styles = _config.style_config.clone();
         self.config = _config; 
         Ok(()) 
     }
@@ -789,3 +1202,118 @@ impl Component for JmesPathDialog {
 }
 
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    #[test]
+    fn test_tab_completes_column() {
+        let mut dialog = JmesPathDialog::new();
+        dialog.config.reset_keybindings_to_default();
+        dialog.set_context(vec!["event_id".to_string(), "host name".to_string()], vec![serde_json::json!({"event_id": 4624})]);
+        dialog.focus = FocusArea::Body;
+        for c in "{id: ev".chars() {
+            dialog.handle_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        assert_eq!(dialog.completions.len(), 1);
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE));
+        assert_eq!(dialog.textarea.lines()[0], "{id: event_id");
+        assert!(dialog.completions.is_empty());
+
+        let area = Rect::new(0, 0, 100, 30);
+        let mut buf = Buffer::empty(area);
+        dialog.render(area, &mut buf);
+        let text: String = buf.content().iter().map(|c| c.symbol()).collect();
+        assert!(text.contains("Row Shape") && text.contains("event_id: number"));
+
+        // Nothing left to complete, so Tab moves focus on
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE));
+        assert_eq!(dialog.focus, FocusArea::Scope);
+    }
+
+    #[test]
+    fn test_live_preview() {
+        let mut dialog = JmesPathDialog::new();
+        dialog.config.reset_keybindings_to_default();
+        let rows = vec![serde_json::json!({"n": 1}), serde_json::json!({"n": "two"})];
+        dialog.set_context(vec!["n".to_string()], rows);
+        let render = |dialog: &mut JmesPathDialog| {
+            let area = Rect::new(0, 0, 160, 40);
+            let mut buf = Buffer::empty(area);
+            dialog.render(area, &mut buf);
+            buf.content().iter().map(|c| c.symbol()).collect::<String>()
+        };
+
+        dialog.load_expression(&JmesExpression::Transform("{m: abs(n)}".to_string()));
+        let text = render(&mut dialog);
+        assert!(text.contains("Preview: selected row") && text.contains("\"m\": 1"));
+        // The second row only fails in the sample
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+        let text = render(&mut dialog);
+        assert!(text.contains("Preview: 2 rows") && text.contains("Row 2") && text.contains("Runtime error"));
+
+        dialog.load_expression(&JmesExpression::Transform("n".to_string()));
+        assert!(render(&mut dialog).contains("the transform needs an object per row"));
+        dialog.load_expression(&JmesExpression::AddColumns(vec![JmesPathKeyValuePair { name: "doubled".to_string(), value: "[n, n]".to_string() }]));
+        assert!(render(&mut dialog).contains("doubled: [1,1]"));
+    }
+
+    #[test]
+    fn test_renders_in_theme_colors() {
+        let mut config = crate::config::Config::default();
+        config.reset_keybindings_to_default();
+        config.theme = Some(crate::style::ThemeConfig { name: crate::style::ThemeName::Light, ..Default::default() });
+        config.apply_theme();
+        let mut dialog = JmesPathDialog::new();
+        dialog.register_config_handler(config).unwrap();
+        dialog.set_context(vec!["event_id".to_string()], vec![serde_json::json!({"event_id": 4624})]);
+        dialog.focus = FocusArea::Body;
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Char('e'), KeyModifiers::NONE));
+
+        let area = Rect::new(0, 0, 160, 40);
+        let mut buf = Buffer::empty(area);
+        dialog.render(area, &mut buf);
+        let light = ThemePalette::light();
+        let fgs: Vec<Color> = buf.content().iter().map(|c| c.fg).collect();
+        assert!(fgs.contains(&light.header) && fgs.contains(&light.accent) && fgs.contains(&light.hint));
+        assert!(!fgs.iter().any(|c| matches!(c, Color::Yellow | Color::Cyan | Color::DarkGray)));
+    }
+
+    #[test]
+    fn test_save_and_pick_from_history() {
+        let press = |dialog: &mut JmesPathDialog, code: KeyCode, modifiers: KeyModifiers| {
+            dialog.handle_key_event(KeyEvent::new(code, modifiers))
+        };
+        let mut dialog = JmesPathDialog::new();
+        dialog.config.reset_keybindings_to_default();
+        dialog.history.record(JmesExpression::Transform("{old: a}".to_string()));
+        dialog.load_expression(&JmesExpression::Transform("{user: payload.user}".to_string()));
+
+        press(&mut dialog, KeyCode::Char('s'), KeyModifiers::CONTROL);
+        for c in "users".chars() {
+            press(&mut dialog, KeyCode::Char(c), KeyModifiers::NONE);
+        }
+        let Some(Action::JmesHistoryChanged(history)) = press(&mut dialog, KeyCode::Enter, KeyModifiers::NONE) else {
+            panic!("expected the history to change");
+        };
+        assert_eq!(history.saved[0].name, "users");
+
+        // Saved entries come first; pick the recent one below
+        press(&mut dialog, KeyCode::Char('o'), KeyModifiers::CONTROL);
+        let area = Rect::new(0, 0, 100, 30);
+        let mut buf = Buffer::empty(area);
+        dialog.render(area, &mut buf);
+        let text: String = buf.content().iter().map(|c| c.symbol()).collect();
+        assert!(text.contains("JMESPath History") && text.contains("(recent)"));
+        press(&mut dialog, KeyCode::Down, KeyModifiers::NONE);
+        press(&mut dialog, KeyCode::Enter, KeyModifiers::NONE);
+        assert_eq!(dialog.textarea.lines().join("\n"), "{old: a}");
+
+        press(&mut dialog, KeyCode::Char('o'), KeyModifiers::CONTROL);
+        assert!(matches!(press(&mut dialog, KeyCode::Delete, KeyModifiers::NONE), Some(Action::JmesHistoryChanged(_))));
+        assert!(dialog.history.saved.is_empty());
+        press(&mut dialog, KeyCode::Esc, KeyModifiers::NONE);
+        assert_eq!(press(&mut dialog, KeyCode::Esc, KeyModifiers::NONE), Some(Action::DialogClose));
+    }
+}
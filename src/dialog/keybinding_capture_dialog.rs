@@ -13,6 +13,8 @@ pub struct KeybindingCaptureDialog {
     pub show_instructions: bool,
     pub pressed_keys: Vec<KeyEvent>,
     pub pressed_display: String,
+    /// Bindings the pressed keys collide with, set by the owning dialog
+    pub warning: Option<String>,
     pub config: Config,
 }
 
@@ -22,6 +24,7 @@ impl KeybindingCaptureDialog {
             show_instructions: true,
             pressed_keys: Vec::new(),
             pressed_display: String::new(),
+            warning: None,
             config: Config::default(),
         }
     }
@@ -65,7 +68,11 @@ impl KeybindingCaptureDialog {
         let inner = inner_block.inner(content_area);
         inner_block.render(content_area, buf);
 
-        let p = Paragraph::new(message).wrap(Wrap { trim: true });
+        let mut lines = vec![Line::from(message)];
+        if let Some(warning) = &self.warning {
+            lines.push(Line::styled(warning.clone(), Style::default().fg(Color::Red)));
+        }
+        let p = Paragraph::new(lines).wrap(Wrap { trim: true });
         p.render(inner, buf);
 
         // Render instructions panel if enabled
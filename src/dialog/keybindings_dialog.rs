@@ -21,6 +21,8 @@ use crate::dialog::MessageDialog;
 pub struct KeybindingEntry {
     pub action: Action,
     pub key_display: String,
+    /// Other bindings on the same keys, e.g. "Global: Escape"
+    pub conflicts: Vec<String>,
 }
 
 // capture handled by a separate dialog now
@@ -77,6 +79,7 @@ impl KeybindingsDialog {
             Mode::DataTabManager,
             Mode::Global,
             Mode::DataTableContainer,
+            Mode::TableNavigation,
             Mode::DataManagement,
             Mode::DataImport,
             Mode::CsvOptions,
@@ -84,6 +87,18 @@ impl KeybindingsDialog {
             Mode::Filter,
             Mode::Find,
             Mode::FindAllResults,
+            Mode::RecordView,
+            Mode::GroupBy,
+            Mode::HexViewer,
+            Mode::CellViewer,
+            Mode::OpenTargetDialog,
+            Mode::HeatmapDialog,
+            Mode::ChartDialog,
+            Mode::MissingDataDialog,
+            Mode::RowTagsDialog,
+            Mode::ValidationDialog,
+            Mode::FindingsReportDialog,
+            Mode::DisplaySettingsDialog,
             Mode::JmesPath,
             Mode::SqlDialog,
             Mode::XlsxOptionsDialog,
@@ -99,6 +114,12 @@ impl KeybindingsDialog {
             Mode::ProjectSettings,
             Mode::TableExport,
             Mode::KeybindingsDialog,
+            Mode::TransformHistoryDialog,
+            Mode::CheckpointsDialog,
+            Mode::ScriptDialog,
+            Mode::PathRemapDialog,
+            Mode::QuickOpenDialog,
+            Mode::SchemaEditorDialog,
         ]
     }
 
@@ -107,15 +128,24 @@ impl KeybindingsDialog {
     fn entries_for_mode(&self, mode: Mode) -> Vec<KeybindingEntry> {
         let mut entries: Vec<KeybindingEntry> = vec![];
         if let Some(map) = self.config.keybindings.0.get(&mode) {
-            for (_seq, action) in map.iter() {
+            for (seq, action) in map.iter() {
                 let key_display = self.config.key_for_action(mode, action).unwrap_or_default();
-                entries.push(KeybindingEntry { action: action.clone(), key_display });
+                let conflicts = self.conflict_descriptions(mode, seq, action);
+                entries.push(KeybindingEntry { action: action.clone(), key_display, conflicts });
             }
         }
         entries.sort_by(|a, b| format!("{}", a.action).cmp(&format!("{}", b.action)));
         entries
     }
 
+    fn conflict_descriptions(&self, mode: Mode, keys: &[KeyEvent], action: &Action) -> Vec<String> {
+        self.config
+            .keybinding_conflicts(mode, keys, action)
+            .into_iter()
+            .map(|(m, a)| format!("{m:?}: {}", self.config.action_to_friendly_name(&a)))
+            .collect()
+    }
+
     fn build_instructions_from_config(&self) -> String {
         self.config.actions_to_instructions(&[
             (Mode::Global, Action::Enter),
@@ -404,7 +434,13 @@ impl KeybindingsDialog {
             let style = if is_selected { self.styles.selected_row } else { base };
             let e = &entries[i];
             let text = format!("{:<20}  {}", e.key_display, self.config.action_to_friendly_name(&e.action));
-            buf.set_string(area.x, y, text, style);
+            buf.set_string(area.x, y, &text, style);
+            if !e.conflicts.is_empty() {
+                let x = area.x + text.chars().count() as u16 + 2;
+                let width = content_width.saturating_sub(x - area.x) as usize;
+                let note = format!("conflicts with {}", e.conflicts.join(", "));
+                buf.set_stringn(x, y, note, width, style.fg(Color::Red));
+            }
         }
 
         // Scrollbar
@@ -463,8 +499,26 @@ impl Component for KeybindingsDialog {
         }
 
         // If capture dialog is active, forward events to it first
-        if let Some(ref mut dialog) = self.capture_dialog {
-            if let Some(a) = Component::handle_key_event(dialog, key)? {
+        if self.capture_dialog.is_some() {
+            let mode = self.current_mode();
+            let action = self
+                .pending_rebind_index
+                .and_then(|i| self.entries_for_mode(mode).get(i).map(|e| e.action.clone()));
+            let Some(ref mut dialog) = self.capture_dialog else { return Ok(None) };
+            let result = Component::handle_key_event(dialog, key)?;
+            if result.is_none()
+                && let Some(action) = action
+            {
+                // Warn before Enter replaces another binding
+                let pressed = dialog.pressed_keys.clone();
+                let conflicts = self.conflict_descriptions(mode, &pressed, &action);
+                if let Some(ref mut dialog) = self.capture_dialog {
+                    dialog.warning = (!conflicts.is_empty())
+                        .then(|| format!("Conflicts with {}; Enter to apply anyway", conflicts.join(", ")));
+                }
+            }
+            let Some(ref mut dialog) = self.capture_dialog else { return Ok(None) };
+            if let Some(a) = result {
                 match a {
                     Action::ConfirmRebinding => {
                         let pressed = dialog.pressed_keys.clone();
@@ -571,9 +625,16 @@ impl Component for KeybindingsDialog {
                     return Ok(None);
                 }
                 Action::SaveKeybindings => {
-                    // Persist to config file path
-                    // For now, signal caller to save workspace (reuse existing action)
-                    return Ok(Some(Action::SaveWorkspaceState));
+                    // Persist to the config file; the caller applies the new bindings
+                    match self.config.save_keybindings() {
+                        Ok(_) => return Ok(Some(Action::SaveKeybindings)),
+                        Err(e) => {
+                            let mut dlg = MessageDialog::with_title(format!("Failed to save keybindings: {e}"), "Error");
+                            let _ = dlg.register_config_handler(self.config.clone());
+                            self.message_dialog = Some(dlg);
+                            return Ok(None);
+                        }
+                    }
                 }
                 Action::SaveKeybindingsAs => {
                     // Open file browser in Save mode with json/json5 filters
@@ -6,10 +6,14 @@
 pub mod azure_openai;
 pub mod openai;
 pub mod ollama;
+pub mod openai_compatible;
+pub mod request_policy;
 
 pub use azure_openai::{AzureOpenAiConfigDialog, AzureOpenAiConfig};
 pub use openai::{OpenAiConfigDialog, OpenAIConfig};
 pub use ollama::{OllamaConfigDialog, OllamaConfig};
+pub use openai_compatible::{OpenAiCompatibleConfigDialog, OpenAICompatibleConfig};
+pub use request_policy::{RequestPolicy, RequestRunner};
 
 
 pub trait LlmConfig {
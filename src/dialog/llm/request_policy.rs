@@ -0,0 +1,147 @@
+//! Retry, backoff and rate limiting for provider requests
+//!
+//! Configured under `[request_policy]` in the LLM settings file.
+
+use std::time::{Duration, Instant};
+
+use color_eyre::Result;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+/// How often to retry a failed request and how fast requests may be sent
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RequestPolicy {
+    /// Extra attempts after the first failure
+    pub max_retries: u32,
+    /// Wait before the first retry; doubles on each further retry
+    pub initial_backoff_ms: u64,
+    /// Upper bound for a single backoff wait
+    pub max_backoff_ms: u64,
+    /// Requests allowed per minute; 0 means unlimited
+    pub requests_per_minute: u32,
+}
+
+impl Default for RequestPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff_ms: 500,
+            max_backoff_ms: 30_000,
+            requests_per_minute: 0,
+        }
+    }
+}
+
+impl RequestPolicy {
+    /// A policy that sends requests once, as fast as possible
+    pub fn no_retries() -> Self {
+        Self { max_retries: 0, requests_per_minute: 0, ..Self::default() }
+    }
+
+    /// Wait before retry number `retry` (0-based)
+    pub fn backoff_for(&self, retry: u32) -> Duration {
+        let ms = self.initial_backoff_ms.saturating_mul(1u64 << retry.min(32));
+        Duration::from_millis(ms.min(self.max_backoff_ms))
+    }
+
+    /// Minimum spacing between requests implied by `requests_per_minute`
+    pub fn min_interval(&self) -> Option<Duration> {
+        (self.requests_per_minute > 0).then(|| Duration::from_secs(60) / self.requests_per_minute)
+    }
+}
+
+/// Sleep in short slices so a cancelled job stops waiting promptly. Returns false if cancelled.
+fn sleep_unless_cancelled(duration: Duration, is_cancelled: &dyn Fn() -> bool) -> bool {
+    let deadline = Instant::now() + duration;
+    loop {
+        if is_cancelled() {
+            return false;
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            return true;
+        }
+        std::thread::sleep((deadline - now).min(Duration::from_millis(50)));
+    }
+}
+
+/// Sends requests through a `RequestPolicy`: spaces them out and retries failures with backoff
+pub struct RequestRunner<'a> {
+    policy: RequestPolicy,
+    is_cancelled: &'a dyn Fn() -> bool,
+    last_request: Option<Instant>,
+}
+
+impl<'a> RequestRunner<'a> {
+    pub fn new(policy: RequestPolicy, is_cancelled: &'a dyn Fn() -> bool) -> Self {
+        Self { policy, is_cancelled, last_request: None }
+    }
+
+    fn wait_for_slot(&mut self) -> Result<()> {
+        if let (Some(interval), Some(last)) = (self.policy.min_interval(), self.last_request) {
+            let elapsed = last.elapsed();
+            if elapsed < interval && !sleep_unless_cancelled(interval - elapsed, self.is_cancelled) {
+                return Err(color_eyre::eyre::eyre!("Cancelled"));
+            }
+        }
+        self.last_request = Some(Instant::now());
+        Ok(())
+    }
+
+    /// Run `request`, retrying per the policy; returns the last error once retries run out
+    pub fn run<T>(&mut self, mut request: impl FnMut() -> Result<T>) -> Result<T> {
+        let mut retry = 0;
+        loop {
+            self.wait_for_slot()?;
+            match request() {
+                Ok(value) => return Ok(value),
+                Err(e) if retry >= self.policy.max_retries => return Err(e),
+                Err(e) => {
+                    warn!("Request failed (attempt {}), retrying: {}", retry + 1, e);
+                    if !sleep_unless_cancelled(self.policy.backoff_for(retry), self.is_cancelled) {
+                        return Err(color_eyre::eyre::eyre!("Cancelled"));
+                    }
+                    retry += 1;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_and_interval() {
+        let policy = RequestPolicy { requests_per_minute: 120, ..RequestPolicy::default() };
+        assert_eq!(policy.backoff_for(0), Duration::from_millis(500));
+        assert_eq!(policy.backoff_for(2), Duration::from_millis(2000));
+        assert_eq!(policy.backoff_for(40), Duration::from_millis(30_000));
+        assert_eq!(policy.min_interval(), Some(Duration::from_millis(500)));
+        assert_eq!(RequestPolicy::default().min_interval(), None);
+    }
+
+    #[test]
+    fn test_runner_retries_then_gives_up() {
+        let policy = RequestPolicy { max_retries: 2, initial_backoff_ms: 1, ..RequestPolicy::default() };
+        let not_cancelled = || false;
+        let mut runner = RequestRunner::new(policy, &not_cancelled);
+
+        let mut calls = 0;
+        let value = runner.run(|| {
+            calls += 1;
+            if calls < 3 { Err(color_eyre::eyre::eyre!("busy")) } else { Ok(calls) }
+        });
+        assert_eq!(value.unwrap(), 3);
+
+        let mut calls = 0;
+        let failed: Result<()> = runner.run(|| {
+            calls += 1;
+            Err(color_eyre::eyre::eyre!("down"))
+        });
+        assert!(failed.is_err());
+        assert_eq!(calls, 3);
+    }
+}
@@ -108,6 +108,7 @@ impl LlmClientCreateDialog {
             ListItem::new("Azure OpenAI"),
             ListItem::new("OpenAI"),
             ListItem::new("Ollama"),
+            ListItem::new("OpenAI-Compatible"),
         ]
     }
 
@@ -117,6 +118,7 @@ impl LlmClientCreateDialog {
                 0 => LlmProvider::Azure,
                 1 => LlmProvider::OpenAI,
                 2 => LlmProvider::Ollama,
+                3 => LlmProvider::OpenAICompatible,
                 _ => LlmProvider::OpenAI,
             };
         }
@@ -331,7 +333,7 @@ impl LlmClientCreateDialog {
                         }
                         Action::Up => {
                             if let Some(selected) = self.provider_list_state.selected() {
-                                let new_selection = if selected == 0 { 2 } else { selected - 1 };
+                                let new_selection = if selected == 0 { 3 } else { selected - 1 };
                                 self.provider_list_state.select(Some(new_selection));
                                 self.update_selected_provider();
                             }
@@ -339,7 +341,7 @@ impl LlmClientCreateDialog {
                         }
                         Action::Down => {
                             if let Some(selected) = self.provider_list_state.selected() {
-                                let new_selection = if selected == 2 { 0 } else { selected + 1 };
+                                let new_selection = if selected == 3 { 0 } else { selected + 1 };
                                 self.provider_list_state.select(Some(new_selection));
                                 self.update_selected_provider();
                             }
@@ -360,7 +362,7 @@ impl LlmClientCreateDialog {
                     }
                     KeyCode::Up => {
                         if let Some(selected) = self.provider_list_state.selected() {
-                            let new_selection = if selected == 0 { 2 } else { selected - 1 };
+                            let new_selection = if selected == 0 { 3 } else { selected - 1 };
                             self.provider_list_state.select(Some(new_selection));
                             self.update_selected_provider();
                         }
@@ -368,7 +370,7 @@ impl LlmClientCreateDialog {
                     }
                     KeyCode::Down => {
                         if let Some(selected) = self.provider_list_state.selected() {
-                            let new_selection = if selected == 2 { 0 } else { selected + 1 };
+                            let new_selection = if selected == 3 { 0 } else { selected + 1 };
                             self.provider_list_state.select(Some(new_selection));
                             self.update_selected_provider();
                         }
@@ -10,12 +10,16 @@ use crate::components::dialog_layout::split_dialog_area;
 use crate::config::Config;
 use serde::{Deserialize, Serialize};
 use strum::Display;
+#[cfg(feature = "llm")]
 use rig::client::builder::{DynClientBuilder, BoxCompletionModel};
+#[cfg(feature = "llm")]
 use rig::embeddings::embedding::EmbeddingModelDyn;
 use crate::dialog::llm::{
     AzureOpenAiConfigDialog, OpenAiConfigDialog,
-    OllamaConfigDialog, AzureOpenAiConfig, OpenAIConfig, OllamaConfig
+    OllamaConfigDialog, OpenAiCompatibleConfigDialog, AzureOpenAiConfig, OpenAIConfig,
+    OllamaConfig, OpenAICompatibleConfig, RequestPolicy
 };
+#[cfg(feature = "llm")]
 use std::collections::HashMap;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Display)]
@@ -23,6 +27,8 @@ pub enum LlmProvider {
     Azure,
     OpenAI,
     Ollama,
+    /// Any server exposing the OpenAI API (LM Studio, vLLM, llama.cpp server)
+    OpenAICompatible,
 }
 
 impl LlmProvider {
@@ -31,6 +37,7 @@ impl LlmProvider {
             LlmProvider::Azure => "Azure OpenAI",
             LlmProvider::OpenAI => "OpenAI",
             LlmProvider::Ollama => "Ollama",
+            LlmProvider::OpenAICompatible => "OpenAI-Compatible",
         }
     }
 }
@@ -41,6 +48,7 @@ pub enum LlmClientDialogMode {
     AzureConfiguration,
     OpenAIConfiguration,
     OllamaConfiguration,
+    OpenAICompatibleConfiguration,
     Error(String),
 }
 
@@ -57,6 +65,7 @@ pub struct LlmClientDialog {
     pub azure_dialog: AzureOpenAiConfigDialog,
     pub openai_dialog: OpenAiConfigDialog,
     pub ollama_dialog: OllamaConfigDialog,
+    pub openai_compatible_dialog: OpenAiCompatibleConfigDialog,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -65,6 +74,12 @@ pub struct LlmConfig {
     pub azure: Option<AzureOpenAiConfig>,
     pub openai: Option<OpenAIConfig>,
     pub ollama: Option<OllamaConfig>,
+    #[serde(default)]
+    pub openai_compatible: Option<OpenAICompatibleConfig>,
+    /// Retry/backoff and rate limits applied to embedding requests
+    #[serde(default)]
+    pub request_policy: RequestPolicy,
+    #[cfg(feature = "llm")]
     #[serde(skip)]
     builders: HashMap<LlmProvider, DynClientBuilder>,
 }
@@ -75,6 +90,8 @@ impl std::fmt::Debug for LlmConfig {
             .field("azure", &self.azure)
             .field("openai", &self.openai)
             .field("ollama", &self.ollama)
+            .field("openai_compatible", &self.openai_compatible)
+            .field("request_policy", &self.request_policy)
             .finish()
     }
 }
@@ -85,6 +102,9 @@ impl Clone for LlmConfig {
             azure: self.azure.clone(),
             openai: self.openai.clone(),
             ollama: self.ollama.clone(),
+            openai_compatible: self.openai_compatible.clone(),
+            request_policy: self.request_policy.clone(),
+            #[cfg(feature = "llm")]
             builders: HashMap::new(),
         }
     }
@@ -92,12 +112,41 @@ impl Clone for LlmConfig {
 
 impl PartialEq for LlmConfig {
     fn eq(&self, other: &Self) -> bool {
-        self.azure == other.azure && self.openai == other.openai && self.ollama == other.ollama
+        self.azure == other.azure
+            && self.openai == other.openai
+            && self.ollama == other.ollama
+            && self.openai_compatible == other.openai_compatible
+            && self.request_policy == other.request_policy
     }
 }
 
 impl Eq for LlmConfig {}
 
+/// The provider settings of an `LlmConfig` without its client builders, so they can move to a
+/// worker thread
+#[derive(Debug, Clone, Default)]
+pub struct LlmProviderSettings {
+    pub azure: Option<AzureOpenAiConfig>,
+    pub openai: Option<OpenAIConfig>,
+    pub ollama: Option<OllamaConfig>,
+    pub openai_compatible: Option<OpenAICompatibleConfig>,
+    pub request_policy: RequestPolicy,
+}
+
+impl LlmProviderSettings {
+    pub fn into_config(self) -> LlmConfig {
+        LlmConfig {
+            azure: self.azure,
+            openai: self.openai,
+            ollama: self.ollama,
+            openai_compatible: self.openai_compatible,
+            request_policy: self.request_policy,
+            #[cfg(feature = "llm")]
+            builders: HashMap::new(),
+        }
+    }
+}
+
 
 impl LlmConfig {
     /// Returns a list of configured providers
@@ -123,6 +172,12 @@ impl LlmConfig {
             }
         }
 
+        if let Some(cfg) = &self.openai_compatible {
+            if crate::dialog::llm::LlmConfig::is_configured(cfg) {
+                providers.push(LlmProvider::OpenAICompatible);
+            }
+        }
+
         if providers.is_empty() {
             None
         } else {
@@ -130,21 +185,37 @@ impl LlmConfig {
         }
     }
 
+    /// Copy the provider settings for use on another thread
+    pub fn provider_settings(&self) -> LlmProviderSettings {
+        LlmProviderSettings {
+            azure: self.azure.clone(),
+            openai: self.openai.clone(),
+            ollama: self.ollama.clone(),
+            openai_compatible: self.openai_compatible.clone(),
+            request_policy: self.request_policy.clone(),
+        }
+    }
+
     /// Store a dynamic client builder for a given provider
+    #[cfg(feature = "llm")]
     pub fn set_builder(&mut self, provider: LlmProvider, builder: DynClientBuilder) {
         self.builders.insert(provider, builder);
     }
 
     /// Retrieve a stored dynamic client builder for a given provider, if available
+    #[cfg(feature = "llm")]
     pub fn get_builder(&self, provider: &LlmProvider) -> Option<&DynClientBuilder> {
         self.builders.get(provider)
     }
 
+    #[cfg(feature = "llm")]
     fn provider_key(provider: &LlmProvider) -> &'static str {
         match provider {
             LlmProvider::OpenAI => "openai",
             LlmProvider::Azure => "azure",
             LlmProvider::Ollama => "ollama",
+            // Speaks the OpenAI API, so rig's OpenAI client applies
+            LlmProvider::OpenAICompatible => "openai",
         }
     }
 
@@ -153,6 +224,8 @@ impl LlmConfig {
             LlmProvider::OpenAI => "text-embedding-3-small",
             LlmProvider::Azure => "text-embedding-3-small",
             LlmProvider::Ollama => "nomic-embed-text",
+            // An empty model name falls back to the model in the endpoint config
+            LlmProvider::OpenAICompatible => "",
         }
     }
 
@@ -161,16 +234,19 @@ impl LlmConfig {
             LlmProvider::OpenAI => "gpt-4o-mini",
             LlmProvider::Azure => "gpt-4o-mini",
             LlmProvider::Ollama => "llama3.1",
+            LlmProvider::OpenAICompatible => "",
         }
     }
 
     /// Construct an EmbeddingModelDyn using the stored builder and default model for the provider
+    #[cfg(feature = "llm")]
     pub fn get_embedding_model_dyn<'a>(&'a self, provider: LlmProvider) -> Result<Box<dyn EmbeddingModelDyn + 'a>> {
         let model = self.default_embedding_model_for(&provider);
         self.get_embedding_model_dyn_with(provider, model)
     }
 
     /// Construct an EmbeddingModelDyn using the stored builder and an explicit model name
+    #[cfg(feature = "llm")]
     pub fn get_embedding_model_dyn_with<'a>(&'a self, provider: LlmProvider, model: &str) -> Result<Box<dyn EmbeddingModelDyn + 'a>> {
         let builder = self.builders.get(&provider)
             .ok_or_else(|| color_eyre::eyre::eyre!("No client builder registered for provider: {}", provider.display_name()))?;
@@ -182,12 +258,14 @@ impl LlmConfig {
     }
 
     /// Construct a BoxCompletionModel using the stored builder and default model for the provider
+    #[cfg(feature = "llm")]
     pub fn get_completion_model_box<'a>(&'a self, provider: LlmProvider) -> Result<BoxCompletionModel<'a>> {
         let model = self.default_completion_model_for(&provider);
         self.get_completion_model_box_with(provider, model)
     }
 
     /// Construct a BoxCompletionModel using the stored builder and an explicit model name
+    #[cfg(feature = "llm")]
     pub fn get_completion_model_box_with<'a>(&'a self, provider: LlmProvider, model: &str) -> Result<BoxCompletionModel<'a>> {
         let builder = self.builders.get(&provider)
             .ok_or_else(|| color_eyre::eyre::eyre!("No client builder registered for provider: {}", provider.display_name()))?;
@@ -222,6 +300,14 @@ impl LlmConfig {
         self.ollama.as_mut().unwrap()
     }
 
+    /// Get or create OpenAI-compatible endpoint config
+    pub fn get_or_create_openai_compatible(&mut self) -> &mut OpenAICompatibleConfig {
+        if self.openai_compatible.is_none() {
+            self.openai_compatible = Some(OpenAICompatibleConfig::default());
+        }
+        self.openai_compatible.as_mut().unwrap()
+    }
+
     /// Fetch embeddings using the selected provider and model
     pub fn fetch_embeddings_via_provider(
         &self,
@@ -234,9 +320,82 @@ impl LlmConfig {
             LlmProvider::OpenAI => self.fetch_openai_embeddings(model_name, inputs, dims_opt),
             LlmProvider::Azure => self.fetch_azure_embeddings(model_name, inputs, dims_opt),
             LlmProvider::Ollama => self.fetch_ollama_embeddings(model_name, inputs),
+            LlmProvider::OpenAICompatible => self.fetch_openai_compatible_embeddings(model_name, inputs, dims_opt),
         }
     }
 
+    /// Run a single-turn chat completion and return the reply text
+    pub fn fetch_completion_via_provider(
+        &self,
+        provider: LlmProvider,
+        model_name: &str,
+        system_prompt: &str,
+        user_prompt: &str,
+    ) -> color_eyre::Result<String> {
+        #[derive(serde::Serialize)]
+        struct ChatMessage<'a> { role: &'a str, content: &'a str }
+        let messages = [
+            ChatMessage { role: "system", content: system_prompt },
+            ChatMessage { role: "user", content: user_prompt },
+        ];
+        #[derive(serde::Serialize)]
+        struct ChatReq<'a> { #[serde(skip_serializing_if="str::is_empty")] model: &'a str, messages: &'a [ChatMessage<'a>], temperature: f32 }
+        #[derive(serde::Deserialize)]
+        struct ChatRes { choices: Vec<ChatChoice> }
+        #[derive(serde::Deserialize)]
+        struct ChatChoice { message: ChatReply }
+        #[derive(serde::Deserialize)]
+        struct ChatReply { content: String }
+        let client = reqwest::blocking::Client::new();
+        let request = match provider {
+            LlmProvider::OpenAI => {
+                let cfg = self.openai.as_ref().ok_or_else(|| color_eyre::eyre::eyre!("OpenAI config is not set"))?;
+                let url = format!("{}/chat/completions", cfg.base_url.trim_end_matches('/'));
+                client.post(url)
+                    .bearer_auth(&cfg.api_key)
+                    .json(&ChatReq { model: model_name, messages: &messages, temperature: 0.0 })
+            }
+            LlmProvider::Azure => {
+                let cfg = self.azure.as_ref().ok_or_else(|| color_eyre::eyre::eyre!("Azure OpenAI config is not set"))?;
+                // Azure selects the model by deployment (base_url), so the model name is omitted
+                let url = format!("{}/chat/completions?api-version={}", cfg.base_url.trim_end_matches('/'), cfg.api_version);
+                client.post(url)
+                    .header("api-key", &cfg.api_key)
+                    .json(&ChatReq { model: "", messages: &messages, temperature: 0.0 })
+            }
+            LlmProvider::OpenAICompatible => {
+                let cfg = self.openai_compatible.as_ref().ok_or_else(|| color_eyre::eyre::eyre!("OpenAI-compatible endpoint config is not set"))?;
+                let url = format!("{}/chat/completions", cfg.base_url.trim_end_matches('/'));
+                let model = if model_name.is_empty() { cfg.model.as_str() } else { model_name };
+                let request = client.post(url)
+                    .json(&ChatReq { model, messages: &messages, temperature: 0.0 });
+                if cfg.api_key.is_empty() { request } else { request.bearer_auth(&cfg.api_key) }
+            }
+            LlmProvider::Ollama => {
+                let cfg = self.ollama.as_ref().ok_or_else(|| color_eyre::eyre::eyre!("Ollama config is not set"))?;
+                let url = format!("{}/api/chat", cfg.host.trim_end_matches('/'));
+                #[derive(serde::Serialize)]
+                struct OllamaChatReq<'a> { model: &'a str, messages: &'a [ChatMessage<'a>], stream: bool }
+                #[derive(serde::Deserialize)]
+                struct OllamaChatRes { message: ChatReply }
+                let res = client.post(url)
+                    .json(&OllamaChatReq { model: model_name, messages: &messages, stream: false })
+                    .send()
+                    .map_err(|e| color_eyre::eyre::eyre!("Ollama chat request failed: {e}"))?;
+                if !res.status().is_success() { return Err(color_eyre::eyre::eyre!("Ollama chat HTTP error: {}", res.status())); }
+                let parsed: OllamaChatRes = res.json().map_err(|e| color_eyre::eyre::eyre!("Ollama chat parse failed: {e}"))?;
+                return Ok(parsed.message.content);
+            }
+        };
+        let res = request.send()
+            .map_err(|e| color_eyre::eyre::eyre!("{} chat request failed: {e}", provider.display_name()))?;
+        if !res.status().is_success() { return Err(color_eyre::eyre::eyre!("{} chat HTTP error: {}", provider.display_name(), res.status())); }
+        let parsed: ChatRes = res.json().map_err(|e| color_eyre::eyre::eyre!("{} chat parse failed: {e}", provider.display_name()))?;
+        parsed.choices.into_iter().next()
+            .map(|c| c.message.content)
+            .ok_or_else(|| color_eyre::eyre::eyre!("{} returned no completion", provider.display_name()))
+    }
+
     fn fetch_openai_embeddings(
         &self,
         model_name: &str,
@@ -263,6 +422,35 @@ impl LlmConfig {
         Ok(parsed.data.into_iter().map(|d| d.embedding).collect())
     }
 
+    fn fetch_openai_compatible_embeddings(
+        &self,
+        model_name: &str,
+        inputs: &Vec<String>,
+        dims_opt: Option<usize>,
+    ) -> color_eyre::Result<Vec<Vec<f32>>> {
+        let cfg = self.openai_compatible.as_ref().ok_or_else(|| color_eyre::eyre::eyre!("OpenAI-compatible endpoint config is not set"))?;
+        let url = format!("{}/embeddings", cfg.base_url.trim_end_matches('/'));
+        let model = if model_name.is_empty() { cfg.model.as_str() } else { model_name };
+        let client = reqwest::blocking::Client::new();
+        #[derive(serde::Serialize)]
+        struct CompatEmbReq<'a> { model: &'a str, input: &'a Vec<String>, #[serde(skip_serializing_if="Option::is_none")] dimensions: Option<usize> }
+        #[derive(serde::Deserialize)]
+        struct CompatEmbRes { data: Vec<CompatEmbDatum> }
+        #[derive(serde::Deserialize)]
+        struct CompatEmbDatum { embedding: Vec<f32> }
+        let req = CompatEmbReq { model, input: inputs, dimensions: dims_opt };
+        let mut request = client.post(url).json(&req);
+        // Most local servers ignore auth; only send a key when one is configured
+        if !cfg.api_key.is_empty() {
+            request = request.bearer_auth(&cfg.api_key);
+        }
+        let res = request.send()
+            .map_err(|e| color_eyre::eyre::eyre!("OpenAI-compatible embeddings request failed: {e}"))?;
+        if !res.status().is_success() { return Err(color_eyre::eyre::eyre!("OpenAI-compatible embeddings HTTP error: {}", res.status())); }
+        let parsed: CompatEmbRes = res.json().map_err(|e| color_eyre::eyre::eyre!("OpenAI-compatible embeddings parse failed: {e}"))?;
+        Ok(parsed.data.into_iter().map(|d| d.embedding).collect())
+    }
+
     fn fetch_azure_embeddings(
         &self,
         model_name: &str,
@@ -337,6 +525,7 @@ impl LlmClientDialog {
             azure_dialog: AzureOpenAiConfigDialog::new(),
             openai_dialog: OpenAiConfigDialog::new(),
             ollama_dialog: OllamaConfigDialog::new(),
+            openai_compatible_dialog: OpenAiCompatibleConfigDialog::new(),
         }
     }
 
@@ -362,6 +551,11 @@ impl LlmClientDialog {
         } else {
             OllamaConfigDialog::new_with_config(config.clone(), OllamaConfig::default())
         };
+
+        let openai_compatible_dialog = OpenAiCompatibleConfigDialog::new_with_config(
+            config.clone(),
+            llm_config.openai_compatible.clone().unwrap_or_default(),
+        );
         
         Self {
             mode: LlmClientDialogMode::ProviderSelection,
@@ -374,6 +568,7 @@ impl LlmClientDialog {
             azure_dialog,
             openai_dialog,
             ollama_dialog,
+            openai_compatible_dialog,
         }
     }
 
@@ -391,6 +586,7 @@ impl LlmClientDialog {
             ListItem::new("Azure OpenAI"),
             ListItem::new("OpenAI"),
             ListItem::new("Ollama"),
+            ListItem::new("OpenAI-Compatible"),
         ]
     }
 
@@ -400,6 +596,7 @@ impl LlmClientDialog {
                 0 => LlmProvider::Azure,
                 1 => LlmProvider::OpenAI,
                 2 => LlmProvider::Ollama,
+                3 => LlmProvider::OpenAICompatible,
                 _ => LlmProvider::OpenAI,
             };
         }
@@ -411,6 +608,7 @@ impl LlmClientDialog {
             LlmProvider::Azure => LlmClientDialogMode::AzureConfiguration,
             LlmProvider::OpenAI => LlmClientDialogMode::OpenAIConfiguration,
             LlmProvider::Ollama => LlmClientDialogMode::OllamaConfiguration,
+            LlmProvider::OpenAICompatible => LlmClientDialogMode::OpenAICompatibleConfiguration,
         };
     }
 
@@ -461,6 +659,9 @@ impl LlmClientDialog {
             LlmClientDialogMode::OllamaConfiguration => {
                 self.ollama_dialog.render(area, buf);
             }
+            LlmClientDialogMode::OpenAICompatibleConfiguration => {
+                self.openai_compatible_dialog.render(area, buf);
+            }
             LlmClientDialogMode::Error(msg) => {
                 let y = content_area.y;
                 buf.set_string(content_area.x, y, "Error:", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD));
@@ -520,14 +721,14 @@ impl LlmClientDialog {
                         }
                         Action::Up => {
                             if let Some(selected) = self.provider_list_state.selected() {
-                                let new_selection = if selected == 0 { 2 } else { selected - 1 };
+                                let new_selection = if selected == 0 { 3 } else { selected - 1 };
                                 self.provider_list_state.select(Some(new_selection));
                             }
                             return None;
                         }
                         Action::Down => {
                             if let Some(selected) = self.provider_list_state.selected() {
-                                let new_selection = if selected == 2 { 0 } else { selected + 1 };
+                                let new_selection = if selected == 3 { 0 } else { selected + 1 };
                                 self.provider_list_state.select(Some(new_selection));
                             }
                             return None;
@@ -547,14 +748,14 @@ impl LlmClientDialog {
                     }
                     KeyCode::Up => {
                         if let Some(selected) = self.provider_list_state.selected() {
-                            let new_selection = if selected == 0 { 2 } else { selected - 1 };
+                            let new_selection = if selected == 0 { 3 } else { selected - 1 };
                             self.provider_list_state.select(Some(new_selection));
                         }
                         return None;
                     }
                     KeyCode::Down => {
                         if let Some(selected) = self.provider_list_state.selected() {
-                            let new_selection = if selected == 2 { 0 } else { selected + 1 };
+                            let new_selection = if selected == 3 { 0 } else { selected + 1 };
                             self.provider_list_state.select(Some(new_selection));
                         }
                         return None;
@@ -613,6 +814,22 @@ impl LlmClientDialog {
                     }
                 }
             }
+            LlmClientDialogMode::OpenAICompatibleConfiguration => {
+                // Delegate to OpenAI-compatible endpoint dialog
+                if let Some(action) = self.openai_compatible_dialog.handle_key_event(key) {
+                    match action {
+                        Action::LlmClientDialogApplied(config) => {
+                            self.llm_config.openai_compatible = config.openai_compatible;
+                            return Some(Action::LlmClientDialogApplied(self.llm_config.clone()));
+                        }
+                        Action::DialogClose => {
+                            self.mode = LlmClientDialogMode::ProviderSelection;
+                            return None;
+                        }
+                        _ => return Some(action),
+                    }
+                }
+            }
             LlmClientDialogMode::Error(_) => {
                 // Only close error on Esc or Enter
                 if let Some(Action::Escape | Action::Enter) = &optional_global_action {
@@ -645,7 +862,8 @@ impl Component for LlmClientDialog {
         // Register with individual dialogs
         self.azure_dialog.register_action_handler(_tx.clone())?;
         self.openai_dialog.register_action_handler(_tx.clone())?;
-        self.ollama_dialog.register_action_handler(_tx)?;
+        self.ollama_dialog.register_action_handler(_tx.clone())?;
+        self.openai_compatible_dialog.register_action_handler(_tx)?;
         Ok(())
     }
     fn register_config_handler(&mut self, _config: crate::config::Config) -> Result<()> {
@@ -653,7 +871,8 @@ impl Component for LlmClientDialog {
         // Register with individual dialogs
         self.azure_dialog.register_config_handler(_config.clone())?;
         self.openai_dialog.register_config_handler(_config.clone())?;
-        self.ollama_dialog.register_config_handler(_config)?;
+        self.ollama_dialog.register_config_handler(_config.clone())?;
+        self.openai_compatible_dialog.register_config_handler(_config)?;
         Ok(())
     }
     fn init(&mut self, _area: ratatui::layout::Size) -> Result<()> {
@@ -661,6 +880,7 @@ impl Component for LlmClientDialog {
         self.azure_dialog.init(_area)?;
         self.openai_dialog.init(_area)?;
         self.ollama_dialog.init(_area)?;
+        self.openai_compatible_dialog.init(_area)?;
         Ok(())
     }
     fn handle_events(&mut self, _event: Option<crate::tui::Event>) -> Result<Option<Action>> {
@@ -0,0 +1,263 @@
+//! MissingDataDialog: null/empty share per column, with drop and fill fixes for the worst columns
+
+use std::sync::Arc;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use polars::prelude::DataFrame;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Clear, Paragraph, Wrap};
+use tui_textarea::TextArea;
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+use crate::dataframe::missing::{columns_over_threshold, drop_columns, fill_missing, missing_report, ColumnMissing};
+
+const BAR_WIDTH: usize = 20;
+const THRESHOLD_STEP: f64 = 5.0;
+
+/// MissingDataDialog: columns sorted by missing share; the ones above the threshold are highlighted
+#[derive(Debug)]
+pub struct MissingDataDialog {
+    pub dataset_name: String,
+    /// Sorted most-missing first
+    pub report: Vec<ColumnMissing>,
+    /// Percent; columns strictly above it are acted on
+    pub threshold: f64,
+    pub selected: usize,
+    pub scroll_offset: usize,
+    pub fill_input: TextArea<'static>,
+    pub error: Option<String>,
+    pub show_instructions: bool,
+    pub config: Config,
+    df: Arc<DataFrame>,
+}
+
+impl MissingDataDialog {
+    pub fn new(dataset_name: &str, df: Arc<DataFrame>) -> Self {
+        let (mut report, error) = match missing_report(&df) {
+            Ok(report) => (report, None),
+            Err(e) => (Vec::new(), Some(e.to_string())),
+        };
+        report.sort_by_key(|c| std::cmp::Reverse(c.missing()));
+        let mut fill_input = TextArea::default();
+        fill_input.set_block(Block::default());
+        fill_input.set_placeholder_text("value for Fill");
+        Self {
+            dataset_name: dataset_name.to_string(),
+            report,
+            threshold: 50.0,
+            selected: 0,
+            scroll_offset: 0,
+            fill_input,
+            error,
+            show_instructions: true,
+            config: Config::default(),
+            df,
+        }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    pub fn flagged_columns(&self) -> Vec<String> {
+        columns_over_threshold(&self.report, self.threshold)
+    }
+
+    fn build_instructions_from_config(&self) -> String {
+        format!(
+            "{}  Left/Right: threshold  Type to set the fill value",
+            self.config.actions_to_instructions(&[
+                (Mode::MissingDataDialog, Action::DropMissingColumns),
+                (Mode::MissingDataDialog, Action::FillMissingColumns),
+                (Mode::Global, Action::Escape),
+                (Mode::Global, Action::ToggleInstructions),
+            ])
+        )
+    }
+
+    /// Render the dialog
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title("Missing Data")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(3)])
+            .split(layout.content_area);
+
+        let flagged = self.flagged_columns().len();
+        let settings = Block::default()
+            .title(format!("Threshold > {:.0}%: {flagged} of {} columns", self.threshold, self.report.len()))
+            .borders(Borders::ALL);
+        let settings_inner = settings.inner(chunks[0]);
+        settings.render(chunks[0], buf);
+        let label = "Fill value: ";
+        buf.set_string(settings_inner.x + 1, settings_inner.y, label, Style::default());
+        let fill_area = Rect {
+            x: settings_inner.x + 1 + label.len() as u16,
+            width: settings_inner.width.saturating_sub(label.len() as u16 + 2),
+            ..settings_inner
+        };
+        self.fill_input.render(fill_area, buf);
+
+        let list = Block::default().title("Columns").borders(Borders::ALL);
+        let list_inner = list.inner(chunks[1]);
+        list.render(chunks[1], buf);
+        let visible = list_inner.height as usize;
+        if self.selected < self.scroll_offset {
+            self.scroll_offset = self.selected;
+        } else if visible > 0 && self.selected >= self.scroll_offset + visible {
+            self.scroll_offset = self.selected + 1 - visible;
+        }
+        let name_width = self.report.iter().map(|c| c.name.chars().count()).max().unwrap_or(0).min(30);
+        for (i, column) in self.report.iter().enumerate().skip(self.scroll_offset).take(visible) {
+            let y = list_inner.y + (i - self.scroll_offset) as u16;
+            let pct = column.percent();
+            let filled = ((pct / 100.0) * BAR_WIDTH as f64).round() as usize;
+            let bar = format!("{}{}", "█".repeat(filled), "░".repeat(BAR_WIDTH - filled.min(BAR_WIDTH)));
+            let name: String = column.name.chars().take(name_width).collect();
+            let line = Line::from(vec![
+                Span::raw(format!(" {name:<name_width$}  ")),
+                Span::styled(bar, Style::default().fg(if pct > self.threshold { Color::Red } else { Color::Green })),
+                Span::raw(format!(
+                    " {pct:>5.1}%  {} null, {} empty of {}  {}",
+                    column.nulls, column.empty, column.rows, column.dtype
+                )),
+            ]);
+            let style = if i == self.selected { Style::default().bg(Color::DarkGray) } else { Style::default() };
+            buf.set_style(Rect { x: list_inner.x, y, width: list_inner.width, height: 1 }, style);
+            buf.set_line(list_inner.x, y, &line, list_inner.width);
+        }
+        if let Some(error) = &self.error {
+            let y = list_inner.bottom().saturating_sub(1);
+            buf.set_string(list_inner.x + 1, y, format!("Error: {error}"), Style::default().fg(Color::Red).add_modifier(Modifier::BOLD));
+        }
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// New dataset built from the flagged columns, or None (with `error` set) when nothing applies
+    fn apply(&mut self, fill: bool) -> Option<Action> {
+        let columns = self.flagged_columns();
+        if columns.is_empty() {
+            self.error = Some(format!("No columns above {:.0}% missing", self.threshold));
+            return None;
+        }
+        let value = self.fill_input.lines().join("");
+        let result = if fill {
+            if value.is_empty() {
+                self.error = Some("Type a fill value first".to_string());
+                return None;
+            }
+            fill_missing(&self.df, &columns, &value)
+        } else {
+            drop_columns(&self.df, &columns)
+        };
+        match result {
+            Ok(df) => Some(Action::SqlDialogAppliedNewDataset {
+                dataset_name: format!("{}_{}", self.dataset_name, if fill { "filled" } else { "dropped" }),
+                dataframe: Arc::new(df),
+            }),
+            Err(e) => {
+                self.error = Some(e.to_string());
+                None
+            }
+        }
+    }
+
+    /// New dataset without the flagged columns, as if the drop key was pressed
+    pub fn drop_flagged(&mut self) -> Option<Action> {
+        self.apply(false)
+    }
+
+    /// Handle keyboard events for the dialog
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        match self.config.action_for_key(Mode::MissingDataDialog, key) {
+            Some(Action::DropMissingColumns) => return self.apply(false),
+            Some(Action::FillMissingColumns) => return self.apply(true),
+            _ => {}
+        }
+        match self.config.action_for_key(Mode::Global, key) {
+            Some(Action::Escape) => return Some(Action::DialogClose),
+            Some(Action::Up) => self.selected = self.selected.saturating_sub(1),
+            Some(Action::Down) => self.selected = (self.selected + 1).min(self.report.len().saturating_sub(1)),
+            Some(Action::Left) => self.threshold = (self.threshold - THRESHOLD_STEP).max(0.0),
+            Some(Action::Right) => self.threshold = (self.threshold + THRESHOLD_STEP).min(100.0),
+            Some(Action::ToggleInstructions) => self.show_instructions = !self.show_instructions,
+            _ => {
+                if matches!(key.code, KeyCode::Char(_) | KeyCode::Backspace | KeyCode::Delete) {
+                    self.fill_input.input(tui_textarea::Input::from(key));
+                    self.error = None;
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+    use polars::prelude::*;
+
+    fn press(dialog: &mut MissingDataDialog, code: KeyCode, modifiers: KeyModifiers) -> Option<Action> {
+        dialog.handle_key_event(KeyEvent::new(code, modifiers))
+    }
+
+    #[test]
+    fn test_threshold_drop_and_fill() {
+        let df = df!(
+            "a" => [Some(1i64), None, None, None],
+            "b" => [Some("x"), Some(""), Some("y"), Some("z")],
+            "c" => [1i64, 2, 3, 4]
+        )
+        .unwrap();
+        let mut dialog = MissingDataDialog::new("logs", Arc::new(df));
+        dialog.config.reset_keybindings_to_default();
+        assert_eq!(dialog.report[0].name, "a");
+        assert_eq!(dialog.flagged_columns(), ["a"]);
+        for _ in 0..6 {
+            press(&mut dialog, KeyCode::Left, KeyModifiers::NONE);
+        }
+        assert_eq!(dialog.flagged_columns(), ["a", "b"]);
+
+        match press(&mut dialog, KeyCode::Char('d'), KeyModifiers::CONTROL) {
+            Some(Action::SqlDialogAppliedNewDataset { dataset_name, dataframe }) => {
+                assert_eq!(dataset_name, "logs_dropped");
+                assert_eq!(dataframe.get_column_names(), ["c"]);
+            }
+            other => panic!("expected a new dataset, got {other:?}"),
+        }
+        assert!(press(&mut dialog, KeyCode::Char('f'), KeyModifiers::CONTROL).is_none());
+        assert!(dialog.error.is_some());
+        press(&mut dialog, KeyCode::Char('0'), KeyModifiers::NONE);
+        match press(&mut dialog, KeyCode::Char('f'), KeyModifiers::CONTROL) {
+            Some(Action::SqlDialogAppliedNewDataset { dataframe, .. }) => {
+                assert_eq!(dataframe.column("a").unwrap().null_count(), 0);
+                assert_eq!(dataframe.column("b").unwrap().str().unwrap().get(1), Some("0"));
+            }
+            other => panic!("expected a new dataset, got {other:?}"),
+        }
+    }
+}
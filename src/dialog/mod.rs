@@ -1,10 +1,18 @@
 pub mod sort_dialog;
 pub mod filter_dialog;
 pub mod sql_dialog;
+pub mod ask_data_dialog;
 pub mod column_width_dialog;
 pub mod file_browser_dialog;
 pub mod find_dialog;
 pub mod find_all_results_dialog;
+pub mod record_view_dialog;
+pub mod group_by_dialog;
+pub mod heatmap_dialog;
+pub mod display_settings_dialog;
+pub mod hex_viewer_dialog;
+pub mod cell_viewer_dialog;
+pub mod open_target_dialog;
 pub mod data_import_dialog;
 pub mod csv_options_dialog;
 pub mod xlsx_options_dialog;
@@ -14,7 +22,9 @@ pub mod json_options_dialog;
 pub mod data_management_dialog;
 pub mod data_tab_manager_dialog;
 pub mod alias_edit_dialog;
+pub mod dataset_notes_dialog;
 pub mod project_settings_dialog;
+pub mod confirm_dialog;
 pub mod error_dialog;
 pub mod message_dialog;
 pub mod jmes_dialog;
@@ -24,11 +34,35 @@ pub mod data_export_dialog;
 pub mod keybinding_capture_dialog;
 pub mod column_operations_dialog;
 pub mod column_operation_options_dialog;
+pub mod cluster_report_dialog;
 pub mod keybindings_dialog;
+pub mod help_dialog;
 pub mod llm_client_dialog;
 pub mod llm_client_create_dialog;
 pub mod llm;
 pub mod embeddings_prompt_dialog;
+pub mod transform_history_dialog;
+pub mod checkpoints_dialog;
+pub mod chart_dialog;
+pub mod benford_dialog;
+pub mod sample_dialog;
+pub mod schema_editor_dialog;
+pub mod resample_dialog;
+pub mod crosstab_dialog;
+pub mod missing_data_dialog;
+pub mod sigma_dialog;
+pub mod pattern_set_dialog;
+pub mod row_tags_dialog;
+pub mod validation_dialog;
+pub mod column_menu_dialog;
+pub mod go_to_column_dialog;
+pub mod go_to_row_dialog;
+pub mod findings_report_dialog;
+pub mod threat_intel_settings_dialog;
+pub mod script_dialog;
+pub mod path_remap_dialog;
+pub mod quick_open_dialog;
+pub mod tab_switcher_dialog;
 pub mod styling;
 pub use filter_dialog::{FilterCondition, ColumnFilter};
 pub use column_width_dialog::ColumnWidthConfig;
@@ -42,10 +76,42 @@ pub use json_options_dialog::{JsonOptionsDialog, JsonImportOptions};
 pub use data_management_dialog::{DataManagementDialog, DataSource, Dataset, DatasetStatus};
 pub use data_tab_manager_dialog::{DataTabManagerDialog, DataTab};
 pub use alias_edit_dialog::AliasEditDialog;
+pub use dataset_notes_dialog::DatasetNotesDialog;
 pub use project_settings_dialog::{ProjectSettingsDialog, ProjectSettingsConfig};
+pub use confirm_dialog::{ConfirmDialog, ConfirmKind};
 pub use error_dialog::ErrorDialog;
 pub use message_dialog::MessageDialog;
 pub use jmes_dialog::JmesPathDialog;
+pub use record_view_dialog::RecordViewDialog;
+pub use group_by_dialog::GroupByDialog;
+pub use heatmap_dialog::{HeatmapDialog, HeatmapConfig, HeatmapColumnConfig};
+pub use hex_viewer_dialog::HexViewerDialog;
+pub use ask_data_dialog::AskDataDialog;
+pub use transform_history_dialog::TransformHistoryDialog;
+pub use checkpoints_dialog::CheckpointsDialog;
+pub use chart_dialog::ChartDialog;
+pub use benford_dialog::BenfordDialog;
+pub use sample_dialog::SampleDialog;
+pub use schema_editor_dialog::SchemaEditorDialog;
+pub use resample_dialog::ResampleDialog;
+pub use crosstab_dialog::CrosstabDialog;
+pub use missing_data_dialog::MissingDataDialog;
+pub use sigma_dialog::SigmaDialog;
+pub use pattern_set_dialog::PatternSetDialog;
+pub use row_tags_dialog::RowTagsDialog;
+pub use validation_dialog::ValidationDialog;
+pub use column_menu_dialog::ColumnMenuDialog;
+pub use go_to_column_dialog::GoToColumnDialog;
+pub use go_to_row_dialog::GoToRowDialog;
+pub use findings_report_dialog::FindingsReportDialog;
+pub use threat_intel_settings_dialog::ThreatIntelSettingsDialog;
+pub use script_dialog::ScriptDialog;
+pub use path_remap_dialog::PathRemapDialog;
+pub use quick_open_dialog::QuickOpenDialog;
+pub use tab_switcher_dialog::TabSwitcherDialog;
+pub use cell_viewer_dialog::CellViewerDialog;
+pub use open_target_dialog::{OpenTargetDialog, OpenTarget};
+pub use display_settings_dialog::{DisplaySettingsDialog, DisplaySettings, ColumnDisplayFormat};
 
 use serde::{Deserialize, Serialize};
 use strum::Display;
@@ -59,12 +125,14 @@ pub use dataframe_details_dialog::DataFrameDetailsDialog;
 pub use table_export_dialog::TableExportDialog;
 pub use data_export_dialog::{DataExportDialog, DataExportFormat};
 pub use column_operations_dialog::{ColumnOperationsDialog, ColumnOperationsMode, ColumnOperationKind};
-pub use column_operation_options_dialog::{ColumnOperationOptionsDialog, ColumnOperationOptionsMode, ColumnOperationConfig, ClusterAlgorithm, KmeansOptions, DbscanOptions, OperationOptions};
+pub use column_operation_options_dialog::{ColumnOperationOptionsDialog, ColumnOperationOptionsMode, ColumnOperationConfig, ColumnOperationPreset, ClusterAlgorithm, KmeansOptions, DbscanOptions, OperationOptions};
+pub use cluster_report_dialog::ClusterReportDialog;
 pub use keybindings_dialog::KeybindingsDialog;
+pub use help_dialog::HelpDialog;
 pub use keybinding_capture_dialog::KeybindingCaptureDialog;
 pub use llm_client_dialog::{LlmClientDialog, LlmProvider, LlmConfig};
 pub use llm_client_create_dialog::{LlmClientCreateDialog, LlmClientCreateMode, LlmClientSelection};
-pub use llm::{AzureOpenAiConfig, OpenAIConfig, OllamaConfig};
+pub use llm::{AzureOpenAiConfig, OpenAIConfig, OllamaConfig, OpenAICompatibleConfig};
 pub use embeddings_prompt_dialog::EmbeddingsPromptDialog;
 pub use styling::{
     StyleSet, StyleRule, MatchedStyle, 
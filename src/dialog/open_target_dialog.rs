@@ -0,0 +1,211 @@
+//! OpenTargetDialog: Confirm opening a URL or file path from the selected cell with the system handler
+
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+use crossterm::event::{KeyEvent, KeyEventKind};
+use lazy_static::lazy_static;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Clear, Paragraph, Wrap};
+use regex::Regex;
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+
+lazy_static! {
+    static ref URL_PATTERN: Regex = Regex::new(r#"(?i)\b(?:https?|ftp|file)://[^\s"'<>]+|\bmailto:[^\s"'<>]+"#).unwrap();
+}
+
+/// Something in a cell that can be opened with the system handler
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OpenTarget {
+    Url(String),
+    Path(PathBuf),
+}
+
+impl std::fmt::Display for OpenTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OpenTarget::Url(url) => write!(f, "{url}"),
+            OpenTarget::Path(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Find a URL in the text, or treat the whole (unquoted) text as an existing local path
+pub fn detect_open_target(text: &str) -> Option<OpenTarget> {
+    if let Some(m) = URL_PATTERN.find(text) {
+        let url = m.as_str().trim_end_matches(['.', ',', ';', ')', ']']);
+        return Some(OpenTarget::Url(url.to_string()));
+    }
+    let candidate = text.trim().trim_matches(['"', '\'']);
+    if candidate.is_empty() || candidate.contains('\n') {
+        return None;
+    }
+    let path = match candidate.strip_prefix("~/") {
+        Some(rest) => directories::BaseDirs::new()?.home_dir().join(rest),
+        None => PathBuf::from(candidate),
+    };
+    path.exists().then_some(OpenTarget::Path(path))
+}
+
+fn system_open_command(target: &str) -> Command {
+    if cfg!(target_os = "windows") {
+        // Not through `cmd /C start`: cmd re-parses the line, so `&`, `|` or `^` in a URL taken
+        // from the data would run further commands
+        let mut cmd = Command::new("rundll32");
+        cmd.arg("url.dll,FileProtocolHandler").arg(target);
+        cmd
+    } else if cfg!(target_os = "macos") {
+        let mut cmd = Command::new("open");
+        cmd.arg(target);
+        cmd
+    } else {
+        let mut cmd = Command::new("xdg-open");
+        cmd.arg(target);
+        cmd
+    }
+}
+
+/// Launch the platform's default handler for the target without waiting for it
+pub fn open_with_system_handler(target: &OpenTarget) -> std::io::Result<()> {
+    let arg = match target {
+        OpenTarget::Url(url) => url.clone(),
+        OpenTarget::Path(path) => path.to_string_lossy().to_string(),
+    };
+    system_open_command(&arg)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+}
+
+/// OpenTargetDialog: ask before handing a cell's URL or path to the system
+#[derive(Debug)]
+pub struct OpenTargetDialog {
+    pub target: OpenTarget,
+    pub error: Option<String>,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+impl OpenTargetDialog {
+    pub fn new(target: OpenTarget) -> Self {
+        Self {
+            target,
+            error: None,
+            show_instructions: true,
+            config: Config::default(),
+        }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    /// Build instructions string from configured keybindings
+    fn build_instructions_from_config(&self) -> String {
+        self.config.actions_to_instructions(&[
+            (Mode::OpenTargetDialog, Action::ConfirmOpenTarget),
+            (Mode::OpenTargetDialog, Action::DenyOpenTarget),
+            (Mode::Global, Action::Escape),
+        ])
+    }
+
+    /// Render the dialog
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title("Open With System Handler")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions,
+            if instructions.is_empty() { None } else { Some(instructions.as_str()) });
+
+        let kind = match self.target {
+            OpenTarget::Url(_) => "URL",
+            OpenTarget::Path(ref p) if p.is_dir() => "folder",
+            OpenTarget::Path(_) => "file",
+        };
+        let mut lines = vec![
+            Line::from(format!("Open this {kind}?")),
+            Line::from(""),
+            Line::styled(self.target.to_string(), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+        ];
+        if let Some(err) = &self.error {
+            lines.push(Line::from(""));
+            lines.push(Line::styled(err.clone(), Style::default().fg(Color::Red)));
+        }
+        Paragraph::new(lines).wrap(Wrap { trim: false }).render(layout.content_area, buf);
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Handle keyboard events for the dialog
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        match self.config.action_for_key(Mode::OpenTargetDialog, key) {
+            Some(Action::ConfirmOpenTarget) => return Some(Action::ConfirmOpenTarget),
+            Some(Action::DenyOpenTarget) => return Some(Action::DialogClose),
+            _ => {}
+        }
+        match self.config.action_for_key(Mode::Global, key) {
+            Some(Action::Escape) => Some(Action::DialogClose),
+            Some(Action::Enter) => Some(Action::ConfirmOpenTarget),
+            Some(Action::ToggleInstructions) => {
+                self.show_instructions = !self.show_instructions;
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    #[test]
+    fn test_detect_open_target() {
+        assert_eq!(
+            detect_open_target("report at https://example.com/r/1?x=2)."),
+            Some(OpenTarget::Url("https://example.com/r/1?x=2".to_string()))
+        );
+        let dir = std::env::temp_dir();
+        let quoted = format!("\"{}\"", dir.display());
+        assert_eq!(detect_open_target(&quoted), Some(OpenTarget::Path(dir)));
+        assert_eq!(detect_open_target("/definitely/not/a/real/path/xyz"), None);
+        assert_eq!(detect_open_target("just text"), None);
+    }
+
+    #[test]
+    fn test_confirm_and_deny() {
+        let mut dialog = OpenTargetDialog::new(OpenTarget::Url("https://example.com".to_string()));
+        dialog.config.reset_keybindings_to_default();
+        assert_eq!(
+            dialog.handle_key_event(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE)),
+            Some(Action::ConfirmOpenTarget)
+        );
+        assert_eq!(
+            dialog.handle_key_event(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::NONE)),
+            Some(Action::DialogClose)
+        );
+    }
+}
@@ -0,0 +1,281 @@
+//! PathRemapDialog: point a workspace at data files that moved
+//!
+//! Shown when a workspace is opened and some dataset source files do not exist, e.g. after the
+//! project was copied to another machine or case share. Each missing file can be pointed at its
+//! new location, or a folder can be searched for files with the same names.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crossterm::event::{KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Cell, Clear, Paragraph, Row, Table, TableState, Wrap};
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+use crate::dialog::file_browser_dialog::{FileBrowserAction, FileBrowserDialog, FileBrowserMode};
+
+/// How deep the folder search descends below the chosen folder
+const SEARCH_DEPTH: usize = 4;
+
+#[derive(Debug)]
+pub enum PathRemapDialogMode {
+    List,
+    /// Choosing the new location of the selected file
+    PickFile(Box<FileBrowserDialog>),
+    /// Choosing a folder to search for all missing files
+    PickFolder(Box<FileBrowserDialog>),
+}
+
+/// A missing source file and where it was found
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathRemapEntry {
+    pub missing: PathBuf,
+    pub replacement: Option<PathBuf>,
+}
+
+#[derive(Debug)]
+pub struct PathRemapDialog {
+    pub entries: Vec<PathRemapEntry>,
+    pub selected: usize,
+    pub mode: PathRemapDialogMode,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+/// First file named `name` under `dir`, searching breadth-first up to `depth` levels down
+fn find_file_named(dir: &Path, name: &std::ffi::OsStr, depth: usize) -> Option<PathBuf> {
+    let mut level = vec![dir.to_path_buf()];
+    for _ in 0..=depth {
+        let mut next = Vec::new();
+        for folder in level {
+            let Ok(read_dir) = std::fs::read_dir(&folder) else { continue };
+            let mut children: Vec<PathBuf> = read_dir.flatten().map(|e| e.path()).collect();
+            children.sort();
+            for child in children {
+                if child.is_dir() {
+                    next.push(child);
+                } else if child.file_name() == Some(name) {
+                    return Some(child);
+                }
+            }
+        }
+        level = next;
+    }
+    None
+}
+
+impl PathRemapDialog {
+    pub fn new(missing: Vec<PathBuf>) -> Self {
+        Self {
+            entries: missing.into_iter().map(|missing| PathRemapEntry { missing, replacement: None }).collect(),
+            selected: 0,
+            mode: PathRemapDialogMode::List,
+            show_instructions: true,
+            config: Config::default(),
+        }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    /// Missing path -> replacement for every file that has been located
+    pub fn remaps(&self) -> HashMap<PathBuf, PathBuf> {
+        self.entries
+            .iter()
+            .filter_map(|e| e.replacement.clone().map(|r| (e.missing.clone(), r)))
+            .collect()
+    }
+
+    /// Use `replacement` for entry `index`, and try the same folder move for the other missing
+    /// files from the same original folder
+    pub fn set_replacement(&mut self, index: usize, replacement: PathBuf) {
+        let Some(entry) = self.entries.get_mut(index) else { return };
+        let old_dir = entry.missing.parent().map(Path::to_path_buf);
+        let new_dir = replacement.parent().map(Path::to_path_buf);
+        entry.replacement = Some(replacement);
+        let (Some(old_dir), Some(new_dir)) = (old_dir, new_dir) else { return };
+        for other in self.entries.iter_mut().filter(|e| e.replacement.is_none()) {
+            if let (Some(parent), Some(name)) = (other.missing.parent(), other.missing.file_name()) {
+                let candidate = new_dir.join(name);
+                if parent == old_dir && candidate.exists() {
+                    other.replacement = Some(candidate);
+                }
+            }
+        }
+    }
+
+    /// Look for each unresolved file by name below `dir`
+    pub fn search_folder(&mut self, dir: &Path) {
+        for entry in self.entries.iter_mut().filter(|e| e.replacement.is_none()) {
+            if let Some(name) = entry.missing.file_name() {
+                entry.replacement = find_file_named(dir, name, SEARCH_DEPTH);
+            }
+        }
+    }
+
+    fn build_instructions_from_config(&self) -> String {
+        format!(
+            "Enter: Locate file  {}",
+            self.config.actions_to_instructions(&[
+                (Mode::PathRemapDialog, Action::SearchFolderForMissingFiles),
+                (Mode::PathRemapDialog, Action::ApplyPathRemap),
+                (Mode::Global, Action::Escape),
+                (Mode::Global, Action::ToggleInstructions),
+            ])
+        )
+    }
+
+    /// Render the dialog
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        match &self.mode {
+            PathRemapDialogMode::PickFile(browser) | PathRemapDialogMode::PickFolder(browser) => {
+                browser.render(area, buf);
+                return;
+            }
+            PathRemapDialogMode::List => {}
+        }
+        Clear.render(area, buf);
+        let found = self.entries.iter().filter(|e| e.replacement.is_some()).count();
+        let outer_block = Block::default()
+            .title(format!("Missing Data Files ({found}/{} located)", self.entries.len()))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let content = layout.content_area;
+
+        let rows = self.entries.iter().map(|entry| {
+            let (status, style) = match &entry.replacement {
+                Some(path) => (path.display().to_string(), Style::default().fg(Color::Green)),
+                None => ("(not found)".to_string(), Style::default().fg(Color::Red)),
+            };
+            Row::new(vec![Cell::from(entry.missing.display().to_string()), Cell::from(status).style(style)])
+        });
+        let table = Table::new(rows, [Constraint::Percentage(50), Constraint::Percentage(50)])
+            .header(
+                Row::new(vec!["Saved path", "New location"])
+                    .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            )
+            .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        let mut state = TableState::default().with_selected(Some(self.selected));
+        StatefulWidget::render(table, content, buf, &mut state);
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Handle keyboard events; returns `ApplyPathRemap` when done, `DialogClose` to open as is
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        match &mut self.mode {
+            PathRemapDialogMode::PickFile(browser) => {
+                match browser.handle_key_event(key) {
+                    Some(FileBrowserAction::Selected(path)) => {
+                        self.mode = PathRemapDialogMode::List;
+                        self.set_replacement(self.selected, path);
+                    }
+                    Some(FileBrowserAction::Cancelled) => self.mode = PathRemapDialogMode::List,
+                    None => {}
+                }
+                return None;
+            }
+            PathRemapDialogMode::PickFolder(browser) => {
+                match browser.handle_key_event(key) {
+                    Some(FileBrowserAction::Selected(path)) => {
+                        self.mode = PathRemapDialogMode::List;
+                        self.search_folder(&path);
+                    }
+                    Some(FileBrowserAction::Cancelled) => self.mode = PathRemapDialogMode::List,
+                    None => {}
+                }
+                return None;
+            }
+            PathRemapDialogMode::List => {}
+        }
+
+        match self.config.action_for_key(Mode::Global, key) {
+            Some(Action::Escape) => return Some(Action::DialogClose),
+            Some(Action::Enter) => {
+                if let Some(entry) = self.entries.get(self.selected) {
+                    let start = entry.missing.parent().filter(|p| p.is_dir()).map(Path::to_path_buf);
+                    let mut browser = FileBrowserDialog::new(start, None, false, FileBrowserMode::Load);
+                    browser.register_config_handler(self.config.clone());
+                    self.mode = PathRemapDialogMode::PickFile(Box::new(browser));
+                }
+                return None;
+            }
+            Some(Action::Up) => {
+                self.selected = self.selected.saturating_sub(1);
+                return None;
+            }
+            Some(Action::Down) => {
+                if self.selected + 1 < self.entries.len() {
+                    self.selected += 1;
+                }
+                return None;
+            }
+            Some(Action::ToggleInstructions) => {
+                self.show_instructions = !self.show_instructions;
+                return None;
+            }
+            _ => {}
+        }
+
+        match self.config.action_for_key(Mode::PathRemapDialog, key) {
+            Some(Action::SearchFolderForMissingFiles) => {
+                let mut browser = FileBrowserDialog::new(None, None, true, FileBrowserMode::Load);
+                browser.register_config_handler(self.config.clone());
+                self.mode = PathRemapDialogMode::PickFolder(Box::new(browser));
+                None
+            }
+            Some(Action::ApplyPathRemap) => Some(Action::ApplyPathRemap),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_folder_search_and_sibling_remap() {
+        let root = std::env::temp_dir().join(format!("datatui_remap_{}", std::process::id()));
+        let evidence = root.join("share").join("evidence");
+        std::fs::create_dir_all(&evidence).unwrap();
+        for name in ["a.csv", "b.csv", "c.json"] {
+            std::fs::write(evidence.join(name), "x").unwrap();
+        }
+
+        let mut dialog = PathRemapDialog::new(vec![
+            PathBuf::from("/old/case/evidence/a.csv"),
+            PathBuf::from("/old/case/evidence/b.csv"),
+            PathBuf::from("/old/elsewhere/c.json"),
+            PathBuf::from("/old/elsewhere/gone.csv"),
+        ]);
+        dialog.set_replacement(0, evidence.join("a.csv"));
+        assert_eq!(dialog.entries[1].replacement, Some(evidence.join("b.csv")));
+        assert_eq!(dialog.entries[2].replacement, None);
+
+        dialog.search_folder(&root);
+        assert_eq!(dialog.entries[2].replacement, Some(evidence.join("c.json")));
+        assert_eq!(dialog.entries[3].replacement, None);
+        assert_eq!(dialog.remaps().len(), 3);
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
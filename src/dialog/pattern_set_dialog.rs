@@ -0,0 +1,301 @@
+//! PatternSetDialog: apply a file of named regex patterns to one or more columns
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Clear, Paragraph, Wrap};
+use tui_textarea::TextArea;
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+use crate::dataframe::pattern_sets::{PatternOutput, PatternSetOptions, DEFAULT_PATTERNS_COLUMN};
+
+const FIELD_PATTERNS_PATH: usize = 0;
+const FIELD_COLUMNS: usize = 1;
+const FIELD_CASE: usize = 2;
+const FIELD_OUTPUT: usize = 3;
+const FIELD_COLUMN: usize = 4;
+const FIELD_COUNT: usize = 5;
+
+fn text_input(placeholder: &str) -> TextArea<'static> {
+    let mut t = TextArea::default();
+    t.set_block(Block::default());
+    t.set_placeholder_text(placeholder.to_string());
+    t
+}
+
+fn input_text(input: &TextArea<'static>) -> String {
+    input.lines().join("").trim().to_string()
+}
+
+/// PatternSetDialog: kept by the container so the pattern file is remembered between runs
+#[derive(Debug)]
+pub struct PatternSetDialog {
+    pub patterns_path_input: TextArea<'static>,
+    pub columns_input: TextArea<'static>,
+    pub case_insensitive: bool,
+    pub output: PatternOutput,
+    pub column_input: TextArea<'static>,
+    /// Columns of the current data, to check the searched columns against
+    pub available_columns: Vec<String>,
+    pub selected_field: usize,
+    /// Set by the container when a run fails
+    pub error: Option<String>,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+impl Default for PatternSetDialog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PatternSetDialog {
+    pub fn new() -> Self {
+        Self {
+            patterns_path_input: text_input("file of `name = regex` lines"),
+            columns_input: text_input("comma-separated columns"),
+            case_insensitive: true,
+            output: PatternOutput::default(),
+            column_input: text_input(DEFAULT_PATTERNS_COLUMN),
+            available_columns: Vec::new(),
+            selected_field: FIELD_PATTERNS_PATH,
+            error: None,
+            show_instructions: true,
+            config: Config::default(),
+        }
+    }
+
+    /// Offer the columns of the current data; an empty column list starts with `selected`
+    pub fn set_columns(&mut self, available_columns: Vec<String>, selected: &str) {
+        self.available_columns = available_columns;
+        if input_text(&self.columns_input).is_empty() {
+            self.columns_input.insert_str(selected);
+        }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    pub fn options(&self) -> PatternSetOptions {
+        PatternSetOptions {
+            patterns_path: input_text(&self.patterns_path_input),
+            columns: input_text(&self.columns_input)
+                .split(',')
+                .map(str::trim)
+                .filter(|c| !c.is_empty())
+                .map(str::to_string)
+                .collect(),
+            case_insensitive: self.case_insensitive,
+            output: self.output,
+            column_name: input_text(&self.column_input),
+        }
+    }
+
+    fn validate(&self, options: &PatternSetOptions) -> Result<(), String> {
+        if options.patterns_path.is_empty() {
+            return Err("Enter a pattern file".to_string());
+        }
+        if options.columns.is_empty() {
+            return Err("Enter at least one column to search".to_string());
+        }
+        if let Some(missing) = options.columns.iter().find(|c| !self.available_columns.contains(c)) {
+            return Err(format!("Unknown column '{missing}'"));
+        }
+        Ok(())
+    }
+
+    fn selected_input(&mut self) -> Option<&mut TextArea<'static>> {
+        match self.selected_field {
+            FIELD_PATTERNS_PATH => Some(&mut self.patterns_path_input),
+            FIELD_COLUMNS => Some(&mut self.columns_input),
+            FIELD_COLUMN => Some(&mut self.column_input),
+            _ => None,
+        }
+    }
+
+    fn build_instructions_from_config(&self) -> String {
+        format!(
+            "{}  Left/Right: change option",
+            self.config.actions_to_instructions(&[
+                (Mode::Global, Action::Up),
+                (Mode::Global, Action::Down),
+                (Mode::Global, Action::Enter),
+                (Mode::Global, Action::Paste),
+                (Mode::Global, Action::Escape),
+                (Mode::Global, Action::ToggleInstructions),
+            ])
+        )
+    }
+
+    /// Render the dialog
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title("Pattern Set")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let block = Block::default().title("Match").borders(Borders::ALL);
+        let content = block.inner(layout.content_area);
+        block.render(layout.content_area, buf);
+
+        let labels = ["Pattern File:", "Columns:", "Ignore Case:", "Output:", "Column Name:"];
+        let label_width = labels.iter().map(|l| l.len()).max().unwrap_or(0) as u16 + 2;
+        for (i, label) in labels.iter().enumerate() {
+            let y = content.y + (i as u16) * 2;
+            if y >= content.bottom() {
+                break;
+            }
+            let selected = i == self.selected_field;
+            let style = if selected { Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD) } else { Style::default() };
+            buf.set_string(content.x + 1, y, label, style);
+            let value_area = Rect { x: content.x + 1 + label_width, y, width: content.width.saturating_sub(label_width + 2), height: 1 };
+            let input = match i {
+                FIELD_PATTERNS_PATH => &self.patterns_path_input,
+                FIELD_COLUMNS => &self.columns_input,
+                FIELD_COLUMN => &self.column_input,
+                FIELD_CASE => {
+                    buf.set_string(value_area.x, y, if self.case_insensitive { "[x]" } else { "[ ]" }, style);
+                    continue;
+                }
+                _ => {
+                    buf.set_string(value_area.x, y, format!("< {} >", self.output.label()), style);
+                    continue;
+                }
+            };
+            let mut ta = input.clone();
+            if !selected {
+                ta.set_cursor_style(Style::default());
+            }
+            ta.render(value_area, buf);
+        }
+        if let Some(error) = &self.error {
+            let y = content.y + FIELD_COUNT as u16 * 2;
+            let area = Rect { x: content.x + 1, y, width: content.width.saturating_sub(2), height: content.bottom().saturating_sub(y) };
+            Paragraph::new(format!("Error: {error}"))
+                .style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
+                .wrap(Wrap { trim: true })
+                .render(area, buf);
+        }
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Handle keyboard events for the dialog
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        match self.config.action_for_key(Mode::Global, key) {
+            Some(Action::Escape) => return Some(Action::DialogClose),
+            Some(Action::Enter) => {
+                let options = self.options();
+                if let Err(e) = self.validate(&options) {
+                    self.error = Some(e);
+                    return None;
+                }
+                self.error = None;
+                return Some(Action::PatternSetDialogApplied(options));
+            }
+            Some(Action::Up) => {
+                self.selected_field = self.selected_field.saturating_sub(1);
+                return None;
+            }
+            Some(Action::Down) => {
+                self.selected_field = (self.selected_field + 1).min(FIELD_COUNT - 1);
+                return None;
+            }
+            Some(Action::Left | Action::Right) if self.selected_field == FIELD_CASE => {
+                self.case_insensitive = !self.case_insensitive;
+                return None;
+            }
+            Some(action @ (Action::Left | Action::Right)) if self.selected_field == FIELD_OUTPUT => {
+                self.output = self.output.cycle(action == Action::Right);
+                return None;
+            }
+            Some(Action::ToggleInstructions) => {
+                self.show_instructions = !self.show_instructions;
+                return None;
+            }
+            Some(Action::Paste) => {
+                if let Ok(mut clipboard) = arboard::Clipboard::new()
+                    && let Ok(text) = clipboard.get_text()
+                    && let Some(input) = self.selected_input() {
+                        input.insert_str(text.trim());
+                    }
+                return None;
+            }
+            _ => {}
+        }
+        if self.selected_field == FIELD_CASE && key.code == KeyCode::Char(' ') {
+            self.case_insensitive = !self.case_insensitive;
+            return None;
+        }
+        if matches!(key.code, KeyCode::Char(_) | KeyCode::Backspace | KeyCode::Delete | KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End)
+            && let Some(input) = self.selected_input() {
+                input.input(tui_textarea::Input::from(key));
+                self.error = None;
+            }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn press(dialog: &mut PatternSetDialog, code: KeyCode) -> Option<Action> {
+        dialog.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    fn type_text(dialog: &mut PatternSetDialog, text: &str) {
+        for c in text.chars() {
+            press(dialog, KeyCode::Char(c));
+        }
+    }
+
+    #[test]
+    fn test_options_from_fields() {
+        let mut dialog = PatternSetDialog::new();
+        dialog.config.reset_keybindings_to_default();
+        dialog.set_columns(vec!["cmd".to_string(), "dst".to_string()], "cmd");
+        type_text(&mut dialog, "iocs.txt");
+        press(&mut dialog, KeyCode::Down);
+        type_text(&mut dialog, ", dts");
+        assert!(press(&mut dialog, KeyCode::Enter).is_none());
+        assert_eq!(dialog.error.as_deref(), Some("Unknown column 'dts'"));
+        press(&mut dialog, KeyCode::Backspace);
+        press(&mut dialog, KeyCode::Backspace);
+        type_text(&mut dialog, "st");
+        press(&mut dialog, KeyCode::Down);
+        press(&mut dialog, KeyCode::Char(' '));
+        press(&mut dialog, KeyCode::Down);
+        press(&mut dialog, KeyCode::Left);
+        assert_eq!(
+            press(&mut dialog, KeyCode::Enter),
+            Some(Action::PatternSetDialogApplied(PatternSetOptions {
+                patterns_path: "iocs.txt".to_string(),
+                columns: vec!["cmd".to_string(), "dst".to_string()],
+                case_insensitive: false,
+                output: PatternOutput::ColumnAndFilter,
+                column_name: String::new(),
+            }))
+        );
+    }
+}
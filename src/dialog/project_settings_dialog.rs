@@ -14,6 +14,7 @@ use crate::components::dialog_layout::split_dialog_area;
 use crate::dialog::file_browser_dialog::{FileBrowserDialog, FileBrowserMode, FileBrowserAction};
 use crate::dialog::message_dialog::MessageDialog;
 use crate::dialog::llm_client_dialog::LlmClientDialog;
+use crate::dialog::threat_intel_settings_dialog::ThreatIntelSettingsDialog;
 // use crate::providers::openai::Client as OpenAIClient;
 use crate::config::get_config_dir;
 
@@ -23,6 +24,7 @@ pub enum ProjectSettingsDialogMode {
     Error(String),
     Save,
     LlmClientDialog,
+    ThreatIntelDialog,
 }
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, Default)]
@@ -31,12 +33,27 @@ pub struct ProjectSettingsConfig {
     pub workspace_path: Option<PathBuf>,
     #[serde(default)]
     pub data_viewer: DataViewerOptions,
+    /// Rhai scripts saved for reuse across the project's datasets
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scripts: Vec<crate::dataframe::scripting::SavedScript>,
+    /// Recent and saved JMESPath expressions
+    #[serde(default, skip_serializing_if = "crate::jmes::history::JmesHistory::is_empty")]
+    pub jmes_history: crate::jmes::history::JmesHistory,
+    /// Recent Find searches, most recent first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub find_history: Vec<crate::dialog::find_dialog::FindHistoryEntry>,
+    /// Column operations saved under a name, run from the Column Operations dialog
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub column_operation_presets: Vec<crate::dialog::ColumnOperationPreset>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Default)]
 pub struct DataViewerOptions {
     #[serde(default)]
     pub auto_exapand_value_display: bool,
+    /// Show the full content of a truncated selected cell in a box next to it
+    #[serde(default)]
+    pub cell_peek: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -46,8 +63,11 @@ pub enum SelectedOption {
     LlmConfigPath,
     LlmConfigBrowse,
     ConfigureLlmClients,
+    ConfigureThreatIntel,
     AutoExpandValueDisplay,
+    CellPeek,
     UpdateCheck,
+    Theme,
     Save,
 }
 
@@ -72,6 +92,7 @@ pub struct ProjectSettingsDialog {
     pub message_dialog_mode: bool,
     pub message_dialog: Option<MessageDialog>,
     pub llm_client_dialog: Option<LlmClientDialog>,
+    pub threat_intel_dialog: Option<ThreatIntelSettingsDialog>,
     pub keybindings_config: crate::config::Config,
 }
 
@@ -96,6 +117,7 @@ impl ProjectSettingsDialog {
             message_dialog_mode: false,
             message_dialog: None,
             llm_client_dialog: None,
+            threat_intel_dialog: None,
             keybindings_config: crate::config::Config::default(),
         }
     }
@@ -110,6 +132,7 @@ impl ProjectSettingsDialog {
     }
 
     pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        let palette = self.keybindings_config.palette();
         Clear.render(area, buf);
         let instructions = self.build_instructions_from_config();
         let optional_instructions = if instructions.is_empty() { None } else { Some(instructions.as_str()) }; 
@@ -152,8 +175,8 @@ impl ProjectSettingsDialog {
 
                 // Workspace Path line + [Browse]
                 let label_wp = "Workspace Path: ";
-                let label_style = Style::default().fg(Color::White);
-                let input_style = Style::default().fg(Color::White);
+                let label_style = Style::default().fg(palette.foreground);
+                let input_style = Style::default().fg(palette.foreground);
                 
                 // Draw label
                 buf.set_string(content_area.x, line0_y, label_wp, label_style);
@@ -178,16 +201,16 @@ impl ProjectSettingsDialog {
                 let browse_x = content_area.x + 
                     content_area.width.saturating_sub(browse_text.len() as u16 + 1) - 1;
                 let browse_style = if self.selected_option == SelectedOption::WorkspaceBrowse {
-                    Style::default().fg(Color::Black).bg(Color::White)
+                    Style::default().fg(palette.cursor_fg).bg(palette.cursor_bg)
                 } else {
-                    Style::default().fg(Color::Gray)
+                    Style::default().fg(palette.muted)
                 };
                 buf.set_string(browse_x, line0_y, browse_text, browse_style);
 
                 // LLM Config Path line + [Browse]
                 let label_config = "LLM Config Path: ";
-                let label_style = Style::default().fg(Color::White);
-                let input_style = Style::default().fg(Color::White);
+                let label_style = Style::default().fg(palette.foreground);
+                let input_style = Style::default().fg(palette.foreground);
                 
                 // Draw label
                 buf.set_string(content_area.x, line1_y, label_config, label_style);
@@ -213,9 +236,9 @@ impl ProjectSettingsDialog {
                     .x
                     + content_area.width.saturating_sub(browse_text.len() as u16 + 1);
                 let browse_style = if self.selected_option == SelectedOption::LlmConfigBrowse {
-                    Style::default().fg(Color::Black).bg(Color::White)
+                    Style::default().fg(palette.cursor_fg).bg(palette.cursor_bg)
                 } else {
-                    Style::default().fg(Color::Gray)
+                    Style::default().fg(palette.muted)
                 };
                 buf.set_string(browse_x, line1_y, browse_text, browse_style);
 
@@ -225,31 +248,48 @@ impl ProjectSettingsDialog {
                 let update_check_value = if self.keybindings_config.next_update_check.is_some() { "enabled" } else { "disabled" };
                 let update_check_style = if self.selected_option == SelectedOption::UpdateCheck {
                     Style::default()
-                        .fg(Color::Black)
-                        .bg(Color::White)
+                        .fg(palette.cursor_fg)
+                        .bg(palette.cursor_bg)
                 } else {
                     Style::default()
-                        .fg(Color::White)
+                        .fg(palette.foreground)
                 };
                 buf.set_string(content_area.x, update_check_y, format!("{update_check_label}{update_check_value}"), update_check_style);
 
+                // Theme selection
+                let theme_y = update_check_y + 1;
+                let theme_name = self.keybindings_config.theme.as_ref().map(|t| t.name).unwrap_or_default();
+                let theme_style = if self.selected_option == SelectedOption::Theme {
+                    Style::default().fg(palette.cursor_fg).bg(palette.cursor_bg)
+                } else {
+                    Style::default().fg(palette.foreground)
+                };
+                buf.set_string(content_area.x, theme_y, format!("Theme: {}", theme_name.display_name()), theme_style);
+
                 // Configure LLM Clients button on a new line
                 let llm_client_text = "[Configure LLM Clients]";
                 let llm_client_x = content_area.x;
-                let llm_client_y = update_check_y + 2;
+                let llm_client_y = theme_y + 2;
                 let llm_client_style = if self.selected_option == SelectedOption::ConfigureLlmClients {
-                    Style::default().fg(Color::Black).bg(Color::White)
+                    Style::default().fg(palette.cursor_fg).bg(palette.cursor_bg)
                 } else {
-                    Style::default().fg(Color::Gray)
+                    Style::default().fg(palette.muted)
                 };
                 buf.set_string(llm_client_x, llm_client_y, llm_client_text, llm_client_style);
+                let threat_intel_text = "[Configure Threat Intel]";
+                let threat_intel_style = if self.selected_option == SelectedOption::ConfigureThreatIntel {
+                    Style::default().fg(palette.cursor_fg).bg(palette.cursor_bg)
+                } else {
+                    Style::default().fg(palette.muted)
+                };
+                buf.set_string(llm_client_x + llm_client_text.len() as u16 + 2, llm_client_y, threat_intel_text, threat_intel_style);
 
                 // Data Viewer section with bordered block
                 let dv_block_area = Rect {
                     x: content_area.x,
                     y: llm_client_y + 2, // Move down to accommodate Update Check and LLM Client button
                     width: content_area.width,
-                    height: 3,
+                    height: 4,
                 };
                 let dv_block = Block::default()
                     .title("Data Viewer")
@@ -262,35 +302,47 @@ impl ProjectSettingsDialog {
                 let dv_value = if self.config.data_viewer.auto_exapand_value_display { "true" } else { "false" };
                 let dv_style = if self.selected_option == SelectedOption::AutoExpandValueDisplay {
                     Style::default()
-                        .fg(Color::Black)
-                        .bg(Color::White)
+                        .fg(palette.cursor_fg)
+                        .bg(palette.cursor_bg)
                 } else {
                     Style::default()
-                        .fg(Color::White)
+                        .fg(palette.foreground)
                 };
                 buf.set_string(dv_inner.x, dv_inner.y, format!("{dv_label}{dv_value}"), dv_style);
 
+                let peek_label = "Quick-Peek Truncated Cells: ";
+                let peek_value = if self.config.data_viewer.cell_peek { "true" } else { "false" };
+                let peek_style = if self.selected_option == SelectedOption::CellPeek {
+                    Style::default()
+                        .fg(palette.cursor_fg)
+                        .bg(palette.cursor_bg)
+                } else {
+                    Style::default()
+                        .fg(palette.foreground)
+                };
+                buf.set_string(dv_inner.x, dv_inner.y + 1, format!("{peek_label}{peek_value}"), peek_style);
+
                 // [Save] button at bottom-right of content area
                 let save_text = "[Save]";
                 let save_x = content_area.x + content_area.width.saturating_sub(save_text.len() as u16 + 2);
                 let save_y = content_area.y + content_area.height.saturating_sub(2);
                 let save_style = if self.selected_option == SelectedOption::Save {
                     Style::default()
-                        .fg(Color::Black)
-                        .bg(Color::White)
+                        .fg(palette.cursor_fg)
+                        .bg(palette.cursor_bg)
                 } else {
-                    Style::default().fg(Color::Gray)
+                    Style::default().fg(palette.muted)
                 };
                 buf.set_string(save_x, save_y, save_text, save_style);
             }
             ProjectSettingsDialogMode::Error(msg) => {
                 let y = content_area.y;
-                buf.set_string(content_area.x, y, "Error:", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD));
+                buf.set_string(content_area.x, y, "Error:", Style::default().fg(palette.error).add_modifier(Modifier::BOLD));
                 let error_lines = wrap(msg, wrap_width);
                 for (i, line) in error_lines.iter().enumerate() {
-                    buf.set_string(content_area.x, y + 1 + i as u16, line, Style::default().fg(Color::Red));
+                    buf.set_string(content_area.x, y + 1 + i as u16, line, Style::default().fg(palette.error));
                 }
-                buf.set_string(content_area.x, y + 1 + error_lines.len() as u16, "Press Esc or Enter to close error", Style::default().fg(Color::Yellow));
+                buf.set_string(content_area.x, y + 1 + error_lines.len() as u16, "Press Esc or Enter to close error", Style::default().fg(palette.header));
             }
             ProjectSettingsDialogMode::Save => { }
             ProjectSettingsDialogMode::LlmClientDialog => {
@@ -299,6 +351,12 @@ impl ProjectSettingsDialog {
                     return;
                 }
             }
+            ProjectSettingsDialogMode::ThreatIntelDialog => {
+                if let Some(dialog) = &self.threat_intel_dialog {
+                    dialog.render(area, buf);
+                    return;
+                }
+            }
         }
 
         if self.show_instructions && let Some(instructions_area) = instructions_area {
@@ -306,7 +364,7 @@ impl ProjectSettingsDialog {
                 .block(Block::default()
                     .title("Instructions")
                     .borders(Borders::ALL))
-                .style(Style::default().fg(Color::Yellow))
+                .style(Style::default().fg(palette.header))
                 .wrap(Wrap { trim: true });
             instructions_paragraph.render(instructions_area, buf);
         }
@@ -339,6 +397,8 @@ impl ProjectSettingsDialog {
                             // Toggle boolean when data viewer option is selected
                             if self.selected_option == SelectedOption::AutoExpandValueDisplay {
                                 self.config.data_viewer.auto_exapand_value_display = !self.config.data_viewer.auto_exapand_value_display;
+                            } else if self.selected_option == SelectedOption::CellPeek {
+                                self.config.data_viewer.cell_peek = !self.config.data_viewer.cell_peek;
                             } else if self.selected_option == SelectedOption::UpdateCheck {
                                 // Toggle update check: if None, enable it (set to 1 day from now), otherwise disable (set to None)
                                 use crate::update_check::calculate_next_check_date;
@@ -347,6 +407,9 @@ impl ProjectSettingsDialog {
                                 } else {
                                     self.keybindings_config.next_update_check = None;
                                 }
+                            } else if self.selected_option == SelectedOption::Theme {
+                                let theme = self.keybindings_config.theme.get_or_insert_with(Default::default);
+                                theme.name = theme.name.next();
                             }
                             return None;
                         }
@@ -408,15 +471,17 @@ impl ProjectSettingsDialog {
                     match key.code {
                         KeyCode::Up => {
                             // Up navigation:
-                            // Left side: Auto Expand Value Display -> Configure LLM Clients -> Update Check -> LLM Config Path -> Workspace Path
+                            // Left side: Quick-Peek -> Auto Expand Value Display -> Configure LLM Clients -> Theme -> Update Check -> LLM Config Path -> Workspace Path
                             // Right side: [Save] -> [Browse] (LLM config path) -> [Browse] (workspace path)
                             self.selected_option = match self.selected_option {
                                 // Left side navigation
+                                SelectedOption::CellPeek => SelectedOption::AutoExpandValueDisplay,
                                 SelectedOption::AutoExpandValueDisplay => SelectedOption::ConfigureLlmClients,
-                                SelectedOption::ConfigureLlmClients => SelectedOption::UpdateCheck,
+                                SelectedOption::ConfigureLlmClients | SelectedOption::ConfigureThreatIntel => SelectedOption::Theme,
+                                SelectedOption::Theme => SelectedOption::UpdateCheck,
                                 SelectedOption::UpdateCheck => SelectedOption::LlmConfigPath,
                                 SelectedOption::LlmConfigPath => SelectedOption::WorkspacePath,
-                                SelectedOption::WorkspacePath => SelectedOption::AutoExpandValueDisplay, // wrap around
+                                SelectedOption::WorkspacePath => SelectedOption::CellPeek, // wrap around
                                 
                                 // Right side navigation
                                 SelectedOption::Save => SelectedOption::LlmConfigBrowse,
@@ -437,15 +502,17 @@ impl ProjectSettingsDialog {
                         }
                         KeyCode::Down => {
                             // Down navigation:
-                            // Left side: Workspace Path -> LLM Config Path -> Update Check -> Configure LLM Clients -> Auto Expand Value Display
+                            // Left side: Workspace Path -> LLM Config Path -> Update Check -> Theme -> Configure LLM Clients -> Auto Expand Value Display -> Quick-Peek
                             // Right side: [Browse] (workspace path) -> [Browse] (LLM config path) -> [Save]
                             self.selected_option = match self.selected_option {
                                 // Left side navigation
                                 SelectedOption::WorkspacePath => SelectedOption::LlmConfigPath,
                                 SelectedOption::LlmConfigPath => SelectedOption::UpdateCheck,
-                                SelectedOption::UpdateCheck => SelectedOption::ConfigureLlmClients,
-                                SelectedOption::ConfigureLlmClients => SelectedOption::AutoExpandValueDisplay,
-                                SelectedOption::AutoExpandValueDisplay => SelectedOption::WorkspacePath, // wrap around
+                                SelectedOption::UpdateCheck => SelectedOption::Theme,
+                                SelectedOption::Theme => SelectedOption::ConfigureLlmClients,
+                                SelectedOption::ConfigureLlmClients | SelectedOption::ConfigureThreatIntel => SelectedOption::AutoExpandValueDisplay,
+                                SelectedOption::AutoExpandValueDisplay => SelectedOption::CellPeek,
+                                SelectedOption::CellPeek => SelectedOption::WorkspacePath, // wrap around
                                 
                                 // Right side navigation
                                 SelectedOption::WorkspaceBrowse => SelectedOption::LlmConfigBrowse,
@@ -485,6 +552,12 @@ impl ProjectSettingsDialog {
                                     self.llm_client_dialog = Some(llm_dialog);
                                     self.mode = ProjectSettingsDialogMode::LlmClientDialog;
                                 }
+                                SelectedOption::ConfigureThreatIntel => {
+                                    let mut dialog = ThreatIntelSettingsDialog::new(self.keybindings_config.threat_intel.clone());
+                                    let _ = dialog.register_config_handler(self.keybindings_config.clone());
+                                    self.threat_intel_dialog = Some(dialog);
+                                    self.mode = ProjectSettingsDialogMode::ThreatIntelDialog;
+                                }
                                 SelectedOption::WorkspaceBrowse | SelectedOption::LlmConfigBrowse => {
                                     // Open file browser
                                     self.file_browser = Some(FileBrowserDialog::new(
@@ -564,11 +637,12 @@ impl ProjectSettingsDialog {
                                 }
                                 _ => {
                                     // Right navigation for other options:
-                                    // [Configure LLM Clients] -> [Save]
+                                    // [Configure LLM Clients] -> [Configure Threat Intel] -> [Save]
                                     // Auto Expand Value Display -> [Save]
                                     self.selected_option = match self.selected_option {
-                                        SelectedOption::ConfigureLlmClients => SelectedOption::Save,
-                                        SelectedOption::AutoExpandValueDisplay => SelectedOption::Save,
+                                        SelectedOption::ConfigureLlmClients => SelectedOption::ConfigureThreatIntel,
+                                        SelectedOption::ConfigureThreatIntel => SelectedOption::Save,
+                                        SelectedOption::AutoExpandValueDisplay | SelectedOption::CellPeek => SelectedOption::Save,
                                         _ => SelectedOption::WorkspacePath, // default
                                     };
                                 }
@@ -601,6 +675,9 @@ impl ProjectSettingsDialog {
                                     // Left navigation from Save button
                                     self.selected_option = SelectedOption::ConfigureLlmClients; // default choice
                                 }
+                                SelectedOption::ConfigureThreatIntel => {
+                                    self.selected_option = SelectedOption::ConfigureLlmClients;
+                                }
                                 _ => {
                                     self.selected_option = SelectedOption::WorkspacePath; // default
                                 }
@@ -683,6 +760,26 @@ impl ProjectSettingsDialog {
                     }
                 }
             }
+            ProjectSettingsDialogMode::ThreatIntelDialog => {
+                match self.threat_intel_dialog.as_mut().and_then(|d| d.handle_key_event(key)) {
+                    Some(Action::ThreatIntelSettingsApplied(settings)) => {
+                        self.keybindings_config.threat_intel = settings;
+                        if let Err(e) = self.keybindings_config.save_threat_intel_config() {
+                            if let Some(dialog) = &mut self.threat_intel_dialog {
+                                dialog.error = Some(format!("Failed to save: {e}"));
+                            }
+                            return None;
+                        }
+                        self.threat_intel_dialog = None;
+                        self.mode = ProjectSettingsDialogMode::Input;
+                    }
+                    Some(Action::DialogClose) => {
+                        self.threat_intel_dialog = None;
+                        self.mode = ProjectSettingsDialogMode::Input;
+                    }
+                    _ => {}
+                }
+            }
         }
         None
     }
@@ -710,9 +807,10 @@ impl ProjectSettingsDialog {
                 .map_err(|e| color_eyre::eyre::eyre!("failed to write settings JSON: {}", e))?;
         }
         
-        // Save update check setting to main config file
+        // Save update check and theme settings to main config file
+        self.keybindings_config.apply_theme();
         if let Err(e) = self.keybindings_config.save() {
-            eprintln!("Warning: Failed to save update check setting: {}", e);
+            eprintln!("Warning: Failed to save update check and theme settings: {}", e);
         }
         
         Ok(())
@@ -0,0 +1,229 @@
+//! QuickOpenDialog: re-open a recently used data file or project by typing part of its path
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Cell, Clear, Paragraph, Row, Table, TableState, Wrap};
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+use crate::recent_files::{fuzzy_score, RecentEntry};
+
+#[derive(Debug)]
+pub struct QuickOpenDialog {
+    pub entries: Vec<RecentEntry>,
+    pub query: String,
+    /// Indices into `entries` matching the query, best match first
+    pub matches: Vec<usize>,
+    pub selected: usize,
+    /// Shown when the chosen entry cannot be opened
+    pub notice: Option<String>,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+impl QuickOpenDialog {
+    pub fn new(entries: Vec<RecentEntry>) -> Self {
+        let mut dialog = Self {
+            entries,
+            query: String::new(),
+            matches: Vec::new(),
+            selected: 0,
+            notice: None,
+            show_instructions: true,
+            config: Config::default(),
+        };
+        dialog.update_matches();
+        dialog
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn update_matches(&mut self) {
+        let mut scored: Vec<(i64, usize)> = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(i, entry)| fuzzy_score(&self.query, &entry.path.to_string_lossy()).map(|s| (s, i)))
+            .collect();
+        // Stable sort keeps most-recent-first order among equal scores
+        if !self.query.is_empty() {
+            scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        }
+        self.matches = scored.into_iter().map(|(_, i)| i).collect();
+        self.selected = 0;
+    }
+
+    /// The entry under the cursor
+    pub fn selected_entry(&self) -> Option<&RecentEntry> {
+        self.matches.get(self.selected).and_then(|&i| self.entries.get(i))
+    }
+
+    fn build_instructions_from_config(&self) -> String {
+        format!(
+            "Type to filter  Enter: Open  {}",
+            self.config.actions_to_instructions(&[
+                (Mode::QuickOpenDialog, Action::ForgetRecentEntry),
+                (Mode::Global, Action::Escape),
+                (Mode::Global, Action::ToggleInstructions),
+            ])
+        )
+    }
+
+    /// Render the dialog
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title("Quick Open")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let content = layout.content_area;
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(1)])
+            .split(content);
+        let (input_title, input_style) = match &self.notice {
+            Some(notice) => (notice.as_str(), Style::default().fg(Color::Red)),
+            None => ("Filter", Style::default()),
+        };
+        Paragraph::new(format!("{}_", self.query))
+            .block(Block::default().borders(Borders::ALL).title(input_title).border_style(input_style))
+            .render(chunks[0], buf);
+
+        if self.entries.is_empty() {
+            Paragraph::new("No recent files yet. Imported files and opened projects are listed here.")
+                .style(Style::default().fg(Color::Gray))
+                .wrap(Wrap { trim: true })
+                .render(chunks[1], buf);
+        } else {
+            let rows = self.matches.iter().map(|&i| {
+                let entry = &self.entries[i];
+                let style = if entry.path.exists() { Style::default() } else { Style::default().fg(Color::DarkGray) };
+                Row::new(vec![
+                    Cell::from(entry.kind_name()),
+                    Cell::from(entry.path.display().to_string()),
+                    Cell::from(entry.opened_at.format("%Y-%m-%d %H:%M").to_string()),
+                ])
+                .style(style)
+            });
+            let table = Table::new(rows, [Constraint::Length(8), Constraint::Min(20), Constraint::Length(16)])
+                .header(
+                    Row::new(vec!["Type", "Path", "Opened"])
+                        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                )
+                .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+            let mut state = TableState::default().with_selected(Some(self.selected));
+            StatefulWidget::render(table, chunks[1], buf, &mut state);
+        }
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Handle keyboard events; Enter returns `QuickOpenRecent` with the index into `entries`
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        match self.config.action_for_key(Mode::Global, key) {
+            Some(Action::Escape) => return Some(Action::DialogClose),
+            Some(Action::Enter) => {
+                let &index = self.matches.get(self.selected)?;
+                let path = &self.entries[index].path;
+                if !path.exists() {
+                    self.notice = Some(format!("Not found: {}", path.display()));
+                    return None;
+                }
+                return Some(Action::QuickOpenRecent(index));
+            }
+            Some(Action::Up) => {
+                self.selected = self.selected.saturating_sub(1);
+                return None;
+            }
+            Some(Action::Down) => {
+                if self.selected + 1 < self.matches.len() {
+                    self.selected += 1;
+                }
+                return None;
+            }
+            Some(Action::ToggleInstructions) => {
+                self.show_instructions = !self.show_instructions;
+                return None;
+            }
+            Some(Action::Backspace) => {
+                self.query.pop();
+                self.notice = None;
+                self.update_matches();
+                return None;
+            }
+            _ => {}
+        }
+        if let Some(Action::ForgetRecentEntry) = self.config.action_for_key(Mode::QuickOpenDialog, key) {
+            let &index = self.matches.get(self.selected)?;
+            self.entries.remove(index);
+            let selected = self.selected;
+            self.update_matches();
+            self.selected = selected.min(self.matches.len().saturating_sub(1));
+            return Some(Action::ForgetRecentEntry);
+        }
+        if let KeyCode::Char(c) = key.code
+            && !key.modifiers.intersects(crossterm::event::KeyModifiers::CONTROL | crossterm::event::KeyModifiers::ALT)
+        {
+            self.query.push(c);
+            self.notice = None;
+            self.update_matches();
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recent_files::RecentKind;
+    use chrono::Utc;
+    use crossterm::event::KeyModifiers;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_filter_and_open() {
+        let dir = std::env::temp_dir();
+        let entries = vec![
+            RecentEntry { path: PathBuf::from("/no/such/case/firewall.csv"), kind: RecentKind::Project, opened_at: Utc::now() },
+            RecentEntry { path: dir.clone(), kind: RecentKind::Project, opened_at: Utc::now() },
+        ];
+        let mut dialog = QuickOpenDialog::new(entries);
+        dialog.config.reset_keybindings_to_default();
+        assert_eq!(dialog.matches, vec![0, 1]);
+
+        for c in "fwcsv".chars() {
+            dialog.handle_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        assert_eq!(dialog.matches, vec![0]);
+        // Missing files stay in the dialog with a notice
+        assert_eq!(dialog.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)), None);
+        assert!(dialog.notice.is_some());
+
+        dialog.query = dir.to_string_lossy().to_string();
+        dialog.update_matches();
+        assert_eq!(
+            dialog.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)),
+            Some(Action::QuickOpenRecent(1))
+        );
+    }
+}
@@ -0,0 +1,346 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Table, Row, Cell, Clear, Paragraph, Wrap, BorderType};
+use crate::components::dialog_layout::split_dialog_area;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crate::action::Action;
+use crate::config::{Config, Mode};
+
+/// Transposed view of a single row: one line per column with its value,
+/// filterable by typing a search term that matches field names or values.
+#[derive(Debug)]
+pub struct RecordViewDialog {
+    pub row: usize,
+    pub fields: Vec<(String, String)>,
+    pub search: String,
+    pub selected: usize,
+    pub scroll_offset: usize,
+    pub show_instructions: bool,
+    pub visible_rows: usize,
+    pub config: Config,
+}
+
+impl RecordViewDialog {
+    pub fn new(row: usize, fields: Vec<(String, String)>) -> Self {
+        Self {
+            row,
+            fields,
+            search: String::new(),
+            selected: 0,
+            scroll_offset: 0,
+            show_instructions: true,
+            visible_rows: 5,
+            config: Config::default(),
+        }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    /// Replace the record being shown, keeping the current search term
+    pub fn set_record(&mut self, row: usize, fields: Vec<(String, String)>) {
+        self.row = row;
+        self.fields = fields;
+        self.selected = 0;
+        self.scroll_offset = 0;
+    }
+
+    /// Indices into `fields` that match the current search term (case-insensitive)
+    pub fn filtered_indices(&self) -> Vec<usize> {
+        if self.search.is_empty() {
+            return (0..self.fields.len()).collect();
+        }
+        let needle = self.search.to_lowercase();
+        self.fields
+            .iter()
+            .enumerate()
+            .filter(|(_, (name, value))| {
+                name.to_lowercase().contains(&needle) || value.to_lowercase().contains(&needle)
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Get the currently selected (column, value) pair
+    pub fn get_selected_field(&self) -> Option<&(String, String)> {
+        self.filtered_indices()
+            .get(self.selected)
+            .and_then(|&i| self.fields.get(i))
+    }
+
+    /// Build instructions string from configured keybindings
+    fn build_instructions_from_config(&self) -> String {
+        let base = self.config.actions_to_instructions(&[
+            (Mode::Global, Action::Escape),
+            (Mode::Global, Action::Enter),
+            (Mode::Global, Action::ToggleInstructions),
+            (Mode::RecordView, Action::GoToFirst),
+            (Mode::RecordView, Action::GoToLast),
+            (Mode::RecordView, Action::PageUp),
+            (Mode::RecordView, Action::PageDown),
+        ]);
+        if base.is_empty() {
+            "Type to search".to_string()
+        } else {
+            format!("Type to search  {base}")
+        }
+    }
+
+    /// Render the dialog with a search line and a scrollable field/value table
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+
+        let outer_block = Block::default()
+            .title(format!("Record {}", self.row + 1))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let content_area = layout.content_area;
+
+        // Search line
+        let search_area = Rect { height: 3.min(content_area.height), ..content_area };
+        let search = Paragraph::new(self.search.as_str())
+            .block(Block::default().borders(Borders::ALL).title("Search"))
+            .style(Style::default().fg(Color::White));
+        search.render(search_area, buf);
+
+        let fields_area = Rect {
+            x: content_area.x,
+            y: content_area.y + search_area.height,
+            width: content_area.width,
+            height: content_area.height.saturating_sub(search_area.height),
+        };
+        let filtered = self.filtered_indices();
+        let block = Block::default()
+            .title(format!("Fields ({}/{})", filtered.len(), self.fields.len()))
+            .borders(Borders::ALL);
+        let table_area = block.inner(fields_area);
+        block.render(fields_area, buf);
+
+        if filtered.is_empty() {
+            Paragraph::new("No matching fields")
+                .style(Style::default().fg(Color::Yellow))
+                .render(table_area, buf);
+        } else {
+            self.render_fields_table(table_area, buf, &filtered);
+        }
+
+        if let Some(instructions_area) = layout.instructions_area {
+            let instructions_paragraph = Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true });
+            instructions_paragraph.render(instructions_area, buf);
+        }
+    }
+
+    /// Ensure the selected field is within the visible viewport by adjusting the scroll offset
+    fn update_scroll_offset(&mut self) {
+        let visible_rows = self.visible_rows.max(1);
+        if self.selected < self.scroll_offset {
+            self.scroll_offset = self.selected;
+        } else if self.selected >= self.scroll_offset + visible_rows {
+            self.scroll_offset = self.selected + 1 - visible_rows;
+        }
+    }
+
+    /// Render the visible slice of fields with a vertical scroll bar
+    fn render_fields_table(&mut self, area: Rect, buf: &mut Buffer, filtered: &[usize]) {
+        let max_rows = area.height as usize;
+        self.visible_rows = max_rows;
+        self.selected = self.selected.min(filtered.len().saturating_sub(1));
+        self.update_scroll_offset();
+
+        let start_idx = self.scroll_offset.min(filtered.len());
+        let end_idx = (start_idx + max_rows).min(filtered.len());
+
+        let show_scroll_bar = filtered.len() > max_rows;
+        let table_width = if show_scroll_bar { area.width.saturating_sub(1) } else { area.width };
+        if show_scroll_bar {
+            let scroll_bar_x = area.x + area.width.saturating_sub(1);
+            let total_items = filtered.len();
+            let thumb_size = std::cmp::max(1, (max_rows * max_rows) / total_items);
+            let thumb_position = (self.scroll_offset * (max_rows - thumb_size)) / (total_items - max_rows);
+            for y in area.y..area.y + max_rows as u16 {
+                buf.set_string(scroll_bar_x, y, "│", Style::default().fg(Color::DarkGray));
+            }
+            let thumb_start = area.y + thumb_position as u16;
+            let thumb_end = (thumb_start + thumb_size as u16).min(area.y + max_rows as u16);
+            for y in thumb_start..thumb_end {
+                buf.set_string(scroll_bar_x, y, "█", Style::default().fg(Color::Cyan));
+            }
+        }
+
+        // Size the field-name column to the longest name, capped at 40% of the width
+        let longest_name = filtered
+            .iter()
+            .map(|&i| self.fields[i].0.chars().count())
+            .max()
+            .unwrap_or(0) as u16;
+        let name_width = longest_name.clamp(4, (table_width * 2 / 5).max(4));
+
+        let rows: Vec<Row> = filtered[start_idx..end_idx]
+            .iter()
+            .enumerate()
+            .map(|(i, &field_idx)| {
+                let idx = start_idx + i;
+                let (name, value) = &self.fields[field_idx];
+                let mut style = Style::default();
+                if idx == self.selected {
+                    style = style.fg(Color::Black).bg(Color::Cyan).add_modifier(Modifier::BOLD);
+                } else if idx.is_multiple_of(2) {
+                    style = style.bg(Color::Rgb(30, 30, 30));
+                }
+                let value = value.replace(['\n', '\r'], " ");
+                Row::new(vec![
+                    Cell::from(name.clone()).style(style.fg(if idx == self.selected { Color::Black } else { Color::Yellow })),
+                    Cell::from(value).style(style),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(rows, [Constraint::Length(name_width), Constraint::Min(10)])
+            .column_spacing(1);
+        let table_area = Rect { width: table_width, ..area };
+        ratatui::prelude::Widget::render(table, table_area, buf);
+    }
+
+    /// Handle keyboard events for navigation, search input and actions
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        let total = self.filtered_indices().len();
+
+        if let Some(global_action) = self.config.action_for_key(Mode::Global, key) {
+            match global_action {
+                Action::Escape => {
+                    if self.search.is_empty() {
+                        return Some(Action::DialogClose);
+                    }
+                    self.search.clear();
+                    self.selected = 0;
+                    self.scroll_offset = 0;
+                    return None;
+                }
+                Action::Enter => {
+                    // Jump to the selected field's column in the main DataTable
+                    return self.get_selected_field().map(|(column, _)| Action::GoToResult {
+                        row: self.row,
+                        column: column.clone(),
+                    });
+                }
+                Action::Up => {
+                    self.selected = self.selected.saturating_sub(1);
+                    self.update_scroll_offset();
+                    return None;
+                }
+                Action::Down => {
+                    if self.selected + 1 < total {
+                        self.selected += 1;
+                        self.update_scroll_offset();
+                    }
+                    return None;
+                }
+                Action::Backspace => {
+                    self.search.pop();
+                    self.selected = 0;
+                    self.scroll_offset = 0;
+                    return None;
+                }
+                Action::ToggleInstructions => {
+                    self.show_instructions = !self.show_instructions;
+                    return None;
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(dialog_action) = self.config.action_for_key(Mode::RecordView, key) {
+            let page_size = self.visible_rows.saturating_sub(1).max(1);
+            match dialog_action {
+                Action::GoToFirst => {
+                    self.selected = 0;
+                    self.scroll_offset = 0;
+                    return None;
+                }
+                Action::GoToLast => {
+                    self.selected = total.saturating_sub(1);
+                    self.update_scroll_offset();
+                    return None;
+                }
+                Action::PageUp => {
+                    self.selected = self.selected.saturating_sub(page_size);
+                    self.update_scroll_offset();
+                    return None;
+                }
+                Action::PageDown => {
+                    self.selected = (self.selected + page_size).min(total.saturating_sub(1));
+                    self.update_scroll_offset();
+                    return None;
+                }
+                _ => {}
+            }
+        }
+
+        // Remaining printable characters feed the search term
+        if let KeyCode::Char(c) = key.code
+            && !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        {
+            self.search.push(c);
+            self.selected = 0;
+            self.scroll_offset = 0;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> RecordViewDialog {
+        let mut dialog = RecordViewDialog::new(3, vec![
+            ("name".to_string(), "alice".to_string()),
+            ("city".to_string(), "Paris".to_string()),
+            ("note".to_string(), "likes paris".to_string()),
+        ]);
+        dialog.config.reset_keybindings_to_default();
+        dialog
+    }
+
+    #[test]
+    fn test_search_matches_names_and_values() {
+        let mut dialog = sample();
+        assert_eq!(dialog.filtered_indices(), vec![0, 1, 2]);
+        dialog.search = "PARIS".to_string();
+        assert_eq!(dialog.filtered_indices(), vec![1, 2]);
+        dialog.search = "name".to_string();
+        assert_eq!(dialog.filtered_indices(), vec![0]);
+    }
+
+    #[test]
+    fn test_typing_and_enter_go_to_column() {
+        let mut dialog = sample();
+        for c in "note".chars() {
+            dialog.handle_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        assert_eq!(dialog.search, "note");
+        let action = dialog.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(action, Some(Action::GoToResult { row: 3, column: "note".to_string() }));
+
+        // Esc first clears the search, then closes
+        assert_eq!(dialog.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)), None);
+        assert!(dialog.search.is_empty());
+        assert_eq!(
+            dialog.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)),
+            Some(Action::DialogClose)
+        );
+    }
+}
@@ -0,0 +1,313 @@
+//! ResampleDialog: bucket rows by time (and optionally a group column) and aggregate them
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Clear, Paragraph, Wrap};
+use serde::{Deserialize, Serialize};
+use tui_textarea::TextArea;
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+use crate::dataframe::resample::{ResampleAgg, ResampleOptions};
+
+const FIELD_TIME: usize = 0;
+const FIELD_EVERY: usize = 1;
+const FIELD_GROUP: usize = 2;
+const FIELD_AGG: usize = 3;
+const FIELD_VALUE: usize = 4;
+const FIELD_OUTPUT: usize = 5;
+const FIELD_NAME: usize = 6;
+const FIELD_COUNT: usize = 7;
+
+/// Where the resampled buckets go
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ResampleOutput {
+    /// A new dataset tab
+    #[default]
+    NewDataset,
+    /// A bar chart of the aggregated values
+    Chart,
+}
+
+impl ResampleOutput {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ResampleOutput::NewDataset => "New Dataset",
+            ResampleOutput::Chart => "Chart",
+        }
+    }
+}
+
+fn text_input(value: &str, placeholder: &str) -> TextArea<'static> {
+    let mut t = TextArea::default();
+    t.set_block(Block::default());
+    t.set_placeholder_text(placeholder.to_string());
+    t.insert_str(value);
+    t
+}
+
+fn cycle_index(idx: usize, len: usize, forward: bool) -> usize {
+    if len == 0 {
+        return 0;
+    }
+    if forward { (idx + 1) % len } else { (idx + len - 1) % len }
+}
+
+/// ResampleDialog: options for a time-bucketed aggregation of the current view
+#[derive(Debug)]
+pub struct ResampleDialog {
+    pub columns: Vec<String>,
+    pub time_idx: usize,
+    /// Index into `columns` plus one; 0 means no group column
+    pub group_idx: usize,
+    pub aggregation: ResampleAgg,
+    pub value_idx: usize,
+    pub output: ResampleOutput,
+    pub every_input: TextArea<'static>,
+    pub name_input: TextArea<'static>,
+    pub selected_field: usize,
+    pub error: Option<String>,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+impl ResampleDialog {
+    pub fn new(dataset_name: &str, columns: Vec<String>, time_idx: usize) -> Self {
+        let options = ResampleOptions::default();
+        Self {
+            time_idx: time_idx.min(columns.len().saturating_sub(1)),
+            columns,
+            group_idx: 0,
+            aggregation: options.aggregation,
+            value_idx: 0,
+            output: ResampleOutput::NewDataset,
+            every_input: text_input(&options.every, "e.g. 15m, 1h, 1d"),
+            name_input: text_input("", &format!("{dataset_name}_resampled")),
+            selected_field: FIELD_EVERY,
+            error: None,
+            show_instructions: true,
+            config: Config::default(),
+        }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    pub fn set_error(&mut self, message: String) {
+        self.error = Some(message);
+    }
+
+    fn column(&self, idx: usize) -> String {
+        self.columns.get(idx).cloned().unwrap_or_default()
+    }
+
+    fn group_label(&self) -> String {
+        if self.group_idx == 0 { "(none)".to_string() } else { self.column(self.group_idx - 1) }
+    }
+
+    fn options(&self) -> ResampleOptions {
+        ResampleOptions {
+            time_column: self.column(self.time_idx),
+            every: self.every_input.lines().join("").trim().to_string(),
+            group_by: if self.group_idx == 0 { String::new() } else { self.column(self.group_idx - 1) },
+            aggregation: self.aggregation,
+            value_column: if self.aggregation.needs_value_column() { self.column(self.value_idx) } else { String::new() },
+        }
+    }
+
+    fn current_input(&mut self) -> Option<&mut TextArea<'static>> {
+        match self.selected_field {
+            FIELD_EVERY => Some(&mut self.every_input),
+            FIELD_NAME => Some(&mut self.name_input),
+            _ => None,
+        }
+    }
+
+    /// Cycle the choice field under the cursor; false when the field is a text input
+    fn cycle_field(&mut self, forward: bool) -> bool {
+        let n = self.columns.len();
+        match self.selected_field {
+            FIELD_TIME => self.time_idx = cycle_index(self.time_idx, n, forward),
+            FIELD_GROUP => self.group_idx = cycle_index(self.group_idx, n + 1, forward),
+            FIELD_AGG => self.aggregation = self.aggregation.cycle(forward),
+            FIELD_VALUE => self.value_idx = cycle_index(self.value_idx, n, forward),
+            FIELD_OUTPUT => {
+                self.output = match self.output {
+                    ResampleOutput::NewDataset => ResampleOutput::Chart,
+                    ResampleOutput::Chart => ResampleOutput::NewDataset,
+                }
+            }
+            _ => return false,
+        }
+        self.error = None;
+        true
+    }
+
+    fn build_instructions_from_config(&self) -> String {
+        format!(
+            "{}  Choices: Left/Right to cycle",
+            self.config.actions_to_instructions(&[
+                (Mode::Global, Action::Up),
+                (Mode::Global, Action::Down),
+                (Mode::Global, Action::Enter),
+                (Mode::Global, Action::Escape),
+                (Mode::Global, Action::ToggleInstructions),
+            ])
+        )
+    }
+
+    /// Render the dialog
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title("Resample by Time")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let block = Block::default().title("Options").borders(Borders::ALL);
+        let content = block.inner(layout.content_area);
+        block.render(layout.content_area, buf);
+
+        let labels = ["Time Column:", "Bucket Size:", "Group By:", "Aggregation:", "Value Column:", "Output:", "New Dataset Name:"];
+        let label_width = labels.iter().map(|l| l.len()).max().unwrap_or(0) as u16 + 2;
+        for (i, label) in labels.iter().enumerate() {
+            let y = content.y + i as u16;
+            if y >= content.bottom() {
+                break;
+            }
+            let selected = i == self.selected_field;
+            let inactive = (i == FIELD_VALUE && !self.aggregation.needs_value_column())
+                || (i == FIELD_NAME && self.output == ResampleOutput::Chart);
+            let style = if selected {
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+            } else if inactive {
+                Style::default().fg(Color::DarkGray)
+            } else {
+                Style::default()
+            };
+            buf.set_string(content.x + 1, y, *label, style);
+            let value_area = Rect { x: content.x + 1 + label_width, y, width: content.width.saturating_sub(label_width + 2), height: 1 };
+            let choice = match i {
+                FIELD_TIME => Some(self.column(self.time_idx)),
+                FIELD_GROUP => Some(self.group_label()),
+                FIELD_AGG => Some(self.aggregation.label().to_string()),
+                FIELD_VALUE => Some(self.column(self.value_idx)),
+                FIELD_OUTPUT => Some(self.output.label().to_string()),
+                _ => None,
+            };
+            if let Some(choice) = choice {
+                buf.set_string(value_area.x, y, format!("< {choice} >"), style);
+                continue;
+            }
+            let mut ta = if i == FIELD_EVERY { self.every_input.clone() } else { self.name_input.clone() };
+            if !selected {
+                ta.set_cursor_style(Style::default());
+            }
+            ta.render(value_area, buf);
+        }
+        if let Some(error) = &self.error {
+            let y = content.y + FIELD_COUNT as u16 + 1;
+            if y < content.bottom() {
+                buf.set_string(content.x + 1, y, format!("Error: {error}"), Style::default().fg(Color::Red).add_modifier(Modifier::BOLD));
+            }
+        }
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Handle keyboard events for the dialog
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        match self.config.action_for_key(Mode::Global, key) {
+            Some(Action::Escape) => return Some(Action::DialogClose),
+            Some(Action::Enter) => {
+                let options = self.options();
+                if options.every.is_empty() {
+                    self.error = Some("Bucket size is required, e.g. 1h".to_string());
+                    return None;
+                }
+                return Some(Action::ResampleDialogApplied {
+                    options,
+                    output: self.output,
+                    dataset_name: self.name_input.lines().join("").trim().to_string(),
+                });
+            }
+            Some(Action::Up) => {
+                self.selected_field = self.selected_field.saturating_sub(1);
+                return None;
+            }
+            Some(Action::Down) => {
+                self.selected_field = (self.selected_field + 1).min(FIELD_COUNT - 1);
+                return None;
+            }
+            Some(action @ (Action::Left | Action::Right)) if self.cycle_field(action == Action::Right) => return None,
+            Some(Action::ToggleInstructions) => {
+                self.show_instructions = !self.show_instructions;
+                return None;
+            }
+            _ => {}
+        }
+        if let Some(input) = self.current_input() {
+            if matches!(key.code, KeyCode::Char(_) | KeyCode::Backspace | KeyCode::Delete | KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End) {
+                input.input(tui_textarea::Input::from(key));
+                self.error = None;
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn press(dialog: &mut ResampleDialog, code: KeyCode) -> Option<Action> {
+        dialog.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn test_events_per_hour_per_host_options() {
+        let columns = vec!["ts".to_string(), "host".to_string(), "bytes".to_string()];
+        let mut dialog = ResampleDialog::new("logs", columns, 0);
+        dialog.config.reset_keybindings_to_default();
+        press(&mut dialog, KeyCode::Down);
+        press(&mut dialog, KeyCode::Right);
+        press(&mut dialog, KeyCode::Right);
+        assert_eq!(dialog.group_label(), "host");
+        press(&mut dialog, KeyCode::Down);
+        press(&mut dialog, KeyCode::Down);
+        press(&mut dialog, KeyCode::Down);
+        press(&mut dialog, KeyCode::Right);
+        assert_eq!(
+            press(&mut dialog, KeyCode::Enter),
+            Some(Action::ResampleDialogApplied {
+                options: ResampleOptions {
+                    time_column: "ts".to_string(),
+                    every: "1h".to_string(),
+                    group_by: "host".to_string(),
+                    aggregation: ResampleAgg::Count,
+                    value_column: String::new(),
+                },
+                output: ResampleOutput::Chart,
+                dataset_name: String::new(),
+            })
+        );
+    }
+}
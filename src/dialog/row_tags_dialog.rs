@@ -0,0 +1,324 @@
+//! RowTagsDialog: tag the selected row with labels and a note, and manage the labels in use
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Cell, Clear, Paragraph, Row, Table, TableState, Wrap};
+use tui_textarea::TextArea;
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+use crate::dataframe::row_tags::{parse_labels, RowTag, RowTags};
+
+fn text_input(value: &str, placeholder: &str) -> TextArea<'static> {
+    let mut t = TextArea::default();
+    t.set_block(Block::default());
+    t.set_placeholder_text(placeholder.to_string());
+    t.insert_str(value);
+    t
+}
+
+#[derive(Debug)]
+pub enum RowTagsDialogMode {
+    /// Labels in use, with their row counts
+    List,
+    /// Editing the selected row's tag; `note_selected` picks the focused input
+    EditRow { labels: Box<TextArea<'static>>, note: Box<TextArea<'static>>, note_selected: bool },
+    /// New name for the selected label
+    Rename(Box<TextArea<'static>>),
+}
+
+/// RowTagsDialog: edits a copy of the dataset's tags; the container copies it back on SaveWorkspaceState
+#[derive(Debug)]
+pub struct RowTagsDialog {
+    pub tags: RowTags,
+    pub labels: Vec<(String, usize)>,
+    pub selected: usize,
+    pub mode: RowTagsDialogMode,
+    /// Opened straight into EditRow: closing the editor closes the dialog
+    pub edit_only: bool,
+    pub error: Option<String>,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+impl RowTagsDialog {
+    pub fn new(tags: RowTags) -> Self {
+        let labels = tags.label_counts();
+        Self {
+            tags,
+            labels,
+            selected: 0,
+            mode: RowTagsDialogMode::List,
+            edit_only: false,
+            error: None,
+            show_instructions: true,
+            config: Config::default(),
+        }
+    }
+
+    /// Open the editor for the selected row, prefilled with its current tag
+    pub fn edit_row(&mut self, current: Option<&RowTag>) {
+        let (labels, note) = current.map(|t| (t.labels.join(", "), t.note.clone())).unwrap_or_default();
+        self.mode = RowTagsDialogMode::EditRow {
+            labels: Box::new(text_input(&labels, "comma-separated labels")),
+            note: Box::new(text_input(&note, "free-text note")),
+            note_selected: false,
+        };
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn selected_label(&self) -> Option<&str> {
+        self.labels.get(self.selected).map(|(label, _)| label.as_str())
+    }
+
+    fn refresh_labels(&mut self) {
+        self.labels = self.tags.label_counts();
+        self.selected = self.selected.min(self.labels.len().saturating_sub(1));
+    }
+
+    fn build_instructions_from_config(&self) -> String {
+        match self.mode {
+            RowTagsDialogMode::List => format!(
+                "Enter: Filter to label  {}",
+                self.config.actions_to_instructions(&[
+                    (Mode::RowTagsDialog, Action::TagRow),
+                    (Mode::RowTagsDialog, Action::RenameTag),
+                    (Mode::RowTagsDialog, Action::DeleteTag),
+                    (Mode::RowTagsDialog, Action::ToggleTagsColumn),
+                    (Mode::Global, Action::Escape),
+                    (Mode::Global, Action::ToggleInstructions),
+                ])
+            ),
+            RowTagsDialogMode::EditRow { .. } => format!(
+                "Empty labels and note remove the tag  {}",
+                self.config.actions_to_instructions(&[
+                    (Mode::Global, Action::Tab),
+                    (Mode::Global, Action::Enter),
+                    (Mode::Global, Action::Escape),
+                ])
+            ),
+            RowTagsDialogMode::Rename(_) => self.config.actions_to_instructions(&[
+                (Mode::Global, Action::Enter),
+                (Mode::Global, Action::Escape),
+            ]),
+        }
+    }
+
+    /// Render the dialog
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title("Row Tags")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let content = layout.content_area;
+
+        match &self.mode {
+            RowTagsDialogMode::EditRow { labels, note, note_selected } => {
+                let chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Length(3), Constraint::Length(3), Constraint::Min(1)])
+                    .split(content);
+                for (i, (title, input)) in [("Labels", labels), ("Note", note)].into_iter().enumerate() {
+                    let focused = (i == 1) == *note_selected;
+                    let style = if focused { Style::default().fg(Color::Yellow) } else { Style::default() };
+                    let block = Block::default().title(title).borders(Borders::ALL).border_style(style);
+                    let inner = block.inner(chunks[i]);
+                    block.render(chunks[i], buf);
+                    let mut ta = input.as_ref().clone();
+                    if !focused {
+                        ta.set_cursor_style(Style::default());
+                    }
+                    ta.render(inner, buf);
+                }
+                let hint = match &self.error {
+                    Some(error) => Line::styled(format!("Error: {error}"), Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+                    None if self.labels.is_empty() => Line::styled("No labels in use yet", Style::default().fg(Color::DarkGray)),
+                    None => Line::styled(
+                        format!("In use: {}", self.labels.iter().map(|(l, _)| l.as_str()).collect::<Vec<_>>().join(", ")),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                };
+                Paragraph::new(hint).wrap(Wrap { trim: true }).render(chunks[2], buf);
+            }
+            RowTagsDialogMode::Rename(input) => {
+                let input_area = Rect { height: content.height.min(3), ..content };
+                let block = Block::default()
+                    .title(format!("Rename '{}'", self.selected_label().unwrap_or_default()))
+                    .borders(Borders::ALL);
+                let inner = block.inner(input_area);
+                block.render(input_area, buf);
+                input.render(inner, buf);
+            }
+            RowTagsDialogMode::List if self.labels.is_empty() => {
+                Paragraph::new("No tagged rows yet. Tag the selected row to start.")
+                    .style(Style::default().fg(Color::Gray))
+                    .render(content, buf);
+            }
+            RowTagsDialogMode::List => {
+                let rows = self
+                    .labels
+                    .iter()
+                    .map(|(label, n)| Row::new(vec![Cell::from(label.clone()), Cell::from(n.to_string())]));
+                let table = Table::new(rows, [Constraint::Min(16), Constraint::Length(8)])
+                    .header(
+                        Row::new(vec!["Label", "Rows"])
+                            .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                    )
+                    .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+                let mut state = TableState::default().with_selected(Some(self.selected));
+                StatefulWidget::render(table, content, buf, &mut state);
+            }
+        }
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Handle keyboard events for the dialog
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        let global_action = self.config.action_for_key(Mode::Global, key);
+        match &mut self.mode {
+            RowTagsDialogMode::EditRow { labels, note, note_selected } => {
+                match global_action {
+                    Some(Action::Escape) if self.edit_only => return Some(Action::DialogClose),
+                    Some(Action::Escape) => self.mode = RowTagsDialogMode::List,
+                    Some(Action::Tab | Action::Up | Action::Down) => *note_selected = !*note_selected,
+                    Some(Action::Enter) => {
+                        return Some(Action::RowTagApplied {
+                            labels: parse_labels(&labels.lines().join(",")),
+                            note: note.lines().join(" "),
+                        });
+                    }
+                    _ => {
+                        if matches!(key.code, KeyCode::Char(_) | KeyCode::Backspace | KeyCode::Delete | KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End) {
+                            let input = if *note_selected { note } else { labels };
+                            input.input(tui_textarea::Input::from(key));
+                            self.error = None;
+                        }
+                    }
+                }
+                return None;
+            }
+            RowTagsDialogMode::Rename(input) => {
+                match global_action {
+                    Some(Action::Escape) => self.mode = RowTagsDialogMode::List,
+                    Some(Action::Enter) => {
+                        let to = input.lines().join(" ").trim().to_string();
+                        let from = self.selected_label().map(str::to_string);
+                        self.mode = RowTagsDialogMode::List;
+                        if let Some(from) = from
+                            && !to.is_empty() && to != from {
+                                self.tags.rename_label(&from, &to);
+                                self.refresh_labels();
+                                return Some(Action::SaveWorkspaceState);
+                            }
+                    }
+                    _ => {
+                        input.input(tui_textarea::Input::from(key));
+                    }
+                }
+                return None;
+            }
+            RowTagsDialogMode::List => {}
+        }
+
+        match global_action {
+            Some(Action::Escape) => return Some(Action::DialogClose),
+            Some(Action::Enter) => return self.selected_label().map(|label| Action::FilterByTag(label.to_string())),
+            Some(Action::Up) => {
+                self.selected = self.selected.saturating_sub(1);
+                return None;
+            }
+            Some(Action::Down) => {
+                if self.selected + 1 < self.labels.len() {
+                    self.selected += 1;
+                }
+                return None;
+            }
+            Some(Action::ToggleInstructions) => {
+                self.show_instructions = !self.show_instructions;
+                return None;
+            }
+            _ => {}
+        }
+
+        match self.config.action_for_key(Mode::RowTagsDialog, key) {
+            Some(Action::TagRow) => Some(Action::TagSelectedRow),
+            Some(Action::RenameTag) => {
+                let label = self.selected_label()?.to_string();
+                self.mode = RowTagsDialogMode::Rename(Box::new(text_input(&label, "new label")));
+                None
+            }
+            Some(Action::DeleteTag) => {
+                let label = self.selected_label()?.to_string();
+                self.tags.remove_label(&label);
+                self.refresh_labels();
+                Some(Action::SaveWorkspaceState)
+            }
+            Some(Action::ToggleTagsColumn) => Some(Action::ToggleTagsColumn),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+    use polars::prelude::*;
+
+    fn press(dialog: &mut RowTagsDialog, code: KeyCode, modifiers: KeyModifiers) -> Option<Action> {
+        dialog.handle_key_event(KeyEvent::new(code, modifiers))
+    }
+
+    #[test]
+    fn test_edit_rename_and_delete() {
+        let df = df!("host" => ["a", "b"]).unwrap();
+        let mut tags = RowTags::default();
+        tags.set(&df, 0, &["host".to_string()], vec!["c2".to_string()], String::new()).unwrap();
+        let mut dialog = RowTagsDialog::new(tags.clone());
+        dialog.config.reset_keybindings_to_default();
+
+        dialog.edit_row(tags.get(&df, 0));
+        for c in ", beacon".chars() {
+            press(&mut dialog, KeyCode::Char(c), KeyModifiers::NONE);
+        }
+        press(&mut dialog, KeyCode::Tab, KeyModifiers::NONE);
+        for c in "seen twice".chars() {
+            press(&mut dialog, KeyCode::Char(c), KeyModifiers::NONE);
+        }
+        assert_eq!(
+            press(&mut dialog, KeyCode::Enter, KeyModifiers::NONE),
+            Some(Action::RowTagApplied { labels: vec!["c2".to_string(), "beacon".to_string()], note: "seen twice".to_string() })
+        );
+        press(&mut dialog, KeyCode::Esc, KeyModifiers::NONE);
+        assert_eq!(press(&mut dialog, KeyCode::Enter, KeyModifiers::NONE), Some(Action::FilterByTag("c2".to_string())));
+
+        press(&mut dialog, KeyCode::Char('e'), KeyModifiers::CONTROL);
+        press(&mut dialog, KeyCode::Char('x'), KeyModifiers::NONE);
+        assert_eq!(press(&mut dialog, KeyCode::Enter, KeyModifiers::NONE), Some(Action::SaveWorkspaceState));
+        assert_eq!(dialog.labels, [("c2x".to_string(), 1)]);
+        assert_eq!(press(&mut dialog, KeyCode::Char('d'), KeyModifiers::CONTROL), Some(Action::SaveWorkspaceState));
+        assert!(dialog.tags.is_empty());
+    }
+}
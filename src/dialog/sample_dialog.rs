@@ -0,0 +1,244 @@
+//! SampleDialog: copy the first, last or a random subset of rows into a new dataset
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Clear, Paragraph, Wrap};
+use tui_textarea::TextArea;
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+use crate::dataframe::sampling::{SampleMethod, SampleOptions};
+
+const FIELD_METHOD: usize = 0;
+const FIELD_AMOUNT: usize = 1;
+const FIELD_SEED: usize = 2;
+const FIELD_NAME: usize = 3;
+const FIELD_COUNT: usize = 4;
+
+fn text_input(value: &str, placeholder: &str) -> TextArea<'static> {
+    let mut t = TextArea::default();
+    t.set_block(Block::default());
+    t.set_placeholder_text(placeholder.to_string());
+    t.insert_str(value);
+    t
+}
+
+/// SampleDialog: options for a sampled working copy of the current view
+#[derive(Debug)]
+pub struct SampleDialog {
+    pub method: SampleMethod,
+    pub amount_input: TextArea<'static>,
+    pub seed_input: TextArea<'static>,
+    pub name_input: TextArea<'static>,
+    /// Rows in the current view, shown for reference
+    pub total_rows: usize,
+    pub selected_field: usize,
+    pub error: Option<String>,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+impl SampleDialog {
+    pub fn new(dataset_name: &str, total_rows: usize) -> Self {
+        let options = SampleOptions::default();
+        Self {
+            method: options.method,
+            amount_input: text_input(&options.amount, ""),
+            seed_input: text_input("", "random"),
+            name_input: text_input("", &format!("{dataset_name}_{}", options.method.suffix())),
+            total_rows,
+            selected_field: FIELD_METHOD,
+            error: None,
+            show_instructions: true,
+            config: Config::default(),
+        }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    pub fn set_error(&mut self, message: String) {
+        self.error = Some(message);
+    }
+
+    fn options(&self) -> SampleOptions {
+        SampleOptions {
+            method: self.method,
+            amount: self.amount_input.lines().join(""),
+            seed: self.seed_input.lines().join(""),
+        }
+    }
+
+    fn current_input(&mut self) -> Option<&mut TextArea<'static>> {
+        match self.selected_field {
+            FIELD_AMOUNT => Some(&mut self.amount_input),
+            FIELD_SEED => Some(&mut self.seed_input),
+            FIELD_NAME => Some(&mut self.name_input),
+            _ => None,
+        }
+    }
+
+    fn build_instructions_from_config(&self) -> String {
+        format!(
+            "{}  Method: Left/Right to cycle",
+            self.config.actions_to_instructions(&[
+                (Mode::Global, Action::Up),
+                (Mode::Global, Action::Down),
+                (Mode::Global, Action::Enter),
+                (Mode::Global, Action::Escape),
+                (Mode::Global, Action::ToggleInstructions),
+            ])
+        )
+    }
+
+    /// Render the dialog
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title("Sample Dataset")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let block = Block::default().title(format!("Options ({} rows in view)", self.total_rows)).borders(Borders::ALL);
+        let content = block.inner(layout.content_area);
+        block.render(layout.content_area, buf);
+
+        let amount_label = if self.method == SampleMethod::RandomPercent { "Percent:" } else { "Rows:" };
+        let labels = ["Method:", amount_label, "Seed:", "New Dataset Name:"];
+        let label_width = labels.iter().map(|l| l.len()).max().unwrap_or(0) as u16 + 2;
+        for (i, label) in labels.iter().enumerate() {
+            let y = content.y + i as u16;
+            if y >= content.bottom() {
+                break;
+            }
+            let selected = i == self.selected_field;
+            let style = if selected { Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD) } else { Style::default() };
+            buf.set_string(content.x + 1, y, *label, style);
+            let value_area = Rect { x: content.x + 1 + label_width, y, width: content.width.saturating_sub(label_width + 2), height: 1 };
+            let input = match i {
+                FIELD_METHOD => {
+                    buf.set_string(value_area.x, y, format!("< {} >", self.method.label()), style);
+                    continue;
+                }
+                FIELD_AMOUNT => &self.amount_input,
+                FIELD_SEED => &self.seed_input,
+                _ => &self.name_input,
+            };
+            let mut ta = input.clone();
+            if !selected {
+                ta.set_cursor_style(Style::default());
+            }
+            ta.render(value_area, buf);
+        }
+        if let Some(error) = &self.error {
+            let y = content.y + FIELD_COUNT as u16 + 1;
+            if y < content.bottom() {
+                buf.set_string(content.x + 1, y, format!("Error: {error}"), Style::default().fg(Color::Red).add_modifier(Modifier::BOLD));
+            }
+        }
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Handle keyboard events for the dialog
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        match self.config.action_for_key(Mode::Global, key) {
+            Some(Action::Escape) => return Some(Action::DialogClose),
+            Some(Action::Enter) => {
+                let options = self.options();
+                if let Err(e) = options.row_count(self.total_rows) {
+                    self.error = Some(e.to_string());
+                    return None;
+                }
+                return Some(Action::SampleDialogApplied {
+                    options,
+                    dataset_name: self.name_input.lines().join("").trim().to_string(),
+                });
+            }
+            Some(Action::Up) => {
+                self.selected_field = self.selected_field.saturating_sub(1);
+                return None;
+            }
+            Some(Action::Down) => {
+                self.selected_field = (self.selected_field + 1).min(FIELD_COUNT - 1);
+                return None;
+            }
+            Some(action @ (Action::Left | Action::Right)) if self.selected_field == FIELD_METHOD => {
+                self.method = self.method.cycle(action == Action::Right);
+                if self.method == SampleMethod::RandomPercent && self.amount_input.lines().join("") == SampleOptions::default().amount {
+                    self.amount_input = text_input("10", "");
+                }
+                self.error = None;
+                return None;
+            }
+            Some(Action::ToggleInstructions) => {
+                self.show_instructions = !self.show_instructions;
+                return None;
+            }
+            _ => {}
+        }
+        if let Some(input) = self.current_input() {
+            if matches!(key.code, KeyCode::Char(_) | KeyCode::Backspace | KeyCode::Delete | KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End) {
+                input.input(tui_textarea::Input::from(key));
+                self.error = None;
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn press(dialog: &mut SampleDialog, code: KeyCode) -> Option<Action> {
+        dialog.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn test_random_percent_sample() {
+        let mut dialog = SampleDialog::new("logs", 500);
+        dialog.config.reset_keybindings_to_default();
+        press(&mut dialog, KeyCode::Left);
+        assert_eq!(dialog.method, SampleMethod::RandomPercent);
+        press(&mut dialog, KeyCode::Down);
+        press(&mut dialog, KeyCode::Backspace);
+        press(&mut dialog, KeyCode::Char('5'));
+        press(&mut dialog, KeyCode::Down);
+        press(&mut dialog, KeyCode::Down);
+        for c in "small".chars() {
+            press(&mut dialog, KeyCode::Char(c));
+        }
+        assert_eq!(
+            press(&mut dialog, KeyCode::Enter),
+            Some(Action::SampleDialogApplied {
+                options: SampleOptions { method: SampleMethod::RandomPercent, amount: "15".to_string(), seed: String::new() },
+                dataset_name: "small".to_string(),
+            })
+        );
+
+        press(&mut dialog, KeyCode::Up);
+        press(&mut dialog, KeyCode::Up);
+        press(&mut dialog, KeyCode::Char('0'));
+        assert_eq!(press(&mut dialog, KeyCode::Enter), None);
+        assert!(dialog.error.is_some());
+    }
+}
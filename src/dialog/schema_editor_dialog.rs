@@ -0,0 +1,337 @@
+//! SchemaEditorDialog: cast, rename and reorder columns, reviewing the values each cast would
+//! lose before the edit is applied as one transform
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crossterm::event::{KeyEvent, KeyEventKind};
+use polars::prelude::DataFrame;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Cell, Clear, Paragraph, Row, Table, TableState, Wrap};
+use tui_textarea::TextArea;
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+use crate::dataframe::schema_edit::{cast_failures, CastFailures, SchemaEdit, TYPE_NAMES};
+
+#[derive(Debug)]
+pub enum SchemaEditorMode {
+    List,
+    Rename(Box<TextArea<'static>>),
+    DatetimeFormat(Box<TextArea<'static>>),
+}
+
+/// SchemaEditorDialog: one row per column, in the order they will have
+#[derive(Debug)]
+pub struct SchemaEditorDialog {
+    df: Arc<DataFrame>,
+    pub edit: SchemaEdit,
+    /// Current type of each source column
+    current_types: HashMap<String, String>,
+    failures: Vec<CastFailures>,
+    pub selected: usize,
+    pub mode: SchemaEditorMode,
+    pub error: Option<String>,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+impl SchemaEditorDialog {
+    pub fn new(df: Arc<DataFrame>) -> Self {
+        let current_types = df
+            .get_columns()
+            .iter()
+            .map(|c| (c.name().to_string(), c.dtype().to_string()))
+            .collect();
+        Self {
+            edit: SchemaEdit::from_frame(&df),
+            df,
+            current_types,
+            failures: Vec::new(),
+            selected: 0,
+            mode: SchemaEditorMode::List,
+            error: None,
+            show_instructions: true,
+            config: Config::default(),
+        }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    pub fn set_error(&mut self, message: impl Into<String>) {
+        self.error = Some(message.into());
+    }
+
+    /// Count the values each cast would lose, after a type or format change
+    fn refresh_failures(&mut self) {
+        match cast_failures(&self.df, &self.edit) {
+            Ok(failures) => {
+                self.failures = failures;
+                self.error = None;
+            }
+            Err(e) => self.error = Some(format!("{e}")),
+        }
+    }
+
+    /// Step the selected column's target type through "keep" and `TYPE_NAMES`
+    fn cycle_type(&mut self, forward: bool) {
+        let Some(column) = self.edit.columns.get_mut(self.selected) else { return };
+        // Position 0 keeps the current type
+        let count = TYPE_NAMES.len() + 1;
+        let position = column
+            .dtype
+            .as_deref()
+            .and_then(|t| TYPE_NAMES.iter().position(|n| *n == t))
+            .map_or(0, |i| i + 1);
+        let next = if forward { (position + 1) % count } else { (position + count - 1) % count };
+        column.dtype = (next > 0).then(|| TYPE_NAMES[next - 1].to_string());
+        self.refresh_failures();
+    }
+
+    fn move_selected(&mut self, up: bool) {
+        let target = if up { self.selected.checked_sub(1) } else { Some(self.selected + 1) };
+        if let Some(target) = target.filter(|t| *t < self.edit.columns.len()) {
+            self.edit.columns.swap(self.selected, target);
+            self.selected = target;
+            // Failures are listed in column order
+            self.refresh_failures();
+        }
+    }
+
+    fn text_input(initial: &str) -> Box<TextArea<'static>> {
+        let mut input = TextArea::new(vec![initial.to_string()]);
+        input.set_block(Block::default());
+        input.move_cursor(tui_textarea::CursorMove::End);
+        Box::new(input)
+    }
+
+    fn build_instructions_from_config(&self) -> String {
+        match self.mode {
+            SchemaEditorMode::List => format!(
+                "Left/Right: Type  {}",
+                self.config.actions_to_instructions(&[
+                    (Mode::SchemaEditorDialog, Action::RenameSchemaColumn),
+                    (Mode::SchemaEditorDialog, Action::EditDatetimeFormat),
+                    (Mode::SchemaEditorDialog, Action::MoveColumnUp),
+                    (Mode::SchemaEditorDialog, Action::MoveColumnDown),
+                    (Mode::Global, Action::Enter),
+                    (Mode::Global, Action::Escape),
+                    (Mode::Global, Action::ToggleInstructions),
+                ])
+            ),
+            _ => self.config.actions_to_instructions(&[
+                (Mode::Global, Action::Enter),
+                (Mode::Global, Action::Escape),
+            ]),
+        }
+    }
+
+    /// Render the dialog
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title("Schema Editor")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let content = layout.content_area;
+        let [table_area, detail_area] = Layout::vertical([Constraint::Min(3), Constraint::Length(4)]).areas(content);
+
+        let rows = self.edit.columns.iter().map(|column| {
+            let current = self.current_types.get(&column.source).cloned().unwrap_or_default();
+            let failed = self.failures.iter().find(|f| f.column == column.source).map(|f| f.failed);
+            let type_cell = match &column.dtype {
+                Some(target) => Cell::from(format!("{current} -> {target}")).style(Style::default().fg(Color::Cyan)),
+                None => Cell::from(current),
+            };
+            Row::new(vec![
+                Cell::from(column.source.clone()),
+                Cell::from(if column.is_renamed() { column.name.clone() } else { String::new() }),
+                type_cell,
+                Cell::from(column.datetime_format.clone().unwrap_or_default()),
+                match failed {
+                    Some(n) => Cell::from(format!("{n} to null")).style(Style::default().fg(Color::Red)),
+                    None => Cell::from(""),
+                },
+            ])
+        });
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Min(14),
+                Constraint::Min(14),
+                Constraint::Length(26),
+                Constraint::Length(14),
+                Constraint::Length(14),
+            ],
+        )
+        .header(
+            Row::new(vec!["Column", "New name", "Type", "Format", "Failures"])
+                .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+        )
+        .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        let mut state = TableState::default().with_selected(Some(self.selected));
+        StatefulWidget::render(table, table_area, buf, &mut state);
+
+        let detail_block = Block::default().borders(Borders::TOP);
+        let detail_inner = detail_block.inner(detail_area);
+        detail_block.render(detail_area, buf);
+        match &self.mode {
+            SchemaEditorMode::Rename(input) | SchemaEditorMode::DatetimeFormat(input) => {
+                let label = if matches!(self.mode, SchemaEditorMode::Rename(_)) { "New name: " } else { "Datetime format (empty detects): " };
+                let [label_area, input_area] =
+                    Layout::horizontal([Constraint::Length(label.len() as u16), Constraint::Min(1)]).areas(detail_inner);
+                Paragraph::new(label).style(Style::default().fg(Color::Cyan)).render(label_area, buf);
+                input.render(Rect { height: 1, ..input_area }, buf);
+            }
+            SchemaEditorMode::List => {
+                let line = if let Some(error) = &self.error {
+                    Line::styled(error.clone(), Style::default().fg(Color::Red))
+                } else {
+                    let selected = self.edit.columns.get(self.selected).map(|c| c.source.as_str());
+                    match self.failures.iter().find(|f| Some(f.column.as_str()) == selected) {
+                        Some(f) => Line::styled(
+                            format!("{} value(s) cannot be converted and become null, e.g. {}", f.failed, f.examples.join(", ")),
+                            Style::default().fg(Color::Red),
+                        ),
+                        None => Line::styled(
+                            format!("{} cast(s) lose no values", self.edit.columns.iter().filter(|c| c.dtype.is_some()).count()),
+                            Style::default().fg(Color::Gray),
+                        ),
+                    }
+                };
+                Paragraph::new(line).wrap(Wrap { trim: true }).render(detail_inner, buf);
+            }
+        }
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Handle keyboard events: `Action::SchemaEditApplied` on Enter, `Action::DialogClose` on Esc
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        let global_action = self.config.action_for_key(Mode::Global, key);
+        if let SchemaEditorMode::Rename(input) | SchemaEditorMode::DatetimeFormat(input) = &mut self.mode {
+            match global_action {
+                Some(Action::Escape) => self.mode = SchemaEditorMode::List,
+                Some(Action::Enter) => {
+                    let text = input.lines().join(" ").trim().to_string();
+                    let renaming = matches!(self.mode, SchemaEditorMode::Rename(_));
+                    if let Some(column) = self.edit.columns.get_mut(self.selected) {
+                        if renaming {
+                            column.name = if text.is_empty() { column.source.clone() } else { text };
+                        } else {
+                            column.datetime_format = (!text.is_empty()).then_some(text);
+                        }
+                    }
+                    self.mode = SchemaEditorMode::List;
+                    if !renaming {
+                        self.refresh_failures();
+                    }
+                }
+                _ => {
+                    input.input(tui_textarea::Input::from(key));
+                }
+            }
+            return None;
+        }
+
+        match global_action {
+            Some(Action::Escape) => return Some(Action::DialogClose),
+            Some(Action::Enter) => return Some(Action::SchemaEditApplied(self.edit.clone())),
+            Some(Action::Up) => {
+                self.selected = self.selected.saturating_sub(1);
+                return None;
+            }
+            Some(Action::Down) => {
+                if self.selected + 1 < self.edit.columns.len() {
+                    self.selected += 1;
+                }
+                return None;
+            }
+            Some(Action::Left) | Some(Action::Right) => {
+                self.cycle_type(global_action == Some(Action::Right));
+                return None;
+            }
+            Some(Action::ToggleInstructions) => {
+                self.show_instructions = !self.show_instructions;
+                return None;
+            }
+            _ => {}
+        }
+
+        let column = self.edit.columns.get(self.selected)?;
+        match self.config.action_for_key(Mode::SchemaEditorDialog, key) {
+            Some(Action::RenameSchemaColumn) => self.mode = SchemaEditorMode::Rename(Self::text_input(&column.name)),
+            Some(Action::EditDatetimeFormat) => {
+                let format = column.datetime_format.clone().unwrap_or_default();
+                self.mode = SchemaEditorMode::DatetimeFormat(Self::text_input(&format));
+            }
+            Some(Action::MoveColumnUp) => self.move_selected(true),
+            Some(Action::MoveColumnDown) => self.move_selected(false),
+            _ => {}
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use polars::prelude::*;
+
+    fn press(dialog: &mut SchemaEditorDialog, code: KeyCode, modifiers: KeyModifiers) -> Option<Action> {
+        dialog.handle_key_event(KeyEvent::new(code, modifiers))
+    }
+
+    #[test]
+    fn test_edit_preview_and_apply() {
+        let df = Arc::new(df!("note" => ["a", "b"], "count" => ["1", "many"]).unwrap());
+        let mut dialog = SchemaEditorDialog::new(df);
+        dialog.config.reset_keybindings_to_default();
+
+        press(&mut dialog, KeyCode::Down, KeyModifiers::NONE);
+        press(&mut dialog, KeyCode::Right, KeyModifiers::NONE);
+        press(&mut dialog, KeyCode::Right, KeyModifiers::NONE);
+        assert_eq!(dialog.edit.columns[1].dtype.as_deref(), Some("Int64"));
+        assert_eq!(dialog.failures[0].examples, vec!["many".to_string()]);
+
+        press(&mut dialog, KeyCode::Char('r'), KeyModifiers::CONTROL);
+        for c in "_n".chars() {
+            press(&mut dialog, KeyCode::Char(c), KeyModifiers::NONE);
+        }
+        press(&mut dialog, KeyCode::Enter, KeyModifiers::NONE);
+        press(&mut dialog, KeyCode::Up, KeyModifiers::CONTROL);
+        assert_eq!(dialog.selected, 0);
+
+        let area = Rect::new(0, 0, 100, 20);
+        let mut buf = Buffer::empty(area);
+        dialog.render(area, &mut buf);
+        let text: String = buf.content().iter().map(|c| c.symbol()).collect();
+        assert!(text.contains("count_n") && text.contains("str -> Int64") && text.contains("1 to null"));
+
+        let Some(Action::SchemaEditApplied(edit)) = press(&mut dialog, KeyCode::Enter, KeyModifiers::NONE) else {
+            panic!("expected the edit to be applied");
+        };
+        assert_eq!(edit.columns[0].name, "count_n");
+        assert_eq!(edit.columns[1].source, "note");
+    }
+}
@@ -0,0 +1,355 @@
+//! ScriptDialog: edit and run Rhai scripts over the current DataFrame, and keep them in the project
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Cell, Clear, Paragraph, Row, Table, TableState, Wrap};
+use textwrap::wrap;
+use tui_textarea::TextArea;
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+use crate::dataframe::scripting::SavedScript;
+
+/// Shown in an empty editor
+const PLACEHOLDER: &str = "fn row(r) { #{ new_column: r.some_column } }   or   #{ new_column: df.some_column }";
+
+/// What a name typed in the dialog is for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptNamePurpose {
+    SaveScript,
+    NewDataset,
+}
+
+#[derive(Debug)]
+pub enum ScriptDialogMode {
+    Input,
+    Error(String),
+    NameInput { purpose: ScriptNamePurpose, input: Box<TextArea<'static>> },
+    SavedList,
+}
+
+/// ScriptDialog: script editor plus the project's saved scripts
+#[derive(Debug)]
+pub struct ScriptDialog {
+    pub textarea: TextArea<'static>,
+    pub mode: ScriptDialogMode,
+    /// Name of the saved script being edited; empty for a new script
+    pub name: String,
+    pub saved_scripts: Vec<SavedScript>,
+    pub selected: usize,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+impl ScriptDialog {
+    pub fn new(saved_scripts: Vec<SavedScript>) -> Self {
+        Self {
+            textarea: Self::new_textarea(),
+            mode: ScriptDialogMode::Input,
+            name: String::new(),
+            saved_scripts,
+            selected: 0,
+            show_instructions: true,
+            config: Config::default(),
+        }
+    }
+
+    fn new_textarea() -> TextArea<'static> {
+        let mut textarea = TextArea::default();
+        textarea.set_line_number_style(Style::default().bg(Color::DarkGray));
+        textarea.set_placeholder_text(PLACEHOLDER);
+        textarea
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    pub fn source(&self) -> String {
+        self.textarea.lines().join("\n")
+    }
+
+    /// Replace the editor content with a saved script
+    pub fn load_script(&mut self, script: &SavedScript) {
+        self.textarea = Self::new_textarea();
+        self.textarea.insert_str(&script.source);
+        self.name = script.name.clone();
+    }
+
+    /// Keep the list in step with the project
+    pub fn set_saved_scripts(&mut self, saved_scripts: Vec<SavedScript>) {
+        self.selected = self.selected.min(saved_scripts.len().saturating_sub(1));
+        self.saved_scripts = saved_scripts;
+    }
+
+    /// Set error message and switch to error mode
+    pub fn set_error(&mut self, msg: String) {
+        self.mode = ScriptDialogMode::Error(msg);
+    }
+
+    fn build_instructions_from_config(&self) -> String {
+        match self.mode {
+            ScriptDialogMode::Input => self.config.actions_to_instructions(&[
+                (Mode::ScriptDialog, Action::RunScript),
+                (Mode::ScriptDialog, Action::CreateNewDataset),
+                (Mode::ScriptDialog, Action::SaveScript),
+                (Mode::ScriptDialog, Action::OpenSavedScripts),
+                (Mode::ScriptDialog, Action::ClearText),
+                (Mode::Global, Action::Escape),
+                (Mode::Global, Action::ToggleInstructions),
+            ]),
+            ScriptDialogMode::SavedList => format!(
+                "Enter: Load  {}",
+                self.config.actions_to_instructions(&[
+                    (Mode::ScriptDialog, Action::DeleteSavedScript),
+                    (Mode::Global, Action::Escape),
+                ])
+            ),
+            ScriptDialogMode::NameInput { .. } | ScriptDialogMode::Error(_) => self.config.actions_to_instructions(&[
+                (Mode::Global, Action::Enter),
+                (Mode::Global, Action::Escape),
+            ]),
+        }
+    }
+
+    /// Render the dialog
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let title = if self.name.is_empty() { "Script".to_string() } else { format!("Script - {}", self.name) };
+        let outer_block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let content = layout.content_area;
+
+        match &self.mode {
+            ScriptDialogMode::Input => {
+                self.textarea.set_block(Block::default().title("Rhai").borders(Borders::ALL));
+                Widget::render(&self.textarea, content, buf);
+            }
+            ScriptDialogMode::Error(msg) => {
+                buf.set_string(content.x, content.y, "Error:", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD));
+                let lines = wrap(msg, content.width.saturating_sub(2) as usize);
+                for (i, line) in lines.iter().enumerate() {
+                    buf.set_string(content.x, content.y + 1 + i as u16, line, Style::default().fg(Color::Red));
+                }
+            }
+            ScriptDialogMode::NameInput { purpose, input } => {
+                let input_area = Rect { height: content.height.min(3), ..content };
+                let title = match purpose {
+                    ScriptNamePurpose::SaveScript => "Script name",
+                    ScriptNamePurpose::NewDataset => "Dataset name",
+                };
+                let block = Block::default().title(title).borders(Borders::ALL);
+                let input_inner = block.inner(input_area);
+                block.render(input_area, buf);
+                input.render(input_inner, buf);
+            }
+            ScriptDialogMode::SavedList if self.saved_scripts.is_empty() => {
+                Paragraph::new("No saved scripts in this project yet.")
+                    .style(Style::default().fg(Color::Gray))
+                    .render(content, buf);
+            }
+            ScriptDialogMode::SavedList => {
+                let rows = self.saved_scripts.iter().map(|script| {
+                    let first_line = script.source.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or_default();
+                    Row::new(vec![Cell::from(script.name.clone()), Cell::from(first_line.to_string())])
+                });
+                let table = Table::new(rows, [Constraint::Min(16), Constraint::Percentage(70)])
+                    .header(
+                        Row::new(vec!["Name", "Script"])
+                            .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                    )
+                    .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+                let mut state = TableState::default().with_selected(Some(self.selected));
+                StatefulWidget::render(table, content, buf, &mut state);
+            }
+        }
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    fn start_name_input(&mut self, purpose: ScriptNamePurpose) {
+        let mut input = TextArea::default();
+        input.set_block(Block::default());
+        if purpose == ScriptNamePurpose::SaveScript {
+            input.insert_str(&self.name);
+        }
+        self.mode = ScriptDialogMode::NameInput { purpose, input: Box::new(input) };
+    }
+
+    /// Handle keyboard events for the dialog
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        let global_action = self.config.action_for_key(Mode::Global, key);
+        if global_action == Some(Action::ToggleInstructions) {
+            self.show_instructions = !self.show_instructions;
+            return None;
+        }
+
+        match &mut self.mode {
+            ScriptDialogMode::Error(_) => {
+                if matches!(global_action, Some(Action::Escape | Action::Enter)) {
+                    self.mode = ScriptDialogMode::Input;
+                }
+                None
+            }
+            ScriptDialogMode::NameInput { purpose, input } => {
+                match global_action {
+                    Some(Action::Escape) => self.mode = ScriptDialogMode::Input,
+                    Some(Action::Enter) => {
+                        let name = input.lines().join(" ").trim().to_string();
+                        if name.is_empty() {
+                            return None;
+                        }
+                        let purpose = *purpose;
+                        self.mode = ScriptDialogMode::Input;
+                        let source = self.source();
+                        return Some(match purpose {
+                            ScriptNamePurpose::SaveScript => {
+                                self.name = name.clone();
+                                Action::ScriptSaved(SavedScript { name, source })
+                            }
+                            ScriptNamePurpose::NewDataset => {
+                                Action::ScriptDialogAppliedNewDataset { dataset_name: name, source }
+                            }
+                        });
+                    }
+                    _ => {
+                        input.input(tui_textarea::Input::from(key));
+                    }
+                }
+                None
+            }
+            ScriptDialogMode::SavedList => {
+                match global_action {
+                    Some(Action::Escape) => self.mode = ScriptDialogMode::Input,
+                    Some(Action::Enter) => {
+                        if let Some(script) = self.saved_scripts.get(self.selected).cloned() {
+                            self.load_script(&script);
+                            self.mode = ScriptDialogMode::Input;
+                        }
+                    }
+                    Some(Action::Up) => self.selected = self.selected.saturating_sub(1),
+                    Some(Action::Down) => {
+                        if self.selected + 1 < self.saved_scripts.len() {
+                            self.selected += 1;
+                        }
+                    }
+                    _ => {
+                        if self.config.action_for_key(Mode::ScriptDialog, key) == Some(Action::DeleteSavedScript)
+                            && self.selected < self.saved_scripts.len()
+                        {
+                            let script = self.saved_scripts.remove(self.selected);
+                            self.selected = self.selected.min(self.saved_scripts.len().saturating_sub(1));
+                            return Some(Action::ScriptDeleted(script.name));
+                        }
+                    }
+                }
+                None
+            }
+            ScriptDialogMode::Input => {
+                match global_action {
+                    Some(Action::Escape) => return Some(Action::DialogClose),
+                    Some(Action::Enter) => {
+                        self.textarea.input(tui_textarea::Input::from(key));
+                        return None;
+                    }
+                    _ => {}
+                }
+                match self.config.action_for_key(Mode::ScriptDialog, key) {
+                    Some(Action::RunScript) => {
+                        return Some(Action::ScriptDialogApplied { name: self.name.clone(), source: self.source() });
+                    }
+                    Some(Action::CreateNewDataset) => self.start_name_input(ScriptNamePurpose::NewDataset),
+                    Some(Action::SaveScript) => self.start_name_input(ScriptNamePurpose::SaveScript),
+                    Some(Action::OpenSavedScripts) => self.mode = ScriptDialogMode::SavedList,
+                    Some(Action::ClearText) => {
+                        self.textarea = Self::new_textarea();
+                        self.name.clear();
+                    }
+                    _ => match key.code {
+                        KeyCode::Char(_) | KeyCode::Backspace | KeyCode::Delete |
+                        KeyCode::Left | KeyCode::Right | KeyCode::Up | KeyCode::Down |
+                        KeyCode::Home | KeyCode::End | KeyCode::PageUp | KeyCode::PageDown |
+                        KeyCode::Tab => {
+                            self.textarea.input(tui_textarea::Input::from(key));
+                        }
+                        _ => {}
+                    },
+                }
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn press(dialog: &mut ScriptDialog, code: KeyCode, modifiers: KeyModifiers) -> Option<Action> {
+        dialog.handle_key_event(KeyEvent::new(code, modifiers))
+    }
+
+    fn type_text(dialog: &mut ScriptDialog, text: &str) {
+        for c in text.chars() {
+            press(dialog, KeyCode::Char(c), KeyModifiers::NONE);
+        }
+    }
+
+    #[test]
+    fn test_save_load_and_run_scripts() {
+        let saved = SavedScript { name: "domains".to_string(), source: "fn row(r) { #{ d: r.url } }".to_string() };
+        let mut dialog = ScriptDialog::new(vec![saved.clone()]);
+        dialog.config.reset_keybindings_to_default();
+
+        press(&mut dialog, KeyCode::Char('o'), KeyModifiers::CONTROL);
+        press(&mut dialog, KeyCode::Enter, KeyModifiers::NONE);
+        assert_eq!(dialog.name, "domains");
+        assert_eq!(
+            press(&mut dialog, KeyCode::Enter, KeyModifiers::CONTROL),
+            Some(Action::ScriptDialogApplied { name: "domains".to_string(), source: saved.source.clone() })
+        );
+
+        press(&mut dialog, KeyCode::Char('l'), KeyModifiers::CONTROL);
+        type_text(&mut dialog, "#{ n: [1] }");
+        press(&mut dialog, KeyCode::Char('s'), KeyModifiers::CONTROL);
+        type_text(&mut dialog, "one");
+        assert_eq!(
+            press(&mut dialog, KeyCode::Enter, KeyModifiers::NONE),
+            Some(Action::ScriptSaved(SavedScript { name: "one".to_string(), source: "#{ n: [1] }".to_string() }))
+        );
+
+        press(&mut dialog, KeyCode::Enter, KeyModifiers::CONTROL | KeyModifiers::SHIFT);
+        type_text(&mut dialog, "ones");
+        assert_eq!(
+            press(&mut dialog, KeyCode::Enter, KeyModifiers::NONE),
+            Some(Action::ScriptDialogAppliedNewDataset { dataset_name: "ones".to_string(), source: "#{ n: [1] }".to_string() })
+        );
+
+        press(&mut dialog, KeyCode::Char('o'), KeyModifiers::CONTROL);
+        assert_eq!(
+            press(&mut dialog, KeyCode::Char('d'), KeyModifiers::CONTROL),
+            Some(Action::ScriptDeleted("domains".to_string()))
+        );
+        assert!(dialog.saved_scripts.is_empty());
+    }
+}
@@ -0,0 +1,255 @@
+//! SigmaDialog: pick a Sigma rules file or folder, map rule fields to columns and choose where matches go
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Clear, Paragraph, Wrap};
+use tui_textarea::TextArea;
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+use crate::dataframe::sigma::{SigmaOptions, SigmaOutput, DEFAULT_SIGMA_COLUMN};
+
+const FIELD_RULES_PATH: usize = 0;
+const FIELD_MAPPING: usize = 1;
+const FIELD_OUTPUT: usize = 2;
+const FIELD_COLUMN: usize = 3;
+const FIELD_COUNT: usize = 4;
+
+fn text_input(placeholder: &str) -> TextArea<'static> {
+    let mut t = TextArea::default();
+    t.set_block(Block::default());
+    t.set_placeholder_text(placeholder.to_string());
+    t
+}
+
+fn input_text(input: &TextArea<'static>) -> String {
+    input.lines().join("").trim().to_string()
+}
+
+/// SigmaDialog: kept by the container between runs so the path and mapping are remembered
+#[derive(Debug)]
+pub struct SigmaDialog {
+    pub rules_path_input: TextArea<'static>,
+    pub mapping_input: TextArea<'static>,
+    pub output: SigmaOutput,
+    pub column_input: TextArea<'static>,
+    pub selected_field: usize,
+    /// Set by the container when a run fails
+    pub error: Option<String>,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+impl Default for SigmaDialog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SigmaDialog {
+    pub fn new() -> Self {
+        Self {
+            rules_path_input: text_input("rule .yml file or folder of rules"),
+            mapping_input: text_input("e.g. Image=process_path, CommandLine=cmdline"),
+            output: SigmaOutput::default(),
+            column_input: text_input(DEFAULT_SIGMA_COLUMN),
+            selected_field: FIELD_RULES_PATH,
+            error: None,
+            show_instructions: true,
+            config: Config::default(),
+        }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    pub fn options(&self) -> SigmaOptions {
+        SigmaOptions {
+            rules_path: input_text(&self.rules_path_input),
+            field_mapping: input_text(&self.mapping_input),
+            output: self.output,
+            column_name: input_text(&self.column_input),
+        }
+    }
+
+    fn selected_input(&mut self) -> Option<&mut TextArea<'static>> {
+        match self.selected_field {
+            FIELD_RULES_PATH => Some(&mut self.rules_path_input),
+            FIELD_MAPPING => Some(&mut self.mapping_input),
+            FIELD_COLUMN => Some(&mut self.column_input),
+            _ => None,
+        }
+    }
+
+    fn build_instructions_from_config(&self) -> String {
+        format!(
+            "{}  Left/Right: output  Unmapped fields match columns of the same name",
+            self.config.actions_to_instructions(&[
+                (Mode::Global, Action::Up),
+                (Mode::Global, Action::Down),
+                (Mode::Global, Action::Enter),
+                (Mode::Global, Action::Paste),
+                (Mode::Global, Action::Escape),
+                (Mode::Global, Action::ToggleInstructions),
+            ])
+        )
+    }
+
+    /// Render the dialog
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title("Sigma Rules")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let block = Block::default().title("Evaluate").borders(Borders::ALL);
+        let content = block.inner(layout.content_area);
+        block.render(layout.content_area, buf);
+
+        let labels = ["Rules Path:", "Field Mapping:", "Output:", "Column Name:"];
+        let label_width = labels.iter().map(|l| l.len()).max().unwrap_or(0) as u16 + 2;
+        for (i, label) in labels.iter().enumerate() {
+            let y = content.y + (i as u16) * 2;
+            if y >= content.bottom() {
+                break;
+            }
+            let selected = i == self.selected_field;
+            let style = if selected { Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD) } else { Style::default() };
+            buf.set_string(content.x + 1, y, label, style);
+            let value_area = Rect { x: content.x + 1 + label_width, y, width: content.width.saturating_sub(label_width + 2), height: 1 };
+            let input = match i {
+                FIELD_RULES_PATH => &self.rules_path_input,
+                FIELD_MAPPING => &self.mapping_input,
+                FIELD_COLUMN => &self.column_input,
+                _ => {
+                    buf.set_string(value_area.x, y, format!("< {} >", self.output.label()), style);
+                    continue;
+                }
+            };
+            let mut ta = input.clone();
+            if !selected {
+                ta.set_cursor_style(Style::default());
+            }
+            ta.render(value_area, buf);
+        }
+        if let Some(error) = &self.error {
+            let y = content.y + FIELD_COUNT as u16 * 2;
+            let area = Rect { x: content.x + 1, y, width: content.width.saturating_sub(2), height: content.bottom().saturating_sub(y) };
+            Paragraph::new(format!("Error: {error}"))
+                .style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
+                .wrap(Wrap { trim: true })
+                .render(area, buf);
+        }
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Handle keyboard events for the dialog
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        match self.config.action_for_key(Mode::Global, key) {
+            Some(Action::Escape) => return Some(Action::DialogClose),
+            Some(Action::Enter) => {
+                let options = self.options();
+                if options.rules_path.is_empty() {
+                    self.error = Some("Enter a rules file or folder".to_string());
+                    return None;
+                }
+                if let Err(e) = options.mapping() {
+                    self.error = Some(e.to_string());
+                    return None;
+                }
+                self.error = None;
+                return Some(Action::SigmaDialogApplied(options));
+            }
+            Some(Action::Up) => {
+                self.selected_field = self.selected_field.saturating_sub(1);
+                return None;
+            }
+            Some(Action::Down) => {
+                self.selected_field = (self.selected_field + 1).min(FIELD_COUNT - 1);
+                return None;
+            }
+            Some(Action::Left | Action::Right) if self.selected_field == FIELD_OUTPUT => {
+                self.output = self.output.toggle();
+                return None;
+            }
+            Some(Action::ToggleInstructions) => {
+                self.show_instructions = !self.show_instructions;
+                return None;
+            }
+            Some(Action::Paste) => {
+                if let Ok(mut clipboard) = arboard::Clipboard::new()
+                    && let Ok(text) = clipboard.get_text()
+                    && let Some(input) = self.selected_input() {
+                        input.insert_str(text.trim());
+                    }
+                return None;
+            }
+            _ => {}
+        }
+        if matches!(key.code, KeyCode::Char(_) | KeyCode::Backspace | KeyCode::Delete | KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End)
+            && let Some(input) = self.selected_input() {
+                input.input(tui_textarea::Input::from(key));
+                self.error = None;
+            }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn press(dialog: &mut SigmaDialog, code: KeyCode) -> Option<Action> {
+        dialog.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    fn type_text(dialog: &mut SigmaDialog, text: &str) {
+        for c in text.chars() {
+            press(dialog, KeyCode::Char(c));
+        }
+    }
+
+    #[test]
+    fn test_options_from_fields() {
+        let mut dialog = SigmaDialog::new();
+        dialog.config.reset_keybindings_to_default();
+        assert!(press(&mut dialog, KeyCode::Enter).is_none());
+        assert!(dialog.error.is_some());
+        type_text(&mut dialog, "rules/");
+        press(&mut dialog, KeyCode::Down);
+        type_text(&mut dialog, "Image");
+        assert!(press(&mut dialog, KeyCode::Enter).is_none());
+        type_text(&mut dialog, "=proc");
+        press(&mut dialog, KeyCode::Down);
+        press(&mut dialog, KeyCode::Right);
+        match press(&mut dialog, KeyCode::Enter) {
+            Some(Action::SigmaDialogApplied(options)) => {
+                assert_eq!(options.rules_path, "rules/");
+                assert_eq!(options.field_mapping, "Image=proc");
+                assert_eq!(options.output, SigmaOutput::Dataset);
+                assert_eq!(options.column_name(), DEFAULT_SIGMA_COLUMN);
+            }
+            other => panic!("expected sigma options, got {other:?}"),
+        }
+    }
+}
@@ -15,18 +15,90 @@ use ratatui::Frame;
 use ratatui::layout::Size;
 use tokio::sync::mpsc::UnboundedSender;
 use crate::components::Component;
+use crate::components::column_search::ColumnSearch;
 use crate::components::dialog_layout::split_dialog_area;
 
 
+/// Where null values go in a sort
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NullsOrder {
+    /// First when ascending, last when descending
+    #[default]
+    Auto,
+    First,
+    Last,
+}
+
+impl NullsOrder {
+    pub fn label(&self) -> &'static str {
+        match self {
+            NullsOrder::Auto => "nulls auto",
+            NullsOrder::First => "nulls first",
+            NullsOrder::Last => "nulls last",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            NullsOrder::Auto => NullsOrder::First,
+            NullsOrder::First => NullsOrder::Last,
+            NullsOrder::Last => NullsOrder::Auto,
+        }
+    }
+
+    pub fn nulls_last(&self, ascending: bool) -> bool {
+        match self {
+            NullsOrder::Auto => !ascending,
+            NullsOrder::First => false,
+            NullsOrder::Last => true,
+        }
+    }
+}
+
 /// Represents a single sort column with direction
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SortColumn {
     pub name: String,
     pub ascending: bool,
+    /// Compare runs of digits by value, so "file2" sorts before "file10" (text columns only)
+    #[serde(default)]
+    pub natural: bool,
+    /// Ignore letter case (text columns only)
+    #[serde(default)]
+    pub case_insensitive: bool,
+    #[serde(default)]
+    pub nulls: NullsOrder,
+}
+
+impl SortColumn {
+    pub fn new(name: impl Into<String>, ascending: bool) -> Self {
+        Self { name: name.into(), ascending, natural: false, case_insensitive: false, nulls: NullsOrder::Auto }
+    }
+
+    /// Options other than the direction, e.g. "natural, ignore case"
+    pub fn options_label(&self) -> String {
+        let mut options = Vec::new();
+        if self.natural {
+            options.push("natural");
+        }
+        if self.case_insensitive {
+            options.push("ignore case");
+        }
+        if self.nulls != NullsOrder::Auto {
+            options.push(self.nulls.label());
+        }
+        options.join(", ")
+    }
 }
+
 impl Display for SortColumn {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}: {}", self.name, if self.ascending { "asc" } else { "desc" })
+        write!(f, "{}: {}", self.name, if self.ascending { "asc" } else { "desc" })?;
+        let options = self.options_label();
+        if !options.is_empty() {
+            write!(f, " ({options})")?;
+        }
+        Ok(())
     }
 }
 
@@ -37,6 +109,11 @@ pub enum SortDialogMode {
     AddColumn,
 }
 
+/// Marker shown next to a sorted column for its direction
+pub fn sort_arrow(ascending: bool) -> &'static str {
+    if ascending { "▲" } else { "▼" }
+}
+
 /// SortDialog: UI for configuring sort columns and order
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SortDialog {
@@ -51,6 +128,9 @@ pub struct SortDialog {
     pub add_column_scroll_offset: usize,
     pub current_column: Option<String>,
     pub show_instructions: bool, // new: show instructions area (default true)
+    /// Type-to-jump over the columns available to add
+    #[serde(skip)]
+    pub column_search: ColumnSearch,
     #[serde(skip)]
     pub config: Config,
 }
@@ -69,6 +149,7 @@ impl SortDialog {
             add_column_scroll_offset: 0,
             current_column: None,
             show_instructions: true,
+            column_search: ColumnSearch::default(),
             config: Config::default(),
         }
     }
@@ -93,10 +174,15 @@ impl SortDialog {
                     (crate::config::Mode::Sort, crate::action::Action::ToggleSortDirection),
                     (crate::config::Mode::Sort, crate::action::Action::RemoveSortColumn),
                     (crate::config::Mode::Sort, crate::action::Action::AddSortColumn),
+                    (crate::config::Mode::Sort, crate::action::Action::SearchColumns),
+                    (crate::config::Mode::Sort, crate::action::Action::ToggleSortNatural),
+                    (crate::config::Mode::Sort, crate::action::Action::ToggleSortCaseInsensitive),
+                    (crate::config::Mode::Sort, crate::action::Action::CycleSortNulls),
                 ])
             }
             SortDialogMode::AddColumn => {
                 self.config.actions_to_instructions(&[
+                    (crate::config::Mode::Sort, crate::action::Action::SearchColumns),
                     (crate::config::Mode::Global, crate::action::Action::Escape),
                     (crate::config::Mode::Global, crate::action::Action::ToggleInstructions),
                 ])
@@ -127,7 +213,7 @@ impl SortDialog {
         let instructions_area = layout.instructions_area;
         // Draw dialog frame
         let block = Block::default()
-            .title("Columns")
+            .title(self.column_search.title().unwrap_or_else(|| "Columns".to_string()))
             .borders(Borders::ALL);
         block.render(content_area, buf);
         let inner = content_area.inner(Margin { vertical: 1, horizontal: 2 });
@@ -152,11 +238,13 @@ impl SortDialog {
                         let col = &self.sort_columns[i];
                         let selected = i == self.active_index;
                         let zebra = i % 2 == 0;
-                        let dir = if col.ascending { "↑" } else { "↓" };
+                        let dir = sort_arrow(col.ascending);
+                        let options = col.options_label();
+                        let options = if options.is_empty() { options } else { format!("  ({options})") };
                         let text = if selected {
-                            format!("> {}  {}", col.name, dir)
+                            format!("> {}  {}{}", col.name, dir, options)
                         } else {
-                            format!("  {}  {}", col.name, dir)
+                            format!("  {}  {}{}", col.name, dir, options)
                         };
                         let mut style = Style::default();
                         if selected {
@@ -242,6 +330,18 @@ impl SortDialog {
                 return None;
             }
 
+            // While searching, keys edit the query and move to the best matching column
+            if self.column_search.is_active() {
+                let available: Vec<String> = self.available_columns().into_iter().cloned().collect();
+                if let Some(i) = self.column_search.handle_key(key, &available) {
+                    self.add_column_index = i;
+                    if i < self.add_column_scroll_offset || i >= self.add_column_scroll_offset + max_rows {
+                        self.add_column_scroll_offset = i.saturating_sub(max_rows / 2);
+                    }
+                }
+                return None;
+            }
+
             // First, honor config-driven actions (Global + Sort)
             if let Some(global_action) = self.config.action_for_key(crate::config::Mode::Global, key) {
                 match global_action {
@@ -257,7 +357,7 @@ impl SortDialog {
                                 let available = self.available_columns();
                                 if !available.is_empty() {
                                     let col_name = available[self.add_column_index].clone();
-                                    self.sort_columns.push(SortColumn { name: col_name.clone(), ascending: true });
+                                    self.sort_columns.push(SortColumn::new(col_name.clone(), true));
                                     self.mode = SortDialogMode::List;
                                     self.active_index = self.sort_columns.len() - 1;
                                     // Adjust scroll for main list
@@ -358,6 +458,17 @@ impl SortDialog {
                             }
                         return None;
                     }
+                    Action::ToggleSortNatural | Action::ToggleSortCaseInsensitive | Action::CycleSortNulls => {
+                        if self.mode == SortDialogMode::List
+                            && let Some(col) = self.sort_columns.get_mut(self.active_index) {
+                                match sort_action {
+                                    Action::ToggleSortNatural => col.natural = !col.natural,
+                                    Action::ToggleSortCaseInsensitive => col.case_insensitive = !col.case_insensitive,
+                                    _ => col.nulls = col.nulls.next(),
+                                }
+                            }
+                        return None;
+                    }
                     Action::RemoveSortColumn => {
                         if self.mode == SortDialogMode::List
                             && !self.sort_columns.is_empty() && self.active_index < self.sort_columns.len() {
@@ -384,6 +495,15 @@ impl SortDialog {
                             }
                         return None;
                     }
+                    Action::SearchColumns => {
+                        if self.mode == SortDialogMode::List {
+                            self.mode = SortDialogMode::AddColumn;
+                            self.add_column_index = 0;
+                            self.add_column_scroll_offset = 0;
+                        }
+                        self.column_search.start();
+                        return None;
+                    }
                     Action::AddSortColumn => {
                         if self.mode == SortDialogMode::List {
                             self.mode = SortDialogMode::AddColumn;
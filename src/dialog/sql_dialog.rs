@@ -8,15 +8,18 @@ use crossterm::event::{KeyEvent, KeyEventKind};
 use textwrap::wrap;
 use crate::dialog::file_browser_dialog::{FileBrowserDialog, FileBrowserAction, FileBrowserMode};
 use arboard::Clipboard;
-use tui_textarea::TextArea;
+use tui_textarea::{CursorMove, TextArea};
 use crate::components::dialog_layout::split_dialog_area;
 use crate::config::Config;
+use crate::sql::diagnostics::SqlDiagnostic;
 
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SqlDialogMode {
     Input,
     Error(String),
+    /// Outcome of a statement that returns no rows, such as creating a materialized view
+    Notice(String),
     FileBrowser,
     NewDatasetInput,
 }
@@ -30,6 +33,10 @@ pub struct SqlDialog {
     pub show_instructions: bool, // new: show instructions area (default true)
     pub create_new_dataset: bool, // whether to create a new dataset from the query
     pub dataset_name_input: String, // input for new dataset name
+    /// Location and suggestions for the error being shown
+    pub error_diagnostic: Option<SqlDiagnostic>,
+    /// The last run asked for every row, ignoring `sql_row_limit`
+    pub fetch_all: bool,
     pub config: Config,
 }
 
@@ -50,6 +57,8 @@ impl SqlDialog {
             show_instructions: true,
             create_new_dataset: false,
             dataset_name_input: String::new(),
+            error_diagnostic: None,
+            fetch_all: false,
             config: Config::default(),
         }
     }
@@ -67,6 +76,7 @@ impl SqlDialog {
             (crate::config::Mode::Global, crate::action::Action::Escape),
             (crate::config::Mode::Global, crate::action::Action::ToggleInstructions),
             (crate::config::Mode::SqlDialog, crate::action::Action::RunQuery),
+            (crate::config::Mode::SqlDialog, crate::action::Action::RunQueryFetchAll),
             // (crate::config::Mode::SqlDialog, crate::action::Action::CreateNewDataset),
             (crate::config::Mode::SqlDialog, crate::action::Action::SelectAllText),
             (crate::config::Mode::SqlDialog, crate::action::Action::CopyText),
@@ -113,14 +123,46 @@ impl SqlDialog {
                         buf.set_string(content_area.x, error_y + 1 + error_lines.len() as u16, "Press Esc or Enter to close error", Style::default().fg(Color::Yellow));
                     }
             }
-            SqlDialogMode::Error(msg) => {
-                let y = content_area.y;
-                buf.set_string(content_area.x, y, "Error:", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD));
-                let error_lines = wrap(msg, wrap_width);
-                for (i, line) in error_lines.iter().enumerate() {
-                    buf.set_string(content_area.x, y + 1 + i as u16, line, Style::default().fg(Color::Red));
+            SqlDialogMode::Error(msg) | SqlDialogMode::Notice(msg) => {
+                let is_error = matches!(self.mode, SqlDialogMode::Error(_));
+                let mut message = msg.clone();
+                let mut hint = None;
+                if let Some(diagnostic) = &self.error_diagnostic {
+                    if let Some(location) = diagnostic.location {
+                        message = format!("Line {}, column {}: {message}", location.line + 1, location.column + 1);
+                    }
+                    if !diagnostic.suggestions.is_empty() {
+                        hint = Some(format!("Did you mean: {}?", diagnostic.suggestions.join(", ")));
+                    }
+                }
+                let error_lines = wrap(&message, wrap_width);
+                // The query stays visible above the error, with the offending token selected
+                let error_height = (error_lines.len() + 2 + usize::from(hint.is_some())) as u16;
+                let query_height = content_area.height.saturating_sub(error_height).max(3).min(content_area.height);
+                let query_area = Rect { height: query_height, ..content_area };
+                self.textarea.set_block(
+                    Block::default()
+                        .title("DataFrame Query")
+                        .borders(Borders::ALL)
+                );
+                ratatui::widgets::Widget::render(&self.textarea, query_area, buf);
+                let y = content_area.y + query_height;
+                let bottom = content_area.y + content_area.height;
+                let (label, color) = if is_error { ("Error:", Color::Red) } else { ("Done:", Color::Green) };
+                let mut lines: Vec<(String, Style)> = vec![(label.to_string(), Style::default().fg(color).add_modifier(Modifier::BOLD))];
+                lines.extend(error_lines.iter().map(|line| (line.to_string(), Style::default().fg(color))));
+                if let Some(hint) = hint {
+                    lines.push((hint, Style::default().fg(Color::Cyan)));
+                }
+                let close = if is_error { "Press Esc or Enter to close error" } else { "Press Esc or Enter to continue" };
+                lines.push((close.to_string(), Style::default().fg(Color::Yellow)));
+                for (i, (line, style)) in lines.iter().enumerate() {
+                    let line_y = y + i as u16;
+                    if line_y >= bottom {
+                        break;
+                    }
+                    buf.set_string(content_area.x, line_y, line, *style);
                 }
-                buf.set_string(content_area.x, y + 1 + error_lines.len() as u16, "Press Esc or Enter to close error", Style::default().fg(Color::Yellow));
             }
             SqlDialogMode::FileBrowser => {
                 if let Some(browser) = &self.file_browser {
@@ -183,15 +225,13 @@ impl SqlDialog {
                 if self.error_active {
                     // Only allow Esc or Enter to clear error
                     if let Some(Action::Escape | Action::Enter) = &optional_global_action {
-                        self.error_active = false;
-                        self.mode = SqlDialogMode::Input;
+                        self.dismiss_error();
                         return None;
                     }
                     // Fallback for hardcoded Esc/Enter
                     match key.code {
                         KeyCode::Esc | KeyCode::Enter => {
-                            self.error_active = false;
-                            self.mode = SqlDialogMode::Input;
+                            self.dismiss_error();
                         }
                         _ => {}
                     }
@@ -235,8 +275,8 @@ impl SqlDialog {
                             self.mode = SqlDialogMode::NewDatasetInput;
                             return None;
                         }
-                        Action::RunQuery => {
-                            // run query
+                        Action::RunQuery | Action::RunQueryFetchAll => {
+                            self.fetch_all = *dialog_action == Action::RunQueryFetchAll;
                             return Some(Action::SqlDialogApplied(self.textarea.lines().join("\n")));
                         }
                         Action::RestoreDataFrame => {
@@ -359,19 +399,17 @@ impl SqlDialog {
                     _ => {}
                 }
             }
-            SqlDialogMode::Error(_) => {
+            SqlDialogMode::Error(_) | SqlDialogMode::Notice(_) => {
                 // Only close error on Esc or Enter
                 // Check Global actions first
                 if let Some(Action::Escape | Action::Enter) = &optional_global_action {
-                    self.error_active = false;
-                    self.mode = SqlDialogMode::Input;
+                    self.dismiss_error();
                     return None;
                 }
                 // Fallback for hardcoded keys
                 match key.code {
                     KeyCode::Esc | KeyCode::Enter => {
-                        self.error_active = false;
-                        self.mode = SqlDialogMode::Input;
+                        self.dismiss_error();
                     }
                     _ => {}
                 }
@@ -384,6 +422,38 @@ impl SqlDialog {
     pub fn set_error(&mut self, msg: String) {
         self.mode = SqlDialogMode::Error(msg);
         self.error_active = true;
+        self.error_diagnostic = None;
+    }
+
+    /// Show a query error, selecting the token it points at so it stays marked after the error is closed
+    pub fn set_query_error(&mut self, msg: String, diagnostic: SqlDiagnostic) {
+        self.set_error(msg);
+        if let Some(location) = diagnostic.location {
+            self.textarea.cancel_selection();
+            self.textarea.move_cursor(CursorMove::Jump(location.line as u16, location.column as u16));
+            self.textarea.start_selection();
+            for _ in 0..location.len {
+                self.textarea.move_cursor(CursorMove::Forward);
+            }
+            self.textarea.set_selection_style(Style::default().fg(Color::White).bg(Color::Red));
+        }
+        self.error_diagnostic = Some(diagnostic);
+    }
+
+    /// Show the outcome of a statement that returned no rows
+    pub fn set_notice(&mut self, msg: String) {
+        self.mode = SqlDialogMode::Notice(msg);
+        self.error_active = false;
+        self.error_diagnostic = None;
+    }
+
+    /// Close the error and go back to editing the query
+    fn dismiss_error(&mut self) {
+        self.error_active = false;
+        self.mode = SqlDialogMode::Input;
+        self.error_diagnostic = None;
+        // Back to tui-textarea's default selection colour; the error token stays selected
+        self.textarea.set_selection_style(Style::default().bg(Color::LightBlue));
     }
 }
 
@@ -12,7 +12,7 @@ use crate::dialog::styling::style_set::{
     StyleApplication, MergeMode, GradientStyle, CategoricalStyle,
 };
 use crate::dialog::styling::application_scope_editor_dialog::ApplicationScopeEditorDialog;
-use crate::dialog::styling::color_picker_dialog::color_to_hex_string;
+use crate::dialog::styling::color_picker_dialog::{color_to_hex_string, parse_color_string};
 use crate::dialog::filter_dialog::{FilterDialog, FilterExpr};
 use ratatui::style::Color;
 
@@ -27,11 +27,40 @@ pub enum StyleRuleField {
     FilterExpr,      // For Filter condition
     RegexPattern,    // For Regex condition
     Applications,    // List of StyleApplication
+    // For Gradient and Categorical:
+    SourceColumn,
+    TargetColumns,   // Comma-separated glob patterns
+    ColorTarget,     // Foreground or background
+    // For Gradient:
+    GradientScale,
+    MinColor,
+    MaxColor,
+    Bounds,          // "min, max" or empty for auto
+    // For Categorical:
+    Palette,         // Comma-separated colors
     // Common fields:
     Priority,
     MergeMode,
 }
 
+impl StyleRuleField {
+    /// Whether the field is edited as free text
+    pub fn is_text(&self) -> bool {
+        matches!(
+            self,
+            Self::Name
+                | Self::ConditionColumns
+                | Self::RegexPattern
+                | Self::SourceColumn
+                | Self::TargetColumns
+                | Self::MinColor
+                | Self::MaxColor
+                | Self::Bounds
+                | Self::Palette
+        )
+    }
+}
+
 /// Type of style logic
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LogicTypeSelection {
@@ -121,11 +150,20 @@ pub struct StyleRuleEditorDialog {
     pub applications: Vec<StyleApplication>,
     pub selected_application_index: usize,
     
+    // For Gradient and Categorical logic:
+    pub source_column: String,
+    pub target_columns: String,     // Comma-separated glob patterns
+    pub apply_to_fg: bool,
+
     // For Gradient logic:
     pub gradient_style: GradientStyle,
+    pub min_color: String,
+    pub max_color: String,
+    pub bounds: String,             // "min, max" or empty for auto
     
     // For Categorical logic:
     pub categorical_style: CategoricalStyle,
+    pub palette: String,            // Comma-separated colors
     
     // UI state
     pub focus_field: StyleRuleField,
@@ -168,6 +206,28 @@ impl StyleRuleEditorDialog {
                 }
             };
         
+        let (source_column, target_columns, apply_to_fg) = match logic_type {
+            LogicTypeSelection::Gradient => (
+                gradient_style.source_column.clone(),
+                gradient_style.target_columns.clone().unwrap_or_default().join(", "),
+                gradient_uses_fg(&gradient_style),
+            ),
+            _ => (
+                categorical_style.source_column.clone(),
+                categorical_style.target_columns.clone().unwrap_or_default().join(", "),
+                categorical_style.apply_to_fg,
+            ),
+        };
+        let (min, max) = if apply_to_fg {
+            (gradient_style.min_style.fg, gradient_style.max_style.fg)
+        } else {
+            (gradient_style.min_style.bg, gradient_style.max_style.bg)
+        };
+        let min_color = min.map(|c| color_to_hex_string(&c)).unwrap_or_default();
+        let max_color = max.map(|c| color_to_hex_string(&c)).unwrap_or_default();
+        let bounds = gradient_style.bounds.map(|(lo, hi)| format!("{lo}, {hi}")).unwrap_or_default();
+        let palette = categorical_style.palette.iter().map(color_to_hex_string).collect::<Vec<_>>().join(", ");
+
         let applications = if applications.is_empty() {
             vec![StyleApplication::default()]
         } else {
@@ -185,8 +245,15 @@ impl StyleRuleEditorDialog {
             regex_pattern,
             applications,
             selected_application_index: 0,
+            source_column,
+            target_columns,
+            apply_to_fg,
             gradient_style,
+            min_color,
+            max_color,
+            bounds,
             categorical_style,
+            palette,
             focus_field: StyleRuleField::Name,
             cursor_position: 0,
             selection_start: None,
@@ -231,10 +298,37 @@ impl StyleRuleEditorDialog {
                 })
             }
             LogicTypeSelection::Gradient => {
-                StyleLogic::Gradient(self.gradient_style.clone())
+                let mut gradient = self.gradient_style.clone();
+                gradient.source_column = self.source_column.trim().to_string();
+                gradient.target_columns = split_patterns(&self.target_columns);
+                // Unparseable colors keep the rule's previous color for that end
+                let (old_min, old_max) = if gradient_uses_fg(&gradient) {
+                    (gradient.min_style.fg, gradient.max_style.fg)
+                } else {
+                    (gradient.min_style.bg, gradient.max_style.bg)
+                };
+                let min = parse_color_string(&self.min_color).or(old_min);
+                let max = parse_color_string(&self.max_color).or(old_max);
+                if self.apply_to_fg {
+                    (gradient.min_style.fg, gradient.max_style.fg) = (min, max);
+                    (gradient.min_style.bg, gradient.max_style.bg) = (None, None);
+                } else {
+                    (gradient.min_style.bg, gradient.max_style.bg) = (min, max);
+                    (gradient.min_style.fg, gradient.max_style.fg) = (None, None);
+                }
+                gradient.bounds = parse_bounds(&self.bounds);
+                StyleLogic::Gradient(gradient)
             }
             LogicTypeSelection::Categorical => {
-                StyleLogic::Categorical(self.categorical_style.clone())
+                let mut categorical = self.categorical_style.clone();
+                categorical.source_column = self.source_column.trim().to_string();
+                categorical.target_columns = split_patterns(&self.target_columns);
+                categorical.apply_to_fg = self.apply_to_fg;
+                let palette: Vec<Color> = self.palette.split(',').filter_map(parse_color_string).collect();
+                if !palette.is_empty() {
+                    categorical.palette = palette;
+                }
+                StyleLogic::Categorical(categorical)
             }
         };
         
@@ -257,6 +351,12 @@ impl StyleRuleEditorDialog {
             StyleRuleField::Name => &self.name,
             StyleRuleField::ConditionColumns => &self.condition_columns,
             StyleRuleField::RegexPattern => &self.regex_pattern,
+            StyleRuleField::SourceColumn => &self.source_column,
+            StyleRuleField::TargetColumns => &self.target_columns,
+            StyleRuleField::MinColor => &self.min_color,
+            StyleRuleField::MaxColor => &self.max_color,
+            StyleRuleField::Bounds => &self.bounds,
+            StyleRuleField::Palette => &self.palette,
             _ => "",
         }
     }
@@ -266,6 +366,12 @@ impl StyleRuleEditorDialog {
             StyleRuleField::Name => &mut self.name,
             StyleRuleField::ConditionColumns => &mut self.condition_columns,
             StyleRuleField::RegexPattern => &mut self.regex_pattern,
+            StyleRuleField::SourceColumn => &mut self.source_column,
+            StyleRuleField::TargetColumns => &mut self.target_columns,
+            StyleRuleField::MinColor => &mut self.min_color,
+            StyleRuleField::MaxColor => &mut self.max_color,
+            StyleRuleField::Bounds => &mut self.bounds,
+            StyleRuleField::Palette => &mut self.palette,
             _ => &mut self.name, // Fallback
         }
     }
@@ -283,6 +389,13 @@ impl StyleRuleEditorDialog {
                     StyleRuleField::Applications => "Enter: Edit, +: Add, -: Remove, ←/→: Select",
                     StyleRuleField::Priority => "←/→: Adjust priority",
                     StyleRuleField::MergeMode => "Space: Toggle merge mode",
+                    StyleRuleField::SourceColumn => "Type source column name, Tab: Next column",
+                    StyleRuleField::TargetColumns => "Type glob patterns to style (empty: source column)",
+                    StyleRuleField::ColorTarget => "Space: Toggle foreground/background",
+                    StyleRuleField::GradientScale => "Space: Toggle scale",
+                    StyleRuleField::MinColor | StyleRuleField::MaxColor => "Type color (#RRGGBB, rgb(r,g,b) or name)",
+                    StyleRuleField::Bounds => "Type min, max (empty: auto from data)",
+                    StyleRuleField::Palette => "Type comma-separated colors",
                 };
                 format!(
                     "{}  {}",
@@ -521,23 +634,86 @@ impl StyleRuleEditorDialog {
             y += 1;
         }
 
+        // Fields shared by Gradient and Categorical
+        if self.logic_type != LogicTypeSelection::Conditional {
+            self.render_label(buf, start_x, y, "Source Column:", StyleRuleField::SourceColumn);
+            self.render_text_field(
+                buf, value_x, y,
+                &self.source_column, "(required)",
+                self.focus_field == StyleRuleField::SourceColumn,
+                self.cursor_position
+            );
+            y += 1;
+
+            self.render_label(buf, start_x, y, "Apply To:", StyleRuleField::TargetColumns);
+            self.render_text_field(
+                buf, value_x, y,
+                &self.target_columns, "(source column)",
+                self.focus_field == StyleRuleField::TargetColumns,
+                self.cursor_position
+            );
+            y += 1;
+
+            self.render_label(buf, start_x, y, "Color Target:", StyleRuleField::ColorTarget);
+            let target_str = if self.apply_to_fg { "Foreground" } else { "Background" };
+            let target_indicator = if self.focus_field == StyleRuleField::ColorTarget { "◀ " } else { "  " };
+            let target_display = format!("{}{}", target_indicator, target_str);
+            self.render_value(buf, value_x, y, &target_display, StyleRuleField::ColorTarget);
+            if self.focus_field == StyleRuleField::ColorTarget {
+                buf.set_string(value_x + target_display.len() as u16, y, " ▶", Style::default().fg(Color::Yellow));
+            }
+            y += 1;
+        }
+
         // Gradient-specific fields
         if self.logic_type == LogicTypeSelection::Gradient {
-            buf.set_string(start_x, y, "Source Column:", Style::default().fg(Color::Gray));
-            buf.set_string(value_x, y, &self.gradient_style.source_column, Style::default().fg(Color::Cyan));
+            self.render_label(buf, start_x, y, "Scale:", StyleRuleField::GradientScale);
+            let scale_indicator = if self.focus_field == StyleRuleField::GradientScale { "◀ " } else { "  " };
+            let scale_display = format!("{}{}", scale_indicator, self.gradient_style.scale.display_name());
+            self.render_value(buf, value_x, y, &scale_display, StyleRuleField::GradientScale);
+            if self.focus_field == StyleRuleField::GradientScale {
+                buf.set_string(value_x + scale_display.len() as u16, y, " ▶", Style::default().fg(Color::Yellow));
+            }
             y += 1;
-            buf.set_string(start_x, y, "Scale:", Style::default().fg(Color::Gray));
-            buf.set_string(value_x, y, self.gradient_style.scale.display_name(), Style::default().fg(Color::Cyan));
+
+            for (label, field, text) in [
+                ("Min Color:", StyleRuleField::MinColor, &self.min_color),
+                ("Max Color:", StyleRuleField::MaxColor, &self.max_color),
+            ] {
+                self.render_label(buf, start_x, y, label, field);
+                self.render_text_field(buf, value_x, y, text, "(none)", self.focus_field == field, self.cursor_position);
+                if let Some(color) = parse_color_string(text) {
+                    let swatch_x = value_x + text.chars().count() as u16 + 2;
+                    buf.set_string(swatch_x, y, "    ", Style::default().bg(color));
+                }
+                y += 1;
+            }
+
+            self.render_label(buf, start_x, y, "Bounds:", StyleRuleField::Bounds);
+            self.render_text_field(
+                buf, value_x, y,
+                &self.bounds, "(auto from data)",
+                self.focus_field == StyleRuleField::Bounds,
+                self.cursor_position
+            );
             y += 2;
         }
 
         // Categorical-specific fields
         if self.logic_type == LogicTypeSelection::Categorical {
-            buf.set_string(start_x, y, "Source Column:", Style::default().fg(Color::Gray));
-            buf.set_string(value_x, y, &self.categorical_style.source_column, Style::default().fg(Color::Cyan));
+            self.render_label(buf, start_x, y, "Palette:", StyleRuleField::Palette);
+            self.render_text_field(
+                buf, value_x, y,
+                &self.palette, "(default palette)",
+                self.focus_field == StyleRuleField::Palette,
+                self.cursor_position
+            );
             y += 1;
-            buf.set_string(start_x, y, "Palette:", Style::default().fg(Color::Gray));
-            buf.set_string(value_x, y, &format!("{} colors", self.categorical_style.palette.len()), Style::default().fg(Color::Cyan));
+            let mut swatch_x = value_x;
+            for color in self.palette.split(',').filter_map(parse_color_string) {
+                buf.set_string(swatch_x, y, "  ", Style::default().bg(color));
+                swatch_x += 3;
+            }
             y += 2;
         }
 
@@ -667,6 +843,12 @@ impl StyleRuleEditorDialog {
                         StyleRuleField::ConditionType => {
                             self.condition_type = self.condition_type.prev();
                         }
+                        StyleRuleField::GradientScale => {
+                            self.gradient_style.scale = self.gradient_style.scale.prev();
+                        }
+                        StyleRuleField::ColorTarget => {
+                            self.apply_to_fg = !self.apply_to_fg;
+                        }
                         StyleRuleField::MergeMode => {
                             self.merge_mode = match self.merge_mode {
                                 MergeMode::Override => MergeMode::Additive,
@@ -680,7 +862,7 @@ impl StyleRuleEditorDialog {
                                 self.selected_application_index -= 1;
                             }
                         }
-                        StyleRuleField::Name | StyleRuleField::ConditionColumns | StyleRuleField::RegexPattern => {
+                        field if field.is_text() => {
                             if self.cursor_position > 0 {
                                 self.cursor_position -= 1;
                             }
@@ -700,6 +882,12 @@ impl StyleRuleEditorDialog {
                         StyleRuleField::ConditionType => {
                             self.condition_type = self.condition_type.next();
                         }
+                        StyleRuleField::GradientScale => {
+                            self.gradient_style.scale = self.gradient_style.scale.next();
+                        }
+                        StyleRuleField::ColorTarget => {
+                            self.apply_to_fg = !self.apply_to_fg;
+                        }
                         StyleRuleField::MergeMode => {
                             self.merge_mode = match self.merge_mode {
                                 MergeMode::Override => MergeMode::Merge,
@@ -713,7 +901,7 @@ impl StyleRuleEditorDialog {
                                 self.selected_application_index += 1;
                             }
                         }
-                        StyleRuleField::Name | StyleRuleField::ConditionColumns | StyleRuleField::RegexPattern => {
+                        field if field.is_text() => {
                             let len = self.get_current_text().chars().count();
                             if self.cursor_position < len {
                                 self.cursor_position += 1;
@@ -724,7 +912,7 @@ impl StyleRuleEditorDialog {
                     return None;
                 }
                 Action::Backspace => {
-                    if matches!(self.focus_field, StyleRuleField::Name | StyleRuleField::ConditionColumns | StyleRuleField::RegexPattern) {
+                    if self.focus_field.is_text() {
                         if self.cursor_position > 0 {
                             let pos = self.cursor_position;
                             let text = self.get_current_text_mut();
@@ -735,6 +923,11 @@ impl StyleRuleEditorDialog {
                     }
                     return None;
                 }
+                Action::Tab if self.focus_field == StyleRuleField::SourceColumn => {
+                    self.source_column = self.next_column_name();
+                    self.cursor_position = self.source_column.chars().count();
+                    return None;
+                }
                 Action::ToggleInstructions => {
                     self.show_instructions = !self.show_instructions;
                     return None;
@@ -754,6 +947,14 @@ impl StyleRuleEditorDialog {
                     self.condition_type = self.condition_type.next();
                     return None;
                 }
+                StyleRuleField::GradientScale => {
+                    self.gradient_style.scale = self.gradient_style.scale.next();
+                    return None;
+                }
+                StyleRuleField::ColorTarget => {
+                    self.apply_to_fg = !self.apply_to_fg;
+                    return None;
+                }
                 StyleRuleField::MergeMode => {
                     self.merge_mode = match self.merge_mode {
                         MergeMode::Override => MergeMode::Merge,
@@ -794,7 +995,7 @@ impl StyleRuleEditorDialog {
         }
 
         // Handle character input for text fields
-        if matches!(self.focus_field, StyleRuleField::Name | StyleRuleField::ConditionColumns | StyleRuleField::RegexPattern) {
+        if self.focus_field.is_text() {
             if let KeyCode::Char(c) = key.code {
                 let pos = self.cursor_position;
                 let text = self.get_current_text_mut();
@@ -841,17 +1042,13 @@ impl StyleRuleEditorDialog {
                     StyleRuleField::FilterExpr | StyleRuleField::RegexPattern => StyleRuleField::Applications,
                     StyleRuleField::Applications => StyleRuleField::Priority,
                     StyleRuleField::Priority => StyleRuleField::MergeMode,
-                    StyleRuleField::MergeMode => StyleRuleField::Name,
+                    _ => StyleRuleField::Name,
                 }
             }
             LogicTypeSelection::Gradient | LogicTypeSelection::Categorical => {
-                match self.focus_field {
-                    StyleRuleField::Name => StyleRuleField::LogicType,
-                    StyleRuleField::LogicType => StyleRuleField::Priority,
-                    StyleRuleField::Priority => StyleRuleField::MergeMode,
-                    StyleRuleField::MergeMode => StyleRuleField::Name,
-                    _ => StyleRuleField::Name,
-                }
+                let fields = self.dynamic_fields();
+                let pos = fields.iter().position(|f| *f == self.focus_field).unwrap_or(fields.len() - 1);
+                fields[(pos + 1) % fields.len()]
             }
         }
     }
@@ -873,19 +1070,73 @@ impl StyleRuleEditorDialog {
                     }
                     StyleRuleField::Priority => StyleRuleField::Applications,
                     StyleRuleField::MergeMode => StyleRuleField::Priority,
+                    _ => StyleRuleField::Name,
                 }
             }
             LogicTypeSelection::Gradient | LogicTypeSelection::Categorical => {
-                match self.focus_field {
-                    StyleRuleField::Name => StyleRuleField::MergeMode,
-                    StyleRuleField::LogicType => StyleRuleField::Name,
-                    StyleRuleField::Priority => StyleRuleField::LogicType,
-                    StyleRuleField::MergeMode => StyleRuleField::Priority,
-                    _ => StyleRuleField::Name,
-                }
+                let fields = self.dynamic_fields();
+                let pos = fields.iter().position(|f| *f == self.focus_field).unwrap_or(0);
+                fields[(pos + fields.len() - 1) % fields.len()]
             }
         }
     }
+
+    /// Field order for Gradient and Categorical rules
+    fn dynamic_fields(&self) -> Vec<StyleRuleField> {
+        let mut fields = vec![
+            StyleRuleField::Name,
+            StyleRuleField::LogicType,
+            StyleRuleField::SourceColumn,
+            StyleRuleField::TargetColumns,
+            StyleRuleField::ColorTarget,
+        ];
+        if self.logic_type == LogicTypeSelection::Gradient {
+            fields.extend([
+                StyleRuleField::GradientScale,
+                StyleRuleField::MinColor,
+                StyleRuleField::MaxColor,
+                StyleRuleField::Bounds,
+            ]);
+        } else {
+            fields.push(StyleRuleField::Palette);
+        }
+        fields.extend([StyleRuleField::Priority, StyleRuleField::MergeMode]);
+        fields
+    }
+
+    /// The column after the current source column, for cycling with Tab
+    fn next_column_name(&self) -> String {
+        if self.columns.is_empty() {
+            return self.source_column.clone();
+        }
+        let next = self
+            .columns
+            .iter()
+            .position(|c| *c == self.source_column)
+            .map(|i| (i + 1) % self.columns.len())
+            .unwrap_or(0);
+        self.columns[next].clone()
+    }
+}
+
+/// Whether a gradient colors the foreground (only when it sets no background)
+fn gradient_uses_fg(gradient: &GradientStyle) -> bool {
+    gradient.min_style.bg.is_none()
+        && gradient.max_style.bg.is_none()
+        && (gradient.min_style.fg.is_some() || gradient.max_style.fg.is_some())
+}
+
+fn split_patterns(text: &str) -> Option<Vec<String>> {
+    let patterns: Vec<String> = text.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect();
+    if patterns.is_empty() { None } else { Some(patterns) }
+}
+
+/// Parse "min, max"; anything else means auto-detect from the data
+fn parse_bounds(text: &str) -> Option<(f64, f64)> {
+    let (min, max) = text.split_once(',')?;
+    let min = min.trim().parse::<f64>().ok()?;
+    let max = max.trim().parse::<f64>().ok()?;
+    (min < max).then_some((min, max))
 }
 
 impl Component for StyleRuleEditorDialog {
@@ -903,3 +1154,46 @@ impl Component for StyleRuleEditorDialog {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edited_gradient_round_trips() {
+        let gradient = GradientStyle { source_column: "bytes".to_string(), ..GradientStyle::default() };
+        let mut dialog = StyleRuleEditorDialog::new(StyleRule::gradient(gradient), vec!["bytes".to_string(), "port".to_string()]);
+        assert!(!dialog.apply_to_fg);
+        assert_eq!(dialog.min_color, "#323296");
+
+        dialog.focus_field = StyleRuleField::SourceColumn;
+        dialog.source_column = dialog.next_column_name();
+        dialog.target_columns = "bytes, port".to_string();
+        dialog.apply_to_fg = true;
+        dialog.max_color = "#FF0000".to_string();
+        dialog.bounds = "0, 1000".to_string();
+
+        let StyleLogic::Gradient(g) = dialog.build_style_rule().logic else { panic!("expected gradient") };
+        assert_eq!(g.source_column, "port");
+        assert_eq!(g.target_columns, Some(vec!["bytes".to_string(), "port".to_string()]));
+        assert_eq!(g.min_style.fg, Some(Color::Rgb(50, 50, 150)));
+        assert_eq!(g.max_style.fg, Some(Color::Rgb(255, 0, 0)));
+        assert_eq!(g.max_style.bg, None);
+        assert_eq!(g.bounds, Some((0.0, 1000.0)));
+    }
+
+    #[test]
+    fn test_edited_categorical_palette() {
+        let mut dialog = StyleRuleEditorDialog::new(StyleRule::categorical(CategoricalStyle::default()), Vec::new());
+        assert_eq!(dialog.logic_type, LogicTypeSelection::Categorical);
+        dialog.source_column = "severity".to_string();
+        dialog.apply_to_fg = false;
+        dialog.palette = "#FF0000, bogus, #00FF00".to_string();
+
+        let StyleLogic::Categorical(c) = dialog.build_style_rule().logic else { panic!("expected categorical") };
+        assert_eq!(c.source_column, "severity");
+        assert!(!c.apply_to_fg);
+        assert_eq!(c.palette, vec![Color::Rgb(255, 0, 0), Color::Rgb(0, 255, 0)]);
+        assert_eq!(c.target_columns, None);
+    }
+}
@@ -238,6 +238,14 @@ impl GradientScale {
             Self::Percentile => Self::Linear,
         }
     }
+
+    pub fn prev(&self) -> Self {
+        match self {
+            Self::Linear => Self::Percentile,
+            Self::Logarithmic => Self::Linear,
+            Self::Percentile => Self::Logarithmic,
+        }
+    }
     
     pub fn display_name(&self) -> &'static str {
         match self {
@@ -92,7 +92,7 @@ impl StyleSetBrowserDialog {
         if self.file_browser.is_none() {
             let mut browser = FileBrowserDialog::new(
                 None,
-                Some(vec!["yaml", "yml"]),
+                Some(vec!["yaml", "yml", "json"]),
                 true, // folder_only
                 FileBrowserMode::Load,
             );
@@ -1,5 +1,5 @@
 //! StyleSetManager: Manages loading, saving, and enabling/disabling StyleSets
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::fs;
 use color_eyre::Result;
@@ -12,22 +12,32 @@ pub struct StyleSetManager {
     /// All loaded style sets, keyed by their identifier (name or path)
     style_sets: BTreeMap<String, StyleSet>,
     /// Set of enabled style set identifiers
-    enabled_sets: std::collections::HashSet<String>,
+    enabled_sets: HashSet<String>,
+    /// Tabs (by dataset id) with their own enabled sets instead of the global ones
+    tab_enabled_sets: HashMap<String, HashSet<String>>,
+    /// Tab whose enabled sets enable/disable/is_enabled act on, if it has its own
+    active_tab: Option<String>,
     /// Folders that have been loaded
     loaded_folders: Vec<PathBuf>,
 }
 
+fn is_json_path(path: &Path) -> bool {
+    path.extension().and_then(|s| s.to_str()).is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+}
+
 impl StyleSetManager {
     /// Create a new StyleSetManager
     pub fn new() -> Self {
         Self {
             style_sets: BTreeMap::new(),
-            enabled_sets: std::collections::HashSet::new(),
+            enabled_sets: HashSet::new(),
+            tab_enabled_sets: HashMap::new(),
+            active_tab: None,
             loaded_folders: Vec::new(),
         }
     }
 
-    /// Load all YAML style set files from a folder
+    /// Load all YAML and JSON style set files from a folder
     pub fn load_from_folder(&mut self, folder_path: &Path) -> Result<Vec<String>> {
         let mut loaded_names = Vec::new();
         
@@ -35,7 +45,7 @@ impl StyleSetManager {
             return Err(color_eyre::eyre::eyre!("Path is not a directory: {}", folder_path.display()));
         }
 
-        // Read all .yaml and .yml files in the folder
+        // Read all .yaml, .yml and .json files in the folder
         for entry in fs::read_dir(folder_path)? {
             let entry = entry?;
             let path = entry.path();
@@ -45,7 +55,7 @@ impl StyleSetManager {
                     .and_then(|s| s.to_str())
                     .unwrap_or("");
                 
-                if ext == "yaml" || ext == "yml" {
+                if ext == "yaml" || ext == "yml" || ext == "json" {
                     match self.load_from_file(&path) {
                         Ok(name) => {
                             loaded_names.push(name);
@@ -66,23 +76,14 @@ impl StyleSetManager {
         Ok(loaded_names)
     }
 
-    /// Load a single style set from a YAML file
+    /// Load a single style set from a YAML or JSON file (by extension)
     pub fn load_from_file(&mut self, file_path: &Path) -> Result<String> {
         let content = fs::read_to_string(file_path)?;
-        // First try direct YAML deserialization (supports legacy `!Variant` tags).
-        // If that fails, fall back to YAML Value -> JSON Value -> StyleSet to accept
-        // tag-free, externally tagged maps (e.g., `logic: { Conditional: {...} }`).
-        let style_set: StyleSet = match serde_yaml::from_str(&content) {
-            Ok(s) => s,
-            Err(e) => {
-                // Fallback path
-                let yaml_val: serde_yaml::Value = serde_yaml::from_str(&content)
-                    .map_err(|e2| color_eyre::eyre::eyre!("Failed to parse YAML: {}", e2))?;
-                let json_val = serde_json::to_value(yaml_val)
-                    .map_err(|e2| color_eyre::eyre::eyre!("Failed to convert YAML to JSON value: {}", e2))?;
-                serde_json::from_value(json_val)
-                    .map_err(|e2| color_eyre::eyre::eyre!("Failed to parse StyleSet from JSON value (original YAML parse error: {e}): {e2}"))?
-            }
+        let style_set = if is_json_path(file_path) {
+            serde_json::from_str(&content)
+                .map_err(|e| color_eyre::eyre::eyre!("Failed to parse StyleSet JSON: {}", e))?
+        } else {
+            Self::parse_yaml(&content)?
         };
         
         // Use name as identifier, or file name if name is empty
@@ -99,13 +100,39 @@ impl StyleSetManager {
         Ok(identifier)
     }
 
-    /// Save a style set to a YAML file
+    fn parse_yaml(content: &str) -> Result<StyleSet> {
+        // First try direct YAML deserialization (supports legacy `!Variant` tags).
+        // If that fails, fall back to YAML Value -> JSON Value -> StyleSet to accept
+        // tag-free, externally tagged maps (e.g., `logic: { Conditional: {...} }`).
+        let style_set: StyleSet = match serde_yaml::from_str(content) {
+            Ok(s) => s,
+            Err(e) => {
+                // Fallback path
+                let yaml_val: serde_yaml::Value = serde_yaml::from_str(content)
+                    .map_err(|e2| color_eyre::eyre::eyre!("Failed to parse YAML: {}", e2))?;
+                let json_val = serde_json::to_value(yaml_val)
+                    .map_err(|e2| color_eyre::eyre::eyre!("Failed to convert YAML to JSON value: {}", e2))?;
+                serde_json::from_value(json_val)
+                    .map_err(|e2| color_eyre::eyre::eyre!("Failed to parse StyleSet from JSON value (original YAML parse error: {e}): {e2}"))?
+            }
+        };
+        Ok(style_set)
+    }
+
+    /// Save a style set to a file: JSON for a `.json` path, YAML otherwise
     pub fn save_to_file(&self, style_set: &StyleSet, file_path: &Path) -> Result<()> {
         // Ensure parent directory exists
         if let Some(parent) = file_path.parent() {
             fs::create_dir_all(parent)?;
         }
 
+        if is_json_path(file_path) {
+            let json = serde_json::to_string_pretty(style_set)
+                .map_err(|e| color_eyre::eyre::eyre!("Failed to serialize StyleSet to JSON: {}", e))?;
+            fs::write(file_path, json)?;
+            return Ok(());
+        }
+
         // Serialize without YAML enum tags by round-tripping through JSON value
         let json_value = serde_json::to_value(style_set)
             .map_err(|e| color_eyre::eyre::eyre!("Failed to serialize StyleSet to JSON value: {}", e))?;
@@ -119,7 +146,7 @@ impl StyleSetManager {
     /// Enable a style set by identifier
     pub fn enable_style_set(&mut self, identifier: &str) -> bool {
         if self.style_sets.contains_key(identifier) {
-            self.enabled_sets.insert(identifier.to_string());
+            self.current_enabled_mut().insert(identifier.to_string());
             true
         } else {
             false
@@ -128,17 +155,17 @@ impl StyleSetManager {
 
     /// Disable a style set by identifier
     pub fn disable_style_set(&mut self, identifier: &str) {
-        self.enabled_sets.remove(identifier);
+        self.current_enabled_mut().remove(identifier);
     }
 
     /// Check if a style set is enabled
     pub fn is_enabled(&self, identifier: &str) -> bool {
-        self.enabled_sets.contains(identifier)
+        self.current_enabled().contains(identifier)
     }
 
     /// Get all enabled style sets
     pub fn get_enabled_sets(&self) -> Vec<&StyleSet> {
-        self.enabled_sets.iter()
+        self.current_enabled().iter()
             .filter_map(|id| self.style_sets.get(id))
             .collect()
     }
@@ -146,7 +173,7 @@ impl StyleSetManager {
     /// Get all style sets (enabled and disabled)
     pub fn get_all_sets(&self) -> Vec<(&String, &StyleSet, bool)> {
         self.style_sets.iter()
-            .map(|(id, set)| (id, set, self.enabled_sets.contains(id)))
+            .map(|(id, set)| (id, set, self.current_enabled().contains(id)))
             .collect()
     }
 
@@ -169,6 +196,9 @@ impl StyleSetManager {
     /// Remove a style set
     pub fn remove_set(&mut self, identifier: &str) -> bool {
         self.enabled_sets.remove(identifier);
+        for enabled in self.tab_enabled_sets.values_mut() {
+            enabled.remove(identifier);
+        }
         self.style_sets.remove(identifier).is_some()
     }
 
@@ -181,6 +211,7 @@ impl StyleSetManager {
     pub fn clear(&mut self) {
         self.style_sets.clear();
         self.enabled_sets.clear();
+        self.tab_enabled_sets.clear();
         self.loaded_folders.clear();
     }
 
@@ -199,6 +230,72 @@ impl StyleSetManager {
         }
     }
     
+    fn current_enabled(&self) -> &HashSet<String> {
+        self.active_tab
+            .as_ref()
+            .and_then(|tab| self.tab_enabled_sets.get(tab))
+            .unwrap_or(&self.enabled_sets)
+    }
+
+    fn current_enabled_mut(&mut self) -> &mut HashSet<String> {
+        match self.active_tab.as_ref() {
+            Some(tab) if self.tab_enabled_sets.contains_key(tab) => self.tab_enabled_sets.get_mut(tab).unwrap(),
+            _ => &mut self.enabled_sets,
+        }
+    }
+
+    /// Select the tab whose style sets are being edited (None for the global sets)
+    pub fn set_active_tab(&mut self, tab_id: Option<String>) {
+        self.active_tab = tab_id;
+    }
+
+    /// Whether the active tab has its own enabled sets
+    pub fn has_tab_override(&self) -> bool {
+        self.active_tab.as_ref().is_some_and(|tab| self.tab_enabled_sets.contains_key(tab))
+    }
+
+    /// Give the active tab its own copy of the enabled sets, or drop it to follow the global
+    /// sets again. Returns whether the active tab now has its own sets.
+    pub fn toggle_tab_override(&mut self) -> bool {
+        let Some(tab) = self.active_tab.clone() else {
+            return false;
+        };
+        if self.tab_enabled_sets.remove(&tab).is_some() {
+            false
+        } else {
+            self.tab_enabled_sets.insert(tab, self.enabled_sets.clone());
+            true
+        }
+    }
+
+    /// Enabled style sets for a tab: its own if it has them, otherwise the global ones
+    pub fn get_enabled_sets_for_tab(&self, tab_id: &str) -> Vec<&StyleSet> {
+        self.tab_enabled_sets
+            .get(tab_id)
+            .unwrap_or(&self.enabled_sets)
+            .iter()
+            .filter_map(|id| self.style_sets.get(id))
+            .collect()
+    }
+
+    /// The tab's own enabled set identifiers (for serialization); None if it follows the global sets
+    pub fn get_tab_enabled_identifiers(&self, tab_id: &str) -> Option<Vec<String>> {
+        self.tab_enabled_sets.get(tab_id).map(|ids| ids.iter().cloned().collect())
+    }
+
+    /// Restore a tab's own enabled sets (for deserialization); None makes it follow the global sets
+    pub fn set_tab_enabled_identifiers(&mut self, tab_id: &str, identifiers: Option<Vec<String>>) {
+        match identifiers {
+            Some(ids) => {
+                let ids = ids.into_iter().filter(|id| self.style_sets.contains_key(id)).collect();
+                self.tab_enabled_sets.insert(tab_id.to_string(), ids);
+            }
+            None => {
+                self.tab_enabled_sets.remove(tab_id);
+            }
+        }
+    }
+
     /// Find StyleSets that match the given column names based on schema hints
     /// Returns a list of (identifier, StyleSet, confidence_score) sorted by confidence
     pub fn find_matching_sets(&self, columns: &[String]) -> Vec<(&String, &StyleSet, f32)> {
@@ -228,7 +325,7 @@ impl StyleSetManager {
     pub fn get_suggestions(&self, columns: &[String]) -> Vec<(&String, &StyleSet, f32)> {
         self.find_matching_sets(columns)
             .into_iter()
-            .filter(|(id, _, _)| !self.enabled_sets.contains(*id))
+            .filter(|(id, _, _)| !self.current_enabled().contains(*id))
             .collect()
     }
     
@@ -237,7 +334,7 @@ impl StyleSetManager {
         let matching: Vec<String> = self.style_sets
             .iter()
             .filter_map(|(id, set)| {
-                if self.enabled_sets.contains(id) {
+                if self.current_enabled().contains(id) {
                     return None; // Already enabled
                 }
                 if let Some(ref hint) = set.schema_hint {
@@ -254,7 +351,7 @@ impl StyleSetManager {
             .collect();
         
         for id in &matching {
-            self.enabled_sets.insert(id.clone());
+            self.current_enabled_mut().insert(id.clone());
         }
         
         matching
@@ -268,3 +365,52 @@ impl Default for StyleSetManager {
 }
 
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialog::styling::templates::{create_template_styleset, TemplateCategory};
+    use uuid::Uuid;
+
+    #[test]
+    fn test_json_export_round_trips() {
+        let dir = std::env::temp_dir().join(format!("datatui_styles_{}", Uuid::new_v4()));
+        let style_set = create_template_styleset(TemplateCategory::Severity);
+        let manager = StyleSetManager::new();
+        let path = dir.join("severity.json");
+        manager.save_to_file(&style_set, &path).unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(serde_json::from_str::<serde_json::Value>(&content).is_ok());
+
+        let mut loaded = StyleSetManager::new();
+        let ids = loaded.load_from_folder(&dir).unwrap();
+        assert_eq!(ids, vec!["Severity Levels".to_string()]);
+        assert_eq!(loaded.get_set("Severity Levels"), Some(&style_set));
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_tab_override_keeps_its_own_enabled_sets() {
+        let mut manager = StyleSetManager::new();
+        let severity = manager.add_set(create_template_styleset(TemplateCategory::Severity));
+        let ioc = manager.add_set(create_template_styleset(TemplateCategory::Ioc));
+        manager.enable_style_set(&severity);
+
+        manager.set_active_tab(Some("tab-a".to_string()));
+        assert!(manager.toggle_tab_override());
+        manager.disable_style_set(&severity);
+        manager.enable_style_set(&ioc);
+        assert!(manager.is_enabled(&ioc));
+
+        // Other tabs and the global sets are untouched
+        assert_eq!(manager.get_enabled_identifiers(), vec![severity.clone()]);
+        assert_eq!(manager.get_enabled_sets_for_tab("tab-b")[0].name, "Severity Levels");
+        assert_eq!(manager.get_enabled_sets_for_tab("tab-a")[0].name, "IOC Highlighting");
+        assert_eq!(manager.get_tab_enabled_identifiers("tab-a"), Some(vec![ioc.clone()]));
+
+        // Dropping the override follows the global sets again
+        assert!(!manager.toggle_tab_override());
+        assert!(manager.is_enabled(&severity));
+        assert_eq!(manager.get_tab_enabled_identifiers("tab-a"), None);
+    }
+}
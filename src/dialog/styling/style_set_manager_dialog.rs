@@ -11,6 +11,7 @@ use crate::components::dialog_layout::split_dialog_area;
 use crate::dialog::styling::style_set_manager::StyleSetManager;
 use crate::dialog::styling::style_set::StyleSet;
 use crate::dialog::styling::style_set_editor_dialog::StyleSetEditorDialog;
+use crate::dialog::styling::templates::{create_template_styleset, get_template_categories};
 use crate::dialog::file_browser_dialog::{FileBrowserDialog, FileBrowserAction, FileBrowserMode};
 use tracing::error;
 use std::collections::{BTreeMap, BTreeSet};
@@ -38,6 +39,8 @@ pub enum StyleSetManagerDialogMode {
     List,
     FileBrowser(Box<FileBrowserDialog>),
     StyleSetEditor(Box<StyleSetEditorDialog>),
+    /// Browsing built-in templates; Enter opens a copy of the selected one in the editor
+    TemplateGallery,
 }
 
 /// StyleSetManagerDialog: UI for managing style sets with tree/table split view
@@ -61,6 +64,7 @@ pub struct StyleSetManagerDialog {
     pub export_selected_id: Option<String>,
     pub editor_style_set_id: Option<String>,
     pub columns: Vec<String>,
+    pub selected_template_index: usize,
 }
 
 impl StyleSetManagerDialog {
@@ -85,6 +89,7 @@ impl StyleSetManagerDialog {
             export_selected_id: None,
             editor_style_set_id: None,
             columns: vec![],
+            selected_template_index: 0,
         };
         dialog.rebuild_category_tree();
         dialog
@@ -255,7 +260,8 @@ impl StyleSetManagerDialog {
         let all_sets = self.style_set_manager.get_all_sets();
         let enabled_count = all_sets.iter().filter(|(_, _, enabled)| *enabled).count();
         let total_count = all_sets.len();
-        format!("{}/{} style sets enabled", enabled_count, total_count)
+        let scope = if self.style_set_manager.has_tab_override() { "this tab" } else { "all tabs" };
+        format!("{}/{} style sets enabled ({})", enabled_count, total_count, scope)
     }
 
     /// Build instructions string from configured keybindings
@@ -279,6 +285,8 @@ impl StyleSetManagerDialog {
                         (Mode::StyleSetManagerDialog, Action::DisableStyleSet),
                         (Mode::StyleSetManagerDialog, Action::ImportStyleSet),
                         (Mode::StyleSetManagerDialog, Action::ExportStyleSet),
+                        (Mode::StyleSetManagerDialog, Action::OpenTemplateGallery),
+                        (Mode::StyleSetManagerDialog, Action::ToggleStyleSetTabScope),
                         (Mode::StyleSetManagerDialog, Action::ToggleCategoryPanel),
                         (Mode::Global, Action::ToggleInstructions),
                     ])
@@ -290,6 +298,16 @@ impl StyleSetManagerDialog {
             StyleSetManagerDialogMode::StyleSetEditor(_) => {
                 "Style Set Editor".to_string()
             }
+            StyleSetManagerDialogMode::TemplateGallery => {
+                format!(
+                    "Enter: Customize and add  {}",
+                    self.config.actions_to_instructions(&[
+                        (Mode::Global, Action::Up),
+                        (Mode::Global, Action::Down),
+                        (Mode::Global, Action::Escape),
+                    ])
+                )
+            }
         }
     }
 
@@ -305,6 +323,10 @@ impl StyleSetManagerDialog {
                 editor.render(area, buf);
                 return 0;
             }
+            StyleSetManagerDialogMode::TemplateGallery => {
+                self.render_template_gallery(area, buf);
+                return 0;
+            }
             StyleSetManagerDialogMode::List => {}
         }
 
@@ -408,6 +430,77 @@ impl StyleSetManagerDialog {
         0
     }
 
+    /// Render the template gallery: template list on the left, preview of the selected one on the right
+    fn render_template_gallery(&self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let outer_block = Block::default()
+            .title("Style Set Templates")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let content_area = layout.content_area;
+
+        let categories = get_template_categories();
+        let left_width = content_area.width * 35 / 100;
+        let left_area = Rect { width: left_width, ..content_area };
+        let right_area = Rect {
+            x: content_area.x + left_width + 1,
+            width: content_area.width.saturating_sub(left_width + 1),
+            ..content_area
+        };
+
+        let block = Block::default()
+            .title("Templates")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+        let list_inner = block.inner(left_area);
+        block.render(left_area, buf);
+        let max_visible = list_inner.height as usize;
+        let scroll_offset = self.selected_template_index.saturating_sub(max_visible.saturating_sub(1));
+        for (vis_idx, (i, category)) in categories.iter().enumerate().skip(scroll_offset).take(max_visible).enumerate() {
+            let style = if i == self.selected_template_index {
+                Style::default().fg(Color::Black).bg(Color::Cyan).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            let line: String = category.name().chars().take(list_inner.width as usize).collect();
+            buf.set_string(list_inner.x, list_inner.y + vis_idx as u16, line, style);
+        }
+
+        let preview_block = Block::default().title("Preview").borders(Borders::ALL);
+        let preview_inner = preview_block.inner(right_area);
+        preview_block.render(right_area, buf);
+        if let Some(category) = categories.get(self.selected_template_index) {
+            let template = create_template_styleset(*category);
+            let mut lines = vec![
+                Line::from(Span::styled(template.name.clone(), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))),
+                Line::from(category.description()),
+                Line::from(""),
+                Line::from(Span::styled(format!("Rules ({}):", template.rules.len()), Style::default().fg(Color::Gray))),
+            ];
+            for rule in &template.rules {
+                let name = rule.name.clone().unwrap_or_else(|| "(unnamed)".to_string());
+                lines.push(Line::from(format!("  {} [{}]", name, rule.logic.display_name())));
+            }
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .render(preview_inner, buf);
+        }
+
+        if let Some(instr_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instr_area, buf);
+        }
+    }
+
     /// Render the category tree
     fn render_category_tree(&self, area: Rect, buf: &mut Buffer) {
         let is_focused = self.focus == StyleSetManagerFocus::CategoryTree;
@@ -601,6 +694,38 @@ impl StyleSetManagerDialog {
             return None;
         }
 
+        // Handle TemplateGallery mode
+        if let StyleSetManagerDialogMode::TemplateGallery = self.mode {
+            let categories = get_template_categories();
+            match self.config.action_for_key(Mode::Global, key) {
+                Some(Action::Escape) => {
+                    self.mode = StyleSetManagerDialogMode::List;
+                }
+                Some(Action::Up) => {
+                    self.selected_template_index = self.selected_template_index.saturating_sub(1);
+                }
+                Some(Action::Down) => {
+                    if self.selected_template_index < categories.len().saturating_sub(1) {
+                        self.selected_template_index += 1;
+                    }
+                }
+                Some(Action::Enter) => {
+                    // Open a copy in the editor; it is added as a new style set once saved
+                    if let Some(category) = categories.get(self.selected_template_index) {
+                        let mut editor = StyleSetEditorDialog::new(create_template_styleset(*category), self.columns.clone());
+                        let _ = editor.register_config_handler(self.config.clone());
+                        self.editor_style_set_id = None;
+                        self.mode = StyleSetManagerDialogMode::StyleSetEditor(Box::new(editor));
+                    }
+                }
+                Some(Action::ToggleInstructions) => {
+                    self.show_instructions = !self.show_instructions;
+                }
+                _ => {}
+            }
+            return None;
+        }
+
         // Handle FileBrowser mode
         if let StyleSetManagerDialogMode::FileBrowser(browser) = &mut self.mode {
             if let Some(action) = browser.handle_key_event(key) {
@@ -852,10 +977,18 @@ impl StyleSetManagerDialog {
                     }
                     return None;
                 }
+                Action::OpenTemplateGallery => {
+                    self.mode = StyleSetManagerDialogMode::TemplateGallery;
+                    return None;
+                }
+                Action::ToggleStyleSetTabScope => {
+                    self.style_set_manager.toggle_tab_override();
+                    return None;
+                }
                 Action::ImportStyleSet => {
                     let mut browser = FileBrowserDialog::new(
                         None,
-                        Some(vec!["yaml", "yml"]),
+                        Some(vec!["yaml", "yml", "json"]),
                         false,
                         FileBrowserMode::Load,
                     );
@@ -865,15 +998,18 @@ impl StyleSetManagerDialog {
                 }
                 Action::ExportStyleSet => {
                     let filtered_sets = self.get_filtered_sets();
-                    if let Some((id, _, _)) = filtered_sets.get(self.selected_table_index) {
+                    if let Some((id, set, _)) = filtered_sets.get(self.selected_table_index) {
                         self.export_selected_id = Some(id.clone());
                         let mut browser = FileBrowserDialog::new(
                             None,
-                            Some(vec!["yaml", "yml"]),
+                            Some(vec!["yaml", "yml", "json"]),
                             false,
                             FileBrowserMode::Save,
                         );
                         browser.register_config_handler(self.config.clone());
+                        // Suggest a shareable JSON file; a .yaml name saves as YAML instead
+                        browser.filename_input = export_file_name(&set.name);
+                        browser.filename_cursor = browser.filename_input.len();
                         self.mode = StyleSetManagerDialogMode::FileBrowser(Box::new(browser));
                     }
                     return None;
@@ -910,6 +1046,18 @@ impl StyleSetManagerDialog {
     }
 }
 
+/// Default export file name for a style set, e.g. "Severity Levels" -> "severity_levels.json"
+fn export_file_name(name: &str) -> String {
+    let stem: String = name
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' { c } else { '_' })
+        .collect();
+    let stem = if stem.is_empty() { "style_set".to_string() } else { stem };
+    format!("{stem}.json")
+}
+
 impl Component for StyleSetManagerDialog {
     fn register_config_handler(&mut self, config: Config) -> Result<()> {
         self.config = config;
@@ -925,3 +1073,32 @@ impl Component for StyleSetManagerDialog {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    #[test]
+    fn test_template_gallery_adds_customized_copy() {
+        let mut dialog = StyleSetManagerDialog::new(StyleSetManager::new());
+        dialog.config.reset_keybindings_to_default();
+        let press = |code| KeyEvent::new(code, KeyModifiers::NONE);
+
+        dialog.handle_key_event_pub(KeyEvent::new(KeyCode::Char('g'), KeyModifiers::CONTROL));
+        assert!(matches!(dialog.mode, StyleSetManagerDialogMode::TemplateGallery));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 100, 30));
+        dialog.render(Rect::new(0, 0, 100, 30), &mut buf);
+
+        dialog.handle_key_event_pub(press(KeyCode::Down));
+        dialog.handle_key_event_pub(press(KeyCode::Enter));
+        let StyleSetManagerDialogMode::StyleSetEditor(editor) = &dialog.mode else { panic!("expected editor") };
+        let template = editor.build_style_set();
+        assert_eq!(template.name, "Severity Levels");
+
+        // Saving from the editor adds the template as a new, enabled style set
+        dialog.handle_key_event_pub(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+        assert!(matches!(dialog.mode, StyleSetManagerDialogMode::List));
+        assert!(dialog.style_set_manager.is_enabled("Severity Levels"));
+    }
+}
@@ -2,7 +2,7 @@
 use crate::dialog::styling::style_set::{
     StyleSet, StyleRule, MatchedStyle, MergeMode,
     ApplicationScope, StyleApplication, Condition, ConditionalStyle, StyleLogic,
-    SchemaHint, ColumnMatcher, GradientStyle, GradientScale, CategoricalStyle,
+    SchemaHint, ColumnMatcher, GradientStyle, GradientScale, CategoricalStyle, GrepCapture,
 };
 use crate::dialog::filter_dialog::{FilterExpr, FilterCondition, ColumnFilter, CompareOp};
 use ratatui::style::{Color, Modifier};
@@ -11,36 +11,48 @@ use ratatui::style::{Color, Modifier};
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TemplateCategory {
     Errors,
+    Severity,
     Status,
+    HttpStatus,
+    Boolean,
     Nulls,
     Numeric,
     Validation,
     Gradient,
     Categorical,
+    Ioc,
 }
 
 impl TemplateCategory {
     pub fn name(&self) -> &'static str {
         match self {
             Self::Errors => "Error Highlighting",
+            Self::Severity => "Severity Levels",
             Self::Status => "Status Colors",
+            Self::HttpStatus => "HTTP Status Classes",
+            Self::Boolean => "Boolean Flags",
             Self::Nulls => "Null/Empty Highlighting",
             Self::Numeric => "Numeric Visualization",
             Self::Validation => "Data Validation",
             Self::Gradient => "Gradient Heatmap",
             Self::Categorical => "Category Colors",
+            Self::Ioc => "IOC Highlighting",
         }
     }
     
     pub fn description(&self) -> &'static str {
         match self {
             Self::Errors => "Highlight error, fail, and exception values",
+            Self::Severity => "Color whole rows by severity/level (critical, high, medium, low, info)",
             Self::Status => "Color code status columns (success/warning/error)",
+            Self::HttpStatus => "Color HTTP status codes by class (2xx, 3xx, 4xx, 5xx)",
+            Self::Boolean => "Color true/false, yes/no and 1/0 flag columns",
             Self::Nulls => "Highlight null, empty, and missing values",
             Self::Numeric => "Visual indicators for numeric ranges",
             Self::Validation => "Highlight potentially invalid data",
             Self::Gradient => "Color gradient based on numeric values (low=blue, high=red)",
             Self::Categorical => "Auto-assign colors to unique category values",
+            Self::Ioc => "Highlight IPs, hashes, domains, URLs and emails inside any text",
         }
     }
 }
@@ -49,12 +61,16 @@ impl TemplateCategory {
 pub fn get_template_categories() -> Vec<TemplateCategory> {
     vec![
         TemplateCategory::Errors,
+        TemplateCategory::Severity,
         TemplateCategory::Status,
+        TemplateCategory::HttpStatus,
+        TemplateCategory::Boolean,
         TemplateCategory::Nulls,
         TemplateCategory::Numeric,
         TemplateCategory::Validation,
         TemplateCategory::Gradient,
         TemplateCategory::Categorical,
+        TemplateCategory::Ioc,
     ]
 }
 
@@ -62,12 +78,16 @@ pub fn get_template_categories() -> Vec<TemplateCategory> {
 pub fn create_template_styleset(category: TemplateCategory) -> StyleSet {
     match category {
         TemplateCategory::Errors => create_error_template(),
+        TemplateCategory::Severity => create_severity_template(),
         TemplateCategory::Status => create_status_template(),
+        TemplateCategory::HttpStatus => create_http_status_template(),
+        TemplateCategory::Boolean => create_boolean_template(),
         TemplateCategory::Nulls => create_null_template(),
         TemplateCategory::Numeric => create_numeric_template(),
         TemplateCategory::Validation => create_validation_template(),
         TemplateCategory::Gradient => create_gradient_template(),
         TemplateCategory::Categorical => create_categorical_template(),
+        TemplateCategory::Ioc => create_ioc_template(),
     }
 }
 
@@ -136,6 +156,186 @@ fn create_error_template() -> StyleSet {
     }
 }
 
+/// Condition matching any of `values` (case-insensitive) in the given columns
+fn in_list_condition(values: &[&str], columns: &[&str]) -> Condition {
+    Condition::Filter {
+        expr: FilterExpr::Condition(ColumnFilter {
+            column: "*".to_string(),
+            condition: FilterCondition::InList {
+                values: values.iter().map(|v| v.to_string()).collect(),
+                case_sensitive: false,
+            },
+        }),
+        columns: Some(columns.iter().map(|c| c.to_string()).collect()),
+    }
+}
+
+/// Create the Severity Levels template
+fn create_severity_template() -> StyleSet {
+    let severity_columns = ["*severity*", "*level*", "*priority*"];
+    let levels: [(&str, &[&str], MatchedStyle, i32); 5] = [
+        (
+            "Critical",
+            &["critical", "crit", "fatal", "emergency", "emerg", "alert"],
+            MatchedStyle { fg: Some(Color::White), bg: Some(Color::Rgb(139, 0, 0)), modifiers: Some(vec![Modifier::BOLD]) },
+            20,
+        ),
+        (
+            "High",
+            &["high", "error", "err"],
+            MatchedStyle { fg: Some(Color::Rgb(255, 110, 90)), bg: None, modifiers: Some(vec![Modifier::BOLD]) },
+            15,
+        ),
+        (
+            "Medium",
+            &["medium", "moderate", "warning", "warn"],
+            MatchedStyle { fg: Some(Color::Rgb(255, 200, 0)), bg: None, modifiers: None },
+            10,
+        ),
+        (
+            "Low",
+            &["low", "notice"],
+            MatchedStyle { fg: Some(Color::Rgb(100, 180, 255)), bg: None, modifiers: None },
+            5,
+        ),
+        (
+            "Informational",
+            &["info", "informational", "debug", "trace"],
+            MatchedStyle { fg: Some(Color::DarkGray), bg: None, modifiers: None },
+            0,
+        ),
+    ];
+
+    let rules = levels
+        .into_iter()
+        .map(|(name, values, style, priority)| {
+            conditional_rule(
+                &format!("{} severity", name),
+                in_list_condition(values, &severity_columns),
+                ApplicationScope::Row,
+                style,
+                priority,
+            )
+        })
+        .collect();
+
+    StyleSet {
+        id: "template-severity".to_string(),
+        name: "Severity Levels".to_string(),
+        categories: Some(vec!["Templates".to_string(), "Severity".to_string()]),
+        tags: Some(vec!["severity".to_string(), "level".to_string(), "priority".to_string(), "log".to_string()]),
+        description: "Colors whole rows by their severity or log level, critical rows in red".to_string(),
+        yaml_path: None,
+        rules,
+        schema_hint: Some(SchemaHint {
+            required_columns: vec![],
+            optional_columns: severity_columns.iter().map(|c| ColumnMatcher::Pattern(c.to_string())).collect(),
+            min_confidence: 0.3,
+        }),
+    }
+}
+
+/// Create the HTTP Status Classes template
+fn create_http_status_template() -> StyleSet {
+    let status_columns = ["*status*", "*code*", "sc-status", "cs-status"];
+    let classes: [(&str, &str, MatchedStyle, i32); 4] = [
+        (
+            "2xx Success",
+            r"^2\d\d$",
+            MatchedStyle { fg: Some(Color::Rgb(0, 200, 0)), bg: None, modifiers: None },
+            5,
+        ),
+        (
+            "3xx Redirect",
+            r"^3\d\d$",
+            MatchedStyle { fg: Some(Color::Rgb(0, 188, 212)), bg: None, modifiers: None },
+            5,
+        ),
+        (
+            "4xx Client Error",
+            r"^4\d\d$",
+            MatchedStyle { fg: Some(Color::Rgb(255, 200, 0)), bg: None, modifiers: Some(vec![Modifier::BOLD]) },
+            5,
+        ),
+        (
+            "5xx Server Error",
+            r"^5\d\d$",
+            MatchedStyle { fg: Some(Color::White), bg: Some(Color::Rgb(139, 0, 0)), modifiers: Some(vec![Modifier::BOLD]) },
+            10,
+        ),
+    ];
+
+    let rules = classes
+        .into_iter()
+        .map(|(name, pattern, style, priority)| {
+            conditional_rule(
+                name,
+                Condition::Filter {
+                    expr: FilterExpr::Condition(ColumnFilter {
+                        column: "*".to_string(),
+                        condition: FilterCondition::Regex { pattern: pattern.to_string(), case_sensitive: true },
+                    }),
+                    columns: Some(status_columns.iter().map(|c| c.to_string()).collect()),
+                },
+                ApplicationScope::Cell,
+                style,
+                priority,
+            )
+        })
+        .collect();
+
+    StyleSet {
+        id: "template-http-status".to_string(),
+        name: "HTTP Status Classes".to_string(),
+        categories: Some(vec!["Templates".to_string(), "Web".to_string()]),
+        tags: Some(vec!["http".to_string(), "status".to_string(), "web".to_string(), "access-log".to_string()]),
+        description: "Colors HTTP status codes: 2xx green, 3xx cyan, 4xx yellow, 5xx red".to_string(),
+        yaml_path: None,
+        rules,
+        schema_hint: Some(SchemaHint {
+            required_columns: vec![],
+            optional_columns: status_columns.iter().map(|c| ColumnMatcher::Pattern(c.to_string())).collect(),
+            min_confidence: 0.3,
+        }),
+    }
+}
+
+/// Create the Boolean Flags template
+fn create_boolean_template() -> StyleSet {
+    let flag_columns = ["is_*", "has_*", "*enabled*", "*flag*", "*_ok"];
+    let rules = vec![
+        conditional_rule(
+            "True values",
+            in_list_condition(&["true", "t", "yes", "y", "1"], &flag_columns),
+            ApplicationScope::Cell,
+            MatchedStyle { fg: Some(Color::Rgb(0, 200, 0)), bg: None, modifiers: None },
+            5,
+        ),
+        conditional_rule(
+            "False values",
+            in_list_condition(&["false", "f", "no", "n", "0"], &flag_columns),
+            ApplicationScope::Cell,
+            MatchedStyle { fg: Some(Color::Rgb(255, 80, 80)), bg: None, modifiers: Some(vec![Modifier::DIM]) },
+            5,
+        ),
+    ];
+
+    StyleSet {
+        id: "template-boolean".to_string(),
+        name: "Boolean Flags".to_string(),
+        categories: Some(vec!["Templates".to_string(), "Data Quality".to_string()]),
+        tags: Some(vec!["boolean".to_string(), "flag".to_string(), "true".to_string(), "false".to_string()]),
+        description: "Colors flag columns: true/yes/1 in green, false/no/0 in red".to_string(),
+        yaml_path: None,
+        rules,
+        schema_hint: Some(SchemaHint {
+            required_columns: vec![],
+            optional_columns: flag_columns.iter().map(|c| ColumnMatcher::Pattern(c.to_string())).collect(),
+            min_confidence: 0.3,
+        }),
+    }
+}
+
 /// Create the Status Colors template
 fn create_status_template() -> StyleSet {
     let status_columns = Some(vec!["*status*".to_string(), "*state*".to_string()]);
@@ -575,6 +775,66 @@ fn create_categorical_template() -> StyleSet {
     }
 }
 
+/// Id of the IOC Highlighting template, used by the one-key toggle
+pub const IOC_TEMPLATE_ID: &str = "template-ioc";
+
+/// Common TLDs; a closed list keeps file names such as `svchost.exe` from matching as domains
+const IOC_DOMAIN_TLDS: &str = "com|net|org|info|biz|io|co|me|us|uk|de|ru|cn|su|jp|br|in|fr|nl|eu|\
+gov|edu|mil|int|xyz|top|site|online|club|live|app|dev|cloud|tk|ml|ga|cf|gq|pw|cc|ws|ly|to|onion";
+
+/// Regex rule styling every match of `pattern` in any column, leaving the rest of the cell as is
+fn ioc_rule(name: &str, pattern: &str, style: MatchedStyle, priority: i32) -> StyleRule {
+    conditional_rule(
+        name,
+        Condition::Regex { pattern: pattern.to_string(), columns: None },
+        ApplicationScope::RegexGroup(GrepCapture::Group(0)),
+        style,
+        priority,
+    )
+}
+
+/// Create the IOC Highlighting template
+fn create_ioc_template() -> StyleSet {
+    let octet = "(?:25[0-5]|2[0-4][0-9]|1[0-9]{2}|[1-9]?[0-9])";
+    let hextet = "[0-9a-f]{1,4}";
+    let ipv4 = format!(r"\b(?:{octet}\.){{3}}{octet}\b");
+    // Full form, or compressed with `::` and at least one group (so `Foo::bar` paths stay plain)
+    let ipv6 = format!(
+        r"(?i)\b(?:{hextet}:){{7}}{hextet}\b|\b{hextet}(?::{hextet}){{0,6}}::(?:{hextet}(?::{hextet}){{0,6}}\b)?|::{hextet}(?::{hextet}){{0,6}}\b"
+    );
+    let domain = format!(r"(?i)\b(?:[a-z0-9](?:[a-z0-9-]{{0,61}}[a-z0-9])?\.)+(?:{IOC_DOMAIN_TLDS})\b");
+    let url = r#"(?i)\b(?:https?|hxxps?|ftp)://[^\s"'<>]+"#;
+    let email = r"(?i)\b[a-z0-9._%+-]+@(?:[a-z0-9-]+\.)+[a-z]{2,24}\b";
+
+    let style = |r, g, b| MatchedStyle {
+        fg: Some(Color::Rgb(r, g, b)),
+        bg: None,
+        modifiers: Some(vec![Modifier::BOLD, Modifier::UNDERLINED]),
+    };
+    // Longer indicators first: overlapping matches keep the one that starts earliest
+    let rules = vec![
+        ioc_rule("URL", url, style(255, 120, 200), 60),
+        ioc_rule("Email address", email, style(200, 150, 255), 55),
+        ioc_rule("SHA256 hash", r"\b[0-9a-fA-F]{64}\b", style(255, 170, 60), 50),
+        ioc_rule("SHA1 hash", r"\b[0-9a-fA-F]{40}\b", style(255, 170, 60), 45),
+        ioc_rule("MD5 hash", r"\b[0-9a-fA-F]{32}\b", style(255, 170, 60), 40),
+        ioc_rule("IPv4 address", &ipv4, style(80, 220, 255), 35),
+        ioc_rule("IPv6 address", &ipv6, style(80, 220, 255), 30),
+        ioc_rule("Domain", &domain, style(120, 230, 120), 25),
+    ];
+
+    StyleSet {
+        id: IOC_TEMPLATE_ID.to_string(),
+        name: "IOC Highlighting".to_string(),
+        categories: Some(vec!["Templates".to_string(), "Forensics".to_string()]),
+        tags: Some(vec!["ioc".to_string(), "forensic".to_string(), "ip".to_string(), "hash".to_string(), "domain".to_string()]),
+        description: "Highlights indicators of compromise (IPs, hashes, domains, URLs, emails) within cell text".to_string(),
+        yaml_path: None,
+        rules,
+        schema_hint: None,
+    }
+}
+
 /// Get all templates as StyleSets
 pub fn get_all_templates() -> Vec<StyleSet> {
     get_template_categories()
@@ -582,3 +842,60 @@ pub fn get_all_templates() -> Vec<StyleSet> {
         .map(create_template_styleset)
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn rule_matches(rule: &StyleRule, row: &[(&str, &str)]) -> bool {
+        let StyleLogic::Conditional(cond) = &rule.logic else { return false };
+        let Condition::Filter { expr, columns } = &cond.condition else { return false };
+        let row_data: BTreeMap<String, String> = row
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .filter(|(k, _)| columns.as_ref().is_none_or(|c| crate::dialog::styling::matches_column(k, c)))
+            .collect();
+        expr.evaluate_row(&row_data).unwrap()
+    }
+
+    #[test]
+    fn test_every_category_has_a_template() {
+        let templates = get_all_templates();
+        assert_eq!(templates.len(), get_template_categories().len());
+        assert!(templates.iter().all(|t| !t.rules.is_empty() && t.id.starts_with("template-")));
+    }
+
+    #[test]
+    fn test_severity_and_http_templates_match_their_columns() {
+        let severity = create_template_styleset(TemplateCategory::Severity);
+        let critical = &severity.rules[0];
+        assert!(rule_matches(critical, &[("severity", "CRITICAL"), ("message", "disk failed")]));
+        assert!(!rule_matches(critical, &[("severity", "info"), ("message", "critical")]));
+
+        let http = create_template_styleset(TemplateCategory::HttpStatus);
+        let server_error = http.rules.iter().find(|r| r.name.as_deref() == Some("5xx Server Error")).unwrap();
+        assert!(rule_matches(server_error, &[("sc-status", "503"), ("bytes", "500")]));
+        assert!(!rule_matches(server_error, &[("sc-status", "404"), ("bytes", "500")]));
+    }
+
+    #[test]
+    fn test_ioc_template_patterns() {
+        let ioc = create_template_styleset(TemplateCategory::Ioc);
+        let matches = |name: &str, text: &str| -> Vec<String> {
+            let rule = ioc.rules.iter().find(|r| r.name.as_deref() == Some(name)).unwrap();
+            let StyleLogic::Conditional(cond) = &rule.logic else { panic!("not conditional") };
+            let Condition::Regex { pattern, .. } = &cond.condition else { panic!("not regex") };
+            regex::Regex::new(pattern).unwrap().find_iter(text).map(|m| m.as_str().to_string()).collect()
+        };
+
+        assert_eq!(matches("IPv4 address", "from 10.0.0.5 to 999.1.1.1"), vec!["10.0.0.5"]);
+        assert_eq!(matches("IPv6 address", "fe80::1%eth0 and ::1, not std::string or 12:30:45"), vec!["fe80::1", "::1"]);
+        assert_eq!(matches("MD5 hash", "md5=d41d8cd98f00b204e9800998ecf8427e"), vec!["d41d8cd98f00b204e9800998ecf8427e"]);
+        assert!(matches("MD5 hash", &"a".repeat(64)).is_empty());
+        assert_eq!(matches("SHA1 hash", "da39a3ee5e6b4b0d3255bfef95601890afd80709").len(), 1);
+        assert_eq!(matches("Domain", "beacon to evil-c2.example.com via svchost.exe"), vec!["evil-c2.example.com"]);
+        assert_eq!(matches("URL", "GET \"http://1.2.3.4/a.ps1\" 200"), vec!["http://1.2.3.4/a.ps1"]);
+        assert_eq!(matches("Email address", "From: Alice <alice.b@corp.example.org>"), vec!["alice.b@corp.example.org"]);
+    }
+}
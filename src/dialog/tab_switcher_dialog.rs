@@ -0,0 +1,205 @@
+//! TabSwitcherDialog: switch to an open tab by typing part of its name or alias
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Cell, Clear, Paragraph, Row, Table, TableState, Wrap};
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+use crate::recent_files::fuzzy_score;
+
+/// An open tab as listed by the switcher
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TabSwitcherEntry {
+    /// Alias, or the dataset name when there is none
+    pub title: String,
+    /// Dataset name
+    pub dataset: String,
+    /// Rows in the tab's current view
+    pub rows: usize,
+    pub columns: usize,
+}
+
+#[derive(Debug)]
+pub struct TabSwitcherDialog {
+    /// Open tabs in tab bar order
+    pub entries: Vec<TabSwitcherEntry>,
+    pub query: String,
+    /// Indices into `entries` matching the query, best match first
+    pub matches: Vec<usize>,
+    pub selected: usize,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+impl TabSwitcherDialog {
+    /// `active` is the current tab, under the cursor until something is typed
+    pub fn new(entries: Vec<TabSwitcherEntry>, active: usize) -> Self {
+        let mut dialog = Self {
+            entries,
+            query: String::new(),
+            matches: Vec::new(),
+            selected: 0,
+            show_instructions: true,
+            config: Config::default(),
+        };
+        dialog.update_matches();
+        dialog.selected = active.min(dialog.matches.len().saturating_sub(1));
+        dialog
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn update_matches(&mut self) {
+        let mut scored: Vec<(i64, usize)> = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(i, entry)| {
+                let score = fuzzy_score(&self.query, &entry.title).max(fuzzy_score(&self.query, &entry.dataset));
+                score.map(|s| (s, i))
+            })
+            .collect();
+        // Stable sort keeps tab order among equal scores
+        if !self.query.is_empty() {
+            scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        }
+        self.matches = scored.into_iter().map(|(_, i)| i).collect();
+        self.selected = 0;
+    }
+
+    fn build_instructions_from_config(&self) -> String {
+        format!(
+            "Type to filter  {}",
+            self.config.actions_to_instructions(&[
+                (Mode::Global, Action::Enter),
+                (Mode::Global, Action::Escape),
+                (Mode::Global, Action::ToggleInstructions),
+            ])
+        )
+    }
+
+    /// Render the dialog
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title("Switch Tab")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(1)])
+            .split(layout.content_area);
+        Paragraph::new(format!("{}_", self.query))
+            .block(Block::default().borders(Borders::ALL).title(format!("{} of {} tabs", self.matches.len(), self.entries.len())))
+            .render(chunks[0], buf);
+
+        let rows = self.matches.iter().map(|&i| {
+            let entry = &self.entries[i];
+            let dataset = if entry.dataset == entry.title { String::new() } else { entry.dataset.clone() };
+            Row::new(vec![
+                Cell::from(format!("{}", i + 1)).style(Style::default().fg(Color::DarkGray)),
+                Cell::from(entry.title.as_str()),
+                Cell::from(dataset).style(Style::default().fg(Color::DarkGray)),
+                Cell::from(format!("{}", entry.rows)),
+                Cell::from(format!("{}", entry.columns)),
+            ])
+        });
+        let table = Table::new(
+            rows,
+            [Constraint::Length(4), Constraint::Min(20), Constraint::Min(10), Constraint::Length(12), Constraint::Length(8)],
+        )
+        .header(
+            Row::new(vec!["#", "Tab", "Dataset", "Rows", "Columns"])
+                .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+        )
+        .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        let mut state = TableState::default().with_selected(Some(self.selected));
+        StatefulWidget::render(table, chunks[1], buf, &mut state);
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Handle keyboard events; Enter returns `GoToTab` with the index into `entries`
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        match self.config.action_for_key(Mode::Global, key) {
+            Some(Action::Escape) => return Some(Action::DialogClose),
+            Some(Action::Enter) => return self.matches.get(self.selected).map(|&i| Action::GoToTab(i)),
+            Some(Action::Up) => {
+                self.selected = self.selected.saturating_sub(1);
+                return None;
+            }
+            Some(Action::Down) => {
+                if self.selected + 1 < self.matches.len() {
+                    self.selected += 1;
+                }
+                return None;
+            }
+            Some(Action::ToggleInstructions) => {
+                self.show_instructions = !self.show_instructions;
+                return None;
+            }
+            Some(Action::Backspace) => {
+                self.query.pop();
+                self.update_matches();
+                return None;
+            }
+            _ => {}
+        }
+        if let KeyCode::Char(c) = key.code
+            && !key.modifiers.intersects(crossterm::event::KeyModifiers::CONTROL | crossterm::event::KeyModifiers::ALT)
+        {
+            self.query.push(c);
+            self.update_matches();
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    #[test]
+    fn test_filter_by_alias_or_dataset_name() {
+        let entry = |title: &str, dataset: &str| TabSwitcherEntry { title: title.to_string(), dataset: dataset.to_string(), rows: 10, columns: 3 };
+        let entries = vec![entry("auth.log", "auth.log"), entry("Web proxy", "proxy_2024.csv"), entry("dns.parquet", "dns.parquet")];
+        let mut dialog = TabSwitcherDialog::new(entries, 2);
+        dialog.config.reset_keybindings_to_default();
+        assert_eq!(dialog.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)), Some(Action::GoToTab(2)));
+
+        for c in "web".chars() {
+            dialog.handle_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        assert_eq!(dialog.matches, vec![1]);
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE));
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE));
+        dialog.handle_key_event(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE));
+        // The dataset name behind an alias matches too
+        for c in "2024".chars() {
+            dialog.handle_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        assert_eq!(dialog.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)), Some(Action::GoToTab(1)));
+        assert_eq!(dialog.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)), Some(Action::DialogClose));
+    }
+}
@@ -0,0 +1,230 @@
+//! ThreatIntelSettingsDialog: API keys and rate limits for the threat-intel enrichment providers
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Clear, Paragraph, Wrap};
+use tui_textarea::TextArea;
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+use crate::threat_intel::{ThreatIntelConfig, ThreatIntelProvider};
+
+/// Per provider: API key then requests per minute; the cache TTL comes last
+const FIELDS_PER_PROVIDER: usize = 2;
+const FIELD_TTL: usize = ThreatIntelProvider::ALL.len() * FIELDS_PER_PROVIDER;
+const FIELD_COUNT: usize = FIELD_TTL + 1;
+
+fn text_input(value: &str, placeholder: &str) -> TextArea<'static> {
+    let mut t = TextArea::default();
+    t.set_block(Block::default());
+    t.set_placeholder_text(placeholder.to_string());
+    t.insert_str(value);
+    t
+}
+
+fn input_text(input: &TextArea<'static>) -> String {
+    input.lines().join("").trim().to_string()
+}
+
+/// API keys are only shown in full while their field is selected
+fn masked(key: &str) -> String {
+    let chars: Vec<char> = key.chars().collect();
+    if chars.len() <= 4 {
+        "•".repeat(chars.len())
+    } else {
+        format!("{}{}", "•".repeat(chars.len() - 4), chars[chars.len() - 4..].iter().collect::<String>())
+    }
+}
+
+#[derive(Debug)]
+pub struct ThreatIntelSettingsDialog {
+    /// `FIELD_COUNT` inputs in display order
+    pub inputs: Vec<TextArea<'static>>,
+    pub selected_field: usize,
+    pub error: Option<String>,
+    pub show_instructions: bool,
+    pub config: Config,
+    base: ThreatIntelConfig,
+}
+
+impl ThreatIntelSettingsDialog {
+    pub fn new(settings: ThreatIntelConfig) -> Self {
+        let mut inputs = Vec::with_capacity(FIELD_COUNT);
+        for provider in ThreatIntelProvider::ALL {
+            let s = settings.settings(provider);
+            inputs.push(text_input(&s.api_key, "not set"));
+            inputs.push(text_input(&s.requests_per_minute.to_string(), "0 = unlimited"));
+        }
+        inputs.push(text_input(&settings.cache_ttl_hours.to_string(), "hours"));
+        Self { inputs, selected_field: 0, error: None, show_instructions: true, config: Config::default(), base: settings }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    /// The settings as edited, or an error naming the first field that is not a number
+    pub fn settings(&self) -> Result<ThreatIntelConfig, String> {
+        let mut settings = self.base.clone();
+        for (i, provider) in ThreatIntelProvider::ALL.into_iter().enumerate() {
+            let rpm = input_text(&self.inputs[i * FIELDS_PER_PROVIDER + 1]);
+            let s = settings.settings_mut(provider);
+            s.api_key = input_text(&self.inputs[i * FIELDS_PER_PROVIDER]);
+            s.requests_per_minute = rpm.parse().map_err(|_| format!("{} requests per minute must be a whole number", provider.label()))?;
+        }
+        settings.cache_ttl_hours = input_text(&self.inputs[FIELD_TTL]).parse().map_err(|_| "Cache TTL must be a whole number of hours".to_string())?;
+        Ok(settings)
+    }
+
+    fn build_instructions_from_config(&self) -> String {
+        self.config.actions_to_instructions(&[
+            (Mode::Global, Action::Up),
+            (Mode::Global, Action::Down),
+            (Mode::Global, Action::Enter),
+            (Mode::Global, Action::Escape),
+            (Mode::Global, Action::ToggleInstructions),
+        ])
+    }
+
+    /// Render the dialog
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title("Threat Intel Providers")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let block = Block::default().title("Settings").borders(Borders::ALL);
+        let content = block.inner(layout.content_area);
+        block.render(layout.content_area, buf);
+
+        let mut labels = Vec::with_capacity(FIELD_COUNT);
+        for provider in ThreatIntelProvider::ALL {
+            labels.push(format!("{} API Key:", provider.label()));
+            labels.push("  Requests / Minute:".to_string());
+        }
+        labels.push("Cache TTL (hours):".to_string());
+        let label_width = labels.iter().map(|l| l.chars().count()).max().unwrap_or(0) as u16 + 2;
+        for (i, label) in labels.iter().enumerate() {
+            let y = content.y + i as u16;
+            if y >= content.bottom() {
+                break;
+            }
+            let selected = i == self.selected_field;
+            let style = if selected { Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD) } else { Style::default() };
+            buf.set_string(content.x + 1, y, label, style);
+            let value_area = Rect { x: content.x + 1 + label_width, y, width: content.width.saturating_sub(label_width + 2), height: 1 };
+            let is_key = i < FIELD_TTL && i % FIELDS_PER_PROVIDER == 0;
+            if is_key && !selected {
+                let key = input_text(&self.inputs[i]);
+                let shown = if key.is_empty() { "not set".to_string() } else { masked(&key) };
+                buf.set_string(value_area.x, y, shown, Style::default().fg(Color::DarkGray));
+                continue;
+            }
+            let mut ta = self.inputs[i].clone();
+            if !selected {
+                ta.set_cursor_style(Style::default());
+            }
+            ta.render(value_area, buf);
+        }
+        let y = content.y + FIELD_COUNT as u16 + 1;
+        let footer = self.error.as_ref().map(|e| (format!("Error: {e}"), Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)))
+            .unwrap_or_else(|| (format!("Saved to {}", ThreatIntelConfig::config_path().display()), Style::default().fg(Color::DarkGray)));
+        if y < content.bottom() {
+            buf.set_string(content.x + 1, y, footer.0, footer.1);
+        }
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Handle keyboard events for the dialog
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        match self.config.action_for_key(Mode::Global, key) {
+            Some(Action::Escape) => return Some(Action::DialogClose),
+            Some(Action::Enter) => match self.settings() {
+                Ok(settings) => return Some(Action::ThreatIntelSettingsApplied(settings)),
+                Err(e) => {
+                    self.error = Some(e);
+                    return None;
+                }
+            },
+            Some(Action::Up) => {
+                self.selected_field = self.selected_field.saturating_sub(1);
+                return None;
+            }
+            Some(Action::Down) => {
+                self.selected_field = (self.selected_field + 1).min(FIELD_COUNT - 1);
+                return None;
+            }
+            Some(Action::ToggleInstructions) => {
+                self.show_instructions = !self.show_instructions;
+                return None;
+            }
+            Some(Action::Paste) => {
+                if let Ok(mut clipboard) = arboard::Clipboard::new()
+                    && let Ok(text) = clipboard.get_text() {
+                        self.inputs[self.selected_field].insert_str(text.trim());
+                        self.error = None;
+                    }
+                return None;
+            }
+            _ => {}
+        }
+        if matches!(key.code, KeyCode::Char(_) | KeyCode::Backspace | KeyCode::Delete | KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End) {
+            self.inputs[self.selected_field].input(tui_textarea::Input::from(key));
+            self.error = None;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn press(dialog: &mut ThreatIntelSettingsDialog, code: KeyCode) -> Option<Action> {
+        dialog.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn test_edit_key_and_rate_limit() {
+        let mut dialog = ThreatIntelSettingsDialog::new(ThreatIntelConfig::default());
+        dialog.config.reset_keybindings_to_default();
+        for c in "abc123".chars() {
+            press(&mut dialog, KeyCode::Char(c));
+        }
+        press(&mut dialog, KeyCode::Down);
+        press(&mut dialog, KeyCode::Backspace);
+        press(&mut dialog, KeyCode::Char('x'));
+        assert!(press(&mut dialog, KeyCode::Enter).is_none());
+        assert!(dialog.error.is_some());
+        press(&mut dialog, KeyCode::Backspace);
+        press(&mut dialog, KeyCode::Char('2'));
+        match press(&mut dialog, KeyCode::Enter) {
+            Some(Action::ThreatIntelSettingsApplied(settings)) => {
+                assert_eq!(settings.virustotal.api_key, "abc123");
+                assert_eq!(settings.virustotal.requests_per_minute, 2);
+                assert_eq!(settings.cache_ttl_hours, 24);
+            }
+            other => panic!("expected applied settings, got {other:?}"),
+        }
+        assert_eq!(masked("abcdefgh"), "••••efgh");
+    }
+}
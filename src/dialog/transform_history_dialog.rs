@@ -0,0 +1,299 @@
+//! TransformHistoryDialog: review the transforms applied to a dataset and replay them
+//!
+//! Enter replays the pipeline from the original data. The pipeline can be exported as JSON and
+//! imported again later, e.g. to repeat an analysis on a re-imported source, or exported as a
+//! Python/Polars script to reproduce the findings outside the TUI.
+
+use crossterm::event::{KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Cell, Clear, Paragraph, Row, Table, TableState, Wrap};
+use tracing::error;
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+use crate::dataframe::pipeline_script::python_script;
+use crate::dataframe::transform_history::TransformHistory;
+use crate::dialog::file_browser_dialog::{FileBrowserAction, FileBrowserDialog, FileBrowserMode};
+
+/// What the file browser is choosing a file for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PipelineFile {
+    Json,
+    PythonScript,
+}
+
+#[derive(Debug)]
+pub enum TransformHistoryDialogMode {
+    List,
+    FileBrowser(Box<FileBrowserDialog>, PipelineFile),
+    /// Replay or file problems, shown until dismissed
+    Notice(String),
+}
+
+/// TransformHistoryDialog: table of the recorded steps of one dataset
+#[derive(Debug)]
+pub struct TransformHistoryDialog {
+    pub dataset_name: String,
+    /// File the dataset was loaded from, read by exported scripts
+    pub source_path: Option<std::path::PathBuf>,
+    pub history: TransformHistory,
+    pub selected: usize,
+    pub mode: TransformHistoryDialogMode,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+impl TransformHistoryDialog {
+    pub fn new(dataset_name: String, history: TransformHistory) -> Self {
+        Self {
+            dataset_name,
+            source_path: None,
+            history,
+            selected: 0,
+            mode: TransformHistoryDialogMode::List,
+            show_instructions: true,
+            config: Config::default(),
+        }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    /// Show a new history, e.g. after a replay
+    pub fn set_history(&mut self, history: TransformHistory) {
+        self.selected = self.selected.min(history.len().saturating_sub(1));
+        self.history = history;
+    }
+
+    pub fn set_notice(&mut self, message: String) {
+        self.mode = TransformHistoryDialogMode::Notice(message);
+    }
+
+    fn build_instructions_from_config(&self) -> String {
+        format!(
+            "Enter: Replay from original  {}",
+            self.config.actions_to_instructions(&[
+                (Mode::TransformHistoryDialog, Action::ExportTransformHistory),
+                (Mode::TransformHistoryDialog, Action::ImportTransformHistory),
+                (Mode::TransformHistoryDialog, Action::ExportTransformScript),
+                (Mode::Global, Action::Escape),
+                (Mode::Global, Action::ToggleInstructions),
+            ])
+        )
+    }
+
+    /// Render the dialog
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        if let TransformHistoryDialogMode::FileBrowser(browser, _) = &self.mode {
+            browser.render(area, buf);
+            return;
+        }
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title(format!("Transform History - {}", self.dataset_name))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let content = layout.content_area;
+
+        match &self.mode {
+            TransformHistoryDialogMode::Notice(msg) => {
+                Paragraph::new(format!("{msg}\n\nPress Enter or Esc to continue."))
+                    .style(Style::default().fg(Color::Red))
+                    .wrap(Wrap { trim: true })
+                    .render(content, buf);
+            }
+            _ if self.history.is_empty() => {
+                Paragraph::new("No transforms have been applied to this dataset yet.")
+                    .style(Style::default().fg(Color::Gray))
+                    .render(content, buf);
+            }
+            _ => {
+                let rows = self.history.records.iter().enumerate().map(|(i, record)| {
+                    let step_style = if record.step.is_replayable() {
+                        Style::default()
+                    } else {
+                        Style::default().fg(Color::DarkGray)
+                    };
+                    Row::new(vec![
+                        Cell::from((i + 1).to_string()),
+                        Cell::from(record.step.kind_name()),
+                        Cell::from(record.step.summary()),
+                        Cell::from(format!("{} x {}", record.rows, record.columns)),
+                        Cell::from(record.applied_at.format("%Y-%m-%d %H:%M:%S").to_string()),
+                    ])
+                    .style(step_style)
+                });
+                let table = Table::new(
+                    rows,
+                    [
+                        Constraint::Length(4),
+                        Constraint::Length(17),
+                        Constraint::Min(20),
+                        Constraint::Length(14),
+                        Constraint::Length(19),
+                    ],
+                )
+                .header(
+                    Row::new(vec!["#", "Step", "Details", "Rows x Cols", "Applied"])
+                        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                )
+                .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+                let mut state = TableState::default().with_selected(Some(self.selected));
+                StatefulWidget::render(table, content, buf, &mut state);
+            }
+        }
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Read an exported pipeline and ask the container to replay it
+    fn import_pipeline(&mut self, path: &std::path::Path) -> Option<Action> {
+        let parsed = std::fs::read_to_string(path)
+            .map_err(color_eyre::Report::from)
+            .and_then(|json| TransformHistory::from_json(&json));
+        match parsed {
+            Ok(history) => {
+                self.mode = TransformHistoryDialogMode::List;
+                Some(Action::ReplayTransformHistory(history.steps()))
+            }
+            Err(e) => {
+                error!("Failed to import transform history: {}", e);
+                self.mode = TransformHistoryDialogMode::Notice(format!("Failed to import {}: {e}", path.display()));
+                None
+            }
+        }
+    }
+
+    fn export_pipeline(&mut self, path: &std::path::Path, file: PipelineFile) {
+        let contents = match file {
+            PipelineFile::Json => self.history.to_json(),
+            PipelineFile::PythonScript => {
+                Ok(python_script(&self.history, &self.dataset_name, self.source_path.as_deref()))
+            }
+        };
+        let written = contents.and_then(|text| std::fs::write(path, text).map_err(color_eyre::Report::from));
+        self.mode = match written {
+            Ok(()) => TransformHistoryDialogMode::List,
+            Err(e) => TransformHistoryDialogMode::Notice(format!("Failed to export {}: {e}", path.display())),
+        };
+    }
+
+    /// Handle keyboard events for the dialog
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        if let TransformHistoryDialogMode::FileBrowser(browser, file) = &mut self.mode {
+            let file = *file;
+            match browser.handle_key_event(key) {
+                Some(FileBrowserAction::Selected(path)) => match browser.mode {
+                    FileBrowserMode::Load => return self.import_pipeline(&path),
+                    FileBrowserMode::Save => self.export_pipeline(&path, file),
+                },
+                Some(FileBrowserAction::Cancelled) => self.mode = TransformHistoryDialogMode::List,
+                None => {}
+            }
+            return None;
+        }
+
+        let global_action = self.config.action_for_key(Mode::Global, key);
+        if let TransformHistoryDialogMode::Notice(_) = self.mode {
+            if matches!(global_action, Some(Action::Escape | Action::Enter)) {
+                self.mode = TransformHistoryDialogMode::List;
+            }
+            return None;
+        }
+
+        match global_action {
+            Some(Action::Escape) => return Some(Action::DialogClose),
+            Some(Action::Enter) => {
+                if !self.history.is_empty() {
+                    return Some(Action::ReplayTransformHistory(self.history.steps()));
+                }
+                return None;
+            }
+            Some(Action::Up) => {
+                self.selected = self.selected.saturating_sub(1);
+                return None;
+            }
+            Some(Action::Down) => {
+                if self.selected + 1 < self.history.len() {
+                    self.selected += 1;
+                }
+                return None;
+            }
+            Some(Action::ToggleInstructions) => {
+                self.show_instructions = !self.show_instructions;
+                return None;
+            }
+            _ => {}
+        }
+
+        match self.config.action_for_key(Mode::TransformHistoryDialog, key) {
+            Some(Action::ExportTransformHistory) => {
+                let mut browser = FileBrowserDialog::new(None, Some(vec!["json"]), false, FileBrowserMode::Save);
+                browser.register_config_handler(self.config.clone());
+                browser.filename_input = format!("{}_pipeline.json", self.dataset_name);
+                browser.filename_cursor = browser.filename_input.len();
+                self.mode = TransformHistoryDialogMode::FileBrowser(Box::new(browser), PipelineFile::Json);
+            }
+            Some(Action::ExportTransformScript) => {
+                let mut browser = FileBrowserDialog::new(None, Some(vec!["py"]), false, FileBrowserMode::Save);
+                browser.register_config_handler(self.config.clone());
+                browser.filename_input = format!("{}_pipeline.py", self.dataset_name);
+                browser.filename_cursor = browser.filename_input.len();
+                self.mode = TransformHistoryDialogMode::FileBrowser(Box::new(browser), PipelineFile::PythonScript);
+            }
+            Some(Action::ImportTransformHistory) => {
+                let mut browser = FileBrowserDialog::new(None, Some(vec!["json"]), false, FileBrowserMode::Load);
+                browser.register_config_handler(self.config.clone());
+                self.mode = TransformHistoryDialogMode::FileBrowser(Box::new(browser), PipelineFile::Json);
+            }
+            _ => {}
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dataframe::transform_history::TransformStep;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    #[test]
+    fn test_enter_replays_and_import_reads_exported_pipeline() {
+        let mut history = TransformHistory::default();
+        history.push(TransformStep::Sql("SELECT * FROM df".to_string()), 5, 2);
+        history.push(TransformStep::Reset, 10, 2);
+
+        let mut dialog = TransformHistoryDialog::new("logs".to_string(), history.clone());
+        dialog.config.reset_keybindings_to_default();
+        assert_eq!(
+            dialog.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)),
+            Some(Action::ReplayTransformHistory(history.steps()))
+        );
+
+        let path = std::env::temp_dir().join(format!("datatui_pipeline_{}.json", std::process::id()));
+        dialog.export_pipeline(&path, PipelineFile::Json);
+        assert!(matches!(dialog.mode, TransformHistoryDialogMode::List));
+        let action = dialog.import_pipeline(&path);
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(action, Some(Action::ReplayTransformHistory(history.steps())));
+    }
+}
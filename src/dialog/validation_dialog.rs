@@ -0,0 +1,364 @@
+//! ValidationDialog: define per-column validation rules, run them over the current view and open
+//! the violations as a dataset
+
+use std::sync::Arc;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use polars::prelude::DataFrame;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, BorderType, Cell, Clear, Paragraph, Row, Table, TableState, Wrap};
+use tui_textarea::TextArea;
+
+use crate::action::Action;
+use crate::components::dialog_layout::split_dialog_area;
+use crate::config::{Config, Mode};
+use crate::dataframe::validation::{ValidationCheck, ValidationRule, CHECK_NAMES};
+
+fn argument_input(value: &str) -> TextArea<'static> {
+    let mut t = TextArea::default();
+    t.set_block(Block::default());
+    t.insert_str(value);
+    t
+}
+
+/// Placeholder for the argument of `CHECK_NAMES[kind]`
+fn argument_hint(kind: usize) -> &'static str {
+    match kind {
+        2 => "regex the values must match",
+        3 => "min..max (either may be left out)",
+        4 => "dataset.column the values must appear in",
+        _ => "no argument",
+    }
+}
+
+#[derive(Debug)]
+pub enum ValidationDialogMode {
+    /// Rules with their failure counts from the last run
+    List,
+    /// Adding a rule (`index` None) or editing one. `field` is the focused input: column, check
+    /// or argument.
+    EditRule { index: Option<usize>, column: usize, kind: usize, argument: Box<TextArea<'static>>, field: usize },
+}
+
+/// ValidationDialog: edits a copy of the dataset's rules; the container copies them back on
+/// SaveWorkspaceState and runs them on RunValidation
+#[derive(Debug)]
+pub struct ValidationDialog {
+    pub rules: Vec<ValidationRule>,
+    /// Columns of the current view, for the rule editor
+    pub columns: Vec<String>,
+    /// Other loaded datasets, listed as a hint for reference checks
+    pub datasets: Vec<String>,
+    /// Failures per rule from the last run
+    pub counts: Option<Vec<usize>>,
+    /// Violations from the last run
+    pub violations: Option<Arc<DataFrame>>,
+    pub selected: usize,
+    pub mode: ValidationDialogMode,
+    pub error: Option<String>,
+    pub show_instructions: bool,
+    pub config: Config,
+}
+
+impl ValidationDialog {
+    pub fn new(rules: Vec<ValidationRule>, columns: Vec<String>, datasets: Vec<String>) -> Self {
+        Self {
+            rules,
+            columns,
+            datasets,
+            counts: None,
+            violations: None,
+            selected: 0,
+            mode: ValidationDialogMode::List,
+            error: None,
+            show_instructions: true,
+            config: Config::default(),
+        }
+    }
+
+    /// Register config handler
+    pub fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    /// Show the results of a run
+    pub fn set_results(&mut self, counts: Vec<usize>, violations: Arc<DataFrame>) {
+        self.counts = Some(counts);
+        self.violations = Some(violations);
+        self.error = None;
+    }
+
+    /// Forget the last run, after the rules changed or the highlighting was cleared
+    pub fn clear_results(&mut self) {
+        self.counts = None;
+        self.violations = None;
+    }
+
+    fn edit_rule(&mut self, index: Option<usize>) {
+        let rule = index.and_then(|i| self.rules.get(i));
+        let column = rule
+            .and_then(|r| self.columns.iter().position(|c| c == &r.column))
+            .unwrap_or(0);
+        let kind = rule.map(|r| r.check.kind_index()).unwrap_or(0);
+        let argument = rule.map(|r| r.check.argument()).unwrap_or_default();
+        self.mode = ValidationDialogMode::EditRule { index, column, kind, argument: Box::new(argument_input(&argument)), field: 0 };
+        self.error = None;
+    }
+
+    fn build_instructions_from_config(&self) -> String {
+        match self.mode {
+            ValidationDialogMode::List => self.config.actions_to_instructions(&[
+                (Mode::ValidationDialog, Action::AddValidationRule),
+                (Mode::ValidationDialog, Action::EditValidationRule),
+                (Mode::ValidationDialog, Action::DeleteValidationRule),
+                (Mode::ValidationDialog, Action::RunValidation),
+                (Mode::ValidationDialog, Action::OpenViolationsDataset),
+                (Mode::ValidationDialog, Action::ClearValidation),
+                (Mode::Global, Action::Escape),
+                (Mode::Global, Action::ToggleInstructions),
+            ]),
+            ValidationDialogMode::EditRule { .. } => format!(
+                "Left/Right: Change column or check  {}",
+                self.config.actions_to_instructions(&[
+                    (Mode::Global, Action::Tab),
+                    (Mode::Global, Action::Enter),
+                    (Mode::Global, Action::Escape),
+                ])
+            ),
+        }
+    }
+
+    /// Render the dialog
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let outer_block = Block::default()
+            .title("Validation Rules")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double);
+        let inner_area = outer_block.inner(area);
+        outer_block.render(area, buf);
+
+        let instructions = self.build_instructions_from_config();
+        let layout = split_dialog_area(inner_area, self.show_instructions, Some(instructions.as_str()));
+        let content = layout.content_area;
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(2)])
+            .split(content);
+
+        match &self.mode {
+            ValidationDialogMode::EditRule { column, kind, argument, field, .. } => {
+                let rows = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Length(3), Constraint::Length(3), Constraint::Length(3), Constraint::Min(0)])
+                    .split(chunks[0]);
+                let column_name = self.columns.get(*column).map(String::as_str).unwrap_or("(no columns)");
+                let choices = [("Column", column_name), ("Check", CHECK_NAMES[*kind])];
+                for (i, (title, value)) in choices.into_iter().enumerate() {
+                    let style = if *field == i { Style::default().fg(Color::Yellow) } else { Style::default() };
+                    Paragraph::new(format!("< {value} >"))
+                        .block(Block::default().title(title).borders(Borders::ALL).border_style(style))
+                        .render(rows[i], buf);
+                }
+                let style = if *field == 2 { Style::default().fg(Color::Yellow) } else { Style::default() };
+                let block = Block::default().title("Argument").borders(Borders::ALL).border_style(style);
+                let inner = block.inner(rows[2]);
+                block.render(rows[2], buf);
+                let mut ta = argument.as_ref().clone();
+                ta.set_placeholder_text(argument_hint(*kind));
+                if *field != 2 {
+                    ta.set_cursor_style(Style::default());
+                }
+                ta.render(inner, buf);
+                if *kind == 4 && !self.datasets.is_empty() {
+                    Paragraph::new(format!("Loaded datasets: {}", self.datasets.join(", ")))
+                        .style(Style::default().fg(Color::DarkGray))
+                        .wrap(Wrap { trim: true })
+                        .render(rows[3], buf);
+                }
+            }
+            ValidationDialogMode::List if self.rules.is_empty() => {
+                Paragraph::new("No validation rules yet. Add one to start.")
+                    .style(Style::default().fg(Color::Gray))
+                    .render(chunks[0], buf);
+            }
+            ValidationDialogMode::List => {
+                let rows = self.rules.iter().enumerate().map(|(i, rule)| {
+                    let failures = match self.counts.as_ref().and_then(|c| c.get(i)) {
+                        Some(0) => Cell::from("ok").style(Style::default().fg(Color::Green)),
+                        Some(n) => Cell::from(n.to_string()).style(Style::default().fg(Color::Red)),
+                        None => Cell::from("-"),
+                    };
+                    Row::new(vec![Cell::from(rule.column.clone()), Cell::from(rule.check.label()), failures])
+                });
+                let table = Table::new(rows, [Constraint::Min(16), Constraint::Min(24), Constraint::Length(10)])
+                    .header(
+                        Row::new(vec!["Column", "Check", "Failures"])
+                            .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                    )
+                    .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+                let mut state = TableState::default().with_selected(Some(self.selected));
+                StatefulWidget::render(table, chunks[0], buf, &mut state);
+            }
+        }
+
+        let status = match (&self.error, &self.counts) {
+            (Some(error), _) => Line::styled(format!("Error: {error}"), Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+            (None, Some(counts)) => {
+                let total: usize = counts.iter().sum();
+                Line::styled(format!("{total} failing cells highlighted"), Style::default().fg(Color::Gray))
+            }
+            (None, None) => Line::styled("Not run since the rules changed", Style::default().fg(Color::DarkGray)),
+        };
+        Paragraph::new(status).wrap(Wrap { trim: true }).render(chunks[1], buf);
+
+        if let Some(instructions_area) = layout.instructions_area {
+            Paragraph::new(instructions.as_str())
+                .block(Block::default().borders(Borders::ALL).title("Instructions"))
+                .style(Style::default().fg(Color::Yellow))
+                .wrap(Wrap { trim: true })
+                .render(instructions_area, buf);
+        }
+    }
+
+    /// Handle keyboard events for the dialog
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        let global_action = self.config.action_for_key(Mode::Global, key);
+        if let ValidationDialogMode::EditRule { index, column, kind, argument, field } = &mut self.mode {
+            match global_action {
+                Some(Action::Escape) => self.mode = ValidationDialogMode::List,
+                Some(Action::Tab | Action::Down) => *field = (*field + 1) % 3,
+                Some(Action::Up) => *field = (*field + 2) % 3,
+                Some(Action::Left | Action::Right) if *field < 2 => {
+                    let (value, len) = if *field == 0 { (column, self.columns.len()) } else { (kind, CHECK_NAMES.len()) };
+                    if len > 0 {
+                        *value = if global_action == Some(Action::Left) { (*value + len - 1) % len } else { (*value + 1) % len };
+                    }
+                }
+                Some(Action::Enter) => {
+                    let column_name = self.columns.get(*column).cloned()?;
+                    match ValidationCheck::parse(*kind, &argument.lines().join("")) {
+                        Ok(check) => {
+                            let rule = ValidationRule { column: column_name, check };
+                            match *index {
+                                Some(i) => self.rules[i] = rule,
+                                None => {
+                                    self.rules.push(rule);
+                                    self.selected = self.rules.len() - 1;
+                                }
+                            }
+                            self.mode = ValidationDialogMode::List;
+                            self.clear_results();
+                            return Some(Action::SaveWorkspaceState);
+                        }
+                        Err(e) => self.error = Some(e.to_string()),
+                    }
+                }
+                _ => {
+                    if *field == 2
+                        && matches!(key.code, KeyCode::Char(_) | KeyCode::Backspace | KeyCode::Delete | KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End)
+                    {
+                        argument.input(tui_textarea::Input::from(key));
+                        self.error = None;
+                    }
+                }
+            }
+            return None;
+        }
+
+        match global_action {
+            Some(Action::Escape) => return Some(Action::DialogClose),
+            Some(Action::Up) => {
+                self.selected = self.selected.saturating_sub(1);
+                return None;
+            }
+            Some(Action::Down) => {
+                if self.selected + 1 < self.rules.len() {
+                    self.selected += 1;
+                }
+                return None;
+            }
+            Some(Action::ToggleInstructions) => {
+                self.show_instructions = !self.show_instructions;
+                return None;
+            }
+            _ => {}
+        }
+
+        match self.config.action_for_key(Mode::ValidationDialog, key) {
+            Some(Action::AddValidationRule) => {
+                self.edit_rule(None);
+                None
+            }
+            Some(Action::EditValidationRule) if self.selected < self.rules.len() => {
+                self.edit_rule(Some(self.selected));
+                None
+            }
+            Some(Action::DeleteValidationRule) if self.selected < self.rules.len() => {
+                self.rules.remove(self.selected);
+                self.selected = self.selected.min(self.rules.len().saturating_sub(1));
+                self.clear_results();
+                Some(Action::SaveWorkspaceState)
+            }
+            Some(Action::RunValidation) if !self.rules.is_empty() => Some(Action::RunValidation),
+            Some(Action::OpenViolationsDataset) if self.violations.is_some() => Some(Action::OpenViolationsDataset),
+            Some(Action::ClearValidation) => {
+                self.clear_results();
+                Some(Action::ClearValidation)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn press(dialog: &mut ValidationDialog, code: KeyCode, modifiers: KeyModifiers) -> Option<Action> {
+        dialog.handle_key_event(KeyEvent::new(code, modifiers))
+    }
+
+    #[test]
+    fn test_add_edit_and_delete_rules() {
+        let columns = vec!["id".to_string(), "bytes".to_string()];
+        let mut dialog = ValidationDialog::new(Vec::new(), columns, vec!["hosts".to_string()]);
+        dialog.config.reset_keybindings_to_default();
+
+        press(&mut dialog, KeyCode::Char('a'), KeyModifiers::CONTROL);
+        press(&mut dialog, KeyCode::Right, KeyModifiers::NONE);
+        press(&mut dialog, KeyCode::Tab, KeyModifiers::NONE);
+        for _ in 0..3 {
+            press(&mut dialog, KeyCode::Right, KeyModifiers::NONE);
+        }
+        press(&mut dialog, KeyCode::Tab, KeyModifiers::NONE);
+        for c in "0..x".chars() {
+            press(&mut dialog, KeyCode::Char(c), KeyModifiers::NONE);
+        }
+        assert_eq!(press(&mut dialog, KeyCode::Enter, KeyModifiers::NONE), None);
+        assert_eq!(dialog.error.as_deref(), Some("'x' is not a number"));
+        press(&mut dialog, KeyCode::Backspace, KeyModifiers::NONE);
+        assert_eq!(press(&mut dialog, KeyCode::Enter, KeyModifiers::NONE), Some(Action::SaveWorkspaceState));
+        assert_eq!(dialog.rules, vec![ValidationRule {
+            column: "bytes".to_string(),
+            check: ValidationCheck::Range { min: Some(0.0), max: None },
+        }]);
+
+        press(&mut dialog, KeyCode::Char('e'), KeyModifiers::CONTROL);
+        press(&mut dialog, KeyCode::Tab, KeyModifiers::NONE);
+        press(&mut dialog, KeyCode::Left, KeyModifiers::NONE);
+        press(&mut dialog, KeyCode::Left, KeyModifiers::NONE);
+        assert_eq!(press(&mut dialog, KeyCode::Enter, KeyModifiers::NONE), Some(Action::SaveWorkspaceState));
+        assert_eq!(dialog.rules[0].check, ValidationCheck::Unique);
+
+        assert_eq!(press(&mut dialog, KeyCode::Char('r'), KeyModifiers::CONTROL), Some(Action::RunValidation));
+        assert_eq!(press(&mut dialog, KeyCode::Char('n'), KeyModifiers::CONTROL), None);
+        assert_eq!(press(&mut dialog, KeyCode::Char('d'), KeyModifiers::CONTROL), Some(Action::SaveWorkspaceState));
+        assert!(dialog.rules.is_empty());
+    }
+}
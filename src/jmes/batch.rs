@@ -0,0 +1,168 @@
+//! Evaluate JMESPath expressions over every row of a DataFrame in parallel
+//!
+//! Rows are converted to JSON a batch at a time, column by column, and batches are spread over
+//! the rayon pool. Each worker compiles the expressions with its own thread-local runtime, since
+//! JMESPath variables are reference counted and cannot cross threads.
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use polars::prelude::{AnyValue, DataFrame};
+use rayon::prelude::*;
+use serde_json::{Map, Number, Value};
+
+use super::with_runtime;
+
+/// Rows converted and evaluated together by one worker
+pub const BATCH_ROWS: usize = 4096;
+
+/// JSON form of a cell as seen by JMESPath expressions
+pub fn anyvalue_to_json(value: AnyValue) -> Value {
+    let float = |f: f64| if f.is_finite() { Number::from_f64(f).map(Value::Number).unwrap_or(Value::Null) } else { Value::Null };
+    match value {
+        AnyValue::Null => Value::Null,
+        AnyValue::Boolean(b) => Value::Bool(b),
+        AnyValue::Int8(x) => Value::Number((x as i64).into()),
+        AnyValue::Int16(x) => Value::Number((x as i64).into()),
+        AnyValue::Int32(x) => Value::Number((x as i64).into()),
+        AnyValue::Int64(x) => Value::Number(x.into()),
+        AnyValue::UInt8(x) => Value::Number((x as u64).into()),
+        AnyValue::UInt16(x) => Value::Number((x as u64).into()),
+        AnyValue::UInt32(x) => Value::Number((x as u64).into()),
+        AnyValue::UInt64(x) => Value::Number(x.into()),
+        AnyValue::Float32(f) => float(f as f64),
+        AnyValue::Float64(f) => float(f),
+        AnyValue::String(s) => Value::String(s.into()),
+        AnyValue::StringOwned(s) => Value::String(s.to_string()),
+        other => Value::String(other.to_string()),
+    }
+}
+
+/// Row objects for `df`, built one column at a time
+pub fn row_objects(df: &DataFrame) -> Vec<Map<String, Value>> {
+    let mut rows = vec![Map::new(); df.height()];
+    for column in df.get_columns() {
+        let series = column.as_materialized_series().rechunk();
+        let name = column.name().to_string();
+        for (row, value) in rows.iter_mut().zip(series.iter()) {
+            row.insert(name.clone(), anyvalue_to_json(value));
+        }
+    }
+    rows
+}
+
+/// Results of each of `expressions` on each row of `df`, in row order.
+///
+/// `on_progress` is called from the workers with the fraction of rows done and returns false to
+/// stop; the remaining batches are then skipped and a "Cancelled" error is returned.
+pub fn evaluate(
+    df: &DataFrame,
+    expressions: &[String],
+    on_progress: impl Fn(f64) -> bool + Sync,
+) -> Result<Vec<Vec<Value>>> {
+    // Report syntax errors once rather than from every worker
+    with_runtime(|runtime| {
+        expressions
+            .iter()
+            .try_for_each(|e| runtime.compile(e).map(|_| ()).map_err(|err| eyre!("JMESPath compile error: {}", err)))
+    })?;
+    let height = df.height();
+    let done = AtomicUsize::new(0);
+    let stopped = AtomicBool::new(false);
+    let cancelled = AtomicBool::new(false);
+    // None for batches skipped after a failure or cancellation
+    let batches: Vec<Option<Result<Vec<Vec<Value>>>>> = (0..height.div_ceil(BATCH_ROWS))
+        .into_par_iter()
+        .map(|batch| {
+            if stopped.load(Ordering::Relaxed) {
+                return None;
+            }
+            let offset = batch * BATCH_ROWS;
+            let rows = row_objects(&df.slice(offset as i64, BATCH_ROWS));
+            let results = evaluate_batch(offset, rows, expressions);
+            match &results {
+                Ok(batch_results) => {
+                    let finished = done.fetch_add(batch_results.len(), Ordering::Relaxed) + batch_results.len();
+                    if !on_progress(finished as f64 / height as f64) {
+                        cancelled.store(true, Ordering::Relaxed);
+                        stopped.store(true, Ordering::Relaxed);
+                    }
+                }
+                Err(_) => stopped.store(true, Ordering::Relaxed),
+            }
+            Some(results)
+        })
+        .collect();
+    if cancelled.load(Ordering::Relaxed) {
+        return Err(eyre!("Cancelled"));
+    }
+    // The earliest failing row among the batches that ran
+    let mut rows = Vec::with_capacity(height);
+    for batch in batches.into_iter().flatten() {
+        rows.extend(batch?);
+    }
+    Ok(rows)
+}
+
+/// Evaluate `expressions` on rows starting at row `offset` with this thread's runtime
+fn evaluate_batch(offset: usize, rows: Vec<Map<String, Value>>, expressions: &[String]) -> Result<Vec<Vec<Value>>> {
+    with_runtime(|runtime| {
+        let compiled = expressions
+            .iter()
+            .map(|e| runtime.compile(e).map_err(|err| eyre!("JMESPath compile error: {}", err)))
+            .collect::<Result<Vec<_>>>()?;
+        rows.into_iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let row_idx = offset + i;
+                let var = jmespath::Variable::try_from(Value::Object(row))
+                    .map_err(|e| eyre!("Failed to convert row to JMES variable at row {}: {}", row_idx, e))?;
+                let var = std::rc::Rc::new(var);
+                compiled
+                    .iter()
+                    .map(|expr| {
+                        let result = expr
+                            .search(var.clone())
+                            .map_err(|e| eyre!("JMESPath search error at row {}: {}", row_idx, e))?;
+                        serde_json::to_value(&*result)
+                            .map_err(|e| eyre!("Failed to convert JMES result at row {}: {}", row_idx, e))
+                    })
+                    .collect()
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use polars::prelude::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_evaluate_in_row_order_across_batches() {
+        let height = BATCH_ROWS * 2 + 7;
+        let ids: Vec<i64> = (0..height as i64).collect();
+        let names: Vec<Option<String>> = (0..height).map(|i| (i % 3 != 0).then(|| format!("n{i}"))).collect();
+        let df = df!("id" => ids, "name" => names).unwrap();
+
+        let expressions = vec!["id".to_string(), "{name: name}".to_string()];
+        let progress = std::sync::Mutex::new(Vec::new());
+        let results = evaluate(&df, &expressions, |p| {
+            progress.lock().unwrap().push(p);
+            true
+        })
+        .unwrap();
+        assert_eq!(results.len(), height);
+        assert_eq!(results[0], vec![json!(0), json!({"name": null})]);
+        assert_eq!(results[height - 1], vec![json!(height - 1), json!({"name": format!("n{}", height - 1)})]);
+        assert!(results.iter().enumerate().all(|(i, r)| r[0] == json!(i)));
+        assert_eq!(progress.lock().unwrap().iter().cloned().fold(0.0, f64::max), 1.0);
+
+        assert!(evaluate(&df, &["{a: ".to_string()], |_| true).unwrap_err().to_string().contains("compile error"));
+        let err = evaluate(&df, &["abs(name)".to_string()], |_| true).unwrap_err().to_string();
+        assert!(err.contains("search error at row 0"), "{err}");
+        assert_eq!(evaluate(&df, &expressions, |_| false).unwrap_err().to_string(), "Cancelled");
+    }
+}
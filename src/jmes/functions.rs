@@ -1,5 +1,74 @@
-use jmespath::{Context, Rcvar, Runtime};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use base64::Engine;
+use chrono::{DateTime, SecondsFormat, Utc};
+use jmespath::{Context, ErrorReason, JmespathError, Rcvar, Runtime, Variable};
 use jmespath::functions::{ArgumentType, CustomFunction, Signature};
+use regex::Regex;
+
+use crate::dataframe::datetime_parse::parse_datetime_micros;
+use crate::dataframe::hashing::HashAlgorithm;
+use crate::dataframe::timestamp_normalize::normalize_timestamp;
+
+/// Custom functions and their signatures, as offered by expression completion.
+pub const CUSTOM_FUNCTIONS: &[(&str, &str)] = &[
+    ("keyvalue_to_object", "keyvalue_to_object(string, kv_sep, pair_sep)"),
+    ("upper", "upper(string)"),
+    ("lower", "lower(string)"),
+    ("format", "format(string, array)"),
+    ("regex_extract", "regex_extract(string, pattern, group?)"),
+    ("regex_match", "regex_match(string, pattern)"),
+    ("to_datetime", "to_datetime(value, format?)"),
+    ("format_datetime", "format_datetime(value, format)"),
+    ("sha256", "sha256(string)"),
+    ("b64decode", "b64decode(string)"),
+    ("split", "split(string, separator)"),
+];
+
+thread_local! {
+    /// Patterns compiled by the regex functions; expressions run once per row
+    static REGEXES: RefCell<HashMap<String, Regex>> = RefCell::new(HashMap::new());
+}
+
+/// Run `f` with `pattern` compiled, reporting an invalid pattern as an evaluation error
+fn with_regex<R>(pattern: &str, ctx: &Context, f: impl FnOnce(&Regex) -> R) -> Result<R, JmespathError> {
+    REGEXES.with(|cell| {
+        let mut cache = cell.borrow_mut();
+        if !cache.contains_key(pattern) {
+            let regex = Regex::new(pattern).map_err(|e| {
+                JmespathError::new(ctx.expression, ctx.offset, ErrorReason::Parse(format!("Invalid regex '{pattern}': {e}")))
+            })?;
+            cache.insert(pattern.to_string(), regex);
+        }
+        Ok(f(&cache[pattern]))
+    })
+}
+
+/// Text of a string argument; other values use their JSON form
+fn text_arg(arg: &Rcvar) -> String {
+    arg.as_string().cloned().unwrap_or_else(|| arg.to_string())
+}
+
+fn string_or_null(value: Option<String>) -> Rcvar {
+    Rcvar::new(value.map(Variable::String).unwrap_or(Variable::Null))
+}
+
+/// A timestamp argument: text or epoch numbers as understood by timestamp normalization, or
+/// text in an explicit chrono `format`
+fn datetime_arg(arg: &Rcvar, format: Option<&str>) -> Option<DateTime<Utc>> {
+    let micros = match (arg.as_number(), format) {
+        (Some(n), _) if n.fract() == 0.0 => normalize_timestamp(&(n as i64).to_string())?.0,
+        (Some(n), _) => normalize_timestamp(&n.to_string())?.0,
+        (None, Some(format)) => parse_datetime_micros(arg.as_string()?, format)?,
+        (None, None) => normalize_timestamp(arg.as_string()?)?.0,
+    };
+    DateTime::from_timestamp_micros(micros)
+}
+
+fn text_or_null() -> ArgumentType {
+    ArgumentType::Union(vec![ArgumentType::String, ArgumentType::Null])
+}
 
 /// Register all custom JMESPath functions available to the application.
 pub fn register_custom_functions(runtime: &mut Runtime) {
@@ -161,6 +230,189 @@ pub fn register_custom_functions(runtime: &mut Runtime) {
             }),
         )),
     );
+
+    // regex_extract(string, pattern, group?) -> string | null
+    // Example: regex_extract('user=bob id=7', 'id=(\\d+)', `1`) => '7'
+    runtime.register_function(
+        "regex_extract",
+        Box::new(CustomFunction::new(
+            Signature::new(vec![text_or_null(), ArgumentType::String], Some(ArgumentType::Number)),
+            Box::new(|args: &[Rcvar], ctx: &mut Context| {
+                if args[0].is_null() {
+                    return Ok(Rcvar::new(Variable::Null));
+                }
+                let group = args.get(2).and_then(|g| g.as_number()).unwrap_or(0.0) as usize;
+                let text = text_arg(&args[0]);
+                let found = with_regex(&text_arg(&args[1]), ctx, |re| {
+                    re.captures(&text).and_then(|c| c.get(group)).map(|m| m.as_str().to_string())
+                })?;
+                Ok(string_or_null(found))
+            }),
+        )),
+    );
+
+    // regex_match(string, pattern) -> boolean
+    runtime.register_function(
+        "regex_match",
+        Box::new(CustomFunction::new(
+            Signature::new(vec![text_or_null(), ArgumentType::String], None),
+            Box::new(|args: &[Rcvar], ctx: &mut Context| {
+                if args[0].is_null() {
+                    return Ok(Rcvar::new(Variable::Bool(false)));
+                }
+                let text = text_arg(&args[0]);
+                let matched = with_regex(&text_arg(&args[1]), ctx, |re| re.is_match(&text))?;
+                Ok(Rcvar::new(Variable::Bool(matched)))
+            }),
+        )),
+    );
+
+    // to_datetime(value, format?) -> RFC 3339 UTC string | null
+    // Text, epoch seconds/millis/micros, FILETIME and WebKit times are detected; a chrono
+    // format reads text exactly
+    runtime.register_function(
+        "to_datetime",
+        Box::new(CustomFunction::new(
+            Signature::new(
+                vec![ArgumentType::Union(vec![ArgumentType::String, ArgumentType::Number, ArgumentType::Null])],
+                Some(ArgumentType::String),
+            ),
+            Box::new(|args: &[Rcvar], _ctx: &mut Context| {
+                let format = args.get(1).and_then(|f| f.as_string()).map(|f| f.as_str());
+                let dt = datetime_arg(&args[0], format);
+                Ok(string_or_null(dt.map(|dt| dt.to_rfc3339_opts(SecondsFormat::AutoSi, true))))
+            }),
+        )),
+    );
+
+    // format_datetime(value, format) -> string | null
+    // Example: format_datetime('2024-03-01T10:00:00Z', '%Y-%m-%d') => '2024-03-01'
+    runtime.register_function(
+        "format_datetime",
+        Box::new(CustomFunction::new(
+            Signature::new(
+                vec![
+                    ArgumentType::Union(vec![ArgumentType::String, ArgumentType::Number, ArgumentType::Null]),
+                    ArgumentType::String,
+                ],
+                None,
+            ),
+            Box::new(|args: &[Rcvar], _ctx: &mut Context| {
+                let format = text_arg(&args[1]);
+                // chrono panics while formatting an invalid specifier, so check it first
+                if chrono::format::StrftimeItems::new(&format).any(|i| matches!(i, chrono::format::Item::Error)) {
+                    return Ok(Rcvar::new(Variable::Null));
+                }
+                let dt = datetime_arg(&args[0], None);
+                Ok(string_or_null(dt.map(|dt| dt.format(&format).to_string())))
+            }),
+        )),
+    );
+
+    // sha256(string) -> lowercase hex digest of the UTF-8 text
+    runtime.register_function(
+        "sha256",
+        Box::new(CustomFunction::new(
+            Signature::new(vec![text_or_null()], None),
+            Box::new(|args: &[Rcvar], _ctx: &mut Context| {
+                if args[0].is_null() {
+                    return Ok(Rcvar::new(Variable::Null));
+                }
+                let digest = HashAlgorithm::Sha256.hex_digest(text_arg(&args[0]).as_bytes());
+                Ok(Rcvar::new(Variable::String(digest)))
+            }),
+        )),
+    );
+
+    // b64decode(string) -> string | null
+    // Standard or URL-safe alphabet, padded or not; invalid UTF-8 is replaced
+    runtime.register_function(
+        "b64decode",
+        Box::new(CustomFunction::new(
+            Signature::new(vec![text_or_null()], None),
+            Box::new(|args: &[Rcvar], _ctx: &mut Context| {
+                if args[0].is_null() {
+                    return Ok(Rcvar::new(Variable::Null));
+                }
+                let text = text_arg(&args[0]);
+                let trimmed = text.trim().trim_end_matches('=');
+                let decoded = base64::engine::general_purpose::STANDARD_NO_PAD
+                    .decode(trimmed)
+                    .or_else(|_| base64::engine::general_purpose::URL_SAFE_NO_PAD.decode(trimmed))
+                    .ok()
+                    .map(|bytes| String::from_utf8_lossy(&bytes).into_owned());
+                Ok(string_or_null(decoded))
+            }),
+        )),
+    );
+
+    // split(string, separator) -> array of strings; an empty separator splits characters
+    runtime.register_function(
+        "split",
+        Box::new(CustomFunction::new(
+            Signature::new(vec![text_or_null(), ArgumentType::String], None),
+            Box::new(|args: &[Rcvar], _ctx: &mut Context| {
+                if args[0].is_null() {
+                    return Ok(Rcvar::new(Variable::Null));
+                }
+                let text = text_arg(&args[0]);
+                let separator = text_arg(&args[1]);
+                let parts: Vec<Rcvar> = if separator.is_empty() {
+                    text.chars().map(|c| Rcvar::new(Variable::String(c.to_string()))).collect()
+                } else {
+                    text.split(separator.as_str()).map(|p| Rcvar::new(Variable::String(p.to_string()))).collect()
+                };
+                Ok(Rcvar::new(Variable::Array(parts)))
+            }),
+        )),
+    );
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn eval(expression: &str, data: &serde_json::Value) -> serde_json::Value {
+        let runtime = crate::jmes::new_runtime();
+        let expr = runtime.compile(expression).unwrap();
+        let result = expr.search(Variable::try_from(data.clone()).unwrap()).unwrap();
+        serde_json::from_str(&result.to_string()).unwrap()
+    }
 
+    #[test]
+    fn test_log_wrangling_functions() {
+        let row = json!({
+            "msg": "Accepted password for bob from 10.0.0.5 port 2222",
+            "ts": "2024-03-01 10:15:00",
+            "epoch": 1709288100,
+            "blob": "aGVsbG8gd29ybGQ",
+            "path": "/home/bob/file.txt",
+            "missing": null,
+        });
+        assert_eq!(eval(r"regex_extract(msg, 'from (\S+)', `1`)", &row), json!("10.0.0.5"));
+        assert_eq!(eval(r"regex_extract(msg, 'port \d+')", &row), json!("port 2222"));
+        assert_eq!(eval("regex_extract(msg, 'nomatch')", &row), json!(null));
+        assert_eq!(eval("regex_match(msg, '^Accepted')", &row), json!(true));
+        assert_eq!(eval("regex_match(missing, '.')", &row), json!(false));
+        assert_eq!(eval("to_datetime(ts)", &row), json!("2024-03-01T10:15:00Z"));
+        assert_eq!(eval("to_datetime(epoch)", &row), json!("2024-03-01T10:15:00Z"));
+        assert_eq!(eval("to_datetime('01/03/2024 10:15', '%d/%m/%Y %H:%M')", &row), json!("2024-03-01T10:15:00Z"));
+        assert_eq!(eval("to_datetime('soon')", &row), json!(null));
+        assert_eq!(eval("format_datetime(epoch, '%Y-%m-%d %H:%M')", &row), json!("2024-03-01 10:15"));
+        assert_eq!(eval("format_datetime(ts, '%Q')", &row), json!(null));
+        assert_eq!(
+            eval("sha256('abc')", &row),
+            json!("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+        );
+        assert_eq!(eval("b64decode(blob)", &row), json!("hello world"));
+        assert_eq!(eval("b64decode('!!')", &row), json!(null));
+        assert_eq!(eval("split(path, '/')[-1]", &row), json!("file.txt"));
+        assert_eq!(eval("split('abc', '')", &row), json!(["a", "b", "c"]));
+        assert_eq!(eval("split(missing, ',')", &row), json!(null));
+
+        let runtime = crate::jmes::new_runtime();
+        let expr = runtime.compile("regex_match(msg, '(')").unwrap();
+        assert!(expr.search(Variable::try_from(row).unwrap()).is_err());
+    }
+}
@@ -0,0 +1,194 @@
+//! Editing hints for JMESPath expressions: completion of column and function names, the
+//! JSON shape of a row to write expressions against, and previews on sample rows
+
+use serde_json::Value;
+
+use super::{with_runtime, CUSTOM_FUNCTIONS};
+
+/// Rows evaluated by the preview: the selected row and the ones after it
+pub const PREVIEW_ROWS: usize = 5;
+
+/// Nesting shown by `json_shape` before objects are summarized
+const MAX_SHAPE_DEPTH: usize = 4;
+
+/// A candidate for the identifier being typed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Completion {
+    /// Text replacing the typed prefix
+    pub insert: String,
+    /// "column" or the function signature
+    pub detail: String,
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Column name as a JMESPath identifier, quoted when it is not a plain identifier
+pub fn quote_identifier(name: &str) -> String {
+    let plain = name.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if plain {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+/// Identifier characters directly before char offset `cursor` of `line`
+pub fn prefix_before(line: &str, cursor: usize) -> String {
+    let before: Vec<char> = line.chars().take(cursor).collect();
+    let start = before.iter().rposition(|c| !is_identifier_char(*c)).map(|i| i + 1).unwrap_or(0);
+    before[start..].iter().collect()
+}
+
+/// Columns and custom functions starting with the identifier before `cursor` (case-insensitive).
+/// Fields after a `.` belong to nested objects, so nothing is offered there.
+pub fn completions(line: &str, cursor: usize, columns: &[String]) -> Vec<Completion> {
+    let prefix = prefix_before(line, cursor);
+    if prefix.is_empty() {
+        return Vec::new();
+    }
+    let before: Vec<char> = line.chars().take(cursor).collect();
+    let start = before.len() - prefix.chars().count();
+    if start > 0 && before[start - 1] == '.' {
+        return Vec::new();
+    }
+    let lower = prefix.to_lowercase();
+    let columns = columns
+        .iter()
+        .filter(|c| c.to_lowercase().starts_with(&lower) && c.as_str() != prefix)
+        .map(|c| Completion { insert: quote_identifier(c), detail: "column".to_string() });
+    let functions = CUSTOM_FUNCTIONS
+        .iter()
+        .filter(|(name, _)| name.starts_with(&lower))
+        .map(|(name, signature)| Completion { insert: format!("{name}("), detail: signature.to_string() });
+    columns.chain(functions).collect()
+}
+
+pub fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Type of `value` and the lines describing its contents. Strings holding a JSON object or
+/// array are expanded, since that is what JSON columns usually contain.
+fn describe(value: &Value, depth: usize, lines: &mut Vec<String>) -> String {
+    match value {
+        Value::Object(map) => {
+            if depth < MAX_SHAPE_DEPTH {
+                shape_lines(map, depth + 1, lines);
+                "object".to_string()
+            } else {
+                format!("object ({} fields)", map.len())
+            }
+        }
+        Value::Array(items) => match items.first() {
+            Some(first) => format!("array[{}] of {}", items.len(), describe(first, depth, lines)),
+            None => "array[0]".to_string(),
+        },
+        Value::String(text) => match serde_json::from_str::<Value>(text) {
+            Ok(parsed @ (Value::Object(_) | Value::Array(_))) => format!("string (JSON {})", describe(&parsed, depth, lines)),
+            _ => "string".to_string(),
+        },
+        other => type_name(other).to_string(),
+    }
+}
+
+fn shape_lines(map: &serde_json::Map<String, Value>, depth: usize, lines: &mut Vec<String>) {
+    let indent = "  ".repeat(depth);
+    for (key, value) in map {
+        let at = lines.len();
+        lines.push(String::new());
+        let described = describe(value, depth, lines);
+        lines[at] = format!("{indent}{key}: {described}");
+    }
+}
+
+/// Indented `field: type` lines for a row object
+pub fn json_shape(row: &Value) -> Vec<String> {
+    let mut lines = Vec::new();
+    match row {
+        Value::Object(map) => shape_lines(map, 0, &mut lines),
+        other => {
+            let described = describe(other, 0, &mut lines);
+            lines.insert(0, described);
+        }
+    }
+    lines
+}
+
+/// Result of an expression on each preview row
+#[derive(Debug, Clone, PartialEq)]
+pub enum Preview {
+    /// The expression does not compile
+    Invalid(String),
+    Rows(Vec<Result<Value, String>>),
+}
+
+/// Evaluate `expression` on `rows`
+pub fn preview(expression: &str, rows: &[Value]) -> Preview {
+    with_runtime(|runtime| {
+        let compiled = match runtime.compile(expression) {
+            Ok(compiled) => compiled,
+            Err(e) => return Preview::Invalid(e.to_string()),
+        };
+        Preview::Rows(
+            rows.iter()
+                .map(|row| {
+                    let var = jmespath::Variable::try_from(row.clone()).map_err(|e| e.to_string())?;
+                    // The reason alone; the expression and caret are the same for every row
+                    let result = compiled.search(var).map_err(|e| e.reason.to_string())?;
+                    serde_json::from_str(&result.to_string()).map_err(|e| e.to_string())
+                })
+                .collect(),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_completions_shape_and_preview() {
+        let columns = vec!["user_name".to_string(), "User Agent".to_string(), "payload".to_string()];
+        let inserts = |line: &str, cursor: usize| completions(line, cursor, &columns).into_iter().map(|c| c.insert).collect::<Vec<_>>();
+        assert_eq!(inserts("u", 1), vec!["user_name", "\"User Agent\"", "upper("]);
+        assert_eq!(inserts("[us", 3), vec!["user_name", "\"User Agent\""]);
+        assert_eq!(inserts("{a: low", 7), vec!["lower("]);
+        assert_eq!(inserts("payload.us", 10), Vec::<String>::new());
+        assert_eq!(inserts("payload ", 8), Vec::<String>::new());
+        assert_eq!(prefix_before("format(us", 9), "us");
+
+        let row = json!({
+            "name": "a",
+            "count": 3,
+            "payload": "{\"user\": {\"id\": 5}, \"tags\": [\"x\", \"y\"]}",
+            "items": [{"sku": "b"}],
+        });
+        assert_eq!(json_shape(&row), vec![
+            "name: string",
+            "count: number",
+            "payload: string (JSON object)",
+            "  user: object",
+            "    id: number",
+            "  tags: array[2] of string",
+            "items: array[1] of object",
+            "  sku: string",
+        ]);
+
+        let rows = vec![json!({"a": 1}), json!({"a": "x"})];
+        assert_eq!(preview("{b: a}", &rows), Preview::Rows(vec![Ok(json!({"b": 1})), Ok(json!({"b": "x"}))]));
+        assert!(matches!(preview("{b: ", &rows), Preview::Invalid(_)));
+        let Preview::Rows(results) = preview("abs(a)", &rows) else { panic!("expected rows") };
+        assert!(results[0].is_ok() && results[1].is_err());
+    }
+}
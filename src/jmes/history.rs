@@ -0,0 +1,137 @@
+//! Recently used and saved JMESPath expressions, kept per project
+
+use serde::{Deserialize, Serialize};
+
+use crate::dialog::jmes_dialog::JmesPathKeyValuePair;
+
+/// Recent expressions kept before the oldest is dropped
+pub const MAX_RECENT: usize = 30;
+
+/// What was entered in one of the JMESPath dialog tabs
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JmesExpression {
+    Transform(String),
+    AddColumns(Vec<JmesPathKeyValuePair>),
+}
+
+impl JmesExpression {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            JmesExpression::Transform(_) => "Transform",
+            JmesExpression::AddColumns(_) => "Add Columns",
+        }
+    }
+
+    /// One-line form for lists
+    pub fn summary(&self) -> String {
+        match self {
+            JmesExpression::Transform(query) => query.split_whitespace().collect::<Vec<_>>().join(" "),
+            JmesExpression::AddColumns(pairs) => pairs
+                .iter()
+                .map(|p| format!("{} = {}", p.name, p.value.split_whitespace().collect::<Vec<_>>().join(" ")))
+                .collect::<Vec<_>>()
+                .join("; "),
+        }
+    }
+
+    fn is_blank(&self) -> bool {
+        match self {
+            JmesExpression::Transform(query) => query.trim().is_empty(),
+            JmesExpression::AddColumns(pairs) => pairs.is_empty(),
+        }
+    }
+}
+
+/// An expression saved under a name
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedJmesExpression {
+    pub name: String,
+    pub expression: JmesExpression,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JmesHistory {
+    /// Most recently applied first
+    #[serde(default)]
+    pub recent: Vec<JmesExpression>,
+    #[serde(default)]
+    pub saved: Vec<SavedJmesExpression>,
+}
+
+impl JmesHistory {
+    pub fn is_empty(&self) -> bool {
+        self.recent.is_empty() && self.saved.is_empty()
+    }
+
+    /// Move `expression` to the front of the recent list
+    pub fn record(&mut self, expression: JmesExpression) {
+        if expression.is_blank() {
+            return;
+        }
+        self.recent.retain(|e| e != &expression);
+        self.recent.insert(0, expression);
+        self.recent.truncate(MAX_RECENT);
+    }
+
+    /// Save `expression` as `name`, replacing an expression of the same name
+    pub fn save(&mut self, name: String, expression: JmesExpression) {
+        match self.saved.iter_mut().find(|s| s.name == name) {
+            Some(existing) => existing.expression = expression,
+            None => self.saved.push(SavedJmesExpression { name, expression }),
+        }
+    }
+
+    /// Saved expressions followed by recent ones, as listed in the dialog
+    pub fn entries(&self) -> Vec<(Option<&str>, &JmesExpression)> {
+        self.saved
+            .iter()
+            .map(|s| (Some(s.name.as_str()), &s.expression))
+            .chain(self.recent.iter().map(|e| (None, e)))
+            .collect()
+    }
+
+    /// Remove the entry at `index` of `entries()`
+    pub fn remove(&mut self, index: usize) {
+        if index < self.saved.len() {
+            self.saved.remove(index);
+        } else if index - self.saved.len() < self.recent.len() {
+            self.recent.remove(index - self.saved.len());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_save_and_remove() {
+        let mut history = JmesHistory::default();
+        history.record(JmesExpression::Transform("{a: a}".to_string()));
+        history.record(JmesExpression::Transform("{b: b}".to_string()));
+        history.record(JmesExpression::Transform("{a: a}".to_string()));
+        history.record(JmesExpression::Transform("  ".to_string()));
+        assert_eq!(history.recent, vec![
+            JmesExpression::Transform("{a: a}".to_string()),
+            JmesExpression::Transform("{b: b}".to_string()),
+        ]);
+        for i in 0..MAX_RECENT {
+            history.record(JmesExpression::Transform(format!("n{i}")));
+        }
+        assert_eq!(history.recent.len(), MAX_RECENT);
+
+        let pairs = vec![JmesPathKeyValuePair { name: "user".to_string(), value: "payload\n.user".to_string() }];
+        history.save("users".to_string(), JmesExpression::AddColumns(pairs.clone()));
+        history.save("users".to_string(), JmesExpression::AddColumns(pairs));
+        assert_eq!(history.saved.len(), 1);
+        let entries = history.entries();
+        assert_eq!(entries[0].0, Some("users"));
+        assert_eq!(entries[0].1.summary(), "user = payload .user");
+        assert_eq!(entries[1].1.summary(), format!("n{}", MAX_RECENT - 1));
+
+        history.remove(1);
+        assert_eq!(history.recent[0], JmesExpression::Transform(format!("n{}", MAX_RECENT - 2)));
+        history.remove(0);
+        assert!(history.saved.is_empty());
+    }
+}
@@ -5,9 +5,12 @@
 
 mod runtime;
 mod functions;
+pub mod batch;
+pub mod hints;
+pub mod history;
 
 pub use runtime::with_runtime;
-pub use functions::register_custom_functions;
+pub use functions::{register_custom_functions, CUSTOM_FUNCTIONS};
 
 /// Create a new `Runtime` with built-in functions and our custom functions registered.
 // Helper kept for callers that want a fresh instance rather than thread-local.
@@ -15,4 +15,9 @@ pub mod jmes;
 pub mod workspace;
 pub mod logging;
 pub mod sql;
-pub mod update_check;
\ No newline at end of file
+pub mod plugins;
+pub mod update_check;
+pub mod threat_intel;
+pub mod recent_files;
+pub mod load_spec;
+pub mod services;
\ No newline at end of file
@@ -0,0 +1,328 @@
+//! `--load` specs: `kind:path;key=value;...` to import configurations
+//!
+//! The kind selects the importer (`text`/`csv`/`tsv`/`psv`, `xlsx`, `sqlite`, `parquet`,
+//! `json`/`jsonl`), the path may be a glob or `STDIN`, and the options map onto the import
+//! dialogs' options. Used by `--load` and the automation server.
+
+use std::fs;
+use std::io::Read;
+use std::path::PathBuf;
+
+use glob::glob;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::data_import_types::DataImportConfig;
+use crate::dialog::csv_options_dialog::CsvImportOptions;
+use crate::dialog::json_options_dialog::JsonImportOptions;
+use crate::dialog::parquet_options_dialog::ParquetImportOptions;
+use crate::dialog::sqlite_options_dialog::SqliteImportOptions;
+use crate::dialog::xlsx_options_dialog::XlsxImportOptions;
+use crate::excel_operations::ExcelOperations;
+
+// Ensure only known option keys are present for a given kind; otherwise return an error
+fn ensure_only_allowed_keys(kind: &str, kv: &std::collections::HashMap<String, String>, allowed: &[&str]) -> color_eyre::Result<()> {
+    let mut unknown: Vec<String> = Vec::new();
+    for k in kv.keys() {
+        if !allowed.contains(&k.as_str()) {
+            unknown.push(k.clone());
+        }
+    }
+    if !unknown.is_empty() {
+        return Err(color_eyre::eyre::eyre!(format!(
+            "Unknown option(s) for {kind}: {}",
+            unknown.join(", ")
+        )));
+    }
+    Ok(())
+}
+
+/// Kinds accepted before the first ':' of a spec
+const KINDS: &[&str] = &["text", "csv", "tsv", "psv", "xlsx", "xls", "sqlite", "db", "parquet", "json", "jsonl", "ndjson"];
+
+/// Returns one or multiple DataImportConfig values for a single spec (e.g., xlsx sheets can expand).
+pub fn parse_load_spec(spec: &str) -> color_eyre::Result<Vec<DataImportConfig>> {
+    // Split on the first ':' into kind and the rest
+    let (kind_raw, rest) = spec
+        .split_once(':')
+        .ok_or_else(|| color_eyre::eyre::eyre!("Expected 'kind:path[;key=value...]'"))?;
+    let kind = kind_raw.trim().to_ascii_lowercase();
+
+    // Path is first segment before ';', options follow as key=value pairs separated by ';'
+    let mut parts = rest.split(';');
+    let mut path = parts.next().unwrap_or("").trim().to_string();
+    let mut kv: std::collections::HashMap<String, String> = std::collections::HashMap::new();
+    for seg in parts {
+        if seg.trim().is_empty() { continue; }
+        if let Some((k, v)) = seg.split_once('=') {
+            kv.insert(k.trim().to_ascii_lowercase(), v.trim().to_string());
+        } else {
+            // bare flags like 'all_sheets' => true
+            kv.insert(seg.trim().to_ascii_lowercase(), "true".to_string());
+        }
+    }
+
+    // Handle STDIN magic path for supported kinds
+    if path.eq_ignore_ascii_case("stdin") || path == "-" {
+        path = write_stdin_to_temp_file(&kind, &kv)?;
+    }
+
+    // Expand wildcards (glob). If none, returns the original path.
+    let paths = expand_glob_paths(&path)?;
+
+    match kind.as_str() {
+        // Text/CSV-like
+        "text" | "csv" | "tsv" | "psv" => {
+            ensure_only_allowed_keys(
+                &kind,
+                &kv,
+                &[
+                    "delim",
+                    "delimiter",
+                    "header",
+                    "has_header",
+                    "quote",
+                    "quote_char",
+                    "escape",
+                    "escape_char",
+                    "merge",
+                ],
+            )?;
+            let mut out = Vec::new();
+            let merge = kv.get("merge").map(|v| parse_bool(v)).unwrap_or(false);
+            if !paths.is_empty() {
+                // First path becomes primary; rest go into additional_paths and merge flag.
+                let mut primary: Option<DataImportConfig> = None;
+                let mut extras: Vec<PathBuf> = Vec::new();
+                for (idx, pb) in paths.iter().enumerate() {
+                    let mut opts = CsvImportOptions::default();
+                    // Kind shortcuts for delimiter
+                    if kind == "tsv" { opts.delimiter = '\t'; }
+                    if kind == "psv" { opts.delimiter = '|'; }
+                    if kind == "csv" { opts.delimiter = ','; }
+                    // Guess from extension if not overridden
+                    if let Some(ext) = pb.extension()
+                            .and_then(|e| e.to_str())
+                            .map(|s| s.to_ascii_lowercase()
+                    )
+                        && opts.delimiter == ',' {
+                            if ext == "tsv" { 
+                                opts.delimiter = '\t';
+                            } else if ext == "psv" { 
+                                opts.delimiter = '|'; 
+                            }
+                        }
+                    if let Some(v) = kv.get("delim").or_else(|| kv.get("delimiter")) {
+                        opts.delimiter = parse_delimiter(v)?;
+                    }
+                    if let Some(v) = kv.get("header").or_else(|| kv.get("has_header")) {
+                        opts.has_header = parse_bool(v);
+                    }
+                    if let Some(v) = kv.get("quote").or_else(|| kv.get("quote_char")) {
+                        opts.quote_char = parse_char_opt(v);
+                    }
+                    if let Some(v) = kv.get("escape").or_else(|| kv.get("escape_char")) {
+                        opts.escape_char = parse_char_opt(v);
+                    }
+                    if idx == 0 {
+                        let mut cfg = DataImportConfig::text(pb.clone(), opts);
+                        if let DataImportConfig::Text(ref mut t) = cfg {
+                            t.merge = merge;
+                        }
+                        primary = Some(cfg);
+                    } else {
+                        extras.push(pb.clone());
+                    }
+                }
+                if let Some(mut cfg) = primary {
+                    if let DataImportConfig::Text(ref mut t) = cfg {
+                        t.additional_paths = extras;
+                    }
+                    out.push(cfg);
+                }
+            }
+            Ok(out)
+        }
+        // Excel
+        "xlsx" | "xls" => {
+            ensure_only_allowed_keys(&kind, &kv, &["all_sheets", "sheets", "sheet"]) ?;
+            let mut out = Vec::new();
+            for pb in paths {
+                // Default: load worksheet info and mark all load=true
+                let mut worksheets = ExcelOperations::read_worksheet_info(&pb).unwrap_or_default();
+                // Filters
+                let all = kv.get("all_sheets").map(|v| parse_bool(v)).unwrap_or(true);
+                if !all
+                    && let Some(names) = kv.get("sheets").or_else(|| kv.get("sheet")) {
+                        let set: std::collections::HashSet<String> = names.split(',').map(|s| s.trim().to_string()).collect();
+                        for ws in &mut worksheets { ws.load = set.contains(&ws.name); }
+                        worksheets.retain(|w| w.load);
+                    }
+                let opts = XlsxImportOptions { worksheets };
+                out.push(DataImportConfig::excel(pb, opts));
+            }
+            Ok(out)
+        }
+        // SQLite
+        "sqlite" | "db" => {
+            ensure_only_allowed_keys(&kind, &kv, &["import_all_tables", "table", "tables"]) ?;
+            let mut out = Vec::new();
+            for pb in paths {
+                let mut opts = SqliteImportOptions::default();
+                if let Some(v) = kv.get("import_all_tables") { opts.import_all_tables = parse_bool(v); }
+                if let Some(t) = kv.get("table") { opts.import_all_tables = false; opts.selected_tables = vec![t.to_string()]; }
+                if let Some(ts) = kv.get("tables") { opts.import_all_tables = false; opts.selected_tables = ts.split(',').map(|s| s.trim().to_string()).collect(); }
+                out.push(DataImportConfig::sqlite(pb, opts));
+            }
+            Ok(out)
+        }
+        // Parquet
+        "parquet" => {
+            ensure_only_allowed_keys(&kind, &kv, &[])?;
+            let mut out = Vec::new();
+            for pb in paths {
+                let opts = ParquetImportOptions::default();
+                out.push(DataImportConfig::parquet(pb, opts));
+            }
+            Ok(out)
+        }
+        // JSON / NDJSON
+        "json" | "jsonl" | "ndjson" => {
+            ensure_only_allowed_keys(&kind, &kv, &["ndjson", "records", "merge"]) ?;
+            let mut out = Vec::new();
+            let merge = kv.get("merge").map(|v| parse_bool(v)).unwrap_or(false);
+            if !paths.is_empty() {
+                let mut primary: Option<DataImportConfig> = None;
+                let mut extras: Vec<PathBuf> = Vec::new();
+                for (idx, pb) in paths.iter().enumerate() {
+                    let mut opts = JsonImportOptions::default();
+                    if kind == "jsonl" || kind == "ndjson" { opts.ndjson = true; }
+                    if let Some(v) = kv.get("ndjson") { opts.ndjson = parse_bool(v); }
+                    if let Some(expr) = kv.get("records") { opts.records_expr = expr.to_string(); }
+                    if idx == 0 {
+                        let mut cfg = DataImportConfig::json(pb.clone(), opts);
+                        if let DataImportConfig::Json(ref mut j) = cfg {
+                            j.merge = merge;
+                        }
+                        primary = Some(cfg);
+                    } else {
+                        extras.push(pb.clone());
+                    }
+                }
+                if let Some(mut cfg) = primary {
+                    if let DataImportConfig::Json(ref mut j) = cfg {
+                        j.additional_paths = extras;
+                    }
+                    out.push(cfg);
+                }
+            }
+            Ok(out)
+        }
+        other => Err(color_eyre::eyre::eyre!(format!("Unknown load kind '{other}'")))
+    }
+}
+
+/// Load kind for a file extension, for inputs given as a bare path
+fn kind_for_extension(path: &str) -> Option<&'static str> {
+    let ext = std::path::Path::new(path).extension()?.to_str()?.to_ascii_lowercase();
+    Some(match ext.as_str() {
+        "csv" | "txt" => "csv",
+        "tsv" => "tsv",
+        "psv" => "psv",
+        "xlsx" | "xls" => "xlsx",
+        "sqlite" | "sqlite3" | "db" => "sqlite",
+        "parquet" => "parquet",
+        "json" => "json",
+        "jsonl" | "ndjson" => "jsonl",
+        _ => return None,
+    })
+}
+
+/// Like `parse_load_spec`, but also accepts a bare file path whose kind follows from its
+/// extension, e.g. `logs.csv` for `csv:logs.csv`
+pub fn parse_input(input: &str) -> color_eyre::Result<Vec<DataImportConfig>> {
+    let has_kind = input
+        .split_once(':')
+        .is_some_and(|(kind, _)| KINDS.contains(&kind.trim().to_ascii_lowercase().as_str()));
+    if has_kind {
+        return parse_load_spec(input);
+    }
+    let path = input.split(';').next().unwrap_or_default();
+    let kind = kind_for_extension(path)
+        .ok_or_else(|| color_eyre::eyre::eyre!("Cannot tell the format of '{input}'; use a kind:path spec"))?;
+    parse_load_spec(&format!("{kind}:{input}"))
+}
+
+fn parse_bool(v: &str) -> bool {
+    matches!(v.to_ascii_lowercase().as_str(), "1" | "true" | "yes" | "on")
+}
+
+fn parse_char_opt(v: &str) -> Option<char> {
+    if v.eq_ignore_ascii_case("none") || v.is_empty() { return None; }
+    if v.starts_with("char:") {
+        return v.chars().nth(5);
+    }
+    let unquoted = v.trim_matches('\'').trim_matches('"');
+    unquoted.chars().next()
+}
+
+fn parse_delimiter(v: &str) -> color_eyre::Result<char> {
+    let s = v.to_ascii_lowercase();
+    Ok(match s.as_str() {
+        "," | "comma" => ',',
+        "\t" | "tab" => '\t',
+        "|" | "pipe" | "psv" => '|',
+        "space" => ' ',
+        _ if s.starts_with("char:") => s.chars().nth(5).ok_or_else(|| color_eyre::eyre::eyre!("Missing char after 'char:'"))?,
+        _ => s.chars().next().ok_or_else(|| color_eyre::eyre::eyre!("Invalid delimiter"))?,
+    })
+}
+
+// If path is STDIN/-: read stdin bytes and write to a temp file with an extension based on kind.
+fn write_stdin_to_temp_file(kind: &str, kv: &std::collections::HashMap<String, String>) -> color_eyre::Result<String> {
+    let mut stdin = std::io::stdin();
+    // If attached to terminal, we will still block waiting for input; it's the user's responsibility.
+    let mut buf: Vec<u8> = Vec::with_capacity(1024 * 64);
+    stdin.read_to_end(&mut buf)?;
+    let ext = match kind {
+        "text" | "csv" | "tsv" | "psv" => {
+            match kv.get("delim").map(|s| s.as_str()) {
+                Some("tab") => "tsv",
+                Some("psv") | Some("pipe") => "psv",
+                _ => "csv",
+            }
+        }
+        "json" | "jsonl" | "ndjson" => {
+            if kv.get("ndjson").map(|v| parse_bool(v)).unwrap_or(kind == "jsonl" || kind == "ndjson") { "jsonl" } else { "json" }
+        }
+        "parquet" => "parquet",
+        "xlsx" | "xls" => "xlsx",
+        "sqlite" | "db" => "sqlite",
+        _ => "dat",
+    };
+    let tmp = std::env::temp_dir().join(format!("datatui_stdin_{}.{}", Uuid::new_v4(), ext));
+    fs::write(&tmp, buf)?;
+    Ok(tmp.to_string_lossy().to_string())
+}
+
+// Expand a potential glob into concrete paths. If no wildcard is present or expansion
+// yields no matches, fall back to the original path as a single entry.
+fn expand_glob_paths(input: &str) -> color_eyre::Result<Vec<PathBuf>> {
+    let has_wildcards = input.contains('*') || input.contains('?') || input.contains('[');
+    if !has_wildcards {
+        return Ok(vec![PathBuf::from(input)]);
+    }
+    let mut out = Vec::new();
+    for entry in glob(input).map_err(|e| color_eyre::eyre::eyre!(format!("Invalid glob pattern '{input}': {e}")))? {
+        match entry {
+            Ok(p) => out.push(p),
+            Err(e) => error!("Glob error on '{input}': {e}"),
+        }
+    }
+    if out.is_empty() {
+        // If nothing matched, treat as literal to avoid surprising drops
+        Ok(vec![PathBuf::from(input)])
+    } else {
+        Ok(out)
+    }
+}
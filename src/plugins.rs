@@ -0,0 +1,303 @@
+//! WASM plugins: SQL functions and column operations loaded from `.wasm` modules
+//!
+//! Plugins are loaded from the plugin directory (`plugin_dir` in the config) at startup. Each
+//! module is named after its file stem and must export:
+//!
+//! - `memory`, its linear memory
+//! - `alloc(len: i32) -> i32`, returning a buffer of `len` bytes for the input value
+//!
+//! and any number of functions with the signature `(ptr: i32, len: i32) -> i64`:
+//!
+//! - `udf_<name>` becomes the SQL function `<name>(value)`
+//! - `op_<name>` becomes the column operation `<plugin>/<name>`
+//!
+//! Each function is called once per non-null value with the value as UTF-8 text and returns
+//! its result as `(ptr << 32) | len`, pointing at UTF-8 text in its memory, or a negative
+//! number for null. Results are String columns. Modules cannot import anything from the host,
+//! and each call runs on a fuel budget of `FUEL_PER_CALL` so a plugin that never returns fails
+//! instead of hanging the app.
+
+use std::path::Path;
+use std::sync::{Arc, Mutex, RwLock};
+
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use lazy_static::lazy_static;
+use polars::prelude::*;
+use polars_plan::dsl::GetOutput;
+use polars_plan::dsl::udf::UserDefinedFunction;
+use polars_sql::SQLContext;
+use tracing::warn;
+use wasmi::{Config, Engine, ExternType, Instance, Linker, Memory, Module, Store, TrapCode, TypedFunc, ValType};
+
+/// Export prefix of SQL functions
+pub const UDF_PREFIX: &str = "udf_";
+/// Export prefix of column operations
+pub const OPERATION_PREFIX: &str = "op_";
+/// Fuel (roughly, executed instructions) given to starting a module and to each call
+pub const FUEL_PER_CALL: u64 = 10_000_000;
+
+lazy_static! {
+    static ref PLUGINS: RwLock<Vec<Arc<Mutex<Plugin>>>> = RwLock::new(Vec::new());
+}
+
+/// One instantiated plugin module
+pub struct Plugin {
+    pub name: String,
+    /// SQL function names, without the export prefix
+    pub udfs: Vec<String>,
+    /// Column operation names, without the export prefix
+    pub operations: Vec<String>,
+    store: Store<()>,
+    instance: Instance,
+    memory: Memory,
+    alloc: TypedFunc<i32, i32>,
+}
+
+impl std::fmt::Debug for Plugin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Plugin")
+            .field("name", &self.name)
+            .field("udfs", &self.udfs)
+            .field("operations", &self.operations)
+            .finish()
+    }
+}
+
+impl Plugin {
+    /// Compile and instantiate a `.wasm` module
+    pub fn from_bytes(name: impl Into<String>, bytes: &[u8]) -> Result<Self> {
+        let name = name.into();
+        let engine = Engine::new(Config::default().consume_fuel(true));
+        let module = Module::new(&engine, bytes).map_err(|e| eyre!("Invalid plugin '{name}': {e}"))?;
+        let mut udfs = Vec::new();
+        let mut operations = Vec::new();
+        for export in module.exports() {
+            let ExternType::Func(ty) = export.ty() else { continue };
+            let value_fn = ty.params() == [ValType::I32, ValType::I32] && ty.results() == [ValType::I64];
+            if let Some(udf) = export.name().strip_prefix(UDF_PREFIX) {
+                if !value_fn {
+                    return Err(eyre!("Plugin '{name}': {} must take (i32, i32) and return i64", export.name()));
+                }
+                udfs.push(udf.to_string());
+            } else if let Some(op) = export.name().strip_prefix(OPERATION_PREFIX) {
+                if !value_fn {
+                    return Err(eyre!("Plugin '{name}': {} must take (i32, i32) and return i64", export.name()));
+                }
+                operations.push(op.to_string());
+            }
+        }
+        let mut store = Store::new(&engine, ());
+        store.set_fuel(FUEL_PER_CALL).map_err(|e| eyre!("Plugin '{name}': {e}"))?;
+        let instance = Linker::<()>::new(&engine)
+            .instantiate_and_start(&mut store, &module)
+            .map_err(|e| eyre!("Failed to start plugin '{name}': {e}"))?;
+        let memory = instance
+            .get_memory(&store, "memory")
+            .ok_or_else(|| eyre!("Plugin '{name}' does not export its memory"))?;
+        let alloc = instance
+            .get_typed_func::<i32, i32>(&store, "alloc")
+            .map_err(|e| eyre!("Plugin '{name}' has no alloc(i32) -> i32 export: {e}"))?;
+        Ok(Self { name, udfs, operations, store, instance, memory, alloc })
+    }
+
+    /// Call the value function `export` on `input`
+    fn call(&mut self, export: &str, input: &str) -> Result<Option<String>> {
+        let func = self
+            .instance
+            .get_typed_func::<(i32, i32), i64>(&self.store, export)
+            .map_err(|e| eyre!("{}: {e}", self.name))?;
+        let len = i32::try_from(input.len()).map_err(|_| eyre!("Value too large for plugin '{}'", self.name))?;
+        self.store.set_fuel(FUEL_PER_CALL).map_err(|e| eyre!("{}: {e}", self.name))?;
+        let ptr = self.alloc.call(&mut self.store, len).map_err(|e| self.call_error("alloc", e))?;
+        self.memory
+            .write(&mut self.store, ptr as u32 as usize, input.as_bytes())
+            .map_err(|e| eyre!("{}: alloc returned an invalid buffer: {e}", self.name))?;
+        let packed = func.call(&mut self.store, (ptr, len)).map_err(|e| self.call_error(export, e))?;
+        if packed < 0 {
+            return Ok(None);
+        }
+        let (out_ptr, out_len) = ((packed >> 32) as usize, (packed & 0xffff_ffff) as usize);
+        let mut out = vec![0u8; out_len];
+        self.memory
+            .read(&self.store, out_ptr, &mut out)
+            .map_err(|e| eyre!("{}.{export} returned an invalid result: {e}", self.name))?;
+        String::from_utf8(out).map(Some).map_err(|_| eyre!("{}.{export} returned invalid UTF-8", self.name))
+    }
+
+    fn call_error(&self, export: &str, e: wasmi::Error) -> color_eyre::Report {
+        if e.as_trap_code() == Some(TrapCode::OutOfFuel) {
+            eyre!("{}.{export} ran out of fuel: it did not return within {FUEL_PER_CALL} instructions", self.name)
+        } else {
+            eyre!("{}.{export}: {e}", self.name)
+        }
+    }
+
+    /// Apply the value function `export` to every value of `column`, cast to text
+    pub fn map_column(&mut self, export: &str, column: &Column, name: PlSmallStr) -> Result<Column> {
+        let text = column.cast(&DataType::String)?;
+        let values = text
+            .str()?
+            .into_iter()
+            .map(|v| v.map(|s| self.call(export, s)).transpose().map(Option::flatten))
+            .collect::<Result<Vec<Option<String>>>>()?;
+        Ok(Column::new(name, values))
+    }
+}
+
+/// Load a plugin, replacing any loaded plugin with the same name
+pub fn register(plugin: Plugin) {
+    let mut plugins = PLUGINS.write().expect("PLUGINS poisoned");
+    plugins.retain(|p| p.lock().map(|p| p.name != plugin.name).unwrap_or(true));
+    plugins.push(Arc::new(Mutex::new(plugin)));
+}
+
+/// Load every `.wasm` file in `dir`. Returns the names of the loaded plugins; files that fail
+/// to load are logged and skipped. A missing directory loads nothing.
+pub fn load_dir(dir: &Path) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(dir) else { return Vec::new() };
+    let mut paths: Vec<_> = entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("wasm")))
+        .collect();
+    paths.sort();
+    let mut loaded = Vec::new();
+    for path in paths {
+        let name = path.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+        match std::fs::read(&path).map_err(Into::into).and_then(|bytes| Plugin::from_bytes(name.clone(), &bytes)) {
+            Ok(plugin) => {
+                register(plugin);
+                loaded.push(name);
+            }
+            Err(e) => warn!("Skipping plugin {}: {e}", path.display()),
+        }
+    }
+    loaded
+}
+
+fn find(name: &str) -> Option<Arc<Mutex<Plugin>>> {
+    let plugins = PLUGINS.read().expect("PLUGINS poisoned");
+    plugins.iter().find(|p| p.lock().is_ok_and(|p| p.name == name)).cloned()
+}
+
+/// Loaded column operations as (plugin, operation) pairs
+pub fn operations() -> Vec<(String, String)> {
+    let plugins = PLUGINS.read().expect("PLUGINS poisoned");
+    plugins
+        .iter()
+        .filter_map(|p| p.lock().ok().map(|p| p.operations.iter().map(|op| (p.name.clone(), op.clone())).collect::<Vec<_>>()))
+        .flatten()
+        .collect()
+}
+
+/// Run the column operation `operation` of `plugin` over `column`
+pub fn apply_operation(plugin: &str, operation: &str, column: &Column, name: &str) -> Result<Column> {
+    let handle = find(plugin).ok_or_else(|| eyre!("Plugin '{plugin}' is not loaded"))?;
+    let mut plugin = handle.lock().map_err(|_| eyre!("Plugin '{plugin}' panicked earlier"))?;
+    if !plugin.operations.iter().any(|op| op == operation) {
+        return Err(eyre!("Plugin '{}' has no operation '{operation}'", plugin.name));
+    }
+    plugin.map_column(&format!("{OPERATION_PREFIX}{operation}"), column, PlSmallStr::from(name))
+}
+
+/// Register the SQL functions of every loaded plugin
+pub fn register_udfs(ctx: &mut SQLContext) -> PolarsResult<()> {
+    let plugins = PLUGINS.read().expect("PLUGINS poisoned").clone();
+    for handle in plugins {
+        let udfs = handle.lock().map(|p| p.udfs.clone()).unwrap_or_default();
+        for udf in udfs {
+            let export = format!("{UDF_PREFIX}{udf}");
+            let handle = handle.clone();
+            let function = move |columns: &mut [Column]| -> PolarsResult<Option<Column>> {
+                let [column] = columns else {
+                    return Err(PolarsError::ComputeError("plugin functions take exactly one argument".into()));
+                };
+                let mut plugin = handle
+                    .lock()
+                    .map_err(|_| PolarsError::ComputeError("plugin panicked earlier".into()))?;
+                plugin
+                    .map_column(&export, column, column.name().clone())
+                    .map(Some)
+                    .map_err(|e| PolarsError::ComputeError(e.to_string().into()))
+            };
+            let udf_fn = UserDefinedFunction::new(udf.as_str().into(), GetOutput::from_type(DataType::String), function);
+            ctx.registry_mut().register(&udf, udf_fn)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sql::{new_sql_context, register_all};
+
+    /// Reverses its input for `udf_reverse` and `op_reverse`; returns null for "null"
+    const REVERSE: &str = r#"
+        (module
+          (memory (export "memory") 1)
+          (global $next (mut i32) (i32.const 1024))
+          (func (export "alloc") (param $len i32) (result i32)
+            (local $ptr i32)
+            (local.set $ptr (global.get $next))
+            (global.set $next (i32.add (global.get $next) (local.get $len)))
+            (local.get $ptr))
+          (func $reverse (param $ptr i32) (param $len i32) (result i64)
+            (local $out i32) (local $i i32)
+            (if (i32.and (i32.eq (local.get $len) (i32.const 4))
+                         (i32.eq (i32.load (local.get $ptr)) (i32.const 0x6c6c756e)))
+              (then (return (i64.const -1))))
+            (local.set $out (global.get $next))
+            (global.set $next (i32.add (global.get $next) (local.get $len)))
+            (block $done
+              (loop $copy
+                (br_if $done (i32.ge_u (local.get $i) (local.get $len)))
+                (i32.store8
+                  (i32.add (local.get $out) (local.get $i))
+                  (i32.load8_u (i32.sub (i32.add (local.get $ptr) (local.get $len))
+                                        (i32.add (local.get $i) (i32.const 1)))))
+                (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                (br $copy)))
+            (i64.or (i64.shl (i64.extend_i32_u (local.get $out)) (i64.const 32))
+                    (i64.extend_i32_u (local.get $len))))
+          (export "udf_reverse" (func $reverse))
+          (export "op_reverse" (func $reverse)))
+    "#;
+
+    #[test]
+    fn test_plugin_udf_and_operation() {
+        let plugin = Plugin::from_bytes("text_tools", &wat::parse_str(REVERSE).unwrap()).unwrap();
+        assert_eq!((plugin.udfs.as_slice(), plugin.operations.as_slice()), (["reverse".to_string()].as_slice(), ["reverse".to_string()].as_slice()));
+        register(plugin);
+        assert!(operations().contains(&("text_tools".to_string(), "reverse".to_string())));
+
+        let column = Column::new("host".into(), [Some("web-1"), None, Some("null")]);
+        let reversed = apply_operation("text_tools", "reverse", &column, "rev").unwrap();
+        assert_eq!(reversed.name().as_str(), "rev");
+        assert_eq!(reversed.get(0).unwrap().str_value(), "1-bew");
+        assert!(reversed.get(1).unwrap().is_null() && reversed.get(2).unwrap().is_null());
+        assert!(apply_operation("text_tools", "missing", &column, "rev").is_err());
+
+        let mut ctx = new_sql_context();
+        register_all(&mut ctx).unwrap();
+        ctx.register("hosts", df!("host" => ["db-2"]).unwrap().lazy());
+        let out = ctx.execute("SELECT reverse(host) AS r FROM hosts").unwrap().collect().unwrap();
+        assert_eq!(out.column("r").unwrap().get(0).unwrap().str_value(), "2-bd");
+    }
+
+    #[test]
+    fn test_plugin_that_never_returns_runs_out_of_fuel() {
+        const SPIN: &str = r#"
+            (module
+              (memory (export "memory") 1)
+              (func (export "alloc") (param i32) (result i32) (i32.const 1024))
+              (func (export "op_spin") (param i32 i32) (result i64)
+                (loop $forever (br $forever))
+                (i64.const -1)))
+        "#;
+        let mut plugin = Plugin::from_bytes("spinner", &wat::parse_str(SPIN).unwrap()).unwrap();
+        let column = Column::new("host".into(), ["web-1"]);
+        let err = plugin.map_column("op_spin", &column, "out".into()).unwrap_err();
+        assert!(err.to_string().contains("ran out of fuel"), "{err}");
+    }
+}
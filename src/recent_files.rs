@@ -0,0 +1,156 @@
+//! Most recently used data files and projects, for quick re-opening
+//!
+//! The list is kept in ~/.datatui-recent.json next to the other user settings. Data files are
+//! stored with the import options they were opened with, so re-opening skips the import dialogs.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use color_eyre::Result;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::config::get_config_dir;
+use crate::data_import_types::DataImportConfig;
+
+/// Number of entries kept in the list
+pub const MAX_RECENT: usize = 30;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RecentKind {
+    /// A data file and the options it was imported with
+    Import(DataImportConfig),
+    /// A workspace folder
+    Project,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecentEntry {
+    pub path: PathBuf,
+    pub kind: RecentKind,
+    pub opened_at: DateTime<Utc>,
+}
+
+impl RecentEntry {
+    pub fn kind_name(&self) -> &'static str {
+        match &self.kind {
+            RecentKind::Import(config) => config.import_type_name(),
+            RecentKind::Project => "Project",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecentFiles {
+    pub entries: Vec<RecentEntry>,
+}
+
+impl RecentFiles {
+    pub fn default_path() -> PathBuf {
+        get_config_dir().join(".datatui-recent.json")
+    }
+
+    /// Load the list from `path`; a missing or unreadable file gives an empty list
+    pub fn load_from(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn load() -> Self {
+        Self::load_from(&Self::default_path())
+    }
+
+    /// Move `entry` to the front, replacing an older entry for the same path and kind
+    pub fn push(&mut self, entry: RecentEntry) {
+        let is_project = entry.kind == RecentKind::Project;
+        self.entries
+            .retain(|e| !(e.path == entry.path && (e.kind == RecentKind::Project) == is_project));
+        self.entries.insert(0, entry);
+        self.entries.truncate(MAX_RECENT);
+    }
+
+    /// Record an entry in the saved list; failures are only logged
+    fn remember(entry: RecentEntry) {
+        let path = Self::default_path();
+        let mut recent = Self::load_from(&path);
+        recent.push(entry);
+        if let Err(e) = recent.save_to(&path) {
+            warn!("Failed to save recent files: {}", e);
+        }
+    }
+
+    pub fn remember_import(config: &DataImportConfig) {
+        Self::remember(RecentEntry {
+            path: config.file_path().clone(),
+            kind: RecentKind::Import(config.clone()),
+            opened_at: Utc::now(),
+        });
+    }
+
+    pub fn remember_project(path: &Path) {
+        Self::remember(RecentEntry { path: path.to_path_buf(), kind: RecentKind::Project, opened_at: Utc::now() });
+    }
+}
+
+/// Score `candidate` against a fuzzy `query`: every query character must appear in order
+/// (ignoring case). Runs of consecutive characters and matches at the start of a path component
+/// score higher. Returns None when the query does not match.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    let candidate: Vec<char> = candidate.chars().flat_map(char::to_lowercase).collect();
+    let mut score = 0i64;
+    let mut next = 0usize;
+    let mut previous: Option<usize> = None;
+    for q in query.chars().flat_map(char::to_lowercase).filter(|c| !c.is_whitespace()) {
+        let offset = candidate[next..].iter().position(|&c| c == q)?;
+        let index = next + offset;
+        score += 1;
+        if previous.is_some_and(|p| p + 1 == index) {
+            score += 5;
+        }
+        if index == 0 || matches!(candidate[index - 1], '/' | '\\' | '_' | '-' | '.' | ' ') {
+            score += 3;
+        }
+        previous = Some(index);
+        next = index + 1;
+    }
+    // Prefer shorter candidates among equal matches
+    Some(score * 1000 - candidate.len() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialog::csv_options_dialog::CsvImportOptions;
+
+    #[test]
+    fn test_push_dedupes_and_fuzzy_score_ranks() {
+        let csv = DataImportConfig::text(PathBuf::from("/cases/a/logs.csv"), CsvImportOptions::default());
+        let entry = |path: &str, kind: RecentKind| RecentEntry { path: PathBuf::from(path), kind, opened_at: Utc::now() };
+        let mut recent = RecentFiles::default();
+        recent.push(entry("/cases/a/logs.csv", RecentKind::Import(csv.clone())));
+        recent.push(entry("/cases/a", RecentKind::Project));
+        recent.push(entry("/cases/a/logs.csv", RecentKind::Import(csv)));
+        assert_eq!(recent.entries.len(), 2);
+        assert_eq!(recent.entries[0].path, PathBuf::from("/cases/a/logs.csv"));
+
+        let path = std::env::temp_dir().join(format!("datatui_recent_{}.json", std::process::id()));
+        recent.save_to(&path).unwrap();
+        assert_eq!(RecentFiles::load_from(&path), recent);
+        let _ = std::fs::remove_file(&path);
+
+        assert!(fuzzy_score("lgcsv", "/cases/a/logs.csv").is_some());
+        assert!(fuzzy_score("xyz", "/cases/a/logs.csv").is_none());
+        assert!(fuzzy_score("logs", "/x/logs.csv") > fuzzy_score("logs", "/x/l_o_g_s.csv"));
+    }
+}
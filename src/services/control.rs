@@ -0,0 +1,363 @@
+//! ControlServer: JSON-RPC automation over a socket
+//!
+//! `datatui --control-socket <ADDR>` serves a `DataService` instead of the TUI. `ADDR` is a
+//! loopback `host:port` TCP address or, on Unix, a socket path. There is no authentication and
+//! clients can read and write any file the process can, so TCP is refused on other interfaces.
+//! Requests and responses are JSON-RPC 2.0 objects, one per line:
+//!
+//! ```text
+//! {"jsonrpc":"2.0","id":1,"method":"load-dataset","params":{"spec":"csv:logs.csv"}}
+//! {"jsonrpc":"2.0","id":1,"result":{"datasets":["logs.csv"]}}
+//! ```
+//!
+//! Methods:
+//!
+//! - `load-dataset` `{spec}` (a `--load` spec) or `{config}` (a `DataImportConfig`)
+//! - `apply-filter` `{dataset, filter, into?}`: filter is a `FilterExpr`; the result replaces
+//!   `dataset` unless `into` names another dataset
+//! - `run-sql` `{query, into?, limit?}`: stores the result as `into` (default `query`) and
+//!   returns its first `limit` rows as `data`
+//! - `export` `{dataset, path, format?}`: format is `csv`, `jsonl` or `parquet`, by default from
+//!   the path's extension
+//! - `get-state`: every dataset with its row count and schema
+//! - `shutdown`: stop the server
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, ToSocketAddrs};
+use std::path::PathBuf;
+
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use polars::prelude::*;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+
+use crate::data_import_types::DataImportConfig;
+use crate::dialog::filter_dialog::FilterExpr;
+use crate::load_spec::parse_load_spec;
+use crate::services::{DataService, ExportFormat};
+
+const PARSE_ERROR: i64 = -32700;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const OPERATION_FAILED: i64 = -32000;
+
+/// Dataset a `run-sql` result is stored as when no name is given
+pub const DEFAULT_QUERY_DATASET: &str = "query";
+
+#[derive(Deserialize)]
+struct Request {
+    #[serde(default)]
+    id: Value,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl From<color_eyre::Report> for RpcError {
+    fn from(e: color_eyre::Report) -> Self {
+        Self { code: OPERATION_FAILED, message: e.to_string() }
+    }
+}
+
+#[derive(Deserialize)]
+struct LoadParams {
+    spec: Option<String>,
+    config: Option<DataImportConfig>,
+}
+
+#[derive(Deserialize)]
+struct FilterParams {
+    dataset: String,
+    filter: FilterExpr,
+    into: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct SqlParams {
+    query: String,
+    into: Option<String>,
+    #[serde(default)]
+    limit: usize,
+}
+
+#[derive(Deserialize)]
+struct ExportParams {
+    dataset: String,
+    path: PathBuf,
+    format: Option<String>,
+}
+
+fn params<T: DeserializeOwned>(value: Value) -> Result<T, RpcError> {
+    let value = if value.is_null() { json!({}) } else { value };
+    serde_json::from_value(value).map_err(|e| RpcError { code: INVALID_PARAMS, message: e.to_string() })
+}
+
+/// Name, row count and schema of a dataset
+fn summary(name: &str, df: &DataFrame) -> Value {
+    let columns: Vec<Value> = df
+        .get_columns()
+        .iter()
+        .map(|c| json!({ "name": c.name().as_str(), "dtype": c.dtype().to_string() }))
+        .collect();
+    json!({ "name": name, "rows": df.height(), "columns": columns })
+}
+
+/// Rows of `df` as JSON objects
+fn records(df: &DataFrame) -> Result<Value> {
+    let mut df = df.clone();
+    let mut buf = Vec::new();
+    JsonWriter::new(&mut buf).with_json_format(JsonFormat::Json).finish(&mut df)?;
+    Ok(serde_json::from_slice(&buf)?)
+}
+
+/// Answers automation requests against its own `DataService`
+#[derive(Debug, Default)]
+pub struct ControlServer {
+    pub service: DataService,
+    shutdown: bool,
+}
+
+impl ControlServer {
+    pub fn new(service: DataService) -> Self {
+        Self { service, shutdown: false }
+    }
+
+    /// Whether a `shutdown` request was handled
+    pub fn is_shutdown(&self) -> bool {
+        self.shutdown
+    }
+
+    fn call(&mut self, method: &str, raw: Value) -> Result<Value, RpcError> {
+        match method {
+            "load-dataset" => {
+                let p: LoadParams = params(raw)?;
+                let configs = match (p.spec, p.config) {
+                    (Some(spec), None) => parse_load_spec(&spec)?,
+                    (None, Some(config)) => vec![config],
+                    _ => return Err(RpcError { code: INVALID_PARAMS, message: "Expected one of spec or config".into() }),
+                };
+                let mut names = Vec::new();
+                for config in configs {
+                    names.extend(self.service.import(config)?);
+                }
+                Ok(json!({ "datasets": names }))
+            }
+            "apply-filter" => {
+                let p: FilterParams = params(raw)?;
+                let df = self.service.filter(&p.dataset, &p.filter)?;
+                let name = p.into.unwrap_or(p.dataset);
+                let result = summary(&name, &df);
+                self.service.insert(name, df);
+                Ok(result)
+            }
+            "run-sql" => {
+                let p: SqlParams = params(raw)?;
+                let df = self.service.sql(&p.query)?;
+                let name = p.into.unwrap_or_else(|| DEFAULT_QUERY_DATASET.to_string());
+                let mut result = summary(&name, &df);
+                if p.limit > 0 {
+                    result["data"] = records(&df.head(Some(p.limit)))?;
+                }
+                self.service.insert(name, df);
+                Ok(result)
+            }
+            "export" => {
+                let p: ExportParams = params(raw)?;
+                let format = match &p.format {
+                    Some(name) => name.parse::<ExportFormat>()?,
+                    None => ExportFormat::from_path(&p.path)?,
+                };
+                let df = self
+                    .service
+                    .dataset(&p.dataset)
+                    .ok_or_else(|| eyre!("No dataset named '{}'", p.dataset))?;
+                let mut df = df.as_ref().clone();
+                DataService::export(&mut df, &p.path, format)?;
+                Ok(json!({ "path": p.path, "rows": df.height() }))
+            }
+            "get-state" => {
+                let datasets: Vec<Value> = self
+                    .service
+                    .names()
+                    .iter()
+                    .filter_map(|name| self.service.dataset(name).map(|df| summary(name, &df)))
+                    .collect();
+                Ok(json!({ "datasets": datasets }))
+            }
+            "shutdown" => {
+                self.shutdown = true;
+                Ok(Value::Null)
+            }
+            other => Err(RpcError { code: METHOD_NOT_FOUND, message: format!("Unknown method '{other}'") }),
+        }
+    }
+
+    /// Answer one request line with one response line
+    pub fn handle_line(&mut self, line: &str) -> String {
+        let (id, outcome) = match serde_json::from_str::<Request>(line) {
+            Ok(request) => (request.id, self.call(&request.method, request.params)),
+            Err(e) => (Value::Null, Err(RpcError { code: PARSE_ERROR, message: e.to_string() })),
+        };
+        let response = match outcome {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(e) => json!({ "jsonrpc": "2.0", "id": id, "error": { "code": e.code, "message": e.message } }),
+        };
+        response.to_string()
+    }
+
+    /// Answer requests from `reader` until it closes or a `shutdown` request arrives
+    pub fn serve_connection(&mut self, reader: impl BufRead, mut writer: impl Write) -> Result<()> {
+        for line in reader.split(b'\n') {
+            let response = match String::from_utf8(line?) {
+                Ok(line) if line.trim().is_empty() => continue,
+                Ok(line) => self.handle_line(&line),
+                Err(e) => json!({ "jsonrpc": "2.0", "id": Value::Null, "error": { "code": PARSE_ERROR, "message": e.to_string() } }).to_string(),
+            };
+            writeln!(writer, "{response}")?;
+            writer.flush()?;
+            if self.shutdown {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Listen on `address` and serve one connection at a time until shut down. A failing
+    /// connection is logged and the next one is accepted.
+    pub fn listen(&mut self, address: &str) -> Result<()> {
+        if !is_host_port(address) {
+            return self.listen_unix(address);
+        }
+        let addrs: Vec<_> = address
+            .to_socket_addrs()
+            .map_err(|e| eyre!("'{address}' is not a usable host:port address: {e}"))?
+            .collect();
+        if addrs.is_empty() || addrs.iter().any(|a| !a.ip().is_loopback()) {
+            return Err(eyre!("'{address}' is not a loopback address; the control server has no authentication, so TCP is only served on 127.0.0.1 or [::1]"));
+        }
+        let listener = TcpListener::bind(&addrs[..])?;
+        for stream in listener.incoming() {
+            let served = stream
+                .map_err(color_eyre::Report::from)
+                .and_then(|stream| self.serve_connection(BufReader::new(stream.try_clone()?), stream));
+            if let Err(e) = served {
+                tracing::warn!("Control connection failed: {e}");
+            }
+            if self.shutdown {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    fn listen_unix(&mut self, path: &str) -> Result<()> {
+        use std::os::unix::fs::FileTypeExt;
+        use std::os::unix::net::UnixListener;
+        // A socket file left by an earlier run would make bind fail; anything else is kept
+        if let Ok(meta) = std::fs::symlink_metadata(path) {
+            if !meta.file_type().is_socket() {
+                return Err(eyre!("'{path}' exists and is not a socket"));
+            }
+            std::fs::remove_file(path)?;
+        }
+        let listener = UnixListener::bind(path)?;
+        for stream in listener.incoming() {
+            let served = stream
+                .map_err(color_eyre::Report::from)
+                .and_then(|stream| self.serve_connection(BufReader::new(stream.try_clone()?), stream));
+            if let Err(e) = served {
+                tracing::warn!("Control connection failed: {e}");
+            }
+            if self.shutdown {
+                break;
+            }
+        }
+        let _ = std::fs::remove_file(path);
+        Ok(())
+    }
+
+    #[cfg(not(unix))]
+    fn listen_unix(&mut self, path: &str) -> Result<()> {
+        Err(eyre!("'{path}' is not a host:port address; socket paths need Unix"))
+    }
+}
+
+/// Whether `address` is meant as `host:port` rather than a socket path: no path separator and a
+/// numeric port after the last colon
+fn is_host_port(address: &str) -> bool {
+    !address.contains(['/', '\\'])
+        && address.rsplit_once(':').is_some_and(|(host, port)| !host.is_empty() && port.parse::<u16>().is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_filters_queries_and_exports() {
+        let dir = std::env::temp_dir().join(format!("datatui_control_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let csv = dir.join("hosts.csv");
+        std::fs::write(&csv, "host,status\nweb-1,200\nweb-2,500\ndb-1,503\n").unwrap();
+        let out = dir.join("errors.jsonl");
+
+        let requests = [
+            json!({ "jsonrpc": "2.0", "id": 1, "method": "load-dataset", "params": { "spec": format!("csv:{}", csv.display()) } }),
+            json!({ "jsonrpc": "2.0", "id": 2, "method": "apply-filter", "params": { "dataset": "hosts.csv", "into": "errors",
+                "filter": { "Condition": { "column": "status", "condition": { "GreaterThanOrEqual": { "value": "500" } } } } } }),
+            json!({ "jsonrpc": "2.0", "id": 3, "method": "run-sql", "params": { "query": "SELECT host FROM errors ORDER BY host", "limit": 1 } }),
+            json!({ "jsonrpc": "2.0", "id": 4, "method": "export", "params": { "dataset": "errors", "path": out } }),
+            json!({ "jsonrpc": "2.0", "id": 5, "method": "get-state" }),
+            json!({ "jsonrpc": "2.0", "id": 6, "method": "frobnicate" }),
+            json!({ "jsonrpc": "2.0", "id": 7, "method": "shutdown" }),
+            json!({ "jsonrpc": "2.0", "id": 8, "method": "get-state" }),
+        ];
+        let input: String = requests.iter().map(|r| format!("{r}\n")).collect();
+        let mut output = Vec::new();
+        let mut server = ControlServer::default();
+        server.serve_connection(input.as_bytes(), &mut output).unwrap();
+
+        let responses: Vec<Value> = output.split(|b| *b == b'\n').filter(|l| !l.is_empty()).map(|l| serde_json::from_slice(l).unwrap()).collect();
+        assert_eq!(responses.len(), 7, "nothing is answered after shutdown");
+        assert_eq!(responses[0]["result"]["datasets"], json!(["hosts.csv"]));
+        assert_eq!(responses[1]["result"]["rows"], 2);
+        assert_eq!(responses[2]["result"]["data"], json!([{ "host": "db-1" }]));
+        assert_eq!(std::fs::read_to_string(&out).unwrap().lines().count(), 2);
+        let names: Vec<&str> = responses[4]["result"]["datasets"].as_array().unwrap().iter().map(|d| d["name"].as_str().unwrap()).collect();
+        assert_eq!(names, ["errors", "hosts.csv", "query"]);
+        assert_eq!(responses[5]["error"]["code"], METHOD_NOT_FOUND);
+        assert!(server.is_shutdown());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_listen_refuses_files_and_remote_addresses() {
+        let dir = std::env::temp_dir().join(format!("datatui_control_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let evidence = dir.join("evidence.csv");
+        std::fs::write(&evidence, "a\n1\n").unwrap();
+
+        let mut server = ControlServer::default();
+        assert!(server.listen(&evidence.to_string_lossy()).is_err());
+        assert!(evidence.exists(), "a file that is not a socket is never removed");
+        assert!(server.listen("0.0.0.0:0").is_err());
+        assert!(is_host_port("localhost:9000"));
+        assert!(!is_host_port("evidence.csv"));
+
+        // Invalid UTF-8 is answered with a parse error and the connection carries on
+        let mut output = Vec::new();
+        server.serve_connection(&b"\xff\n{\"id\":1,\"method\":\"get-state\"}\n"[..], &mut output).unwrap();
+        let responses: Vec<Value> = output.split(|b| *b == b'\n').filter(|l| !l.is_empty()).map(|l| serde_json::from_slice(l).unwrap()).collect();
+        assert_eq!(responses[0]["error"]["code"], PARSE_ERROR);
+        assert!(responses[1]["result"]["datasets"].is_array());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
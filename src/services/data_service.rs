@@ -0,0 +1,159 @@
+//! DataService: load, filter, sort, query and export datasets without a terminal
+//!
+//! The service holds named datasets and runs the same import, filter and SQL code as the TUI.
+//! Each operation returns a new DataFrame and leaves the stored datasets unchanged.
+//!
+//! ```no_run
+//! use datatui::data_import_types::DataImportConfig;
+//! use datatui::dialog::csv_options_dialog::CsvImportOptions;
+//! use datatui::services::{DataService, ExportFormat};
+//!
+//! let mut service = DataService::new();
+//! let names = service.import(DataImportConfig::text("logs.csv".into(), CsvImportOptions::default()))?;
+//! let mut errors = service.sql(&format!("SELECT * FROM \"{}\" WHERE status >= 500", names[0]))?;
+//! DataService::export(&mut errors, "errors.parquet".as_ref(), ExportFormat::Parquet)?;
+//! # Ok::<(), color_eyre::Report>(())
+//! ```
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use polars::prelude::*;
+
+use crate::data_import_types::DataImportConfig;
+use crate::dataframe::manager::{filter_with_progress, ManagedDataFrame, SortableDataFrame};
+use crate::dialog::data_management_dialog::DataManagementDialog;
+use crate::dialog::filter_dialog::FilterExpr;
+use crate::dialog::sort_dialog::SortColumn;
+use crate::sql::{new_sql_context, register_all};
+
+/// File formats written by `DataService::export`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    /// One JSON object per line
+    JsonLines,
+    Parquet,
+}
+
+impl std::str::FromStr for ExportFormat {
+    type Err = color_eyre::Report;
+
+    /// `csv`, `jsonl` (or `ndjson`) and `parquet`, in any case
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "csv" => Ok(Self::Csv),
+            "jsonl" | "ndjson" => Ok(Self::JsonLines),
+            "parquet" => Ok(Self::Parquet),
+            other => Err(eyre!("Unknown export format '{other}'; expected csv, jsonl or parquet")),
+        }
+    }
+}
+
+impl ExportFormat {
+    /// The format matching `path`'s extension
+    pub fn from_path(path: &Path) -> Result<Self> {
+        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or_default();
+        ext.parse().map_err(|_| eyre!("Cannot tell the export format of '{}' from its extension", path.display()))
+    }
+}
+
+/// Named datasets and the operations the TUI runs on them
+#[derive(Debug, Default, Clone)]
+pub struct DataService {
+    datasets: BTreeMap<String, Arc<DataFrame>>,
+}
+
+impl DataService {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Import a file with the same options as the import dialogs. A file can hold several
+    /// datasets (Excel worksheets, SQLite tables); each is stored under its name, and the names
+    /// are returned. Any dataset that fails to load fails the whole import.
+    pub fn import(&mut self, config: DataImportConfig) -> Result<Vec<String>> {
+        let mut manager = DataManagementDialog::new();
+        manager.add_data_source(config);
+        let mut loaded = Vec::new();
+        for source in &manager.data_sources {
+            for dataset in &source.datasets {
+                let df = source
+                    .load_dataset(dataset)
+                    .map_err(|e| eyre!("Failed to load '{}': {}", dataset.name, e))?;
+                loaded.push((dataset.alias.clone().unwrap_or_else(|| dataset.name.clone()), df));
+            }
+        }
+        let names = loaded.iter().map(|(name, _)| name.clone()).collect();
+        self.datasets.extend(loaded);
+        Ok(names)
+    }
+
+    /// Store `df` under `name`, replacing any dataset with that name
+    pub fn insert(&mut self, name: impl Into<String>, df: DataFrame) {
+        self.datasets.insert(name.into(), Arc::new(df));
+    }
+
+    /// Remove a dataset; returns it if it existed
+    pub fn remove(&mut self, name: &str) -> Option<Arc<DataFrame>> {
+        self.datasets.remove(name)
+    }
+
+    pub fn dataset(&self, name: &str) -> Option<Arc<DataFrame>> {
+        self.datasets.get(name).cloned()
+    }
+
+    /// Names of the stored datasets, in sorted order
+    pub fn names(&self) -> Vec<String> {
+        self.datasets.keys().cloned().collect()
+    }
+
+    fn get(&self, name: &str) -> Result<&Arc<DataFrame>> {
+        self.datasets.get(name).ok_or_else(|| eyre!("No dataset named '{}'", name))
+    }
+
+    /// Rows of `name` matching `filter`
+    pub fn filter(&self, name: &str, filter: &FilterExpr) -> Result<DataFrame> {
+        filter_with_progress(self.get(name)?.as_ref().clone().lazy(), filter, |_| true)
+    }
+
+    /// `name` sorted by `columns`, in priority order
+    pub fn sort(&self, name: &str, columns: &[SortColumn]) -> Result<DataFrame> {
+        let mut managed = ManagedDataFrame::from_arc(self.get(name)?.clone(), name.to_string(), None, None);
+        managed.sort_by_columns(columns)?;
+        Ok(managed.get_dataframe()?.as_ref().clone())
+    }
+
+    /// Run a SQL query with every stored dataset registered as a table under its name
+    pub fn sql(&self, query: &str) -> Result<DataFrame> {
+        let mut ctx = new_sql_context();
+        register_all(&mut ctx).map_err(|e| eyre!("SQL error: {e}"))?;
+        for (name, df) in &self.datasets {
+            ctx.register(name, df.as_ref().clone().lazy());
+        }
+        let lf = ctx.execute(query).map_err(|e| eyre!("SQL error: {e}"))?;
+        lf.collect().map_err(|e| eyre!("Collect error: {e}"))
+    }
+
+    /// Write `df` to `path`
+    pub fn export(df: &mut DataFrame, path: &Path, format: ExportFormat) -> Result<()> {
+        Self::write(df, File::create(path)?, format)
+    }
+
+    /// Write `df` to any writer, e.g. stdout
+    pub fn write(df: &mut DataFrame, writer: impl Write, format: ExportFormat) -> Result<()> {
+        match format {
+            ExportFormat::Csv => CsvWriter::new(writer).include_header(true).finish(df)?,
+            ExportFormat::JsonLines => JsonWriter::new(writer).with_json_format(JsonFormat::JsonLines).finish(df)?,
+            ExportFormat::Parquet => {
+                ParquetWriter::new(writer).finish(df)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+//! Services: the data engine without the TUI, for embedding datatui in other Rust tools
+
+pub mod control;
+pub mod data_service;
+
+pub use control::ControlServer;
+pub use data_service::{DataService, ExportFormat};
@@ -0,0 +1,147 @@
+//! Locate the part of a query a SQL error refers to, and suggest names for "not found" errors
+
+/// Where in the query an error points, in characters from the start of a line (0-based)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SqlErrorLocation {
+    pub line: usize,
+    pub column: usize,
+    /// Length of the offending token
+    pub len: usize,
+}
+
+/// What could be worked out from a SQL error message
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SqlDiagnostic {
+    pub location: Option<SqlErrorLocation>,
+    /// Known table or column names close to the one that was not found
+    pub suggestions: Vec<String>,
+}
+
+/// Most suggestions listed for one missing name
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Read `error` (as returned by the SQL context) against the `query` that caused it. Parser
+/// errors carry a line and column; for missing tables, columns and functions the first use of
+/// the name in the query is located, and missing tables and columns get the closest of
+/// `tables` or `columns` as suggestions.
+pub fn diagnose(query: &str, error: &str, tables: &[String], columns: &[String]) -> SqlDiagnostic {
+    if let Some(location) = parser_location(query, error) {
+        return SqlDiagnostic { location: Some(location), suggestions: Vec::new() };
+    }
+    let (name, candidates) = if let Some(table) = quoted_after(error, "relation '", '\'') {
+        (table, tables)
+    } else if let Some(column) = quoted_after(error, "unable to find column \"", '"') {
+        (column, columns)
+    } else if let Some(function) = quoted_after(error, "unsupported function '", '\'') {
+        (function, &[][..])
+    } else if let Some(rest) = error.split("not found: ").nth(1) {
+        (rest.split(['\n', ';']).next().unwrap_or_default().trim().to_string(), columns)
+    } else {
+        return SqlDiagnostic::default();
+    };
+    if name.is_empty() {
+        return SqlDiagnostic::default();
+    }
+    SqlDiagnostic { location: find_identifier(query, &name), suggestions: closest_names(&name, candidates) }
+}
+
+/// `Line: N, Column: M` from a parser error, widened to the token found there
+fn parser_location(query: &str, error: &str) -> Option<SqlErrorLocation> {
+    let rest = error.split("Line: ").nth(1)?;
+    let (line, rest) = rest.split_once(", Column: ")?;
+    let line: usize = line.trim().parse().ok()?;
+    let column: usize = rest.chars().take_while(char::is_ascii_digit).collect::<String>().parse().ok()?;
+    let (line, column) = (line.checked_sub(1)?, column.checked_sub(1)?);
+    let text: Vec<char> = query.lines().nth(line)?.chars().collect();
+    let len = text.iter().skip(column).take_while(|c| !c.is_whitespace()).count().max(1);
+    Some(SqlErrorLocation { line, column: column.min(text.len()), len })
+}
+
+/// Text between `prefix` and the next `close` in `error`
+fn quoted_after(error: &str, prefix: &str, close: char) -> Option<String> {
+    let rest = error.split(prefix).nth(1)?;
+    Some(rest.split(close).next()?.to_string())
+}
+
+/// First use of `name` as a whole identifier, exact case first and then ignoring case
+fn find_identifier(query: &str, name: &str) -> Option<SqlErrorLocation> {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    let name_len = name.chars().count();
+    for ignore_case in [false, true] {
+        for (line, text) in query.lines().enumerate() {
+            let chars: Vec<char> = text.chars().collect();
+            for column in 0..chars.len() {
+                let Some(candidate) = chars.get(column..column + name_len) else { break };
+                let candidate: String = candidate.iter().collect();
+                let matches = if ignore_case { candidate.eq_ignore_ascii_case(name) } else { candidate == name };
+                let bounded = (column == 0 || !is_ident(chars[column - 1]))
+                    && chars.get(column + name_len).is_none_or(|&c| !is_ident(c));
+                if matches && bounded {
+                    return Some(SqlErrorLocation { line, column, len: name_len });
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Known names within a few edits of `name`, closest first
+fn closest_names(name: &str, candidates: &[String]) -> Vec<String> {
+    let lowered = name.to_lowercase();
+    let max_distance = (name.chars().count() / 3).max(2);
+    let mut scored: Vec<(usize, &String)> = candidates
+        .iter()
+        .map(|candidate| (edit_distance(&lowered, &candidate.to_lowercase()), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+    scored.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(b.1)));
+    scored.dedup_by(|a, b| a.1 == b.1);
+    scored.into_iter().take(MAX_SUGGESTIONS).map(|(_, candidate)| candidate.clone()).collect()
+}
+
+/// Levenshtein distance in characters
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diagnose_polars_sql_errors() {
+        let tables = vec!["events".to_string(), "hosts".to_string()];
+        let columns = vec!["host".to_string(), "status".to_string(), "bytes".to_string()];
+
+        let query = "SELECT host FORM events";
+        let error = "sql parser error: Expected: end of statement, found: events at Line: 1, Column: 18";
+        assert_eq!(diagnose(query, error, &tables, &columns).location, Some(SqlErrorLocation { line: 0, column: 17, len: 6 }));
+
+        let query = "SELECT host,\n  bytess\nFROM events";
+        let diagnostic = diagnose(query, "not found: bytess", &tables, &columns);
+        assert_eq!(diagnostic.location, Some(SqlErrorLocation { line: 1, column: 2, len: 6 }));
+        assert_eq!(diagnostic.suggestions, vec!["bytes".to_string()]);
+
+        let query = "SELECT * FROM events WHERE hots = 'a'";
+        let error = "not found: unable to find column \"hots\"; valid columns: [\"host\", \"status\", \"bytes\"]";
+        let diagnostic = diagnose(query, error, &tables, &columns);
+        assert_eq!(diagnostic.location, Some(SqlErrorLocation { line: 0, column: 27, len: 4 }));
+        assert_eq!(diagnostic.suggestions, vec!["host".to_string()]);
+
+        let diagnostic = diagnose("SELECT host FROM evnts", "relation 'evnts' was not found", &tables, &columns);
+        assert_eq!(diagnostic.location, Some(SqlErrorLocation { line: 0, column: 17, len: 5 }));
+        assert_eq!(diagnostic.suggestions, vec!["events".to_string()]);
+
+        assert_eq!(diagnose("SELECT 1", "something else went wrong", &tables, &columns), SqlDiagnostic::default());
+    }
+}
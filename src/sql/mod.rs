@@ -1,3 +1,6 @@
+pub mod diagnostics;
+pub mod session;
+
 use polars::prelude::*;
 use polars_plan::dsl::udf::UserDefinedFunction;
 use polars_plan::dsl::GetOutput;
@@ -29,6 +32,7 @@ pub fn register_all(ctx: &mut SQLContext) -> PolarsResult<()> {
 		upper_impl,
 	);
 	ctx.registry_mut().register("upper", udf)?;
+	crate::plugins::register_udfs(ctx)?;
 
 	// // Build UDF for embed(text) -> List(Float32)
 	// let embed_udf = UserDefinedFunction::new(
@@ -67,6 +71,39 @@ pub fn new_sql_context() -> SQLContext {
 		.with_function_registry(Arc::new(MyFunctionRegistry::default()))
 }
 
+/// A table name that can be used in SQL without quoting, derived from a dataset or file name.
+/// A short file extension is dropped and runs of other characters become one underscore,
+/// e.g. `Web Proxy (2024).csv` -> `Web_Proxy_2024`.
+pub fn sql_table_name(name: &str) -> String {
+    let stem = match name.rsplit_once('.') {
+        Some((stem, ext)) if !stem.is_empty() && (1..=8).contains(&ext.len()) && ext.chars().all(|c| c.is_ascii_alphanumeric()) => stem,
+        _ => name,
+    };
+    let mut table = String::with_capacity(stem.len());
+    for c in stem.chars() {
+        if c.is_ascii_alphanumeric() {
+            table.push(c);
+        } else if !table.is_empty() && !table.ends_with('_') {
+            table.push('_');
+        }
+    }
+    let table = table.trim_end_matches('_');
+    match table.chars().next() {
+        None => "dataset".to_string(),
+        Some(c) if c.is_ascii_digit() => format!("t_{table}"),
+        Some(_) => table.to_string(),
+    }
+}
+
+/// `base`, or the first of `base_2`, `base_3`, ... that is not in `taken` (ignoring case)
+pub fn unique_table_name(base: &str, taken: &[String]) -> String {
+    let is_taken = |name: &str| taken.iter().any(|t| t.eq_ignore_ascii_case(name));
+    if !is_taken(base) {
+        return base.to_string();
+    }
+    (2..).map(|n| format!("{base}_{n}")).find(|name| !is_taken(name)).unwrap_or_default()
+}
+
 // // Embeddings provider configuration
 // // The provider takes a slice of unique strings and returns an embedding vector per input, in order.
 // lazy_static! {
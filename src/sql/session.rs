@@ -0,0 +1,414 @@
+//! SqlSession: one SQL context per workspace, kept between queries
+//!
+//! Datasets are registered once and only re-registered when the set of open datasets changes.
+//! `CREATE [OR REPLACE] MATERIALIZED VIEW name AS <query>` runs a query once and keeps its result
+//! under `name` for later queries; `DROP MATERIALIZED VIEW [IF EXISTS] name` frees it. View
+//! definitions are saved with the workspace and recomputed when first used after loading; a view
+//! that no longer computes is left out (and reported when a query fails) instead of failing every
+//! statement.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use polars::prelude::*;
+use polars_sql::SQLContext;
+use serde::{Deserialize, Serialize};
+
+use crate::dataframe::spill::{sink_to_parquet, SpillFile};
+use crate::dialog::data_management_dialog::LoadedDataset;
+use crate::sql::{new_sql_context, register_all};
+
+/// A query whose result is kept under a name and reused by later queries
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MaterializedView {
+    pub name: String,
+    pub query: String,
+}
+
+/// What running a statement produced
+#[derive(Debug, Clone)]
+pub enum SqlOutput {
+    Rows(DataFrame),
+    /// The first rows of a result that was larger than the row limit
+    Limited(DataFrame),
+    /// A view was created or dropped; there are no rows to show
+    Message(String),
+}
+
+/// The workspace's session, shared by every tab
+pub type SharedSqlSession = Arc<Mutex<SqlSession>>;
+
+pub struct SqlSession {
+    ctx: SQLContext,
+    /// Table name and generation of each dataset registered in `ctx`
+    registered: Vec<(String, u64)>,
+    views: Vec<MaterializedView>,
+    /// Computed views by name; a view missing here is computed when next needed
+    results: HashMap<String, Arc<DataFrame>>,
+    /// Views whose query failed, with the error; retried when the datasets change
+    failed: HashMap<String, String>,
+}
+
+impl std::fmt::Debug for SqlSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SqlSession")
+            .field("registered", &self.registered)
+            .field("views", &self.views)
+            .field("failed", &self.failed)
+            .finish()
+    }
+}
+
+impl Default for SqlSession {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+enum ViewStatement<'a> {
+    Create { name: String, query: &'a str, replace: bool },
+    Drop { name: String, if_exists: bool },
+}
+
+impl SqlSession {
+    pub fn new() -> Self {
+        Self {
+            ctx: new_sql_context(),
+            registered: Vec::new(),
+            views: Vec::new(),
+            results: HashMap::new(),
+            failed: HashMap::new(),
+        }
+    }
+
+    /// A new session ready to be shared between tabs
+    pub fn shared() -> SharedSqlSession {
+        Arc::new(Mutex::new(Self::new()))
+    }
+
+    /// Views in the order they were created
+    pub fn views(&self) -> &[MaterializedView] {
+        &self.views
+    }
+
+    /// Views that could not be computed, with the error
+    pub fn failed_views(&self) -> &HashMap<String, String> {
+        &self.failed
+    }
+
+    /// Replace the view definitions, e.g. from a saved workspace; each is computed when first used
+    pub fn set_views(&mut self, views: Vec<MaterializedView>) {
+        for name in self.results.keys() {
+            self.ctx.unregister(name);
+        }
+        self.results.clear();
+        self.failed.clear();
+        self.views = views;
+    }
+
+    /// Run `statement` with `datasets` registered under their alias (or name). Query results are
+    /// cut to `row_limit` rows; materialized views are always computed in full.
+    pub fn execute(
+        &mut self,
+        statement: &str,
+        datasets: &HashMap<String, LoadedDataset>,
+        row_limit: Option<usize>,
+    ) -> Result<SqlOutput> {
+        self.sync_datasets(datasets)?;
+        match parse_view_statement(statement) {
+            Some(ViewStatement::Create { name, query, replace }) => {
+                if self.registered.iter().any(|(table, _)| table.eq_ignore_ascii_case(&name)) {
+                    return Err(eyre!("SQL error: '{name}' is already the name of a dataset"));
+                }
+                let existing = self.views.iter().position(|v| v.name.eq_ignore_ascii_case(&name));
+                if existing.is_some() && !replace {
+                    return Err(eyre!("SQL error: materialized view '{name}' already exists; use CREATE OR REPLACE"));
+                }
+                self.materialize_pending();
+                let df = self.collect(query).map_err(|e| self.note_failed_views(e))?;
+                let rows = df.height();
+                if let Some(index) = existing {
+                    let old = self.views.remove(index);
+                    self.results.remove(&old.name);
+                    self.failed.remove(&old.name);
+                    self.ctx.unregister(&old.name);
+                }
+                self.ctx.register(&name, df.clone().lazy());
+                self.results.insert(name.clone(), Arc::new(df));
+                self.views.push(MaterializedView { name: name.clone(), query: query.to_string() });
+                Ok(SqlOutput::Message(format!("Materialized view '{name}': {rows} rows")))
+            }
+            Some(ViewStatement::Drop { name, if_exists }) => {
+                let Some(index) = self.views.iter().position(|v| v.name.eq_ignore_ascii_case(&name)) else {
+                    if if_exists {
+                        return Ok(SqlOutput::Message(format!("No materialized view '{name}'")));
+                    }
+                    return Err(eyre!("SQL error: no materialized view '{name}'"));
+                };
+                let view = self.views.remove(index);
+                self.results.remove(&view.name);
+                self.failed.remove(&view.name);
+                self.ctx.unregister(&view.name);
+                Ok(SqlOutput::Message(format!("Dropped materialized view '{}'", view.name)))
+            }
+            None => {
+                self.materialize_pending();
+                let Some(limit) = row_limit else {
+                    return Ok(SqlOutput::Rows(self.collect(statement).map_err(|e| self.note_failed_views(e))?));
+                };
+                let lf = self
+                    .ctx
+                    .execute(statement)
+                    .map_err(|e| self.note_failed_views(eyre!("SQL error: {e}")))?;
+                // One row past the limit tells whether the result was cut
+                let fetch = IdxSize::try_from(limit.saturating_add(1)).unwrap_or(IdxSize::MAX);
+                let df = lf.limit(fetch).collect().map_err(|e| eyre!("Collect error: {e}"))?;
+                if df.height() > limit {
+                    Ok(SqlOutput::Limited(df.head(Some(limit))))
+                } else {
+                    Ok(SqlOutput::Rows(df))
+                }
+            }
+        }
+    }
+
+    /// Stream the rows of query `statement` into a spill file when the tables it can read (the
+    /// datasets and views together) are larger than `spill_bytes`, and return a scan of the file.
+    /// None when the statement should go through `execute`: it is small enough, or it creates or
+    /// drops a view.
+    pub fn spill_query(
+        &mut self,
+        statement: &str,
+        datasets: &HashMap<String, LoadedDataset>,
+        spill_bytes: Option<usize>,
+    ) -> Result<Option<(LazyFrame, SpillFile)>> {
+        let Some(limit) = spill_bytes else {
+            return Ok(None);
+        };
+        if parse_view_statement(statement).is_some() {
+            return Ok(None);
+        }
+        let input: usize = datasets
+            .values()
+            .map(|d| d.dataframe.estimated_size())
+            .chain(self.results.values().map(|df| df.estimated_size()))
+            .sum();
+        if input <= limit {
+            return Ok(None);
+        }
+        self.sync_datasets(datasets)?;
+        self.materialize_pending();
+        let lf = self
+            .ctx
+            .execute(statement)
+            .map_err(|e| self.note_failed_views(eyre!("SQL error: {e}")))?;
+        sink_to_parquet(lf).map(Some)
+    }
+
+    fn collect(&mut self, query: &str) -> Result<DataFrame> {
+        let lf = self.ctx.execute(query).map_err(|e| eyre!("SQL error: {e}"))?;
+        lf.collect().map_err(|e| eyre!("Collect error: {e}"))
+    }
+
+    /// Rebuild the context when datasets were added, removed, renamed or reloaded
+    fn sync_datasets(&mut self, datasets: &HashMap<String, LoadedDataset>) -> Result<()> {
+        let mut current: Vec<(String, u64)> = datasets
+            .values()
+            .map(|d| {
+                let name = d.dataset.alias.clone().unwrap_or_else(|| d.dataset.name.clone());
+                (name, d.generation)
+            })
+            .collect();
+        current.sort();
+        if current == self.registered {
+            return Ok(());
+        }
+        let mut ctx = new_sql_context();
+        register_all(&mut ctx).map_err(|e| eyre!("SQL error: {e}"))?;
+        for dataset in datasets.values() {
+            let name = dataset.dataset.alias.clone().unwrap_or_else(|| dataset.dataset.name.clone());
+            ctx.register(&name, (*dataset.dataframe).clone().lazy());
+        }
+        for (name, df) in &self.results {
+            ctx.register(name, df.as_ref().clone().lazy());
+        }
+        self.ctx = ctx;
+        self.registered = current;
+        // The datasets a failed view needs may be back
+        self.failed.clear();
+        Ok(())
+    }
+
+    /// Compute views restored from the workspace, in creation order so views can build on each other.
+    /// A view that fails is recorded in `failed` and left out, so unrelated queries still run.
+    fn materialize_pending(&mut self) {
+        for index in 0..self.views.len() {
+            let view = self.views[index].clone();
+            if self.results.contains_key(&view.name) || self.failed.contains_key(&view.name) {
+                continue;
+            }
+            match self.collect(&view.query) {
+                Ok(df) => {
+                    self.ctx.register(&view.name, df.clone().lazy());
+                    self.results.insert(view.name, Arc::new(df));
+                }
+                Err(e) => {
+                    tracing::warn!("Materialized view '{}' could not be computed: {e}", view.name);
+                    self.failed.insert(view.name, e.to_string());
+                }
+            }
+        }
+    }
+
+    /// Add the views that could not be computed to a query error, since it may be why the query failed
+    fn note_failed_views(&self, error: color_eyre::Report) -> color_eyre::Report {
+        let mut failed: Vec<_> = self.failed.iter().collect();
+        if failed.is_empty() {
+            return error;
+        }
+        failed.sort();
+        let notes: Vec<String> = failed
+            .into_iter()
+            .map(|(name, reason)| format!("materialized view '{name}' could not be computed: {reason}"))
+            .collect();
+        eyre!("{error}\n({})", notes.join("; "))
+    }
+}
+
+/// `keyword` at the start of `text` (ignoring case and leading space) followed by a space or the end
+fn strip_keyword<'a>(text: &'a str, keyword: &str) -> Option<&'a str> {
+    let text = text.trim_start();
+    let head = text.get(..keyword.len())?;
+    let rest = &text[keyword.len()..];
+    (head.eq_ignore_ascii_case(keyword) && rest.chars().next().is_none_or(char::is_whitespace)).then_some(rest)
+}
+
+/// A table name, optionally double-quoted, and the text after it
+fn split_name(text: &str) -> Option<(String, &str)> {
+    let text = text.trim_start();
+    if let Some(quoted) = text.strip_prefix('"') {
+        let (name, rest) = quoted.split_once('"')?;
+        return Some((name.to_string(), rest));
+    }
+    let end = text.find(|c: char| c.is_whitespace() || c == ';').unwrap_or(text.len());
+    (end > 0).then(|| (text[..end].to_string(), &text[end..]))
+}
+
+fn parse_view_statement(statement: &str) -> Option<ViewStatement<'_>> {
+    if let Some(rest) = strip_keyword(statement, "CREATE") {
+        let (replace, rest) = match strip_keyword(rest, "OR").and_then(|r| strip_keyword(r, "REPLACE")) {
+            Some(rest) => (true, rest),
+            None => (false, rest),
+        };
+        let rest = strip_keyword(strip_keyword(rest, "MATERIALIZED")?, "VIEW")?;
+        let (name, rest) = split_name(rest)?;
+        let query = strip_keyword(rest, "AS")?.trim().trim_end_matches(';').trim_end();
+        return Some(ViewStatement::Create { name, query, replace });
+    }
+    let rest = strip_keyword(strip_keyword(strip_keyword(statement, "DROP")?, "MATERIALIZED")?, "VIEW")?;
+    let (if_exists, rest) = match strip_keyword(rest, "IF").and_then(|r| strip_keyword(r, "EXISTS")) {
+        Some(rest) => (true, rest),
+        None => (false, rest),
+    };
+    let (name, _) = split_name(rest)?;
+    Some(ViewStatement::Drop { name, if_exists })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dialog::data_management_dialog::{DataSource, Dataset, DatasetStatus};
+    use crate::data_import_types::DataImportConfig;
+    use crate::dialog::csv_options_dialog::CsvImportOptions;
+
+    fn datasets(df: DataFrame) -> HashMap<String, LoadedDataset> {
+        let config = DataImportConfig::text("events.csv".into(), CsvImportOptions::default());
+        let data_source = DataSource::from_import_config(0, &config);
+        let dataset = Dataset {
+            id: "events".to_string(),
+            name: "events".to_string(),
+            alias: None,
+            row_count: df.height(),
+            column_count: df.width(),
+            status: DatasetStatus::Imported,
+            error_message: None,
+            notes: None,
+        };
+        let generation = crate::dialog::data_management_dialog::next_generation();
+        HashMap::from([("events".to_string(), LoadedDataset { data_source, dataset, dataframe: Arc::new(df), generation })])
+    }
+
+    fn rows(output: SqlOutput) -> DataFrame {
+        match output {
+            SqlOutput::Rows(df) | SqlOutput::Limited(df) => df,
+            SqlOutput::Message(message) => panic!("expected rows, got '{message}'"),
+        }
+    }
+
+    #[test]
+    fn test_materialized_views_outlive_queries() {
+        let mut session = SqlSession::new();
+        let data = datasets(df!("host" => ["web-1", "web-2", "web-1"], "status" => [200, 500, 500]).unwrap());
+
+        let created = session.execute("CREATE MATERIALIZED VIEW errors AS SELECT * FROM events WHERE status >= 500;", &data, None).unwrap();
+        assert!(matches!(created, SqlOutput::Message(ref m) if m == "Materialized view 'errors': 2 rows"));
+        assert!(session.execute("CREATE MATERIALIZED VIEW errors AS SELECT 1", &data, None).is_err());
+        let counted = rows(session.execute("SELECT COUNT(*) AS n FROM errors", &data, None).unwrap());
+        assert_eq!(counted.column("n").unwrap().get(0).unwrap().str_value(), "2");
+
+        // Views survive the datasets being reloaded, and restored definitions are recomputed
+        let reloaded = datasets(df!("host" => ["db-1"], "status" => [503]).unwrap());
+        assert_eq!(rows(session.execute("SELECT * FROM errors", &reloaded, None).unwrap()).height(), 2);
+        let saved = session.views().to_vec();
+        session.set_views(saved);
+        assert_eq!(rows(session.execute("SELECT * FROM errors", &reloaded, None).unwrap()).height(), 1);
+
+        session.execute("drop materialized view errors", &reloaded, None).unwrap();
+        assert!(session.views().is_empty());
+        assert!(session.execute("SELECT * FROM errors", &reloaded, None).is_err());
+        assert!(session.execute("DROP MATERIALIZED VIEW IF EXISTS errors", &reloaded, None).is_ok());
+    }
+
+    #[test]
+    fn test_row_limit_cuts_large_results() {
+        let mut session = SqlSession::new();
+        let data = datasets(df!("host" => ["a", "b", "c"], "status" => [1, 2, 3]).unwrap());
+        let cross = "SELECT * FROM events CROSS JOIN events AS other";
+        assert!(matches!(session.execute(cross, &data, Some(4)).unwrap(), SqlOutput::Limited(df) if df.height() == 4));
+        assert!(matches!(session.execute(cross, &data, Some(9)).unwrap(), SqlOutput::Rows(df) if df.height() == 9));
+        assert!(matches!(session.execute(cross, &data, None).unwrap(), SqlOutput::Rows(df) if df.height() == 9));
+    }
+
+    #[test]
+    fn test_broken_view_does_not_block_other_queries() {
+        let mut session = SqlSession::new();
+        let data = datasets(df!("host" => ["web-1", "web-2"], "status" => [200, 500]).unwrap());
+        session.set_views(vec![MaterializedView { name: "by_account".into(), query: "SELECT account FROM events".into() }]);
+
+        assert_eq!(rows(session.execute("SELECT * FROM events", &data, None).unwrap()).height(), 2);
+        assert!(session.failed_views().contains_key("by_account"));
+        let error = session.execute("SELECT * FROM by_account", &data, None).unwrap_err().to_string();
+        assert!(error.contains("materialized view 'by_account' could not be computed"), "{error}");
+
+        // A reload with the same table name is seen through the generation, and the view is retried
+        let renamed = datasets(df!("account" => ["alice"]).unwrap());
+        assert_eq!(rows(session.execute("SELECT * FROM by_account", &renamed, None).unwrap()).height(), 1);
+        assert!(session.failed_views().is_empty());
+    }
+
+    #[test]
+    fn test_large_query_streams_to_parquet() {
+        let mut session = SqlSession::new();
+        let data = datasets(df!("host" => ["a", "b", "c"], "status" => [1, 2, 3]).unwrap());
+        let query = "SELECT host FROM events WHERE status > 1 ORDER BY host DESC";
+        assert!(session.spill_query(query, &data, Some(usize::MAX)).unwrap().is_none());
+        assert!(session.spill_query("CREATE MATERIALIZED VIEW v AS SELECT 1", &data, Some(0)).unwrap().is_none());
+
+        let (scan, spill) = session.spill_query(query, &data, Some(0)).unwrap().unwrap();
+        assert!(spill.path.exists());
+        let hosts = scan.collect().unwrap();
+        assert_eq!(hosts.column("host").unwrap().str().unwrap().into_no_null_iter().collect::<Vec<_>>(), vec!["c", "b"]);
+    }
+}
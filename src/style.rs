@@ -1,5 +1,7 @@
+use ratatui::buffer::Buffer;
 use ratatui::style::{Style, Color, Modifier};
 use serde::{Serialize, Deserialize};
+use std::sync::OnceLock;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StyleConfig {
@@ -115,6 +117,253 @@ impl CursorStyle {
     }
 }
 
+/// Built-in theme choice; `Custom` uses the palette from the config file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ThemeName {
+    #[default]
+    Dark,
+    Light,
+    Solarized,
+    Custom,
+}
+
+impl ThemeName {
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Dark => Self::Light,
+            Self::Light => Self::Solarized,
+            Self::Solarized => Self::Custom,
+            Self::Custom => Self::Dark,
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Dark => "Dark",
+            Self::Light => "Light",
+            Self::Solarized => "Solarized",
+            Self::Custom => "Custom",
+        }
+    }
+}
+
+/// How colors are sent to the terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ColorMode {
+    /// Truecolor if the terminal advertises it (COLORTERM), otherwise 256 colors
+    #[default]
+    Auto,
+    TrueColor,
+    Ansi256,
+}
+
+impl ColorMode {
+    pub fn use_truecolor(&self) -> bool {
+        match self {
+            Self::Auto => supports_truecolor(),
+            Self::TrueColor => true,
+            Self::Ansi256 => false,
+        }
+    }
+}
+
+/// The handful of colors a theme is made of
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThemePalette {
+    pub foreground: Color,
+    pub header: Color,
+    pub border: Color,
+    pub selection_fg: Color,
+    pub selection_bg: Color,
+    pub row_even_bg: Color,
+    pub row_odd_bg: Color,
+    pub error: Color,
+    pub cursor_fg: Color,
+    pub cursor_bg: Color,
+    /// Background of highlighted matches (search/find cursors)
+    pub highlight_bg: Color,
+    /// Dimmed text such as unfocused cursors
+    pub muted: Color,
+    // Dialog colors; palettes saved before they existed get the dark theme's
+    /// Background of pop-ups drawn over a dialog
+    #[serde(default = "default_background")]
+    pub background: Color,
+    /// Selected list entries and scrollbar thumbs in dialogs
+    #[serde(default = "default_accent")]
+    pub accent: Color,
+    /// Hints and scrollbar tracks
+    #[serde(default = "default_hint")]
+    pub hint: Color,
+    /// Passed checks
+    #[serde(default = "default_success")]
+    pub success: Color,
+}
+
+fn default_background() -> Color { ThemePalette::dark().background }
+fn default_accent() -> Color { ThemePalette::dark().accent }
+fn default_hint() -> Color { ThemePalette::dark().hint }
+fn default_success() -> Color { ThemePalette::dark().success }
+
+impl Default for ThemePalette {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl ThemePalette {
+    /// The original datatui colors
+    pub fn dark() -> Self {
+        Self {
+            foreground: Color::White,
+            header: Color::Yellow,
+            border: Color::Gray,
+            selection_fg: Color::Black,
+            selection_bg: Color::Yellow,
+            row_even_bg: Color::Rgb(30, 30, 30),
+            row_odd_bg: Color::Rgb(40, 40, 40),
+            error: Color::Red,
+            cursor_fg: Color::Black,
+            cursor_bg: Color::White,
+            highlight_bg: Color::Yellow,
+            muted: Color::Gray,
+            background: Color::Black,
+            accent: Color::Cyan,
+            hint: Color::DarkGray,
+            success: Color::Green,
+        }
+    }
+
+    pub fn light() -> Self {
+        Self {
+            foreground: Color::Rgb(30, 30, 30),
+            header: Color::Rgb(0, 90, 160),
+            border: Color::Rgb(120, 120, 120),
+            selection_fg: Color::White,
+            selection_bg: Color::Rgb(0, 120, 215),
+            row_even_bg: Color::Rgb(250, 250, 250),
+            row_odd_bg: Color::Rgb(236, 236, 236),
+            error: Color::Rgb(200, 0, 0),
+            cursor_fg: Color::White,
+            cursor_bg: Color::Rgb(30, 30, 30),
+            highlight_bg: Color::Rgb(255, 215, 0),
+            muted: Color::Rgb(140, 140, 140),
+            background: Color::Rgb(250, 250, 250),
+            accent: Color::Rgb(0, 150, 170),
+            hint: Color::Rgb(160, 160, 160),
+            success: Color::Rgb(0, 140, 60),
+        }
+    }
+
+    /// Solarized dark
+    pub fn solarized() -> Self {
+        Self {
+            foreground: Color::Rgb(131, 148, 150),
+            header: Color::Rgb(181, 137, 0),
+            border: Color::Rgb(88, 110, 117),
+            selection_fg: Color::Rgb(0, 43, 54),
+            selection_bg: Color::Rgb(38, 139, 210),
+            row_even_bg: Color::Rgb(0, 43, 54),
+            row_odd_bg: Color::Rgb(7, 54, 66),
+            error: Color::Rgb(220, 50, 47),
+            cursor_fg: Color::Rgb(0, 43, 54),
+            cursor_bg: Color::Rgb(147, 161, 161),
+            highlight_bg: Color::Rgb(181, 137, 0),
+            muted: Color::Rgb(88, 110, 117),
+            background: Color::Rgb(0, 43, 54),
+            accent: Color::Rgb(42, 161, 152),
+            hint: Color::Rgb(88, 110, 117),
+            success: Color::Rgb(133, 153, 0),
+        }
+    }
+
+    pub fn style_config(&self) -> StyleConfig {
+        StyleConfig {
+            table_header: Style::default().fg(self.header).add_modifier(Modifier::BOLD),
+            table_cell: Style::default().fg(self.foreground),
+            table_border: Style::default().fg(self.border),
+            selected_row: Style::default().fg(self.selection_fg).bg(self.selection_bg),
+            dialog: Style::default().fg(self.foreground),
+            error: Style::default().fg(self.error).add_modifier(Modifier::BOLD),
+            table_row_even: Style::default().bg(self.row_even_bg),
+            table_row_odd: Style::default().bg(self.row_odd_bg),
+            cursor: CursorStyle {
+                block: Style::default().fg(self.cursor_fg).bg(self.cursor_bg),
+                highlighted: Style::default().fg(self.cursor_fg).bg(self.highlight_bg),
+                hidden: Style::default().fg(self.muted),
+            },
+        }
+    }
+}
+
+/// Theme settings persisted under `theme` in the config file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct ThemeConfig {
+    #[serde(default)]
+    pub name: ThemeName,
+    /// Palette used by `ThemeName::Custom`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom: Option<ThemePalette>,
+    #[serde(default)]
+    pub color_mode: ColorMode,
+}
+
+impl ThemeConfig {
+    pub fn palette(&self) -> ThemePalette {
+        match self.name {
+            ThemeName::Dark => ThemePalette::dark(),
+            ThemeName::Light => ThemePalette::light(),
+            ThemeName::Solarized => ThemePalette::solarized(),
+            ThemeName::Custom => self.custom.clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// Whether the terminal advertises 24-bit color support
+pub fn supports_truecolor() -> bool {
+    static TRUECOLOR: OnceLock<bool> = OnceLock::new();
+    *TRUECOLOR.get_or_init(|| {
+        let colorterm = std::env::var("COLORTERM").unwrap_or_default().to_lowercase();
+        // Windows Terminal supports truecolor but does not set COLORTERM
+        colorterm.contains("truecolor") || colorterm.contains("24bit") || std::env::var_os("WT_SESSION").is_some()
+    })
+}
+
+/// Nearest xterm 256-color index for an RGB color
+pub fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    if r == g && g == b {
+        // Grayscale ramp 232..=255 covers 8..=238; the ends fall back to the cube's black and white
+        return match r {
+            0..=7 => 16,
+            249..=255 => 231,
+            _ => 232 + ((r as u16 - 8) * 24 / 247) as u8,
+        };
+    }
+    let level = |v: u8| -> u8 {
+        match v {
+            0..=47 => 0,
+            48..=114 => 1,
+            _ => (v - 35) / 40,
+        }
+    };
+    16 + 36 * level(r) + 6 * level(g) + level(b)
+}
+
+/// `color` with RGB values mapped onto the 256-color palette
+pub fn to_ansi256(color: Color) -> Color {
+    match color {
+        Color::Rgb(r, g, b) => Color::Indexed(rgb_to_ansi256(r, g, b)),
+        other => other,
+    }
+}
+
+/// Map every RGB color in a rendered frame onto the 256-color palette
+pub fn downgrade_buffer(buf: &mut Buffer) {
+    for cell in buf.content.iter_mut() {
+        cell.fg = to_ansi256(cell.fg);
+        cell.bg = to_ansi256(cell.bg);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -149,4 +398,34 @@ mod tests {
         assert_eq!(custom.table_header.fg, Some(Color::Green));
         assert_eq!(custom.error.fg, Some(Color::Magenta));
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_themes_and_ansi256_fallback() {
+        // The dark theme reproduces the original defaults
+        let dark = ThemePalette::dark().style_config();
+        let default = StyleConfig::default();
+        assert_eq!(dark.table_row_even, default.table_row_even);
+        assert_eq!(dark.selected_row, default.selected_row);
+        assert_eq!(dark.cursor.highlighted, default.cursor.highlighted);
+
+        let custom = ThemeConfig { name: ThemeName::Custom, custom: Some(ThemePalette::light()), color_mode: ColorMode::Ansi256 };
+        assert_eq!(custom.palette(), ThemePalette::light());
+        assert!(!custom.color_mode.use_truecolor());
+        assert_eq!(ThemeConfig { name: ThemeName::Custom, ..ThemeConfig::default() }.palette(), ThemePalette::dark());
+        // A custom palette saved without the dialog colors still loads
+        let mut saved = serde_json::to_value(ThemePalette::light()).unwrap();
+        saved.as_object_mut().unwrap().retain(|k, _| !["background", "accent", "hint", "success"].contains(&k.as_str()));
+        let loaded: ThemePalette = serde_json::from_value(saved).unwrap();
+        assert_eq!((loaded.foreground, loaded.accent), (ThemePalette::light().foreground, ThemePalette::dark().accent));
+
+        assert_eq!(rgb_to_ansi256(0, 0, 0), 16);
+        assert_eq!(rgb_to_ansi256(255, 0, 0), 196);
+        assert_eq!(rgb_to_ansi256(30, 30, 30), 234);
+        assert_eq!(to_ansi256(Color::Yellow), Color::Yellow);
+
+        let mut buf = Buffer::empty(ratatui::layout::Rect::new(0, 0, 2, 1));
+        buf[(0, 0)].set_bg(Color::Rgb(0, 43, 54));
+        downgrade_buffer(&mut buf);
+        assert_eq!(buf[(0, 0)].bg, Color::Indexed(rgb_to_ansi256(0, 43, 54)));
+    }
+}
@@ -0,0 +1,376 @@
+//! Threat-intel enrichment: look up hashes, IPs and domains with VirusTotal, AbuseIPDB or OTX
+//!
+//! API keys and per-provider rate limits live in `~/.datatui-threat-intel.toml`. Responses are
+//! summarised to one short string per indicator and cached on disk under
+//! `~/.datatui-threat-intel-cache/` so re-running an enrichment does not spend API quota again.
+
+use std::collections::{HashMap, HashSet};
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::config::get_config_dir;
+use crate::dialog::llm::{RequestPolicy, RequestRunner};
+
+/// A threat-intel service the enrichment column operation can query
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum ThreatIntelProvider {
+    #[default]
+    VirusTotal,
+    AbuseIpDb,
+    Otx,
+}
+
+impl ThreatIntelProvider {
+    pub const ALL: [ThreatIntelProvider; 3] = [ThreatIntelProvider::VirusTotal, ThreatIntelProvider::AbuseIpDb, ThreatIntelProvider::Otx];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ThreatIntelProvider::VirusTotal => "VirusTotal",
+            ThreatIntelProvider::AbuseIpDb => "AbuseIPDB",
+            ThreatIntelProvider::Otx => "AlienVault OTX",
+        }
+    }
+
+    /// Cycle to the next (or previous) provider
+    pub fn cycle(&self, forward: bool) -> ThreatIntelProvider {
+        let pos = Self::ALL.iter().position(|p| p == self).unwrap_or(0);
+        let len = Self::ALL.len();
+        Self::ALL[if forward { (pos + 1) % len } else { (pos + len - 1) % len }]
+    }
+
+    /// Whether the provider can look up this kind of indicator (AbuseIPDB only knows IPs)
+    pub fn supports(&self, kind: IndicatorKind) -> bool {
+        !matches!((self, kind), (ThreatIntelProvider::AbuseIpDb, IndicatorKind::Hash | IndicatorKind::Domain))
+    }
+
+    fn cache_file(&self) -> &'static str {
+        match self {
+            ThreatIntelProvider::VirusTotal => "virustotal.json",
+            ThreatIntelProvider::AbuseIpDb => "abuseipdb.json",
+            ThreatIntelProvider::Otx => "otx.json",
+        }
+    }
+}
+
+/// What an indicator value looks like
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndicatorKind {
+    /// MD5, SHA1 or SHA256 hex digest
+    Hash,
+    Ip,
+    Domain,
+}
+
+impl IndicatorKind {
+    /// Classify a cell value; None for values that are none of the three
+    pub fn detect(value: &str) -> Option<IndicatorKind> {
+        let value = value.trim();
+        if value.parse::<IpAddr>().is_ok() {
+            return Some(IndicatorKind::Ip);
+        }
+        if matches!(value.len(), 32 | 40 | 64) && value.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Some(IndicatorKind::Hash);
+        }
+        let labels: Vec<&str> = value.trim_end_matches('.').split('.').collect();
+        let is_domain = labels.len() >= 2
+            && labels.iter().all(|l| !l.is_empty() && l.len() <= 63 && l.chars().all(|c| c.is_ascii_alphanumeric() || c == '-'))
+            && labels.last().is_some_and(|tld| tld.chars().all(|c| c.is_ascii_alphabetic()));
+        is_domain.then_some(IndicatorKind::Domain)
+    }
+}
+
+/// API key and rate limit for one provider
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct ThreatIntelProviderSettings {
+    pub api_key: String,
+    /// Requests allowed per minute; 0 means unlimited
+    pub requests_per_minute: u32,
+}
+
+/// Saved in `~/.datatui-threat-intel.toml`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThreatIntelConfig {
+    pub virustotal: ThreatIntelProviderSettings,
+    pub abuseipdb: ThreatIntelProviderSettings,
+    pub otx: ThreatIntelProviderSettings,
+    /// Cached responses older than this are fetched again
+    pub cache_ttl_hours: u64,
+}
+
+impl Default for ThreatIntelConfig {
+    fn default() -> Self {
+        Self {
+            // The public VirusTotal API allows 4 lookups a minute
+            virustotal: ThreatIntelProviderSettings { api_key: String::new(), requests_per_minute: 4 },
+            abuseipdb: ThreatIntelProviderSettings { api_key: String::new(), requests_per_minute: 60 },
+            otx: ThreatIntelProviderSettings { api_key: String::new(), requests_per_minute: 60 },
+            cache_ttl_hours: 24,
+        }
+    }
+}
+
+impl ThreatIntelConfig {
+    pub fn settings(&self, provider: ThreatIntelProvider) -> &ThreatIntelProviderSettings {
+        match provider {
+            ThreatIntelProvider::VirusTotal => &self.virustotal,
+            ThreatIntelProvider::AbuseIpDb => &self.abuseipdb,
+            ThreatIntelProvider::Otx => &self.otx,
+        }
+    }
+
+    pub fn settings_mut(&mut self, provider: ThreatIntelProvider) -> &mut ThreatIntelProviderSettings {
+        match provider {
+            ThreatIntelProvider::VirusTotal => &mut self.virustotal,
+            ThreatIntelProvider::AbuseIpDb => &mut self.abuseipdb,
+            ThreatIntelProvider::Otx => &mut self.otx,
+        }
+    }
+
+    pub fn config_path() -> PathBuf {
+        get_config_dir().join(".datatui-threat-intel.toml")
+    }
+
+    pub fn cache_dir() -> PathBuf {
+        get_config_dir().join(".datatui-threat-intel-cache")
+    }
+
+    /// Load from `path`, falling back to defaults when the file is missing
+    pub fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)?;
+        toml::from_str(&content).map_err(|e| eyre!("Failed to parse {}: {}", path.display(), e))
+    }
+
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, toml::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Pacing and retries for `provider`; rate-limit responses are retried with backoff
+    pub fn request_policy(&self, provider: ThreatIntelProvider) -> RequestPolicy {
+        RequestPolicy { requests_per_minute: self.settings(provider).requests_per_minute, max_retries: 2, ..RequestPolicy::default() }
+    }
+}
+
+/// A summarised response and when it was fetched (Unix seconds)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedResponse {
+    pub fetched_at: i64,
+    pub summary: String,
+}
+
+/// Per-provider JSON files of summaries keyed by lowercased indicator
+#[derive(Debug, Clone)]
+pub struct EnrichmentCache {
+    dir: PathBuf,
+}
+
+impl EnrichmentCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn load(&self, provider: ThreatIntelProvider) -> HashMap<String, CachedResponse> {
+        std::fs::read_to_string(self.dir.join(provider.cache_file()))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, provider: ThreatIntelProvider, entries: &HashMap<String, CachedResponse>) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        std::fs::write(self.dir.join(provider.cache_file()), serde_json::to_string(entries)?)?;
+        Ok(())
+    }
+}
+
+/// One-line verdict from a provider's JSON response; `Value::Null` means the indicator is unknown
+pub fn summarize(provider: ThreatIntelProvider, response: &Value) -> String {
+    if response.is_null() {
+        return "not found".to_string();
+    }
+    let n = |v: &Value| v.as_u64().unwrap_or(0);
+    match provider {
+        ThreatIntelProvider::VirusTotal => {
+            let stats = &response["data"]["attributes"]["last_analysis_stats"];
+            let total: u64 = ["malicious", "suspicious", "harmless", "undetected"].iter().map(|k| n(&stats[*k])).sum();
+            format!("malicious {}/{total}, suspicious {}", n(&stats["malicious"]), n(&stats["suspicious"]))
+        }
+        ThreatIntelProvider::AbuseIpDb => {
+            let data = &response["data"];
+            format!("abuse score {}%, {} reports", n(&data["abuseConfidenceScore"]), n(&data["totalReports"]))
+        }
+        ThreatIntelProvider::Otx => format!("{} pulses", n(&response["pulse_info"]["count"])),
+    }
+}
+
+fn request_url(provider: ThreatIntelProvider, kind: IndicatorKind, value: &str) -> String {
+    let encoded = percent_encoding::utf8_percent_encode(value, percent_encoding::NON_ALPHANUMERIC);
+    match (provider, kind) {
+        (ThreatIntelProvider::VirusTotal, IndicatorKind::Hash) => format!("https://www.virustotal.com/api/v3/files/{encoded}"),
+        (ThreatIntelProvider::VirusTotal, IndicatorKind::Ip) => format!("https://www.virustotal.com/api/v3/ip_addresses/{encoded}"),
+        (ThreatIntelProvider::VirusTotal, IndicatorKind::Domain) => format!("https://www.virustotal.com/api/v3/domains/{encoded}"),
+        (ThreatIntelProvider::AbuseIpDb, _) => format!("https://api.abuseipdb.com/api/v2/check?ipAddress={encoded}&maxAgeInDays=90"),
+        (ThreatIntelProvider::Otx, kind) => {
+            let section = match kind {
+                IndicatorKind::Hash => "file",
+                IndicatorKind::Ip if value.contains(':') => "IPv6",
+                IndicatorKind::Ip => "IPv4",
+                IndicatorKind::Domain => "domain",
+            };
+            format!("https://otx.alienvault.com/api/v1/indicators/{section}/{encoded}/general")
+        }
+    }
+}
+
+/// Query one indicator. 404 is an answer (unknown indicator), not an error.
+fn fetch(client: &reqwest::blocking::Client, provider: ThreatIntelProvider, api_key: &str, kind: IndicatorKind, value: &str) -> Result<Value> {
+    let header = match provider {
+        ThreatIntelProvider::VirusTotal => "x-apikey",
+        ThreatIntelProvider::AbuseIpDb => "Key",
+        ThreatIntelProvider::Otx => "X-OTX-API-KEY",
+    };
+    let response = client
+        .get(request_url(provider, kind, value))
+        .header(header, api_key)
+        .header("Accept", "application/json")
+        .send()?;
+    let status = response.status();
+    if status == reqwest::StatusCode::NOT_FOUND {
+        return Ok(Value::Null);
+    }
+    if !status.is_success() {
+        return Err(eyre!("{} returned {} for {}", provider.label(), status, value));
+    }
+    Ok(response.json()?)
+}
+
+/// Fetched answers between cache writes, so a cancelled run keeps the quota it already spent
+const CACHE_SAVE_INTERVAL: usize = 25;
+
+/// Enrich `values` with `provider`, one summary per value (None for empty or unsupported
+/// values and failed lookups). Each distinct indicator is fetched at most once, cached answers
+/// younger than the TTL are reused, and fetched answers are written back to the cache as they
+/// arrive (every `CACHE_SAVE_INTERVAL` answers, at the end and on cancel).
+/// Returns the summaries and one warning per failed lookup.
+pub fn enrich_values(
+    provider: ThreatIntelProvider,
+    config: &ThreatIntelConfig,
+    cache: &EnrichmentCache,
+    values: &[Option<String>],
+    is_cancelled: &dyn Fn() -> bool,
+    mut progress: impl FnMut(f64),
+) -> Result<(Vec<Option<String>>, Vec<String>)> {
+    let api_key = config.settings(provider).api_key.trim().to_string();
+    let mut entries = cache.load(provider);
+    let now = chrono::Utc::now().timestamp();
+    // The TTL comes from the user's file, so an absurd value must not overflow
+    let ttl = i64::try_from(config.cache_ttl_hours.saturating_mul(3600)).unwrap_or(i64::MAX);
+
+    let mut pending: Vec<(String, IndicatorKind)> = Vec::new();
+    let mut seen = HashSet::new();
+    for value in values.iter().flatten() {
+        let key = value.trim().to_lowercase();
+        let Some(kind) = IndicatorKind::detect(&key) else { continue };
+        let fresh = entries.get(&key).is_some_and(|e| now - e.fetched_at < ttl);
+        if provider.supports(kind) && !fresh && seen.insert(key.clone()) {
+            pending.push((key, kind));
+        }
+    }
+    if !pending.is_empty() && api_key.is_empty() {
+        return Err(eyre!("No {} API key configured; add one under Project Settings > Threat Intel", provider.label()));
+    }
+
+    let client = reqwest::blocking::Client::builder()
+        .timeout(Duration::from_secs(20))
+        .user_agent("datatui-threat-intel")
+        .build()?;
+    let mut runner = RequestRunner::new(config.request_policy(provider), is_cancelled);
+    let mut warnings = Vec::new();
+    let mut unsaved = 0;
+    for (i, (key, kind)) in pending.iter().enumerate() {
+        if is_cancelled() {
+            if unsaved > 0 {
+                cache.save(provider, &entries)?;
+            }
+            return Err(eyre!("Cancelled"));
+        }
+        match runner.run(|| fetch(&client, provider, &api_key, *kind, key)) {
+            Ok(response) => {
+                entries.insert(key.clone(), CachedResponse { fetched_at: now, summary: summarize(provider, &response) });
+                unsaved += 1;
+                if unsaved >= CACHE_SAVE_INTERVAL {
+                    cache.save(provider, &entries)?;
+                    unsaved = 0;
+                }
+            }
+            Err(e) => warnings.push(format!("{key}: {e}")),
+        }
+        progress((i + 1) as f64 / pending.len() as f64);
+    }
+    if unsaved > 0 {
+        cache.save(provider, &entries)?;
+    }
+
+    let summaries = values
+        .iter()
+        .map(|v| v.as_ref().and_then(|v| entries.get(&v.trim().to_lowercase())).map(|e| e.summary.clone()))
+        .collect();
+    Ok((summaries, warnings))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_and_summarize() {
+        assert_eq!(IndicatorKind::detect("8.8.8.8"), Some(IndicatorKind::Ip));
+        assert_eq!(IndicatorKind::detect("2001:db8::1"), Some(IndicatorKind::Ip));
+        assert_eq!(IndicatorKind::detect("d41d8cd98f00b204e9800998ecf8427e"), Some(IndicatorKind::Hash));
+        assert_eq!(IndicatorKind::detect("evil.example.com"), Some(IndicatorKind::Domain));
+        assert_eq!(IndicatorKind::detect("not an indicator"), None);
+        assert!(!ThreatIntelProvider::AbuseIpDb.supports(IndicatorKind::Domain));
+
+        let vt = serde_json::json!({"data": {"attributes": {"last_analysis_stats": {"malicious": 3, "suspicious": 1, "harmless": 50, "undetected": 16}}}});
+        assert_eq!(summarize(ThreatIntelProvider::VirusTotal, &vt), "malicious 3/70, suspicious 1");
+        let abuse = serde_json::json!({"data": {"abuseConfidenceScore": 87, "totalReports": 12}});
+        assert_eq!(summarize(ThreatIntelProvider::AbuseIpDb, &abuse), "abuse score 87%, 12 reports");
+        assert_eq!(summarize(ThreatIntelProvider::Otx, &Value::Null), "not found");
+    }
+
+    #[test]
+    fn test_cached_values_skip_the_network() {
+        let dir = std::env::temp_dir().join(format!("datatui-ti-cache-{}", uuid::Uuid::new_v4()));
+        let cache = EnrichmentCache::new(&dir);
+        let now = chrono::Utc::now().timestamp();
+        let entries = HashMap::from([("8.8.8.8".to_string(), CachedResponse { fetched_at: now, summary: "0 pulses".to_string() })]);
+        cache.save(ThreatIntelProvider::Otx, &entries).unwrap();
+
+        // No API key: only cached and unsupported values may be answered
+        let config = ThreatIntelConfig::default();
+        let values = vec![Some("8.8.8.8 ".to_string()), None, Some("hello".to_string())];
+        let (summaries, warnings) = enrich_values(ThreatIntelProvider::Otx, &config, &cache, &values, &|| false, |_| {}).unwrap();
+        assert_eq!(summaries, [Some("0 pulses".to_string()), None, None]);
+        assert!(warnings.is_empty());
+        let uncached = vec![Some("1.1.1.1".to_string())];
+        assert!(enrich_values(ThreatIntelProvider::Otx, &config, &cache, &uncached, &|| false, |_| {}).is_err());
+        let forever = ThreatIntelConfig { cache_ttl_hours: u64::MAX, ..ThreatIntelConfig::default() };
+        let (summaries, _) = enrich_values(ThreatIntelProvider::Otx, &forever, &cache, &values, &|| false, |_| {}).unwrap();
+        assert_eq!(summaries[0].as_deref(), Some("0 pulses"));
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -1,5 +1,5 @@
 use std::fs::{File, create_dir_all};
-use std::path::Path;
+use std::path::{Component, Path, PathBuf};
 use serde::{Deserialize, Serialize};
 use ratatui::style::{Color, Style};
 use tui_textarea::TextArea;
@@ -11,12 +11,71 @@ use crate::dialog::filter_dialog::FilterExpr;
 use crate::components::datatable_container::DataTableContainer;
 use crate::dialog::data_tab_manager_dialog::DataTabManagerDialog;
 use crate::dialog::column_width_dialog::ColumnWidthConfig;
+use crate::dialog::heatmap_dialog::HeatmapConfig;
+use crate::dialog::display_settings_dialog::DisplaySettings;
 use crate::dialog::jmes_dialog::JmesPathKeyValuePair;
+use std::sync::Arc;
+use crate::dataframe::checkpoints::Checkpoint;
+use crate::dataframe::row_tags::RowTags;
+use crate::dataframe::validation::ValidationRule;
+use crate::dataframe::transform_history::TransformHistory;
+use crate::sql::session::MaterializedView;
 use polars::prelude::ParquetReader;
 use tracing::info;
 
 // We surface errors via color-eyre; no custom error type needed.
 
+/// Collapse `.` and `..` components without touching the filesystem
+pub fn normalize_path(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !out.pop() {
+                    out.push("..");
+                }
+            }
+            other => out.push(other.as_os_str()),
+        }
+    }
+    out
+}
+
+/// `path` relative to `base` when both share a directory below the filesystem root, so a project
+/// copied together with its evidence folder still resolves; other paths stay absolute.
+pub fn relative_to(path: &Path, base: &Path) -> PathBuf {
+    if !path.is_absolute() || !base.is_absolute() {
+        return path.to_path_buf();
+    }
+    let (path, base) = (normalize_path(path), normalize_path(base));
+    let path_parts: Vec<Component> = path.components().collect();
+    let base_parts: Vec<Component> = base.components().collect();
+    let common = path_parts.iter().zip(&base_parts).take_while(|(a, b)| a == b).count();
+    let shares_directory = path_parts[..common].iter().any(|c| matches!(c, Component::Normal(_)));
+    if !shares_directory {
+        return path;
+    }
+    let mut relative = PathBuf::new();
+    for _ in common..base_parts.len() {
+        relative.push("..");
+    }
+    for part in &path_parts[common..] {
+        relative.push(part.as_os_str());
+    }
+    relative
+}
+
+/// Absolute form of a stored path; relative paths are taken from `base`
+pub fn resolve_path(path: &Path, base: &Path) -> PathBuf {
+    if path.is_absolute() { path.to_path_buf() } else { normalize_path(&base.join(path)) }
+}
+
+/// Sources created inside the app (SQL results, tab copies) use `scheme://` paths, not files
+pub fn is_generated_path(path: &Path) -> bool {
+    path.to_string_lossy().contains("://")
+}
+
 /// Serializable snapshot of dialogs/state we want to persist for a workspace
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkspaceState {
@@ -27,6 +86,9 @@ pub struct WorkspaceState {
     // Enabled style set identifiers
     #[serde(default)]
     pub enabled_style_sets: Vec<String>,
+    // Materialized SQL views, recomputed from their queries when first used
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sql_views: Vec<MaterializedView>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -38,11 +100,30 @@ pub struct TabState {
     pub sort: Vec<SortColumn>,
     pub filter: Option<FilterExpr>,
     pub column_widths: ColumnWidthConfig,
+    #[serde(default)]
+    pub heatmaps: HeatmapConfig,
+    #[serde(default)]
+    pub display_settings: DisplaySettings,
     pub sql_query: String,
     pub jmes_expression: String,
     pub jmes_add_columns: Vec<JmesPathKeyValuePair>,
     // If current_df is materialized, a parquet file name stored under workspace/.datatui/tabs
     pub current_df_parquet: Option<String>,
+    // Style sets enabled for this tab only; None when the tab uses the global enabled sets
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled_style_sets: Option<Vec<String>>,
+    // Ordered transforms applied to the dataset, for review and replay
+    #[serde(default)]
+    pub history: TransformHistory,
+    // Named checkpoints; snapshots are written as parquet next to current_df_parquet
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub checkpoints: Vec<Checkpoint>,
+    // Row tags, keyed by a hash of each row's values so they survive reloads
+    #[serde(default, skip_serializing_if = "RowTags::is_empty")]
+    pub row_tags: RowTags,
+    // Per-column validation rules; results are not kept and are rerun on demand
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub validation_rules: Vec<ValidationRule>,
 }
 
 impl WorkspaceState {
@@ -54,8 +135,9 @@ impl WorkspaceState {
         let mut tabs: Vec<TabState> = Vec::new();
         for tab in &manager.tabs {
             let tab_id = tab.loaded_dataset.dataset.id.clone();
+            let enabled_style_sets = manager.style_set_manager.get_tab_enabled_identifiers(&tab_id);
             if let Some(container) = manager.containers.get(&tab_id) {
-                tabs.push(Self::capture_tab_state(tab_id, container));
+                tabs.push(TabState { enabled_style_sets, ..Self::capture_tab_state(tab_id, container) });
             } else {
                 // fallback: minimal
                 tabs.push(TabState{
@@ -65,10 +147,17 @@ impl WorkspaceState {
                     sort: vec![],
                     filter: None,
                     column_widths: ColumnWidthConfig::default(),
+                    heatmaps: HeatmapConfig::default(),
+                    display_settings: DisplaySettings::default(),
                     sql_query: String::new(),
                     jmes_expression: String::new(),
                     jmes_add_columns: vec![],
                     current_df_parquet: None,
+                    enabled_style_sets,
+                    history: TransformHistory::default(),
+                    checkpoints: vec![],
+                    row_tags: RowTags::default(),
+                    validation_rules: vec![],
                 });
             }
         }
@@ -78,6 +167,7 @@ impl WorkspaceState {
             data_sources,
             tabs,
             enabled_style_sets: manager.style_set_manager.get_enabled_identifiers(),
+            sql_views: manager.sql_session.lock().map(|session| session.views().to_vec()).unwrap_or_default(),
         })
     }
 
@@ -97,6 +187,10 @@ impl WorkspaceState {
         let dataset_name = Some(container.datatable.dataframe.metadata.name.clone());
         // Column widths
         let column_widths = container.datatable.dataframe.column_width_config.clone();
+        // Heatmap shading
+        let heatmaps = container.datatable.dataframe.heatmap_config.clone();
+        // Display formatting
+        let display_settings = container.datatable.dataframe.display_settings.clone();
         // SQL query (prefer recorded last_sql_query if available)
         let sql_query = container
             .datatable
@@ -120,11 +214,51 @@ impl WorkspaceState {
             sort,
             filter,
             column_widths,
+            heatmaps,
+            display_settings,
             sql_query,
             jmes_expression,
             jmes_add_columns,
-            current_df_parquet: None
+            current_df_parquet: None,
+            enabled_style_sets: None,
+            history: container.datatable.dataframe.history.clone(),
+            checkpoints: container.datatable.dataframe.checkpoints.clone(),
+            row_tags: container.datatable.dataframe.row_tags.clone(),
+            validation_rules: container.datatable.dataframe.validation_rules.clone(),
+        }
+    }
+
+    /// Rewrite every dataset source path with `f`
+    pub fn map_source_paths(&mut self, mut f: impl FnMut(&Path) -> PathBuf) {
+        let mut f = |path: &Path| if is_generated_path(path) { path.to_path_buf() } else { f(path) };
+        for source in &mut self.data_sources {
+            source.file_path = f(Path::new(&source.file_path)).to_string_lossy().to_string();
+            for path in source.data_import_config.paths_mut() {
+                *path = f(path);
+            }
+            for hash in &mut source.file_hashes {
+                hash.path = f(&hash.path);
+            }
         }
+        for tab in &mut self.tabs {
+            if let Some(path) = &tab.source_file_path {
+                tab.source_file_path = Some(f(Path::new(path)).to_string_lossy().to_string());
+            }
+        }
+    }
+
+    /// Source files that do not exist on this machine, in first-seen order
+    pub fn missing_source_paths(&self) -> Vec<PathBuf> {
+        let mut missing: Vec<PathBuf> = Vec::new();
+        for source in &self.data_sources {
+            let mut config = source.data_import_config.clone();
+            for path in config.paths_mut() {
+                if !is_generated_path(path) && !path.exists() && !missing.contains(path) {
+                    missing.push(path.clone());
+                }
+            }
+        }
+        missing
     }
 
     pub fn save_to(&self, workspace_path: &Path) -> color_eyre::Result<()> {
@@ -133,8 +267,11 @@ impl WorkspaceState {
         }
         // ensure folders and just write state JSON; parquet writing is handled by caller with access to containers
         create_dir_all(workspace_path)?;
+        // Source paths are stored relative to the workspace so the project can be moved
+        let mut portable = self.clone();
+        portable.map_source_paths(|path| relative_to(path, workspace_path));
         let file = File::create(workspace_path.join("datatui_workspace_state.json"))?;
-        serde_json::to_writer_pretty(file, &self)?;
+        serde_json::to_writer_pretty(file, &portable)?;
         Ok(())
     }
 
@@ -144,13 +281,36 @@ impl WorkspaceState {
             return Ok(None);
         }
         let file = File::open(file_path)?;
-        let state: WorkspaceState = serde_json::from_reader(file)?;
+        let mut state: WorkspaceState = serde_json::from_reader(file)?;
+        state.map_source_paths(|path| resolve_path(path, workspace_path));
+        // The folder the state was loaded from wins over the path recorded when it was saved
+        state.project.workspace_path = Some(workspace_path.to_path_buf());
         Ok(Some(state))
     }
 
     pub fn apply_to(self, manager: &mut DataTabManagerDialog) -> color_eyre::Result<()> {
         // Apply project settings (workspace already known)
         manager.project_settings_dialog.config = self.project;
+        if let Ok(mut session) = manager.sql_session.lock() {
+            session.set_views(self.sql_views);
+        }
+
+        // Generated datasets have no file to load from; their saved view is their data
+        if let Some(path) = manager.project_settings_dialog.config.workspace_path.as_ref() {
+            let parquet_root = path.join(".datatui").join("tabs");
+            for tab_state in &self.tabs {
+                let generated = self.data_sources.iter().any(|source| {
+                    is_generated_path(Path::new(&source.file_path))
+                        && source.datasets.iter().any(|d| d.id == tab_state.dataset_id)
+                });
+                if let (true, Some(fname)) = (generated, &tab_state.current_df_parquet)
+                    && let Ok(file) = File::open(parquet_root.join(fname))
+                    && let Ok(df) = ParquetReader::new(file).finish()
+                {
+                    manager.data_management_dialog.cache_dataframe(&tab_state.dataset_id, Arc::new(df));
+                }
+            }
+        }
 
         // Extend data sources using a function
         manager.data_management_dialog.extend_data_sources(self.data_sources);
@@ -166,6 +326,10 @@ impl WorkspaceState {
             return Ok(());
         };
 
+        // Per-tab style sets are restored once the matching tabs are known
+        let mut tab_style_sets: Vec<(String, Vec<String>)> = Vec::new();
+        // Tabs come back in the order they were saved
+        let mut saved_order: Vec<String> = Vec::new();
         for tab_state in self.tabs.into_iter() {
             // Try direct match by dataset_id
             let mut container_key: Option<String> = if manager.containers.contains_key(&tab_state.dataset_id) {
@@ -196,6 +360,12 @@ impl WorkspaceState {
                 container_key = found;
             }
 
+            if let Some(key) = &container_key {
+                saved_order.push(key.clone());
+            }
+            if let (Some(key), Some(ids)) = (&container_key, &tab_state.enabled_style_sets) {
+                tab_style_sets.push((key.clone(), ids.clone()));
+            }
             if let Some(key) = container_key
                 && let Some(container) = manager.containers.get_mut(&key)
             {
@@ -205,6 +375,29 @@ impl WorkspaceState {
 
                 // column widths
                 container.datatable.set_column_width_config(tab_state.column_widths.clone());
+                container.datatable.set_heatmap_config(tab_state.heatmaps.clone());
+                container.datatable.set_display_settings(tab_state.display_settings.clone());
+
+                container.datatable.dataframe.history = tab_state.history.clone();
+                container.datatable.dataframe.checkpoints = tab_state
+                    .checkpoints
+                    .iter()
+                    .cloned()
+                    .map(|mut checkpoint| {
+                        // Without a snapshot the checkpoint is rebuilt from its history on restore
+                        checkpoint.dataframe = checkpoint
+                            .parquet
+                            .as_ref()
+                            .map(|fname| parquet_root.join(fname))
+                            .filter(|path| path.exists())
+                            .and_then(|path| File::open(path).ok())
+                            .and_then(|file| ParquetReader::new(file).finish().ok())
+                            .map(Arc::new);
+                        checkpoint
+                    })
+                    .collect();
+                container.datatable.dataframe.row_tags = tab_state.row_tags.clone();
+                container.datatable.dataframe.validation_rules = tab_state.validation_rules.clone();
 
                 // sql
                 container.sql_dialog.set_textarea_content(&tab_state.sql_query);
@@ -242,6 +435,10 @@ impl WorkspaceState {
         }
         // Apply enabled style sets
         manager.style_set_manager.set_enabled_identifiers(self.enabled_style_sets);
+        for (tab_id, ids) in tab_style_sets {
+            manager.style_set_manager.set_tab_enabled_identifiers(&tab_id, Some(ids));
+        }
+        manager.set_tab_order(&saved_order);
 
         // After applying state, refresh active container so UI reflects latest data
         if let Some(container) = manager.get_active_container() {
@@ -252,3 +449,25 @@ impl WorkspaceState {
 }
 
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relative_paths_round_trip() {
+        let workspace = Path::new("/cases/1234/project");
+        let evidence = Path::new("/cases/1234/evidence/logs/access.csv");
+        let relative = relative_to(evidence, workspace);
+        assert_eq!(relative, PathBuf::from("../evidence/logs/access.csv"));
+        assert_eq!(resolve_path(&relative, workspace), evidence);
+        assert_eq!(
+            resolve_path(&relative, Path::new("/mnt/share/1234/project")),
+            PathBuf::from("/mnt/share/1234/evidence/logs/access.csv")
+        );
+
+        // Nothing in common but the root: keep the absolute path
+        assert_eq!(relative_to(Path::new("/data/x.csv"), workspace), PathBuf::from("/data/x.csv"));
+        assert_eq!(relative_to(Path::new("/cases/1234/project/x.csv"), workspace), PathBuf::from("x.csv"));
+    }
+}
@@ -0,0 +1,34 @@
+use std::path::Path;
+use std::process::Command;
+
+fn datatui(dir: &Path, args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_datatui"))
+        .args(args)
+        .current_dir(dir)
+        .env("HOME", dir)
+        .output()
+        .expect("failed to run datatui");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn query_schema_and_convert_subcommands() {
+    let dir = std::env::temp_dir().join(format!("datatui_cli_{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("hosts.csv"), "host,status\nweb-1,200\nweb-2,500\ndb-1,503\n").unwrap();
+
+    let out = datatui(&dir, &["query", "--load", "hosts.csv", "SELECT host FROM \"hosts.csv\" WHERE status >= 500 ORDER BY host"]);
+    assert_eq!(out, "host\ndb-1\nweb-2\n");
+
+    let schema = datatui(&dir, &["schema", "hosts.csv"]);
+    assert!(schema.starts_with("hosts.csv (3 rows)"));
+    assert!(schema.contains("status\ti64"));
+
+    datatui(&dir, &["convert", "hosts.csv", "hosts.parquet"]);
+    let schema = datatui(&dir, &["schema", "--json", "hosts.parquet"]);
+    let parsed: serde_json::Value = serde_json::from_str(&schema).unwrap();
+    assert_eq!(parsed[0]["rows"], 3);
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
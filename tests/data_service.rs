@@ -0,0 +1,89 @@
+use std::fs;
+use std::path::PathBuf;
+
+use datatui::data_import_types::DataImportConfig;
+use datatui::dialog::csv_options_dialog::CsvImportOptions;
+use datatui::dialog::filter_dialog::{ColumnFilter, FilterCondition, FilterExpr};
+use datatui::dialog::sort_dialog::SortColumn;
+use datatui::services::{DataService, ExportFormat};
+use polars::prelude::*;
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("datatui-service-{name}-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn load_sample(dir: &PathBuf) -> (DataService, String) {
+    let path = dir.join("events.csv");
+    fs::write(
+        &path,
+        "host,status,bytes\nweb-1,200,512\nweb-2,500,128\nweb-1,404,64\nweb-3,500,2048\n",
+    )
+    .unwrap();
+    let mut service = DataService::new();
+    let names = service
+        .import(DataImportConfig::text(path, CsvImportOptions::default()))
+        .expect("csv import should succeed");
+    assert_eq!(names.len(), 1);
+    assert_eq!(service.names(), names);
+    let name = names[0].clone();
+    (service, name)
+}
+
+#[test]
+fn import_filter_sort_and_query_without_tui() {
+    let dir = temp_dir("query");
+    let (service, name) = load_sample(&dir);
+    assert_eq!(service.dataset(&name).unwrap().height(), 4);
+
+    let filter = FilterExpr::Condition(ColumnFilter {
+        column: "host".to_string(),
+        condition: FilterCondition::Equals { value: "web-1".to_string(), case_sensitive: true },
+    });
+    let filtered = service.filter(&name, &filter).unwrap();
+    assert_eq!(filtered.height(), 2);
+
+    let sorted = service
+        .sort(&name, &[SortColumn::new("bytes", false)])
+        .unwrap();
+    assert_eq!(sorted.column("bytes").unwrap().get(0).unwrap().str_value(), "2048");
+
+    let errors = service
+        .sql(&format!("SELECT host, bytes FROM \"{name}\" WHERE status >= 500 ORDER BY bytes"))
+        .unwrap();
+    assert_eq!(errors.height(), 2);
+    assert_eq!(errors.column("host").unwrap().get(0).unwrap().str_value(), "web-2");
+
+    // Operations leave the stored dataset unchanged
+    assert_eq!(service.dataset(&name).unwrap().height(), 4);
+    assert!(service.sql("SELECT * FROM missing").is_err());
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn export_formats_round_trip() {
+    let dir = temp_dir("export");
+    let (service, name) = load_sample(&dir);
+    let mut df = service.dataset(&name).unwrap().as_ref().clone();
+
+    let parquet = dir.join("out.parquet");
+    DataService::export(&mut df, &parquet, ExportFormat::Parquet).unwrap();
+    let back = ParquetReader::new(fs::File::open(&parquet).unwrap()).finish().unwrap();
+    assert!(back.equals(&df));
+
+    let csv = dir.join("out.csv");
+    DataService::export(&mut df, &csv, ExportFormat::Csv).unwrap();
+    let mut reimported = DataService::new();
+    let names = reimported
+        .import(DataImportConfig::text(csv, CsvImportOptions::default()))
+        .unwrap();
+    assert_eq!(reimported.dataset(&names[0]).unwrap().height(), 4);
+
+    let jsonl = dir.join("out.jsonl");
+    DataService::export(&mut df, &jsonl, ExportFormat::JsonLines).unwrap();
+    let text = fs::read_to_string(&jsonl).unwrap();
+    assert_eq!(text.lines().count(), 4);
+    assert!(text.lines().next().unwrap().contains("\"host\":\"web-1\""));
+    fs::remove_dir_all(&dir).ok();
+}